@@ -0,0 +1,184 @@
+use super::{AsPort, BuilderInner};
+use crate::{
+    component::{Host, Scheme},
+    encoding::{
+        encoder::{Fragment, Path, Query, Userinfo},
+        EStr,
+    },
+    error::{BuildError, BuildErrorKind, Component},
+    internal::Meta,
+    Uri,
+};
+use alloc::string::String;
+
+/// A builder for URI reference without typestates.
+///
+/// Unlike [`Builder`], components may be set in any order, each with an
+/// optional value, which makes it easy to assemble a URI reference from
+/// components only known at runtime. The authority component is present
+/// if and only if the host is set. An unset path is empty.
+///
+/// All constraints are checked by [`build`], which reports
+/// the [component at fault] on error.
+///
+/// [`Builder`]: crate::Builder
+/// [`build`]: Self::build
+/// [component at fault]: BuildError::component
+///
+/// # Examples
+///
+/// ```
+/// use fluent_uri::{component::Scheme, encoding::EStr, error::Component, DynBuilder};
+///
+/// fn build(port: Option<u16>, query: Option<&str>) -> Result<String, fluent_uri::error::BuildError> {
+///     let uri = DynBuilder::new()
+///         .scheme(Some(Scheme::new_or_panic("http")))
+///         .host(Some(EStr::new_or_panic("example.com").into()))
+///         .port(port)
+///         .path(Some(EStr::new_or_panic("/search")))
+///         .query(query.map(EStr::new_or_panic))
+///         .build()?;
+///     Ok(uri.into_string())
+/// }
+///
+/// assert_eq!(build(None, None).unwrap(), "http://example.com/search");
+/// assert_eq!(build(Some(80), Some("q=1")).unwrap(), "http://example.com:80/search?q=1");
+///
+/// let e = DynBuilder::new()
+///     .port(Some(80))
+///     .path(Some(EStr::new_or_panic("/")))
+///     .build()
+///     .unwrap_err();
+/// assert_eq!(e.component(), Component::Port);
+/// ```
+#[derive(Clone, Default)]
+#[must_use]
+pub struct DynBuilder<'a> {
+    scheme: Option<&'a Scheme>,
+    userinfo: Option<&'a EStr<Userinfo>>,
+    host: Option<Host<'a>>,
+    port: Option<u16>,
+    path: Option<&'a EStr<Path>>,
+    query: Option<&'a EStr<Query>>,
+    fragment: Option<&'a EStr<Fragment>>,
+}
+
+impl<'a> DynBuilder<'a> {
+    /// Creates a new builder with no component set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets or clears the [scheme] component.
+    ///
+    /// [scheme]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.1
+    pub fn scheme(mut self, scheme: Option<&'a Scheme>) -> Self {
+        self.scheme = scheme;
+        self
+    }
+
+    /// Sets or clears the [userinfo] subcomponent of authority.
+    ///
+    /// [userinfo]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.2.1
+    pub fn userinfo(mut self, userinfo: Option<&'a EStr<Userinfo>>) -> Self {
+        self.userinfo = userinfo;
+        self
+    }
+
+    /// Sets or clears the [host] subcomponent of authority.
+    ///
+    /// Setting the host adds the authority component, while clearing it removes
+    /// the authority. See [`Builder::host`] for how a [`Host`] is written.
+    ///
+    /// [host]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.2.2
+    /// [`Builder::host`]: crate::Builder::host
+    pub fn host(mut self, host: Option<Host<'a>>) -> Self {
+        self.host = host;
+        self
+    }
+
+    /// Sets or clears the [port] subcomponent of authority.
+    ///
+    /// [port]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.2.3
+    pub fn port(mut self, port: Option<u16>) -> Self {
+        self.port = port;
+        self
+    }
+
+    /// Sets or clears the [path] component.
+    ///
+    /// [path]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.3
+    pub fn path(mut self, path: Option<&'a EStr<Path>>) -> Self {
+        self.path = path;
+        self
+    }
+
+    /// Sets or clears the [query] component.
+    ///
+    /// [query]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.4
+    pub fn query(mut self, query: Option<&'a EStr<Query>>) -> Self {
+        self.query = query;
+        self
+    }
+
+    /// Sets or clears the [fragment] component.
+    ///
+    /// [fragment]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.5
+    pub fn fragment(mut self, fragment: Option<&'a EStr<Fragment>>) -> Self {
+        self.fragment = fragment;
+        self
+    }
+
+    /// Builds the URI reference.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the userinfo or the port is set without the host,
+    /// or if any of the conditions listed in [`Builder::build`] is not met.
+    ///
+    /// [`Builder::build`]: crate::Builder::build
+    pub fn build(self) -> Result<Uri<String>, BuildError> {
+        let mut inner = BuilderInner {
+            buf: String::new(),
+            meta: Meta::default(),
+            err: None,
+        };
+
+        if let Some(scheme) = self.scheme {
+            inner.push_scheme(scheme.as_str());
+        }
+
+        if let Some(host) = self.host {
+            inner.start_authority();
+            if let Some(userinfo) = self.userinfo {
+                inner.push_userinfo(userinfo.as_str());
+            }
+            inner.push_host(host);
+            if let Some(port) = self.port {
+                port.push_to(&mut inner.buf);
+            }
+        } else if self.userinfo.is_some() {
+            return Err(BuildError(BuildErrorKind::MissingAuthority(
+                Component::Userinfo,
+            )));
+        } else if self.port.is_some() {
+            return Err(BuildError(BuildErrorKind::MissingAuthority(
+                Component::Port,
+            )));
+        }
+
+        inner.push_path(self.path.map_or("", EStr::as_str));
+        if let Some(query) = self.query {
+            inner.push_query(query.as_str());
+        }
+        if let Some(fragment) = self.fragment {
+            inner.push_fragment(fragment.as_str());
+        }
+
+        inner.validate()?;
+        Ok(Uri {
+            val: inner.buf,
+            meta: inner.meta,
+        })
+    }
+}
@@ -1,19 +1,23 @@
 #![allow(missing_debug_implementations)]
 
-mod state;
+mod dynamic;
+pub(crate) mod state;
+
+pub use dynamic::DynBuilder;
 
 use crate::{
-    component::{Host, Scheme},
+    component::{Authority, Host, Scheme},
     encoding::{
-        encoder::{Fragment, Path, Port, Query, Userinfo},
-        EStr,
+        encoder::{Fragment, Path, PathSegment, Port, Query, Userinfo},
+        EStr, EString, Encoder, Table,
     },
     error::{BuildError, BuildErrorKind},
-    internal::{AuthMeta, Meta},
+    internal::{AuthMeta, HostMeta, Meta},
     parser, Uri,
 };
 use alloc::string::String;
-use core::{fmt::Write, marker::PhantomData, num::NonZeroUsize};
+use borrow_or_share::Bos;
+use core::{fmt::Write, marker::PhantomData, mem, num::NonZeroUsize};
 use state::*;
 
 /// A builder for URI reference.
@@ -73,6 +77,30 @@ use state::*;
 /// The builder typestates are currently private. Please open an issue
 /// if it is a problem not being able to name the type of a builder.
 ///
+/// # Modifying an existing URI reference
+///
+/// A builder created by [`from_uri`] or [`Uri::to_builder`] starts with
+/// all the components of a URI reference and is not subject to the above
+/// constraints. Components may be overridden or cleared in any order with
+/// the `set_*` methods, and [`build`] validates the result as a whole.
+///
+/// ```
+/// use fluent_uri::{encoding::EStr, Uri};
+///
+/// let base = Uri::parse("https://user@example.com:8443/foo?bar#baz")?;
+/// let uri = base
+///     .to_builder()
+///     .set_userinfo(None)
+///     .set_port(None)
+///     .set_path(EStr::new_or_panic("/qux"))
+///     .set_query(None)
+///     .build()?;
+/// assert_eq!(uri.as_str(), "https://example.com/qux#baz");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [`from_uri`]: Self::from_uri
+///
 /// [`advance`]: Self::advance
 /// [`optional`]: Self::optional
 /// [`scheme`]: Self::scheme
@@ -93,6 +121,7 @@ pub struct Builder<S> {
 struct BuilderInner {
     buf: String,
     meta: Meta,
+    err: Option<BuildError>,
 }
 
 impl BuilderInner {
@@ -112,29 +141,16 @@ impl BuilderInner {
         self.buf.push('@');
     }
 
-    fn push_host(&mut self, host: Host<'_>) {
-        let auth_meta = self.meta.auth_meta.as_mut().unwrap();
-        auth_meta.host_bounds.0 = self.buf.len();
-
-        match host {
-            #[cfg(feature = "net")]
-            Host::Ipv4(addr) => {
-                write!(self.buf, "{addr}").unwrap();
-                auth_meta.host_meta = crate::internal::HostMeta::Ipv4(addr);
-            }
-            #[cfg(feature = "net")]
-            Host::Ipv6(addr) => {
-                write!(self.buf, "[{addr}]").unwrap();
-                auth_meta.host_meta = crate::internal::HostMeta::Ipv6(addr);
-            }
-            Host::RegName(name) => {
-                auth_meta.host_meta = parser::parse_v4_or_reg_name(name.as_str().as_bytes());
-                self.buf.push_str(name.as_str());
+    fn push_host<'a>(&mut self, host: impl AsHost<'a>) {
+        let start = self.buf.len();
+        match host.push_to(&mut self.buf) {
+            Ok(host_meta) => {
+                let auth_meta = self.meta.auth_meta.as_mut().unwrap();
+                auth_meta.host_bounds = (start, self.buf.len());
+                auth_meta.host_meta = host_meta;
             }
-            _ => unreachable!(),
+            Err(e) => self.fail(e),
         }
-
-        auth_meta.host_bounds.1 = self.buf.len();
     }
 
     fn push_path(&mut self, v: &str) {
@@ -154,28 +170,79 @@ impl BuilderInner {
         self.buf.push_str(v);
     }
 
+    /// Records an error to be returned on build, keeping the first one.
+    fn fail(&mut self, e: BuildError) {
+        self.err.get_or_insert(e);
+    }
+
     fn validate(&self) -> Result<(), BuildError> {
-        fn first_segment_contains_colon(path: &str) -> bool {
-            path.split_once('/').map_or(path, |x| x.0).contains(':')
+        if let Some(e) = self.err {
+            return Err(e);
         }
-
         let (start, end) = self.meta.path_bounds;
-        let path = &self.buf[start..end];
+        validate(
+            self.meta.scheme_end.is_some(),
+            self.meta.auth_meta.is_some(),
+            &self.buf[start..end],
+        )
+    }
+}
 
-        if self.meta.auth_meta.is_some() {
-            if !path.is_empty() && !path.starts_with('/') {
-                return Err(BuildError(BuildErrorKind::NonAbemptyPath));
-            }
-        } else {
-            if path.starts_with("//") {
-                return Err(BuildError(BuildErrorKind::PathStartingWithDoubleSlash));
-            }
-            if self.meta.scheme_end.is_none() && first_segment_contains_colon(path) {
-                return Err(BuildError(BuildErrorKind::ColonInFirstPathSegment));
-            }
+/// Appends a host to the buffer, returning its metadata.
+///
+/// Returns `Err` without touching the buffer if the host does not carry
+/// its text, which is the case for an IPvFuture address, or for an IP address
+/// when the `net` feature is disabled.
+pub(crate) fn push_host(buf: &mut String, host: Host<'_>) -> Result<HostMeta, BuildError> {
+    Ok(match host {
+        #[cfg(feature = "net")]
+        Host::Ipv4(addr) => {
+            write!(buf, "{addr}").unwrap();
+            HostMeta::Ipv4(addr)
+        }
+        #[cfg(feature = "net")]
+        Host::Ipv6(addr) => {
+            write!(buf, "[{addr}]").unwrap();
+            HostMeta::Ipv6(addr)
+        }
+        Host::RegName(name) => {
+            buf.push_str(name.as_str());
+            parser::parse_v4_or_reg_name(name.as_str().as_bytes())
+        }
+        _ => return Err(BuildError(BuildErrorKind::UnwritableHost)),
+    })
+}
+
+/// Checks that a path is valid given the presence of scheme and authority.
+pub(crate) fn validate(
+    has_scheme: bool,
+    has_authority: bool,
+    path: &str,
+) -> Result<(), BuildError> {
+    fn first_segment_contains_colon(path: &str) -> bool {
+        path.split_once('/').map_or(path, |x| x.0).contains(':')
+    }
+
+    if has_authority {
+        if !path.is_empty() && !path.starts_with('/') {
+            return Err(BuildError(BuildErrorKind::NonAbemptyPath));
+        }
+    } else {
+        if path.starts_with("//") {
+            return Err(BuildError(BuildErrorKind::PathStartingWithDoubleSlash));
+        }
+        if !has_scheme && first_segment_contains_colon(path) {
+            return Err(BuildError(BuildErrorKind::ColonInFirstPathSegment));
         }
-        Ok(())
     }
+    Ok(())
+}
+
+/// An encoder for the user name and the password in userinfo.
+struct UserOrPassword;
+
+impl Encoder for UserOrPassword {
+    const TABLE: &'static Table = &Userinfo::TABLE.sub(&Table::gen(b":"));
 }
 
 pub(crate) type BuilderStart = Builder<Start>;
@@ -187,12 +254,111 @@ impl Builder<Start> {
             inner: BuilderInner {
                 buf: String::new(),
                 meta: Meta::default(),
+                err: None,
             },
             state: PhantomData,
         }
     }
 }
 
+impl Builder<Modify> {
+    /// Creates a builder pre-populated with the components of a URI reference.
+    ///
+    /// See the [type-level documentation](Self#modifying-an-existing-uri-reference)
+    /// for details.
+    pub fn from_uri<T: Bos<str>>(uri: &Uri<T>) -> Self {
+        Self {
+            inner: BuilderInner {
+                buf: uri.as_str().into(),
+                meta: uri.meta,
+                err: None,
+            },
+            state: PhantomData,
+        }
+    }
+
+    fn modify(mut self, f: impl FnOnce(&mut Uri<String>)) -> Self {
+        let mut uri = Uri {
+            val: mem::take(&mut self.inner.buf),
+            meta: self.inner.meta,
+        };
+        f(&mut uri);
+        self.inner.buf = uri.val;
+        self.inner.meta = uri.meta;
+        self
+    }
+
+    /// Sets or clears the [scheme] component.
+    ///
+    /// [scheme]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.1
+    pub fn set_scheme(self, scheme: Option<&Scheme>) -> Self {
+        self.modify(|uri| uri.replace_scheme(scheme))
+    }
+
+    /// Sets or clears the [authority] component.
+    ///
+    /// [authority]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.2
+    pub fn set_authority<U: Bos<str>>(self, authority: Option<&Authority<U>>) -> Self {
+        self.modify(|uri| uri.replace_authority(authority))
+    }
+
+    /// Sets or clears the [userinfo] subcomponent of authority.
+    ///
+    /// When setting it, an authority component with an empty host is added
+    /// if there is none. Clearing it does nothing if there is no authority.
+    ///
+    /// [userinfo]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.2.1
+    pub fn set_userinfo(self, userinfo: Option<&EStr<Userinfo>>) -> Self {
+        self.modify(|uri| uri.replace_userinfo(userinfo))
+    }
+
+    /// Sets the [host] subcomponent of authority.
+    ///
+    /// An authority component is added if there is none.
+    /// See [`host`](Builder::host) for the types of argument accepted.
+    ///
+    /// [host]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.2.2
+    pub fn set_host<'a>(self, host: impl Into<Host<'a>>) -> Self {
+        let mut res = Ok(());
+        let mut this = self.modify(|uri| res = uri.replace_host(host.into()));
+        if let Err(e) = res {
+            this.inner.fail(e);
+        }
+        this
+    }
+
+    /// Sets or clears the [port] subcomponent of authority.
+    ///
+    /// When setting it, an authority component with an empty host is added
+    /// if there is none. Clearing it does nothing if there is no authority.
+    ///
+    /// [port]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.2.3
+    pub fn set_port(self, port: Option<u16>) -> Self {
+        self.modify(|uri| uri.replace_port(port))
+    }
+
+    /// Sets the [path] component.
+    ///
+    /// [path]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.3
+    pub fn set_path(self, path: &EStr<Path>) -> Self {
+        self.modify(|uri| uri.replace_path(path))
+    }
+
+    /// Sets or clears the [query] component.
+    ///
+    /// [query]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.4
+    pub fn set_query(self, query: Option<&EStr<Query>>) -> Self {
+        self.modify(|uri| uri.replace_query(query))
+    }
+
+    /// Sets or clears the [fragment] component.
+    ///
+    /// [fragment]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.5
+    pub fn set_fragment(self, fragment: Option<&EStr<Fragment>>) -> Self {
+        self.modify(|uri| uri.set_fragment(fragment))
+    }
+}
+
 impl<S> Builder<S> {
     fn cast<T>(self) -> Builder<T>
     where
@@ -295,6 +461,42 @@ impl<S: To<UserinfoEnd>> Builder<S> {
         self.inner.push_userinfo(userinfo.as_str());
         self.cast()
     }
+
+    /// Sets the [userinfo] subcomponent of authority to a user name and an
+    /// optional password, percent-encoding them.
+    ///
+    /// The user name and the password are joined with `':'`, which is
+    /// percent-encoded where it appears in either of them.
+    ///
+    /// Note that passing authentication information in the userinfo
+    /// subcomponent is deprecated by RFC 3986.
+    ///
+    /// [userinfo]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.2.1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::{encoding::EStr, Uri};
+    ///
+    /// let uri = Uri::builder()
+    ///     .authority(|b| {
+    ///         b.userinfo_encoded("John Doe", Some("p@ss:word"))
+    ///             .host(EStr::new_or_panic("example.com"))
+    ///     })
+    ///     .path(EStr::EMPTY)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(uri.as_str(), "//John%20Doe:p%40ss%3Aword@example.com");
+    /// ```
+    pub fn userinfo_encoded(self, user: &str, password: Option<&str>) -> Builder<UserinfoEnd> {
+        let mut buf = EString::<Userinfo>::new();
+        buf.encode::<UserOrPassword>(user);
+        if let Some(password) = password {
+            buf.push_byte(b':');
+            buf.encode::<UserOrPassword>(password);
+        }
+        self.userinfo(&buf)
+    }
 }
 
 impl<S: To<HostEnd>> Builder<S> {
@@ -309,10 +511,20 @@ impl<S: To<HostEnd>> Builder<S> {
     ///   <code>&amp;[EString]&lt;[RegName]&gt;</code>
     ///   into [`Host::RegName`].
     ///
+    /// With the `idna` crate feature enabled, this method also takes a `&str`
+    /// as argument, which is converted to its ASCII form with [`idna::to_ascii`],
+    /// with any remaining character not allowed in a registered name
+    /// percent-encoded. If the conversion fails, [`build`](Self::build)
+    /// returns `Err`.
+    ///
     /// If the contents of an input [`Host::RegName`] variant matches the
     /// `IPv4address` ABNF rule defined in [Section 3.2.2 of RFC 3986][host],
     /// the resulting [`Uri`] will output a [`Host::Ipv4`] variant instead.
     ///
+    /// A [`Host::IpvFuture`] variant, or a [`Host::Ipv4`] or [`Host::Ipv6`]
+    /// variant when the `net` crate feature is disabled, does not carry the
+    /// text of the host and causes [`build`](Self::build) to return `Err`.
+    ///
     /// Note that the host subcomponent is *case-insensitive* and normalized to
     /// *lowercase*. You should use only lowercase in registered names for consistency.
     ///
@@ -323,6 +535,7 @@ impl<S: To<HostEnd>> Builder<S> {
     /// [`IpAddr::V6`]: std::net::IpAddr::V6
     /// [RegName]: crate::encoding::encoder::RegName
     /// [EString]: crate::encoding::EString
+    /// [`idna::to_ascii`]: crate::idna::to_ascii
     ///
     /// # Examples
     ///
@@ -336,12 +549,35 @@ impl<S: To<HostEnd>> Builder<S> {
     ///     .unwrap();
     /// assert!(matches!(uri.authority().unwrap().host_parsed(), Host::Ipv4(_)));
     /// ```
-    pub fn host<'a>(mut self, host: impl Into<Host<'a>>) -> Builder<HostEnd> {
-        self.inner.push_host(host.into());
+    pub fn host<'a, H: AsHost<'a>>(mut self, host: H) -> Builder<HostEnd> {
+        self.inner.push_host(host);
         self.cast()
     }
 }
 
+pub trait AsHost<'a> {
+    fn push_to(self, buf: &mut String) -> Result<HostMeta, BuildError>;
+}
+
+impl<'a, T: Into<Host<'a>>> AsHost<'a> for T {
+    fn push_to(self, buf: &mut String) -> Result<HostMeta, BuildError> {
+        push_host(buf, self.into())
+    }
+}
+
+#[cfg(feature = "idna")]
+impl<'a> AsHost<'a> for &'a str {
+    fn push_to(self, buf: &mut String) -> Result<HostMeta, BuildError> {
+        use crate::encoding::encoder::RegName;
+
+        let ascii = crate::idna::to_ascii(self)
+            .map_err(|e| BuildError(BuildErrorKind::InvalidDomainName(e)))?;
+        let mut name = EString::<RegName>::new();
+        name.encode::<RegName>(&ascii);
+        push_host(buf, Host::RegName(&name))
+    }
+}
+
 pub trait AsPort {
     fn push_to(&self, buf: &mut String);
 }
@@ -387,6 +623,39 @@ impl<S: To<PathEnd>> Builder<S> {
         self.inner.push_path(path.as_str());
         self.cast()
     }
+
+    /// Sets the [path] component to the given segments, percent-encoding them.
+    ///
+    /// Each segment is preceded by `'/'`, which is percent-encoded where it
+    /// appears in a segment. The path is empty if there are no segments.
+    ///
+    /// [path]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.3
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::{component::Scheme, encoding::EStr, Uri};
+    ///
+    /// let uri = Uri::builder()
+    ///     .scheme(Scheme::new_or_panic("http"))
+    ///     .authority(|b| b.host(EStr::new_or_panic("example.com")))
+    ///     .path_segments(["files", "a/b c.txt"])
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(uri.as_str(), "http://example.com/files/a%2Fb%20c.txt");
+    /// ```
+    pub fn path_segments<I>(self, segments: I) -> Builder<PathEnd>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut buf = EString::<Path>::new();
+        for segment in segments {
+            buf.push_byte(b'/');
+            buf.encode::<PathSegment>(segment.as_ref());
+        }
+        self.path(&buf)
+    }
 }
 
 impl<S: To<QueryEnd>> Builder<S> {
@@ -397,6 +666,38 @@ impl<S: To<QueryEnd>> Builder<S> {
         self.inner.push_query(query.as_str());
         self.cast()
     }
+
+    /// Sets the [query] component to the given name-value pairs,
+    /// serialized as `application/x-www-form-urlencoded`.
+    ///
+    /// See [`EString::push_form_pair`] for how the pairs are encoded.
+    ///
+    /// [query]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.4
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::{encoding::EStr, Uri};
+    ///
+    /// let uri = Uri::builder()
+    ///     .path(EStr::new_or_panic("/search"))
+    ///     .query_pairs([("q", "rust & uri"), ("page", "2")])
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(uri.as_str(), "/search?q=rust+%26+uri&page=2");
+    /// ```
+    pub fn query_pairs<I, K, V>(self, pairs: I) -> Builder<QueryEnd>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let mut buf = EString::<Query>::new();
+        for (name, value) in pairs {
+            buf.push_form_pair(name.as_ref(), value.as_ref());
+        }
+        self.query(&buf)
+    }
 }
 
 impl<S: To<FragmentEnd>> Builder<S> {
@@ -407,6 +708,31 @@ impl<S: To<FragmentEnd>> Builder<S> {
         self.inner.push_fragment(fragment.as_str());
         self.cast()
     }
+
+    /// Sets the [fragment] component, percent-encoding the given string.
+    ///
+    /// All characters not allowed in the fragment component,
+    /// as well as `'%'`, are percent-encoded.
+    ///
+    /// [fragment]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.5
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::{encoding::EStr, Uri};
+    ///
+    /// let uri = Uri::builder()
+    ///     .path(EStr::EMPTY)
+    ///     .fragment_encoded("section 1/50%")
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(uri.as_str(), "#section%201/50%25");
+    /// ```
+    pub fn fragment_encoded(self, fragment: &str) -> Builder<FragmentEnd> {
+        let mut buf = EString::<Fragment>::new();
+        buf.encode::<Fragment>(fragment);
+        self.fragment(&buf)
+    }
 }
 
 impl<S: To<End>> Builder<S> {
@@ -419,6 +745,7 @@ impl<S: To<End>> Builder<S> {
     /// - When authority is present, the path must either be empty or start with `'/'`.
     /// - When authority is not present, the path cannot start with `"//"`.
     /// - In a [relative-path reference][rel-ref], the first path segment cannot contain `':'`.
+    /// - The host, if set, must carry its text (see [`host`](Builder::host)).
     ///
     /// [rel-ref]: https://datatracker.ietf.org/doc/html/rfc3986/#section-4.2
     pub fn build(self) -> Result<Uri<String>, BuildError> {
@@ -22,6 +22,8 @@ pub struct QueryEnd(());
 pub struct FragmentEnd(());
 /// End of URI reference.
 pub struct End(());
+/// Modification of an existing URI reference.
+pub struct Modify(());
 
 /// Indicates the next possible state.
 pub trait To<T> {}
@@ -44,6 +46,7 @@ impl_to!(AuthorityEnd => PathEnd);
 impl_to!(PathEnd => QueryEnd, FragmentEnd, End);
 impl_to!(QueryEnd => FragmentEnd, End);
 impl_to!(FragmentEnd => End);
+impl_to!(Modify => End);
 
 /// Indicates that we may advance to this state.
 pub trait AdvanceDst {}
@@ -3,18 +3,30 @@
 use crate::{
     encoding::{
         encoder::{Port, RegName, Userinfo},
-        table, EStr, EString,
+        table, EStr, EString, Encoder,
     },
+    error::{DnsNameError, DnsNameErrorKind},
     internal::{AuthMeta, HostMeta},
     Uri,
 };
+use alloc::borrow::Cow;
 use borrow_or_share::BorrowOrShare;
-use core::{iter, num::ParseIntError};
+use core::{
+    hash::{Hash, Hasher},
+    iter,
+    marker::PhantomData,
+    num::ParseIntError,
+};
 use ref_cast::{ref_cast_custom, RefCastCustom};
 
 #[cfg(feature = "net")]
 use crate::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
+#[cfg(feature = "idna")]
+use crate::error::{IdnaError, IdnaErrorKind};
+#[cfg(feature = "idna")]
+use alloc::string::String;
+
 #[cfg(all(feature = "net", feature = "std"))]
 use std::{
     io,
@@ -127,17 +139,34 @@ impl Eq for Scheme {}
 /// The [authority] component of URI reference.
 ///
 /// [authority]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.2
+///
+/// # Type parameters
+///
+/// The encoders of the userinfo and registered name subcomponents are
+/// [`Userinfo`] and [`RegName`] in a [`Uri`], and [`IUserinfo`] and
+/// [`IRegName`] in an [`Iri`].
+///
+/// [`Iri`]: crate::Iri
+/// [`IUserinfo`]: crate::encoding::encoder::IUserinfo
+/// [`IRegName`]: crate::encoding::encoder::IRegName
 #[derive(RefCastCustom)]
 #[repr(transparent)]
-pub struct Authority<T> {
+pub struct Authority<T, UserinfoE: Encoder = Userinfo, RegNameE: Encoder = RegName> {
+    #[trivial]
+    encoder: PhantomData<(UserinfoE, RegNameE)>,
     uri: Uri<T>,
 }
 
-impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Authority<T> {
-    /// Converts from `&Uri<T>` to `&Authority<T>`,
+impl<'i, 'o, T, UserinfoE, RegNameE> Authority<T, UserinfoE, RegNameE>
+where
+    T: BorrowOrShare<'i, 'o, str>,
+    UserinfoE: Encoder,
+    RegNameE: Encoder,
+{
+    /// Converts from `&Uri<T>` to `&Authority<T, UserinfoE, RegNameE>`,
     /// assuming that authority is present.
     #[ref_cast_custom]
-    pub(crate) fn new(uri: &Uri<T>) -> &Authority<T>;
+    pub(crate) fn new(uri: &Uri<T>) -> &Authority<T, UserinfoE, RegNameE>;
 
     pub(crate) fn meta(&self) -> &AuthMeta {
         self.uri.auth_meta.as_ref().unwrap()
@@ -194,7 +223,7 @@ impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Authority<T> {
     /// # Ok::<_, fluent_uri::error::ParseError>(())
     /// ```
     #[must_use]
-    pub fn userinfo(&'i self) -> Option<&'o EStr<Userinfo>> {
+    pub fn userinfo(&'i self) -> Option<&'o EStr<UserinfoE>> {
         let (start, host_start) = (self.start(), self.host_bounds().0);
         (start != host_start).then(|| self.uri.eslice(start, host_start - 1))
     }
@@ -265,7 +294,7 @@ impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Authority<T> {
     /// # Ok::<_, fluent_uri::error::ParseError>(())
     /// ```
     #[must_use]
-    pub fn host_parsed(&'i self) -> Host<'o> {
+    pub fn host_parsed(&'i self) -> Host<'o, RegNameE> {
         match self.meta().host_meta {
             #[cfg(feature = "net")]
             HostMeta::Ipv4(addr) => Host::Ipv4(addr),
@@ -356,6 +385,82 @@ impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Authority<T> {
             .transpose()
     }
 
+    /// Returns the host as a domain name in ASCII form, with non-ASCII labels
+    /// converted to A-labels as per [UTS #46](crate::idna).
+    ///
+    /// A registered name is percent-decoded before conversion. An IP literal
+    /// or IPv4 address is returned as is.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the percent-decoded registered name is not valid UTF-8
+    /// or fails the conversion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::{Iri, Uri};
+    ///
+    /// let uri = Uri::parse("http://B%C3%BCcher.example/")?;
+    /// assert_eq!(uri.authority().unwrap().host_ascii()?, "xn--bcher-kva.example");
+    ///
+    /// let iri = Iri::parse("http://Bücher.example/")?;
+    /// assert_eq!(iri.authority().unwrap().host_ascii()?, "xn--bcher-kva.example");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "idna")]
+    pub fn host_ascii(&'i self) -> Result<Cow<'o, str>, IdnaError> {
+        self.map_reg_name(crate::idna::to_ascii)
+    }
+
+    /// Returns the host as a domain name in Unicode form, with A-labels
+    /// converted to U-labels as per [UTS #46](crate::idna).
+    ///
+    /// A registered name is percent-decoded before conversion. An IP literal
+    /// or IPv4 address is returned as is.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the percent-decoded registered name is not valid UTF-8
+    /// or fails the conversion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::Uri;
+    ///
+    /// let uri = Uri::parse("http://xn--bcher-kva.EXAMPLE/")?;
+    /// assert_eq!(uri.authority().unwrap().host_unicode()?, "bücher.example");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "idna")]
+    pub fn host_unicode(&'i self) -> Result<Cow<'o, str>, IdnaError> {
+        self.map_reg_name(crate::idna::to_unicode)
+    }
+
+    #[cfg(feature = "idna")]
+    fn map_reg_name(
+        &'i self,
+        f: fn(&str) -> Result<String, IdnaError>,
+    ) -> Result<Cow<'o, str>, IdnaError> {
+        let host = self.host();
+        let Host::RegName(name) = self.host_parsed() else {
+            return Ok(Cow::Borrowed(host));
+        };
+        let decoded = name
+            .decode()
+            .into_string()
+            .map_err(|_| IdnaError(IdnaErrorKind::InvalidUtf8))?;
+        let mapped = f(&decoded)?;
+        Ok(if mapped == host {
+            Cow::Borrowed(host)
+        } else {
+            Cow::Owned(mapped)
+        })
+    }
+}
+
+impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Authority<T> {
     /// Converts the authority component to an iterator of resolved [`SocketAddr`]s.
     ///
     /// The default port is used if the port component is not present or is empty.
@@ -394,9 +499,13 @@ impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Authority<T> {
 /// The parsed [host] component of URI reference.
 ///
 /// [host]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.2.2
-#[derive(Debug, Clone, Copy)]
-#[cfg_attr(fuzzing, derive(PartialEq, Eq))]
-pub enum Host<'a> {
+///
+/// The type parameter `RegNameE` is the encoder of registered names, which is
+/// [`RegName`] in a [`Uri`] and [`IRegName`] in an [`Iri`].
+///
+/// [`Iri`]: crate::Iri
+/// [`IRegName`]: crate::encoding::encoder::IRegName
+pub enum Host<'a, RegNameE: Encoder = RegName> {
     /// An IPv4 address.
     #[cfg_attr(not(feature = "net"), non_exhaustive)]
     Ipv4(
@@ -420,11 +529,40 @@ pub enum Host<'a> {
     /// A registered name.
     ///
     /// Note that registered names are *case-insensitive*.
-    RegName(&'a EStr<RegName>),
+    RegName(&'a EStr<RegNameE>),
+}
+
+impl<RegNameE: Encoder> Clone for Host<'_, RegNameE> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<RegNameE: Encoder> Copy for Host<'_, RegNameE> {}
+
+#[cfg(fuzzing)]
+impl<RegNameE: Encoder> PartialEq for Host<'_, RegNameE> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            #[cfg(feature = "net")]
+            (Host::Ipv4(a), Host::Ipv4(b)) => a == b,
+            #[cfg(feature = "net")]
+            (Host::Ipv6(a), Host::Ipv6(b)) => a == b,
+            #[cfg(not(feature = "net"))]
+            (Host::Ipv4(), Host::Ipv4()) | (Host::Ipv6(), Host::Ipv6()) => true,
+            (Host::IpvFuture, Host::IpvFuture) => true,
+            (Host::RegName(a), Host::RegName(b)) => a == b,
+            _ => false,
+        }
+    }
 }
 
+#[cfg(fuzzing)]
+impl<RegNameE: Encoder> Eq for Host<'_, RegNameE> {}
+
 #[cfg(feature = "net")]
-impl<'a> From<Ipv4Addr> for Host<'a> {
+impl<'a, RegNameE: Encoder> From<Ipv4Addr> for Host<'a, RegNameE> {
     #[inline]
     fn from(value: Ipv4Addr) -> Self {
         Self::Ipv4(value)
@@ -432,7 +570,7 @@ impl<'a> From<Ipv4Addr> for Host<'a> {
 }
 
 #[cfg(feature = "net")]
-impl<'a> From<Ipv6Addr> for Host<'a> {
+impl<'a, RegNameE: Encoder> From<Ipv6Addr> for Host<'a, RegNameE> {
     #[inline]
     fn from(value: Ipv6Addr) -> Self {
         Self::Ipv6(value)
@@ -440,7 +578,7 @@ impl<'a> From<Ipv6Addr> for Host<'a> {
 }
 
 #[cfg(feature = "net")]
-impl<'a> From<IpAddr> for Host<'a> {
+impl<'a, RegNameE: Encoder> From<IpAddr> for Host<'a, RegNameE> {
     #[inline]
     fn from(value: IpAddr) -> Self {
         match value {
@@ -450,16 +588,175 @@ impl<'a> From<IpAddr> for Host<'a> {
     }
 }
 
-impl<'a> From<&'a EStr<RegName>> for Host<'a> {
+impl<'a, RegNameE: Encoder> From<&'a EStr<RegNameE>> for Host<'a, RegNameE> {
     #[inline]
-    fn from(value: &'a EStr<RegName>) -> Self {
+    fn from(value: &'a EStr<RegNameE>) -> Self {
         Self::RegName(value)
     }
 }
 
-impl<'a> From<&'a EString<RegName>> for Host<'a> {
+impl<'a, RegNameE: Encoder> From<&'a EString<RegNameE>> for Host<'a, RegNameE> {
     #[inline]
-    fn from(value: &'a EString<RegName>) -> Self {
+    fn from(value: &'a EString<RegNameE>) -> Self {
         Self::RegName(value)
     }
 }
+
+impl<'a, RegNameE: Encoder> Host<'a, RegNameE> {
+    /// Validates the host as a DNS name in the syntax for host names
+    /// defined in [Section 2.1 of RFC 1123][rfc1123].
+    ///
+    /// The registered name is percent-decoded before validation.
+    /// See [`DnsName::new`] for the rules checked.
+    ///
+    /// [rfc1123]: https://datatracker.ietf.org/doc/html/rfc1123/#section-2.1
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the host is not a registered name or is not a valid DNS name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::{error::DnsNameErrorKind, Uri};
+    ///
+    /// let uri = Uri::parse("http://www.%65xample.com./")?;
+    /// let name = uri.authority().unwrap().host_parsed().as_dns_name()?;
+    /// assert_eq!(name.as_str(), "www.example.com.");
+    /// assert!(name.is_fully_qualified());
+    ///
+    /// let uri = Uri::parse("http://under_score.example/")?;
+    /// let e = uri.authority().unwrap().host_parsed().as_dns_name().unwrap_err();
+    /// assert_eq!(e.kind(), DnsNameErrorKind::InvalidChar);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn as_dns_name(&self) -> Result<DnsName<'a>, DnsNameError> {
+        match *self {
+            Host::RegName(name) => dns_name_from_estr(name),
+            _ => Err(DnsNameError(DnsNameErrorKind::NotRegName)),
+        }
+    }
+}
+
+pub(crate) fn dns_name_from_estr<E: Encoder>(name: &EStr<E>) -> Result<DnsName<'_>, DnsNameError> {
+    let name = name
+        .decode()
+        .into_string()
+        .map_err(|_| DnsNameError(DnsNameErrorKind::InvalidChar))?;
+    validate_dns_name(&name)?;
+    Ok(DnsName(name))
+}
+
+fn validate_dns_name(name: &str) -> Result<(), DnsNameError> {
+    let err = |kind| Err(DnsNameError(kind));
+
+    let name = name.strip_suffix('.').unwrap_or(name);
+    if name.is_empty() {
+        return err(DnsNameErrorKind::Empty);
+    }
+    if name.len() > 253 {
+        return err(DnsNameErrorKind::NameTooLong);
+    }
+
+    let mut last = "";
+    for label in name.split('.') {
+        if label.is_empty() {
+            return err(DnsNameErrorKind::EmptyLabel);
+        }
+        if label.len() > 63 {
+            return err(DnsNameErrorKind::LabelTooLong);
+        }
+        if !label
+            .bytes()
+            .all(|x| x.is_ascii_alphanumeric() || x == b'-')
+        {
+            return err(DnsNameErrorKind::InvalidChar);
+        }
+        if label.starts_with('-') || label.ends_with('-') {
+            return err(DnsNameErrorKind::HyphenAtLabelEdge);
+        }
+        last = label;
+    }
+    if last.bytes().all(|x| x.is_ascii_digit()) {
+        return err(DnsNameErrorKind::NumericTopLevelLabel);
+    }
+    Ok(())
+}
+
+/// A DNS name in the syntax for host names defined in [Section 2.1 of RFC 1123][rfc1123].
+///
+/// [rfc1123]: https://datatracker.ietf.org/doc/html/rfc1123/#section-2.1
+///
+/// This struct is created by [`DnsName::new`], [`Host::as_dns_name`]
+/// or [`EStr::to_hostname`].
+///
+/// # Comparison
+///
+/// `DnsName`s are compared case-insensitively, with any trailing dot taken
+/// into account.
+#[derive(Clone)]
+pub struct DnsName<'a>(Cow<'a, str>);
+
+impl<'a> DnsName<'a> {
+    /// Validates a string as a DNS name.
+    ///
+    /// The following rules are checked:
+    ///
+    /// - The name consists of labels separated by `'.'`, optionally followed
+    ///   by a trailing `'.'` denoting the root label.
+    /// - The name is non-empty and at most 253 octets long, excluding any trailing dot.
+    /// - Each label is non-empty and at most 63 octets long.
+    /// - Each label consists only of ASCII letters, digits and hyphens,
+    ///   and neither starts nor ends with a hyphen.
+    /// - The top-level label does not consist only of digits.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if any of the rules is violated.
+    pub fn new(s: &'a str) -> Result<Self, DnsNameError> {
+        validate_dns_name(s)?;
+        Ok(DnsName(Cow::Borrowed(s)))
+    }
+
+    /// Returns the DNS name as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Checks whether the DNS name is fully qualified, i.e., ends with a dot.
+    #[must_use]
+    pub fn is_fully_qualified(&self) -> bool {
+        self.0.ends_with('.')
+    }
+
+    /// Returns an iterator over the labels of the DNS name, excluding the root label.
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn labels(&self) -> impl DoubleEndedIterator<Item = &str> {
+        let name = self.0.strip_suffix('.').unwrap_or(&self.0);
+        name.split('.')
+    }
+
+    /// Consumes this `DnsName` and yields the underlying string.
+    #[must_use]
+    pub fn into_inner(self) -> Cow<'a, str> {
+        self.0
+    }
+}
+
+impl PartialEq for DnsName<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignore_ascii_case(&other.0)
+    }
+}
+
+impl Eq for DnsName<'_> {}
+
+impl Hash for DnsName<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for x in self.0.bytes() {
+            state.write_u8(x.to_ascii_lowercase());
+        }
+        state.write_u8(0xff);
+    }
+}
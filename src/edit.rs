@@ -0,0 +1,547 @@
+use crate::{
+    builder::{push_host, validate, AsPort},
+    component::{Authority, Host, Scheme},
+    encoding::{
+        encoder::{Fragment, Path, Query, Userinfo},
+        EStr, EString,
+    },
+    error::{BuildError, BuildErrorKind, Component},
+    internal::AuthMeta,
+    Uri,
+};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    string::String,
+    vec::Vec,
+};
+use borrow_or_share::Bos;
+use core::{num::NonZeroUsize, ops::Range};
+
+/// A component of URI reference, in order of appearance.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Part {
+    Scheme,
+    Authority,
+    Path,
+    Query,
+    Fragment,
+}
+
+/// Methods for setting components in place.
+///
+/// These methods splice the underlying `String` without reparsing and
+/// enforce the same invariants as [`Builder::build`], leaving the
+/// URI reference unchanged on error.
+///
+/// [`Builder::build`]: crate::Builder::build
+///
+/// # Examples
+///
+/// ```
+/// use fluent_uri::{component::Scheme, encoding::EStr, Uri};
+///
+/// let mut uri = Uri::parse("http://example.com/foo?bar#baz".to_owned())?;
+///
+/// uri.set_scheme(Some(Scheme::new_or_panic("https")))?;
+/// uri.set_host(EStr::new_or_panic("example.org"))?;
+/// uri.set_port(Some(8443))?;
+/// uri.set_path(EStr::new_or_panic("/qux"))?;
+/// uri.set_query(None);
+/// assert_eq!(uri.as_str(), "https://example.org:8443/qux#baz");
+///
+/// // The path must either be empty or start with '/' when authority is present.
+/// assert!(uri.set_path(EStr::new_or_panic("qux")).is_err());
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+impl Uri<String> {
+    /// Replaces the bytes in the given range, which lies within `part`,
+    /// shifting the offsets of the components after `part`.
+    fn splice(&mut self, range: Range<usize>, s: &str, part: Part) {
+        let shift = |i: usize| i - range.len() + s.len();
+
+        if part < Part::Authority {
+            if let Some(auth_meta) = &mut self.meta.auth_meta {
+                let (start, end) = auth_meta.host_bounds;
+                auth_meta.host_bounds = (shift(start), shift(end));
+            }
+        }
+        if part < Part::Path {
+            let (start, end) = self.path_bounds;
+            self.path_bounds = (shift(start), shift(end));
+        }
+        if part < Part::Query {
+            self.query_end = self
+                .query_end
+                .map(|i| NonZeroUsize::new(shift(i.get())).unwrap());
+        }
+        self.val.replace_range(range, s);
+    }
+
+    /// Returns the index where the authority component (including `"//"`)
+    /// starts or would start.
+    fn authority_start(&self) -> usize {
+        self.scheme_end.map_or(0, |i| i.get() + 1)
+    }
+
+    /// Adds an authority component with an empty host if there is none.
+    fn ensure_authority(&mut self) -> AuthMeta {
+        if let Some(auth_meta) = self.auth_meta {
+            return auth_meta;
+        }
+        let start = self.path_bounds.0;
+        self.splice(start..start, "//", Part::Authority);
+        let auth_meta = AuthMeta {
+            host_bounds: (start + 2, start + 2),
+            ..AuthMeta::default()
+        };
+        self.auth_meta = Some(auth_meta);
+        auth_meta
+    }
+
+    pub(crate) fn replace_scheme(&mut self, scheme: Option<&Scheme>) {
+        let mut new = String::new();
+        if let Some(scheme) = scheme {
+            new.push_str(scheme.as_str());
+            new.push(':');
+        }
+        self.splice(0..self.authority_start(), &new, Part::Scheme);
+        self.scheme_end = NonZeroUsize::new(new.len().saturating_sub(1));
+    }
+
+    pub(crate) fn replace_authority<U: Bos<str>>(&mut self, authority: Option<&Authority<U>>) {
+        let start = self.authority_start();
+        let mut new = String::new();
+        let auth_meta = authority.map(|auth| {
+            new.push_str("//");
+            new.push_str(auth.as_str());
+
+            let mut meta = *auth.meta();
+            let offset = start + 2;
+            meta.host_bounds.0 = meta.host_bounds.0 - auth.start() + offset;
+            meta.host_bounds.1 = meta.host_bounds.1 - auth.start() + offset;
+            meta
+        });
+        self.splice(start..self.path_bounds.0, &new, Part::Authority);
+        self.auth_meta = auth_meta;
+    }
+
+    pub(crate) fn replace_userinfo(&mut self, userinfo: Option<&EStr<Userinfo>>) {
+        if userinfo.is_none() && self.auth_meta.is_none() {
+            return;
+        }
+        let auth_meta = self.ensure_authority();
+
+        let start = self.authority_start() + 2;
+        let mut new = String::new();
+        if let Some(userinfo) = userinfo {
+            new.push_str(userinfo.as_str());
+            new.push('@');
+        }
+        let (host_start, host_end) = auth_meta.host_bounds;
+        self.splice(start..host_start, &new, Part::Authority);
+
+        let host_start = start + new.len();
+        self.auth_meta.as_mut().unwrap().host_bounds =
+            (host_start, host_start + host_end - auth_meta.host_bounds.0);
+    }
+
+    pub(crate) fn replace_host(&mut self, host: Host<'_>) -> Result<(), BuildError> {
+        let mut new = String::new();
+        let host_meta = push_host(&mut new, host)?;
+
+        let (start, end) = self.ensure_authority().host_bounds;
+        self.splice(start..end, &new, Part::Authority);
+        self.auth_meta = Some(AuthMeta {
+            host_bounds: (start, start + new.len()),
+            host_meta,
+        });
+        Ok(())
+    }
+
+    pub(crate) fn replace_port(&mut self, port: Option<u16>) {
+        if port.is_none() && self.auth_meta.is_none() {
+            return;
+        }
+        let auth_meta = self.ensure_authority();
+
+        let mut new = String::new();
+        if let Some(port) = port {
+            port.push_to(&mut new);
+        }
+        self.splice(
+            auth_meta.host_bounds.1..self.path_bounds.0,
+            &new,
+            Part::Authority,
+        );
+    }
+
+    pub(crate) fn replace_path(&mut self, path: &EStr<Path>) {
+        let (start, end) = self.path_bounds;
+        self.splice(start..end, path.as_str(), Part::Path);
+        self.path_bounds = (start, start + path.len());
+    }
+
+    pub(crate) fn replace_query(&mut self, query: Option<&EStr<Query>>) {
+        let start = self.path_bounds.1;
+        let end = self.query_end.map_or(start, NonZeroUsize::get);
+
+        let mut new = String::new();
+        if let Some(query) = query {
+            new.push('?');
+            new.push_str(query.as_str());
+        }
+        self.splice(start..end, &new, Part::Query);
+        self.query_end = NonZeroUsize::new(start + new.len()).filter(|_| query.is_some());
+    }
+
+    /// Sets or removes the [scheme] component.
+    ///
+    /// [scheme]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.1
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the scheme is removed from a URI reference
+    /// without authority whose first path segment contains `':'`.
+    pub fn set_scheme(&mut self, scheme: Option<&Scheme>) -> Result<(), BuildError> {
+        validate(
+            scheme.is_some(),
+            self.auth_meta.is_some(),
+            self.path().as_str(),
+        )?;
+        self.replace_scheme(scheme);
+        Ok(())
+    }
+
+    /// Sets or removes the [authority] component.
+    ///
+    /// [authority]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.2
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the authority is added to a URI reference whose path is neither
+    /// empty nor starts with `'/'`, or removed from one whose path starts with `"//"`
+    /// or whose first path segment contains `':'` when the scheme is absent.
+    pub fn set_authority<U: Bos<str>>(
+        &mut self,
+        authority: Option<&Authority<U>>,
+    ) -> Result<(), BuildError> {
+        validate(
+            self.scheme_end.is_some(),
+            authority.is_some(),
+            self.path().as_str(),
+        )?;
+        self.replace_authority(authority);
+        Ok(())
+    }
+
+    /// Sets or removes the [userinfo] subcomponent of authority.
+    ///
+    /// [userinfo]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.2.1
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if authority is absent.
+    pub fn set_userinfo(&mut self, userinfo: Option<&EStr<Userinfo>>) -> Result<(), BuildError> {
+        if self.auth_meta.is_none() {
+            return Err(BuildError(BuildErrorKind::MissingAuthority(
+                Component::Userinfo,
+            )));
+        }
+        self.replace_userinfo(userinfo);
+        Ok(())
+    }
+
+    /// Sets the [host] subcomponent of authority, adding
+    /// an authority component with the host if there is none.
+    ///
+    /// This method takes any value whose type implements `Into<Host<'_>>`
+    /// as argument, as does [`Builder::host`].
+    ///
+    /// [host]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.2.2
+    /// [`Builder::host`]: crate::Builder::host
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the authority is added to a URI
+    /// reference whose path is neither empty nor starts with `'/'`,
+    /// or if the host cannot be written (see [`Builder::host`]).
+    pub fn set_host<'a>(&mut self, host: impl Into<Host<'a>>) -> Result<(), BuildError> {
+        validate(self.scheme_end.is_some(), true, self.path().as_str())?;
+        self.replace_host(host.into())
+    }
+
+    /// Sets or removes the [port] subcomponent of authority.
+    ///
+    /// [port]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.2.3
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if authority is absent.
+    pub fn set_port(&mut self, port: Option<u16>) -> Result<(), BuildError> {
+        if self.auth_meta.is_none() {
+            return Err(BuildError(BuildErrorKind::MissingAuthority(
+                Component::Port,
+            )));
+        }
+        self.replace_port(port);
+        Ok(())
+    }
+
+    /// Sets the [path] component.
+    ///
+    /// [path]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.3
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if any of the conditions listed in [`Builder::build`] is not met.
+    ///
+    /// [`Builder::build`]: crate::Builder::build
+    pub fn set_path(&mut self, path: &EStr<Path>) -> Result<(), BuildError> {
+        validate(
+            self.scheme_end.is_some(),
+            self.auth_meta.is_some(),
+            path.as_str(),
+        )?;
+        self.replace_path(path);
+        Ok(())
+    }
+
+    /// Sets or removes the [query] component.
+    ///
+    /// [query]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.4
+    pub fn set_query(&mut self, query: Option<&EStr<Query>>) {
+        self.replace_query(query);
+    }
+
+    /// Sets or removes the [fragment] component.
+    ///
+    /// [fragment]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.5
+    pub fn set_fragment(&mut self, fragment: Option<&EStr<Fragment>>) {
+        let len = self.val.len();
+        let start = self.fragment_start().map_or(len, |i| i - 1);
+
+        let mut new = String::new();
+        if let Some(fragment) = fragment {
+            new.push('#');
+            new.push_str(fragment.as_str());
+        }
+        self.splice(start..len, &new, Part::Fragment);
+    }
+}
+
+/// Methods for manipulating path segments in place.
+///
+/// These methods behave like their counterparts on [`EString<Path>`],
+/// except that a `'/'` is prepended to the path if it would otherwise be
+/// rootless while authority is present, and that the same invariants as
+/// [`set_path`] are enforced.
+///
+/// [`set_path`]: Self::set_path
+///
+/// # Examples
+///
+/// ```
+/// use fluent_uri::Uri;
+///
+/// let mut uri = Uri::parse("http://example.com?q".to_owned())?;
+/// uri.push_segment("docs")?;
+/// uri.push_segment("index.html")?;
+/// assert_eq!(uri.as_str(), "http://example.com/docs/index.html?q");
+///
+/// uri.set_extension("md")?;
+/// assert_eq!(uri.as_str(), "http://example.com/docs/index.md?q");
+///
+/// assert!(uri.pop_segment());
+/// uri.set_last_segment("api v2")?;
+/// assert_eq!(uri.as_str(), "http://example.com/api%20v2?q");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+impl Uri<String> {
+    fn edit_path(&mut self, f: impl FnOnce(&mut EString<Path>)) -> Result<(), BuildError> {
+        let mut path = self.path().to_owned();
+        f(&mut path);
+        if self.auth_meta.is_some() && !path.is_empty() && path.is_rootless() {
+            path.buf.insert(0, '/');
+        }
+        self.set_path(&path)
+    }
+
+    /// Appends a segment onto the end of the path, percent-encoding it.
+    ///
+    /// See [`EString::push_segment`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the segment would become the first segment
+    /// of a relative-path reference and contains `':'`.
+    pub fn push_segment(&mut self, segment: &str) -> Result<(), BuildError> {
+        self.edit_path(|path| path.push_segment(segment))
+    }
+
+    /// Removes the last segment of the path, returning `false` if
+    /// the path is empty or `"/"`.
+    ///
+    /// See [`EString::pop_segment`] for details.
+    pub fn pop_segment(&mut self) -> bool {
+        let Some(parent) = self.path().parent() else {
+            return false;
+        };
+        let end = self.path_bounds.0 + parent.len();
+        self.splice(end..self.path_bounds.1, "", Part::Path);
+        self.path_bounds.1 = end;
+        true
+    }
+
+    /// Replaces the last segment of the path, percent-encoding the new one.
+    ///
+    /// See [`EString::set_last_segment`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the segment would become the first segment
+    /// of a relative-path reference and contains `':'`.
+    pub fn set_last_segment(&mut self, segment: &str) -> Result<(), BuildError> {
+        self.edit_path(|path| path.set_last_segment(segment))
+    }
+
+    /// Replaces the extension of the last segment of the path,
+    /// percent-encoding the new one.
+    ///
+    /// See [`EStr::with_extension`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the extension would become part of the first segment
+    /// of a relative-path reference and contains `':'`.
+    pub fn set_extension(&mut self, ext: &str) -> Result<(), BuildError> {
+        self.edit_path(|path| *path = path.with_extension(ext))
+    }
+}
+
+/// Methods for editing the query component in place as a sequence of
+/// [`application/x-www-form-urlencoded`] name-value pairs.
+///
+/// Names and values are passed and compared in decoded form. Pairs that are
+/// left untouched keep their original encoding, while new pairs are encoded
+/// as by [`EString::push_form_pair`]. Empty pairs (e.g., in `"a=1&&b=2"`)
+/// are dropped by all methods except [`append_param`].
+///
+/// If no pairs remain after an edit, the query component is removed,
+/// along with the preceding `'?'`.
+///
+/// [`application/x-www-form-urlencoded`]: https://url.spec.whatwg.org/#application/x-www-form-urlencoded
+/// [`append_param`]: Self::append_param
+///
+/// # Examples
+///
+/// ```
+/// use fluent_uri::Uri;
+///
+/// let mut uri = Uri::parse("https://example.com/list?page=2&sort=asc#top".to_owned())?;
+///
+/// uri.set_param("page", "3");
+/// uri.append_param("tag", "a b");
+/// uri.remove_param("sort");
+/// assert_eq!(uri.as_str(), "https://example.com/list?page=3&tag=a+b#top");
+///
+/// uri.retain_params(|name, _| name != "page" && name != "tag");
+/// assert_eq!(uri.as_str(), "https://example.com/list#top");
+/// assert_eq!(uri.fragment().unwrap(), "top");
+/// # Ok::<_, fluent_uri::error::ParseError<String>>(())
+/// ```
+impl Uri<String> {
+    /// Sets the value of the first pair with the given name and removes the
+    /// other pairs with that name, or appends a new pair if there is none.
+    pub fn set_param(&mut self, name: &str, value: &str) {
+        let mut found = false;
+        self.edit_params(|buf, piece, (n, _)| {
+            if n == name {
+                if !found {
+                    found = true;
+                    buf.push_form_pair(name, value);
+                }
+            } else {
+                push_piece(buf, piece);
+            }
+        });
+        if !found {
+            self.append_param(name, value);
+        }
+    }
+
+    /// Appends a name-value pair to the query, adding the query
+    /// component if there is none.
+    pub fn append_param(&mut self, name: &str, value: &str) {
+        let mut buf = EString::<Query>::new();
+        if let Some(query) = self.query() {
+            buf.push_estr(query);
+        }
+        buf.push_form_pair(name, value);
+        self.replace_query(Some(&buf));
+    }
+
+    /// Removes all pairs with the given name.
+    pub fn remove_param(&mut self, name: &str) {
+        self.retain_params(|n, _| n != name);
+    }
+
+    /// Retains only the pairs for which the predicate returns `true`.
+    ///
+    /// The predicate is called with the name and the value of each pair in order.
+    pub fn retain_params<F: FnMut(&str, &str) -> bool>(&mut self, mut f: F) {
+        self.edit_params(|buf, piece, (name, value)| {
+            if f(&name, &value) {
+                push_piece(buf, piece);
+            }
+        });
+    }
+
+    /// Sorts the pairs by name.
+    ///
+    /// This sort is stable, i.e., pairs with the same name keep their relative order.
+    pub fn sort_params(&mut self) {
+        let Some(query) = self.query() else {
+            return;
+        };
+        let mut pieces: Vec<_> = query
+            .split('&')
+            .filter(|piece| !piece.is_empty())
+            .map(|piece| (decode_pair(piece).0, piece))
+            .collect();
+        pieces.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut buf = EString::<Query>::new();
+        for (_, piece) in pieces {
+            push_piece(&mut buf, piece);
+        }
+        self.replace_query(Some(buf.as_estr()).filter(|buf| !buf.is_empty()));
+    }
+
+    /// Rebuilds the query by calling `f` on each non-empty pair
+    /// with its raw and decoded forms.
+    fn edit_params<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut EString<Query>, &EStr<Query>, (Cow<'_, str>, Cow<'_, str>)),
+    {
+        let Some(query) = self.query() else {
+            return;
+        };
+        let mut buf = EString::<Query>::new();
+        for piece in query.split('&').filter(|piece| !piece.is_empty()) {
+            f(&mut buf, piece, decode_pair(piece));
+        }
+        self.replace_query(Some(buf.as_estr()).filter(|buf| !buf.is_empty()));
+    }
+}
+
+/// Decodes a non-empty pair.
+fn decode_pair(piece: &EStr<Query>) -> (Cow<'_, str>, Cow<'_, str>) {
+    piece.form_pairs().next().unwrap()
+}
+
+fn push_piece(buf: &mut EString<Query>, piece: &EStr<Query>) {
+    if !buf.is_empty() {
+        buf.push_byte(b'&');
+    }
+    buf.push_estr(piece);
+}
@@ -1,6 +1,6 @@
 #![allow(missing_debug_implementations)]
 
-//! Percent-encoders for URI components.
+//! Percent-encoders for URI and IRI components.
 
 use super::{table::*, Encoder, Table};
 
@@ -37,6 +37,13 @@ impl Encoder for Path {
     const TABLE: &'static Table = PATH;
 }
 
+/// An encoder for path segments, which encodes `'/'`.
+pub(crate) struct PathSegment(());
+
+impl Encoder for PathSegment {
+    const TABLE: &'static Table = &PATH.sub(&Table::gen(b"/"));
+}
+
 /// An encoder for query.
 pub struct Query(());
 
@@ -51,6 +58,41 @@ impl Encoder for Fragment {
     const TABLE: &'static Table = FRAGMENT;
 }
 
+/// An encoder for IRI userinfo.
+pub struct IUserinfo(());
+
+impl Encoder for IUserinfo {
+    const TABLE: &'static Table = IUSERINFO;
+}
+
+/// An encoder for IRI registered name.
+pub struct IRegName(());
+
+impl Encoder for IRegName {
+    const TABLE: &'static Table = IREG_NAME;
+}
+
+/// An encoder for IRI path.
+pub struct IPath(());
+
+impl Encoder for IPath {
+    const TABLE: &'static Table = IPATH;
+}
+
+/// An encoder for IRI query.
+pub struct IQuery(());
+
+impl Encoder for IQuery {
+    const TABLE: &'static Table = IQUERY;
+}
+
+/// An encoder for IRI fragment.
+pub struct IFragment(());
+
+impl Encoder for IFragment {
+    const TABLE: &'static Table = IFRAGMENT;
+}
+
 /// An encoder for data which preserves only [unreserved] characters
 /// and encodes the others.
 ///
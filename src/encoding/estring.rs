@@ -1,4 +1,8 @@
-use super::{Assert, EStr, Encoder};
+use super::{
+    encoder::{Path, PathSegment, Query},
+    table::FORM,
+    Assert, EStr, Encoder,
+};
 use alloc::{borrow::ToOwned, string::String};
 use core::{borrow::Borrow, cmp::Ordering, hash, marker::PhantomData, ops::Deref};
 
@@ -111,13 +115,16 @@ impl<E: Encoder> EString<E> {
 
     /// Encodes a byte sequence with a sub-encoder and appends the result onto the end of this `EString`.
     ///
-    /// A byte will be percent-encoded if and only if `SubE::TABLE` does not [allow] it.
+    /// A byte will be percent-encoded if and only if `SubE::TABLE` does not [allow] it,
+    /// unless it is part of a well-formed UTF-8 sequence of a character that
+    /// `SubE::TABLE` allows, such as a [`ucschar`] in an IRI component.
     /// When encoding data, make sure that `SubE::TABLE` does not [allow] the component delimiters
     /// that delimit the data.
     ///
     /// Note that this method will **not** encode `0x20` (space) as `U+002B` (+).
     ///
     /// [allow]: super::Table::allows
+    /// [`ucschar`]: super::Table::allows_ucschar
     ///
     /// # Panics
     ///
@@ -129,9 +136,7 @@ impl<E: Encoder> EString<E> {
         let () = Assert::<SubE, E>::LEFT_IS_SUB_ENCODER_OF_RIGHT;
         let () = EStr::<SubE>::ASSERT_ALLOWS_ENC;
 
-        for &x in s.as_ref() {
-            SubE::TABLE.encode(x, &mut self.buf);
-        }
+        SubE::TABLE.encode(s.as_ref(), &mut self.buf);
     }
 
     /// Appends an unencoded byte onto the end of this `EString`.
@@ -158,6 +163,122 @@ impl<E: Encoder> EString<E> {
     }
 }
 
+impl EString<Path> {
+    /// Appends a segment onto the end of the path, percent-encoding it.
+    ///
+    /// A `'/'` is first appended unless the path is empty or ends with `'/'`,
+    /// in which case the segment takes the place of the empty last segment.
+    /// Any `'/'` in the segment is percent-encoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::encoding::{encoder::Path, EStr, EString};
+    ///
+    /// let mut path = EString::<Path>::from(EStr::new_or_panic("/files/"));
+    /// path.push_segment("a/b");
+    /// path.push_segment("c d");
+    /// assert_eq!(path, "/files/a%2Fb/c%20d");
+    /// ```
+    pub fn push_segment(&mut self, segment: &str) {
+        if !self.buf.is_empty() && !self.buf.ends_with('/') {
+            self.buf.push('/');
+        }
+        self.encode::<PathSegment>(segment);
+    }
+
+    /// Removes the last segment of the path, returning `false` if
+    /// the path is empty or `"/"`.
+    ///
+    /// This truncates the path to its [`parent`](EStr::parent).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::encoding::{encoder::Path, EStr, EString};
+    ///
+    /// let mut path = EString::<Path>::from(EStr::new_or_panic("/a/b"));
+    /// assert!(path.pop_segment());
+    /// assert_eq!(path, "/a");
+    /// assert!(path.pop_segment());
+    /// assert_eq!(path, "/");
+    /// assert!(!path.pop_segment());
+    /// ```
+    pub fn pop_segment(&mut self) -> bool {
+        match self.parent() {
+            Some(parent) => {
+                self.buf.truncate(parent.len());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Replaces the last segment of the path, percent-encoding the new one.
+    ///
+    /// The last segment is everything after the last `'/'`, or the whole path
+    /// if there is none. Any `'/'` in the new segment is percent-encoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::encoding::{encoder::Path, EStr, EString};
+    ///
+    /// let mut path = EString::<Path>::from(EStr::new_or_panic("/a/b"));
+    /// path.set_last_segment("c");
+    /// assert_eq!(path, "/a/c");
+    ///
+    /// let mut path = EString::<Path>::from(EStr::new_or_panic("/a/"));
+    /// path.set_last_segment("c");
+    /// assert_eq!(path, "/a/c");
+    /// ```
+    pub fn set_last_segment(&mut self, segment: &str) {
+        let start = self.last_segment_start();
+        self.buf.truncate(start);
+        self.encode::<PathSegment>(segment);
+    }
+}
+
+impl EString<Query> {
+    /// Appends a name-value pair onto the end of this `EString`,
+    /// serialized as [`application/x-www-form-urlencoded`].
+    ///
+    /// A `'&'` is first appended if this `EString` is not empty. The name and
+    /// the value are then joined with `'='`, where spaces are encoded as `'+'` and
+    /// all bytes other than ASCII alphanumerics, `'*'`, `'-'`, `'.'` and `'_'`
+    /// are percent-encoded.
+    ///
+    /// [`application/x-www-form-urlencoded`]: https://url.spec.whatwg.org/#application/x-www-form-urlencoded
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::encoding::{encoder::Query, EString};
+    ///
+    /// let mut query = EString::<Query>::new();
+    /// query.push_form_pair("name", "Zoë Smith");
+    /// query.push_form_pair("expr", "1+1=2");
+    /// assert_eq!(query, "name=Zo%C3%AB+Smith&expr=1%2B1%3D2");
+    /// ```
+    pub fn push_form_pair(&mut self, name: &str, value: &str) {
+        if !self.buf.is_empty() {
+            self.buf.push('&');
+        }
+        encode_form(name, &mut self.buf);
+        self.buf.push('=');
+        encode_form(value, &mut self.buf);
+    }
+}
+
+fn encode_form(s: &str, buf: &mut String) {
+    for (i, chunk) in s.split(' ').enumerate() {
+        if i > 0 {
+            buf.push('+');
+        }
+        FORM.encode(chunk.as_bytes(), buf);
+    }
+}
+
 impl<E: Encoder> AsRef<EStr<E>> for EString<E> {
     fn as_ref(&self) -> &EStr<E> {
         self
@@ -26,6 +26,29 @@ pub(crate) fn decode_octet(hi: u8, lo: u8) -> u8 {
     OCTET_TABLE_HI[hi as usize] | OCTET_TABLE_LO[lo as usize]
 }
 
+/// Decodes the code point at index `i` of a UTF-8 byte sequence
+/// and returns it along with its length in bytes.
+///
+/// The bytes must be valid UTF-8 and `i` must be at a character boundary.
+pub(crate) const fn next_code_point(s: &[u8], i: usize) -> (u32, usize) {
+    let x = s[i];
+    if x < 0x80 {
+        (x as u32, 1)
+    } else if x < 0xe0 {
+        (((x as u32 & 0x1f) << 6) | (s[i + 1] as u32 & 0x3f), 2)
+    } else if x < 0xf0 {
+        let cp =
+            ((x as u32 & 0x0f) << 12) | ((s[i + 1] as u32 & 0x3f) << 6) | (s[i + 2] as u32 & 0x3f);
+        (cp, 3)
+    } else {
+        let cp = ((x as u32 & 0x07) << 18)
+            | ((s[i + 1] as u32 & 0x3f) << 12)
+            | ((s[i + 2] as u32 & 0x3f) << 6)
+            | (s[i + 3] as u32 & 0x3f);
+        (cp, 4)
+    }
+}
+
 /// Decodes a percent-encoded string, assuming that the string is properly encoded.
 pub(crate) fn decode(s: &[u8]) -> Option<Vec<u8>> {
     // Skip bytes that are not '%'.
@@ -46,3 +69,31 @@ pub(crate) fn decode(s: &[u8]) -> Option<Vec<u8>> {
     }
     Some(buf)
 }
+
+/// Decodes an `application/x-www-form-urlencoded` string,
+/// assuming that the string is properly encoded.
+pub(crate) fn decode_form(s: &[u8]) -> Option<Vec<u8>> {
+    // Skip bytes that are not '%' or '+'.
+    let mut i = s.iter().position(|&x| x == b'%' || x == b'+')?;
+
+    let mut buf = Vec::with_capacity(s.len());
+    buf.extend_from_slice(&s[..i]);
+
+    while i < s.len() {
+        match s[i] {
+            b'%' => {
+                buf.push(decode_octet(s[i + 1], s[i + 2]));
+                i += 3;
+            }
+            b'+' => {
+                buf.push(b' ');
+                i += 1;
+            }
+            x => {
+                buf.push(x);
+                i += 1;
+            }
+        }
+    }
+    Some(buf)
+}
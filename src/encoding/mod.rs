@@ -7,15 +7,16 @@ pub(crate) mod table;
 
 pub use estring::EString;
 
-pub(crate) use imp::{decode_octet, OCTET_TABLE_LO};
+pub(crate) use imp::{decode_octet, next_code_point, OCTET_TABLE_LO};
 
+use crate::{component::DnsName, error::DnsNameError};
 use alloc::{
     borrow::{Cow, ToOwned},
     string::{FromUtf8Error, String},
     vec::Vec,
 };
 use core::{cmp::Ordering, hash, iter::FusedIterator, marker::PhantomData, str};
-use encoder::Path;
+use encoder::{Path, PathSegment, Query, RegName};
 use ref_cast::{ref_cast_custom, RefCastCustom};
 
 /// A table specifying the byte patterns allowed in a string.
@@ -23,6 +24,8 @@ use ref_cast::{ref_cast_custom, RefCastCustom};
 pub struct Table {
     arr: [u8; 256],
     allows_enc: bool,
+    allows_ucschar: bool,
+    allows_iprivate: bool,
 }
 
 /// A trait used by [`EStr`] and [`EString`] to specify the table used for encoding.
@@ -54,6 +57,11 @@ pub trait Encoder: 'static {
 ///
 /// - `[x]` where `E::TABLE.allows(x)`.
 /// - `[b'%', hi, lo]` where `E::TABLE.allows_enc() && hi.is_ascii_hexdigit() && lo.is_ascii_hexdigit()`.
+/// - The UTF-8 encoding of a [`ucschar`] where `E::TABLE.allows_ucschar()`.
+/// - The UTF-8 encoding of an [`iprivate`] where `E::TABLE.allows_iprivate()`.
+///
+/// [`ucschar`]: https://datatracker.ietf.org/doc/html/rfc3987/#section-2.2
+/// [`iprivate`]: https://datatracker.ietf.org/doc/html/rfc3987/#section-2.2
 ///
 /// # Comparison
 ///
@@ -413,6 +421,171 @@ impl EStr<Path> {
         }
         split
     }
+
+    /// Returns the index at which the last segment starts.
+    fn last_segment_start(&self) -> usize {
+        self.inner.rfind('/').map_or(0, |i| i + 1)
+    }
+
+    /// Returns the path without its last segment, or `None`
+    /// if the path is empty or `"/"`.
+    ///
+    /// The `'/'` preceding the last segment is also removed,
+    /// unless it is the leading `'/'` of an absolute path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::encoding::{encoder::Path, EStr};
+    ///
+    /// let path = EStr::<Path>::new_or_panic("/a/b/c");
+    /// assert_eq!(path.parent().unwrap(), "/a/b");
+    /// assert_eq!(path.parent().unwrap().parent().unwrap(), "/a");
+    ///
+    /// assert_eq!(EStr::<Path>::new_or_panic("/a/").parent().unwrap(), "/a");
+    /// assert_eq!(EStr::<Path>::new_or_panic("/a").parent().unwrap(), "/");
+    /// assert_eq!(EStr::<Path>::new_or_panic("a").parent().unwrap(), "");
+    /// assert_eq!(EStr::<Path>::new_or_panic("/").parent(), None);
+    /// assert_eq!(EStr::<Path>::EMPTY.parent(), None);
+    /// ```
+    #[must_use]
+    pub fn parent(&self) -> Option<&Self> {
+        let parent = match self.inner.rfind('/') {
+            _ if self.inner.is_empty() || &self.inner == "/" => return None,
+            Some(0) => "/",
+            Some(i) => &self.inner[..i],
+            None => "",
+        };
+        Some(EStr::new_validated(parent))
+    }
+
+    /// Returns a copy of the path with its last segment replaced
+    /// by the given name, which is percent-encoded.
+    ///
+    /// See [`EString::set_last_segment`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::encoding::{encoder::Path, EStr};
+    ///
+    /// let path = EStr::<Path>::new_or_panic("/docs/index.html");
+    /// assert_eq!(path.with_file_name("a/b.html"), "/docs/a%2Fb.html");
+    /// ```
+    #[must_use]
+    pub fn with_file_name(&self, name: &str) -> EString<Path> {
+        let mut buf = self.to_owned();
+        buf.set_last_segment(name);
+        buf
+    }
+
+    /// Returns a copy of the path with the extension of its last segment
+    /// replaced by the given one, which is percent-encoded.
+    ///
+    /// The extension is the part of the last segment after its last `'.'`,
+    /// unless the `'.'` is at the start of the segment. An extension is added
+    /// if there is none, and removed if the given one is empty. The path is
+    /// left unchanged if the last segment is empty, `"."` or `".."`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::encoding::{encoder::Path, EStr};
+    ///
+    /// let path = EStr::<Path>::new_or_panic("/docs/index.html");
+    /// assert_eq!(path.with_extension("md"), "/docs/index.md");
+    /// assert_eq!(path.with_extension(""), "/docs/index");
+    ///
+    /// let path = EStr::<Path>::new_or_panic("/home/.bashrc");
+    /// assert_eq!(path.with_extension("bak"), "/home/.bashrc.bak");
+    /// ```
+    #[must_use]
+    pub fn with_extension(&self, ext: &str) -> EString<Path> {
+        let start = self.last_segment_start();
+        let name = &self.inner[start..];
+        if matches!(name, "" | "." | "..") {
+            return self.to_owned();
+        }
+
+        let stem_end = match name.rfind('.') {
+            Some(0) | None => self.len(),
+            Some(i) => start + i,
+        };
+        let mut buf = EString::new_validated(self.inner[..stem_end].into());
+        if !ext.is_empty() {
+            buf.push_byte(b'.');
+            buf.encode::<PathSegment>(ext);
+        }
+        buf
+    }
+}
+
+/// Extension methods for the [registered name] subcomponent of URI reference.
+///
+/// [registered name]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.2.2
+impl EStr<RegName> {
+    /// Percent-decodes and validates the registered name as a DNS name in the
+    /// syntax for host names defined in [Section 2.1 of RFC 1123][rfc1123].
+    ///
+    /// This is the same as [`Host::as_dns_name`] on a [`Host::RegName`].
+    ///
+    /// [rfc1123]: https://datatracker.ietf.org/doc/html/rfc1123/#section-2.1
+    /// [`Host::as_dns_name`]: crate::component::Host::as_dns_name
+    /// [`Host::RegName`]: crate::component::Host::RegName
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the registered name is not a valid DNS name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::encoding::{encoder::RegName, EStr};
+    ///
+    /// let name = EStr::<RegName>::new_or_panic("Example.COM");
+    /// assert_eq!(name.to_hostname()?.as_str(), "Example.COM");
+    ///
+    /// let name = EStr::<RegName>::new_or_panic("-example.com");
+    /// assert!(name.to_hostname().is_err());
+    /// # Ok::<_, fluent_uri::error::DnsNameError>(())
+    /// ```
+    pub fn to_hostname(&self) -> Result<DnsName<'_>, DnsNameError> {
+        crate::component::dns_name_from_estr(self)
+    }
+}
+
+/// Extension methods for the [query] component of URI reference.
+///
+/// [query]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.4
+impl EStr<Query> {
+    /// Returns an iterator over the decoded name-value pairs of the query,
+    /// parsed as [`application/x-www-form-urlencoded`].
+    ///
+    /// The query is split at `'&'` and empty pieces are skipped. Each piece is
+    /// split at its first `'='` into a name and a value, where the value is empty
+    /// if there is no `'='`. Both are then decoded with `'+'` read as a space,
+    /// and invalid UTF-8 is replaced with `U+FFFD`.
+    ///
+    /// [`application/x-www-form-urlencoded`]: https://url.spec.whatwg.org/#application/x-www-form-urlencoded
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::Uri;
+    ///
+    /// let uri = Uri::parse("?name=Zo%C3%AB+Smith&&flag&a=b=c")?;
+    /// let query = uri.query().unwrap();
+    /// assert!(query
+    ///     .form_pairs()
+    ///     .eq([("name", "Zoë Smith"), ("flag", ""), ("a", "b=c")]
+    ///         .map(|(k, v)| (k.into(), v.into()))));
+    /// # Ok::<_, fluent_uri::error::ParseError>(())
+    /// ```
+    pub fn form_pairs(&self) -> FormPairs<'_> {
+        FormPairs {
+            inner: self.inner.split('&'),
+        }
+    }
 }
 
 /// A wrapper of percent-decoded bytes.
@@ -495,3 +668,32 @@ impl<'a, E: Encoder> DoubleEndedIterator for Split<'a, E> {
 }
 
 impl<E: Encoder> FusedIterator for Split<'_, E> {}
+
+/// An iterator over the decoded name-value pairs of a form-urlencoded query.
+///
+/// This struct is created by [`EStr::form_pairs`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FormPairs<'a> {
+    inner: str::Split<'a, char>,
+}
+
+impl<'a> Iterator for FormPairs<'a> {
+    type Item = (Cow<'a, str>, Cow<'a, str>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pair = self.inner.find(|s| !s.is_empty())?;
+        let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+        Some((decode_form(name), decode_form(value)))
+    }
+}
+
+impl FusedIterator for FormPairs<'_> {}
+
+fn decode_form(s: &str) -> Cow<'_, str> {
+    match imp::decode_form(s.as_bytes()) {
+        Some(vec) => Decode::Owned(vec),
+        None => Decode::Borrowed(s),
+    }
+    .into_string_lossy()
+}
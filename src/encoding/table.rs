@@ -1,12 +1,13 @@
-//! Byte pattern tables from RFC 3986.
+//! Byte pattern tables from RFC 3986 and RFC 3987.
 //!
 //! The predefined table constants in this module are documented with
 //! the ABNF notation of [RFC 5234].
 //!
 //! [RFC 5234]: https://datatracker.ietf.org/doc/html/rfc5234/
 
-use super::Table;
+use super::{next_code_point, Table};
 use alloc::string::String;
+use core::str;
 
 const fn gen_hex_table() -> [u8; 512] {
     const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
@@ -43,6 +44,8 @@ impl Table {
         Table {
             arr,
             allows_enc: false,
+            allows_ucschar: false,
+            allows_iprivate: false,
         }
     }
 
@@ -53,6 +56,24 @@ impl Table {
         self
     }
 
+    /// Marks this table as allowing the UTF-8 encoding of any [`ucschar`].
+    ///
+    /// [`ucschar`]: https://datatracker.ietf.org/doc/html/rfc3987/#section-2.2
+    #[must_use]
+    pub const fn ucschar(mut self) -> Table {
+        self.allows_ucschar = true;
+        self
+    }
+
+    /// Marks this table as allowing the UTF-8 encoding of any [`iprivate`].
+    ///
+    /// [`iprivate`]: https://datatracker.ietf.org/doc/html/rfc3987/#section-2.2
+    #[must_use]
+    pub const fn iprivate(mut self) -> Table {
+        self.allows_iprivate = true;
+        self
+    }
+
     /// Combines two tables into one.
     ///
     /// Returns a new table that allows all the byte patterns allowed
@@ -65,6 +86,8 @@ impl Table {
             i += 1;
         }
         self.allows_enc |= other.allows_enc;
+        self.allows_ucschar |= other.allows_ucschar;
+        self.allows_iprivate |= other.allows_iprivate;
         self
     }
 
@@ -84,6 +107,12 @@ impl Table {
         if other.allows_enc {
             self.allows_enc = false;
         }
+        if other.allows_ucschar {
+            self.allows_ucschar = false;
+        }
+        if other.allows_iprivate {
+            self.allows_iprivate = false;
+        }
         self
     }
 
@@ -98,7 +127,9 @@ impl Table {
             }
             i += 1;
         }
-        !self.allows_enc || other.allows_enc
+        (!self.allows_enc || other.allows_enc)
+            && (!self.allows_ucschar || other.allows_ucschar)
+            && (!self.allows_iprivate || other.allows_iprivate)
     }
 
     /// Returns the specified table value.
@@ -121,18 +152,66 @@ impl Table {
         self.allows_enc
     }
 
+    /// Checks whether the UTF-8 encoding of any [`ucschar`] is allowed by the table.
+    ///
+    /// [`ucschar`]: https://datatracker.ietf.org/doc/html/rfc3987/#section-2.2
     #[inline]
-    pub(crate) fn encode(&self, x: u8, buf: &mut String) {
-        if self.allows(x) {
-            buf.push(x as char);
-        } else {
-            buf.push('%');
-            buf.push(HEX_TABLE[x as usize * 2] as char);
-            buf.push(HEX_TABLE[x as usize * 2 + 1] as char);
+    #[must_use]
+    pub const fn allows_ucschar(&self) -> bool {
+        self.allows_ucschar
+    }
+
+    /// Checks whether the UTF-8 encoding of any [`iprivate`] is allowed by the table.
+    ///
+    /// [`iprivate`]: https://datatracker.ietf.org/doc/html/rfc3987/#section-2.2
+    #[inline]
+    #[must_use]
+    pub const fn allows_iprivate(&self) -> bool {
+        self.allows_iprivate
+    }
+
+    /// Checks whether the given non-ASCII code point is allowed by the table.
+    #[inline]
+    pub(crate) const fn allows_code_point(&self, x: u32) -> bool {
+        (self.allows_ucschar && is_ucschar(x)) || (self.allows_iprivate && is_iprivate(x))
+    }
+
+    /// Percent-encodes a byte sequence with the table and appends the result onto `buf`.
+    ///
+    /// A non-ASCII character will be preserved if the table allows it.
+    pub(crate) fn encode(&self, s: &[u8], buf: &mut String) {
+        let mut i = 0;
+        while i < s.len() {
+            let x = s[i];
+            if self.allows(x) {
+                buf.push(x as char);
+                i += 1;
+                continue;
+            }
+            if !x.is_ascii() && (self.allows_ucschar || self.allows_iprivate) {
+                let len = match x {
+                    0xc2..=0xdf => 2,
+                    0xe0..=0xef => 3,
+                    0xf0..=0xf4 => 4,
+                    // Not a leading byte.
+                    _ => 1,
+                };
+                if let Some(Ok(ch)) = s.get(i..i + len).filter(|_| len > 1).map(str::from_utf8) {
+                    if self.allows_code_point(next_code_point(ch.as_bytes(), 0).0) {
+                        buf.push_str(ch);
+                        i += len;
+                        continue;
+                    }
+                }
+            }
+            push_pct_encoded(buf, x);
+            i += 1;
         }
     }
 
     /// Validates the given byte sequence with the table.
+    ///
+    /// The bytes must be valid UTF-8.
     pub(crate) const fn validate(&self, s: &[u8]) -> bool {
         let mut i = 0;
         if self.allows_enc() {
@@ -148,11 +227,17 @@ impl Table {
                         return false;
                     }
                     i += 3;
-                } else {
+                } else if x.is_ascii() {
                     if !self.allows(x) {
                         return false;
                     }
                     i += 1;
+                } else {
+                    let (cp, len) = next_code_point(s, i);
+                    if !self.allows_code_point(cp) {
+                        return false;
+                    }
+                    i += len;
                 }
             }
         } else {
@@ -167,6 +252,25 @@ impl Table {
     }
 }
 
+/// Appends a percent-encoded octet onto `buf` with uppercase hexadecimal digits.
+#[inline]
+pub(crate) fn push_pct_encoded(buf: &mut String, x: u8) {
+    buf.push('%');
+    buf.push(HEX_TABLE[x as usize * 2] as char);
+    buf.push(HEX_TABLE[x as usize * 2 + 1] as char);
+}
+
+/// `ucschar = %xA0-D7FF / %xF900-FDCF / %xFDF0-FFEF / %x10000-1FFFD / ... / %xE1000-EFFFD`
+const fn is_ucschar(x: u32) -> bool {
+    matches!(x, 0xa0..=0xd7ff | 0xf900..=0xfdcf | 0xfdf0..=0xffef)
+        || (matches!(x, 0x10000..=0xdffff | 0xe1000..=0xeffff) && x & 0xffff < 0xfffe)
+}
+
+/// `iprivate = %xE000-F8FF / %xF0000-FFFFD / %x100000-10FFFD`
+const fn is_iprivate(x: u32) -> bool {
+    matches!(x, 0xe000..=0xf8ff | 0xf0000..=0xffffd | 0x100000..=0x10fffd)
+}
+
 const fn gen(bytes: &[u8]) -> Table {
     Table::gen(bytes)
 }
@@ -207,6 +311,10 @@ pub const QUERY: &Table = &PCHAR.or(&gen(b"/?"));
 /// `fragment = *( pchar / "/" / "?" )`
 pub const FRAGMENT: &Table = QUERY;
 
+/// Bytes left unencoded by the `application/x-www-form-urlencoded`
+/// serializer from the WHATWG URL Standard.
+pub const FORM: &Table = &ALPHA.or(DIGIT).or(&gen(b"*-._"));
+
 /// `unreserved = ALPHA / DIGIT / "-" / "." / "_" / "~"`
 pub const UNRESERVED: &Table = &ALPHA.or(DIGIT).or(&gen(b"-._~"));
 
@@ -219,3 +327,38 @@ pub const GEN_DELIMS: &Table = &gen(b":/?#[]@");
 /// `sub-delims = "!" / "$" / "&" / "'" / "(" / ")"
 ///             / "*" / "+" / "," / ";" / "="`
 pub const SUB_DELIMS: &Table = &gen(b"!$&'()*+,;=");
+
+/// `ucschar = %xA0-D7FF / %xF900-FDCF / %xFDF0-FFEF
+///         / %x10000-1FFFD / %x20000-2FFFD / %x30000-3FFFD
+///         / %x40000-4FFFD / %x50000-5FFFD / %x60000-6FFFD
+///         / %x70000-7FFFD / %x80000-8FFFD / %x90000-9FFFD
+///         / %xA0000-AFFFD / %xB0000-BFFFD / %xC0000-CFFFD
+///         / %xD0000-DFFFD / %xE1000-EFFFD`
+pub const UCSCHAR: &Table = &gen(b"").ucschar();
+
+/// `iprivate = %xE000-F8FF / %xF0000-FFFFD / %x100000-10FFFD`
+pub const IPRIVATE: &Table = &gen(b"").iprivate();
+
+/// `iunreserved = ALPHA / DIGIT / "-" / "." / "_" / "~" / ucschar`
+pub const IUNRESERVED: &Table = &UNRESERVED.or(UCSCHAR);
+
+/// `iuserinfo = *( iunreserved / pct-encoded / sub-delims / ":" )`
+pub const IUSERINFO: &Table = &IUNRESERVED.or(SUB_DELIMS).or(&gen(b":")).enc();
+
+/// `ireg-name = *( iunreserved / pct-encoded / sub-delims )`
+pub const IREG_NAME: &Table = &IUNRESERVED.or(SUB_DELIMS).enc();
+
+/// `ipath = *( ipchar / "/" )`
+pub const IPATH: &Table = &IPCHAR.or(&gen(b"/"));
+
+/// `isegment-nz-nc = 1*( iunreserved / pct-encoded / sub-delims / "@" )`
+pub const ISEGMENT_NZ_NC: &Table = &IUNRESERVED.or(SUB_DELIMS).or(&gen(b"@")).enc();
+
+/// `ipchar = iunreserved / pct-encoded / sub-delims / ":" / "@"`
+pub const IPCHAR: &Table = &IUNRESERVED.or(SUB_DELIMS).or(&gen(b":@")).enc();
+
+/// `iquery = *( ipchar / iprivate / "/" / "?" )`
+pub const IQUERY: &Table = &IPCHAR.or(IPRIVATE).or(&gen(b"/?"));
+
+/// `ifragment = *( ipchar / "/" / "?" )`
+pub const IFRAGMENT: &Table = &IPCHAR.or(&gen(b"/?"));
@@ -1,6 +1,9 @@
 //! Error types.
 
-use crate::internal::{NoInput, ToUri};
+use crate::{
+    encoding::Table,
+    internal::{NoInput, ToUri},
+};
 
 /// Detailed cause of a [`ParseError`].
 #[derive(Clone, Copy, Debug)]
@@ -19,11 +22,44 @@ pub(crate) enum ParseErrorKind {
     InvalidIpv6Addr,
 }
 
+/// A URI component in which a [`ParseError`] or a [`BuildError`] occurred.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Component {
+    /// The scheme component.
+    Scheme,
+    /// The userinfo subcomponent of authority.
+    Userinfo,
+    /// The host subcomponent of authority.
+    Host,
+    /// The port subcomponent of authority.
+    Port,
+    /// The path component.
+    Path,
+    /// The query component.
+    Query,
+    /// The fragment component.
+    Fragment,
+}
+
 /// An error occurred when parsing URI references.
+///
+/// Besides the byte index at which parsing failed, the error records the
+/// [component] being parsed, the offending [character] and the [set of byte
+/// patterns] that would have been accepted instead. Use [`render`] to display
+/// these together with the input.
+///
+/// [component]: Self::component
+/// [character]: Self::unexpected_char
+/// [set of byte patterns]: Self::expected
+/// [`render`]: Self::render
 #[derive(Clone, Copy)]
 pub struct ParseError<I = NoInput> {
     pub(crate) index: usize,
     pub(crate) kind: ParseErrorKind,
+    pub(crate) component: Component,
+    pub(crate) expected: Option<&'static Table>,
+    pub(crate) ch: Option<char>,
     pub(crate) input: I,
 }
 
@@ -32,6 +68,76 @@ impl ParseError {
         ParseError {
             index: self.index,
             kind: self.kind,
+            component: self.component,
+            expected: self.expected,
+            ch: self.ch,
+            input,
+        }
+    }
+}
+
+impl<I> ParseError<I> {
+    /// Returns the byte index in the input at which the error occurred.
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the component in which the error occurred.
+    #[must_use]
+    pub fn component(&self) -> Component {
+        self.component
+    }
+
+    /// Returns the unexpected character at the error index.
+    ///
+    /// Returns `None` if the error is not caused by an unexpected character
+    /// or if the end of input was unexpectedly reached.
+    #[must_use]
+    pub fn unexpected_char(&self) -> Option<char> {
+        self.ch
+    }
+
+    /// Returns the table of byte patterns that would be accepted at the error index.
+    ///
+    /// The [`Display`] implementation of [`Table`] describes the table
+    /// in ABNF terms, e.g., `pchar, '/' or '?'`.
+    ///
+    /// Returns `None` if no single table applies, e.g., for an invalid IPv6 address.
+    ///
+    /// [`Display`]: core::fmt::Display
+    #[must_use]
+    pub fn expected(&self) -> Option<&'static Table> {
+        self.expected
+    }
+
+    /// Returns a value that displays the error in detail along with
+    /// the input, with a caret pointing to the error index.
+    ///
+    /// `input` should be the string that was attempted to parse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::Uri;
+    ///
+    /// let s = "http://a/b?c d";
+    /// let e = Uri::parse(s).unwrap_err();
+    /// assert_eq!(
+    ///     e.render(s).to_string(),
+    ///     "unexpected character ' ' in query at index 12; expected pchar, '/' or '?'\n\
+    ///      http://a/b?c d\n\
+    ///      \x20           ^"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn render<'a>(&'a self, input: &'a str) -> Render<'a> {
+        Render {
+            index: self.index,
+            kind: self.kind,
+            component: self.component,
+            expected: self.expected,
+            ch: self.ch,
             input,
         }
     }
@@ -52,11 +158,27 @@ impl<I: ToUri> ParseError<I> {
         ParseError {
             index: self.index,
             kind: self.kind,
+            component: self.component,
+            expected: self.expected,
+            ch: self.ch,
             input: NoInput,
         }
     }
 }
 
+/// A detailed rendering of a [`ParseError`] along with the input.
+///
+/// This struct is created by [`ParseError::render`].
+#[derive(Clone, Copy, Debug)]
+pub struct Render<'a> {
+    pub(crate) index: usize,
+    pub(crate) kind: ParseErrorKind,
+    pub(crate) component: Component,
+    pub(crate) expected: Option<&'static Table>,
+    pub(crate) ch: Option<char>,
+    pub(crate) input: &'a str,
+}
+
 #[cfg(feature = "std")]
 impl<I> std::error::Error for ParseError<I> {}
 
@@ -66,12 +188,36 @@ pub(crate) enum BuildErrorKind {
     NonAbemptyPath,
     PathStartingWithDoubleSlash,
     ColonInFirstPathSegment,
+    MissingAuthority(Component),
+    UnwritableHost,
+    #[cfg(feature = "idna")]
+    InvalidDomainName(IdnaError),
 }
 
 /// An error occurred when building URI references.
 #[derive(Clone, Copy, Debug)]
 pub struct BuildError(pub(crate) BuildErrorKind);
 
+impl BuildError {
+    /// Returns the component at fault.
+    ///
+    /// This is [`Component::Path`] when the path conflicts with the presence or
+    /// absence of scheme and authority, [`Component::Userinfo`] or
+    /// [`Component::Port`] when either is set without authority, and
+    /// [`Component::Host`] when the host cannot be written or converted
+    /// to its ASCII form.
+    #[must_use]
+    pub fn component(&self) -> Component {
+        match self.0 {
+            BuildErrorKind::MissingAuthority(comp) => comp,
+            BuildErrorKind::UnwritableHost => Component::Host,
+            #[cfg(feature = "idna")]
+            BuildErrorKind::InvalidDomainName(_) => Component::Host,
+            _ => Component::Path,
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for BuildError {}
 
@@ -80,7 +226,7 @@ impl std::error::Error for BuildError {}
 pub(crate) enum ResolveErrorKind {
     NonAbsoluteBase,
     NonHierarchicalBase,
-    // PathUnderflow,
+    PathUnderflow,
 }
 
 /// An error occurred when resolving URI references.
@@ -89,3 +235,153 @@ pub struct ResolveError(pub(crate) ResolveErrorKind);
 
 #[cfg(feature = "std")]
 impl std::error::Error for ResolveError {}
+
+/// Detailed cause of a [`TemplateError`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum TemplateErrorKind {
+    /// Invalid percent-encoded octet that is either non-hexadecimal or incomplete.
+    ///
+    /// The error index points to the percent character "%" of the octet.
+    InvalidOctet,
+    /// Unexpected character that is not allowed by the URI Template syntax.
+    ///
+    /// The error index points to the first byte of the character.
+    UnexpectedChar,
+    /// Expression not closed by "}".
+    ///
+    /// The error index points to the opening brace "{" of the expression.
+    UnclosedExpression,
+}
+
+/// An error occurred when parsing URI templates.
+#[derive(Clone, Copy, Debug)]
+pub struct TemplateError {
+    pub(crate) index: usize,
+    pub(crate) kind: TemplateErrorKind,
+}
+
+impl TemplateError {
+    /// Returns the byte index in the template at which the error occurred.
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TemplateError {}
+
+/// Detailed cause of an [`ExpandError`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum ExpandErrorKind {
+    PrefixOnComposite,
+    InvalidUri(ParseError),
+}
+
+/// An error occurred when expanding URI templates.
+#[derive(Clone, Copy, Debug)]
+pub struct ExpandError(pub(crate) ExpandErrorKind);
+
+#[cfg(feature = "std")]
+impl std::error::Error for ExpandError {}
+
+/// Detailed cause of a [`MatchError`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum MatchErrorKind {
+    NoMatch,
+    AmbiguousTemplate,
+}
+
+/// An error occurred when matching URI references against URI templates.
+#[derive(Clone, Copy, Debug)]
+pub struct MatchError(pub(crate) MatchErrorKind);
+
+#[cfg(feature = "std")]
+impl std::error::Error for MatchError {}
+
+/// Detailed cause of a [`DnsNameError`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DnsNameErrorKind {
+    /// The host is not a registered name.
+    NotRegName,
+    /// The name is empty or consists only of the root label.
+    Empty,
+    /// The name contains an empty label other than the root label.
+    EmptyLabel,
+    /// A label is longer than 63 octets.
+    LabelTooLong,
+    /// The name is longer than 253 octets, excluding a trailing dot.
+    NameTooLong,
+    /// A label contains an octet other than an ASCII letter, digit or hyphen.
+    InvalidChar,
+    /// A label starts or ends with a hyphen.
+    HyphenAtLabelEdge,
+    /// The top-level label consists only of digits.
+    NumericTopLevelLabel,
+}
+
+/// An error occurred when validating a host as a DNS name.
+#[derive(Clone, Copy, Debug)]
+pub struct DnsNameError(pub(crate) DnsNameErrorKind);
+
+impl DnsNameError {
+    /// Returns the detailed cause of the error.
+    #[must_use]
+    pub fn kind(&self) -> DnsNameErrorKind {
+        self.0
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DnsNameError {}
+
+/// Detailed cause of an [`IdnaError`].
+#[cfg(feature = "idna")]
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum IdnaErrorKind {
+    InvalidUtf8,
+    DisallowedChar,
+    InvalidPunycode,
+    InvalidLabel,
+}
+
+/// An error occurred when processing internationalized domain names.
+#[cfg(feature = "idna")]
+#[derive(Clone, Copy, Debug)]
+pub struct IdnaError(pub(crate) IdnaErrorKind);
+
+#[cfg(all(feature = "idna", feature = "std"))]
+impl std::error::Error for IdnaError {}
+
+/// Detailed cause of a [`DataUriError`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum DataUriErrorKind {
+    NotDataUri,
+    MissingComma,
+    InvalidMediaType,
+    InvalidBase64,
+}
+
+/// An error occurred when handling `data` URIs.
+#[derive(Clone, Copy, Debug)]
+pub struct DataUriError(pub(crate) DataUriErrorKind);
+
+#[cfg(feature = "std")]
+impl std::error::Error for DataUriError {}
+
+/// Detailed cause of a [`MailtoUriError`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum MailtoUriErrorKind {
+    NotMailtoUri,
+    InvalidUtf8,
+    EmptyAddress,
+    InvalidHeaderField,
+}
+
+/// An error occurred when handling `mailto` URIs.
+#[derive(Clone, Copy, Debug)]
+pub struct MailtoUriError(pub(crate) MailtoUriErrorKind);
+
+#[cfg(feature = "std")]
+impl std::error::Error for MailtoUriError {}
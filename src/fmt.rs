@@ -1,14 +1,23 @@
 use crate::{
-    component::{Authority, Scheme},
-    encoding::{EStr, EString, Encoder},
+    component::{Authority, DnsName, Host, Scheme},
+    encoding::{table::*, EStr, EString, Encoder, Table},
     error::{
-        BuildError, BuildErrorKind, ParseError, ParseErrorKind, ResolveError, ResolveErrorKind,
+        BuildError, BuildErrorKind, Component, DataUriError, DataUriErrorKind, DnsNameError,
+        DnsNameErrorKind, ExpandError, ExpandErrorKind, MailtoUriError, MailtoUriErrorKind,
+        MatchError, MatchErrorKind, ParseError, ParseErrorKind, Render, ResolveError,
+        ResolveErrorKind, TemplateError, TemplateErrorKind,
     },
-    Uri,
+    origin::Origin,
+    template::UriTemplate,
+    Diagnostics, Fixups, Iri, NormalizedHash, Uri,
 };
+use alloc::vec::Vec;
 use borrow_or_share::Bos;
 use core::fmt::{Debug, Display, Formatter, Result};
 
+#[cfg(feature = "idna")]
+use crate::error::{IdnaError, IdnaErrorKind};
+
 impl<E: Encoder> Debug for EStr<E> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         Debug::fmt(self.as_str(), f)
@@ -38,6 +47,8 @@ impl<I> Debug for ParseError<I> {
         f.debug_struct("ParseError")
             .field("index", &self.index)
             .field("kind", &self.kind)
+            .field("component", &self.component)
+            .field("unexpected_char", &self.ch)
             .finish()
     }
 }
@@ -53,6 +64,108 @@ impl<I> Display for ParseError<I> {
     }
 }
 
+impl Debug for Diagnostics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("Diagnostics")
+            .field("errors", &self.errors)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Display for Component {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(match self {
+            Component::Scheme => "scheme",
+            Component::Userinfo => "userinfo",
+            Component::Host => "host",
+            Component::Port => "port",
+            Component::Path => "path",
+            Component::Query => "query",
+            Component::Fragment => "fragment",
+        })
+    }
+}
+
+impl Display for Render<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match (self.kind, self.ch) {
+            (ParseErrorKind::InvalidOctet, _) => f.write_str("invalid percent-encoded octet")?,
+            (ParseErrorKind::UnexpectedChar, Some(ch)) => {
+                write!(f, "unexpected character {ch:?}")?;
+            }
+            (ParseErrorKind::UnexpectedChar, None) => f.write_str("unexpected end of input")?,
+            (ParseErrorKind::InvalidIpv6Addr, _) => f.write_str("invalid IPv6 address")?,
+        }
+        write!(f, " in {} at index {}", self.component, self.index)?;
+        if let Some(table) = self.expected {
+            write!(f, "; expected {table}")?;
+        }
+
+        let prefix = self.input.get(..self.index).unwrap_or(self.input);
+        write!(
+            f,
+            "\n{}\n{:>2$}",
+            self.input,
+            '^',
+            prefix.chars().count() + 1
+        )
+    }
+}
+
+/// Named tables tried in order when describing a table.
+const NAMED_TABLES: &[(&str, &Table)] = &[
+    ("ipchar", IPCHAR),
+    ("pchar", PCHAR),
+    ("iunreserved", IUNRESERVED),
+    ("unreserved", UNRESERVED),
+    ("sub-delims", SUB_DELIMS),
+    ("ALPHA", ALPHA),
+    ("HEXDIG", HEXDIG),
+    ("DIGIT", DIGIT),
+    ("ucschar", UCSCHAR),
+    ("iprivate", IPRIVATE),
+];
+
+/// Describes the table with the ABNF rule names of RFC 3986 and RFC 3987,
+/// followed by any remaining characters, e.g., `pchar, '/' or '?'`.
+impl Display for Table {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut rest = *self;
+        let mut names = Vec::new();
+        for &(name, table) in NAMED_TABLES {
+            if table.is_subset(&rest) {
+                rest = rest.sub(table);
+                names.push(name);
+            }
+        }
+        if rest.allows_enc() {
+            names.push("pct-encoded");
+        }
+
+        let chars = (0..128).filter(|&x| rest.allows(x)).map(char::from);
+        let len = names.len() + chars.clone().count();
+        if len == 0 {
+            return f.write_str("nothing");
+        }
+
+        for (i, item) in names
+            .iter()
+            .map(|&name| Ok(name))
+            .chain(chars.map(Err))
+            .enumerate()
+        {
+            if i > 0 {
+                f.write_str(if i == len - 1 { " or " } else { ", " })?;
+            }
+            match item {
+                Ok(name) => f.write_str(name)?,
+                Err(ch) => Debug::fmt(&ch, f)?,
+            }
+        }
+        Ok(())
+    }
+}
+
 impl Display for BuildError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         let msg = match self.0 {
@@ -65,6 +178,16 @@ impl Display for BuildError {
             BuildErrorKind::ColonInFirstPathSegment => {
                 "first path segment cannot contain ':' in relative-path reference"
             }
+            BuildErrorKind::MissingAuthority(comp) => {
+                return write!(f, "{comp} cannot be set when authority is absent");
+            }
+            BuildErrorKind::UnwritableHost => {
+                "host cannot be written without its text, e.g., IPvFuture address"
+            }
+            #[cfg(feature = "idna")]
+            BuildErrorKind::InvalidDomainName(e) => {
+                return write!(f, "invalid domain name in host: {e}");
+            }
         };
         f.write_str(msg)
     }
@@ -77,6 +200,122 @@ impl Display for ResolveError {
             ResolveErrorKind::NonHierarchicalBase => {
                 "resolving non-same-document relative reference against non-hierarchical base URI"
             }
+            ResolveErrorKind::PathUnderflow => "double-dot segment climbing above root of path",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl Debug for DnsName<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for DnsName<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for DataUriError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let msg = match self.0 {
+            DataUriErrorKind::NotDataUri => "not a data URI",
+            DataUriErrorKind::MissingComma => "missing comma before data in data URI",
+            DataUriErrorKind::InvalidMediaType => "invalid media type in data URI",
+            DataUriErrorKind::InvalidBase64 => "invalid base64 data in data URI",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl Display for MailtoUriError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let msg = match self.0 {
+            MailtoUriErrorKind::NotMailtoUri => "not a mailto URI",
+            MailtoUriErrorKind::InvalidUtf8 => "invalid UTF-8 after percent-decoding in mailto URI",
+            MailtoUriErrorKind::EmptyAddress => "empty address in mailto URI",
+            MailtoUriErrorKind::InvalidHeaderField => "invalid header field in mailto URI",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl Display for DnsNameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let msg = match self.0 {
+            DnsNameErrorKind::NotRegName => "host is not a registered name",
+            DnsNameErrorKind::Empty => "empty DNS name",
+            DnsNameErrorKind::EmptyLabel => "empty label in DNS name",
+            DnsNameErrorKind::LabelTooLong => "label longer than 63 octets in DNS name",
+            DnsNameErrorKind::NameTooLong => "DNS name longer than 253 octets",
+            DnsNameErrorKind::InvalidChar => {
+                "character other than letter, digit or hyphen in DNS name label"
+            }
+            DnsNameErrorKind::HyphenAtLabelEdge => "DNS name label starting or ending with hyphen",
+            DnsNameErrorKind::NumericTopLevelLabel => "all-numeric top-level label in DNS name",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl Debug for UriTemplate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for UriTemplate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let msg = match self.kind {
+            TemplateErrorKind::InvalidOctet => "invalid percent-encoded octet at index ",
+            TemplateErrorKind::UnexpectedChar => "unexpected character at index ",
+            TemplateErrorKind::UnclosedExpression => "unclosed expression at index ",
+        };
+        write!(f, "{}{}", msg, self.index)
+    }
+}
+
+impl Display for ExpandError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            ExpandErrorKind::PrefixOnComposite => {
+                f.write_str("prefix modifier applied to list or associative array")
+            }
+            ExpandErrorKind::InvalidUri(e) => {
+                write!(f, "expansion is not a valid URI reference: {e}")
+            }
+        }
+    }
+}
+
+impl Display for MatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let msg = match self.0 {
+            MatchErrorKind::NoMatch => "URI reference does not match template",
+            MatchErrorKind::AmbiguousTemplate => {
+                "template has adjacent expressions that cannot be told apart"
+            }
+        };
+        f.write_str(msg)
+    }
+}
+
+#[cfg(feature = "idna")]
+impl Display for IdnaError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let msg = match self.0 {
+            IdnaErrorKind::InvalidUtf8 => "percent-decoded host is not valid UTF-8",
+            IdnaErrorKind::DisallowedChar => "disallowed character in domain name",
+            IdnaErrorKind::InvalidPunycode => "invalid A-label in domain name",
+            IdnaErrorKind::InvalidLabel => "invalid label in domain name",
         };
         f.write_str(msg)
     }
@@ -100,6 +339,27 @@ impl<T: Bos<str>> Display for Uri<T> {
     }
 }
 
+impl Display for Origin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Origin::Tuple(tuple) => {
+                write!(f, "{}://{}", tuple.scheme(), tuple.host())?;
+                if !tuple.is_default_port() {
+                    write!(f, ":{}", tuple.port())?;
+                }
+                Ok(())
+            }
+            Origin::Opaque(_) => f.write_str("null"),
+        }
+    }
+}
+
+impl<T: Bos<str>> Debug for NormalizedHash<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_tuple("NormalizedHash").field(&self.0).finish()
+    }
+}
+
 impl Debug for Scheme {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
@@ -114,7 +374,30 @@ impl Display for Scheme {
     }
 }
 
-impl<T: Bos<str>> Debug for Authority<T> {
+impl<T: Bos<str>> Debug for Iri<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("Iri")
+            .field("scheme", &self.scheme())
+            .field("authority", &self.authority())
+            .field("path", &self.path())
+            .field("query", &self.query())
+            .field("fragment", &self.fragment())
+            .finish()
+    }
+}
+
+impl<T: Bos<str>> Display for Iri<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<T, UserinfoE, RegNameE> Debug for Authority<T, UserinfoE, RegNameE>
+where
+    T: Bos<str>,
+    UserinfoE: Encoder,
+    RegNameE: Encoder,
+{
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         f.debug_struct("Authority")
             .field("userinfo", &self.userinfo())
@@ -125,8 +408,36 @@ impl<T: Bos<str>> Debug for Authority<T> {
     }
 }
 
-impl<T: Bos<str>> Display for Authority<T> {
+impl<T, UserinfoE, RegNameE> Display for Authority<T, UserinfoE, RegNameE>
+where
+    T: Bos<str>,
+    UserinfoE: Encoder,
+    RegNameE: Encoder,
+{
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         Display::fmt(self.as_str(), f)
     }
 }
+
+impl<RegNameE: Encoder> Debug for Host<'_, RegNameE> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            #[cfg(feature = "net")]
+            Host::Ipv4(addr) => f.debug_tuple("Ipv4").field(addr).finish(),
+            #[cfg(feature = "net")]
+            Host::Ipv6(addr) => f.debug_tuple("Ipv6").field(addr).finish(),
+            #[cfg(not(feature = "net"))]
+            Host::Ipv4() => f.write_str("Ipv4"),
+            #[cfg(not(feature = "net"))]
+            Host::Ipv6() => f.write_str("Ipv6"),
+            Host::IpvFuture => f.write_str("IpvFuture"),
+            Host::RegName(name) => f.debug_tuple("RegName").field(name).finish(),
+        }
+    }
+}
+
+impl Debug for Fixups {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
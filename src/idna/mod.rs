@@ -0,0 +1,338 @@
+//! Internationalized domain names in applications (IDNA).
+//!
+//! This module implements the [processing] of domain names defined in
+//! [Unicode Technical Standard #46][uts46] with the parameters used by the
+//! [WHATWG URL Standard][whatwg]:
+//!
+//! - Nontransitional processing, so that deviation characters such as
+//!   `'ß'` are kept as is.
+//! - `CheckBidi` and `CheckJoiners` enabled, so that labels are checked
+//!   against the Bidi Rule of [RFC 5893] and the ContextJ rules of [RFC 5892].
+//! - `CheckHyphens`, `UseSTD3ASCIIRules` and `VerifyDnsLength` disabled.
+//!   Instead, [forbidden domain code points] are disallowed after mapping.
+//!
+//! Domain names are mapped with the IDNA Mapping Table and normalized to
+//! Normalization Form C, both for the Unicode version [`UNICODE_VERSION`].
+//! Labels are converted to and from A-labels with [Punycode](punycode).
+//!
+//! [processing]: https://www.unicode.org/reports/tr46/#Processing
+//! [uts46]: https://www.unicode.org/reports/tr46/
+//! [whatwg]: https://url.spec.whatwg.org/#idna
+//! [RFC 5893]: https://datatracker.ietf.org/doc/html/rfc5893/#section-2
+//! [RFC 5892]: https://datatracker.ietf.org/doc/html/rfc5892/#appendix-A
+//! [forbidden domain code points]: https://url.spec.whatwg.org/#forbidden-domain-code-point
+//!
+//! # Examples
+//!
+//! ```
+//! use fluent_uri::idna;
+//!
+//! assert_eq!(idna::to_ascii("Bücher.EXAMPLE")?, "xn--bcher-kva.example");
+//! assert_eq!(idna::to_ascii("bu\u{308}cher.example")?, "xn--bcher-kva.example");
+//! assert_eq!(idna::to_ascii("ℌ.Straße")?, "h.xn--strae-oqa");
+//! assert_eq!(idna::to_unicode("xn--bcher-kva.example")?, "bücher.example");
+//! assert!(idna::to_ascii("xn--a.example").is_err());
+//! # Ok::<_, fluent_uri::error::IdnaError>(())
+//! ```
+
+mod nfc;
+pub mod punycode;
+mod tables;
+
+use crate::error::{IdnaError, IdnaErrorKind};
+use alloc::string::String;
+use core::cmp::Ordering;
+
+/// The version of Unicode that the IDNA processing is based on,
+/// in the same form as [`char::UNICODE_VERSION`].
+pub const UNICODE_VERSION: (u8, u8, u8) = tables::UNICODE_VERSION;
+
+const ACE_PREFIX: &str = "xn--";
+
+/// The IDNA status of a character with `UseSTD3ASCIIRules=false`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Valid,
+    Ignored,
+    Mapped,
+    Deviation,
+    Disallowed,
+}
+
+/// A bidirectional character type other than `L` used in the Bidi Rule.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+enum BidiClass {
+    R,
+    AL,
+    AN,
+    EN,
+    ES,
+    CS,
+    ET,
+    ON,
+    BN,
+    NSM,
+}
+
+/// A joining type used in the ContextJ rules.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum JoiningType {
+    D,
+    L,
+    R,
+    T,
+}
+
+/// Converts a domain name to its ASCII form, with non-ASCII labels
+/// converted to A-labels.
+///
+/// # Errors
+///
+/// Returns `Err` if the domain name contains a disallowed character,
+/// if a label starting with `"xn--"` is not a valid A-label,
+/// or if a label fails the validity criteria.
+pub fn to_ascii(domain: &str) -> Result<String, IdnaError> {
+    let processed = process(domain)?;
+    let mut out = String::with_capacity(processed.len());
+    for (i, label) in processed.split('.').enumerate() {
+        if i > 0 {
+            out.push('.');
+        }
+        if label.is_ascii() {
+            out.push_str(label);
+        } else {
+            let encoded =
+                punycode::encode(label).ok_or(IdnaError(IdnaErrorKind::InvalidPunycode))?;
+            out.push_str(ACE_PREFIX);
+            out.push_str(&encoded);
+        }
+    }
+    Ok(out)
+}
+
+/// Converts a domain name to its Unicode form, with A-labels
+/// converted to U-labels.
+///
+/// # Errors
+///
+/// Returns `Err` if the domain name contains a disallowed character,
+/// if a label starting with `"xn--"` is not a valid A-label,
+/// or if a label fails the validity criteria.
+pub fn to_unicode(domain: &str) -> Result<String, IdnaError> {
+    process(domain)
+}
+
+/// Processes a domain name as per Section 4 of UTS #46,
+/// with A-labels converted to U-labels.
+fn process(domain: &str) -> Result<String, IdnaError> {
+    let mapped = map(domain)?;
+    let mut out = String::with_capacity(mapped.len());
+    for (i, label) in mapped.split('.').enumerate() {
+        if i > 0 {
+            out.push('.');
+        }
+        if let Some(encoded) = label.strip_prefix(ACE_PREFIX) {
+            let decoded = decode_label(encoded).ok_or(IdnaError(IdnaErrorKind::InvalidPunycode))?;
+            out.push_str(&decoded);
+        } else if is_valid_label(label) {
+            out.push_str(label);
+        } else {
+            return Err(IdnaError(IdnaErrorKind::InvalidLabel));
+        }
+    }
+
+    let is_bidi_domain = out.chars().any(|c| {
+        matches!(
+            bidi_class(c),
+            Some(BidiClass::R | BidiClass::AL | BidiClass::AN)
+        )
+    });
+    if is_bidi_domain && !out.split('.').all(satisfies_bidi_rule) {
+        return Err(IdnaError(IdnaErrorKind::InvalidLabel));
+    }
+    Ok(out)
+}
+
+/// Maps a domain name and normalizes it to NFC.
+fn map(domain: &str) -> Result<String, IdnaError> {
+    let mut out = String::with_capacity(domain.len());
+    for c in domain.chars() {
+        // ASCII characters are either valid or mapped to lowercase.
+        if c.is_ascii() {
+            out.push(c.to_ascii_lowercase());
+            continue;
+        }
+        let (status, mapped) = status(c);
+        match status {
+            Status::Valid | Status::Deviation => out.push(c),
+            Status::Mapped => out.push_str(mapped),
+            Status::Ignored => {}
+            Status::Disallowed => return Err(IdnaError(IdnaErrorKind::DisallowedChar)),
+        }
+    }
+
+    let out = if out.is_ascii() {
+        out
+    } else {
+        nfc::normalize(&out)
+    };
+    if out.chars().any(is_forbidden) {
+        return Err(IdnaError(IdnaErrorKind::DisallowedChar));
+    }
+    Ok(out)
+}
+
+/// Decodes an A-label without the prefix, returning the U-label if valid.
+fn decode_label(encoded: &str) -> Option<String> {
+    if !encoded.is_ascii() {
+        return None;
+    }
+    let decoded = punycode::decode(encoded)?;
+    if decoded.is_ascii() || !nfc::is_normalized(&decoded) || !is_valid_label(&decoded) {
+        return None;
+    }
+    Some(decoded)
+}
+
+/// Checks a mapped label in NFC against the validity criteria
+/// other than the Bidi Rule.
+fn is_valid_label(label: &str) -> bool {
+    // Mapped ASCII labels are always valid.
+    if label.is_ascii() {
+        return true;
+    }
+    if label.starts_with(ACE_PREFIX) || label.chars().next().map_or(false, is_mark) {
+        return false;
+    }
+
+    let mut prev = None;
+    for (i, c) in label.char_indices() {
+        if c == '.' || !matches!(status(c).0, Status::Valid | Status::Deviation) {
+            return false;
+        }
+        if matches!(c, '\u{200c}' | '\u{200d}') && !is_valid_joiner(label, i, prev) {
+            return false;
+        }
+        prev = Some(c);
+    }
+    true
+}
+
+/// Checks the ContextJ rule for a zero width (non-)joiner at a given index.
+fn is_valid_joiner(label: &str, i: usize, prev: Option<char>) -> bool {
+    const VIRAMA: u8 = 9;
+
+    if prev.map_or(false, |c| nfc::combining_class(c) == VIRAMA) {
+        return true;
+    }
+    if label[i..].starts_with('\u{200d}') {
+        return false;
+    }
+
+    let skip_transparent = |c: &char| joining_type(*c) == Some(JoiningType::T);
+    let before = label[..i].chars().rev().find(|c| !skip_transparent(c));
+    let after = label[i + 3..].chars().find(|c| !skip_transparent(c));
+    matches!(
+        before.and_then(joining_type),
+        Some(JoiningType::L | JoiningType::D)
+    ) && matches!(
+        after.and_then(joining_type),
+        Some(JoiningType::R | JoiningType::D)
+    )
+}
+
+/// Checks a label against the Bidi Rule.
+fn satisfies_bidi_rule(label: &str) -> bool {
+    use BidiClass::*;
+
+    let Some(first) = label.chars().next() else {
+        return true;
+    };
+    let mut classes = label.chars().map(bidi_class);
+    // The last class other than NSM.
+    let last = label
+        .chars()
+        .rev()
+        .map(bidi_class)
+        .find(|&b| b != Some(NSM));
+
+    match bidi_class(first) {
+        // LTR label.
+        None => {
+            classes.all(|b| matches!(b, None | Some(EN | ES | CS | ET | ON | BN | NSM)))
+                && matches!(last, Some(None | Some(EN)))
+        }
+        // RTL label.
+        Some(R | AL) => {
+            let (mut has_en, mut has_an) = (false, false);
+            classes.all(|b| {
+                has_en |= b == Some(EN);
+                has_an |= b == Some(AN);
+                b.is_some()
+            }) && !(has_en && has_an)
+                && matches!(last, Some(Some(R | AL | EN | AN)))
+        }
+        _ => false,
+    }
+}
+
+/// Checks whether an ASCII character is a forbidden domain code point.
+fn is_forbidden(c: char) -> bool {
+    matches!(
+        c,
+        '\0'..=' '
+            | '#'
+            | '%'
+            | '/'
+            | ':'
+            | '<'
+            | '>'
+            | '?'
+            | '@'
+            | '['
+            | '\\'
+            | ']'
+            | '^'
+            | '|'
+            | '\x7f'
+    )
+}
+
+/// Returns the IDNA status of a character and its mapping.
+fn status(c: char) -> (Status, &'static str) {
+    let i = tables::MAPPING.partition_point(|x| x.0 <= c) - 1;
+    let (_, status, start, end) = tables::MAPPING[i];
+    (
+        status,
+        &tables::MAPPED[usize::from(start)..usize::from(end)],
+    )
+}
+
+fn is_mark(c: char) -> bool {
+    lookup(tables::MARK, c).is_some()
+}
+
+fn bidi_class(c: char) -> Option<BidiClass> {
+    lookup(tables::BIDI_CLASS, c)
+}
+
+fn joining_type(c: char) -> Option<JoiningType> {
+    lookup(tables::JOINING_TYPE, c)
+}
+
+/// Looks up a character in a sorted table of inclusive ranges.
+fn lookup<T: Copy>(table: &[(char, char, T)], c: char) -> Option<T> {
+    table
+        .binary_search_by(|&(start, end, _)| {
+            if end < c {
+                Ordering::Less
+            } else if start > c {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|i| table[i].2)
+}
@@ -0,0 +1,115 @@
+//! Unicode Normalization Form C.
+
+use super::{lookup, tables};
+use alloc::{string::String, vec::Vec};
+
+const S_BASE: u32 = 0xac00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11a7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+/// Returns the canonical combining class of a character.
+pub(super) fn combining_class(c: char) -> u8 {
+    lookup(tables::COMBINING_CLASS, c).unwrap_or(0)
+}
+
+/// Normalizes a string to NFC.
+pub(super) fn normalize(s: &str) -> String {
+    let mut buf = Vec::with_capacity(s.len());
+    for c in s.chars() {
+        decompose(c, &mut buf);
+    }
+    reorder(&mut buf);
+    compose(&mut buf);
+    buf.into_iter().map(|(c, _)| c).collect()
+}
+
+/// Checks whether a string is in NFC.
+pub(super) fn is_normalized(s: &str) -> bool {
+    s.is_ascii() || normalize(s) == s
+}
+
+/// Appends the full canonical decomposition of a character
+/// along with the combining classes.
+fn decompose(c: char, buf: &mut Vec<(char, u8)>) {
+    let s_index = u32::from(c).wrapping_sub(S_BASE);
+    if s_index < S_COUNT {
+        let jamo = |x| char::from_u32(x).unwrap();
+        buf.push((jamo(L_BASE + s_index / N_COUNT), 0));
+        buf.push((jamo(V_BASE + s_index % N_COUNT / T_COUNT), 0));
+        if s_index % T_COUNT != 0 {
+            buf.push((jamo(T_BASE + s_index % T_COUNT), 0));
+        }
+    } else if let Ok(i) = tables::DECOMPOSITION.binary_search_by_key(&c, |x| x.0) {
+        let (_, start, end) = tables::DECOMPOSITION[i];
+        let decomposed = &tables::DECOMPOSED[usize::from(start)..usize::from(end)];
+        buf.extend(decomposed.chars().map(|c| (c, combining_class(c))));
+    } else {
+        buf.push((c, combining_class(c)));
+    }
+}
+
+/// Puts each run of non-starters in canonical order.
+fn reorder(buf: &mut [(char, u8)]) {
+    for i in 1..buf.len() {
+        let mut j = i;
+        while j > 0 && buf[j].1 != 0 && buf[j - 1].1 > buf[j].1 {
+            buf.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+/// Applies the canonical composition algorithm in place.
+fn compose(buf: &mut Vec<(char, u8)>) {
+    let mut starter = None;
+    let mut len = 0;
+    for i in 0..buf.len() {
+        let (c, ccc) = buf[i];
+        if let Some(s) = starter {
+            // A character is blocked from the last starter if there is a
+            // character in between with a combining class of zero or
+            // no less than its own.
+            let blocked = len - 1 > s && (buf[len - 1].1 == 0 || buf[len - 1].1 >= ccc);
+            if !blocked {
+                if let Some(composite) = compose_pair(buf[s].0, c) {
+                    buf[s] = (composite, combining_class(composite));
+                    continue;
+                }
+            }
+        }
+        if ccc == 0 {
+            starter = Some(len);
+        }
+        buf[len] = (c, ccc);
+        len += 1;
+    }
+    buf.truncate(len);
+}
+
+/// Returns the primary composite of two characters, if any.
+fn compose_pair(a: char, b: char) -> Option<char> {
+    let (a_u32, b_u32) = (u32::from(a), u32::from(b));
+
+    let l_index = a_u32.wrapping_sub(L_BASE);
+    let v_index = b_u32.wrapping_sub(V_BASE);
+    if l_index < L_COUNT && v_index < V_COUNT {
+        return char::from_u32(S_BASE + (l_index * V_COUNT + v_index) * T_COUNT);
+    }
+
+    let s_index = a_u32.wrapping_sub(S_BASE);
+    let t_index = b_u32.wrapping_sub(T_BASE);
+    if s_index < S_COUNT && s_index % T_COUNT == 0 && t_index > 0 && t_index < T_COUNT {
+        return char::from_u32(a_u32 + t_index);
+    }
+
+    tables::COMPOSITION
+        .binary_search_by_key(&(a, b), |x| (x.0, x.1))
+        .ok()
+        .map(|i| tables::COMPOSITION[i].2)
+}
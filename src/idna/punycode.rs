@@ -0,0 +1,153 @@
+//! Punycode encoding and decoding as defined in [RFC 3492].
+//!
+//! [RFC 3492]: https://datatracker.ietf.org/doc/html/rfc3492/
+//!
+//! # Examples
+//!
+//! ```
+//! use fluent_uri::idna::punycode;
+//!
+//! assert_eq!(punycode::encode("bücher").unwrap(), "bcher-kva");
+//! assert_eq!(punycode::decode("bcher-kva").unwrap(), "bücher");
+//! ```
+
+use alloc::{string::String, vec::Vec};
+
+const BASE: u32 = 36;
+const T_MIN: u32 = 1;
+const T_MAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 0x80;
+const DELIMITER: char = '-';
+
+fn adapt(mut delta: u32, num_points: u32, first_time: bool) -> u32 {
+    delta /= if first_time { DAMP } else { 2 };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+        delta /= BASE - T_MIN;
+        k += BASE;
+    }
+    k + (BASE - T_MIN + 1) * delta / (delta + SKEW)
+}
+
+fn threshold(k: u32, bias: u32) -> u32 {
+    if k <= bias {
+        T_MIN
+    } else if k >= bias + T_MAX {
+        T_MAX
+    } else {
+        k - bias
+    }
+}
+
+fn encode_digit(d: u32) -> char {
+    let d = d as u8;
+    char::from(if d < 26 { b'a' + d } else { b'0' + d - 26 })
+}
+
+fn decode_digit(x: u8) -> Option<u32> {
+    match x {
+        b'0'..=b'9' => Some(u32::from(x - b'0') + 26),
+        b'a'..=b'z' => Some(u32::from(x - b'a')),
+        b'A'..=b'Z' => Some(u32::from(x - b'A')),
+        _ => None,
+    }
+}
+
+/// Encodes a string with Punycode, without the `"xn--"` prefix.
+///
+/// Returns `None` on overflow, which only occurs with unreasonably long input.
+#[must_use]
+pub fn encode(input: &str) -> Option<String> {
+    let mut out = String::with_capacity(input.len());
+    out.extend(input.chars().filter(char::is_ascii));
+
+    let basic_len = out.len() as u32;
+    let input_len = input.chars().count() as u32;
+    if basic_len > 0 {
+        out.push(DELIMITER);
+    }
+
+    let (mut n, mut delta, mut bias) = (INITIAL_N, 0u32, INITIAL_BIAS);
+    let mut handled = basic_len;
+
+    while handled < input_len {
+        let m = input.chars().map(u32::from).filter(|&c| c >= n).min()?;
+        delta = delta.checked_add((m - n).checked_mul(handled + 1)?)?;
+        n = m;
+
+        for c in input.chars().map(u32::from) {
+            if c < n {
+                delta = delta.checked_add(1)?;
+            } else if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = threshold(k, bias);
+                    if q < t {
+                        break;
+                    }
+                    out.push(encode_digit(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                out.push(encode_digit(q));
+                bias = adapt(delta, handled + 1, handled == basic_len);
+                delta = 0;
+                handled += 1;
+            }
+        }
+
+        delta = delta.checked_add(1)?;
+        n += 1;
+    }
+    Some(out)
+}
+
+/// Decodes a Punycode string, without the `"xn--"` prefix.
+///
+/// Returns `None` if the input is not valid Punycode.
+#[must_use]
+pub fn decode(input: &str) -> Option<String> {
+    if !input.is_ascii() {
+        return None;
+    }
+
+    let (basic, rest) = match input.rfind(DELIMITER) {
+        Some(i) => (&input[..i], &input[i + 1..]),
+        None => ("", input),
+    };
+    let mut out: Vec<char> = basic.chars().collect();
+
+    let (mut n, mut i, mut bias) = (INITIAL_N, 0u32, INITIAL_BIAS);
+    let mut bytes = rest.bytes();
+
+    while bytes.len() > 0 {
+        let old_i = i;
+        let mut w = 1u32;
+        let mut k = BASE;
+        loop {
+            let digit = decode_digit(bytes.next()?)?;
+            i = i.checked_add(digit.checked_mul(w)?)?;
+            let t = threshold(k, bias);
+            if digit < t {
+                break;
+            }
+            w = w.checked_mul(BASE - t)?;
+            k += BASE;
+        }
+
+        let len = out.len() as u32 + 1;
+        bias = adapt(i - old_i, len, old_i == 0);
+        n = n.checked_add(i / len)?;
+        i %= len;
+
+        let c = char::from_u32(n).filter(|c| !c.is_ascii())?;
+        out.insert(i as usize, c);
+        i += 1;
+    }
+    Some(out.into_iter().collect())
+}
@@ -0,0 +1,208 @@
+#!/usr/bin/env python3
+"""Generates `tables.rs` from the Unicode data shipped with Python.
+
+The UTS #46 mapping table and the joining types are taken from the `idna`
+package, and the remaining character properties from `unicodedata`.
+Both must be for the same Unicode version:
+
+    python3 tables.py > tables.rs
+"""
+
+import unicodedata
+
+from idna import idnadata, uts46data
+
+VERSION = "15.1.0"
+
+assert unicodedata.unidata_version == VERSION, unicodedata.unidata_version
+assert uts46data.__version__ == VERSION, uts46data.__version__
+assert idnadata.__version__ == VERSION, idnadata.__version__
+
+MAX = 0x110000
+HANGUL = range(0xAC00, 0xD7A4)
+
+
+def ranges(prop):
+    """Yields `(start, end, value)` for maximal runs of non-`None` values."""
+    start, prev = 0, prop(0)
+    for cp in range(1, MAX + 1):
+        value = prop(cp) if cp < MAX else None
+        if value != prev:
+            if prev is not None:
+                yield start, cp - 1, prev
+            start, prev = cp, value
+
+
+def mapping():
+    """Yields `(start, status, mapping)` with `UseSTD3ASCIIRules=false`."""
+    for entry in uts46data.uts46data:
+        cp, status = entry[0], entry[1]
+        mapped = entry[2] if len(entry) > 2 else None
+        if status == "3":
+            status = "V" if mapped is None else "M"
+        if status != "M":
+            mapped = None
+        yield cp, status, mapped
+
+
+def decomposition(cp):
+    if cp in HANGUL:
+        return None
+    d = unicodedata.decomposition(chr(cp))
+    if not d or d.startswith("<"):
+        return None
+    full = unicodedata.normalize("NFD", chr(cp))
+    return full if full != chr(cp) else None
+
+
+def compositions():
+    for cp in range(MAX):
+        if cp in HANGUL:
+            continue
+        d = unicodedata.decomposition(chr(cp))
+        if not d or d.startswith("<"):
+            continue
+        parts = [int(x, 16) for x in d.split()]
+        if len(parts) == 2 and unicodedata.normalize("NFC", "".join(map(chr, parts))) == chr(cp):
+            yield parts[0], parts[1], cp
+
+
+def combining_class(cp):
+    return unicodedata.combining(chr(cp)) or None
+
+
+def is_mark(cp):
+    return True if unicodedata.category(chr(cp)).startswith("M") else None
+
+
+BIDI_CLASSES = {"R", "AL", "AN", "EN", "ES", "CS", "ET", "ON", "BN", "NSM"}
+
+
+def bidi_class(cp):
+    bidi = unicodedata.bidirectional(chr(cp))
+    return bidi if bidi in BIDI_CLASSES else None
+
+
+JOINING_TYPES = {"D", "L", "R", "T"}
+
+
+def joining_type(cp):
+    jt = idnadata.joining_types.get(cp)
+    jt = chr(jt) if isinstance(jt, int) else jt
+    return jt if jt in JOINING_TYPES else None
+
+
+def char(cp):
+    return f"'\\u{{{cp:X}}}'"
+
+
+def escape(s):
+    return "".join(c if 0x20 <= ord(c) < 0x7F and c not in '"\\' else f"\\u{{{ord(c):x}}}" for c in s)
+
+
+def strings(values):
+    """Concatenates strings, returning the whole and the bounds of each."""
+    whole, bounds, seen = [], {}, {}
+    length = 0
+    for s in values:
+        if s in seen:
+            continue
+        b = len(s.encode())
+        seen[s] = (length, length + b)
+        whole.append(s)
+        length += b
+    assert length < 1 << 16
+    return "".join(whole), seen
+
+
+STATUS = {"V": "Valid", "I": "Ignored", "M": "Mapped", "D": "Deviation", "X": "Disallowed"}
+
+
+def main():
+    out = []
+    w = out.append
+    w("// This file is generated by `tables.py`. Do not edit.")
+    w("")
+    w("use super::{BidiClass, JoiningType, Status};")
+    w("")
+    w("/// The version of Unicode that the tables are generated from.")
+    w(f'pub(super) const UNICODE_VERSION: (u8, u8, u8) = ({VERSION.replace(".", ", ")});')
+
+    entries = []
+    for cp, status, mapped in mapping():
+        # Surrogates are not chars.
+        if 0xD800 <= cp < 0xE000:
+            cp = 0xE000
+        if entries and entries[-1][0] == cp:
+            entries.pop()
+        if entries and entries[-1][1:] == (status, mapped):
+            continue
+        entries.append((cp, status, mapped))
+    text, bounds = strings(m for _, _, m in entries if m is not None)
+    w("")
+    w("/// Concatenated mappings of code points with the `mapped` status.")
+    w(f'pub(super) static MAPPED: &str = "{escape(text)}";')
+    w("")
+    w("/// The first code point of each run with the same status and mapping,")
+    w("/// and the bounds of the mapping in [`MAPPED`].")
+    w("pub(super) static MAPPING: &[(char, Status, u16, u16)] = &[")
+    for cp, status, mapped in entries:
+        start, end = bounds[mapped] if mapped is not None else (0, 0)
+        w(f"    ({char(cp)}, Status::{STATUS[status]}, {start}, {end}),")
+    w("];")
+
+    decomps = [(cp, d) for cp in range(MAX) if (d := decomposition(cp)) is not None]
+    text, bounds = strings(d for _, d in decomps)
+    w("")
+    w("/// Concatenated full canonical decompositions.")
+    w(f'pub(super) static DECOMPOSED: &str = "{escape(text)}";')
+    w("")
+    w("/// Code points with a canonical decomposition other than Hangul syllables,")
+    w("/// and the bounds of their full decomposition in [`DECOMPOSED`].")
+    w("pub(super) static DECOMPOSITION: &[(char, u16, u16)] = &[")
+    for cp, d in decomps:
+        w(f"    ({char(cp)}, {bounds[d][0]}, {bounds[d][1]}),")
+    w("];")
+
+    w("")
+    w("/// Pairs of code points that compose into a primary composite")
+    w("/// other than Hangul syllables, sorted.")
+    w("pub(super) static COMPOSITION: &[(char, char, char)] = &[")
+    for a, b, c in sorted(compositions()):
+        w(f"    ({char(a)}, {char(b)}, {char(c)}),")
+    w("];")
+
+    w("")
+    w("/// Ranges of code points with a non-zero canonical combining class.")
+    w("pub(super) static COMBINING_CLASS: &[(char, char, u8)] = &[")
+    for start, end, ccc in ranges(combining_class):
+        w(f"    ({char(start)}, {char(end)}, {ccc}),")
+    w("];")
+
+    w("")
+    w("/// Ranges of code points with the general category `Mark`.")
+    w("pub(super) static MARK: &[(char, char, ())] = &[")
+    for start, end, _ in ranges(is_mark):
+        w(f"    ({char(start)}, {char(end)}, ()),")
+    w("];")
+
+    w("")
+    w("/// Ranges of code points with a bidirectional class other than `L`")
+    w("/// that is relevant to the Bidi Rule.")
+    w("pub(super) static BIDI_CLASS: &[(char, char, BidiClass)] = &[")
+    for start, end, bidi in ranges(bidi_class):
+        w(f"    ({char(start)}, {char(end)}, BidiClass::{bidi}),")
+    w("];")
+
+    w("")
+    w("/// Ranges of code points with a joining type relevant to the ContextJ rules.")
+    w("pub(super) static JOINING_TYPE: &[(char, char, JoiningType)] = &[")
+    for start, end, jt in ranges(joining_type):
+        w(f"    ({char(start)}, {char(end)}, JoiningType::{jt}),")
+    w("];")
+
+    print("\n".join(out))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,12996 @@
+// This file is generated by `tables.py`. Do not edit.
+
+use super::{BidiClass, JoiningType, Status};
+
+/// The version of Unicode that the tables are generated from.
+pub(super) const UNICODE_VERSION: (u8, u8, u8) = (15, 1, 0);
+
+/// Concatenated mappings of code points with the `mapped` status.
+pub(super) static MAPPED: &str = "abcdefghijklmnopqrstuvwxyz  \u{308} \u{304}23 \u{301}\u{3bc} \u{327}11\u{2044}41\u{2044}23\u{2044}4\u{e0}\u{e1}\u{e2}\u{e3}\u{e4}\u{e5}\u{e6}\u{e7}\u{e8}\u{e9}\u{ea}\u{eb}\u{ec}\u{ed}\u{ee}\u{ef}\u{f0}\u{f1}\u{f2}\u{f3}\u{f4}\u{f5}\u{f6}\u{f8}\u{f9}\u{fa}\u{fb}\u{fc}\u{fd}\u{fe}\u{101}\u{103}\u{105}\u{107}\u{109}\u{10b}\u{10d}\u{10f}\u{111}\u{113}\u{115}\u{117}\u{119}\u{11b}\u{11d}\u{11f}\u{121}\u{123}\u{125}\u{127}\u{129}\u{12b}\u{12d}\u{12f}i\u{307}ij\u{135}\u{137}\u{13a}\u{13c}\u{13e}l\u{b7}\u{142}\u{144}\u{146}\u{148}\u{2bc}n\u{14b}\u{14d}\u{14f}\u{151}\u{153}\u{155}\u{157}\u{159}\u{15b}\u{15d}\u{15f}\u{161}\u{163}\u{165}\u{167}\u{169}\u{16b}\u{16d}\u{16f}\u{171}\u{173}\u{175}\u{177}\u{ff}\u{17a}\u{17c}\u{17e}\u{253}\u{183}\u{185}\u{254}\u{188}\u{256}\u{257}\u{18c}\u{1dd}\u{259}\u{25b}\u{192}\u{260}\u{263}\u{269}\u{268}\u{199}\u{26f}\u{272}\u{275}\u{1a1}\u{1a3}\u{1a5}\u{280}\u{1a8}\u{283}\u{1ad}\u{288}\u{1b0}\u{28a}\u{28b}\u{1b4}\u{1b6}\u{292}\u{1b9}\u{1bd}d\u{17e}ljnj\u{1ce}\u{1d0}\u{1d2}\u{1d4}\u{1d6}\u{1d8}\u{1da}\u{1dc}\u{1df}\u{1e1}\u{1e3}\u{1e5}\u{1e7}\u{1e9}\u{1eb}\u{1ed}\u{1ef}dz\u{1f5}\u{195}\u{1bf}\u{1f9}\u{1fb}\u{1fd}\u{1ff}\u{201}\u{203}\u{205}\u{207}\u{209}\u{20b}\u{20d}\u{20f}\u{211}\u{213}\u{215}\u{217}\u{219}\u{21b}\u{21d}\u{21f}\u{19e}\u{223}\u{225}\u{227}\u{229}\u{22b}\u{22d}\u{22f}\u{231}\u{233}\u{2c65}\u{23c}\u{19a}\u{2c66}\u{242}\u{180}\u{289}\u{28c}\u{247}\u{249}\u{24b}\u{24d}\u{24f}\u{266}\u{279}\u{27b}\u{281} \u{306} \u{307} \u{30a} \u{328} \u{303} \u{30b}\u{295}\u{300}\u{301}\u{313}\u{308}\u{301}\u{3b9}\u{371}\u{373}\u{2b9}\u{377} \u{3b9};\u{3f3} \u{308}\u{301}\u{3ac}\u{b7}\u{3ad}\u{3ae}\u{3af}\u{3cc}\u{3cd}\u{3ce}\u{3b1}\u{3b2}\u{3b3}\u{3b4}\u{3b5}\u{3b6}\u{3b7}\u{3b8}\u{3ba}\u{3bb}\u{3bd}\u{3be}\u{3bf}\u{3c0}\u{3c1}\u{3c3}\u{3c4}\u{3c5}\u{3c6}\u{3c7}\u{3c8}\u{3c9}\u{3ca}\u{3cb}\u{3d7}\u{3d9}\u{3db}\u{3dd}\u{3df}\u{3e1}\u{3e3}\u{3e5}\u{3e7}\u{3e9}\u{3eb}\u{3ed}\u{3ef}\u{3f8}\u{3fb}\u{37b}\u{37c}\u{37d}\u{450}\u{451}\u{452}\u{453}\u{454}\u{455}\u{456}\u{457}\u{458}\u{459}\u{45a}\u{45b}\u{45c}\u{45d}\u{45e}\u{45f}\u{430}\u{431}\u{432}\u{433}\u{434}\u{435}\u{436}\u{437}\u{438}\u{439}\u{43a}\u{43b}\u{43c}\u{43d}\u{43e}\u{43f}\u{440}\u{441}\u{442}\u{443}\u{444}\u{445}\u{446}\u{447}\u{448}\u{449}\u{44a}\u{44b}\u{44c}\u{44d}\u{44e}\u{44f}\u{461}\u{463}\u{465}\u{467}\u{469}\u{46b}\u{46d}\u{46f}\u{471}\u{473}\u{475}\u{477}\u{479}\u{47b}\u{47d}\u{47f}\u{481}\u{48b}\u{48d}\u{48f}\u{491}\u{493}\u{495}\u{497}\u{499}\u{49b}\u{49d}\u{49f}\u{4a1}\u{4a3}\u{4a5}\u{4a7}\u{4a9}\u{4ab}\u{4ad}\u{4af}\u{4b1}\u{4b3}\u{4b5}\u{4b7}\u{4b9}\u{4bb}\u{4bd}\u{4bf}\u{4c2}\u{4c4}\u{4c6}\u{4c8}\u{4ca}\u{4cc}\u{4ce}\u{4d1}\u{4d3}\u{4d5}\u{4d7}\u{4d9}\u{4db}\u{4dd}\u{4df}\u{4e1}\u{4e3}\u{4e5}\u{4e7}\u{4e9}\u{4eb}\u{4ed}\u{4ef}\u{4f1}\u{4f3}\u{4f5}\u{4f7}\u{4f9}\u{4fb}\u{4fd}\u{4ff}\u{501}\u{503}\u{505}\u{507}\u{509}\u{50b}\u{50d}\u{50f}\u{511}\u{513}\u{515}\u{517}\u{519}\u{51b}\u{51d}\u{51f}\u{521}\u{523}\u{525}\u{527}\u{529}\u{52b}\u{52d}\u{52f}\u{561}\u{562}\u{563}\u{564}\u{565}\u{566}\u{567}\u{568}\u{569}\u{56a}\u{56b}\u{56c}\u{56d}\u{56e}\u{56f}\u{570}\u{571}\u{572}\u{573}\u{574}\u{575}\u{576}\u{577}\u{578}\u{579}\u{57a}\u{57b}\u{57c}\u{57d}\u{57e}\u{57f}\u{580}\u{581}\u{582}\u{583}\u{584}\u{585}\u{586}\u{565}\u{582}\u{627}\u{674}\u{648}\u{674}\u{6c7}\u{674}\u{64a}\u{674}\u{915}\u{93c}\u{916}\u{93c}\u{917}\u{93c}\u{91c}\u{93c}\u{921}\u{93c}\u{922}\u{93c}\u{92b}\u{93c}\u{92f}\u{93c}\u{9a1}\u{9bc}\u{9a2}\u{9bc}\u{9af}\u{9bc}\u{a32}\u{a3c}\u{a38}\u{a3c}\u{a16}\u{a3c}\u{a17}\u{a3c}\u{a1c}\u{a3c}\u{a2b}\u{a3c}\u{b21}\u{b3c}\u{b22}\u{b3c}\u{e4d}\u{e32}\u{ecd}\u{eb2}\u{eab}\u{e99}\u{eab}\u{ea1}\u{f0b}\u{f42}\u{fb7}\u{f4c}\u{fb7}\u{f51}\u{fb7}\u{f56}\u{fb7}\u{f5b}\u{fb7}\u{f40}\u{fb5}\u{f71}\u{f72}\u{f71}\u{f74}\u{fb2}\u{f80}\u{fb2}\u{f71}\u{f80}\u{fb3}\u{f80}\u{fb3}\u{f71}\u{f80}\u{f71}\u{f80}\u{f92}\u{fb7}\u{f9c}\u{fb7}\u{fa1}\u{fb7}\u{fa6}\u{fb7}\u{fab}\u{fb7}\u{f90}\u{fb5}\u{2d27}\u{2d2d}\u{10dc}\u{13f0}\u{13f1}\u{13f2}\u{13f3}\u{13f4}\u{13f5}\u{a64b}\u{10d0}\u{10d1}\u{10d2}\u{10d3}\u{10d4}\u{10d5}\u{10d6}\u{10d7}\u{10d8}\u{10d9}\u{10da}\u{10db}\u{10dd}\u{10de}\u{10df}\u{10e0}\u{10e1}\u{10e2}\u{10e3}\u{10e4}\u{10e5}\u{10e6}\u{10e7}\u{10e8}\u{10e9}\u{10ea}\u{10eb}\u{10ec}\u{10ed}\u{10ee}\u{10ef}\u{10f0}\u{10f1}\u{10f2}\u{10f3}\u{10f4}\u{10f5}\u{10f6}\u{10f7}\u{10f8}\u{10f9}\u{10fa}\u{10fd}\u{10fe}\u{10ff}\u{250}\u{251}\u{1d02}\u{25c}\u{1d16}\u{1d17}\u{1d1d}\u{1d25}\u{252}\u{255}\u{25f}\u{261}\u{265}\u{26a}\u{1d7b}\u{29d}\u{26d}\u{1d85}\u{29f}\u{271}\u{270}\u{273}\u{274}\u{278}\u{282}\u{1ab}\u{1d1c}\u{290}\u{291}\u{1e01}\u{1e03}\u{1e05}\u{1e07}\u{1e09}\u{1e0b}\u{1e0d}\u{1e0f}\u{1e11}\u{1e13}\u{1e15}\u{1e17}\u{1e19}\u{1e1b}\u{1e1d}\u{1e1f}\u{1e21}\u{1e23}\u{1e25}\u{1e27}\u{1e29}\u{1e2b}\u{1e2d}\u{1e2f}\u{1e31}\u{1e33}\u{1e35}\u{1e37}\u{1e39}\u{1e3b}\u{1e3d}\u{1e3f}\u{1e41}\u{1e43}\u{1e45}\u{1e47}\u{1e49}\u{1e4b}\u{1e4d}\u{1e4f}\u{1e51}\u{1e53}\u{1e55}\u{1e57}\u{1e59}\u{1e5b}\u{1e5d}\u{1e5f}\u{1e61}\u{1e63}\u{1e65}\u{1e67}\u{1e69}\u{1e6b}\u{1e6d}\u{1e6f}\u{1e71}\u{1e73}\u{1e75}\u{1e77}\u{1e79}\u{1e7b}\u{1e7d}\u{1e7f}\u{1e81}\u{1e83}\u{1e85}\u{1e87}\u{1e89}\u{1e8b}\u{1e8d}\u{1e8f}\u{1e91}\u{1e93}\u{1e95}a\u{2be}\u{df}\u{1ea1}\u{1ea3}\u{1ea5}\u{1ea7}\u{1ea9}\u{1eab}\u{1ead}\u{1eaf}\u{1eb1}\u{1eb3}\u{1eb5}\u{1eb7}\u{1eb9}\u{1ebb}\u{1ebd}\u{1ebf}\u{1ec1}\u{1ec3}\u{1ec5}\u{1ec7}\u{1ec9}\u{1ecb}\u{1ecd}\u{1ecf}\u{1ed1}\u{1ed3}\u{1ed5}\u{1ed7}\u{1ed9}\u{1edb}\u{1edd}\u{1edf}\u{1ee1}\u{1ee3}\u{1ee5}\u{1ee7}\u{1ee9}\u{1eeb}\u{1eed}\u{1eef}\u{1ef1}\u{1ef3}\u{1ef5}\u{1ef7}\u{1ef9}\u{1efb}\u{1efd}\u{1eff}\u{1f00}\u{1f01}\u{1f02}\u{1f03}\u{1f04}\u{1f05}\u{1f06}\u{1f07}\u{1f10}\u{1f11}\u{1f12}\u{1f13}\u{1f14}\u{1f15}\u{1f20}\u{1f21}\u{1f22}\u{1f23}\u{1f24}\u{1f25}\u{1f26}\u{1f27}\u{1f30}\u{1f31}\u{1f32}\u{1f33}\u{1f34}\u{1f35}\u{1f36}\u{1f37}\u{1f40}\u{1f41}\u{1f42}\u{1f43}\u{1f44}\u{1f45}\u{1f51}\u{1f53}\u{1f55}\u{1f57}\u{1f60}\u{1f61}\u{1f62}\u{1f63}\u{1f64}\u{1f65}\u{1f66}\u{1f67}\u{1f00}\u{3b9}\u{1f01}\u{3b9}\u{1f02}\u{3b9}\u{1f03}\u{3b9}\u{1f04}\u{3b9}\u{1f05}\u{3b9}\u{1f06}\u{3b9}\u{1f07}\u{3b9}\u{1f20}\u{3b9}\u{1f21}\u{3b9}\u{1f22}\u{3b9}\u{1f23}\u{3b9}\u{1f24}\u{3b9}\u{1f25}\u{3b9}\u{1f26}\u{3b9}\u{1f27}\u{3b9}\u{1f60}\u{3b9}\u{1f61}\u{3b9}\u{1f62}\u{3b9}\u{1f63}\u{3b9}\u{1f64}\u{3b9}\u{1f65}\u{3b9}\u{1f66}\u{3b9}\u{1f67}\u{3b9}\u{1f70}\u{3b9}\u{3b1}\u{3b9}\u{3ac}\u{3b9}\u{1fb6}\u{3b9}\u{1fb0}\u{1fb1}\u{1f70} \u{313} \u{342} \u{308}\u{342}\u{1f74}\u{3b9}\u{3b7}\u{3b9}\u{3ae}\u{3b9}\u{1fc6}\u{3b9}\u{1f72}\u{1f74} \u{313}\u{300} \u{313}\u{301} \u{313}\u{342}\u{390}\u{1fd0}\u{1fd1}\u{1f76} \u{314}\u{300} \u{314}\u{301} \u{314}\u{342}\u{3b0}\u{1fe0}\u{1fe1}\u{1f7a}\u{1fe5} \u{308}\u{300}`\u{1f7c}\u{3b9}\u{3c9}\u{3b9}\u{3ce}\u{3b9}\u{1ff6}\u{3b9}\u{1f78}\u{1f7c} \u{314}\u{2010} \u{333}\u{2032}\u{2032}\u{2032}\u{2032}\u{2032}\u{2035}\u{2035}\u{2035}\u{2035}\u{2035}!! \u{305}???!!?\u{2032}\u{2032}\u{2032}\u{2032}0456789+\u{2212}=()rsa/ca/s\u{b0}cc/oc/u\u{b0}fnosmteltm\u{5d0}\u{5d1}\u{5d2}\u{5d3}fax\u{2211}1\u{2044}71\u{2044}91\u{2044}101\u{2044}32\u{2044}31\u{2044}52\u{2044}53\u{2044}54\u{2044}51\u{2044}65\u{2044}61\u{2044}83\u{2044}85\u{2044}87\u{2044}81\u{2044}iiiiiivviviiviiiixxixii0\u{2044}3\u{222b}\u{222b}\u{222b}\u{222b}\u{222b}\u{222e}\u{222e}\u{222e}\u{222e}\u{222e}\u{3008}\u{3009}1011121314151617181920(1)(2)(3)(4)(5)(6)(7)(8)(9)(10)(11)(12)(13)(14)(15)(16)(17)(18)(19)(20)(a)(b)(c)(d)(e)(f)(g)(h)(i)(j)(k)(l)(m)(n)(o)(p)(q)(r)(s)(t)(u)(v)(w)(x)(y)(z)\u{222b}\u{222b}\u{222b}\u{222b}::======\u{2add}\u{338}\u{2c30}\u{2c31}\u{2c32}\u{2c33}\u{2c34}\u{2c35}\u{2c36}\u{2c37}\u{2c38}\u{2c39}\u{2c3a}\u{2c3b}\u{2c3c}\u{2c3d}\u{2c3e}\u{2c3f}\u{2c40}\u{2c41}\u{2c42}\u{2c43}\u{2c44}\u{2c45}\u{2c46}\u{2c47}\u{2c48}\u{2c49}\u{2c4a}\u{2c4b}\u{2c4c}\u{2c4d}\u{2c4e}\u{2c4f}\u{2c50}\u{2c51}\u{2c52}\u{2c53}\u{2c54}\u{2c55}\u{2c56}\u{2c57}\u{2c58}\u{2c59}\u{2c5a}\u{2c5b}\u{2c5c}\u{2c5d}\u{2c5e}\u{2c5f}\u{2c61}\u{26b}\u{1d7d}\u{27d}\u{2c68}\u{2c6a}\u{2c6c}\u{2c73}\u{2c76}\u{23f}\u{240}\u{2c81}\u{2c83}\u{2c85}\u{2c87}\u{2c89}\u{2c8b}\u{2c8d}\u{2c8f}\u{2c91}\u{2c93}\u{2c95}\u{2c97}\u{2c99}\u{2c9b}\u{2c9d}\u{2c9f}\u{2ca1}\u{2ca3}\u{2ca5}\u{2ca7}\u{2ca9}\u{2cab}\u{2cad}\u{2caf}\u{2cb1}\u{2cb3}\u{2cb5}\u{2cb7}\u{2cb9}\u{2cbb}\u{2cbd}\u{2cbf}\u{2cc1}\u{2cc3}\u{2cc5}\u{2cc7}\u{2cc9}\u{2ccb}\u{2ccd}\u{2ccf}\u{2cd1}\u{2cd3}\u{2cd5}\u{2cd7}\u{2cd9}\u{2cdb}\u{2cdd}\u{2cdf}\u{2ce1}\u{2ce3}\u{2cec}\u{2cee}\u{2cf3}\u{2d61}\u{6bcd}\u{9f9f}\u{4e00}\u{4e28}\u{4e36}\u{4e3f}\u{4e59}\u{4e85}\u{4e8c}\u{4ea0}\u{4eba}\u{513f}\u{5165}\u{516b}\u{5182}\u{5196}\u{51ab}\u{51e0}\u{51f5}\u{5200}\u{529b}\u{52f9}\u{5315}\u{531a}\u{5338}\u{5341}\u{535c}\u{5369}\u{5382}\u{53b6}\u{53c8}\u{53e3}\u{56d7}\u{571f}\u{58eb}\u{5902}\u{590a}\u{5915}\u{5927}\u{5973}\u{5b50}\u{5b80}\u{5bf8}\u{5c0f}\u{5c22}\u{5c38}\u{5c6e}\u{5c71}\u{5ddb}\u{5de5}\u{5df1}\u{5dfe}\u{5e72}\u{5e7a}\u{5e7f}\u{5ef4}\u{5efe}\u{5f0b}\u{5f13}\u{5f50}\u{5f61}\u{5f73}\u{5fc3}\u{6208}\u{6236}\u{624b}\u{652f}\u{6534}\u{6587}\u{6597}\u{65a4}\u{65b9}\u{65e0}\u{65e5}\u{66f0}\u{6708}\u{6728}\u{6b20}\u{6b62}\u{6b79}\u{6bb3}\u{6bcb}\u{6bd4}\u{6bdb}\u{6c0f}\u{6c14}\u{6c34}\u{706b}\u{722a}\u{7236}\u{723b}\u{723f}\u{7247}\u{7259}\u{725b}\u{72ac}\u{7384}\u{7389}\u{74dc}\u{74e6}\u{7518}\u{751f}\u{7528}\u{7530}\u{758b}\u{7592}\u{7676}\u{767d}\u{76ae}\u{76bf}\u{76ee}\u{77db}\u{77e2}\u{77f3}\u{793a}\u{79b8}\u{79be}\u{7a74}\u{7acb}\u{7af9}\u{7c73}\u{7cf8}\u{7f36}\u{7f51}\u{7f8a}\u{7fbd}\u{8001}\u{800c}\u{8012}\u{8033}\u{807f}\u{8089}\u{81e3}\u{81ea}\u{81f3}\u{81fc}\u{820c}\u{821b}\u{821f}\u{826e}\u{8272}\u{8278}\u{864d}\u{866b}\u{8840}\u{884c}\u{8863}\u{897e}\u{898b}\u{89d2}\u{8a00}\u{8c37}\u{8c46}\u{8c55}\u{8c78}\u{8c9d}\u{8d64}\u{8d70}\u{8db3}\u{8eab}\u{8eca}\u{8f9b}\u{8fb0}\u{8fb5}\u{9091}\u{9149}\u{91c6}\u{91cc}\u{91d1}\u{9577}\u{9580}\u{961c}\u{96b6}\u{96b9}\u{96e8}\u{9751}\u{975e}\u{9762}\u{9769}\u{97cb}\u{97ed}\u{97f3}\u{9801}\u{98a8}\u{98db}\u{98df}\u{9996}\u{9999}\u{99ac}\u{9aa8}\u{9ad8}\u{9adf}\u{9b25}\u{9b2f}\u{9b32}\u{9b3c}\u{9b5a}\u{9ce5}\u{9e75}\u{9e7f}\u{9ea5}\u{9ebb}\u{9ec3}\u{9ecd}\u{9ed1}\u{9ef9}\u{9efd}\u{9f0e}\u{9f13}\u{9f20}\u{9f3b}\u{9f4a}\u{9f52}\u{9f8d}\u{9f9c}\u{9fa0}.\u{3012}\u{5344}\u{5345} \u{3099} \u{309a}\u{3088}\u{308a}\u{30b3}\u{30c8}\u{1100}\u{1101}\u{11aa}\u{1102}\u{11ac}\u{11ad}\u{1103}\u{1104}\u{1105}\u{11b0}\u{11b1}\u{11b2}\u{11b3}\u{11b4}\u{11b5}\u{111a}\u{1106}\u{1107}\u{1108}\u{1121}\u{1109}\u{110a}\u{110b}\u{110c}\u{110d}\u{110e}\u{110f}\u{1110}\u{1111}\u{1112}\u{1161}\u{1162}\u{1163}\u{1164}\u{1165}\u{1166}\u{1167}\u{1168}\u{1169}\u{116a}\u{116b}\u{116c}\u{116d}\u{116e}\u{116f}\u{1170}\u{1171}\u{1172}\u{1173}\u{1174}\u{1175}\u{1114}\u{1115}\u{11c7}\u{11c8}\u{11cc}\u{11ce}\u{11d3}\u{11d7}\u{11d9}\u{111c}\u{11dd}\u{11df}\u{111d}\u{111e}\u{1120}\u{1122}\u{1123}\u{1127}\u{1129}\u{112b}\u{112c}\u{112d}\u{112e}\u{112f}\u{1132}\u{1136}\u{1140}\u{1147}\u{114c}\u{11f1}\u{11f2}\u{1157}\u{1158}\u{1159}\u{1184}\u{1185}\u{1188}\u{1191}\u{1192}\u{1194}\u{119e}\u{11a1}\u{4e09}\u{56db}\u{4e0a}\u{4e2d}\u{4e0b}\u{7532}\u{4e19}\u{4e01}\u{5929}\u{5730}(\u{1100})(\u{1102})(\u{1103})(\u{1105})(\u{1106})(\u{1107})(\u{1109})(\u{110b})(\u{110c})(\u{110e})(\u{110f})(\u{1110})(\u{1111})(\u{1112})(\u{ac00})(\u{b098})(\u{b2e4})(\u{b77c})(\u{b9c8})(\u{bc14})(\u{c0ac})(\u{c544})(\u{c790})(\u{cc28})(\u{ce74})(\u{d0c0})(\u{d30c})(\u{d558})(\u{c8fc})(\u{c624}\u{c804})(\u{c624}\u{d6c4})(\u{4e00})(\u{4e8c})(\u{4e09})(\u{56db})(\u{4e94})(\u{516d})(\u{4e03})(\u{516b})(\u{4e5d})(\u{5341})(\u{6708})(\u{706b})(\u{6c34})(\u{6728})(\u{91d1})(\u{571f})(\u{65e5})(\u{682a})(\u{6709})(\u{793e})(\u{540d})(\u{7279})(\u{8ca1})(\u{795d})(\u{52b4})(\u{4ee3})(\u{547c})(\u{5b66})(\u{76e3})(\u{4f01})(\u{8cc7})(\u{5354})(\u{796d})(\u{4f11})(\u{81ea})(\u{81f3})\u{554f}\u{5e7c}\u{7b8f}pte212223242526272829303132333435\u{ac00}\u{b098}\u{b2e4}\u{b77c}\u{b9c8}\u{bc14}\u{c0ac}\u{c544}\u{c790}\u{cc28}\u{ce74}\u{d0c0}\u{d30c}\u{d558}\u{cc38}\u{ace0}\u{c8fc}\u{c758}\u{c6b0}\u{4e94}\u{516d}\u{4e03}\u{4e5d}\u{682a}\u{6709}\u{793e}\u{540d}\u{7279}\u{8ca1}\u{795d}\u{52b4}\u{79d8}\u{7537}\u{9069}\u{512a}\u{5370}\u{6ce8}\u{9805}\u{4f11}\u{5199}\u{6b63}\u{5de6}\u{53f3}\u{533b}\u{5b97}\u{5b66}\u{76e3}\u{4f01}\u{8cc7}\u{5354}\u{591c}3637383940414243444546474849501\u{6708}2\u{6708}3\u{6708}4\u{6708}5\u{6708}6\u{6708}7\u{6708}8\u{6708}9\u{6708}10\u{6708}11\u{6708}12\u{6708}hgergevltd\u{30a2}\u{30a4}\u{30a6}\u{30a8}\u{30aa}\u{30ab}\u{30ad}\u{30af}\u{30b1}\u{30b3}\u{30b5}\u{30b7}\u{30b9}\u{30bb}\u{30bd}\u{30bf}\u{30c1}\u{30c4}\u{30c6}\u{30c8}\u{30ca}\u{30cb}\u{30cc}\u{30cd}\u{30ce}\u{30cf}\u{30d2}\u{30d5}\u{30d8}\u{30db}\u{30de}\u{30df}\u{30e0}\u{30e1}\u{30e2}\u{30e4}\u{30e6}\u{30e8}\u{30e9}\u{30ea}\u{30eb}\u{30ec}\u{30ed}\u{30ef}\u{30f0}\u{30f1}\u{30f2}\u{4ee4}\u{548c}\u{30a2}\u{30d1}\u{30fc}\u{30c8}\u{30a2}\u{30eb}\u{30d5}\u{30a1}\u{30a2}\u{30f3}\u{30da}\u{30a2}\u{30a2}\u{30fc}\u{30eb}\u{30a4}\u{30cb}\u{30f3}\u{30b0}\u{30a4}\u{30f3}\u{30c1}\u{30a6}\u{30a9}\u{30f3}\u{30a8}\u{30b9}\u{30af}\u{30fc}\u{30c9}\u{30a8}\u{30fc}\u{30ab}\u{30fc}\u{30aa}\u{30f3}\u{30b9}\u{30aa}\u{30fc}\u{30e0}\u{30ab}\u{30a4}\u{30ea}\u{30ab}\u{30e9}\u{30c3}\u{30c8}\u{30ab}\u{30ed}\u{30ea}\u{30fc}\u{30ac}\u{30ed}\u{30f3}\u{30ac}\u{30f3}\u{30de}\u{30ae}\u{30ac}\u{30ae}\u{30cb}\u{30fc}\u{30ad}\u{30e5}\u{30ea}\u{30fc}\u{30ae}\u{30eb}\u{30c0}\u{30fc}\u{30ad}\u{30ed}\u{30ad}\u{30ed}\u{30b0}\u{30e9}\u{30e0}\u{30ad}\u{30ed}\u{30e1}\u{30fc}\u{30c8}\u{30eb}\u{30ad}\u{30ed}\u{30ef}\u{30c3}\u{30c8}\u{30b0}\u{30e9}\u{30e0}\u{30b0}\u{30e9}\u{30e0}\u{30c8}\u{30f3}\u{30af}\u{30eb}\u{30bc}\u{30a4}\u{30ed}\u{30af}\u{30ed}\u{30fc}\u{30cd}\u{30b1}\u{30fc}\u{30b9}\u{30b3}\u{30eb}\u{30ca}\u{30b3}\u{30fc}\u{30dd}\u{30b5}\u{30a4}\u{30af}\u{30eb}\u{30b5}\u{30f3}\u{30c1}\u{30fc}\u{30e0}\u{30b7}\u{30ea}\u{30f3}\u{30b0}\u{30bb}\u{30f3}\u{30c1}\u{30bb}\u{30f3}\u{30c8}\u{30c0}\u{30fc}\u{30b9}\u{30c7}\u{30b7}\u{30c9}\u{30eb}\u{30c8}\u{30f3}\u{30ca}\u{30ce}\u{30ce}\u{30c3}\u{30c8}\u{30cf}\u{30a4}\u{30c4}\u{30d1}\u{30fc}\u{30bb}\u{30f3}\u{30c8}\u{30d1}\u{30fc}\u{30c4}\u{30d0}\u{30fc}\u{30ec}\u{30eb}\u{30d4}\u{30a2}\u{30b9}\u{30c8}\u{30eb}\u{30d4}\u{30af}\u{30eb}\u{30d4}\u{30b3}\u{30d3}\u{30eb}\u{30d5}\u{30a1}\u{30e9}\u{30c3}\u{30c9}\u{30d5}\u{30a3}\u{30fc}\u{30c8}\u{30d6}\u{30c3}\u{30b7}\u{30a7}\u{30eb}\u{30d5}\u{30e9}\u{30f3}\u{30d8}\u{30af}\u{30bf}\u{30fc}\u{30eb}\u{30da}\u{30bd}\u{30da}\u{30cb}\u{30d2}\u{30d8}\u{30eb}\u{30c4}\u{30da}\u{30f3}\u{30b9}\u{30da}\u{30fc}\u{30b8}\u{30d9}\u{30fc}\u{30bf}\u{30dd}\u{30a4}\u{30f3}\u{30c8}\u{30dc}\u{30eb}\u{30c8}\u{30db}\u{30f3}\u{30dd}\u{30f3}\u{30c9}\u{30db}\u{30fc}\u{30eb}\u{30db}\u{30fc}\u{30f3}\u{30de}\u{30a4}\u{30af}\u{30ed}\u{30de}\u{30a4}\u{30eb}\u{30de}\u{30c3}\u{30cf}\u{30de}\u{30eb}\u{30af}\u{30de}\u{30f3}\u{30b7}\u{30e7}\u{30f3}\u{30df}\u{30af}\u{30ed}\u{30f3}\u{30df}\u{30ea}\u{30df}\u{30ea}\u{30d0}\u{30fc}\u{30eb}\u{30e1}\u{30ac}\u{30e1}\u{30ac}\u{30c8}\u{30f3}\u{30e1}\u{30fc}\u{30c8}\u{30eb}\u{30e4}\u{30fc}\u{30c9}\u{30e4}\u{30fc}\u{30eb}\u{30e6}\u{30a2}\u{30f3}\u{30ea}\u{30c3}\u{30c8}\u{30eb}\u{30ea}\u{30e9}\u{30eb}\u{30d4}\u{30fc}\u{30eb}\u{30fc}\u{30d6}\u{30eb}\u{30ec}\u{30e0}\u{30ec}\u{30f3}\u{30c8}\u{30b2}\u{30f3}\u{30ef}\u{30c3}\u{30c8}0\u{70b9}1\u{70b9}2\u{70b9}3\u{70b9}4\u{70b9}5\u{70b9}6\u{70b9}7\u{70b9}8\u{70b9}9\u{70b9}10\u{70b9}11\u{70b9}12\u{70b9}13\u{70b9}14\u{70b9}15\u{70b9}16\u{70b9}17\u{70b9}18\u{70b9}19\u{70b9}20\u{70b9}21\u{70b9}22\u{70b9}23\u{70b9}24\u{70b9}hpadaaubarovpcdmdm2dm3iu\u{5e73}\u{6210}\u{662d}\u{548c}\u{5927}\u{6b63}\u{660e}\u{6cbb}\u{682a}\u{5f0f}\u{4f1a}\u{793e}pana\u{3bc}amakakbmbgbcalkcalpfnf\u{3bc}f\u{3bc}gmgkghzkhzmhzghzthz\u{3bc}lmldlklfmnm\u{3bc}mmmcmkmmm2cm2m2km2mm3cm3m3km3m\u{2215}sm\u{2215}s2kpampagparadrad\u{2215}srad\u{2215}s2psns\u{3bc}smspvnv\u{3bc}vmvkvpwnw\u{3bc}wmwkwk\u{3c9}m\u{3c9}bqcccdc\u{2215}kgdbgyhahpinkkktlmlnloglxmilmolphppmprsrsvwbv\u{2215}ma\u{2215}m1\u{65e5}2\u{65e5}3\u{65e5}4\u{65e5}5\u{65e5}6\u{65e5}7\u{65e5}8\u{65e5}9\u{65e5}10\u{65e5}11\u{65e5}12\u{65e5}13\u{65e5}14\u{65e5}15\u{65e5}16\u{65e5}17\u{65e5}18\u{65e5}19\u{65e5}20\u{65e5}21\u{65e5}22\u{65e5}23\u{65e5}24\u{65e5}25\u{65e5}26\u{65e5}27\u{65e5}28\u{65e5}29\u{65e5}30\u{65e5}31\u{65e5}gal\u{a641}\u{a643}\u{a645}\u{a647}\u{a649}\u{a64d}\u{a64f}\u{a651}\u{a653}\u{a655}\u{a657}\u{a659}\u{a65b}\u{a65d}\u{a65f}\u{a661}\u{a663}\u{a665}\u{a667}\u{a669}\u{a66b}\u{a66d}\u{a681}\u{a683}\u{a685}\u{a687}\u{a689}\u{a68b}\u{a68d}\u{a68f}\u{a691}\u{a693}\u{a695}\u{a697}\u{a699}\u{a69b}\u{a723}\u{a725}\u{a727}\u{a729}\u{a72b}\u{a72d}\u{a72f}\u{a733}\u{a735}\u{a737}\u{a739}\u{a73b}\u{a73d}\u{a73f}\u{a741}\u{a743}\u{a745}\u{a747}\u{a749}\u{a74b}\u{a74d}\u{a74f}\u{a751}\u{a753}\u{a755}\u{a757}\u{a759}\u{a75b}\u{a75d}\u{a75f}\u{a761}\u{a763}\u{a765}\u{a767}\u{a769}\u{a76b}\u{a76d}\u{a76f}\u{a77a}\u{a77c}\u{1d79}\u{a77f}\u{a781}\u{a783}\u{a785}\u{a787}\u{a78c}\u{a791}\u{a793}\u{a797}\u{a799}\u{a79b}\u{a79d}\u{a79f}\u{a7a1}\u{a7a3}\u{a7a5}\u{a7a7}\u{a7a9}\u{26c}\u{29e}\u{287}\u{ab53}\u{a7b5}\u{a7b7}\u{a7b9}\u{a7bb}\u{a7bd}\u{a7bf}\u{a7c1}\u{a7c3}\u{a794}\u{1d8e}\u{a7c8}\u{a7ca}\u{a7d1}\u{a7d7}\u{a7d9}\u{a7f6}\u{ab37}\u{ab52}\u{28d}\u{13a0}\u{13a1}\u{13a2}\u{13a3}\u{13a4}\u{13a5}\u{13a6}\u{13a7}\u{13a8}\u{13a9}\u{13aa}\u{13ab}\u{13ac}\u{13ad}\u{13ae}\u{13af}\u{13b0}\u{13b1}\u{13b2}\u{13b3}\u{13b4}\u{13b5}\u{13b6}\u{13b7}\u{13b8}\u{13b9}\u{13ba}\u{13bb}\u{13bc}\u{13bd}\u{13be}\u{13bf}\u{13c0}\u{13c1}\u{13c2}\u{13c3}\u{13c4}\u{13c5}\u{13c6}\u{13c7}\u{13c8}\u{13c9}\u{13ca}\u{13cb}\u{13cc}\u{13cd}\u{13ce}\u{13cf}\u{13d0}\u{13d1}\u{13d2}\u{13d3}\u{13d4}\u{13d5}\u{13d6}\u{13d7}\u{13d8}\u{13d9}\u{13da}\u{13db}\u{13dc}\u{13dd}\u{13de}\u{13df}\u{13e0}\u{13e1}\u{13e2}\u{13e3}\u{13e4}\u{13e5}\u{13e6}\u{13e7}\u{13e8}\u{13e9}\u{13ea}\u{13eb}\u{13ec}\u{13ed}\u{13ee}\u{13ef}\u{8c48}\u{66f4}\u{8cc8}\u{6ed1}\u{4e32}\u{53e5}\u{5951}\u{5587}\u{5948}\u{61f6}\u{7669}\u{7f85}\u{863f}\u{87ba}\u{88f8}\u{908f}\u{6a02}\u{6d1b}\u{70d9}\u{73de}\u{843d}\u{916a}\u{99f1}\u{4e82}\u{5375}\u{6b04}\u{721b}\u{862d}\u{9e1e}\u{5d50}\u{6feb}\u{85cd}\u{8964}\u{62c9}\u{81d8}\u{881f}\u{5eca}\u{6717}\u{6d6a}\u{72fc}\u{90ce}\u{4f86}\u{51b7}\u{52de}\u{64c4}\u{6ad3}\u{7210}\u{76e7}\u{8606}\u{865c}\u{8def}\u{9732}\u{9b6f}\u{9dfa}\u{788c}\u{797f}\u{7da0}\u{83c9}\u{9304}\u{8ad6}\u{58df}\u{5f04}\u{7c60}\u{807e}\u{7262}\u{78ca}\u{8cc2}\u{96f7}\u{58d8}\u{5c62}\u{6a13}\u{6dda}\u{6f0f}\u{7d2f}\u{7e37}\u{964b}\u{52d2}\u{808b}\u{51dc}\u{51cc}\u{7a1c}\u{7dbe}\u{83f1}\u{9675}\u{8b80}\u{62cf}\u{8afe}\u{4e39}\u{5be7}\u{6012}\u{7387}\u{7570}\u{5317}\u{78fb}\u{4fbf}\u{5fa9}\u{4e0d}\u{6ccc}\u{6578}\u{7d22}\u{53c3}\u{585e}\u{7701}\u{8449}\u{8aaa}\u{6bba}\u{6c88}\u{62fe}\u{82e5}\u{63a0}\u{7565}\u{4eae}\u{5169}\u{51c9}\u{6881}\u{7ce7}\u{826f}\u{8ad2}\u{91cf}\u{52f5}\u{5442}\u{5eec}\u{65c5}\u{6ffe}\u{792a}\u{95ad}\u{9a6a}\u{9e97}\u{9ece}\u{66c6}\u{6b77}\u{8f62}\u{5e74}\u{6190}\u{6200}\u{649a}\u{6f23}\u{7149}\u{7489}\u{79ca}\u{7df4}\u{806f}\u{8f26}\u{84ee}\u{9023}\u{934a}\u{5217}\u{52a3}\u{54bd}\u{70c8}\u{88c2}\u{5ec9}\u{5ff5}\u{637b}\u{6bae}\u{7c3e}\u{7375}\u{4ee4}\u{56f9}\u{5dba}\u{601c}\u{73b2}\u{7469}\u{7f9a}\u{8046}\u{9234}\u{96f6}\u{9748}\u{9818}\u{4f8b}\u{79ae}\u{91b4}\u{96b8}\u{60e1}\u{4e86}\u{50da}\u{5bee}\u{5c3f}\u{6599}\u{71ce}\u{7642}\u{84fc}\u{907c}\u{6688}\u{962e}\u{5289}\u{677b}\u{67f3}\u{6d41}\u{6e9c}\u{7409}\u{7559}\u{786b}\u{7d10}\u{985e}\u{622e}\u{9678}\u{502b}\u{5d19}\u{6dea}\u{8f2a}\u{5f8b}\u{6144}\u{6817}\u{9686}\u{5229}\u{540f}\u{5c65}\u{6613}\u{674e}\u{68a8}\u{6ce5}\u{7406}\u{75e2}\u{7f79}\u{88cf}\u{88e1}\u{96e2}\u{533f}\u{6eba}\u{541d}\u{71d0}\u{7498}\u{85fa}\u{96a3}\u{9c57}\u{9e9f}\u{6797}\u{6dcb}\u{81e8}\u{7b20}\u{7c92}\u{72c0}\u{7099}\u{8b58}\u{4ec0}\u{8336}\u{523a}\u{5207}\u{5ea6}\u{62d3}\u{7cd6}\u{5b85}\u{6d1e}\u{66b4}\u{8f3b}\u{964d}\u{5ed3}\u{5140}\u{55c0}\u{585a}\u{6674}\u{51de}\u{732a}\u{76ca}\u{793c}\u{795e}\u{7965}\u{798f}\u{9756}\u{7cbe}\u{8612}\u{8af8}\u{9038}\u{90fd}\u{98ef}\u{98fc}\u{9928}\u{9db4}\u{90de}\u{96b7}\u{4fae}\u{50e7}\u{514d}\u{52c9}\u{52e4}\u{5351}\u{559d}\u{5606}\u{5668}\u{5840}\u{58a8}\u{5c64}\u{6094}\u{6168}\u{618e}\u{61f2}\u{654f}\u{65e2}\u{6691}\u{6885}\u{6d77}\u{6e1a}\u{6f22}\u{716e}\u{722b}\u{7422}\u{7891}\u{7949}\u{7948}\u{7950}\u{7956}\u{798d}\u{798e}\u{7a40}\u{7a81}\u{7bc0}\u{7e09}\u{7e41}\u{7f72}\u{8005}\u{81ed}\u{8279}\u{8457}\u{8910}\u{8996}\u{8b01}\u{8b39}\u{8cd3}\u{8d08}\u{8fb6}\u{96e3}\u{97ff}\u{983b}\u{6075}\u{242ee}\u{8218}\u{4e26}\u{51b5}\u{5168}\u{4f80}\u{5145}\u{5180}\u{52c7}\u{52fa}\u{5555}\u{5599}\u{55e2}\u{58b3}\u{5944}\u{5954}\u{5a62}\u{5b28}\u{5ed2}\u{5ed9}\u{5f69}\u{5fad}\u{60d8}\u{614e}\u{6108}\u{6160}\u{6234}\u{63c4}\u{641c}\u{6452}\u{6556}\u{671b}\u{6756}\u{6edb}\u{6ecb}\u{701e}\u{77a7}\u{7235}\u{72af}\u{7471}\u{7506}\u{753b}\u{761d}\u{761f}\u{76db}\u{76f4}\u{774a}\u{7740}\u{78cc}\u{7ab1}\u{7c7b}\u{7d5b}\u{7f3e}\u{8352}\u{83ef}\u{8779}\u{8941}\u{8986}\u{8abf}\u{8acb}\u{8aed}\u{8b8a}\u{8f38}\u{9072}\u{9199}\u{9276}\u{967c}\u{97db}\u{980b}\u{9b12}\u{2284a}\u{22844}\u{233d5}\u{3b9d}\u{4018}\u{4039}\u{25249}\u{25cd0}\u{27ed3}\u{9f43}\u{9f8e}fffiflffifflst\u{574}\u{576}\u{574}\u{565}\u{574}\u{56b}\u{57e}\u{576}\u{574}\u{56d}\u{5d9}\u{5b4}\u{5f2}\u{5b7}\u{5e2}\u{5d4}\u{5db}\u{5dc}\u{5dd}\u{5e8}\u{5ea}\u{5e9}\u{5c1}\u{5e9}\u{5c2}\u{5e9}\u{5bc}\u{5c1}\u{5e9}\u{5bc}\u{5c2}\u{5d0}\u{5b7}\u{5d0}\u{5b8}\u{5d0}\u{5bc}\u{5d1}\u{5bc}\u{5d2}\u{5bc}\u{5d3}\u{5bc}\u{5d4}\u{5bc}\u{5d5}\u{5bc}\u{5d6}\u{5bc}\u{5d8}\u{5bc}\u{5d9}\u{5bc}\u{5da}\u{5bc}\u{5db}\u{5bc}\u{5dc}\u{5bc}\u{5de}\u{5bc}\u{5e0}\u{5bc}\u{5e1}\u{5bc}\u{5e3}\u{5bc}\u{5e4}\u{5bc}\u{5e6}\u{5bc}\u{5e7}\u{5bc}\u{5e8}\u{5bc}\u{5e9}\u{5bc}\u{5ea}\u{5bc}\u{5d5}\u{5b9}\u{5d1}\u{5bf}\u{5db}\u{5bf}\u{5e4}\u{5bf}\u{5d0}\u{5dc}\u{671}\u{67b}\u{67e}\u{680}\u{67a}\u{67f}\u{679}\u{6a4}\u{6a6}\u{684}\u{683}\u{686}\u{687}\u{68d}\u{68c}\u{68e}\u{688}\u{698}\u{691}\u{6a9}\u{6af}\u{6b3}\u{6b1}\u{6ba}\u{6bb}\u{6c0}\u{6c1}\u{6be}\u{6d2}\u{6d3}\u{6ad}\u{6c7}\u{6c6}\u{6c8}\u{6cb}\u{6c5}\u{6c9}\u{6d0}\u{649}\u{626}\u{627}\u{626}\u{6d5}\u{626}\u{648}\u{626}\u{6c7}\u{626}\u{6c6}\u{626}\u{6c8}\u{626}\u{6d0}\u{626}\u{649}\u{6cc}\u{626}\u{62c}\u{626}\u{62d}\u{626}\u{645}\u{626}\u{64a}\u{628}\u{62c}\u{628}\u{62d}\u{628}\u{62e}\u{628}\u{645}\u{628}\u{649}\u{628}\u{64a}\u{62a}\u{62c}\u{62a}\u{62d}\u{62a}\u{62e}\u{62a}\u{645}\u{62a}\u{649}\u{62a}\u{64a}\u{62b}\u{62c}\u{62b}\u{645}\u{62b}\u{649}\u{62b}\u{64a}\u{62c}\u{62d}\u{62c}\u{645}\u{62d}\u{62c}\u{62d}\u{645}\u{62e}\u{62c}\u{62e}\u{62d}\u{62e}\u{645}\u{633}\u{62c}\u{633}\u{62d}\u{633}\u{62e}\u{633}\u{645}\u{635}\u{62d}\u{635}\u{645}\u{636}\u{62c}\u{636}\u{62d}\u{636}\u{62e}\u{636}\u{645}\u{637}\u{62d}\u{637}\u{645}\u{638}\u{645}\u{639}\u{62c}\u{639}\u{645}\u{63a}\u{62c}\u{63a}\u{645}\u{641}\u{62c}\u{641}\u{62d}\u{641}\u{62e}\u{641}\u{645}\u{641}\u{649}\u{641}\u{64a}\u{642}\u{62d}\u{642}\u{645}\u{642}\u{649}\u{642}\u{64a}\u{643}\u{627}\u{643}\u{62c}\u{643}\u{62d}\u{643}\u{62e}\u{643}\u{644}\u{643}\u{645}\u{643}\u{649}\u{643}\u{64a}\u{644}\u{62c}\u{644}\u{62d}\u{644}\u{62e}\u{644}\u{645}\u{644}\u{649}\u{644}\u{64a}\u{645}\u{62c}\u{645}\u{62d}\u{645}\u{62e}\u{645}\u{645}\u{645}\u{649}\u{645}\u{64a}\u{646}\u{62c}\u{646}\u{62d}\u{646}\u{62e}\u{646}\u{645}\u{646}\u{649}\u{646}\u{64a}\u{647}\u{62c}\u{647}\u{645}\u{647}\u{649}\u{647}\u{64a}\u{64a}\u{62c}\u{64a}\u{62d}\u{64a}\u{62e}\u{64a}\u{645}\u{64a}\u{649}\u{64a}\u{64a}\u{630}\u{670}\u{631}\u{670}\u{649}\u{670} \u{64c}\u{651} \u{64d}\u{651} \u{64e}\u{651} \u{64f}\u{651} \u{650}\u{651} \u{651}\u{670}\u{626}\u{631}\u{626}\u{632}\u{626}\u{646}\u{628}\u{631}\u{628}\u{632}\u{628}\u{646}\u{62a}\u{631}\u{62a}\u{632}\u{62a}\u{646}\u{62b}\u{631}\u{62b}\u{632}\u{62b}\u{646}\u{645}\u{627}\u{646}\u{631}\u{646}\u{632}\u{646}\u{646}\u{64a}\u{631}\u{64a}\u{632}\u{64a}\u{646}\u{626}\u{62e}\u{626}\u{647}\u{628}\u{647}\u{62a}\u{647}\u{635}\u{62e}\u{644}\u{647}\u{646}\u{647}\u{647}\u{670}\u{64a}\u{647}\u{62b}\u{647}\u{633}\u{647}\u{634}\u{645}\u{634}\u{647}\u{640}\u{64e}\u{651}\u{640}\u{64f}\u{651}\u{640}\u{650}\u{651}\u{637}\u{649}\u{637}\u{64a}\u{639}\u{649}\u{639}\u{64a}\u{63a}\u{649}\u{63a}\u{64a}\u{633}\u{649}\u{633}\u{64a}\u{634}\u{649}\u{634}\u{64a}\u{62d}\u{649}\u{62d}\u{64a}\u{62c}\u{649}\u{62c}\u{64a}\u{62e}\u{649}\u{62e}\u{64a}\u{635}\u{649}\u{635}\u{64a}\u{636}\u{649}\u{636}\u{64a}\u{634}\u{62c}\u{634}\u{62d}\u{634}\u{62e}\u{634}\u{631}\u{633}\u{631}\u{635}\u{631}\u{636}\u{631}\u{627}\u{64b}\u{62a}\u{62c}\u{645}\u{62a}\u{62d}\u{62c}\u{62a}\u{62d}\u{645}\u{62a}\u{62e}\u{645}\u{62a}\u{645}\u{62c}\u{62a}\u{645}\u{62d}\u{62a}\u{645}\u{62e}\u{62c}\u{645}\u{62d}\u{62d}\u{645}\u{64a}\u{62d}\u{645}\u{649}\u{633}\u{62d}\u{62c}\u{633}\u{62c}\u{62d}\u{633}\u{62c}\u{649}\u{633}\u{645}\u{62d}\u{633}\u{645}\u{62c}\u{633}\u{645}\u{645}\u{635}\u{62d}\u{62d}\u{635}\u{645}\u{645}\u{634}\u{62d}\u{645}\u{634}\u{62c}\u{64a}\u{634}\u{645}\u{62e}\u{634}\u{645}\u{645}\u{636}\u{62d}\u{649}\u{636}\u{62e}\u{645}\u{637}\u{645}\u{62d}\u{637}\u{645}\u{645}\u{637}\u{645}\u{64a}\u{639}\u{62c}\u{645}\u{639}\u{645}\u{645}\u{639}\u{645}\u{649}\u{63a}\u{645}\u{645}\u{63a}\u{645}\u{64a}\u{63a}\u{645}\u{649}\u{641}\u{62e}\u{645}\u{642}\u{645}\u{62d}\u{642}\u{645}\u{645}\u{644}\u{62d}\u{645}\u{644}\u{62d}\u{64a}\u{644}\u{62d}\u{649}\u{644}\u{62c}\u{62c}\u{644}\u{62e}\u{645}\u{644}\u{645}\u{62d}\u{645}\u{62d}\u{62c}\u{645}\u{62d}\u{645}\u{645}\u{62d}\u{64a}\u{645}\u{62c}\u{62d}\u{645}\u{62c}\u{645}\u{645}\u{62e}\u{62c}\u{645}\u{62e}\u{645}\u{645}\u{62c}\u{62e}\u{647}\u{645}\u{62c}\u{647}\u{645}\u{645}\u{646}\u{62d}\u{645}\u{646}\u{62d}\u{649}\u{646}\u{62c}\u{645}\u{646}\u{62c}\u{649}\u{646}\u{645}\u{64a}\u{646}\u{645}\u{649}\u{64a}\u{645}\u{645}\u{628}\u{62e}\u{64a}\u{62a}\u{62c}\u{64a}\u{62a}\u{62c}\u{649}\u{62a}\u{62e}\u{64a}\u{62a}\u{62e}\u{649}\u{62a}\u{645}\u{64a}\u{62a}\u{645}\u{649}\u{62c}\u{645}\u{64a}\u{62c}\u{62d}\u{649}\u{62c}\u{645}\u{649}\u{633}\u{62e}\u{649}\u{635}\u{62d}\u{64a}\u{634}\u{62d}\u{64a}\u{636}\u{62d}\u{64a}\u{644}\u{62c}\u{64a}\u{644}\u{645}\u{64a}\u{64a}\u{62d}\u{64a}\u{64a}\u{62c}\u{64a}\u{64a}\u{645}\u{64a}\u{645}\u{645}\u{64a}\u{642}\u{645}\u{64a}\u{646}\u{62d}\u{64a}\u{639}\u{645}\u{64a}\u{643}\u{645}\u{64a}\u{646}\u{62c}\u{62d}\u{645}\u{62e}\u{64a}\u{644}\u{62c}\u{645}\u{643}\u{645}\u{645}\u{62c}\u{62d}\u{64a}\u{62d}\u{62c}\u{64a}\u{645}\u{62c}\u{64a}\u{641}\u{645}\u{64a}\u{628}\u{62d}\u{64a}\u{633}\u{62e}\u{64a}\u{646}\u{62c}\u{64a}\u{635}\u{644}\u{6d2}\u{642}\u{644}\u{6d2}\u{627}\u{644}\u{644}\u{647}\u{627}\u{643}\u{628}\u{631}\u{645}\u{62d}\u{645}\u{62f}\u{635}\u{644}\u{639}\u{645}\u{631}\u{633}\u{648}\u{644}\u{639}\u{644}\u{64a}\u{647}\u{648}\u{633}\u{644}\u{645}\u{635}\u{644}\u{649}\u{635}\u{644}\u{649} \u{627}\u{644}\u{644}\u{647} \u{639}\u{644}\u{64a}\u{647} \u{648}\u{633}\u{644}\u{645}\u{62c}\u{644} \u{62c}\u{644}\u{627}\u{644}\u{647}\u{631}\u{6cc}\u{627}\u{644},\u{3001}:!?\u{3016}\u{3017}\u{2014}\u{2013}_{}\u{3014}\u{3015}\u{3010}\u{3011}\u{300a}\u{300b}\u{300c}\u{300d}\u{300e}\u{300f}[]#&*-<>\u{5c}$%@ \u{64b}\u{640}\u{64b} \u{64c} \u{64d} \u{64e}\u{640}\u{64e} \u{64f}\u{640}\u{64f} \u{650}\u{640}\u{650} \u{651}\u{640}\u{651} \u{652}\u{640}\u{652}\u{621}\u{622}\u{623}\u{624}\u{625}\u{626}\u{627}\u{628}\u{629}\u{62a}\u{62b}\u{62c}\u{62d}\u{62e}\u{62f}\u{630}\u{631}\u{632}\u{633}\u{634}\u{635}\u{636}\u{637}\u{638}\u{639}\u{63a}\u{641}\u{642}\u{643}\u{644}\u{645}\u{646}\u{647}\u{648}\u{64a}\u{644}\u{622}\u{644}\u{623}\u{644}\u{625}\u{644}\u{627}\u{22}'/^|~\u{2985}\u{2986}\u{30fb}\u{30a1}\u{30a3}\u{30a5}\u{30a7}\u{30a9}\u{30e3}\u{30e5}\u{30e7}\u{30c3}\u{30fc}\u{30f3}\u{3099}\u{309a}\u{a2}\u{a3}\u{ac}\u{a6}\u{a5}\u{20a9}\u{2502}\u{2190}\u{2191}\u{2192}\u{2193}\u{25a0}\u{25cb}\u{10428}\u{10429}\u{1042a}\u{1042b}\u{1042c}\u{1042d}\u{1042e}\u{1042f}\u{10430}\u{10431}\u{10432}\u{10433}\u{10434}\u{10435}\u{10436}\u{10437}\u{10438}\u{10439}\u{1043a}\u{1043b}\u{1043c}\u{1043d}\u{1043e}\u{1043f}\u{10440}\u{10441}\u{10442}\u{10443}\u{10444}\u{10445}\u{10446}\u{10447}\u{10448}\u{10449}\u{1044a}\u{1044b}\u{1044c}\u{1044d}\u{1044e}\u{1044f}\u{104d8}\u{104d9}\u{104da}\u{104db}\u{104dc}\u{104dd}\u{104de}\u{104df}\u{104e0}\u{104e1}\u{104e2}\u{104e3}\u{104e4}\u{104e5}\u{104e6}\u{104e7}\u{104e8}\u{104e9}\u{104ea}\u{104eb}\u{104ec}\u{104ed}\u{104ee}\u{104ef}\u{104f0}\u{104f1}\u{104f2}\u{104f3}\u{104f4}\u{104f5}\u{104f6}\u{104f7}\u{104f8}\u{104f9}\u{104fa}\u{104fb}\u{10597}\u{10598}\u{10599}\u{1059a}\u{1059b}\u{1059c}\u{1059d}\u{1059e}\u{1059f}\u{105a0}\u{105a1}\u{105a3}\u{105a4}\u{105a5}\u{105a6}\u{105a7}\u{105a8}\u{105a9}\u{105aa}\u{105ab}\u{105ac}\u{105ad}\u{105ae}\u{105af}\u{105b0}\u{105b1}\u{105b3}\u{105b4}\u{105b5}\u{105b6}\u{105b7}\u{105b8}\u{105b9}\u{105bb}\u{105bc}\u{2d0}\u{2d1}\u{299}\u{2a3}\u{ab66}\u{2a5}\u{2a4}\u{1d91}\u{258}\u{25e}\u{2a9}\u{264}\u{262}\u{29b}\u{29c}\u{267}\u{284}\u{2aa}\u{2ab}\u{1df04}\u{a78e}\u{26e}\u{1df05}\u{28e}\u{1df06}\u{276}\u{277}\u{27a}\u{1df08}\u{27e}\u{2a8}\u{2a6}\u{ab67}\u{2a7}\u{2c71}\u{28f}\u{2a1}\u{2a2}\u{298}\u{1c0}\u{1c1}\u{1c2}\u{1df0a}\u{1df1e}\u{10cc0}\u{10cc1}\u{10cc2}\u{10cc3}\u{10cc4}\u{10cc5}\u{10cc6}\u{10cc7}\u{10cc8}\u{10cc9}\u{10cca}\u{10ccb}\u{10ccc}\u{10ccd}\u{10cce}\u{10ccf}\u{10cd0}\u{10cd1}\u{10cd2}\u{10cd3}\u{10cd4}\u{10cd5}\u{10cd6}\u{10cd7}\u{10cd8}\u{10cd9}\u{10cda}\u{10cdb}\u{10cdc}\u{10cdd}\u{10cde}\u{10cdf}\u{10ce0}\u{10ce1}\u{10ce2}\u{10ce3}\u{10ce4}\u{10ce5}\u{10ce6}\u{10ce7}\u{10ce8}\u{10ce9}\u{10cea}\u{10ceb}\u{10cec}\u{10ced}\u{10cee}\u{10cef}\u{10cf0}\u{10cf1}\u{10cf2}\u{118c0}\u{118c1}\u{118c2}\u{118c3}\u{118c4}\u{118c5}\u{118c6}\u{118c7}\u{118c8}\u{118c9}\u{118ca}\u{118cb}\u{118cc}\u{118cd}\u{118ce}\u{118cf}\u{118d0}\u{118d1}\u{118d2}\u{118d3}\u{118d4}\u{118d5}\u{118d6}\u{118d7}\u{118d8}\u{118d9}\u{118da}\u{118db}\u{118dc}\u{118dd}\u{118de}\u{118df}\u{16e60}\u{16e61}\u{16e62}\u{16e63}\u{16e64}\u{16e65}\u{16e66}\u{16e67}\u{16e68}\u{16e69}\u{16e6a}\u{16e6b}\u{16e6c}\u{16e6d}\u{16e6e}\u{16e6f}\u{16e70}\u{16e71}\u{16e72}\u{16e73}\u{16e74}\u{16e75}\u{16e76}\u{16e77}\u{16e78}\u{16e79}\u{16e7a}\u{16e7b}\u{16e7c}\u{16e7d}\u{16e7e}\u{16e7f}\u{1d157}\u{1d165}\u{1d158}\u{1d165}\u{1d158}\u{1d165}\u{1d16e}\u{1d158}\u{1d165}\u{1d16f}\u{1d158}\u{1d165}\u{1d170}\u{1d158}\u{1d165}\u{1d171}\u{1d158}\u{1d165}\u{1d172}\u{1d1b9}\u{1d165}\u{1d1ba}\u{1d165}\u{1d1b9}\u{1d165}\u{1d16e}\u{1d1ba}\u{1d165}\u{1d16e}\u{1d1b9}\u{1d165}\u{1d16f}\u{1d1ba}\u{1d165}\u{1d16f}\u{131}\u{237}\u{2207}\u{2202}\u{4cf}\u{1e922}\u{1e923}\u{1e924}\u{1e925}\u{1e926}\u{1e927}\u{1e928}\u{1e929}\u{1e92a}\u{1e92b}\u{1e92c}\u{1e92d}\u{1e92e}\u{1e92f}\u{1e930}\u{1e931}\u{1e932}\u{1e933}\u{1e934}\u{1e935}\u{1e936}\u{1e937}\u{1e938}\u{1e939}\u{1e93a}\u{1e93b}\u{1e93c}\u{1e93d}\u{1e93e}\u{1e93f}\u{1e940}\u{1e941}\u{1e942}\u{1e943}\u{66e}\u{6a1}\u{66f}0,1,2,3,4,5,6,7,8,9,\u{3014}s\u{3015}wzhvsdssppvwcmcmdmrdj\u{307b}\u{304b}\u{30b3}\u{30b3}\u{5b57}\u{53cc}\u{30c7}\u{591a}\u{89e3}\u{4ea4}\u{6620}\u{7121}\u{524d}\u{5f8c}\u{518d}\u{65b0}\u{521d}\u{7d42}\u{8ca9}\u{58f0}\u{5439}\u{6f14}\u{6295}\u{6355}\u{904a}\u{6307}\u{6253}\u{7981}\u{7a7a}\u{5408}\u{6e80}\u{7533}\u{5272}\u{55b6}\u{914d}\u{3014}\u{672c}\u{3015}\u{3014}\u{4e09}\u{3015}\u{3014}\u{4e8c}\u{3015}\u{3014}\u{5b89}\u{3015}\u{3014}\u{70b9}\u{3015}\u{3014}\u{6253}\u{3015}\u{3014}\u{76d7}\u{3015}\u{3014}\u{52dd}\u{3015}\u{3014}\u{6557}\u{3015}\u{5f97}\u{53ef}\u{4e3d}\u{4e38}\u{4e41}\u{20122}\u{4f60}\u{4fbb}\u{5002}\u{507a}\u{5099}\u{50cf}\u{349e}\u{2063a}\u{5154}\u{5164}\u{5177}\u{2051c}\u{34b9}\u{5167}\u{2054b}\u{5197}\u{51a4}\u{4ecc}\u{51ac}\u{291df}\u{5203}\u{34df}\u{523b}\u{5246}\u{5277}\u{3515}\u{5305}\u{5306}\u{5349}\u{535a}\u{5373}\u{537d}\u{537f}\u{20a2c}\u{7070}\u{53ca}\u{53df}\u{20b63}\u{53eb}\u{53f1}\u{5406}\u{549e}\u{5438}\u{5448}\u{5468}\u{54a2}\u{54f6}\u{5510}\u{5553}\u{5563}\u{5584}\u{55ab}\u{55b3}\u{55c2}\u{5716}\u{5717}\u{5651}\u{5674}\u{58ee}\u{57ce}\u{57f4}\u{580d}\u{578b}\u{5832}\u{5831}\u{58ac}\u{214e4}\u{58f2}\u{58f7}\u{5906}\u{5922}\u{5962}\u{216a8}\u{216ea}\u{59ec}\u{5a1b}\u{5a27}\u{59d8}\u{5a66}\u{36ee}\u{5b08}\u{5b3e}\u{219c8}\u{5bc3}\u{5bd8}\u{5bf3}\u{21b18}\u{5bff}\u{5c06}\u{3781}\u{5c60}\u{5cc0}\u{5c8d}\u{21de4}\u{5d43}\u{21de6}\u{5d6e}\u{5d6b}\u{5d7c}\u{5de1}\u{5de2}\u{382f}\u{5dfd}\u{5e28}\u{5e3d}\u{5e69}\u{3862}\u{22183}\u{387c}\u{5eb0}\u{5eb3}\u{5eb6}\u{2a392}\u{22331}\u{8201}\u{5f22}\u{38c7}\u{232b8}\u{261da}\u{5f62}\u{5f6b}\u{38e3}\u{5f9a}\u{5fcd}\u{5fd7}\u{5ff9}\u{6081}\u{393a}\u{391c}\u{226d4}\u{60c7}\u{6148}\u{614c}\u{617a}\u{61b2}\u{61a4}\u{61af}\u{61de}\u{6210}\u{621b}\u{625d}\u{62b1}\u{62d4}\u{6350}\u{22b0c}\u{633d}\u{62fc}\u{6368}\u{6383}\u{63e4}\u{22bf1}\u{6422}\u{63c5}\u{63a9}\u{3a2e}\u{6469}\u{647e}\u{649d}\u{6477}\u{3a6c}\u{656c}\u{2300a}\u{65e3}\u{66f8}\u{6649}\u{3b19}\u{3b08}\u{3ae4}\u{5192}\u{5195}\u{6700}\u{669c}\u{80ad}\u{43d9}\u{6721}\u{675e}\u{6753}\u{233c3}\u{3b49}\u{67fa}\u{6785}\u{6852}\u{2346d}\u{688e}\u{681f}\u{6914}\u{6942}\u{69a3}\u{69ea}\u{6aa8}\u{236a3}\u{6adb}\u{3c18}\u{6b21}\u{238a7}\u{6b54}\u{3c4e}\u{6b72}\u{6b9f}\u{6bbb}\u{23a8d}\u{21d0b}\u{23afa}\u{6c4e}\u{23cbc}\u{6cbf}\u{6ccd}\u{6c67}\u{6d16}\u{6d3e}\u{6d69}\u{6d78}\u{6d85}\u{23d1e}\u{6d34}\u{6e2f}\u{6e6e}\u{3d33}\u{6ec7}\u{23ed1}\u{6df9}\u{6f6e}\u{23f5e}\u{23f8e}\u{6fc6}\u{7039}\u{701b}\u{3d96}\u{704a}\u{707d}\u{7077}\u{70ad}\u{20525}\u{7145}\u{24263}\u{719c}\u{7228}\u{7250}\u{24608}\u{7280}\u{7295}\u{24735}\u{24814}\u{737a}\u{738b}\u{3eac}\u{73a5}\u{3eb8}\u{7447}\u{745c}\u{7485}\u{74ca}\u{3f1b}\u{7524}\u{24c36}\u{753e}\u{24c92}\u{2219f}\u{7610}\u{24fa1}\u{24fb8}\u{25044}\u{3ffc}\u{4008}\u{250f3}\u{250f2}\u{25119}\u{25133}\u{771e}\u{771f}\u{778b}\u{4046}\u{4096}\u{2541d}\u{784e}\u{40e3}\u{25626}\u{2569a}\u{256c5}\u{79eb}\u{412f}\u{7a4a}\u{7a4f}\u{2597c}\u{25aa7}\u{4202}\u{25bab}\u{7bc6}\u{7bc9}\u{4227}\u{25c80}\u{7cd2}\u{42a0}\u{7ce8}\u{7ce3}\u{7d00}\u{25f86}\u{7d63}\u{4301}\u{7dc7}\u{7e02}\u{7e45}\u{4334}\u{26228}\u{26247}\u{4359}\u{262d9}\u{7f7a}\u{2633e}\u{7f95}\u{7ffa}\u{264da}\u{26523}\u{8060}\u{265a8}\u{8070}\u{2335f}\u{43d5}\u{80b2}\u{8103}\u{440b}\u{813e}\u{5ab5}\u{267a7}\u{267b5}\u{23393}\u{2339c}\u{8204}\u{8f9e}\u{446b}\u{8291}\u{828b}\u{829d}\u{52b3}\u{82b1}\u{82b3}\u{82bd}\u{82e6}\u{26b3c}\u{831d}\u{8363}\u{83ad}\u{8323}\u{83bd}\u{83e7}\u{8353}\u{83ca}\u{83cc}\u{83dc}\u{26c36}\u{26d6b}\u{26cd5}\u{452b}\u{84f1}\u{84f3}\u{8516}\u{273ca}\u{8564}\u{26f2c}\u{455d}\u{4561}\u{26fb1}\u{270d2}\u{456b}\u{8650}\u{8667}\u{8669}\u{86a9}\u{8688}\u{870e}\u{86e2}\u{8728}\u{876b}\u{8786}\u{87e1}\u{8801}\u{45f9}\u{8860}\u{27667}\u{88d7}\u{88de}\u{4635}\u{88fa}\u{34bb}\u{278ae}\u{27966}\u{46be}\u{46c7}\u{8aa0}\u{27ca8}\u{8cab}\u{8cc1}\u{8d1b}\u{8d77}\u{27f2f}\u{20804}\u{8dcb}\u{8dbc}\u{8df0}\u{208de}\u{8ed4}\u{285d2}\u{285ed}\u{9094}\u{90f1}\u{9111}\u{2872e}\u{911b}\u{9238}\u{92d7}\u{92d8}\u{927c}\u{93f9}\u{9415}\u{28bfa}\u{958b}\u{4995}\u{95b7}\u{28d77}\u{49e6}\u{96c3}\u{5db2}\u{9723}\u{29145}\u{2921a}\u{4a6e}\u{4a76}\u{97e0}\u{2940a}\u{4ab2}\u{29496}\u{9829}\u{295b6}\u{98e2}\u{4b33}\u{9929}\u{99a7}\u{99c2}\u{99fe}\u{4bce}\u{29b30}\u{9c40}\u{9cfd}\u{4cce}\u{4ced}\u{9d67}\u{2a0ce}\u{4cf8}\u{2a105}\u{2a20e}\u{2a291}\u{4d56}\u{9efe}\u{9f05}\u{9f0f}\u{9f16}\u{2a600}";
+
+/// The first code point of each run with the same status and mapping,
+/// and the bounds of the mapping in [`MAPPED`].
+pub(super) static MAPPING: &[(char, Status, u16, u16)] = &[
+    ('\u{0}', Status::Valid, 0, 0),
+    ('\u{41}', Status::Mapped, 0, 1),
+    ('\u{42}', Status::Mapped, 1, 2),
+    ('\u{43}', Status::Mapped, 2, 3),
+    ('\u{44}', Status::Mapped, 3, 4),
+    ('\u{45}', Status::Mapped, 4, 5),
+    ('\u{46}', Status::Mapped, 5, 6),
+    ('\u{47}', Status::Mapped, 6, 7),
+    ('\u{48}', Status::Mapped, 7, 8),
+    ('\u{49}', Status::Mapped, 8, 9),
+    ('\u{4A}', Status::Mapped, 9, 10),
+    ('\u{4B}', Status::Mapped, 10, 11),
+    ('\u{4C}', Status::Mapped, 11, 12),
+    ('\u{4D}', Status::Mapped, 12, 13),
+    ('\u{4E}', Status::Mapped, 13, 14),
+    ('\u{4F}', Status::Mapped, 14, 15),
+    ('\u{50}', Status::Mapped, 15, 16),
+    ('\u{51}', Status::Mapped, 16, 17),
+    ('\u{52}', Status::Mapped, 17, 18),
+    ('\u{53}', Status::Mapped, 18, 19),
+    ('\u{54}', Status::Mapped, 19, 20),
+    ('\u{55}', Status::Mapped, 20, 21),
+    ('\u{56}', Status::Mapped, 21, 22),
+    ('\u{57}', Status::Mapped, 22, 23),
+    ('\u{58}', Status::Mapped, 23, 24),
+    ('\u{59}', Status::Mapped, 24, 25),
+    ('\u{5A}', Status::Mapped, 25, 26),
+    ('\u{5B}', Status::Valid, 0, 0),
+    ('\u{80}', Status::Disallowed, 0, 0),
+    ('\u{A0}', Status::Mapped, 26, 27),
+    ('\u{A1}', Status::Valid, 0, 0),
+    ('\u{A8}', Status::Mapped, 27, 30),
+    ('\u{A9}', Status::Valid, 0, 0),
+    ('\u{AA}', Status::Mapped, 0, 1),
+    ('\u{AB}', Status::Valid, 0, 0),
+    ('\u{AD}', Status::Ignored, 0, 0),
+    ('\u{AE}', Status::Valid, 0, 0),
+    ('\u{AF}', Status::Mapped, 30, 33),
+    ('\u{B0}', Status::Valid, 0, 0),
+    ('\u{B2}', Status::Mapped, 33, 34),
+    ('\u{B3}', Status::Mapped, 34, 35),
+    ('\u{B4}', Status::Mapped, 35, 38),
+    ('\u{B5}', Status::Mapped, 38, 40),
+    ('\u{B6}', Status::Valid, 0, 0),
+    ('\u{B8}', Status::Mapped, 40, 43),
+    ('\u{B9}', Status::Mapped, 43, 44),
+    ('\u{BA}', Status::Mapped, 14, 15),
+    ('\u{BB}', Status::Valid, 0, 0),
+    ('\u{BC}', Status::Mapped, 44, 49),
+    ('\u{BD}', Status::Mapped, 49, 54),
+    ('\u{BE}', Status::Mapped, 54, 59),
+    ('\u{BF}', Status::Valid, 0, 0),
+    ('\u{C0}', Status::Mapped, 59, 61),
+    ('\u{C1}', Status::Mapped, 61, 63),
+    ('\u{C2}', Status::Mapped, 63, 65),
+    ('\u{C3}', Status::Mapped, 65, 67),
+    ('\u{C4}', Status::Mapped, 67, 69),
+    ('\u{C5}', Status::Mapped, 69, 71),
+    ('\u{C6}', Status::Mapped, 71, 73),
+    ('\u{C7}', Status::Mapped, 73, 75),
+    ('\u{C8}', Status::Mapped, 75, 77),
+    ('\u{C9}', Status::Mapped, 77, 79),
+    ('\u{CA}', Status::Mapped, 79, 81),
+    ('\u{CB}', Status::Mapped, 81, 83),
+    ('\u{CC}', Status::Mapped, 83, 85),
+    ('\u{CD}', Status::Mapped, 85, 87),
+    ('\u{CE}', Status::Mapped, 87, 89),
+    ('\u{CF}', Status::Mapped, 89, 91),
+    ('\u{D0}', Status::Mapped, 91, 93),
+    ('\u{D1}', Status::Mapped, 93, 95),
+    ('\u{D2}', Status::Mapped, 95, 97),
+    ('\u{D3}', Status::Mapped, 97, 99),
+    ('\u{D4}', Status::Mapped, 99, 101),
+    ('\u{D5}', Status::Mapped, 101, 103),
+    ('\u{D6}', Status::Mapped, 103, 105),
+    ('\u{D7}', Status::Valid, 0, 0),
+    ('\u{D8}', Status::Mapped, 105, 107),
+    ('\u{D9}', Status::Mapped, 107, 109),
+    ('\u{DA}', Status::Mapped, 109, 111),
+    ('\u{DB}', Status::Mapped, 111, 113),
+    ('\u{DC}', Status::Mapped, 113, 115),
+    ('\u{DD}', Status::Mapped, 115, 117),
+    ('\u{DE}', Status::Mapped, 117, 119),
+    ('\u{DF}', Status::Deviation, 0, 0),
+    ('\u{E0}', Status::Valid, 0, 0),
+    ('\u{100}', Status::Mapped, 119, 121),
+    ('\u{101}', Status::Valid, 0, 0),
+    ('\u{102}', Status::Mapped, 121, 123),
+    ('\u{103}', Status::Valid, 0, 0),
+    ('\u{104}', Status::Mapped, 123, 125),
+    ('\u{105}', Status::Valid, 0, 0),
+    ('\u{106}', Status::Mapped, 125, 127),
+    ('\u{107}', Status::Valid, 0, 0),
+    ('\u{108}', Status::Mapped, 127, 129),
+    ('\u{109}', Status::Valid, 0, 0),
+    ('\u{10A}', Status::Mapped, 129, 131),
+    ('\u{10B}', Status::Valid, 0, 0),
+    ('\u{10C}', Status::Mapped, 131, 133),
+    ('\u{10D}', Status::Valid, 0, 0),
+    ('\u{10E}', Status::Mapped, 133, 135),
+    ('\u{10F}', Status::Valid, 0, 0),
+    ('\u{110}', Status::Mapped, 135, 137),
+    ('\u{111}', Status::Valid, 0, 0),
+    ('\u{112}', Status::Mapped, 137, 139),
+    ('\u{113}', Status::Valid, 0, 0),
+    ('\u{114}', Status::Mapped, 139, 141),
+    ('\u{115}', Status::Valid, 0, 0),
+    ('\u{116}', Status::Mapped, 141, 143),
+    ('\u{117}', Status::Valid, 0, 0),
+    ('\u{118}', Status::Mapped, 143, 145),
+    ('\u{119}', Status::Valid, 0, 0),
+    ('\u{11A}', Status::Mapped, 145, 147),
+    ('\u{11B}', Status::Valid, 0, 0),
+    ('\u{11C}', Status::Mapped, 147, 149),
+    ('\u{11D}', Status::Valid, 0, 0),
+    ('\u{11E}', Status::Mapped, 149, 151),
+    ('\u{11F}', Status::Valid, 0, 0),
+    ('\u{120}', Status::Mapped, 151, 153),
+    ('\u{121}', Status::Valid, 0, 0),
+    ('\u{122}', Status::Mapped, 153, 155),
+    ('\u{123}', Status::Valid, 0, 0),
+    ('\u{124}', Status::Mapped, 155, 157),
+    ('\u{125}', Status::Valid, 0, 0),
+    ('\u{126}', Status::Mapped, 157, 159),
+    ('\u{127}', Status::Valid, 0, 0),
+    ('\u{128}', Status::Mapped, 159, 161),
+    ('\u{129}', Status::Valid, 0, 0),
+    ('\u{12A}', Status::Mapped, 161, 163),
+    ('\u{12B}', Status::Valid, 0, 0),
+    ('\u{12C}', Status::Mapped, 163, 165),
+    ('\u{12D}', Status::Valid, 0, 0),
+    ('\u{12E}', Status::Mapped, 165, 167),
+    ('\u{12F}', Status::Valid, 0, 0),
+    ('\u{130}', Status::Mapped, 167, 170),
+    ('\u{131}', Status::Valid, 0, 0),
+    ('\u{132}', Status::Mapped, 170, 172),
+    ('\u{134}', Status::Mapped, 172, 174),
+    ('\u{135}', Status::Valid, 0, 0),
+    ('\u{136}', Status::Mapped, 174, 176),
+    ('\u{137}', Status::Valid, 0, 0),
+    ('\u{139}', Status::Mapped, 176, 178),
+    ('\u{13A}', Status::Valid, 0, 0),
+    ('\u{13B}', Status::Mapped, 178, 180),
+    ('\u{13C}', Status::Valid, 0, 0),
+    ('\u{13D}', Status::Mapped, 180, 182),
+    ('\u{13E}', Status::Valid, 0, 0),
+    ('\u{13F}', Status::Mapped, 182, 185),
+    ('\u{141}', Status::Mapped, 185, 187),
+    ('\u{142}', Status::Valid, 0, 0),
+    ('\u{143}', Status::Mapped, 187, 189),
+    ('\u{144}', Status::Valid, 0, 0),
+    ('\u{145}', Status::Mapped, 189, 191),
+    ('\u{146}', Status::Valid, 0, 0),
+    ('\u{147}', Status::Mapped, 191, 193),
+    ('\u{148}', Status::Valid, 0, 0),
+    ('\u{149}', Status::Mapped, 193, 196),
+    ('\u{14A}', Status::Mapped, 196, 198),
+    ('\u{14B}', Status::Valid, 0, 0),
+    ('\u{14C}', Status::Mapped, 198, 200),
+    ('\u{14D}', Status::Valid, 0, 0),
+    ('\u{14E}', Status::Mapped, 200, 202),
+    ('\u{14F}', Status::Valid, 0, 0),
+    ('\u{150}', Status::Mapped, 202, 204),
+    ('\u{151}', Status::Valid, 0, 0),
+    ('\u{152}', Status::Mapped, 204, 206),
+    ('\u{153}', Status::Valid, 0, 0),
+    ('\u{154}', Status::Mapped, 206, 208),
+    ('\u{155}', Status::Valid, 0, 0),
+    ('\u{156}', Status::Mapped, 208, 210),
+    ('\u{157}', Status::Valid, 0, 0),
+    ('\u{158}', Status::Mapped, 210, 212),
+    ('\u{159}', Status::Valid, 0, 0),
+    ('\u{15A}', Status::Mapped, 212, 214),
+    ('\u{15B}', Status::Valid, 0, 0),
+    ('\u{15C}', Status::Mapped, 214, 216),
+    ('\u{15D}', Status::Valid, 0, 0),
+    ('\u{15E}', Status::Mapped, 216, 218),
+    ('\u{15F}', Status::Valid, 0, 0),
+    ('\u{160}', Status::Mapped, 218, 220),
+    ('\u{161}', Status::Valid, 0, 0),
+    ('\u{162}', Status::Mapped, 220, 222),
+    ('\u{163}', Status::Valid, 0, 0),
+    ('\u{164}', Status::Mapped, 222, 224),
+    ('\u{165}', Status::Valid, 0, 0),
+    ('\u{166}', Status::Mapped, 224, 226),
+    ('\u{167}', Status::Valid, 0, 0),
+    ('\u{168}', Status::Mapped, 226, 228),
+    ('\u{169}', Status::Valid, 0, 0),
+    ('\u{16A}', Status::Mapped, 228, 230),
+    ('\u{16B}', Status::Valid, 0, 0),
+    ('\u{16C}', Status::Mapped, 230, 232),
+    ('\u{16D}', Status::Valid, 0, 0),
+    ('\u{16E}', Status::Mapped, 232, 234),
+    ('\u{16F}', Status::Valid, 0, 0),
+    ('\u{170}', Status::Mapped, 234, 236),
+    ('\u{171}', Status::Valid, 0, 0),
+    ('\u{172}', Status::Mapped, 236, 238),
+    ('\u{173}', Status::Valid, 0, 0),
+    ('\u{174}', Status::Mapped, 238, 240),
+    ('\u{175}', Status::Valid, 0, 0),
+    ('\u{176}', Status::Mapped, 240, 242),
+    ('\u{177}', Status::Valid, 0, 0),
+    ('\u{178}', Status::Mapped, 242, 244),
+    ('\u{179}', Status::Mapped, 244, 246),
+    ('\u{17A}', Status::Valid, 0, 0),
+    ('\u{17B}', Status::Mapped, 246, 248),
+    ('\u{17C}', Status::Valid, 0, 0),
+    ('\u{17D}', Status::Mapped, 248, 250),
+    ('\u{17E}', Status::Valid, 0, 0),
+    ('\u{17F}', Status::Mapped, 18, 19),
+    ('\u{180}', Status::Valid, 0, 0),
+    ('\u{181}', Status::Mapped, 250, 252),
+    ('\u{182}', Status::Mapped, 252, 254),
+    ('\u{183}', Status::Valid, 0, 0),
+    ('\u{184}', Status::Mapped, 254, 256),
+    ('\u{185}', Status::Valid, 0, 0),
+    ('\u{186}', Status::Mapped, 256, 258),
+    ('\u{187}', Status::Mapped, 258, 260),
+    ('\u{188}', Status::Valid, 0, 0),
+    ('\u{189}', Status::Mapped, 260, 262),
+    ('\u{18A}', Status::Mapped, 262, 264),
+    ('\u{18B}', Status::Mapped, 264, 266),
+    ('\u{18C}', Status::Valid, 0, 0),
+    ('\u{18E}', Status::Mapped, 266, 268),
+    ('\u{18F}', Status::Mapped, 268, 270),
+    ('\u{190}', Status::Mapped, 270, 272),
+    ('\u{191}', Status::Mapped, 272, 274),
+    ('\u{192}', Status::Valid, 0, 0),
+    ('\u{193}', Status::Mapped, 274, 276),
+    ('\u{194}', Status::Mapped, 276, 278),
+    ('\u{195}', Status::Valid, 0, 0),
+    ('\u{196}', Status::Mapped, 278, 280),
+    ('\u{197}', Status::Mapped, 280, 282),
+    ('\u{198}', Status::Mapped, 282, 284),
+    ('\u{199}', Status::Valid, 0, 0),
+    ('\u{19C}', Status::Mapped, 284, 286),
+    ('\u{19D}', Status::Mapped, 286, 288),
+    ('\u{19E}', Status::Valid, 0, 0),
+    ('\u{19F}', Status::Mapped, 288, 290),
+    ('\u{1A0}', Status::Mapped, 290, 292),
+    ('\u{1A1}', Status::Valid, 0, 0),
+    ('\u{1A2}', Status::Mapped, 292, 294),
+    ('\u{1A3}', Status::Valid, 0, 0),
+    ('\u{1A4}', Status::Mapped, 294, 296),
+    ('\u{1A5}', Status::Valid, 0, 0),
+    ('\u{1A6}', Status::Mapped, 296, 298),
+    ('\u{1A7}', Status::Mapped, 298, 300),
+    ('\u{1A8}', Status::Valid, 0, 0),
+    ('\u{1A9}', Status::Mapped, 300, 302),
+    ('\u{1AA}', Status::Valid, 0, 0),
+    ('\u{1AC}', Status::Mapped, 302, 304),
+    ('\u{1AD}', Status::Valid, 0, 0),
+    ('\u{1AE}', Status::Mapped, 304, 306),
+    ('\u{1AF}', Status::Mapped, 306, 308),
+    ('\u{1B0}', Status::Valid, 0, 0),
+    ('\u{1B1}', Status::Mapped, 308, 310),
+    ('\u{1B2}', Status::Mapped, 310, 312),
+    ('\u{1B3}', Status::Mapped, 312, 314),
+    ('\u{1B4}', Status::Valid, 0, 0),
+    ('\u{1B5}', Status::Mapped, 314, 316),
+    ('\u{1B6}', Status::Valid, 0, 0),
+    ('\u{1B7}', Status::Mapped, 316, 318),
+    ('\u{1B8}', Status::Mapped, 318, 320),
+    ('\u{1B9}', Status::Valid, 0, 0),
+    ('\u{1BC}', Status::Mapped, 320, 322),
+    ('\u{1BD}', Status::Valid, 0, 0),
+    ('\u{1C4}', Status::Mapped, 322, 325),
+    ('\u{1C7}', Status::Mapped, 325, 327),
+    ('\u{1CA}', Status::Mapped, 327, 329),
+    ('\u{1CD}', Status::Mapped, 329, 331),
+    ('\u{1CE}', Status::Valid, 0, 0),
+    ('\u{1CF}', Status::Mapped, 331, 333),
+    ('\u{1D0}', Status::Valid, 0, 0),
+    ('\u{1D1}', Status::Mapped, 333, 335),
+    ('\u{1D2}', Status::Valid, 0, 0),
+    ('\u{1D3}', Status::Mapped, 335, 337),
+    ('\u{1D4}', Status::Valid, 0, 0),
+    ('\u{1D5}', Status::Mapped, 337, 339),
+    ('\u{1D6}', Status::Valid, 0, 0),
+    ('\u{1D7}', Status::Mapped, 339, 341),
+    ('\u{1D8}', Status::Valid, 0, 0),
+    ('\u{1D9}', Status::Mapped, 341, 343),
+    ('\u{1DA}', Status::Valid, 0, 0),
+    ('\u{1DB}', Status::Mapped, 343, 345),
+    ('\u{1DC}', Status::Valid, 0, 0),
+    ('\u{1DE}', Status::Mapped, 345, 347),
+    ('\u{1DF}', Status::Valid, 0, 0),
+    ('\u{1E0}', Status::Mapped, 347, 349),
+    ('\u{1E1}', Status::Valid, 0, 0),
+    ('\u{1E2}', Status::Mapped, 349, 351),
+    ('\u{1E3}', Status::Valid, 0, 0),
+    ('\u{1E4}', Status::Mapped, 351, 353),
+    ('\u{1E5}', Status::Valid, 0, 0),
+    ('\u{1E6}', Status::Mapped, 353, 355),
+    ('\u{1E7}', Status::Valid, 0, 0),
+    ('\u{1E8}', Status::Mapped, 355, 357),
+    ('\u{1E9}', Status::Valid, 0, 0),
+    ('\u{1EA}', Status::Mapped, 357, 359),
+    ('\u{1EB}', Status::Valid, 0, 0),
+    ('\u{1EC}', Status::Mapped, 359, 361),
+    ('\u{1ED}', Status::Valid, 0, 0),
+    ('\u{1EE}', Status::Mapped, 361, 363),
+    ('\u{1EF}', Status::Valid, 0, 0),
+    ('\u{1F1}', Status::Mapped, 363, 365),
+    ('\u{1F4}', Status::Mapped, 365, 367),
+    ('\u{1F5}', Status::Valid, 0, 0),
+    ('\u{1F6}', Status::Mapped, 367, 369),
+    ('\u{1F7}', Status::Mapped, 369, 371),
+    ('\u{1F8}', Status::Mapped, 371, 373),
+    ('\u{1F9}', Status::Valid, 0, 0),
+    ('\u{1FA}', Status::Mapped, 373, 375),
+    ('\u{1FB}', Status::Valid, 0, 0),
+    ('\u{1FC}', Status::Mapped, 375, 377),
+    ('\u{1FD}', Status::Valid, 0, 0),
+    ('\u{1FE}', Status::Mapped, 377, 379),
+    ('\u{1FF}', Status::Valid, 0, 0),
+    ('\u{200}', Status::Mapped, 379, 381),
+    ('\u{201}', Status::Valid, 0, 0),
+    ('\u{202}', Status::Mapped, 381, 383),
+    ('\u{203}', Status::Valid, 0, 0),
+    ('\u{204}', Status::Mapped, 383, 385),
+    ('\u{205}', Status::Valid, 0, 0),
+    ('\u{206}', Status::Mapped, 385, 387),
+    ('\u{207}', Status::Valid, 0, 0),
+    ('\u{208}', Status::Mapped, 387, 389),
+    ('\u{209}', Status::Valid, 0, 0),
+    ('\u{20A}', Status::Mapped, 389, 391),
+    ('\u{20B}', Status::Valid, 0, 0),
+    ('\u{20C}', Status::Mapped, 391, 393),
+    ('\u{20D}', Status::Valid, 0, 0),
+    ('\u{20E}', Status::Mapped, 393, 395),
+    ('\u{20F}', Status::Valid, 0, 0),
+    ('\u{210}', Status::Mapped, 395, 397),
+    ('\u{211}', Status::Valid, 0, 0),
+    ('\u{212}', Status::Mapped, 397, 399),
+    ('\u{213}', Status::Valid, 0, 0),
+    ('\u{214}', Status::Mapped, 399, 401),
+    ('\u{215}', Status::Valid, 0, 0),
+    ('\u{216}', Status::Mapped, 401, 403),
+    ('\u{217}', Status::Valid, 0, 0),
+    ('\u{218}', Status::Mapped, 403, 405),
+    ('\u{219}', Status::Valid, 0, 0),
+    ('\u{21A}', Status::Mapped, 405, 407),
+    ('\u{21B}', Status::Valid, 0, 0),
+    ('\u{21C}', Status::Mapped, 407, 409),
+    ('\u{21D}', Status::Valid, 0, 0),
+    ('\u{21E}', Status::Mapped, 409, 411),
+    ('\u{21F}', Status::Valid, 0, 0),
+    ('\u{220}', Status::Mapped, 411, 413),
+    ('\u{221}', Status::Valid, 0, 0),
+    ('\u{222}', Status::Mapped, 413, 415),
+    ('\u{223}', Status::Valid, 0, 0),
+    ('\u{224}', Status::Mapped, 415, 417),
+    ('\u{225}', Status::Valid, 0, 0),
+    ('\u{226}', Status::Mapped, 417, 419),
+    ('\u{227}', Status::Valid, 0, 0),
+    ('\u{228}', Status::Mapped, 419, 421),
+    ('\u{229}', Status::Valid, 0, 0),
+    ('\u{22A}', Status::Mapped, 421, 423),
+    ('\u{22B}', Status::Valid, 0, 0),
+    ('\u{22C}', Status::Mapped, 423, 425),
+    ('\u{22D}', Status::Valid, 0, 0),
+    ('\u{22E}', Status::Mapped, 425, 427),
+    ('\u{22F}', Status::Valid, 0, 0),
+    ('\u{230}', Status::Mapped, 427, 429),
+    ('\u{231}', Status::Valid, 0, 0),
+    ('\u{232}', Status::Mapped, 429, 431),
+    ('\u{233}', Status::Valid, 0, 0),
+    ('\u{23A}', Status::Mapped, 431, 434),
+    ('\u{23B}', Status::Mapped, 434, 436),
+    ('\u{23C}', Status::Valid, 0, 0),
+    ('\u{23D}', Status::Mapped, 436, 438),
+    ('\u{23E}', Status::Mapped, 438, 441),
+    ('\u{23F}', Status::Valid, 0, 0),
+    ('\u{241}', Status::Mapped, 441, 443),
+    ('\u{242}', Status::Valid, 0, 0),
+    ('\u{243}', Status::Mapped, 443, 445),
+    ('\u{244}', Status::Mapped, 445, 447),
+    ('\u{245}', Status::Mapped, 447, 449),
+    ('\u{246}', Status::Mapped, 449, 451),
+    ('\u{247}', Status::Valid, 0, 0),
+    ('\u{248}', Status::Mapped, 451, 453),
+    ('\u{249}', Status::Valid, 0, 0),
+    ('\u{24A}', Status::Mapped, 453, 455),
+    ('\u{24B}', Status::Valid, 0, 0),
+    ('\u{24C}', Status::Mapped, 455, 457),
+    ('\u{24D}', Status::Valid, 0, 0),
+    ('\u{24E}', Status::Mapped, 457, 459),
+    ('\u{24F}', Status::Valid, 0, 0),
+    ('\u{2B0}', Status::Mapped, 7, 8),
+    ('\u{2B1}', Status::Mapped, 459, 461),
+    ('\u{2B2}', Status::Mapped, 9, 10),
+    ('\u{2B3}', Status::Mapped, 17, 18),
+    ('\u{2B4}', Status::Mapped, 461, 463),
+    ('\u{2B5}', Status::Mapped, 463, 465),
+    ('\u{2B6}', Status::Mapped, 465, 467),
+    ('\u{2B7}', Status::Mapped, 22, 23),
+    ('\u{2B8}', Status::Mapped, 24, 25),
+    ('\u{2B9}', Status::Valid, 0, 0),
+    ('\u{2D8}', Status::Mapped, 467, 470),
+    ('\u{2D9}', Status::Mapped, 470, 473),
+    ('\u{2DA}', Status::Mapped, 473, 476),
+    ('\u{2DB}', Status::Mapped, 476, 479),
+    ('\u{2DC}', Status::Mapped, 479, 482),
+    ('\u{2DD}', Status::Mapped, 482, 485),
+    ('\u{2DE}', Status::Valid, 0, 0),
+    ('\u{2E0}', Status::Mapped, 276, 278),
+    ('\u{2E1}', Status::Mapped, 11, 12),
+    ('\u{2E2}', Status::Mapped, 18, 19),
+    ('\u{2E3}', Status::Mapped, 23, 24),
+    ('\u{2E4}', Status::Mapped, 485, 487),
+    ('\u{2E5}', Status::Valid, 0, 0),
+    ('\u{340}', Status::Mapped, 487, 489),
+    ('\u{341}', Status::Mapped, 489, 491),
+    ('\u{342}', Status::Valid, 0, 0),
+    ('\u{343}', Status::Mapped, 491, 493),
+    ('\u{344}', Status::Mapped, 493, 497),
+    ('\u{345}', Status::Mapped, 497, 499),
+    ('\u{346}', Status::Valid, 0, 0),
+    ('\u{34F}', Status::Ignored, 0, 0),
+    ('\u{350}', Status::Valid, 0, 0),
+    ('\u{370}', Status::Mapped, 499, 501),
+    ('\u{371}', Status::Valid, 0, 0),
+    ('\u{372}', Status::Mapped, 501, 503),
+    ('\u{373}', Status::Valid, 0, 0),
+    ('\u{374}', Status::Mapped, 503, 505),
+    ('\u{375}', Status::Valid, 0, 0),
+    ('\u{376}', Status::Mapped, 505, 507),
+    ('\u{377}', Status::Valid, 0, 0),
+    ('\u{378}', Status::Disallowed, 0, 0),
+    ('\u{37A}', Status::Mapped, 507, 510),
+    ('\u{37B}', Status::Valid, 0, 0),
+    ('\u{37E}', Status::Mapped, 510, 511),
+    ('\u{37F}', Status::Mapped, 511, 513),
+    ('\u{380}', Status::Disallowed, 0, 0),
+    ('\u{384}', Status::Mapped, 35, 38),
+    ('\u{385}', Status::Mapped, 513, 518),
+    ('\u{386}', Status::Mapped, 518, 520),
+    ('\u{387}', Status::Mapped, 520, 522),
+    ('\u{388}', Status::Mapped, 522, 524),
+    ('\u{389}', Status::Mapped, 524, 526),
+    ('\u{38A}', Status::Mapped, 526, 528),
+    ('\u{38B}', Status::Disallowed, 0, 0),
+    ('\u{38C}', Status::Mapped, 528, 530),
+    ('\u{38D}', Status::Disallowed, 0, 0),
+    ('\u{38E}', Status::Mapped, 530, 532),
+    ('\u{38F}', Status::Mapped, 532, 534),
+    ('\u{390}', Status::Valid, 0, 0),
+    ('\u{391}', Status::Mapped, 534, 536),
+    ('\u{392}', Status::Mapped, 536, 538),
+    ('\u{393}', Status::Mapped, 538, 540),
+    ('\u{394}', Status::Mapped, 540, 542),
+    ('\u{395}', Status::Mapped, 542, 544),
+    ('\u{396}', Status::Mapped, 544, 546),
+    ('\u{397}', Status::Mapped, 546, 548),
+    ('\u{398}', Status::Mapped, 548, 550),
+    ('\u{399}', Status::Mapped, 497, 499),
+    ('\u{39A}', Status::Mapped, 550, 552),
+    ('\u{39B}', Status::Mapped, 552, 554),
+    ('\u{39C}', Status::Mapped, 38, 40),
+    ('\u{39D}', Status::Mapped, 554, 556),
+    ('\u{39E}', Status::Mapped, 556, 558),
+    ('\u{39F}', Status::Mapped, 558, 560),
+    ('\u{3A0}', Status::Mapped, 560, 562),
+    ('\u{3A1}', Status::Mapped, 562, 564),
+    ('\u{3A2}', Status::Disallowed, 0, 0),
+    ('\u{3A3}', Status::Mapped, 564, 566),
+    ('\u{3A4}', Status::Mapped, 566, 568),
+    ('\u{3A5}', Status::Mapped, 568, 570),
+    ('\u{3A6}', Status::Mapped, 570, 572),
+    ('\u{3A7}', Status::Mapped, 572, 574),
+    ('\u{3A8}', Status::Mapped, 574, 576),
+    ('\u{3A9}', Status::Mapped, 576, 578),
+    ('\u{3AA}', Status::Mapped, 578, 580),
+    ('\u{3AB}', Status::Mapped, 580, 582),
+    ('\u{3AC}', Status::Valid, 0, 0),
+    ('\u{3C2}', Status::Deviation, 0, 0),
+    ('\u{3C3}', Status::Valid, 0, 0),
+    ('\u{3CF}', Status::Mapped, 582, 584),
+    ('\u{3D0}', Status::Mapped, 536, 538),
+    ('\u{3D1}', Status::Mapped, 548, 550),
+    ('\u{3D2}', Status::Mapped, 568, 570),
+    ('\u{3D3}', Status::Mapped, 530, 532),
+    ('\u{3D4}', Status::Mapped, 580, 582),
+    ('\u{3D5}', Status::Mapped, 570, 572),
+    ('\u{3D6}', Status::Mapped, 560, 562),
+    ('\u{3D7}', Status::Valid, 0, 0),
+    ('\u{3D8}', Status::Mapped, 584, 586),
+    ('\u{3D9}', Status::Valid, 0, 0),
+    ('\u{3DA}', Status::Mapped, 586, 588),
+    ('\u{3DB}', Status::Valid, 0, 0),
+    ('\u{3DC}', Status::Mapped, 588, 590),
+    ('\u{3DD}', Status::Valid, 0, 0),
+    ('\u{3DE}', Status::Mapped, 590, 592),
+    ('\u{3DF}', Status::Valid, 0, 0),
+    ('\u{3E0}', Status::Mapped, 592, 594),
+    ('\u{3E1}', Status::Valid, 0, 0),
+    ('\u{3E2}', Status::Mapped, 594, 596),
+    ('\u{3E3}', Status::Valid, 0, 0),
+    ('\u{3E4}', Status::Mapped, 596, 598),
+    ('\u{3E5}', Status::Valid, 0, 0),
+    ('\u{3E6}', Status::Mapped, 598, 600),
+    ('\u{3E7}', Status::Valid, 0, 0),
+    ('\u{3E8}', Status::Mapped, 600, 602),
+    ('\u{3E9}', Status::Valid, 0, 0),
+    ('\u{3EA}', Status::Mapped, 602, 604),
+    ('\u{3EB}', Status::Valid, 0, 0),
+    ('\u{3EC}', Status::Mapped, 604, 606),
+    ('\u{3ED}', Status::Valid, 0, 0),
+    ('\u{3EE}', Status::Mapped, 606, 608),
+    ('\u{3EF}', Status::Valid, 0, 0),
+    ('\u{3F0}', Status::Mapped, 550, 552),
+    ('\u{3F1}', Status::Mapped, 562, 564),
+    ('\u{3F2}', Status::Mapped, 564, 566),
+    ('\u{3F3}', Status::Valid, 0, 0),
+    ('\u{3F4}', Status::Mapped, 548, 550),
+    ('\u{3F5}', Status::Mapped, 542, 544),
+    ('\u{3F6}', Status::Valid, 0, 0),
+    ('\u{3F7}', Status::Mapped, 608, 610),
+    ('\u{3F8}', Status::Valid, 0, 0),
+    ('\u{3F9}', Status::Mapped, 564, 566),
+    ('\u{3FA}', Status::Mapped, 610, 612),
+    ('\u{3FB}', Status::Valid, 0, 0),
+    ('\u{3FD}', Status::Mapped, 612, 614),
+    ('\u{3FE}', Status::Mapped, 614, 616),
+    ('\u{3FF}', Status::Mapped, 616, 618),
+    ('\u{400}', Status::Mapped, 618, 620),
+    ('\u{401}', Status::Mapped, 620, 622),
+    ('\u{402}', Status::Mapped, 622, 624),
+    ('\u{403}', Status::Mapped, 624, 626),
+    ('\u{404}', Status::Mapped, 626, 628),
+    ('\u{405}', Status::Mapped, 628, 630),
+    ('\u{406}', Status::Mapped, 630, 632),
+    ('\u{407}', Status::Mapped, 632, 634),
+    ('\u{408}', Status::Mapped, 634, 636),
+    ('\u{409}', Status::Mapped, 636, 638),
+    ('\u{40A}', Status::Mapped, 638, 640),
+    ('\u{40B}', Status::Mapped, 640, 642),
+    ('\u{40C}', Status::Mapped, 642, 644),
+    ('\u{40D}', Status::Mapped, 644, 646),
+    ('\u{40E}', Status::Mapped, 646, 648),
+    ('\u{40F}', Status::Mapped, 648, 650),
+    ('\u{410}', Status::Mapped, 650, 652),
+    ('\u{411}', Status::Mapped, 652, 654),
+    ('\u{412}', Status::Mapped, 654, 656),
+    ('\u{413}', Status::Mapped, 656, 658),
+    ('\u{414}', Status::Mapped, 658, 660),
+    ('\u{415}', Status::Mapped, 660, 662),
+    ('\u{416}', Status::Mapped, 662, 664),
+    ('\u{417}', Status::Mapped, 664, 666),
+    ('\u{418}', Status::Mapped, 666, 668),
+    ('\u{419}', Status::Mapped, 668, 670),
+    ('\u{41A}', Status::Mapped, 670, 672),
+    ('\u{41B}', Status::Mapped, 672, 674),
+    ('\u{41C}', Status::Mapped, 674, 676),
+    ('\u{41D}', Status::Mapped, 676, 678),
+    ('\u{41E}', Status::Mapped, 678, 680),
+    ('\u{41F}', Status::Mapped, 680, 682),
+    ('\u{420}', Status::Mapped, 682, 684),
+    ('\u{421}', Status::Mapped, 684, 686),
+    ('\u{422}', Status::Mapped, 686, 688),
+    ('\u{423}', Status::Mapped, 688, 690),
+    ('\u{424}', Status::Mapped, 690, 692),
+    ('\u{425}', Status::Mapped, 692, 694),
+    ('\u{426}', Status::Mapped, 694, 696),
+    ('\u{427}', Status::Mapped, 696, 698),
+    ('\u{428}', Status::Mapped, 698, 700),
+    ('\u{429}', Status::Mapped, 700, 702),
+    ('\u{42A}', Status::Mapped, 702, 704),
+    ('\u{42B}', Status::Mapped, 704, 706),
+    ('\u{42C}', Status::Mapped, 706, 708),
+    ('\u{42D}', Status::Mapped, 708, 710),
+    ('\u{42E}', Status::Mapped, 710, 712),
+    ('\u{42F}', Status::Mapped, 712, 714),
+    ('\u{430}', Status::Valid, 0, 0),
+    ('\u{460}', Status::Mapped, 714, 716),
+    ('\u{461}', Status::Valid, 0, 0),
+    ('\u{462}', Status::Mapped, 716, 718),
+    ('\u{463}', Status::Valid, 0, 0),
+    ('\u{464}', Status::Mapped, 718, 720),
+    ('\u{465}', Status::Valid, 0, 0),
+    ('\u{466}', Status::Mapped, 720, 722),
+    ('\u{467}', Status::Valid, 0, 0),
+    ('\u{468}', Status::Mapped, 722, 724),
+    ('\u{469}', Status::Valid, 0, 0),
+    ('\u{46A}', Status::Mapped, 724, 726),
+    ('\u{46B}', Status::Valid, 0, 0),
+    ('\u{46C}', Status::Mapped, 726, 728),
+    ('\u{46D}', Status::Valid, 0, 0),
+    ('\u{46E}', Status::Mapped, 728, 730),
+    ('\u{46F}', Status::Valid, 0, 0),
+    ('\u{470}', Status::Mapped, 730, 732),
+    ('\u{471}', Status::Valid, 0, 0),
+    ('\u{472}', Status::Mapped, 732, 734),
+    ('\u{473}', Status::Valid, 0, 0),
+    ('\u{474}', Status::Mapped, 734, 736),
+    ('\u{475}', Status::Valid, 0, 0),
+    ('\u{476}', Status::Mapped, 736, 738),
+    ('\u{477}', Status::Valid, 0, 0),
+    ('\u{478}', Status::Mapped, 738, 740),
+    ('\u{479}', Status::Valid, 0, 0),
+    ('\u{47A}', Status::Mapped, 740, 742),
+    ('\u{47B}', Status::Valid, 0, 0),
+    ('\u{47C}', Status::Mapped, 742, 744),
+    ('\u{47D}', Status::Valid, 0, 0),
+    ('\u{47E}', Status::Mapped, 744, 746),
+    ('\u{47F}', Status::Valid, 0, 0),
+    ('\u{480}', Status::Mapped, 746, 748),
+    ('\u{481}', Status::Valid, 0, 0),
+    ('\u{48A}', Status::Mapped, 748, 750),
+    ('\u{48B}', Status::Valid, 0, 0),
+    ('\u{48C}', Status::Mapped, 750, 752),
+    ('\u{48D}', Status::Valid, 0, 0),
+    ('\u{48E}', Status::Mapped, 752, 754),
+    ('\u{48F}', Status::Valid, 0, 0),
+    ('\u{490}', Status::Mapped, 754, 756),
+    ('\u{491}', Status::Valid, 0, 0),
+    ('\u{492}', Status::Mapped, 756, 758),
+    ('\u{493}', Status::Valid, 0, 0),
+    ('\u{494}', Status::Mapped, 758, 760),
+    ('\u{495}', Status::Valid, 0, 0),
+    ('\u{496}', Status::Mapped, 760, 762),
+    ('\u{497}', Status::Valid, 0, 0),
+    ('\u{498}', Status::Mapped, 762, 764),
+    ('\u{499}', Status::Valid, 0, 0),
+    ('\u{49A}', Status::Mapped, 764, 766),
+    ('\u{49B}', Status::Valid, 0, 0),
+    ('\u{49C}', Status::Mapped, 766, 768),
+    ('\u{49D}', Status::Valid, 0, 0),
+    ('\u{49E}', Status::Mapped, 768, 770),
+    ('\u{49F}', Status::Valid, 0, 0),
+    ('\u{4A0}', Status::Mapped, 770, 772),
+    ('\u{4A1}', Status::Valid, 0, 0),
+    ('\u{4A2}', Status::Mapped, 772, 774),
+    ('\u{4A3}', Status::Valid, 0, 0),
+    ('\u{4A4}', Status::Mapped, 774, 776),
+    ('\u{4A5}', Status::Valid, 0, 0),
+    ('\u{4A6}', Status::Mapped, 776, 778),
+    ('\u{4A7}', Status::Valid, 0, 0),
+    ('\u{4A8}', Status::Mapped, 778, 780),
+    ('\u{4A9}', Status::Valid, 0, 0),
+    ('\u{4AA}', Status::Mapped, 780, 782),
+    ('\u{4AB}', Status::Valid, 0, 0),
+    ('\u{4AC}', Status::Mapped, 782, 784),
+    ('\u{4AD}', Status::Valid, 0, 0),
+    ('\u{4AE}', Status::Mapped, 784, 786),
+    ('\u{4AF}', Status::Valid, 0, 0),
+    ('\u{4B0}', Status::Mapped, 786, 788),
+    ('\u{4B1}', Status::Valid, 0, 0),
+    ('\u{4B2}', Status::Mapped, 788, 790),
+    ('\u{4B3}', Status::Valid, 0, 0),
+    ('\u{4B4}', Status::Mapped, 790, 792),
+    ('\u{4B5}', Status::Valid, 0, 0),
+    ('\u{4B6}', Status::Mapped, 792, 794),
+    ('\u{4B7}', Status::Valid, 0, 0),
+    ('\u{4B8}', Status::Mapped, 794, 796),
+    ('\u{4B9}', Status::Valid, 0, 0),
+    ('\u{4BA}', Status::Mapped, 796, 798),
+    ('\u{4BB}', Status::Valid, 0, 0),
+    ('\u{4BC}', Status::Mapped, 798, 800),
+    ('\u{4BD}', Status::Valid, 0, 0),
+    ('\u{4BE}', Status::Mapped, 800, 802),
+    ('\u{4BF}', Status::Valid, 0, 0),
+    ('\u{4C0}', Status::Disallowed, 0, 0),
+    ('\u{4C1}', Status::Mapped, 802, 804),
+    ('\u{4C2}', Status::Valid, 0, 0),
+    ('\u{4C3}', Status::Mapped, 804, 806),
+    ('\u{4C4}', Status::Valid, 0, 0),
+    ('\u{4C5}', Status::Mapped, 806, 808),
+    ('\u{4C6}', Status::Valid, 0, 0),
+    ('\u{4C7}', Status::Mapped, 808, 810),
+    ('\u{4C8}', Status::Valid, 0, 0),
+    ('\u{4C9}', Status::Mapped, 810, 812),
+    ('\u{4CA}', Status::Valid, 0, 0),
+    ('\u{4CB}', Status::Mapped, 812, 814),
+    ('\u{4CC}', Status::Valid, 0, 0),
+    ('\u{4CD}', Status::Mapped, 814, 816),
+    ('\u{4CE}', Status::Valid, 0, 0),
+    ('\u{4D0}', Status::Mapped, 816, 818),
+    ('\u{4D1}', Status::Valid, 0, 0),
+    ('\u{4D2}', Status::Mapped, 818, 820),
+    ('\u{4D3}', Status::Valid, 0, 0),
+    ('\u{4D4}', Status::Mapped, 820, 822),
+    ('\u{4D5}', Status::Valid, 0, 0),
+    ('\u{4D6}', Status::Mapped, 822, 824),
+    ('\u{4D7}', Status::Valid, 0, 0),
+    ('\u{4D8}', Status::Mapped, 824, 826),
+    ('\u{4D9}', Status::Valid, 0, 0),
+    ('\u{4DA}', Status::Mapped, 826, 828),
+    ('\u{4DB}', Status::Valid, 0, 0),
+    ('\u{4DC}', Status::Mapped, 828, 830),
+    ('\u{4DD}', Status::Valid, 0, 0),
+    ('\u{4DE}', Status::Mapped, 830, 832),
+    ('\u{4DF}', Status::Valid, 0, 0),
+    ('\u{4E0}', Status::Mapped, 832, 834),
+    ('\u{4E1}', Status::Valid, 0, 0),
+    ('\u{4E2}', Status::Mapped, 834, 836),
+    ('\u{4E3}', Status::Valid, 0, 0),
+    ('\u{4E4}', Status::Mapped, 836, 838),
+    ('\u{4E5}', Status::Valid, 0, 0),
+    ('\u{4E6}', Status::Mapped, 838, 840),
+    ('\u{4E7}', Status::Valid, 0, 0),
+    ('\u{4E8}', Status::Mapped, 840, 842),
+    ('\u{4E9}', Status::Valid, 0, 0),
+    ('\u{4EA}', Status::Mapped, 842, 844),
+    ('\u{4EB}', Status::Valid, 0, 0),
+    ('\u{4EC}', Status::Mapped, 844, 846),
+    ('\u{4ED}', Status::Valid, 0, 0),
+    ('\u{4EE}', Status::Mapped, 846, 848),
+    ('\u{4EF}', Status::Valid, 0, 0),
+    ('\u{4F0}', Status::Mapped, 848, 850),
+    ('\u{4F1}', Status::Valid, 0, 0),
+    ('\u{4F2}', Status::Mapped, 850, 852),
+    ('\u{4F3}', Status::Valid, 0, 0),
+    ('\u{4F4}', Status::Mapped, 852, 854),
+    ('\u{4F5}', Status::Valid, 0, 0),
+    ('\u{4F6}', Status::Mapped, 854, 856),
+    ('\u{4F7}', Status::Valid, 0, 0),
+    ('\u{4F8}', Status::Mapped, 856, 858),
+    ('\u{4F9}', Status::Valid, 0, 0),
+    ('\u{4FA}', Status::Mapped, 858, 860),
+    ('\u{4FB}', Status::Valid, 0, 0),
+    ('\u{4FC}', Status::Mapped, 860, 862),
+    ('\u{4FD}', Status::Valid, 0, 0),
+    ('\u{4FE}', Status::Mapped, 862, 864),
+    ('\u{4FF}', Status::Valid, 0, 0),
+    ('\u{500}', Status::Mapped, 864, 866),
+    ('\u{501}', Status::Valid, 0, 0),
+    ('\u{502}', Status::Mapped, 866, 868),
+    ('\u{503}', Status::Valid, 0, 0),
+    ('\u{504}', Status::Mapped, 868, 870),
+    ('\u{505}', Status::Valid, 0, 0),
+    ('\u{506}', Status::Mapped, 870, 872),
+    ('\u{507}', Status::Valid, 0, 0),
+    ('\u{508}', Status::Mapped, 872, 874),
+    ('\u{509}', Status::Valid, 0, 0),
+    ('\u{50A}', Status::Mapped, 874, 876),
+    ('\u{50B}', Status::Valid, 0, 0),
+    ('\u{50C}', Status::Mapped, 876, 878),
+    ('\u{50D}', Status::Valid, 0, 0),
+    ('\u{50E}', Status::Mapped, 878, 880),
+    ('\u{50F}', Status::Valid, 0, 0),
+    ('\u{510}', Status::Mapped, 880, 882),
+    ('\u{511}', Status::Valid, 0, 0),
+    ('\u{512}', Status::Mapped, 882, 884),
+    ('\u{513}', Status::Valid, 0, 0),
+    ('\u{514}', Status::Mapped, 884, 886),
+    ('\u{515}', Status::Valid, 0, 0),
+    ('\u{516}', Status::Mapped, 886, 888),
+    ('\u{517}', Status::Valid, 0, 0),
+    ('\u{518}', Status::Mapped, 888, 890),
+    ('\u{519}', Status::Valid, 0, 0),
+    ('\u{51A}', Status::Mapped, 890, 892),
+    ('\u{51B}', Status::Valid, 0, 0),
+    ('\u{51C}', Status::Mapped, 892, 894),
+    ('\u{51D}', Status::Valid, 0, 0),
+    ('\u{51E}', Status::Mapped, 894, 896),
+    ('\u{51F}', Status::Valid, 0, 0),
+    ('\u{520}', Status::Mapped, 896, 898),
+    ('\u{521}', Status::Valid, 0, 0),
+    ('\u{522}', Status::Mapped, 898, 900),
+    ('\u{523}', Status::Valid, 0, 0),
+    ('\u{524}', Status::Mapped, 900, 902),
+    ('\u{525}', Status::Valid, 0, 0),
+    ('\u{526}', Status::Mapped, 902, 904),
+    ('\u{527}', Status::Valid, 0, 0),
+    ('\u{528}', Status::Mapped, 904, 906),
+    ('\u{529}', Status::Valid, 0, 0),
+    ('\u{52A}', Status::Mapped, 906, 908),
+    ('\u{52B}', Status::Valid, 0, 0),
+    ('\u{52C}', Status::Mapped, 908, 910),
+    ('\u{52D}', Status::Valid, 0, 0),
+    ('\u{52E}', Status::Mapped, 910, 912),
+    ('\u{52F}', Status::Valid, 0, 0),
+    ('\u{530}', Status::Disallowed, 0, 0),
+    ('\u{531}', Status::Mapped, 912, 914),
+    ('\u{532}', Status::Mapped, 914, 916),
+    ('\u{533}', Status::Mapped, 916, 918),
+    ('\u{534}', Status::Mapped, 918, 920),
+    ('\u{535}', Status::Mapped, 920, 922),
+    ('\u{536}', Status::Mapped, 922, 924),
+    ('\u{537}', Status::Mapped, 924, 926),
+    ('\u{538}', Status::Mapped, 926, 928),
+    ('\u{539}', Status::Mapped, 928, 930),
+    ('\u{53A}', Status::Mapped, 930, 932),
+    ('\u{53B}', Status::Mapped, 932, 934),
+    ('\u{53C}', Status::Mapped, 934, 936),
+    ('\u{53D}', Status::Mapped, 936, 938),
+    ('\u{53E}', Status::Mapped, 938, 940),
+    ('\u{53F}', Status::Mapped, 940, 942),
+    ('\u{540}', Status::Mapped, 942, 944),
+    ('\u{541}', Status::Mapped, 944, 946),
+    ('\u{542}', Status::Mapped, 946, 948),
+    ('\u{543}', Status::Mapped, 948, 950),
+    ('\u{544}', Status::Mapped, 950, 952),
+    ('\u{545}', Status::Mapped, 952, 954),
+    ('\u{546}', Status::Mapped, 954, 956),
+    ('\u{547}', Status::Mapped, 956, 958),
+    ('\u{548}', Status::Mapped, 958, 960),
+    ('\u{549}', Status::Mapped, 960, 962),
+    ('\u{54A}', Status::Mapped, 962, 964),
+    ('\u{54B}', Status::Mapped, 964, 966),
+    ('\u{54C}', Status::Mapped, 966, 968),
+    ('\u{54D}', Status::Mapped, 968, 970),
+    ('\u{54E}', Status::Mapped, 970, 972),
+    ('\u{54F}', Status::Mapped, 972, 974),
+    ('\u{550}', Status::Mapped, 974, 976),
+    ('\u{551}', Status::Mapped, 976, 978),
+    ('\u{552}', Status::Mapped, 978, 980),
+    ('\u{553}', Status::Mapped, 980, 982),
+    ('\u{554}', Status::Mapped, 982, 984),
+    ('\u{555}', Status::Mapped, 984, 986),
+    ('\u{556}', Status::Mapped, 986, 988),
+    ('\u{557}', Status::Disallowed, 0, 0),
+    ('\u{559}', Status::Valid, 0, 0),
+    ('\u{587}', Status::Mapped, 988, 992),
+    ('\u{588}', Status::Valid, 0, 0),
+    ('\u{58B}', Status::Disallowed, 0, 0),
+    ('\u{58D}', Status::Valid, 0, 0),
+    ('\u{590}', Status::Disallowed, 0, 0),
+    ('\u{591}', Status::Valid, 0, 0),
+    ('\u{5C8}', Status::Disallowed, 0, 0),
+    ('\u{5D0}', Status::Valid, 0, 0),
+    ('\u{5EB}', Status::Disallowed, 0, 0),
+    ('\u{5EF}', Status::Valid, 0, 0),
+    ('\u{5F5}', Status::Disallowed, 0, 0),
+    ('\u{606}', Status::Valid, 0, 0),
+    ('\u{61C}', Status::Disallowed, 0, 0),
+    ('\u{61D}', Status::Valid, 0, 0),
+    ('\u{675}', Status::Mapped, 992, 996),
+    ('\u{676}', Status::Mapped, 996, 1000),
+    ('\u{677}', Status::Mapped, 1000, 1004),
+    ('\u{678}', Status::Mapped, 1004, 1008),
+    ('\u{679}', Status::Valid, 0, 0),
+    ('\u{6DD}', Status::Disallowed, 0, 0),
+    ('\u{6DE}', Status::Valid, 0, 0),
+    ('\u{70E}', Status::Disallowed, 0, 0),
+    ('\u{710}', Status::Valid, 0, 0),
+    ('\u{74B}', Status::Disallowed, 0, 0),
+    ('\u{74D}', Status::Valid, 0, 0),
+    ('\u{7B2}', Status::Disallowed, 0, 0),
+    ('\u{7C0}', Status::Valid, 0, 0),
+    ('\u{7FB}', Status::Disallowed, 0, 0),
+    ('\u{7FD}', Status::Valid, 0, 0),
+    ('\u{82E}', Status::Disallowed, 0, 0),
+    ('\u{830}', Status::Valid, 0, 0),
+    ('\u{83F}', Status::Disallowed, 0, 0),
+    ('\u{840}', Status::Valid, 0, 0),
+    ('\u{85C}', Status::Disallowed, 0, 0),
+    ('\u{85E}', Status::Valid, 0, 0),
+    ('\u{85F}', Status::Disallowed, 0, 0),
+    ('\u{860}', Status::Valid, 0, 0),
+    ('\u{86B}', Status::Disallowed, 0, 0),
+    ('\u{870}', Status::Valid, 0, 0),
+    ('\u{88F}', Status::Disallowed, 0, 0),
+    ('\u{898}', Status::Valid, 0, 0),
+    ('\u{8E2}', Status::Disallowed, 0, 0),
+    ('\u{8E3}', Status::Valid, 0, 0),
+    ('\u{958}', Status::Mapped, 1008, 1014),
+    ('\u{959}', Status::Mapped, 1014, 1020),
+    ('\u{95A}', Status::Mapped, 1020, 1026),
+    ('\u{95B}', Status::Mapped, 1026, 1032),
+    ('\u{95C}', Status::Mapped, 1032, 1038),
+    ('\u{95D}', Status::Mapped, 1038, 1044),
+    ('\u{95E}', Status::Mapped, 1044, 1050),
+    ('\u{95F}', Status::Mapped, 1050, 1056),
+    ('\u{960}', Status::Valid, 0, 0),
+    ('\u{984}', Status::Disallowed, 0, 0),
+    ('\u{985}', Status::Valid, 0, 0),
+    ('\u{98D}', Status::Disallowed, 0, 0),
+    ('\u{98F}', Status::Valid, 0, 0),
+    ('\u{991}', Status::Disallowed, 0, 0),
+    ('\u{993}', Status::Valid, 0, 0),
+    ('\u{9A9}', Status::Disallowed, 0, 0),
+    ('\u{9AA}', Status::Valid, 0, 0),
+    ('\u{9B1}', Status::Disallowed, 0, 0),
+    ('\u{9B2}', Status::Valid, 0, 0),
+    ('\u{9B3}', Status::Disallowed, 0, 0),
+    ('\u{9B6}', Status::Valid, 0, 0),
+    ('\u{9BA}', Status::Disallowed, 0, 0),
+    ('\u{9BC}', Status::Valid, 0, 0),
+    ('\u{9C5}', Status::Disallowed, 0, 0),
+    ('\u{9C7}', Status::Valid, 0, 0),
+    ('\u{9C9}', Status::Disallowed, 0, 0),
+    ('\u{9CB}', Status::Valid, 0, 0),
+    ('\u{9CF}', Status::Disallowed, 0, 0),
+    ('\u{9D7}', Status::Valid, 0, 0),
+    ('\u{9D8}', Status::Disallowed, 0, 0),
+    ('\u{9DC}', Status::Mapped, 1056, 1062),
+    ('\u{9DD}', Status::Mapped, 1062, 1068),
+    ('\u{9DE}', Status::Disallowed, 0, 0),
+    ('\u{9DF}', Status::Mapped, 1068, 1074),
+    ('\u{9E0}', Status::Valid, 0, 0),
+    ('\u{9E4}', Status::Disallowed, 0, 0),
+    ('\u{9E6}', Status::Valid, 0, 0),
+    ('\u{9FF}', Status::Disallowed, 0, 0),
+    ('\u{A01}', Status::Valid, 0, 0),
+    ('\u{A04}', Status::Disallowed, 0, 0),
+    ('\u{A05}', Status::Valid, 0, 0),
+    ('\u{A0B}', Status::Disallowed, 0, 0),
+    ('\u{A0F}', Status::Valid, 0, 0),
+    ('\u{A11}', Status::Disallowed, 0, 0),
+    ('\u{A13}', Status::Valid, 0, 0),
+    ('\u{A29}', Status::Disallowed, 0, 0),
+    ('\u{A2A}', Status::Valid, 0, 0),
+    ('\u{A31}', Status::Disallowed, 0, 0),
+    ('\u{A32}', Status::Valid, 0, 0),
+    ('\u{A33}', Status::Mapped, 1074, 1080),
+    ('\u{A34}', Status::Disallowed, 0, 0),
+    ('\u{A35}', Status::Valid, 0, 0),
+    ('\u{A36}', Status::Mapped, 1080, 1086),
+    ('\u{A37}', Status::Disallowed, 0, 0),
+    ('\u{A38}', Status::Valid, 0, 0),
+    ('\u{A3A}', Status::Disallowed, 0, 0),
+    ('\u{A3C}', Status::Valid, 0, 0),
+    ('\u{A3D}', Status::Disallowed, 0, 0),
+    ('\u{A3E}', Status::Valid, 0, 0),
+    ('\u{A43}', Status::Disallowed, 0, 0),
+    ('\u{A47}', Status::Valid, 0, 0),
+    ('\u{A49}', Status::Disallowed, 0, 0),
+    ('\u{A4B}', Status::Valid, 0, 0),
+    ('\u{A4E}', Status::Disallowed, 0, 0),
+    ('\u{A51}', Status::Valid, 0, 0),
+    ('\u{A52}', Status::Disallowed, 0, 0),
+    ('\u{A59}', Status::Mapped, 1086, 1092),
+    ('\u{A5A}', Status::Mapped, 1092, 1098),
+    ('\u{A5B}', Status::Mapped, 1098, 1104),
+    ('\u{A5C}', Status::Valid, 0, 0),
+    ('\u{A5D}', Status::Disallowed, 0, 0),
+    ('\u{A5E}', Status::Mapped, 1104, 1110),
+    ('\u{A5F}', Status::Disallowed, 0, 0),
+    ('\u{A66}', Status::Valid, 0, 0),
+    ('\u{A77}', Status::Disallowed, 0, 0),
+    ('\u{A81}', Status::Valid, 0, 0),
+    ('\u{A84}', Status::Disallowed, 0, 0),
+    ('\u{A85}', Status::Valid, 0, 0),
+    ('\u{A8E}', Status::Disallowed, 0, 0),
+    ('\u{A8F}', Status::Valid, 0, 0),
+    ('\u{A92}', Status::Disallowed, 0, 0),
+    ('\u{A93}', Status::Valid, 0, 0),
+    ('\u{AA9}', Status::Disallowed, 0, 0),
+    ('\u{AAA}', Status::Valid, 0, 0),
+    ('\u{AB1}', Status::Disallowed, 0, 0),
+    ('\u{AB2}', Status::Valid, 0, 0),
+    ('\u{AB4}', Status::Disallowed, 0, 0),
+    ('\u{AB5}', Status::Valid, 0, 0),
+    ('\u{ABA}', Status::Disallowed, 0, 0),
+    ('\u{ABC}', Status::Valid, 0, 0),
+    ('\u{AC6}', Status::Disallowed, 0, 0),
+    ('\u{AC7}', Status::Valid, 0, 0),
+    ('\u{ACA}', Status::Disallowed, 0, 0),
+    ('\u{ACB}', Status::Valid, 0, 0),
+    ('\u{ACE}', Status::Disallowed, 0, 0),
+    ('\u{AD0}', Status::Valid, 0, 0),
+    ('\u{AD1}', Status::Disallowed, 0, 0),
+    ('\u{AE0}', Status::Valid, 0, 0),
+    ('\u{AE4}', Status::Disallowed, 0, 0),
+    ('\u{AE6}', Status::Valid, 0, 0),
+    ('\u{AF2}', Status::Disallowed, 0, 0),
+    ('\u{AF9}', Status::Valid, 0, 0),
+    ('\u{B00}', Status::Disallowed, 0, 0),
+    ('\u{B01}', Status::Valid, 0, 0),
+    ('\u{B04}', Status::Disallowed, 0, 0),
+    ('\u{B05}', Status::Valid, 0, 0),
+    ('\u{B0D}', Status::Disallowed, 0, 0),
+    ('\u{B0F}', Status::Valid, 0, 0),
+    ('\u{B11}', Status::Disallowed, 0, 0),
+    ('\u{B13}', Status::Valid, 0, 0),
+    ('\u{B29}', Status::Disallowed, 0, 0),
+    ('\u{B2A}', Status::Valid, 0, 0),
+    ('\u{B31}', Status::Disallowed, 0, 0),
+    ('\u{B32}', Status::Valid, 0, 0),
+    ('\u{B34}', Status::Disallowed, 0, 0),
+    ('\u{B35}', Status::Valid, 0, 0),
+    ('\u{B3A}', Status::Disallowed, 0, 0),
+    ('\u{B3C}', Status::Valid, 0, 0),
+    ('\u{B45}', Status::Disallowed, 0, 0),
+    ('\u{B47}', Status::Valid, 0, 0),
+    ('\u{B49}', Status::Disallowed, 0, 0),
+    ('\u{B4B}', Status::Valid, 0, 0),
+    ('\u{B4E}', Status::Disallowed, 0, 0),
+    ('\u{B55}', Status::Valid, 0, 0),
+    ('\u{B58}', Status::Disallowed, 0, 0),
+    ('\u{B5C}', Status::Mapped, 1110, 1116),
+    ('\u{B5D}', Status::Mapped, 1116, 1122),
+    ('\u{B5E}', Status::Disallowed, 0, 0),
+    ('\u{B5F}', Status::Valid, 0, 0),
+    ('\u{B64}', Status::Disallowed, 0, 0),
+    ('\u{B66}', Status::Valid, 0, 0),
+    ('\u{B78}', Status::Disallowed, 0, 0),
+    ('\u{B82}', Status::Valid, 0, 0),
+    ('\u{B84}', Status::Disallowed, 0, 0),
+    ('\u{B85}', Status::Valid, 0, 0),
+    ('\u{B8B}', Status::Disallowed, 0, 0),
+    ('\u{B8E}', Status::Valid, 0, 0),
+    ('\u{B91}', Status::Disallowed, 0, 0),
+    ('\u{B92}', Status::Valid, 0, 0),
+    ('\u{B96}', Status::Disallowed, 0, 0),
+    ('\u{B99}', Status::Valid, 0, 0),
+    ('\u{B9B}', Status::Disallowed, 0, 0),
+    ('\u{B9C}', Status::Valid, 0, 0),
+    ('\u{B9D}', Status::Disallowed, 0, 0),
+    ('\u{B9E}', Status::Valid, 0, 0),
+    ('\u{BA0}', Status::Disallowed, 0, 0),
+    ('\u{BA3}', Status::Valid, 0, 0),
+    ('\u{BA5}', Status::Disallowed, 0, 0),
+    ('\u{BA8}', Status::Valid, 0, 0),
+    ('\u{BAB}', Status::Disallowed, 0, 0),
+    ('\u{BAE}', Status::Valid, 0, 0),
+    ('\u{BBA}', Status::Disallowed, 0, 0),
+    ('\u{BBE}', Status::Valid, 0, 0),
+    ('\u{BC3}', Status::Disallowed, 0, 0),
+    ('\u{BC6}', Status::Valid, 0, 0),
+    ('\u{BC9}', Status::Disallowed, 0, 0),
+    ('\u{BCA}', Status::Valid, 0, 0),
+    ('\u{BCE}', Status::Disallowed, 0, 0),
+    ('\u{BD0}', Status::Valid, 0, 0),
+    ('\u{BD1}', Status::Disallowed, 0, 0),
+    ('\u{BD7}', Status::Valid, 0, 0),
+    ('\u{BD8}', Status::Disallowed, 0, 0),
+    ('\u{BE6}', Status::Valid, 0, 0),
+    ('\u{BFB}', Status::Disallowed, 0, 0),
+    ('\u{C00}', Status::Valid, 0, 0),
+    ('\u{C0D}', Status::Disallowed, 0, 0),
+    ('\u{C0E}', Status::Valid, 0, 0),
+    ('\u{C11}', Status::Disallowed, 0, 0),
+    ('\u{C12}', Status::Valid, 0, 0),
+    ('\u{C29}', Status::Disallowed, 0, 0),
+    ('\u{C2A}', Status::Valid, 0, 0),
+    ('\u{C3A}', Status::Disallowed, 0, 0),
+    ('\u{C3C}', Status::Valid, 0, 0),
+    ('\u{C45}', Status::Disallowed, 0, 0),
+    ('\u{C46}', Status::Valid, 0, 0),
+    ('\u{C49}', Status::Disallowed, 0, 0),
+    ('\u{C4A}', Status::Valid, 0, 0),
+    ('\u{C4E}', Status::Disallowed, 0, 0),
+    ('\u{C55}', Status::Valid, 0, 0),
+    ('\u{C57}', Status::Disallowed, 0, 0),
+    ('\u{C58}', Status::Valid, 0, 0),
+    ('\u{C5B}', Status::Disallowed, 0, 0),
+    ('\u{C5D}', Status::Valid, 0, 0),
+    ('\u{C5E}', Status::Disallowed, 0, 0),
+    ('\u{C60}', Status::Valid, 0, 0),
+    ('\u{C64}', Status::Disallowed, 0, 0),
+    ('\u{C66}', Status::Valid, 0, 0),
+    ('\u{C70}', Status::Disallowed, 0, 0),
+    ('\u{C77}', Status::Valid, 0, 0),
+    ('\u{C8D}', Status::Disallowed, 0, 0),
+    ('\u{C8E}', Status::Valid, 0, 0),
+    ('\u{C91}', Status::Disallowed, 0, 0),
+    ('\u{C92}', Status::Valid, 0, 0),
+    ('\u{CA9}', Status::Disallowed, 0, 0),
+    ('\u{CAA}', Status::Valid, 0, 0),
+    ('\u{CB4}', Status::Disallowed, 0, 0),
+    ('\u{CB5}', Status::Valid, 0, 0),
+    ('\u{CBA}', Status::Disallowed, 0, 0),
+    ('\u{CBC}', Status::Valid, 0, 0),
+    ('\u{CC5}', Status::Disallowed, 0, 0),
+    ('\u{CC6}', Status::Valid, 0, 0),
+    ('\u{CC9}', Status::Disallowed, 0, 0),
+    ('\u{CCA}', Status::Valid, 0, 0),
+    ('\u{CCE}', Status::Disallowed, 0, 0),
+    ('\u{CD5}', Status::Valid, 0, 0),
+    ('\u{CD7}', Status::Disallowed, 0, 0),
+    ('\u{CDD}', Status::Valid, 0, 0),
+    ('\u{CDF}', Status::Disallowed, 0, 0),
+    ('\u{CE0}', Status::Valid, 0, 0),
+    ('\u{CE4}', Status::Disallowed, 0, 0),
+    ('\u{CE6}', Status::Valid, 0, 0),
+    ('\u{CF0}', Status::Disallowed, 0, 0),
+    ('\u{CF1}', Status::Valid, 0, 0),
+    ('\u{CF4}', Status::Disallowed, 0, 0),
+    ('\u{D00}', Status::Valid, 0, 0),
+    ('\u{D0D}', Status::Disallowed, 0, 0),
+    ('\u{D0E}', Status::Valid, 0, 0),
+    ('\u{D11}', Status::Disallowed, 0, 0),
+    ('\u{D12}', Status::Valid, 0, 0),
+    ('\u{D45}', Status::Disallowed, 0, 0),
+    ('\u{D46}', Status::Valid, 0, 0),
+    ('\u{D49}', Status::Disallowed, 0, 0),
+    ('\u{D4A}', Status::Valid, 0, 0),
+    ('\u{D50}', Status::Disallowed, 0, 0),
+    ('\u{D54}', Status::Valid, 0, 0),
+    ('\u{D64}', Status::Disallowed, 0, 0),
+    ('\u{D66}', Status::Valid, 0, 0),
+    ('\u{D80}', Status::Disallowed, 0, 0),
+    ('\u{D81}', Status::Valid, 0, 0),
+    ('\u{D84}', Status::Disallowed, 0, 0),
+    ('\u{D85}', Status::Valid, 0, 0),
+    ('\u{D97}', Status::Disallowed, 0, 0),
+    ('\u{D9A}', Status::Valid, 0, 0),
+    ('\u{DB2}', Status::Disallowed, 0, 0),
+    ('\u{DB3}', Status::Valid, 0, 0),
+    ('\u{DBC}', Status::Disallowed, 0, 0),
+    ('\u{DBD}', Status::Valid, 0, 0),
+    ('\u{DBE}', Status::Disallowed, 0, 0),
+    ('\u{DC0}', Status::Valid, 0, 0),
+    ('\u{DC7}', Status::Disallowed, 0, 0),
+    ('\u{DCA}', Status::Valid, 0, 0),
+    ('\u{DCB}', Status::Disallowed, 0, 0),
+    ('\u{DCF}', Status::Valid, 0, 0),
+    ('\u{DD5}', Status::Disallowed, 0, 0),
+    ('\u{DD6}', Status::Valid, 0, 0),
+    ('\u{DD7}', Status::Disallowed, 0, 0),
+    ('\u{DD8}', Status::Valid, 0, 0),
+    ('\u{DE0}', Status::Disallowed, 0, 0),
+    ('\u{DE6}', Status::Valid, 0, 0),
+    ('\u{DF0}', Status::Disallowed, 0, 0),
+    ('\u{DF2}', Status::Valid, 0, 0),
+    ('\u{DF5}', Status::Disallowed, 0, 0),
+    ('\u{E01}', Status::Valid, 0, 0),
+    ('\u{E33}', Status::Mapped, 1122, 1128),
+    ('\u{E34}', Status::Valid, 0, 0),
+    ('\u{E3B}', Status::Disallowed, 0, 0),
+    ('\u{E3F}', Status::Valid, 0, 0),
+    ('\u{E5C}', Status::Disallowed, 0, 0),
+    ('\u{E81}', Status::Valid, 0, 0),
+    ('\u{E83}', Status::Disallowed, 0, 0),
+    ('\u{E84}', Status::Valid, 0, 0),
+    ('\u{E85}', Status::Disallowed, 0, 0),
+    ('\u{E86}', Status::Valid, 0, 0),
+    ('\u{E8B}', Status::Disallowed, 0, 0),
+    ('\u{E8C}', Status::Valid, 0, 0),
+    ('\u{EA4}', Status::Disallowed, 0, 0),
+    ('\u{EA5}', Status::Valid, 0, 0),
+    ('\u{EA6}', Status::Disallowed, 0, 0),
+    ('\u{EA7}', Status::Valid, 0, 0),
+    ('\u{EB3}', Status::Mapped, 1128, 1134),
+    ('\u{EB4}', Status::Valid, 0, 0),
+    ('\u{EBE}', Status::Disallowed, 0, 0),
+    ('\u{EC0}', Status::Valid, 0, 0),
+    ('\u{EC5}', Status::Disallowed, 0, 0),
+    ('\u{EC6}', Status::Valid, 0, 0),
+    ('\u{EC7}', Status::Disallowed, 0, 0),
+    ('\u{EC8}', Status::Valid, 0, 0),
+    ('\u{ECF}', Status::Disallowed, 0, 0),
+    ('\u{ED0}', Status::Valid, 0, 0),
+    ('\u{EDA}', Status::Disallowed, 0, 0),
+    ('\u{EDC}', Status::Mapped, 1134, 1140),
+    ('\u{EDD}', Status::Mapped, 1140, 1146),
+    ('\u{EDE}', Status::Valid, 0, 0),
+    ('\u{EE0}', Status::Disallowed, 0, 0),
+    ('\u{F00}', Status::Valid, 0, 0),
+    ('\u{F0C}', Status::Mapped, 1146, 1149),
+    ('\u{F0D}', Status::Valid, 0, 0),
+    ('\u{F43}', Status::Mapped, 1149, 1155),
+    ('\u{F44}', Status::Valid, 0, 0),
+    ('\u{F48}', Status::Disallowed, 0, 0),
+    ('\u{F49}', Status::Valid, 0, 0),
+    ('\u{F4D}', Status::Mapped, 1155, 1161),
+    ('\u{F4E}', Status::Valid, 0, 0),
+    ('\u{F52}', Status::Mapped, 1161, 1167),
+    ('\u{F53}', Status::Valid, 0, 0),
+    ('\u{F57}', Status::Mapped, 1167, 1173),
+    ('\u{F58}', Status::Valid, 0, 0),
+    ('\u{F5C}', Status::Mapped, 1173, 1179),
+    ('\u{F5D}', Status::Valid, 0, 0),
+    ('\u{F69}', Status::Mapped, 1179, 1185),
+    ('\u{F6A}', Status::Valid, 0, 0),
+    ('\u{F6D}', Status::Disallowed, 0, 0),
+    ('\u{F71}', Status::Valid, 0, 0),
+    ('\u{F73}', Status::Mapped, 1185, 1191),
+    ('\u{F74}', Status::Valid, 0, 0),
+    ('\u{F75}', Status::Mapped, 1191, 1197),
+    ('\u{F76}', Status::Mapped, 1197, 1203),
+    ('\u{F77}', Status::Mapped, 1203, 1212),
+    ('\u{F78}', Status::Mapped, 1212, 1218),
+    ('\u{F79}', Status::Mapped, 1218, 1227),
+    ('\u{F7A}', Status::Valid, 0, 0),
+    ('\u{F81}', Status::Mapped, 1227, 1233),
+    ('\u{F82}', Status::Valid, 0, 0),
+    ('\u{F93}', Status::Mapped, 1233, 1239),
+    ('\u{F94}', Status::Valid, 0, 0),
+    ('\u{F98}', Status::Disallowed, 0, 0),
+    ('\u{F99}', Status::Valid, 0, 0),
+    ('\u{F9D}', Status::Mapped, 1239, 1245),
+    ('\u{F9E}', Status::Valid, 0, 0),
+    ('\u{FA2}', Status::Mapped, 1245, 1251),
+    ('\u{FA3}', Status::Valid, 0, 0),
+    ('\u{FA7}', Status::Mapped, 1251, 1257),
+    ('\u{FA8}', Status::Valid, 0, 0),
+    ('\u{FAC}', Status::Mapped, 1257, 1263),
+    ('\u{FAD}', Status::Valid, 0, 0),
+    ('\u{FB9}', Status::Mapped, 1263, 1269),
+    ('\u{FBA}', Status::Valid, 0, 0),
+    ('\u{FBD}', Status::Disallowed, 0, 0),
+    ('\u{FBE}', Status::Valid, 0, 0),
+    ('\u{FCD}', Status::Disallowed, 0, 0),
+    ('\u{FCE}', Status::Valid, 0, 0),
+    ('\u{FDB}', Status::Disallowed, 0, 0),
+    ('\u{1000}', Status::Valid, 0, 0),
+    ('\u{10A0}', Status::Disallowed, 0, 0),
+    ('\u{10C7}', Status::Mapped, 1269, 1272),
+    ('\u{10C8}', Status::Disallowed, 0, 0),
+    ('\u{10CD}', Status::Mapped, 1272, 1275),
+    ('\u{10CE}', Status::Disallowed, 0, 0),
+    ('\u{10D0}', Status::Valid, 0, 0),
+    ('\u{10FC}', Status::Mapped, 1275, 1278),
+    ('\u{10FD}', Status::Valid, 0, 0),
+    ('\u{115F}', Status::Disallowed, 0, 0),
+    ('\u{1161}', Status::Valid, 0, 0),
+    ('\u{1249}', Status::Disallowed, 0, 0),
+    ('\u{124A}', Status::Valid, 0, 0),
+    ('\u{124E}', Status::Disallowed, 0, 0),
+    ('\u{1250}', Status::Valid, 0, 0),
+    ('\u{1257}', Status::Disallowed, 0, 0),
+    ('\u{1258}', Status::Valid, 0, 0),
+    ('\u{1259}', Status::Disallowed, 0, 0),
+    ('\u{125A}', Status::Valid, 0, 0),
+    ('\u{125E}', Status::Disallowed, 0, 0),
+    ('\u{1260}', Status::Valid, 0, 0),
+    ('\u{1289}', Status::Disallowed, 0, 0),
+    ('\u{128A}', Status::Valid, 0, 0),
+    ('\u{128E}', Status::Disallowed, 0, 0),
+    ('\u{1290}', Status::Valid, 0, 0),
+    ('\u{12B1}', Status::Disallowed, 0, 0),
+    ('\u{12B2}', Status::Valid, 0, 0),
+    ('\u{12B6}', Status::Disallowed, 0, 0),
+    ('\u{12B8}', Status::Valid, 0, 0),
+    ('\u{12BF}', Status::Disallowed, 0, 0),
+    ('\u{12C0}', Status::Valid, 0, 0),
+    ('\u{12C1}', Status::Disallowed, 0, 0),
+    ('\u{12C2}', Status::Valid, 0, 0),
+    ('\u{12C6}', Status::Disallowed, 0, 0),
+    ('\u{12C8}', Status::Valid, 0, 0),
+    ('\u{12D7}', Status::Disallowed, 0, 0),
+    ('\u{12D8}', Status::Valid, 0, 0),
+    ('\u{1311}', Status::Disallowed, 0, 0),
+    ('\u{1312}', Status::Valid, 0, 0),
+    ('\u{1316}', Status::Disallowed, 0, 0),
+    ('\u{1318}', Status::Valid, 0, 0),
+    ('\u{135B}', Status::Disallowed, 0, 0),
+    ('\u{135D}', Status::Valid, 0, 0),
+    ('\u{137D}', Status::Disallowed, 0, 0),
+    ('\u{1380}', Status::Valid, 0, 0),
+    ('\u{139A}', Status::Disallowed, 0, 0),
+    ('\u{13A0}', Status::Valid, 0, 0),
+    ('\u{13F6}', Status::Disallowed, 0, 0),
+    ('\u{13F8}', Status::Mapped, 1278, 1281),
+    ('\u{13F9}', Status::Mapped, 1281, 1284),
+    ('\u{13FA}', Status::Mapped, 1284, 1287),
+    ('\u{13FB}', Status::Mapped, 1287, 1290),
+    ('\u{13FC}', Status::Mapped, 1290, 1293),
+    ('\u{13FD}', Status::Mapped, 1293, 1296),
+    ('\u{13FE}', Status::Disallowed, 0, 0),
+    ('\u{1400}', Status::Valid, 0, 0),
+    ('\u{1680}', Status::Disallowed, 0, 0),
+    ('\u{1681}', Status::Valid, 0, 0),
+    ('\u{169D}', Status::Disallowed, 0, 0),
+    ('\u{16A0}', Status::Valid, 0, 0),
+    ('\u{16F9}', Status::Disallowed, 0, 0),
+    ('\u{1700}', Status::Valid, 0, 0),
+    ('\u{1716}', Status::Disallowed, 0, 0),
+    ('\u{171F}', Status::Valid, 0, 0),
+    ('\u{1737}', Status::Disallowed, 0, 0),
+    ('\u{1740}', Status::Valid, 0, 0),
+    ('\u{1754}', Status::Disallowed, 0, 0),
+    ('\u{1760}', Status::Valid, 0, 0),
+    ('\u{176D}', Status::Disallowed, 0, 0),
+    ('\u{176E}', Status::Valid, 0, 0),
+    ('\u{1771}', Status::Disallowed, 0, 0),
+    ('\u{1772}', Status::Valid, 0, 0),
+    ('\u{1774}', Status::Disallowed, 0, 0),
+    ('\u{1780}', Status::Valid, 0, 0),
+    ('\u{17B4}', Status::Disallowed, 0, 0),
+    ('\u{17B6}', Status::Valid, 0, 0),
+    ('\u{17DE}', Status::Disallowed, 0, 0),
+    ('\u{17E0}', Status::Valid, 0, 0),
+    ('\u{17EA}', Status::Disallowed, 0, 0),
+    ('\u{17F0}', Status::Valid, 0, 0),
+    ('\u{17FA}', Status::Disallowed, 0, 0),
+    ('\u{1800}', Status::Valid, 0, 0),
+    ('\u{1806}', Status::Disallowed, 0, 0),
+    ('\u{1807}', Status::Valid, 0, 0),
+    ('\u{180B}', Status::Ignored, 0, 0),
+    ('\u{180E}', Status::Disallowed, 0, 0),
+    ('\u{180F}', Status::Ignored, 0, 0),
+    ('\u{1810}', Status::Valid, 0, 0),
+    ('\u{181A}', Status::Disallowed, 0, 0),
+    ('\u{1820}', Status::Valid, 0, 0),
+    ('\u{1879}', Status::Disallowed, 0, 0),
+    ('\u{1880}', Status::Valid, 0, 0),
+    ('\u{18AB}', Status::Disallowed, 0, 0),
+    ('\u{18B0}', Status::Valid, 0, 0),
+    ('\u{18F6}', Status::Disallowed, 0, 0),
+    ('\u{1900}', Status::Valid, 0, 0),
+    ('\u{191F}', Status::Disallowed, 0, 0),
+    ('\u{1920}', Status::Valid, 0, 0),
+    ('\u{192C}', Status::Disallowed, 0, 0),
+    ('\u{1930}', Status::Valid, 0, 0),
+    ('\u{193C}', Status::Disallowed, 0, 0),
+    ('\u{1940}', Status::Valid, 0, 0),
+    ('\u{1941}', Status::Disallowed, 0, 0),
+    ('\u{1944}', Status::Valid, 0, 0),
+    ('\u{196E}', Status::Disallowed, 0, 0),
+    ('\u{1970}', Status::Valid, 0, 0),
+    ('\u{1975}', Status::Disallowed, 0, 0),
+    ('\u{1980}', Status::Valid, 0, 0),
+    ('\u{19AC}', Status::Disallowed, 0, 0),
+    ('\u{19B0}', Status::Valid, 0, 0),
+    ('\u{19CA}', Status::Disallowed, 0, 0),
+    ('\u{19D0}', Status::Valid, 0, 0),
+    ('\u{19DB}', Status::Disallowed, 0, 0),
+    ('\u{19DE}', Status::Valid, 0, 0),
+    ('\u{1A1C}', Status::Disallowed, 0, 0),
+    ('\u{1A1E}', Status::Valid, 0, 0),
+    ('\u{1A5F}', Status::Disallowed, 0, 0),
+    ('\u{1A60}', Status::Valid, 0, 0),
+    ('\u{1A7D}', Status::Disallowed, 0, 0),
+    ('\u{1A7F}', Status::Valid, 0, 0),
+    ('\u{1A8A}', Status::Disallowed, 0, 0),
+    ('\u{1A90}', Status::Valid, 0, 0),
+    ('\u{1A9A}', Status::Disallowed, 0, 0),
+    ('\u{1AA0}', Status::Valid, 0, 0),
+    ('\u{1AAE}', Status::Disallowed, 0, 0),
+    ('\u{1AB0}', Status::Valid, 0, 0),
+    ('\u{1ACF}', Status::Disallowed, 0, 0),
+    ('\u{1B00}', Status::Valid, 0, 0),
+    ('\u{1B4D}', Status::Disallowed, 0, 0),
+    ('\u{1B50}', Status::Valid, 0, 0),
+    ('\u{1B7F}', Status::Disallowed, 0, 0),
+    ('\u{1B80}', Status::Valid, 0, 0),
+    ('\u{1BF4}', Status::Disallowed, 0, 0),
+    ('\u{1BFC}', Status::Valid, 0, 0),
+    ('\u{1C38}', Status::Disallowed, 0, 0),
+    ('\u{1C3B}', Status::Valid, 0, 0),
+    ('\u{1C4A}', Status::Disallowed, 0, 0),
+    ('\u{1C4D}', Status::Valid, 0, 0),
+    ('\u{1C80}', Status::Mapped, 654, 656),
+    ('\u{1C81}', Status::Mapped, 658, 660),
+    ('\u{1C82}', Status::Mapped, 678, 680),
+    ('\u{1C83}', Status::Mapped, 684, 686),
+    ('\u{1C84}', Status::Mapped, 686, 688),
+    ('\u{1C86}', Status::Mapped, 702, 704),
+    ('\u{1C87}', Status::Mapped, 716, 718),
+    ('\u{1C88}', Status::Mapped, 1296, 1299),
+    ('\u{1C89}', Status::Disallowed, 0, 0),
+    ('\u{1C90}', Status::Mapped, 1299, 1302),
+    ('\u{1C91}', Status::Mapped, 1302, 1305),
+    ('\u{1C92}', Status::Mapped, 1305, 1308),
+    ('\u{1C93}', Status::Mapped, 1308, 1311),
+    ('\u{1C94}', Status::Mapped, 1311, 1314),
+    ('\u{1C95}', Status::Mapped, 1314, 1317),
+    ('\u{1C96}', Status::Mapped, 1317, 1320),
+    ('\u{1C97}', Status::Mapped, 1320, 1323),
+    ('\u{1C98}', Status::Mapped, 1323, 1326),
+    ('\u{1C99}', Status::Mapped, 1326, 1329),
+    ('\u{1C9A}', Status::Mapped, 1329, 1332),
+    ('\u{1C9B}', Status::Mapped, 1332, 1335),
+    ('\u{1C9C}', Status::Mapped, 1275, 1278),
+    ('\u{1C9D}', Status::Mapped, 1335, 1338),
+    ('\u{1C9E}', Status::Mapped, 1338, 1341),
+    ('\u{1C9F}', Status::Mapped, 1341, 1344),
+    ('\u{1CA0}', Status::Mapped, 1344, 1347),
+    ('\u{1CA1}', Status::Mapped, 1347, 1350),
+    ('\u{1CA2}', Status::Mapped, 1350, 1353),
+    ('\u{1CA3}', Status::Mapped, 1353, 1356),
+    ('\u{1CA4}', Status::Mapped, 1356, 1359),
+    ('\u{1CA5}', Status::Mapped, 1359, 1362),
+    ('\u{1CA6}', Status::Mapped, 1362, 1365),
+    ('\u{1CA7}', Status::Mapped, 1365, 1368),
+    ('\u{1CA8}', Status::Mapped, 1368, 1371),
+    ('\u{1CA9}', Status::Mapped, 1371, 1374),
+    ('\u{1CAA}', Status::Mapped, 1374, 1377),
+    ('\u{1CAB}', Status::Mapped, 1377, 1380),
+    ('\u{1CAC}', Status::Mapped, 1380, 1383),
+    ('\u{1CAD}', Status::Mapped, 1383, 1386),
+    ('\u{1CAE}', Status::Mapped, 1386, 1389),
+    ('\u{1CAF}', Status::Mapped, 1389, 1392),
+    ('\u{1CB0}', Status::Mapped, 1392, 1395),
+    ('\u{1CB1}', Status::Mapped, 1395, 1398),
+    ('\u{1CB2}', Status::Mapped, 1398, 1401),
+    ('\u{1CB3}', Status::Mapped, 1401, 1404),
+    ('\u{1CB4}', Status::Mapped, 1404, 1407),
+    ('\u{1CB5}', Status::Mapped, 1407, 1410),
+    ('\u{1CB6}', Status::Mapped, 1410, 1413),
+    ('\u{1CB7}', Status::Mapped, 1413, 1416),
+    ('\u{1CB8}', Status::Mapped, 1416, 1419),
+    ('\u{1CB9}', Status::Mapped, 1419, 1422),
+    ('\u{1CBA}', Status::Mapped, 1422, 1425),
+    ('\u{1CBB}', Status::Disallowed, 0, 0),
+    ('\u{1CBD}', Status::Mapped, 1425, 1428),
+    ('\u{1CBE}', Status::Mapped, 1428, 1431),
+    ('\u{1CBF}', Status::Mapped, 1431, 1434),
+    ('\u{1CC0}', Status::Valid, 0, 0),
+    ('\u{1CC8}', Status::Disallowed, 0, 0),
+    ('\u{1CD0}', Status::Valid, 0, 0),
+    ('\u{1CFB}', Status::Disallowed, 0, 0),
+    ('\u{1D00}', Status::Valid, 0, 0),
+    ('\u{1D2C}', Status::Mapped, 0, 1),
+    ('\u{1D2D}', Status::Mapped, 71, 73),
+    ('\u{1D2E}', Status::Mapped, 1, 2),
+    ('\u{1D2F}', Status::Valid, 0, 0),
+    ('\u{1D30}', Status::Mapped, 3, 4),
+    ('\u{1D31}', Status::Mapped, 4, 5),
+    ('\u{1D32}', Status::Mapped, 266, 268),
+    ('\u{1D33}', Status::Mapped, 6, 7),
+    ('\u{1D34}', Status::Mapped, 7, 8),
+    ('\u{1D35}', Status::Mapped, 8, 9),
+    ('\u{1D36}', Status::Mapped, 9, 10),
+    ('\u{1D37}', Status::Mapped, 10, 11),
+    ('\u{1D38}', Status::Mapped, 11, 12),
+    ('\u{1D39}', Status::Mapped, 12, 13),
+    ('\u{1D3A}', Status::Mapped, 13, 14),
+    ('\u{1D3B}', Status::Valid, 0, 0),
+    ('\u{1D3C}', Status::Mapped, 14, 15),
+    ('\u{1D3D}', Status::Mapped, 413, 415),
+    ('\u{1D3E}', Status::Mapped, 15, 16),
+    ('\u{1D3F}', Status::Mapped, 17, 18),
+    ('\u{1D40}', Status::Mapped, 19, 20),
+    ('\u{1D41}', Status::Mapped, 20, 21),
+    ('\u{1D42}', Status::Mapped, 22, 23),
+    ('\u{1D43}', Status::Mapped, 0, 1),
+    ('\u{1D44}', Status::Mapped, 1434, 1436),
+    ('\u{1D45}', Status::Mapped, 1436, 1438),
+    ('\u{1D46}', Status::Mapped, 1438, 1441),
+    ('\u{1D47}', Status::Mapped, 1, 2),
+    ('\u{1D48}', Status::Mapped, 3, 4),
+    ('\u{1D49}', Status::Mapped, 4, 5),
+    ('\u{1D4A}', Status::Mapped, 268, 270),
+    ('\u{1D4B}', Status::Mapped, 270, 272),
+    ('\u{1D4C}', Status::Mapped, 1441, 1443),
+    ('\u{1D4D}', Status::Mapped, 6, 7),
+    ('\u{1D4E}', Status::Valid, 0, 0),
+    ('\u{1D4F}', Status::Mapped, 10, 11),
+    ('\u{1D50}', Status::Mapped, 12, 13),
+    ('\u{1D51}', Status::Mapped, 196, 198),
+    ('\u{1D52}', Status::Mapped, 14, 15),
+    ('\u{1D53}', Status::Mapped, 256, 258),
+    ('\u{1D54}', Status::Mapped, 1443, 1446),
+    ('\u{1D55}', Status::Mapped, 1446, 1449),
+    ('\u{1D56}', Status::Mapped, 15, 16),
+    ('\u{1D57}', Status::Mapped, 19, 20),
+    ('\u{1D58}', Status::Mapped, 20, 21),
+    ('\u{1D59}', Status::Mapped, 1449, 1452),
+    ('\u{1D5A}', Status::Mapped, 284, 286),
+    ('\u{1D5B}', Status::Mapped, 21, 22),
+    ('\u{1D5C}', Status::Mapped, 1452, 1455),
+    ('\u{1D5D}', Status::Mapped, 536, 538),
+    ('\u{1D5E}', Status::Mapped, 538, 540),
+    ('\u{1D5F}', Status::Mapped, 540, 542),
+    ('\u{1D60}', Status::Mapped, 570, 572),
+    ('\u{1D61}', Status::Mapped, 572, 574),
+    ('\u{1D62}', Status::Mapped, 8, 9),
+    ('\u{1D63}', Status::Mapped, 17, 18),
+    ('\u{1D64}', Status::Mapped, 20, 21),
+    ('\u{1D65}', Status::Mapped, 21, 22),
+    ('\u{1D66}', Status::Mapped, 536, 538),
+    ('\u{1D67}', Status::Mapped, 538, 540),
+    ('\u{1D68}', Status::Mapped, 562, 564),
+    ('\u{1D69}', Status::Mapped, 570, 572),
+    ('\u{1D6A}', Status::Mapped, 572, 574),
+    ('\u{1D6B}', Status::Valid, 0, 0),
+    ('\u{1D78}', Status::Mapped, 676, 678),
+    ('\u{1D79}', Status::Valid, 0, 0),
+    ('\u{1D9B}', Status::Mapped, 1455, 1457),
+    ('\u{1D9C}', Status::Mapped, 2, 3),
+    ('\u{1D9D}', Status::Mapped, 1457, 1459),
+    ('\u{1D9E}', Status::Mapped, 91, 93),
+    ('\u{1D9F}', Status::Mapped, 1441, 1443),
+    ('\u{1DA0}', Status::Mapped, 5, 6),
+    ('\u{1DA1}', Status::Mapped, 1459, 1461),
+    ('\u{1DA2}', Status::Mapped, 1461, 1463),
+    ('\u{1DA3}', Status::Mapped, 1463, 1465),
+    ('\u{1DA4}', Status::Mapped, 280, 282),
+    ('\u{1DA5}', Status::Mapped, 278, 280),
+    ('\u{1DA6}', Status::Mapped, 1465, 1467),
+    ('\u{1DA7}', Status::Mapped, 1467, 1470),
+    ('\u{1DA8}', Status::Mapped, 1470, 1472),
+    ('\u{1DA9}', Status::Mapped, 1472, 1474),
+    ('\u{1DAA}', Status::Mapped, 1474, 1477),
+    ('\u{1DAB}', Status::Mapped, 1477, 1479),
+    ('\u{1DAC}', Status::Mapped, 1479, 1481),
+    ('\u{1DAD}', Status::Mapped, 1481, 1483),
+    ('\u{1DAE}', Status::Mapped, 286, 288),
+    ('\u{1DAF}', Status::Mapped, 1483, 1485),
+    ('\u{1DB0}', Status::Mapped, 1485, 1487),
+    ('\u{1DB1}', Status::Mapped, 288, 290),
+    ('\u{1DB2}', Status::Mapped, 1487, 1489),
+    ('\u{1DB3}', Status::Mapped, 1489, 1491),
+    ('\u{1DB4}', Status::Mapped, 300, 302),
+    ('\u{1DB5}', Status::Mapped, 1491, 1493),
+    ('\u{1DB6}', Status::Mapped, 445, 447),
+    ('\u{1DB7}', Status::Mapped, 308, 310),
+    ('\u{1DB8}', Status::Mapped, 1493, 1496),
+    ('\u{1DB9}', Status::Mapped, 310, 312),
+    ('\u{1DBA}', Status::Mapped, 447, 449),
+    ('\u{1DBB}', Status::Mapped, 25, 26),
+    ('\u{1DBC}', Status::Mapped, 1496, 1498),
+    ('\u{1DBD}', Status::Mapped, 1498, 1500),
+    ('\u{1DBE}', Status::Mapped, 316, 318),
+    ('\u{1DBF}', Status::Mapped, 548, 550),
+    ('\u{1DC0}', Status::Valid, 0, 0),
+    ('\u{1E00}', Status::Mapped, 1500, 1503),
+    ('\u{1E01}', Status::Valid, 0, 0),
+    ('\u{1E02}', Status::Mapped, 1503, 1506),
+    ('\u{1E03}', Status::Valid, 0, 0),
+    ('\u{1E04}', Status::Mapped, 1506, 1509),
+    ('\u{1E05}', Status::Valid, 0, 0),
+    ('\u{1E06}', Status::Mapped, 1509, 1512),
+    ('\u{1E07}', Status::Valid, 0, 0),
+    ('\u{1E08}', Status::Mapped, 1512, 1515),
+    ('\u{1E09}', Status::Valid, 0, 0),
+    ('\u{1E0A}', Status::Mapped, 1515, 1518),
+    ('\u{1E0B}', Status::Valid, 0, 0),
+    ('\u{1E0C}', Status::Mapped, 1518, 1521),
+    ('\u{1E0D}', Status::Valid, 0, 0),
+    ('\u{1E0E}', Status::Mapped, 1521, 1524),
+    ('\u{1E0F}', Status::Valid, 0, 0),
+    ('\u{1E10}', Status::Mapped, 1524, 1527),
+    ('\u{1E11}', Status::Valid, 0, 0),
+    ('\u{1E12}', Status::Mapped, 1527, 1530),
+    ('\u{1E13}', Status::Valid, 0, 0),
+    ('\u{1E14}', Status::Mapped, 1530, 1533),
+    ('\u{1E15}', Status::Valid, 0, 0),
+    ('\u{1E16}', Status::Mapped, 1533, 1536),
+    ('\u{1E17}', Status::Valid, 0, 0),
+    ('\u{1E18}', Status::Mapped, 1536, 1539),
+    ('\u{1E19}', Status::Valid, 0, 0),
+    ('\u{1E1A}', Status::Mapped, 1539, 1542),
+    ('\u{1E1B}', Status::Valid, 0, 0),
+    ('\u{1E1C}', Status::Mapped, 1542, 1545),
+    ('\u{1E1D}', Status::Valid, 0, 0),
+    ('\u{1E1E}', Status::Mapped, 1545, 1548),
+    ('\u{1E1F}', Status::Valid, 0, 0),
+    ('\u{1E20}', Status::Mapped, 1548, 1551),
+    ('\u{1E21}', Status::Valid, 0, 0),
+    ('\u{1E22}', Status::Mapped, 1551, 1554),
+    ('\u{1E23}', Status::Valid, 0, 0),
+    ('\u{1E24}', Status::Mapped, 1554, 1557),
+    ('\u{1E25}', Status::Valid, 0, 0),
+    ('\u{1E26}', Status::Mapped, 1557, 1560),
+    ('\u{1E27}', Status::Valid, 0, 0),
+    ('\u{1E28}', Status::Mapped, 1560, 1563),
+    ('\u{1E29}', Status::Valid, 0, 0),
+    ('\u{1E2A}', Status::Mapped, 1563, 1566),
+    ('\u{1E2B}', Status::Valid, 0, 0),
+    ('\u{1E2C}', Status::Mapped, 1566, 1569),
+    ('\u{1E2D}', Status::Valid, 0, 0),
+    ('\u{1E2E}', Status::Mapped, 1569, 1572),
+    ('\u{1E2F}', Status::Valid, 0, 0),
+    ('\u{1E30}', Status::Mapped, 1572, 1575),
+    ('\u{1E31}', Status::Valid, 0, 0),
+    ('\u{1E32}', Status::Mapped, 1575, 1578),
+    ('\u{1E33}', Status::Valid, 0, 0),
+    ('\u{1E34}', Status::Mapped, 1578, 1581),
+    ('\u{1E35}', Status::Valid, 0, 0),
+    ('\u{1E36}', Status::Mapped, 1581, 1584),
+    ('\u{1E37}', Status::Valid, 0, 0),
+    ('\u{1E38}', Status::Mapped, 1584, 1587),
+    ('\u{1E39}', Status::Valid, 0, 0),
+    ('\u{1E3A}', Status::Mapped, 1587, 1590),
+    ('\u{1E3B}', Status::Valid, 0, 0),
+    ('\u{1E3C}', Status::Mapped, 1590, 1593),
+    ('\u{1E3D}', Status::Valid, 0, 0),
+    ('\u{1E3E}', Status::Mapped, 1593, 1596),
+    ('\u{1E3F}', Status::Valid, 0, 0),
+    ('\u{1E40}', Status::Mapped, 1596, 1599),
+    ('\u{1E41}', Status::Valid, 0, 0),
+    ('\u{1E42}', Status::Mapped, 1599, 1602),
+    ('\u{1E43}', Status::Valid, 0, 0),
+    ('\u{1E44}', Status::Mapped, 1602, 1605),
+    ('\u{1E45}', Status::Valid, 0, 0),
+    ('\u{1E46}', Status::Mapped, 1605, 1608),
+    ('\u{1E47}', Status::Valid, 0, 0),
+    ('\u{1E48}', Status::Mapped, 1608, 1611),
+    ('\u{1E49}', Status::Valid, 0, 0),
+    ('\u{1E4A}', Status::Mapped, 1611, 1614),
+    ('\u{1E4B}', Status::Valid, 0, 0),
+    ('\u{1E4C}', Status::Mapped, 1614, 1617),
+    ('\u{1E4D}', Status::Valid, 0, 0),
+    ('\u{1E4E}', Status::Mapped, 1617, 1620),
+    ('\u{1E4F}', Status::Valid, 0, 0),
+    ('\u{1E50}', Status::Mapped, 1620, 1623),
+    ('\u{1E51}', Status::Valid, 0, 0),
+    ('\u{1E52}', Status::Mapped, 1623, 1626),
+    ('\u{1E53}', Status::Valid, 0, 0),
+    ('\u{1E54}', Status::Mapped, 1626, 1629),
+    ('\u{1E55}', Status::Valid, 0, 0),
+    ('\u{1E56}', Status::Mapped, 1629, 1632),
+    ('\u{1E57}', Status::Valid, 0, 0),
+    ('\u{1E58}', Status::Mapped, 1632, 1635),
+    ('\u{1E59}', Status::Valid, 0, 0),
+    ('\u{1E5A}', Status::Mapped, 1635, 1638),
+    ('\u{1E5B}', Status::Valid, 0, 0),
+    ('\u{1E5C}', Status::Mapped, 1638, 1641),
+    ('\u{1E5D}', Status::Valid, 0, 0),
+    ('\u{1E5E}', Status::Mapped, 1641, 1644),
+    ('\u{1E5F}', Status::Valid, 0, 0),
+    ('\u{1E60}', Status::Mapped, 1644, 1647),
+    ('\u{1E61}', Status::Valid, 0, 0),
+    ('\u{1E62}', Status::Mapped, 1647, 1650),
+    ('\u{1E63}', Status::Valid, 0, 0),
+    ('\u{1E64}', Status::Mapped, 1650, 1653),
+    ('\u{1E65}', Status::Valid, 0, 0),
+    ('\u{1E66}', Status::Mapped, 1653, 1656),
+    ('\u{1E67}', Status::Valid, 0, 0),
+    ('\u{1E68}', Status::Mapped, 1656, 1659),
+    ('\u{1E69}', Status::Valid, 0, 0),
+    ('\u{1E6A}', Status::Mapped, 1659, 1662),
+    ('\u{1E6B}', Status::Valid, 0, 0),
+    ('\u{1E6C}', Status::Mapped, 1662, 1665),
+    ('\u{1E6D}', Status::Valid, 0, 0),
+    ('\u{1E6E}', Status::Mapped, 1665, 1668),
+    ('\u{1E6F}', Status::Valid, 0, 0),
+    ('\u{1E70}', Status::Mapped, 1668, 1671),
+    ('\u{1E71}', Status::Valid, 0, 0),
+    ('\u{1E72}', Status::Mapped, 1671, 1674),
+    ('\u{1E73}', Status::Valid, 0, 0),
+    ('\u{1E74}', Status::Mapped, 1674, 1677),
+    ('\u{1E75}', Status::Valid, 0, 0),
+    ('\u{1E76}', Status::Mapped, 1677, 1680),
+    ('\u{1E77}', Status::Valid, 0, 0),
+    ('\u{1E78}', Status::Mapped, 1680, 1683),
+    ('\u{1E79}', Status::Valid, 0, 0),
+    ('\u{1E7A}', Status::Mapped, 1683, 1686),
+    ('\u{1E7B}', Status::Valid, 0, 0),
+    ('\u{1E7C}', Status::Mapped, 1686, 1689),
+    ('\u{1E7D}', Status::Valid, 0, 0),
+    ('\u{1E7E}', Status::Mapped, 1689, 1692),
+    ('\u{1E7F}', Status::Valid, 0, 0),
+    ('\u{1E80}', Status::Mapped, 1692, 1695),
+    ('\u{1E81}', Status::Valid, 0, 0),
+    ('\u{1E82}', Status::Mapped, 1695, 1698),
+    ('\u{1E83}', Status::Valid, 0, 0),
+    ('\u{1E84}', Status::Mapped, 1698, 1701),
+    ('\u{1E85}', Status::Valid, 0, 0),
+    ('\u{1E86}', Status::Mapped, 1701, 1704),
+    ('\u{1E87}', Status::Valid, 0, 0),
+    ('\u{1E88}', Status::Mapped, 1704, 1707),
+    ('\u{1E89}', Status::Valid, 0, 0),
+    ('\u{1E8A}', Status::Mapped, 1707, 1710),
+    ('\u{1E8B}', Status::Valid, 0, 0),
+    ('\u{1E8C}', Status::Mapped, 1710, 1713),
+    ('\u{1E8D}', Status::Valid, 0, 0),
+    ('\u{1E8E}', Status::Mapped, 1713, 1716),
+    ('\u{1E8F}', Status::Valid, 0, 0),
+    ('\u{1E90}', Status::Mapped, 1716, 1719),
+    ('\u{1E91}', Status::Valid, 0, 0),
+    ('\u{1E92}', Status::Mapped, 1719, 1722),
+    ('\u{1E93}', Status::Valid, 0, 0),
+    ('\u{1E94}', Status::Mapped, 1722, 1725),
+    ('\u{1E95}', Status::Valid, 0, 0),
+    ('\u{1E9A}', Status::Mapped, 1725, 1728),
+    ('\u{1E9B}', Status::Mapped, 1644, 1647),
+    ('\u{1E9C}', Status::Valid, 0, 0),
+    ('\u{1E9E}', Status::Mapped, 1728, 1730),
+    ('\u{1E9F}', Status::Valid, 0, 0),
+    ('\u{1EA0}', Status::Mapped, 1730, 1733),
+    ('\u{1EA1}', Status::Valid, 0, 0),
+    ('\u{1EA2}', Status::Mapped, 1733, 1736),
+    ('\u{1EA3}', Status::Valid, 0, 0),
+    ('\u{1EA4}', Status::Mapped, 1736, 1739),
+    ('\u{1EA5}', Status::Valid, 0, 0),
+    ('\u{1EA6}', Status::Mapped, 1739, 1742),
+    ('\u{1EA7}', Status::Valid, 0, 0),
+    ('\u{1EA8}', Status::Mapped, 1742, 1745),
+    ('\u{1EA9}', Status::Valid, 0, 0),
+    ('\u{1EAA}', Status::Mapped, 1745, 1748),
+    ('\u{1EAB}', Status::Valid, 0, 0),
+    ('\u{1EAC}', Status::Mapped, 1748, 1751),
+    ('\u{1EAD}', Status::Valid, 0, 0),
+    ('\u{1EAE}', Status::Mapped, 1751, 1754),
+    ('\u{1EAF}', Status::Valid, 0, 0),
+    ('\u{1EB0}', Status::Mapped, 1754, 1757),
+    ('\u{1EB1}', Status::Valid, 0, 0),
+    ('\u{1EB2}', Status::Mapped, 1757, 1760),
+    ('\u{1EB3}', Status::Valid, 0, 0),
+    ('\u{1EB4}', Status::Mapped, 1760, 1763),
+    ('\u{1EB5}', Status::Valid, 0, 0),
+    ('\u{1EB6}', Status::Mapped, 1763, 1766),
+    ('\u{1EB7}', Status::Valid, 0, 0),
+    ('\u{1EB8}', Status::Mapped, 1766, 1769),
+    ('\u{1EB9}', Status::Valid, 0, 0),
+    ('\u{1EBA}', Status::Mapped, 1769, 1772),
+    ('\u{1EBB}', Status::Valid, 0, 0),
+    ('\u{1EBC}', Status::Mapped, 1772, 1775),
+    ('\u{1EBD}', Status::Valid, 0, 0),
+    ('\u{1EBE}', Status::Mapped, 1775, 1778),
+    ('\u{1EBF}', Status::Valid, 0, 0),
+    ('\u{1EC0}', Status::Mapped, 1778, 1781),
+    ('\u{1EC1}', Status::Valid, 0, 0),
+    ('\u{1EC2}', Status::Mapped, 1781, 1784),
+    ('\u{1EC3}', Status::Valid, 0, 0),
+    ('\u{1EC4}', Status::Mapped, 1784, 1787),
+    ('\u{1EC5}', Status::Valid, 0, 0),
+    ('\u{1EC6}', Status::Mapped, 1787, 1790),
+    ('\u{1EC7}', Status::Valid, 0, 0),
+    ('\u{1EC8}', Status::Mapped, 1790, 1793),
+    ('\u{1EC9}', Status::Valid, 0, 0),
+    ('\u{1ECA}', Status::Mapped, 1793, 1796),
+    ('\u{1ECB}', Status::Valid, 0, 0),
+    ('\u{1ECC}', Status::Mapped, 1796, 1799),
+    ('\u{1ECD}', Status::Valid, 0, 0),
+    ('\u{1ECE}', Status::Mapped, 1799, 1802),
+    ('\u{1ECF}', Status::Valid, 0, 0),
+    ('\u{1ED0}', Status::Mapped, 1802, 1805),
+    ('\u{1ED1}', Status::Valid, 0, 0),
+    ('\u{1ED2}', Status::Mapped, 1805, 1808),
+    ('\u{1ED3}', Status::Valid, 0, 0),
+    ('\u{1ED4}', Status::Mapped, 1808, 1811),
+    ('\u{1ED5}', Status::Valid, 0, 0),
+    ('\u{1ED6}', Status::Mapped, 1811, 1814),
+    ('\u{1ED7}', Status::Valid, 0, 0),
+    ('\u{1ED8}', Status::Mapped, 1814, 1817),
+    ('\u{1ED9}', Status::Valid, 0, 0),
+    ('\u{1EDA}', Status::Mapped, 1817, 1820),
+    ('\u{1EDB}', Status::Valid, 0, 0),
+    ('\u{1EDC}', Status::Mapped, 1820, 1823),
+    ('\u{1EDD}', Status::Valid, 0, 0),
+    ('\u{1EDE}', Status::Mapped, 1823, 1826),
+    ('\u{1EDF}', Status::Valid, 0, 0),
+    ('\u{1EE0}', Status::Mapped, 1826, 1829),
+    ('\u{1EE1}', Status::Valid, 0, 0),
+    ('\u{1EE2}', Status::Mapped, 1829, 1832),
+    ('\u{1EE3}', Status::Valid, 0, 0),
+    ('\u{1EE4}', Status::Mapped, 1832, 1835),
+    ('\u{1EE5}', Status::Valid, 0, 0),
+    ('\u{1EE6}', Status::Mapped, 1835, 1838),
+    ('\u{1EE7}', Status::Valid, 0, 0),
+    ('\u{1EE8}', Status::Mapped, 1838, 1841),
+    ('\u{1EE9}', Status::Valid, 0, 0),
+    ('\u{1EEA}', Status::Mapped, 1841, 1844),
+    ('\u{1EEB}', Status::Valid, 0, 0),
+    ('\u{1EEC}', Status::Mapped, 1844, 1847),
+    ('\u{1EED}', Status::Valid, 0, 0),
+    ('\u{1EEE}', Status::Mapped, 1847, 1850),
+    ('\u{1EEF}', Status::Valid, 0, 0),
+    ('\u{1EF0}', Status::Mapped, 1850, 1853),
+    ('\u{1EF1}', Status::Valid, 0, 0),
+    ('\u{1EF2}', Status::Mapped, 1853, 1856),
+    ('\u{1EF3}', Status::Valid, 0, 0),
+    ('\u{1EF4}', Status::Mapped, 1856, 1859),
+    ('\u{1EF5}', Status::Valid, 0, 0),
+    ('\u{1EF6}', Status::Mapped, 1859, 1862),
+    ('\u{1EF7}', Status::Valid, 0, 0),
+    ('\u{1EF8}', Status::Mapped, 1862, 1865),
+    ('\u{1EF9}', Status::Valid, 0, 0),
+    ('\u{1EFA}', Status::Mapped, 1865, 1868),
+    ('\u{1EFB}', Status::Valid, 0, 0),
+    ('\u{1EFC}', Status::Mapped, 1868, 1871),
+    ('\u{1EFD}', Status::Valid, 0, 0),
+    ('\u{1EFE}', Status::Mapped, 1871, 1874),
+    ('\u{1EFF}', Status::Valid, 0, 0),
+    ('\u{1F08}', Status::Mapped, 1874, 1877),
+    ('\u{1F09}', Status::Mapped, 1877, 1880),
+    ('\u{1F0A}', Status::Mapped, 1880, 1883),
+    ('\u{1F0B}', Status::Mapped, 1883, 1886),
+    ('\u{1F0C}', Status::Mapped, 1886, 1889),
+    ('\u{1F0D}', Status::Mapped, 1889, 1892),
+    ('\u{1F0E}', Status::Mapped, 1892, 1895),
+    ('\u{1F0F}', Status::Mapped, 1895, 1898),
+    ('\u{1F10}', Status::Valid, 0, 0),
+    ('\u{1F16}', Status::Disallowed, 0, 0),
+    ('\u{1F18}', Status::Mapped, 1898, 1901),
+    ('\u{1F19}', Status::Mapped, 1901, 1904),
+    ('\u{1F1A}', Status::Mapped, 1904, 1907),
+    ('\u{1F1B}', Status::Mapped, 1907, 1910),
+    ('\u{1F1C}', Status::Mapped, 1910, 1913),
+    ('\u{1F1D}', Status::Mapped, 1913, 1916),
+    ('\u{1F1E}', Status::Disallowed, 0, 0),
+    ('\u{1F20}', Status::Valid, 0, 0),
+    ('\u{1F28}', Status::Mapped, 1916, 1919),
+    ('\u{1F29}', Status::Mapped, 1919, 1922),
+    ('\u{1F2A}', Status::Mapped, 1922, 1925),
+    ('\u{1F2B}', Status::Mapped, 1925, 1928),
+    ('\u{1F2C}', Status::Mapped, 1928, 1931),
+    ('\u{1F2D}', Status::Mapped, 1931, 1934),
+    ('\u{1F2E}', Status::Mapped, 1934, 1937),
+    ('\u{1F2F}', Status::Mapped, 1937, 1940),
+    ('\u{1F30}', Status::Valid, 0, 0),
+    ('\u{1F38}', Status::Mapped, 1940, 1943),
+    ('\u{1F39}', Status::Mapped, 1943, 1946),
+    ('\u{1F3A}', Status::Mapped, 1946, 1949),
+    ('\u{1F3B}', Status::Mapped, 1949, 1952),
+    ('\u{1F3C}', Status::Mapped, 1952, 1955),
+    ('\u{1F3D}', Status::Mapped, 1955, 1958),
+    ('\u{1F3E}', Status::Mapped, 1958, 1961),
+    ('\u{1F3F}', Status::Mapped, 1961, 1964),
+    ('\u{1F40}', Status::Valid, 0, 0),
+    ('\u{1F46}', Status::Disallowed, 0, 0),
+    ('\u{1F48}', Status::Mapped, 1964, 1967),
+    ('\u{1F49}', Status::Mapped, 1967, 1970),
+    ('\u{1F4A}', Status::Mapped, 1970, 1973),
+    ('\u{1F4B}', Status::Mapped, 1973, 1976),
+    ('\u{1F4C}', Status::Mapped, 1976, 1979),
+    ('\u{1F4D}', Status::Mapped, 1979, 1982),
+    ('\u{1F4E}', Status::Disallowed, 0, 0),
+    ('\u{1F50}', Status::Valid, 0, 0),
+    ('\u{1F58}', Status::Disallowed, 0, 0),
+    ('\u{1F59}', Status::Mapped, 1982, 1985),
+    ('\u{1F5A}', Status::Disallowed, 0, 0),
+    ('\u{1F5B}', Status::Mapped, 1985, 1988),
+    ('\u{1F5C}', Status::Disallowed, 0, 0),
+    ('\u{1F5D}', Status::Mapped, 1988, 1991),
+    ('\u{1F5E}', Status::Disallowed, 0, 0),
+    ('\u{1F5F}', Status::Mapped, 1991, 1994),
+    ('\u{1F60}', Status::Valid, 0, 0),
+    ('\u{1F68}', Status::Mapped, 1994, 1997),
+    ('\u{1F69}', Status::Mapped, 1997, 2000),
+    ('\u{1F6A}', Status::Mapped, 2000, 2003),
+    ('\u{1F6B}', Status::Mapped, 2003, 2006),
+    ('\u{1F6C}', Status::Mapped, 2006, 2009),
+    ('\u{1F6D}', Status::Mapped, 2009, 2012),
+    ('\u{1F6E}', Status::Mapped, 2012, 2015),
+    ('\u{1F6F}', Status::Mapped, 2015, 2018),
+    ('\u{1F70}', Status::Valid, 0, 0),
+    ('\u{1F71}', Status::Mapped, 518, 520),
+    ('\u{1F72}', Status::Valid, 0, 0),
+    ('\u{1F73}', Status::Mapped, 522, 524),
+    ('\u{1F74}', Status::Valid, 0, 0),
+    ('\u{1F75}', Status::Mapped, 524, 526),
+    ('\u{1F76}', Status::Valid, 0, 0),
+    ('\u{1F77}', Status::Mapped, 526, 528),
+    ('\u{1F78}', Status::Valid, 0, 0),
+    ('\u{1F79}', Status::Mapped, 528, 530),
+    ('\u{1F7A}', Status::Valid, 0, 0),
+    ('\u{1F7B}', Status::Mapped, 530, 532),
+    ('\u{1F7C}', Status::Valid, 0, 0),
+    ('\u{1F7D}', Status::Mapped, 532, 534),
+    ('\u{1F7E}', Status::Disallowed, 0, 0),
+    ('\u{1F80}', Status::Mapped, 2018, 2023),
+    ('\u{1F81}', Status::Mapped, 2023, 2028),
+    ('\u{1F82}', Status::Mapped, 2028, 2033),
+    ('\u{1F83}', Status::Mapped, 2033, 2038),
+    ('\u{1F84}', Status::Mapped, 2038, 2043),
+    ('\u{1F85}', Status::Mapped, 2043, 2048),
+    ('\u{1F86}', Status::Mapped, 2048, 2053),
+    ('\u{1F87}', Status::Mapped, 2053, 2058),
+    ('\u{1F88}', Status::Mapped, 2018, 2023),
+    ('\u{1F89}', Status::Mapped, 2023, 2028),
+    ('\u{1F8A}', Status::Mapped, 2028, 2033),
+    ('\u{1F8B}', Status::Mapped, 2033, 2038),
+    ('\u{1F8C}', Status::Mapped, 2038, 2043),
+    ('\u{1F8D}', Status::Mapped, 2043, 2048),
+    ('\u{1F8E}', Status::Mapped, 2048, 2053),
+    ('\u{1F8F}', Status::Mapped, 2053, 2058),
+    ('\u{1F90}', Status::Mapped, 2058, 2063),
+    ('\u{1F91}', Status::Mapped, 2063, 2068),
+    ('\u{1F92}', Status::Mapped, 2068, 2073),
+    ('\u{1F93}', Status::Mapped, 2073, 2078),
+    ('\u{1F94}', Status::Mapped, 2078, 2083),
+    ('\u{1F95}', Status::Mapped, 2083, 2088),
+    ('\u{1F96}', Status::Mapped, 2088, 2093),
+    ('\u{1F97}', Status::Mapped, 2093, 2098),
+    ('\u{1F98}', Status::Mapped, 2058, 2063),
+    ('\u{1F99}', Status::Mapped, 2063, 2068),
+    ('\u{1F9A}', Status::Mapped, 2068, 2073),
+    ('\u{1F9B}', Status::Mapped, 2073, 2078),
+    ('\u{1F9C}', Status::Mapped, 2078, 2083),
+    ('\u{1F9D}', Status::Mapped, 2083, 2088),
+    ('\u{1F9E}', Status::Mapped, 2088, 2093),
+    ('\u{1F9F}', Status::Mapped, 2093, 2098),
+    ('\u{1FA0}', Status::Mapped, 2098, 2103),
+    ('\u{1FA1}', Status::Mapped, 2103, 2108),
+    ('\u{1FA2}', Status::Mapped, 2108, 2113),
+    ('\u{1FA3}', Status::Mapped, 2113, 2118),
+    ('\u{1FA4}', Status::Mapped, 2118, 2123),
+    ('\u{1FA5}', Status::Mapped, 2123, 2128),
+    ('\u{1FA6}', Status::Mapped, 2128, 2133),
+    ('\u{1FA7}', Status::Mapped, 2133, 2138),
+    ('\u{1FA8}', Status::Mapped, 2098, 2103),
+    ('\u{1FA9}', Status::Mapped, 2103, 2108),
+    ('\u{1FAA}', Status::Mapped, 2108, 2113),
+    ('\u{1FAB}', Status::Mapped, 2113, 2118),
+    ('\u{1FAC}', Status::Mapped, 2118, 2123),
+    ('\u{1FAD}', Status::Mapped, 2123, 2128),
+    ('\u{1FAE}', Status::Mapped, 2128, 2133),
+    ('\u{1FAF}', Status::Mapped, 2133, 2138),
+    ('\u{1FB0}', Status::Valid, 0, 0),
+    ('\u{1FB2}', Status::Mapped, 2138, 2143),
+    ('\u{1FB3}', Status::Mapped, 2143, 2147),
+    ('\u{1FB4}', Status::Mapped, 2147, 2151),
+    ('\u{1FB5}', Status::Disallowed, 0, 0),
+    ('\u{1FB6}', Status::Valid, 0, 0),
+    ('\u{1FB7}', Status::Mapped, 2151, 2156),
+    ('\u{1FB8}', Status::Mapped, 2156, 2159),
+    ('\u{1FB9}', Status::Mapped, 2159, 2162),
+    ('\u{1FBA}', Status::Mapped, 2162, 2165),
+    ('\u{1FBB}', Status::Mapped, 518, 520),
+    ('\u{1FBC}', Status::Mapped, 2143, 2147),
+    ('\u{1FBD}', Status::Mapped, 2165, 2168),
+    ('\u{1FBE}', Status::Mapped, 497, 499),
+    ('\u{1FBF}', Status::Mapped, 2165, 2168),
+    ('\u{1FC0}', Status::Mapped, 2168, 2171),
+    ('\u{1FC1}', Status::Mapped, 2171, 2176),
+    ('\u{1FC2}', Status::Mapped, 2176, 2181),
+    ('\u{1FC3}', Status::Mapped, 2181, 2185),
+    ('\u{1FC4}', Status::Mapped, 2185, 2189),
+    ('\u{1FC5}', Status::Disallowed, 0, 0),
+    ('\u{1FC6}', Status::Valid, 0, 0),
+    ('\u{1FC7}', Status::Mapped, 2189, 2194),
+    ('\u{1FC8}', Status::Mapped, 2194, 2197),
+    ('\u{1FC9}', Status::Mapped, 522, 524),
+    ('\u{1FCA}', Status::Mapped, 2197, 2200),
+    ('\u{1FCB}', Status::Mapped, 524, 526),
+    ('\u{1FCC}', Status::Mapped, 2181, 2185),
+    ('\u{1FCD}', Status::Mapped, 2200, 2205),
+    ('\u{1FCE}', Status::Mapped, 2205, 2210),
+    ('\u{1FCF}', Status::Mapped, 2210, 2215),
+    ('\u{1FD0}', Status::Valid, 0, 0),
+    ('\u{1FD3}', Status::Mapped, 2215, 2217),
+    ('\u{1FD4}', Status::Disallowed, 0, 0),
+    ('\u{1FD6}', Status::Valid, 0, 0),
+    ('\u{1FD8}', Status::Mapped, 2217, 2220),
+    ('\u{1FD9}', Status::Mapped, 2220, 2223),
+    ('\u{1FDA}', Status::Mapped, 2223, 2226),
+    ('\u{1FDB}', Status::Mapped, 526, 528),
+    ('\u{1FDC}', Status::Disallowed, 0, 0),
+    ('\u{1FDD}', Status::Mapped, 2226, 2231),
+    ('\u{1FDE}', Status::Mapped, 2231, 2236),
+    ('\u{1FDF}', Status::Mapped, 2236, 2241),
+    ('\u{1FE0}', Status::Valid, 0, 0),
+    ('\u{1FE3}', Status::Mapped, 2241, 2243),
+    ('\u{1FE4}', Status::Valid, 0, 0),
+    ('\u{1FE8}', Status::Mapped, 2243, 2246),
+    ('\u{1FE9}', Status::Mapped, 2246, 2249),
+    ('\u{1FEA}', Status::Mapped, 2249, 2252),
+    ('\u{1FEB}', Status::Mapped, 530, 532),
+    ('\u{1FEC}', Status::Mapped, 2252, 2255),
+    ('\u{1FED}', Status::Mapped, 2255, 2260),
+    ('\u{1FEE}', Status::Mapped, 513, 518),
+    ('\u{1FEF}', Status::Mapped, 2260, 2261),
+    ('\u{1FF0}', Status::Disallowed, 0, 0),
+    ('\u{1FF2}', Status::Mapped, 2261, 2266),
+    ('\u{1FF3}', Status::Mapped, 2266, 2270),
+    ('\u{1FF4}', Status::Mapped, 2270, 2274),
+    ('\u{1FF5}', Status::Disallowed, 0, 0),
+    ('\u{1FF6}', Status::Valid, 0, 0),
+    ('\u{1FF7}', Status::Mapped, 2274, 2279),
+    ('\u{1FF8}', Status::Mapped, 2279, 2282),
+    ('\u{1FF9}', Status::Mapped, 528, 530),
+    ('\u{1FFA}', Status::Mapped, 2282, 2285),
+    ('\u{1FFB}', Status::Mapped, 532, 534),
+    ('\u{1FFC}', Status::Mapped, 2266, 2270),
+    ('\u{1FFD}', Status::Mapped, 35, 38),
+    ('\u{1FFE}', Status::Mapped, 2285, 2288),
+    ('\u{1FFF}', Status::Disallowed, 0, 0),
+    ('\u{2000}', Status::Mapped, 26, 27),
+    ('\u{200B}', Status::Ignored, 0, 0),
+    ('\u{200C}', Status::Deviation, 0, 0),
+    ('\u{200E}', Status::Disallowed, 0, 0),
+    ('\u{2010}', Status::Valid, 0, 0),
+    ('\u{2011}', Status::Mapped, 2288, 2291),
+    ('\u{2012}', Status::Valid, 0, 0),
+    ('\u{2017}', Status::Mapped, 2291, 2294),
+    ('\u{2018}', Status::Valid, 0, 0),
+    ('\u{2024}', Status::Disallowed, 0, 0),
+    ('\u{2027}', Status::Valid, 0, 0),
+    ('\u{2028}', Status::Disallowed, 0, 0),
+    ('\u{202F}', Status::Mapped, 26, 27),
+    ('\u{2030}', Status::Valid, 0, 0),
+    ('\u{2033}', Status::Mapped, 2294, 2300),
+    ('\u{2034}', Status::Mapped, 2300, 2309),
+    ('\u{2035}', Status::Valid, 0, 0),
+    ('\u{2036}', Status::Mapped, 2309, 2315),
+    ('\u{2037}', Status::Mapped, 2315, 2324),
+    ('\u{2038}', Status::Valid, 0, 0),
+    ('\u{203C}', Status::Mapped, 2324, 2326),
+    ('\u{203D}', Status::Valid, 0, 0),
+    ('\u{203E}', Status::Mapped, 2326, 2329),
+    ('\u{203F}', Status::Valid, 0, 0),
+    ('\u{2047}', Status::Mapped, 2329, 2331),
+    ('\u{2048}', Status::Mapped, 2331, 2333),
+    ('\u{2049}', Status::Mapped, 2333, 2335),
+    ('\u{204A}', Status::Valid, 0, 0),
+    ('\u{2057}', Status::Mapped, 2335, 2347),
+    ('\u{2058}', Status::Valid, 0, 0),
+    ('\u{205F}', Status::Mapped, 26, 27),
+    ('\u{2060}', Status::Ignored, 0, 0),
+    ('\u{2061}', Status::Disallowed, 0, 0),
+    ('\u{2064}', Status::Ignored, 0, 0),
+    ('\u{2065}', Status::Disallowed, 0, 0),
+    ('\u{2070}', Status::Mapped, 2347, 2348),
+    ('\u{2071}', Status::Mapped, 8, 9),
+    ('\u{2072}', Status::Disallowed, 0, 0),
+    ('\u{2074}', Status::Mapped, 2348, 2349),
+    ('\u{2075}', Status::Mapped, 2349, 2350),
+    ('\u{2076}', Status::Mapped, 2350, 2351),
+    ('\u{2077}', Status::Mapped, 2351, 2352),
+    ('\u{2078}', Status::Mapped, 2352, 2353),
+    ('\u{2079}', Status::Mapped, 2353, 2354),
+    ('\u{207A}', Status::Mapped, 2354, 2355),
+    ('\u{207B}', Status::Mapped, 2355, 2358),
+    ('\u{207C}', Status::Mapped, 2358, 2359),
+    ('\u{207D}', Status::Mapped, 2359, 2360),
+    ('\u{207E}', Status::Mapped, 2360, 2361),
+    ('\u{207F}', Status::Mapped, 13, 14),
+    ('\u{2080}', Status::Mapped, 2347, 2348),
+    ('\u{2081}', Status::Mapped, 43, 44),
+    ('\u{2082}', Status::Mapped, 33, 34),
+    ('\u{2083}', Status::Mapped, 34, 35),
+    ('\u{2084}', Status::Mapped, 2348, 2349),
+    ('\u{2085}', Status::Mapped, 2349, 2350),
+    ('\u{2086}', Status::Mapped, 2350, 2351),
+    ('\u{2087}', Status::Mapped, 2351, 2352),
+    ('\u{2088}', Status::Mapped, 2352, 2353),
+    ('\u{2089}', Status::Mapped, 2353, 2354),
+    ('\u{208A}', Status::Mapped, 2354, 2355),
+    ('\u{208B}', Status::Mapped, 2355, 2358),
+    ('\u{208C}', Status::Mapped, 2358, 2359),
+    ('\u{208D}', Status::Mapped, 2359, 2360),
+    ('\u{208E}', Status::Mapped, 2360, 2361),
+    ('\u{208F}', Status::Disallowed, 0, 0),
+    ('\u{2090}', Status::Mapped, 0, 1),
+    ('\u{2091}', Status::Mapped, 4, 5),
+    ('\u{2092}', Status::Mapped, 14, 15),
+    ('\u{2093}', Status::Mapped, 23, 24),
+    ('\u{2094}', Status::Mapped, 268, 270),
+    ('\u{2095}', Status::Mapped, 7, 8),
+    ('\u{2096}', Status::Mapped, 10, 11),
+    ('\u{2097}', Status::Mapped, 11, 12),
+    ('\u{2098}', Status::Mapped, 12, 13),
+    ('\u{2099}', Status::Mapped, 13, 14),
+    ('\u{209A}', Status::Mapped, 15, 16),
+    ('\u{209B}', Status::Mapped, 18, 19),
+    ('\u{209C}', Status::Mapped, 19, 20),
+    ('\u{209D}', Status::Disallowed, 0, 0),
+    ('\u{20A0}', Status::Valid, 0, 0),
+    ('\u{20A8}', Status::Mapped, 2361, 2363),
+    ('\u{20A9}', Status::Valid, 0, 0),
+    ('\u{20C1}', Status::Disallowed, 0, 0),
+    ('\u{20D0}', Status::Valid, 0, 0),
+    ('\u{20F1}', Status::Disallowed, 0, 0),
+    ('\u{2100}', Status::Mapped, 2363, 2366),
+    ('\u{2101}', Status::Mapped, 2366, 2369),
+    ('\u{2102}', Status::Mapped, 2, 3),
+    ('\u{2103}', Status::Mapped, 2369, 2372),
+    ('\u{2104}', Status::Valid, 0, 0),
+    ('\u{2105}', Status::Mapped, 2372, 2375),
+    ('\u{2106}', Status::Mapped, 2375, 2378),
+    ('\u{2107}', Status::Mapped, 270, 272),
+    ('\u{2108}', Status::Valid, 0, 0),
+    ('\u{2109}', Status::Mapped, 2378, 2381),
+    ('\u{210A}', Status::Mapped, 6, 7),
+    ('\u{210B}', Status::Mapped, 7, 8),
+    ('\u{210F}', Status::Mapped, 157, 159),
+    ('\u{2110}', Status::Mapped, 8, 9),
+    ('\u{2112}', Status::Mapped, 11, 12),
+    ('\u{2114}', Status::Valid, 0, 0),
+    ('\u{2115}', Status::Mapped, 13, 14),
+    ('\u{2116}', Status::Mapped, 2381, 2383),
+    ('\u{2117}', Status::Valid, 0, 0),
+    ('\u{2119}', Status::Mapped, 15, 16),
+    ('\u{211A}', Status::Mapped, 16, 17),
+    ('\u{211B}', Status::Mapped, 17, 18),
+    ('\u{211E}', Status::Valid, 0, 0),
+    ('\u{2120}', Status::Mapped, 2383, 2385),
+    ('\u{2121}', Status::Mapped, 2385, 2388),
+    ('\u{2122}', Status::Mapped, 2388, 2390),
+    ('\u{2123}', Status::Valid, 0, 0),
+    ('\u{2124}', Status::Mapped, 25, 26),
+    ('\u{2125}', Status::Valid, 0, 0),
+    ('\u{2126}', Status::Mapped, 576, 578),
+    ('\u{2127}', Status::Valid, 0, 0),
+    ('\u{2128}', Status::Mapped, 25, 26),
+    ('\u{2129}', Status::Valid, 0, 0),
+    ('\u{212A}', Status::Mapped, 10, 11),
+    ('\u{212B}', Status::Mapped, 69, 71),
+    ('\u{212C}', Status::Mapped, 1, 2),
+    ('\u{212D}', Status::Mapped, 2, 3),
+    ('\u{212E}', Status::Valid, 0, 0),
+    ('\u{212F}', Status::Mapped, 4, 5),
+    ('\u{2131}', Status::Mapped, 5, 6),
+    ('\u{2132}', Status::Disallowed, 0, 0),
+    ('\u{2133}', Status::Mapped, 12, 13),
+    ('\u{2134}', Status::Mapped, 14, 15),
+    ('\u{2135}', Status::Mapped, 2390, 2392),
+    ('\u{2136}', Status::Mapped, 2392, 2394),
+    ('\u{2137}', Status::Mapped, 2394, 2396),
+    ('\u{2138}', Status::Mapped, 2396, 2398),
+    ('\u{2139}', Status::Mapped, 8, 9),
+    ('\u{213A}', Status::Valid, 0, 0),
+    ('\u{213B}', Status::Mapped, 2398, 2401),
+    ('\u{213C}', Status::Mapped, 560, 562),
+    ('\u{213D}', Status::Mapped, 538, 540),
+    ('\u{213F}', Status::Mapped, 560, 562),
+    ('\u{2140}', Status::Mapped, 2401, 2404),
+    ('\u{2141}', Status::Valid, 0, 0),
+    ('\u{2145}', Status::Mapped, 3, 4),
+    ('\u{2147}', Status::Mapped, 4, 5),
+    ('\u{2148}', Status::Mapped, 8, 9),
+    ('\u{2149}', Status::Mapped, 9, 10),
+    ('\u{214A}', Status::Valid, 0, 0),
+    ('\u{2150}', Status::Mapped, 2404, 2409),
+    ('\u{2151}', Status::Mapped, 2409, 2414),
+    ('\u{2152}', Status::Mapped, 2414, 2420),
+    ('\u{2153}', Status::Mapped, 2420, 2425),
+    ('\u{2154}', Status::Mapped, 2425, 2430),
+    ('\u{2155}', Status::Mapped, 2430, 2435),
+    ('\u{2156}', Status::Mapped, 2435, 2440),
+    ('\u{2157}', Status::Mapped, 2440, 2445),
+    ('\u{2158}', Status::Mapped, 2445, 2450),
+    ('\u{2159}', Status::Mapped, 2450, 2455),
+    ('\u{215A}', Status::Mapped, 2455, 2460),
+    ('\u{215B}', Status::Mapped, 2460, 2465),
+    ('\u{215C}', Status::Mapped, 2465, 2470),
+    ('\u{215D}', Status::Mapped, 2470, 2475),
+    ('\u{215E}', Status::Mapped, 2475, 2480),
+    ('\u{215F}', Status::Mapped, 2480, 2484),
+    ('\u{2160}', Status::Mapped, 8, 9),
+    ('\u{2161}', Status::Mapped, 2484, 2486),
+    ('\u{2162}', Status::Mapped, 2486, 2489),
+    ('\u{2163}', Status::Mapped, 2489, 2491),
+    ('\u{2164}', Status::Mapped, 21, 22),
+    ('\u{2165}', Status::Mapped, 2491, 2493),
+    ('\u{2166}', Status::Mapped, 2493, 2496),
+    ('\u{2167}', Status::Mapped, 2496, 2500),
+    ('\u{2168}', Status::Mapped, 2500, 2502),
+    ('\u{2169}', Status::Mapped, 23, 24),
+    ('\u{216A}', Status::Mapped, 2502, 2504),
+    ('\u{216B}', Status::Mapped, 2504, 2507),
+    ('\u{216C}', Status::Mapped, 11, 12),
+    ('\u{216D}', Status::Mapped, 2, 3),
+    ('\u{216E}', Status::Mapped, 3, 4),
+    ('\u{216F}', Status::Mapped, 12, 13),
+    ('\u{2170}', Status::Mapped, 8, 9),
+    ('\u{2171}', Status::Mapped, 2484, 2486),
+    ('\u{2172}', Status::Mapped, 2486, 2489),
+    ('\u{2173}', Status::Mapped, 2489, 2491),
+    ('\u{2174}', Status::Mapped, 21, 22),
+    ('\u{2175}', Status::Mapped, 2491, 2493),
+    ('\u{2176}', Status::Mapped, 2493, 2496),
+    ('\u{2177}', Status::Mapped, 2496, 2500),
+    ('\u{2178}', Status::Mapped, 2500, 2502),
+    ('\u{2179}', Status::Mapped, 23, 24),
+    ('\u{217A}', Status::Mapped, 2502, 2504),
+    ('\u{217B}', Status::Mapped, 2504, 2507),
+    ('\u{217C}', Status::Mapped, 11, 12),
+    ('\u{217D}', Status::Mapped, 2, 3),
+    ('\u{217E}', Status::Mapped, 3, 4),
+    ('\u{217F}', Status::Mapped, 12, 13),
+    ('\u{2180}', Status::Valid, 0, 0),
+    ('\u{2183}', Status::Disallowed, 0, 0),
+    ('\u{2184}', Status::Valid, 0, 0),
+    ('\u{2189}', Status::Mapped, 2507, 2512),
+    ('\u{218A}', Status::Valid, 0, 0),
+    ('\u{218C}', Status::Disallowed, 0, 0),
+    ('\u{2190}', Status::Valid, 0, 0),
+    ('\u{222C}', Status::Mapped, 2512, 2518),
+    ('\u{222D}', Status::Mapped, 2518, 2527),
+    ('\u{222E}', Status::Valid, 0, 0),
+    ('\u{222F}', Status::Mapped, 2527, 2533),
+    ('\u{2230}', Status::Mapped, 2533, 2542),
+    ('\u{2231}', Status::Valid, 0, 0),
+    ('\u{2329}', Status::Mapped, 2542, 2545),
+    ('\u{232A}', Status::Mapped, 2545, 2548),
+    ('\u{232B}', Status::Valid, 0, 0),
+    ('\u{2427}', Status::Disallowed, 0, 0),
+    ('\u{2440}', Status::Valid, 0, 0),
+    ('\u{244B}', Status::Disallowed, 0, 0),
+    ('\u{2460}', Status::Mapped, 43, 44),
+    ('\u{2461}', Status::Mapped, 33, 34),
+    ('\u{2462}', Status::Mapped, 34, 35),
+    ('\u{2463}', Status::Mapped, 2348, 2349),
+    ('\u{2464}', Status::Mapped, 2349, 2350),
+    ('\u{2465}', Status::Mapped, 2350, 2351),
+    ('\u{2466}', Status::Mapped, 2351, 2352),
+    ('\u{2467}', Status::Mapped, 2352, 2353),
+    ('\u{2468}', Status::Mapped, 2353, 2354),
+    ('\u{2469}', Status::Mapped, 2548, 2550),
+    ('\u{246A}', Status::Mapped, 2550, 2552),
+    ('\u{246B}', Status::Mapped, 2552, 2554),
+    ('\u{246C}', Status::Mapped, 2554, 2556),
+    ('\u{246D}', Status::Mapped, 2556, 2558),
+    ('\u{246E}', Status::Mapped, 2558, 2560),
+    ('\u{246F}', Status::Mapped, 2560, 2562),
+    ('\u{2470}', Status::Mapped, 2562, 2564),
+    ('\u{2471}', Status::Mapped, 2564, 2566),
+    ('\u{2472}', Status::Mapped, 2566, 2568),
+    ('\u{2473}', Status::Mapped, 2568, 2570),
+    ('\u{2474}', Status::Mapped, 2570, 2573),
+    ('\u{2475}', Status::Mapped, 2573, 2576),
+    ('\u{2476}', Status::Mapped, 2576, 2579),
+    ('\u{2477}', Status::Mapped, 2579, 2582),
+    ('\u{2478}', Status::Mapped, 2582, 2585),
+    ('\u{2479}', Status::Mapped, 2585, 2588),
+    ('\u{247A}', Status::Mapped, 2588, 2591),
+    ('\u{247B}', Status::Mapped, 2591, 2594),
+    ('\u{247C}', Status::Mapped, 2594, 2597),
+    ('\u{247D}', Status::Mapped, 2597, 2601),
+    ('\u{247E}', Status::Mapped, 2601, 2605),
+    ('\u{247F}', Status::Mapped, 2605, 2609),
+    ('\u{2480}', Status::Mapped, 2609, 2613),
+    ('\u{2481}', Status::Mapped, 2613, 2617),
+    ('\u{2482}', Status::Mapped, 2617, 2621),
+    ('\u{2483}', Status::Mapped, 2621, 2625),
+    ('\u{2484}', Status::Mapped, 2625, 2629),
+    ('\u{2485}', Status::Mapped, 2629, 2633),
+    ('\u{2486}', Status::Mapped, 2633, 2637),
+    ('\u{2487}', Status::Mapped, 2637, 2641),
+    ('\u{2488}', Status::Disallowed, 0, 0),
+    ('\u{249C}', Status::Mapped, 2641, 2644),
+    ('\u{249D}', Status::Mapped, 2644, 2647),
+    ('\u{249E}', Status::Mapped, 2647, 2650),
+    ('\u{249F}', Status::Mapped, 2650, 2653),
+    ('\u{24A0}', Status::Mapped, 2653, 2656),
+    ('\u{24A1}', Status::Mapped, 2656, 2659),
+    ('\u{24A2}', Status::Mapped, 2659, 2662),
+    ('\u{24A3}', Status::Mapped, 2662, 2665),
+    ('\u{24A4}', Status::Mapped, 2665, 2668),
+    ('\u{24A5}', Status::Mapped, 2668, 2671),
+    ('\u{24A6}', Status::Mapped, 2671, 2674),
+    ('\u{24A7}', Status::Mapped, 2674, 2677),
+    ('\u{24A8}', Status::Mapped, 2677, 2680),
+    ('\u{24A9}', Status::Mapped, 2680, 2683),
+    ('\u{24AA}', Status::Mapped, 2683, 2686),
+    ('\u{24AB}', Status::Mapped, 2686, 2689),
+    ('\u{24AC}', Status::Mapped, 2689, 2692),
+    ('\u{24AD}', Status::Mapped, 2692, 2695),
+    ('\u{24AE}', Status::Mapped, 2695, 2698),
+    ('\u{24AF}', Status::Mapped, 2698, 2701),
+    ('\u{24B0}', Status::Mapped, 2701, 2704),
+    ('\u{24B1}', Status::Mapped, 2704, 2707),
+    ('\u{24B2}', Status::Mapped, 2707, 2710),
+    ('\u{24B3}', Status::Mapped, 2710, 2713),
+    ('\u{24B4}', Status::Mapped, 2713, 2716),
+    ('\u{24B5}', Status::Mapped, 2716, 2719),
+    ('\u{24B6}', Status::Mapped, 0, 1),
+    ('\u{24B7}', Status::Mapped, 1, 2),
+    ('\u{24B8}', Status::Mapped, 2, 3),
+    ('\u{24B9}', Status::Mapped, 3, 4),
+    ('\u{24BA}', Status::Mapped, 4, 5),
+    ('\u{24BB}', Status::Mapped, 5, 6),
+    ('\u{24BC}', Status::Mapped, 6, 7),
+    ('\u{24BD}', Status::Mapped, 7, 8),
+    ('\u{24BE}', Status::Mapped, 8, 9),
+    ('\u{24BF}', Status::Mapped, 9, 10),
+    ('\u{24C0}', Status::Mapped, 10, 11),
+    ('\u{24C1}', Status::Mapped, 11, 12),
+    ('\u{24C2}', Status::Mapped, 12, 13),
+    ('\u{24C3}', Status::Mapped, 13, 14),
+    ('\u{24C4}', Status::Mapped, 14, 15),
+    ('\u{24C5}', Status::Mapped, 15, 16),
+    ('\u{24C6}', Status::Mapped, 16, 17),
+    ('\u{24C7}', Status::Mapped, 17, 18),
+    ('\u{24C8}', Status::Mapped, 18, 19),
+    ('\u{24C9}', Status::Mapped, 19, 20),
+    ('\u{24CA}', Status::Mapped, 20, 21),
+    ('\u{24CB}', Status::Mapped, 21, 22),
+    ('\u{24CC}', Status::Mapped, 22, 23),
+    ('\u{24CD}', Status::Mapped, 23, 24),
+    ('\u{24CE}', Status::Mapped, 24, 25),
+    ('\u{24CF}', Status::Mapped, 25, 26),
+    ('\u{24D0}', Status::Mapped, 0, 1),
+    ('\u{24D1}', Status::Mapped, 1, 2),
+    ('\u{24D2}', Status::Mapped, 2, 3),
+    ('\u{24D3}', Status::Mapped, 3, 4),
+    ('\u{24D4}', Status::Mapped, 4, 5),
+    ('\u{24D5}', Status::Mapped, 5, 6),
+    ('\u{24D6}', Status::Mapped, 6, 7),
+    ('\u{24D7}', Status::Mapped, 7, 8),
+    ('\u{24D8}', Status::Mapped, 8, 9),
+    ('\u{24D9}', Status::Mapped, 9, 10),
+    ('\u{24DA}', Status::Mapped, 10, 11),
+    ('\u{24DB}', Status::Mapped, 11, 12),
+    ('\u{24DC}', Status::Mapped, 12, 13),
+    ('\u{24DD}', Status::Mapped, 13, 14),
+    ('\u{24DE}', Status::Mapped, 14, 15),
+    ('\u{24DF}', Status::Mapped, 15, 16),
+    ('\u{24E0}', Status::Mapped, 16, 17),
+    ('\u{24E1}', Status::Mapped, 17, 18),
+    ('\u{24E2}', Status::Mapped, 18, 19),
+    ('\u{24E3}', Status::Mapped, 19, 20),
+    ('\u{24E4}', Status::Mapped, 20, 21),
+    ('\u{24E5}', Status::Mapped, 21, 22),
+    ('\u{24E6}', Status::Mapped, 22, 23),
+    ('\u{24E7}', Status::Mapped, 23, 24),
+    ('\u{24E8}', Status::Mapped, 24, 25),
+    ('\u{24E9}', Status::Mapped, 25, 26),
+    ('\u{24EA}', Status::Mapped, 2347, 2348),
+    ('\u{24EB}', Status::Valid, 0, 0),
+    ('\u{2A0C}', Status::Mapped, 2719, 2731),
+    ('\u{2A0D}', Status::Valid, 0, 0),
+    ('\u{2A74}', Status::Mapped, 2731, 2734),
+    ('\u{2A75}', Status::Mapped, 2734, 2736),
+    ('\u{2A76}', Status::Mapped, 2736, 2739),
+    ('\u{2A77}', Status::Valid, 0, 0),
+    ('\u{2ADC}', Status::Mapped, 2739, 2744),
+    ('\u{2ADD}', Status::Valid, 0, 0),
+    ('\u{2B74}', Status::Disallowed, 0, 0),
+    ('\u{2B76}', Status::Valid, 0, 0),
+    ('\u{2B96}', Status::Disallowed, 0, 0),
+    ('\u{2B97}', Status::Valid, 0, 0),
+    ('\u{2C00}', Status::Mapped, 2744, 2747),
+    ('\u{2C01}', Status::Mapped, 2747, 2750),
+    ('\u{2C02}', Status::Mapped, 2750, 2753),
+    ('\u{2C03}', Status::Mapped, 2753, 2756),
+    ('\u{2C04}', Status::Mapped, 2756, 2759),
+    ('\u{2C05}', Status::Mapped, 2759, 2762),
+    ('\u{2C06}', Status::Mapped, 2762, 2765),
+    ('\u{2C07}', Status::Mapped, 2765, 2768),
+    ('\u{2C08}', Status::Mapped, 2768, 2771),
+    ('\u{2C09}', Status::Mapped, 2771, 2774),
+    ('\u{2C0A}', Status::Mapped, 2774, 2777),
+    ('\u{2C0B}', Status::Mapped, 2777, 2780),
+    ('\u{2C0C}', Status::Mapped, 2780, 2783),
+    ('\u{2C0D}', Status::Mapped, 2783, 2786),
+    ('\u{2C0E}', Status::Mapped, 2786, 2789),
+    ('\u{2C0F}', Status::Mapped, 2789, 2792),
+    ('\u{2C10}', Status::Mapped, 2792, 2795),
+    ('\u{2C11}', Status::Mapped, 2795, 2798),
+    ('\u{2C12}', Status::Mapped, 2798, 2801),
+    ('\u{2C13}', Status::Mapped, 2801, 2804),
+    ('\u{2C14}', Status::Mapped, 2804, 2807),
+    ('\u{2C15}', Status::Mapped, 2807, 2810),
+    ('\u{2C16}', Status::Mapped, 2810, 2813),
+    ('\u{2C17}', Status::Mapped, 2813, 2816),
+    ('\u{2C18}', Status::Mapped, 2816, 2819),
+    ('\u{2C19}', Status::Mapped, 2819, 2822),
+    ('\u{2C1A}', Status::Mapped, 2822, 2825),
+    ('\u{2C1B}', Status::Mapped, 2825, 2828),
+    ('\u{2C1C}', Status::Mapped, 2828, 2831),
+    ('\u{2C1D}', Status::Mapped, 2831, 2834),
+    ('\u{2C1E}', Status::Mapped, 2834, 2837),
+    ('\u{2C1F}', Status::Mapped, 2837, 2840),
+    ('\u{2C20}', Status::Mapped, 2840, 2843),
+    ('\u{2C21}', Status::Mapped, 2843, 2846),
+    ('\u{2C22}', Status::Mapped, 2846, 2849),
+    ('\u{2C23}', Status::Mapped, 2849, 2852),
+    ('\u{2C24}', Status::Mapped, 2852, 2855),
+    ('\u{2C25}', Status::Mapped, 2855, 2858),
+    ('\u{2C26}', Status::Mapped, 2858, 2861),
+    ('\u{2C27}', Status::Mapped, 2861, 2864),
+    ('\u{2C28}', Status::Mapped, 2864, 2867),
+    ('\u{2C29}', Status::Mapped, 2867, 2870),
+    ('\u{2C2A}', Status::Mapped, 2870, 2873),
+    ('\u{2C2B}', Status::Mapped, 2873, 2876),
+    ('\u{2C2C}', Status::Mapped, 2876, 2879),
+    ('\u{2C2D}', Status::Mapped, 2879, 2882),
+    ('\u{2C2E}', Status::Mapped, 2882, 2885),
+    ('\u{2C2F}', Status::Mapped, 2885, 2888),
+    ('\u{2C30}', Status::Valid, 0, 0),
+    ('\u{2C60}', Status::Mapped, 2888, 2891),
+    ('\u{2C61}', Status::Valid, 0, 0),
+    ('\u{2C62}', Status::Mapped, 2891, 2893),
+    ('\u{2C63}', Status::Mapped, 2893, 2896),
+    ('\u{2C64}', Status::Mapped, 2896, 2898),
+    ('\u{2C65}', Status::Valid, 0, 0),
+    ('\u{2C67}', Status::Mapped, 2898, 2901),
+    ('\u{2C68}', Status::Valid, 0, 0),
+    ('\u{2C69}', Status::Mapped, 2901, 2904),
+    ('\u{2C6A}', Status::Valid, 0, 0),
+    ('\u{2C6B}', Status::Mapped, 2904, 2907),
+    ('\u{2C6C}', Status::Valid, 0, 0),
+    ('\u{2C6D}', Status::Mapped, 1436, 1438),
+    ('\u{2C6E}', Status::Mapped, 1479, 1481),
+    ('\u{2C6F}', Status::Mapped, 1434, 1436),
+    ('\u{2C70}', Status::Mapped, 1455, 1457),
+    ('\u{2C71}', Status::Valid, 0, 0),
+    ('\u{2C72}', Status::Mapped, 2907, 2910),
+    ('\u{2C73}', Status::Valid, 0, 0),
+    ('\u{2C75}', Status::Mapped, 2910, 2913),
+    ('\u{2C76}', Status::Valid, 0, 0),
+    ('\u{2C7C}', Status::Mapped, 9, 10),
+    ('\u{2C7D}', Status::Mapped, 21, 22),
+    ('\u{2C7E}', Status::Mapped, 2913, 2915),
+    ('\u{2C7F}', Status::Mapped, 2915, 2917),
+    ('\u{2C80}', Status::Mapped, 2917, 2920),
+    ('\u{2C81}', Status::Valid, 0, 0),
+    ('\u{2C82}', Status::Mapped, 2920, 2923),
+    ('\u{2C83}', Status::Valid, 0, 0),
+    ('\u{2C84}', Status::Mapped, 2923, 2926),
+    ('\u{2C85}', Status::Valid, 0, 0),
+    ('\u{2C86}', Status::Mapped, 2926, 2929),
+    ('\u{2C87}', Status::Valid, 0, 0),
+    ('\u{2C88}', Status::Mapped, 2929, 2932),
+    ('\u{2C89}', Status::Valid, 0, 0),
+    ('\u{2C8A}', Status::Mapped, 2932, 2935),
+    ('\u{2C8B}', Status::Valid, 0, 0),
+    ('\u{2C8C}', Status::Mapped, 2935, 2938),
+    ('\u{2C8D}', Status::Valid, 0, 0),
+    ('\u{2C8E}', Status::Mapped, 2938, 2941),
+    ('\u{2C8F}', Status::Valid, 0, 0),
+    ('\u{2C90}', Status::Mapped, 2941, 2944),
+    ('\u{2C91}', Status::Valid, 0, 0),
+    ('\u{2C92}', Status::Mapped, 2944, 2947),
+    ('\u{2C93}', Status::Valid, 0, 0),
+    ('\u{2C94}', Status::Mapped, 2947, 2950),
+    ('\u{2C95}', Status::Valid, 0, 0),
+    ('\u{2C96}', Status::Mapped, 2950, 2953),
+    ('\u{2C97}', Status::Valid, 0, 0),
+    ('\u{2C98}', Status::Mapped, 2953, 2956),
+    ('\u{2C99}', Status::Valid, 0, 0),
+    ('\u{2C9A}', Status::Mapped, 2956, 2959),
+    ('\u{2C9B}', Status::Valid, 0, 0),
+    ('\u{2C9C}', Status::Mapped, 2959, 2962),
+    ('\u{2C9D}', Status::Valid, 0, 0),
+    ('\u{2C9E}', Status::Mapped, 2962, 2965),
+    ('\u{2C9F}', Status::Valid, 0, 0),
+    ('\u{2CA0}', Status::Mapped, 2965, 2968),
+    ('\u{2CA1}', Status::Valid, 0, 0),
+    ('\u{2CA2}', Status::Mapped, 2968, 2971),
+    ('\u{2CA3}', Status::Valid, 0, 0),
+    ('\u{2CA4}', Status::Mapped, 2971, 2974),
+    ('\u{2CA5}', Status::Valid, 0, 0),
+    ('\u{2CA6}', Status::Mapped, 2974, 2977),
+    ('\u{2CA7}', Status::Valid, 0, 0),
+    ('\u{2CA8}', Status::Mapped, 2977, 2980),
+    ('\u{2CA9}', Status::Valid, 0, 0),
+    ('\u{2CAA}', Status::Mapped, 2980, 2983),
+    ('\u{2CAB}', Status::Valid, 0, 0),
+    ('\u{2CAC}', Status::Mapped, 2983, 2986),
+    ('\u{2CAD}', Status::Valid, 0, 0),
+    ('\u{2CAE}', Status::Mapped, 2986, 2989),
+    ('\u{2CAF}', Status::Valid, 0, 0),
+    ('\u{2CB0}', Status::Mapped, 2989, 2992),
+    ('\u{2CB1}', Status::Valid, 0, 0),
+    ('\u{2CB2}', Status::Mapped, 2992, 2995),
+    ('\u{2CB3}', Status::Valid, 0, 0),
+    ('\u{2CB4}', Status::Mapped, 2995, 2998),
+    ('\u{2CB5}', Status::Valid, 0, 0),
+    ('\u{2CB6}', Status::Mapped, 2998, 3001),
+    ('\u{2CB7}', Status::Valid, 0, 0),
+    ('\u{2CB8}', Status::Mapped, 3001, 3004),
+    ('\u{2CB9}', Status::Valid, 0, 0),
+    ('\u{2CBA}', Status::Mapped, 3004, 3007),
+    ('\u{2CBB}', Status::Valid, 0, 0),
+    ('\u{2CBC}', Status::Mapped, 3007, 3010),
+    ('\u{2CBD}', Status::Valid, 0, 0),
+    ('\u{2CBE}', Status::Mapped, 3010, 3013),
+    ('\u{2CBF}', Status::Valid, 0, 0),
+    ('\u{2CC0}', Status::Mapped, 3013, 3016),
+    ('\u{2CC1}', Status::Valid, 0, 0),
+    ('\u{2CC2}', Status::Mapped, 3016, 3019),
+    ('\u{2CC3}', Status::Valid, 0, 0),
+    ('\u{2CC4}', Status::Mapped, 3019, 3022),
+    ('\u{2CC5}', Status::Valid, 0, 0),
+    ('\u{2CC6}', Status::Mapped, 3022, 3025),
+    ('\u{2CC7}', Status::Valid, 0, 0),
+    ('\u{2CC8}', Status::Mapped, 3025, 3028),
+    ('\u{2CC9}', Status::Valid, 0, 0),
+    ('\u{2CCA}', Status::Mapped, 3028, 3031),
+    ('\u{2CCB}', Status::Valid, 0, 0),
+    ('\u{2CCC}', Status::Mapped, 3031, 3034),
+    ('\u{2CCD}', Status::Valid, 0, 0),
+    ('\u{2CCE}', Status::Mapped, 3034, 3037),
+    ('\u{2CCF}', Status::Valid, 0, 0),
+    ('\u{2CD0}', Status::Mapped, 3037, 3040),
+    ('\u{2CD1}', Status::Valid, 0, 0),
+    ('\u{2CD2}', Status::Mapped, 3040, 3043),
+    ('\u{2CD3}', Status::Valid, 0, 0),
+    ('\u{2CD4}', Status::Mapped, 3043, 3046),
+    ('\u{2CD5}', Status::Valid, 0, 0),
+    ('\u{2CD6}', Status::Mapped, 3046, 3049),
+    ('\u{2CD7}', Status::Valid, 0, 0),
+    ('\u{2CD8}', Status::Mapped, 3049, 3052),
+    ('\u{2CD9}', Status::Valid, 0, 0),
+    ('\u{2CDA}', Status::Mapped, 3052, 3055),
+    ('\u{2CDB}', Status::Valid, 0, 0),
+    ('\u{2CDC}', Status::Mapped, 3055, 3058),
+    ('\u{2CDD}', Status::Valid, 0, 0),
+    ('\u{2CDE}', Status::Mapped, 3058, 3061),
+    ('\u{2CDF}', Status::Valid, 0, 0),
+    ('\u{2CE0}', Status::Mapped, 3061, 3064),
+    ('\u{2CE1}', Status::Valid, 0, 0),
+    ('\u{2CE2}', Status::Mapped, 3064, 3067),
+    ('\u{2CE3}', Status::Valid, 0, 0),
+    ('\u{2CEB}', Status::Mapped, 3067, 3070),
+    ('\u{2CEC}', Status::Valid, 0, 0),
+    ('\u{2CED}', Status::Mapped, 3070, 3073),
+    ('\u{2CEE}', Status::Valid, 0, 0),
+    ('\u{2CF2}', Status::Mapped, 3073, 3076),
+    ('\u{2CF3}', Status::Valid, 0, 0),
+    ('\u{2CF4}', Status::Disallowed, 0, 0),
+    ('\u{2CF9}', Status::Valid, 0, 0),
+    ('\u{2D26}', Status::Disallowed, 0, 0),
+    ('\u{2D27}', Status::Valid, 0, 0),
+    ('\u{2D28}', Status::Disallowed, 0, 0),
+    ('\u{2D2D}', Status::Valid, 0, 0),
+    ('\u{2D2E}', Status::Disallowed, 0, 0),
+    ('\u{2D30}', Status::Valid, 0, 0),
+    ('\u{2D68}', Status::Disallowed, 0, 0),
+    ('\u{2D6F}', Status::Mapped, 3076, 3079),
+    ('\u{2D70}', Status::Valid, 0, 0),
+    ('\u{2D71}', Status::Disallowed, 0, 0),
+    ('\u{2D7F}', Status::Valid, 0, 0),
+    ('\u{2D97}', Status::Disallowed, 0, 0),
+    ('\u{2DA0}', Status::Valid, 0, 0),
+    ('\u{2DA7}', Status::Disallowed, 0, 0),
+    ('\u{2DA8}', Status::Valid, 0, 0),
+    ('\u{2DAF}', Status::Disallowed, 0, 0),
+    ('\u{2DB0}', Status::Valid, 0, 0),
+    ('\u{2DB7}', Status::Disallowed, 0, 0),
+    ('\u{2DB8}', Status::Valid, 0, 0),
+    ('\u{2DBF}', Status::Disallowed, 0, 0),
+    ('\u{2DC0}', Status::Valid, 0, 0),
+    ('\u{2DC7}', Status::Disallowed, 0, 0),
+    ('\u{2DC8}', Status::Valid, 0, 0),
+    ('\u{2DCF}', Status::Disallowed, 0, 0),
+    ('\u{2DD0}', Status::Valid, 0, 0),
+    ('\u{2DD7}', Status::Disallowed, 0, 0),
+    ('\u{2DD8}', Status::Valid, 0, 0),
+    ('\u{2DDF}', Status::Disallowed, 0, 0),
+    ('\u{2DE0}', Status::Valid, 0, 0),
+    ('\u{2E5E}', Status::Disallowed, 0, 0),
+    ('\u{2E80}', Status::Valid, 0, 0),
+    ('\u{2E9A}', Status::Disallowed, 0, 0),
+    ('\u{2E9B}', Status::Valid, 0, 0),
+    ('\u{2E9F}', Status::Mapped, 3079, 3082),
+    ('\u{2EA0}', Status::Valid, 0, 0),
+    ('\u{2EF3}', Status::Mapped, 3082, 3085),
+    ('\u{2EF4}', Status::Disallowed, 0, 0),
+    ('\u{2F00}', Status::Mapped, 3085, 3088),
+    ('\u{2F01}', Status::Mapped, 3088, 3091),
+    ('\u{2F02}', Status::Mapped, 3091, 3094),
+    ('\u{2F03}', Status::Mapped, 3094, 3097),
+    ('\u{2F04}', Status::Mapped, 3097, 3100),
+    ('\u{2F05}', Status::Mapped, 3100, 3103),
+    ('\u{2F06}', Status::Mapped, 3103, 3106),
+    ('\u{2F07}', Status::Mapped, 3106, 3109),
+    ('\u{2F08}', Status::Mapped, 3109, 3112),
+    ('\u{2F09}', Status::Mapped, 3112, 3115),
+    ('\u{2F0A}', Status::Mapped, 3115, 3118),
+    ('\u{2F0B}', Status::Mapped, 3118, 3121),
+    ('\u{2F0C}', Status::Mapped, 3121, 3124),
+    ('\u{2F0D}', Status::Mapped, 3124, 3127),
+    ('\u{2F0E}', Status::Mapped, 3127, 3130),
+    ('\u{2F0F}', Status::Mapped, 3130, 3133),
+    ('\u{2F10}', Status::Mapped, 3133, 3136),
+    ('\u{2F11}', Status::Mapped, 3136, 3139),
+    ('\u{2F12}', Status::Mapped, 3139, 3142),
+    ('\u{2F13}', Status::Mapped, 3142, 3145),
+    ('\u{2F14}', Status::Mapped, 3145, 3148),
+    ('\u{2F15}', Status::Mapped, 3148, 3151),
+    ('\u{2F16}', Status::Mapped, 3151, 3154),
+    ('\u{2F17}', Status::Mapped, 3154, 3157),
+    ('\u{2F18}', Status::Mapped, 3157, 3160),
+    ('\u{2F19}', Status::Mapped, 3160, 3163),
+    ('\u{2F1A}', Status::Mapped, 3163, 3166),
+    ('\u{2F1B}', Status::Mapped, 3166, 3169),
+    ('\u{2F1C}', Status::Mapped, 3169, 3172),
+    ('\u{2F1D}', Status::Mapped, 3172, 3175),
+    ('\u{2F1E}', Status::Mapped, 3175, 3178),
+    ('\u{2F1F}', Status::Mapped, 3178, 3181),
+    ('\u{2F20}', Status::Mapped, 3181, 3184),
+    ('\u{2F21}', Status::Mapped, 3184, 3187),
+    ('\u{2F22}', Status::Mapped, 3187, 3190),
+    ('\u{2F23}', Status::Mapped, 3190, 3193),
+    ('\u{2F24}', Status::Mapped, 3193, 3196),
+    ('\u{2F25}', Status::Mapped, 3196, 3199),
+    ('\u{2F26}', Status::Mapped, 3199, 3202),
+    ('\u{2F27}', Status::Mapped, 3202, 3205),
+    ('\u{2F28}', Status::Mapped, 3205, 3208),
+    ('\u{2F29}', Status::Mapped, 3208, 3211),
+    ('\u{2F2A}', Status::Mapped, 3211, 3214),
+    ('\u{2F2B}', Status::Mapped, 3214, 3217),
+    ('\u{2F2C}', Status::Mapped, 3217, 3220),
+    ('\u{2F2D}', Status::Mapped, 3220, 3223),
+    ('\u{2F2E}', Status::Mapped, 3223, 3226),
+    ('\u{2F2F}', Status::Mapped, 3226, 3229),
+    ('\u{2F30}', Status::Mapped, 3229, 3232),
+    ('\u{2F31}', Status::Mapped, 3232, 3235),
+    ('\u{2F32}', Status::Mapped, 3235, 3238),
+    ('\u{2F33}', Status::Mapped, 3238, 3241),
+    ('\u{2F34}', Status::Mapped, 3241, 3244),
+    ('\u{2F35}', Status::Mapped, 3244, 3247),
+    ('\u{2F36}', Status::Mapped, 3247, 3250),
+    ('\u{2F37}', Status::Mapped, 3250, 3253),
+    ('\u{2F38}', Status::Mapped, 3253, 3256),
+    ('\u{2F39}', Status::Mapped, 3256, 3259),
+    ('\u{2F3A}', Status::Mapped, 3259, 3262),
+    ('\u{2F3B}', Status::Mapped, 3262, 3265),
+    ('\u{2F3C}', Status::Mapped, 3265, 3268),
+    ('\u{2F3D}', Status::Mapped, 3268, 3271),
+    ('\u{2F3E}', Status::Mapped, 3271, 3274),
+    ('\u{2F3F}', Status::Mapped, 3274, 3277),
+    ('\u{2F40}', Status::Mapped, 3277, 3280),
+    ('\u{2F41}', Status::Mapped, 3280, 3283),
+    ('\u{2F42}', Status::Mapped, 3283, 3286),
+    ('\u{2F43}', Status::Mapped, 3286, 3289),
+    ('\u{2F44}', Status::Mapped, 3289, 3292),
+    ('\u{2F45}', Status::Mapped, 3292, 3295),
+    ('\u{2F46}', Status::Mapped, 3295, 3298),
+    ('\u{2F47}', Status::Mapped, 3298, 3301),
+    ('\u{2F48}', Status::Mapped, 3301, 3304),
+    ('\u{2F49}', Status::Mapped, 3304, 3307),
+    ('\u{2F4A}', Status::Mapped, 3307, 3310),
+    ('\u{2F4B}', Status::Mapped, 3310, 3313),
+    ('\u{2F4C}', Status::Mapped, 3313, 3316),
+    ('\u{2F4D}', Status::Mapped, 3316, 3319),
+    ('\u{2F4E}', Status::Mapped, 3319, 3322),
+    ('\u{2F4F}', Status::Mapped, 3322, 3325),
+    ('\u{2F50}', Status::Mapped, 3325, 3328),
+    ('\u{2F51}', Status::Mapped, 3328, 3331),
+    ('\u{2F52}', Status::Mapped, 3331, 3334),
+    ('\u{2F53}', Status::Mapped, 3334, 3337),
+    ('\u{2F54}', Status::Mapped, 3337, 3340),
+    ('\u{2F55}', Status::Mapped, 3340, 3343),
+    ('\u{2F56}', Status::Mapped, 3343, 3346),
+    ('\u{2F57}', Status::Mapped, 3346, 3349),
+    ('\u{2F58}', Status::Mapped, 3349, 3352),
+    ('\u{2F59}', Status::Mapped, 3352, 3355),
+    ('\u{2F5A}', Status::Mapped, 3355, 3358),
+    ('\u{2F5B}', Status::Mapped, 3358, 3361),
+    ('\u{2F5C}', Status::Mapped, 3361, 3364),
+    ('\u{2F5D}', Status::Mapped, 3364, 3367),
+    ('\u{2F5E}', Status::Mapped, 3367, 3370),
+    ('\u{2F5F}', Status::Mapped, 3370, 3373),
+    ('\u{2F60}', Status::Mapped, 3373, 3376),
+    ('\u{2F61}', Status::Mapped, 3376, 3379),
+    ('\u{2F62}', Status::Mapped, 3379, 3382),
+    ('\u{2F63}', Status::Mapped, 3382, 3385),
+    ('\u{2F64}', Status::Mapped, 3385, 3388),
+    ('\u{2F65}', Status::Mapped, 3388, 3391),
+    ('\u{2F66}', Status::Mapped, 3391, 3394),
+    ('\u{2F67}', Status::Mapped, 3394, 3397),
+    ('\u{2F68}', Status::Mapped, 3397, 3400),
+    ('\u{2F69}', Status::Mapped, 3400, 3403),
+    ('\u{2F6A}', Status::Mapped, 3403, 3406),
+    ('\u{2F6B}', Status::Mapped, 3406, 3409),
+    ('\u{2F6C}', Status::Mapped, 3409, 3412),
+    ('\u{2F6D}', Status::Mapped, 3412, 3415),
+    ('\u{2F6E}', Status::Mapped, 3415, 3418),
+    ('\u{2F6F}', Status::Mapped, 3418, 3421),
+    ('\u{2F70}', Status::Mapped, 3421, 3424),
+    ('\u{2F71}', Status::Mapped, 3424, 3427),
+    ('\u{2F72}', Status::Mapped, 3427, 3430),
+    ('\u{2F73}', Status::Mapped, 3430, 3433),
+    ('\u{2F74}', Status::Mapped, 3433, 3436),
+    ('\u{2F75}', Status::Mapped, 3436, 3439),
+    ('\u{2F76}', Status::Mapped, 3439, 3442),
+    ('\u{2F77}', Status::Mapped, 3442, 3445),
+    ('\u{2F78}', Status::Mapped, 3445, 3448),
+    ('\u{2F79}', Status::Mapped, 3448, 3451),
+    ('\u{2F7A}', Status::Mapped, 3451, 3454),
+    ('\u{2F7B}', Status::Mapped, 3454, 3457),
+    ('\u{2F7C}', Status::Mapped, 3457, 3460),
+    ('\u{2F7D}', Status::Mapped, 3460, 3463),
+    ('\u{2F7E}', Status::Mapped, 3463, 3466),
+    ('\u{2F7F}', Status::Mapped, 3466, 3469),
+    ('\u{2F80}', Status::Mapped, 3469, 3472),
+    ('\u{2F81}', Status::Mapped, 3472, 3475),
+    ('\u{2F82}', Status::Mapped, 3475, 3478),
+    ('\u{2F83}', Status::Mapped, 3478, 3481),
+    ('\u{2F84}', Status::Mapped, 3481, 3484),
+    ('\u{2F85}', Status::Mapped, 3484, 3487),
+    ('\u{2F86}', Status::Mapped, 3487, 3490),
+    ('\u{2F87}', Status::Mapped, 3490, 3493),
+    ('\u{2F88}', Status::Mapped, 3493, 3496),
+    ('\u{2F89}', Status::Mapped, 3496, 3499),
+    ('\u{2F8A}', Status::Mapped, 3499, 3502),
+    ('\u{2F8B}', Status::Mapped, 3502, 3505),
+    ('\u{2F8C}', Status::Mapped, 3505, 3508),
+    ('\u{2F8D}', Status::Mapped, 3508, 3511),
+    ('\u{2F8E}', Status::Mapped, 3511, 3514),
+    ('\u{2F8F}', Status::Mapped, 3514, 3517),
+    ('\u{2F90}', Status::Mapped, 3517, 3520),
+    ('\u{2F91}', Status::Mapped, 3520, 3523),
+    ('\u{2F92}', Status::Mapped, 3523, 3526),
+    ('\u{2F93}', Status::Mapped, 3526, 3529),
+    ('\u{2F94}', Status::Mapped, 3529, 3532),
+    ('\u{2F95}', Status::Mapped, 3532, 3535),
+    ('\u{2F96}', Status::Mapped, 3535, 3538),
+    ('\u{2F97}', Status::Mapped, 3538, 3541),
+    ('\u{2F98}', Status::Mapped, 3541, 3544),
+    ('\u{2F99}', Status::Mapped, 3544, 3547),
+    ('\u{2F9A}', Status::Mapped, 3547, 3550),
+    ('\u{2F9B}', Status::Mapped, 3550, 3553),
+    ('\u{2F9C}', Status::Mapped, 3553, 3556),
+    ('\u{2F9D}', Status::Mapped, 3556, 3559),
+    ('\u{2F9E}', Status::Mapped, 3559, 3562),
+    ('\u{2F9F}', Status::Mapped, 3562, 3565),
+    ('\u{2FA0}', Status::Mapped, 3565, 3568),
+    ('\u{2FA1}', Status::Mapped, 3568, 3571),
+    ('\u{2FA2}', Status::Mapped, 3571, 3574),
+    ('\u{2FA3}', Status::Mapped, 3574, 3577),
+    ('\u{2FA4}', Status::Mapped, 3577, 3580),
+    ('\u{2FA5}', Status::Mapped, 3580, 3583),
+    ('\u{2FA6}', Status::Mapped, 3583, 3586),
+    ('\u{2FA7}', Status::Mapped, 3586, 3589),
+    ('\u{2FA8}', Status::Mapped, 3589, 3592),
+    ('\u{2FA9}', Status::Mapped, 3592, 3595),
+    ('\u{2FAA}', Status::Mapped, 3595, 3598),
+    ('\u{2FAB}', Status::Mapped, 3598, 3601),
+    ('\u{2FAC}', Status::Mapped, 3601, 3604),
+    ('\u{2FAD}', Status::Mapped, 3604, 3607),
+    ('\u{2FAE}', Status::Mapped, 3607, 3610),
+    ('\u{2FAF}', Status::Mapped, 3610, 3613),
+    ('\u{2FB0}', Status::Mapped, 3613, 3616),
+    ('\u{2FB1}', Status::Mapped, 3616, 3619),
+    ('\u{2FB2}', Status::Mapped, 3619, 3622),
+    ('\u{2FB3}', Status::Mapped, 3622, 3625),
+    ('\u{2FB4}', Status::Mapped, 3625, 3628),
+    ('\u{2FB5}', Status::Mapped, 3628, 3631),
+    ('\u{2FB6}', Status::Mapped, 3631, 3634),
+    ('\u{2FB7}', Status::Mapped, 3634, 3637),
+    ('\u{2FB8}', Status::Mapped, 3637, 3640),
+    ('\u{2FB9}', Status::Mapped, 3640, 3643),
+    ('\u{2FBA}', Status::Mapped, 3643, 3646),
+    ('\u{2FBB}', Status::Mapped, 3646, 3649),
+    ('\u{2FBC}', Status::Mapped, 3649, 3652),
+    ('\u{2FBD}', Status::Mapped, 3652, 3655),
+    ('\u{2FBE}', Status::Mapped, 3655, 3658),
+    ('\u{2FBF}', Status::Mapped, 3658, 3661),
+    ('\u{2FC0}', Status::Mapped, 3661, 3664),
+    ('\u{2FC1}', Status::Mapped, 3664, 3667),
+    ('\u{2FC2}', Status::Mapped, 3667, 3670),
+    ('\u{2FC3}', Status::Mapped, 3670, 3673),
+    ('\u{2FC4}', Status::Mapped, 3673, 3676),
+    ('\u{2FC5}', Status::Mapped, 3676, 3679),
+    ('\u{2FC6}', Status::Mapped, 3679, 3682),
+    ('\u{2FC7}', Status::Mapped, 3682, 3685),
+    ('\u{2FC8}', Status::Mapped, 3685, 3688),
+    ('\u{2FC9}', Status::Mapped, 3688, 3691),
+    ('\u{2FCA}', Status::Mapped, 3691, 3694),
+    ('\u{2FCB}', Status::Mapped, 3694, 3697),
+    ('\u{2FCC}', Status::Mapped, 3697, 3700),
+    ('\u{2FCD}', Status::Mapped, 3700, 3703),
+    ('\u{2FCE}', Status::Mapped, 3703, 3706),
+    ('\u{2FCF}', Status::Mapped, 3706, 3709),
+    ('\u{2FD0}', Status::Mapped, 3709, 3712),
+    ('\u{2FD1}', Status::Mapped, 3712, 3715),
+    ('\u{2FD2}', Status::Mapped, 3715, 3718),
+    ('\u{2FD3}', Status::Mapped, 3718, 3721),
+    ('\u{2FD4}', Status::Mapped, 3721, 3724),
+    ('\u{2FD5}', Status::Mapped, 3724, 3727),
+    ('\u{2FD6}', Status::Disallowed, 0, 0),
+    ('\u{3000}', Status::Mapped, 26, 27),
+    ('\u{3001}', Status::Valid, 0, 0),
+    ('\u{3002}', Status::Mapped, 3727, 3728),
+    ('\u{3003}', Status::Valid, 0, 0),
+    ('\u{3036}', Status::Mapped, 3728, 3731),
+    ('\u{3037}', Status::Valid, 0, 0),
+    ('\u{3038}', Status::Mapped, 3154, 3157),
+    ('\u{3039}', Status::Mapped, 3731, 3734),
+    ('\u{303A}', Status::Mapped, 3734, 3737),
+    ('\u{303B}', Status::Valid, 0, 0),
+    ('\u{3040}', Status::Disallowed, 0, 0),
+    ('\u{3041}', Status::Valid, 0, 0),
+    ('\u{3097}', Status::Disallowed, 0, 0),
+    ('\u{3099}', Status::Valid, 0, 0),
+    ('\u{309B}', Status::Mapped, 3737, 3741),
+    ('\u{309C}', Status::Mapped, 3741, 3745),
+    ('\u{309D}', Status::Valid, 0, 0),
+    ('\u{309F}', Status::Mapped, 3745, 3751),
+    ('\u{30A0}', Status::Valid, 0, 0),
+    ('\u{30FF}', Status::Mapped, 3751, 3757),
+    ('\u{3100}', Status::Disallowed, 0, 0),
+    ('\u{3105}', Status::Valid, 0, 0),
+    ('\u{3130}', Status::Disallowed, 0, 0),
+    ('\u{3131}', Status::Mapped, 3757, 3760),
+    ('\u{3132}', Status::Mapped, 3760, 3763),
+    ('\u{3133}', Status::Mapped, 3763, 3766),
+    ('\u{3134}', Status::Mapped, 3766, 3769),
+    ('\u{3135}', Status::Mapped, 3769, 3772),
+    ('\u{3136}', Status::Mapped, 3772, 3775),
+    ('\u{3137}', Status::Mapped, 3775, 3778),
+    ('\u{3138}', Status::Mapped, 3778, 3781),
+    ('\u{3139}', Status::Mapped, 3781, 3784),
+    ('\u{313A}', Status::Mapped, 3784, 3787),
+    ('\u{313B}', Status::Mapped, 3787, 3790),
+    ('\u{313C}', Status::Mapped, 3790, 3793),
+    ('\u{313D}', Status::Mapped, 3793, 3796),
+    ('\u{313E}', Status::Mapped, 3796, 3799),
+    ('\u{313F}', Status::Mapped, 3799, 3802),
+    ('\u{3140}', Status::Mapped, 3802, 3805),
+    ('\u{3141}', Status::Mapped, 3805, 3808),
+    ('\u{3142}', Status::Mapped, 3808, 3811),
+    ('\u{3143}', Status::Mapped, 3811, 3814),
+    ('\u{3144}', Status::Mapped, 3814, 3817),
+    ('\u{3145}', Status::Mapped, 3817, 3820),
+    ('\u{3146}', Status::Mapped, 3820, 3823),
+    ('\u{3147}', Status::Mapped, 3823, 3826),
+    ('\u{3148}', Status::Mapped, 3826, 3829),
+    ('\u{3149}', Status::Mapped, 3829, 3832),
+    ('\u{314A}', Status::Mapped, 3832, 3835),
+    ('\u{314B}', Status::Mapped, 3835, 3838),
+    ('\u{314C}', Status::Mapped, 3838, 3841),
+    ('\u{314D}', Status::Mapped, 3841, 3844),
+    ('\u{314E}', Status::Mapped, 3844, 3847),
+    ('\u{314F}', Status::Mapped, 3847, 3850),
+    ('\u{3150}', Status::Mapped, 3850, 3853),
+    ('\u{3151}', Status::Mapped, 3853, 3856),
+    ('\u{3152}', Status::Mapped, 3856, 3859),
+    ('\u{3153}', Status::Mapped, 3859, 3862),
+    ('\u{3154}', Status::Mapped, 3862, 3865),
+    ('\u{3155}', Status::Mapped, 3865, 3868),
+    ('\u{3156}', Status::Mapped, 3868, 3871),
+    ('\u{3157}', Status::Mapped, 3871, 3874),
+    ('\u{3158}', Status::Mapped, 3874, 3877),
+    ('\u{3159}', Status::Mapped, 3877, 3880),
+    ('\u{315A}', Status::Mapped, 3880, 3883),
+    ('\u{315B}', Status::Mapped, 3883, 3886),
+    ('\u{315C}', Status::Mapped, 3886, 3889),
+    ('\u{315D}', Status::Mapped, 3889, 3892),
+    ('\u{315E}', Status::Mapped, 3892, 3895),
+    ('\u{315F}', Status::Mapped, 3895, 3898),
+    ('\u{3160}', Status::Mapped, 3898, 3901),
+    ('\u{3161}', Status::Mapped, 3901, 3904),
+    ('\u{3162}', Status::Mapped, 3904, 3907),
+    ('\u{3163}', Status::Mapped, 3907, 3910),
+    ('\u{3164}', Status::Disallowed, 0, 0),
+    ('\u{3165}', Status::Mapped, 3910, 3913),
+    ('\u{3166}', Status::Mapped, 3913, 3916),
+    ('\u{3167}', Status::Mapped, 3916, 3919),
+    ('\u{3168}', Status::Mapped, 3919, 3922),
+    ('\u{3169}', Status::Mapped, 3922, 3925),
+    ('\u{316A}', Status::Mapped, 3925, 3928),
+    ('\u{316B}', Status::Mapped, 3928, 3931),
+    ('\u{316C}', Status::Mapped, 3931, 3934),
+    ('\u{316D}', Status::Mapped, 3934, 3937),
+    ('\u{316E}', Status::Mapped, 3937, 3940),
+    ('\u{316F}', Status::Mapped, 3940, 3943),
+    ('\u{3170}', Status::Mapped, 3943, 3946),
+    ('\u{3171}', Status::Mapped, 3946, 3949),
+    ('\u{3172}', Status::Mapped, 3949, 3952),
+    ('\u{3173}', Status::Mapped, 3952, 3955),
+    ('\u{3174}', Status::Mapped, 3955, 3958),
+    ('\u{3175}', Status::Mapped, 3958, 3961),
+    ('\u{3176}', Status::Mapped, 3961, 3964),
+    ('\u{3177}', Status::Mapped, 3964, 3967),
+    ('\u{3178}', Status::Mapped, 3967, 3970),
+    ('\u{3179}', Status::Mapped, 3970, 3973),
+    ('\u{317A}', Status::Mapped, 3973, 3976),
+    ('\u{317B}', Status::Mapped, 3976, 3979),
+    ('\u{317C}', Status::Mapped, 3979, 3982),
+    ('\u{317D}', Status::Mapped, 3982, 3985),
+    ('\u{317E}', Status::Mapped, 3985, 3988),
+    ('\u{317F}', Status::Mapped, 3988, 3991),
+    ('\u{3180}', Status::Mapped, 3991, 3994),
+    ('\u{3181}', Status::Mapped, 3994, 3997),
+    ('\u{3182}', Status::Mapped, 3997, 4000),
+    ('\u{3183}', Status::Mapped, 4000, 4003),
+    ('\u{3184}', Status::Mapped, 4003, 4006),
+    ('\u{3185}', Status::Mapped, 4006, 4009),
+    ('\u{3186}', Status::Mapped, 4009, 4012),
+    ('\u{3187}', Status::Mapped, 4012, 4015),
+    ('\u{3188}', Status::Mapped, 4015, 4018),
+    ('\u{3189}', Status::Mapped, 4018, 4021),
+    ('\u{318A}', Status::Mapped, 4021, 4024),
+    ('\u{318B}', Status::Mapped, 4024, 4027),
+    ('\u{318C}', Status::Mapped, 4027, 4030),
+    ('\u{318D}', Status::Mapped, 4030, 4033),
+    ('\u{318E}', Status::Mapped, 4033, 4036),
+    ('\u{318F}', Status::Disallowed, 0, 0),
+    ('\u{3190}', Status::Valid, 0, 0),
+    ('\u{3192}', Status::Mapped, 3085, 3088),
+    ('\u{3193}', Status::Mapped, 3103, 3106),
+    ('\u{3194}', Status::Mapped, 4036, 4039),
+    ('\u{3195}', Status::Mapped, 4039, 4042),
+    ('\u{3196}', Status::Mapped, 4042, 4045),
+    ('\u{3197}', Status::Mapped, 4045, 4048),
+    ('\u{3198}', Status::Mapped, 4048, 4051),
+    ('\u{3199}', Status::Mapped, 4051, 4054),
+    ('\u{319A}', Status::Mapped, 3097, 3100),
+    ('\u{319B}', Status::Mapped, 4054, 4057),
+    ('\u{319C}', Status::Mapped, 4057, 4060),
+    ('\u{319D}', Status::Mapped, 4060, 4063),
+    ('\u{319E}', Status::Mapped, 4063, 4066),
+    ('\u{319F}', Status::Mapped, 3109, 3112),
+    ('\u{31A0}', Status::Valid, 0, 0),
+    ('\u{31E4}', Status::Disallowed, 0, 0),
+    ('\u{31F0}', Status::Valid, 0, 0),
+    ('\u{3200}', Status::Mapped, 4066, 4071),
+    ('\u{3201}', Status::Mapped, 4071, 4076),
+    ('\u{3202}', Status::Mapped, 4076, 4081),
+    ('\u{3203}', Status::Mapped, 4081, 4086),
+    ('\u{3204}', Status::Mapped, 4086, 4091),
+    ('\u{3205}', Status::Mapped, 4091, 4096),
+    ('\u{3206}', Status::Mapped, 4096, 4101),
+    ('\u{3207}', Status::Mapped, 4101, 4106),
+    ('\u{3208}', Status::Mapped, 4106, 4111),
+    ('\u{3209}', Status::Mapped, 4111, 4116),
+    ('\u{320A}', Status::Mapped, 4116, 4121),
+    ('\u{320B}', Status::Mapped, 4121, 4126),
+    ('\u{320C}', Status::Mapped, 4126, 4131),
+    ('\u{320D}', Status::Mapped, 4131, 4136),
+    ('\u{320E}', Status::Mapped, 4136, 4141),
+    ('\u{320F}', Status::Mapped, 4141, 4146),
+    ('\u{3210}', Status::Mapped, 4146, 4151),
+    ('\u{3211}', Status::Mapped, 4151, 4156),
+    ('\u{3212}', Status::Mapped, 4156, 4161),
+    ('\u{3213}', Status::Mapped, 4161, 4166),
+    ('\u{3214}', Status::Mapped, 4166, 4171),
+    ('\u{3215}', Status::Mapped, 4171, 4176),
+    ('\u{3216}', Status::Mapped, 4176, 4181),
+    ('\u{3217}', Status::Mapped, 4181, 4186),
+    ('\u{3218}', Status::Mapped, 4186, 4191),
+    ('\u{3219}', Status::Mapped, 4191, 4196),
+    ('\u{321A}', Status::Mapped, 4196, 4201),
+    ('\u{321B}', Status::Mapped, 4201, 4206),
+    ('\u{321C}', Status::Mapped, 4206, 4211),
+    ('\u{321D}', Status::Mapped, 4211, 4219),
+    ('\u{321E}', Status::Mapped, 4219, 4227),
+    ('\u{321F}', Status::Disallowed, 0, 0),
+    ('\u{3220}', Status::Mapped, 4227, 4232),
+    ('\u{3221}', Status::Mapped, 4232, 4237),
+    ('\u{3222}', Status::Mapped, 4237, 4242),
+    ('\u{3223}', Status::Mapped, 4242, 4247),
+    ('\u{3224}', Status::Mapped, 4247, 4252),
+    ('\u{3225}', Status::Mapped, 4252, 4257),
+    ('\u{3226}', Status::Mapped, 4257, 4262),
+    ('\u{3227}', Status::Mapped, 4262, 4267),
+    ('\u{3228}', Status::Mapped, 4267, 4272),
+    ('\u{3229}', Status::Mapped, 4272, 4277),
+    ('\u{322A}', Status::Mapped, 4277, 4282),
+    ('\u{322B}', Status::Mapped, 4282, 4287),
+    ('\u{322C}', Status::Mapped, 4287, 4292),
+    ('\u{322D}', Status::Mapped, 4292, 4297),
+    ('\u{322E}', Status::Mapped, 4297, 4302),
+    ('\u{322F}', Status::Mapped, 4302, 4307),
+    ('\u{3230}', Status::Mapped, 4307, 4312),
+    ('\u{3231}', Status::Mapped, 4312, 4317),
+    ('\u{3232}', Status::Mapped, 4317, 4322),
+    ('\u{3233}', Status::Mapped, 4322, 4327),
+    ('\u{3234}', Status::Mapped, 4327, 4332),
+    ('\u{3235}', Status::Mapped, 4332, 4337),
+    ('\u{3236}', Status::Mapped, 4337, 4342),
+    ('\u{3237}', Status::Mapped, 4342, 4347),
+    ('\u{3238}', Status::Mapped, 4347, 4352),
+    ('\u{3239}', Status::Mapped, 4352, 4357),
+    ('\u{323A}', Status::Mapped, 4357, 4362),
+    ('\u{323B}', Status::Mapped, 4362, 4367),
+    ('\u{323C}', Status::Mapped, 4367, 4372),
+    ('\u{323D}', Status::Mapped, 4372, 4377),
+    ('\u{323E}', Status::Mapped, 4377, 4382),
+    ('\u{323F}', Status::Mapped, 4382, 4387),
+    ('\u{3240}', Status::Mapped, 4387, 4392),
+    ('\u{3241}', Status::Mapped, 4392, 4397),
+    ('\u{3242}', Status::Mapped, 4397, 4402),
+    ('\u{3243}', Status::Mapped, 4402, 4407),
+    ('\u{3244}', Status::Mapped, 4407, 4410),
+    ('\u{3245}', Status::Mapped, 4410, 4413),
+    ('\u{3246}', Status::Mapped, 3283, 3286),
+    ('\u{3247}', Status::Mapped, 4413, 4416),
+    ('\u{3248}', Status::Valid, 0, 0),
+    ('\u{3250}', Status::Mapped, 4416, 4419),
+    ('\u{3251}', Status::Mapped, 4419, 4421),
+    ('\u{3252}', Status::Mapped, 4421, 4423),
+    ('\u{3253}', Status::Mapped, 4423, 4425),
+    ('\u{3254}', Status::Mapped, 4425, 4427),
+    ('\u{3255}', Status::Mapped, 4427, 4429),
+    ('\u{3256}', Status::Mapped, 4429, 4431),
+    ('\u{3257}', Status::Mapped, 4431, 4433),
+    ('\u{3258}', Status::Mapped, 4433, 4435),
+    ('\u{3259}', Status::Mapped, 4435, 4437),
+    ('\u{325A}', Status::Mapped, 4437, 4439),
+    ('\u{325B}', Status::Mapped, 4439, 4441),
+    ('\u{325C}', Status::Mapped, 4441, 4443),
+    ('\u{325D}', Status::Mapped, 4443, 4445),
+    ('\u{325E}', Status::Mapped, 4445, 4447),
+    ('\u{325F}', Status::Mapped, 4447, 4449),
+    ('\u{3260}', Status::Mapped, 3757, 3760),
+    ('\u{3261}', Status::Mapped, 3766, 3769),
+    ('\u{3262}', Status::Mapped, 3775, 3778),
+    ('\u{3263}', Status::Mapped, 3781, 3784),
+    ('\u{3264}', Status::Mapped, 3805, 3808),
+    ('\u{3265}', Status::Mapped, 3808, 3811),
+    ('\u{3266}', Status::Mapped, 3817, 3820),
+    ('\u{3267}', Status::Mapped, 3823, 3826),
+    ('\u{3268}', Status::Mapped, 3826, 3829),
+    ('\u{3269}', Status::Mapped, 3832, 3835),
+    ('\u{326A}', Status::Mapped, 3835, 3838),
+    ('\u{326B}', Status::Mapped, 3838, 3841),
+    ('\u{326C}', Status::Mapped, 3841, 3844),
+    ('\u{326D}', Status::Mapped, 3844, 3847),
+    ('\u{326E}', Status::Mapped, 4449, 4452),
+    ('\u{326F}', Status::Mapped, 4452, 4455),
+    ('\u{3270}', Status::Mapped, 4455, 4458),
+    ('\u{3271}', Status::Mapped, 4458, 4461),
+    ('\u{3272}', Status::Mapped, 4461, 4464),
+    ('\u{3273}', Status::Mapped, 4464, 4467),
+    ('\u{3274}', Status::Mapped, 4467, 4470),
+    ('\u{3275}', Status::Mapped, 4470, 4473),
+    ('\u{3276}', Status::Mapped, 4473, 4476),
+    ('\u{3277}', Status::Mapped, 4476, 4479),
+    ('\u{3278}', Status::Mapped, 4479, 4482),
+    ('\u{3279}', Status::Mapped, 4482, 4485),
+    ('\u{327A}', Status::Mapped, 4485, 4488),
+    ('\u{327B}', Status::Mapped, 4488, 4491),
+    ('\u{327C}', Status::Mapped, 4491, 4497),
+    ('\u{327D}', Status::Mapped, 4497, 4503),
+    ('\u{327E}', Status::Mapped, 4503, 4506),
+    ('\u{327F}', Status::Valid, 0, 0),
+    ('\u{3280}', Status::Mapped, 3085, 3088),
+    ('\u{3281}', Status::Mapped, 3103, 3106),
+    ('\u{3282}', Status::Mapped, 4036, 4039),
+    ('\u{3283}', Status::Mapped, 4039, 4042),
+    ('\u{3284}', Status::Mapped, 4506, 4509),
+    ('\u{3285}', Status::Mapped, 4509, 4512),
+    ('\u{3286}', Status::Mapped, 4512, 4515),
+    ('\u{3287}', Status::Mapped, 3118, 3121),
+    ('\u{3288}', Status::Mapped, 4515, 4518),
+    ('\u{3289}', Status::Mapped, 3154, 3157),
+    ('\u{328A}', Status::Mapped, 3304, 3307),
+    ('\u{328B}', Status::Mapped, 3340, 3343),
+    ('\u{328C}', Status::Mapped, 3337, 3340),
+    ('\u{328D}', Status::Mapped, 3307, 3310),
+    ('\u{328E}', Status::Mapped, 3583, 3586),
+    ('\u{328F}', Status::Mapped, 3178, 3181),
+    ('\u{3290}', Status::Mapped, 3298, 3301),
+    ('\u{3291}', Status::Mapped, 4518, 4521),
+    ('\u{3292}', Status::Mapped, 4521, 4524),
+    ('\u{3293}', Status::Mapped, 4524, 4527),
+    ('\u{3294}', Status::Mapped, 4527, 4530),
+    ('\u{3295}', Status::Mapped, 4530, 4533),
+    ('\u{3296}', Status::Mapped, 4533, 4536),
+    ('\u{3297}', Status::Mapped, 4536, 4539),
+    ('\u{3298}', Status::Mapped, 4539, 4542),
+    ('\u{3299}', Status::Mapped, 4542, 4545),
+    ('\u{329A}', Status::Mapped, 4545, 4548),
+    ('\u{329B}', Status::Mapped, 3196, 3199),
+    ('\u{329C}', Status::Mapped, 4548, 4551),
+    ('\u{329D}', Status::Mapped, 4551, 4554),
+    ('\u{329E}', Status::Mapped, 4554, 4557),
+    ('\u{329F}', Status::Mapped, 4557, 4560),
+    ('\u{32A0}', Status::Mapped, 4560, 4563),
+    ('\u{32A1}', Status::Mapped, 4563, 4566),
+    ('\u{32A2}', Status::Mapped, 4566, 4569),
+    ('\u{32A3}', Status::Mapped, 4569, 4572),
+    ('\u{32A4}', Status::Mapped, 4042, 4045),
+    ('\u{32A5}', Status::Mapped, 4045, 4048),
+    ('\u{32A6}', Status::Mapped, 4048, 4051),
+    ('\u{32A7}', Status::Mapped, 4572, 4575),
+    ('\u{32A8}', Status::Mapped, 4575, 4578),
+    ('\u{32A9}', Status::Mapped, 4578, 4581),
+    ('\u{32AA}', Status::Mapped, 4581, 4584),
+    ('\u{32AB}', Status::Mapped, 4584, 4587),
+    ('\u{32AC}', Status::Mapped, 4587, 4590),
+    ('\u{32AD}', Status::Mapped, 4590, 4593),
+    ('\u{32AE}', Status::Mapped, 4593, 4596),
+    ('\u{32AF}', Status::Mapped, 4596, 4599),
+    ('\u{32B0}', Status::Mapped, 4599, 4602),
+    ('\u{32B1}', Status::Mapped, 4602, 4604),
+    ('\u{32B2}', Status::Mapped, 4604, 4606),
+    ('\u{32B3}', Status::Mapped, 4606, 4608),
+    ('\u{32B4}', Status::Mapped, 4608, 4610),
+    ('\u{32B5}', Status::Mapped, 4610, 4612),
+    ('\u{32B6}', Status::Mapped, 4612, 4614),
+    ('\u{32B7}', Status::Mapped, 4614, 4616),
+    ('\u{32B8}', Status::Mapped, 4616, 4618),
+    ('\u{32B9}', Status::Mapped, 4618, 4620),
+    ('\u{32BA}', Status::Mapped, 4620, 4622),
+    ('\u{32BB}', Status::Mapped, 4622, 4624),
+    ('\u{32BC}', Status::Mapped, 4624, 4626),
+    ('\u{32BD}', Status::Mapped, 4626, 4628),
+    ('\u{32BE}', Status::Mapped, 4628, 4630),
+    ('\u{32BF}', Status::Mapped, 4630, 4632),
+    ('\u{32C0}', Status::Mapped, 4632, 4636),
+    ('\u{32C1}', Status::Mapped, 4636, 4640),
+    ('\u{32C2}', Status::Mapped, 4640, 4644),
+    ('\u{32C3}', Status::Mapped, 4644, 4648),
+    ('\u{32C4}', Status::Mapped, 4648, 4652),
+    ('\u{32C5}', Status::Mapped, 4652, 4656),
+    ('\u{32C6}', Status::Mapped, 4656, 4660),
+    ('\u{32C7}', Status::Mapped, 4660, 4664),
+    ('\u{32C8}', Status::Mapped, 4664, 4668),
+    ('\u{32C9}', Status::Mapped, 4668, 4673),
+    ('\u{32CA}', Status::Mapped, 4673, 4678),
+    ('\u{32CB}', Status::Mapped, 4678, 4683),
+    ('\u{32CC}', Status::Mapped, 4683, 4685),
+    ('\u{32CD}', Status::Mapped, 4685, 4688),
+    ('\u{32CE}', Status::Mapped, 4688, 4690),
+    ('\u{32CF}', Status::Mapped, 4690, 4693),
+    ('\u{32D0}', Status::Mapped, 4693, 4696),
+    ('\u{32D1}', Status::Mapped, 4696, 4699),
+    ('\u{32D2}', Status::Mapped, 4699, 4702),
+    ('\u{32D3}', Status::Mapped, 4702, 4705),
+    ('\u{32D4}', Status::Mapped, 4705, 4708),
+    ('\u{32D5}', Status::Mapped, 4708, 4711),
+    ('\u{32D6}', Status::Mapped, 4711, 4714),
+    ('\u{32D7}', Status::Mapped, 4714, 4717),
+    ('\u{32D8}', Status::Mapped, 4717, 4720),
+    ('\u{32D9}', Status::Mapped, 4720, 4723),
+    ('\u{32DA}', Status::Mapped, 4723, 4726),
+    ('\u{32DB}', Status::Mapped, 4726, 4729),
+    ('\u{32DC}', Status::Mapped, 4729, 4732),
+    ('\u{32DD}', Status::Mapped, 4732, 4735),
+    ('\u{32DE}', Status::Mapped, 4735, 4738),
+    ('\u{32DF}', Status::Mapped, 4738, 4741),
+    ('\u{32E0}', Status::Mapped, 4741, 4744),
+    ('\u{32E1}', Status::Mapped, 4744, 4747),
+    ('\u{32E2}', Status::Mapped, 4747, 4750),
+    ('\u{32E3}', Status::Mapped, 4750, 4753),
+    ('\u{32E4}', Status::Mapped, 4753, 4756),
+    ('\u{32E5}', Status::Mapped, 4756, 4759),
+    ('\u{32E6}', Status::Mapped, 4759, 4762),
+    ('\u{32E7}', Status::Mapped, 4762, 4765),
+    ('\u{32E8}', Status::Mapped, 4765, 4768),
+    ('\u{32E9}', Status::Mapped, 4768, 4771),
+    ('\u{32EA}', Status::Mapped, 4771, 4774),
+    ('\u{32EB}', Status::Mapped, 4774, 4777),
+    ('\u{32EC}', Status::Mapped, 4777, 4780),
+    ('\u{32ED}', Status::Mapped, 4780, 4783),
+    ('\u{32EE}', Status::Mapped, 4783, 4786),
+    ('\u{32EF}', Status::Mapped, 4786, 4789),
+    ('\u{32F0}', Status::Mapped, 4789, 4792),
+    ('\u{32F1}', Status::Mapped, 4792, 4795),
+    ('\u{32F2}', Status::Mapped, 4795, 4798),
+    ('\u{32F3}', Status::Mapped, 4798, 4801),
+    ('\u{32F4}', Status::Mapped, 4801, 4804),
+    ('\u{32F5}', Status::Mapped, 4804, 4807),
+    ('\u{32F6}', Status::Mapped, 4807, 4810),
+    ('\u{32F7}', Status::Mapped, 4810, 4813),
+    ('\u{32F8}', Status::Mapped, 4813, 4816),
+    ('\u{32F9}', Status::Mapped, 4816, 4819),
+    ('\u{32FA}', Status::Mapped, 4819, 4822),
+    ('\u{32FB}', Status::Mapped, 4822, 4825),
+    ('\u{32FC}', Status::Mapped, 4825, 4828),
+    ('\u{32FD}', Status::Mapped, 4828, 4831),
+    ('\u{32FE}', Status::Mapped, 4831, 4834),
+    ('\u{32FF}', Status::Mapped, 4834, 4840),
+    ('\u{3300}', Status::Mapped, 4840, 4852),
+    ('\u{3301}', Status::Mapped, 4852, 4864),
+    ('\u{3302}', Status::Mapped, 4864, 4876),
+    ('\u{3303}', Status::Mapped, 4876, 4885),
+    ('\u{3304}', Status::Mapped, 4885, 4897),
+    ('\u{3305}', Status::Mapped, 4897, 4906),
+    ('\u{3306}', Status::Mapped, 4906, 4915),
+    ('\u{3307}', Status::Mapped, 4915, 4930),
+    ('\u{3308}', Status::Mapped, 4930, 4942),
+    ('\u{3309}', Status::Mapped, 4942, 4951),
+    ('\u{330A}', Status::Mapped, 4951, 4960),
+    ('\u{330B}', Status::Mapped, 4960, 4969),
+    ('\u{330C}', Status::Mapped, 4969, 4981),
+    ('\u{330D}', Status::Mapped, 4981, 4993),
+    ('\u{330E}', Status::Mapped, 4993, 5002),
+    ('\u{330F}', Status::Mapped, 5002, 5011),
+    ('\u{3310}', Status::Mapped, 5011, 5017),
+    ('\u{3311}', Status::Mapped, 5017, 5026),
+    ('\u{3312}', Status::Mapped, 5026, 5038),
+    ('\u{3313}', Status::Mapped, 5038, 5050),
+    ('\u{3314}', Status::Mapped, 5050, 5056),
+    ('\u{3315}', Status::Mapped, 5056, 5071),
+    ('\u{3316}', Status::Mapped, 5071, 5089),
+    ('\u{3317}', Status::Mapped, 5089, 5104),
+    ('\u{3318}', Status::Mapped, 5104, 5113),
+    ('\u{3319}', Status::Mapped, 5113, 5128),
+    ('\u{331A}', Status::Mapped, 5128, 5143),
+    ('\u{331B}', Status::Mapped, 5143, 5155),
+    ('\u{331C}', Status::Mapped, 5155, 5164),
+    ('\u{331D}', Status::Mapped, 5164, 5173),
+    ('\u{331E}', Status::Mapped, 5173, 5182),
+    ('\u{331F}', Status::Mapped, 5182, 5194),
+    ('\u{3320}', Status::Mapped, 5194, 5209),
+    ('\u{3321}', Status::Mapped, 5209, 5221),
+    ('\u{3322}', Status::Mapped, 5221, 5230),
+    ('\u{3323}', Status::Mapped, 5230, 5239),
+    ('\u{3324}', Status::Mapped, 5239, 5248),
+    ('\u{3325}', Status::Mapped, 5248, 5254),
+    ('\u{3326}', Status::Mapped, 5254, 5260),
+    ('\u{3327}', Status::Mapped, 5260, 5266),
+    ('\u{3328}', Status::Mapped, 5266, 5272),
+    ('\u{3329}', Status::Mapped, 5272, 5281),
+    ('\u{332A}', Status::Mapped, 5281, 5290),
+    ('\u{332B}', Status::Mapped, 5290, 5305),
+    ('\u{332C}', Status::Mapped, 5305, 5314),
+    ('\u{332D}', Status::Mapped, 5314, 5326),
+    ('\u{332E}', Status::Mapped, 5326, 5341),
+    ('\u{332F}', Status::Mapped, 5341, 5350),
+    ('\u{3330}', Status::Mapped, 5350, 5356),
+    ('\u{3331}', Status::Mapped, 5356, 5362),
+    ('\u{3332}', Status::Mapped, 5362, 5377),
+    ('\u{3333}', Status::Mapped, 5377, 5389),
+    ('\u{3334}', Status::Mapped, 5389, 5404),
+    ('\u{3335}', Status::Mapped, 5404, 5413),
+    ('\u{3336}', Status::Mapped, 5413, 5428),
+    ('\u{3337}', Status::Mapped, 5428, 5434),
+    ('\u{3338}', Status::Mapped, 5434, 5443),
+    ('\u{3339}', Status::Mapped, 5443, 5452),
+    ('\u{333A}', Status::Mapped, 5452, 5461),
+    ('\u{333B}', Status::Mapped, 5461, 5470),
+    ('\u{333C}', Status::Mapped, 5470, 5479),
+    ('\u{333D}', Status::Mapped, 5479, 5491),
+    ('\u{333E}', Status::Mapped, 5491, 5500),
+    ('\u{333F}', Status::Mapped, 5500, 5506),
+    ('\u{3340}', Status::Mapped, 5506, 5515),
+    ('\u{3341}', Status::Mapped, 5515, 5524),
+    ('\u{3342}', Status::Mapped, 5524, 5533),
+    ('\u{3343}', Status::Mapped, 5533, 5545),
+    ('\u{3344}', Status::Mapped, 5545, 5554),
+    ('\u{3345}', Status::Mapped, 5554, 5563),
+    ('\u{3346}', Status::Mapped, 5563, 5572),
+    ('\u{3347}', Status::Mapped, 5572, 5587),
+    ('\u{3348}', Status::Mapped, 5587, 5599),
+    ('\u{3349}', Status::Mapped, 5599, 5605),
+    ('\u{334A}', Status::Mapped, 5605, 5620),
+    ('\u{334B}', Status::Mapped, 5620, 5626),
+    ('\u{334C}', Status::Mapped, 5626, 5638),
+    ('\u{334D}', Status::Mapped, 5638, 5650),
+    ('\u{334E}', Status::Mapped, 5650, 5659),
+    ('\u{334F}', Status::Mapped, 5659, 5668),
+    ('\u{3350}', Status::Mapped, 5668, 5677),
+    ('\u{3351}', Status::Mapped, 5677, 5689),
+    ('\u{3352}', Status::Mapped, 5689, 5695),
+    ('\u{3353}', Status::Mapped, 5695, 5704),
+    ('\u{3354}', Status::Mapped, 5704, 5716),
+    ('\u{3355}', Status::Mapped, 5716, 5722),
+    ('\u{3356}', Status::Mapped, 5722, 5737),
+    ('\u{3357}', Status::Mapped, 5737, 5746),
+    ('\u{3358}', Status::Mapped, 5746, 5750),
+    ('\u{3359}', Status::Mapped, 5750, 5754),
+    ('\u{335A}', Status::Mapped, 5754, 5758),
+    ('\u{335B}', Status::Mapped, 5758, 5762),
+    ('\u{335C}', Status::Mapped, 5762, 5766),
+    ('\u{335D}', Status::Mapped, 5766, 5770),
+    ('\u{335E}', Status::Mapped, 5770, 5774),
+    ('\u{335F}', Status::Mapped, 5774, 5778),
+    ('\u{3360}', Status::Mapped, 5778, 5782),
+    ('\u{3361}', Status::Mapped, 5782, 5786),
+    ('\u{3362}', Status::Mapped, 5786, 5791),
+    ('\u{3363}', Status::Mapped, 5791, 5796),
+    ('\u{3364}', Status::Mapped, 5796, 5801),
+    ('\u{3365}', Status::Mapped, 5801, 5806),
+    ('\u{3366}', Status::Mapped, 5806, 5811),
+    ('\u{3367}', Status::Mapped, 5811, 5816),
+    ('\u{3368}', Status::Mapped, 5816, 5821),
+    ('\u{3369}', Status::Mapped, 5821, 5826),
+    ('\u{336A}', Status::Mapped, 5826, 5831),
+    ('\u{336B}', Status::Mapped, 5831, 5836),
+    ('\u{336C}', Status::Mapped, 5836, 5841),
+    ('\u{336D}', Status::Mapped, 5841, 5846),
+    ('\u{336E}', Status::Mapped, 5846, 5851),
+    ('\u{336F}', Status::Mapped, 5851, 5856),
+    ('\u{3370}', Status::Mapped, 5856, 5861),
+    ('\u{3371}', Status::Mapped, 5861, 5864),
+    ('\u{3372}', Status::Mapped, 5864, 5866),
+    ('\u{3373}', Status::Mapped, 5866, 5868),
+    ('\u{3374}', Status::Mapped, 5868, 5871),
+    ('\u{3375}', Status::Mapped, 5871, 5873),
+    ('\u{3376}', Status::Mapped, 5873, 5875),
+    ('\u{3377}', Status::Mapped, 5875, 5877),
+    ('\u{3378}', Status::Mapped, 5877, 5880),
+    ('\u{3379}', Status::Mapped, 5880, 5883),
+    ('\u{337A}', Status::Mapped, 5883, 5885),
+    ('\u{337B}', Status::Mapped, 5885, 5891),
+    ('\u{337C}', Status::Mapped, 5891, 5897),
+    ('\u{337D}', Status::Mapped, 5897, 5903),
+    ('\u{337E}', Status::Mapped, 5903, 5909),
+    ('\u{337F}', Status::Mapped, 5909, 5921),
+    ('\u{3380}', Status::Mapped, 5921, 5923),
+    ('\u{3381}', Status::Mapped, 5923, 5925),
+    ('\u{3382}', Status::Mapped, 5925, 5928),
+    ('\u{3383}', Status::Mapped, 5928, 5930),
+    ('\u{3384}', Status::Mapped, 5930, 5932),
+    ('\u{3385}', Status::Mapped, 5932, 5934),
+    ('\u{3386}', Status::Mapped, 5934, 5936),
+    ('\u{3387}', Status::Mapped, 5936, 5938),
+    ('\u{3388}', Status::Mapped, 5938, 5941),
+    ('\u{3389}', Status::Mapped, 5941, 5945),
+    ('\u{338A}', Status::Mapped, 5945, 5947),
+    ('\u{338B}', Status::Mapped, 5947, 5949),
+    ('\u{338C}', Status::Mapped, 5949, 5952),
+    ('\u{338D}', Status::Mapped, 5952, 5955),
+    ('\u{338E}', Status::Mapped, 5955, 5957),
+    ('\u{338F}', Status::Mapped, 5957, 5959),
+    ('\u{3390}', Status::Mapped, 5959, 5961),
+    ('\u{3391}', Status::Mapped, 5961, 5964),
+    ('\u{3392}', Status::Mapped, 5964, 5967),
+    ('\u{3393}', Status::Mapped, 5967, 5970),
+    ('\u{3394}', Status::Mapped, 5970, 5973),
+    ('\u{3395}', Status::Mapped, 5973, 5976),
+    ('\u{3396}', Status::Mapped, 5976, 5978),
+    ('\u{3397}', Status::Mapped, 5978, 5980),
+    ('\u{3398}', Status::Mapped, 5980, 5982),
+    ('\u{3399}', Status::Mapped, 5982, 5984),
+    ('\u{339A}', Status::Mapped, 5984, 5986),
+    ('\u{339B}', Status::Mapped, 5986, 5989),
+    ('\u{339C}', Status::Mapped, 5989, 5991),
+    ('\u{339D}', Status::Mapped, 5991, 5993),
+    ('\u{339E}', Status::Mapped, 5993, 5995),
+    ('\u{339F}', Status::Mapped, 5995, 5998),
+    ('\u{33A0}', Status::Mapped, 5998, 6001),
+    ('\u{33A1}', Status::Mapped, 6001, 6003),
+    ('\u{33A2}', Status::Mapped, 6003, 6006),
+    ('\u{33A3}', Status::Mapped, 6006, 6009),
+    ('\u{33A4}', Status::Mapped, 6009, 6012),
+    ('\u{33A5}', Status::Mapped, 6012, 6014),
+    ('\u{33A6}', Status::Mapped, 6014, 6017),
+    ('\u{33A7}', Status::Mapped, 6017, 6022),
+    ('\u{33A8}', Status::Mapped, 6022, 6028),
+    ('\u{33A9}', Status::Mapped, 5921, 5923),
+    ('\u{33AA}', Status::Mapped, 6028, 6031),
+    ('\u{33AB}', Status::Mapped, 6031, 6034),
+    ('\u{33AC}', Status::Mapped, 6034, 6037),
+    ('\u{33AD}', Status::Mapped, 6037, 6040),
+    ('\u{33AE}', Status::Mapped, 6040, 6047),
+    ('\u{33AF}', Status::Mapped, 6047, 6055),
+    ('\u{33B0}', Status::Mapped, 6055, 6057),
+    ('\u{33B1}', Status::Mapped, 6057, 6059),
+    ('\u{33B2}', Status::Mapped, 6059, 6062),
+    ('\u{33B3}', Status::Mapped, 6062, 6064),
+    ('\u{33B4}', Status::Mapped, 6064, 6066),
+    ('\u{33B5}', Status::Mapped, 6066, 6068),
+    ('\u{33B6}', Status::Mapped, 6068, 6071),
+    ('\u{33B7}', Status::Mapped, 6071, 6073),
+    ('\u{33B8}', Status::Mapped, 6073, 6075),
+    ('\u{33B9}', Status::Mapped, 6071, 6073),
+    ('\u{33BA}', Status::Mapped, 6075, 6077),
+    ('\u{33BB}', Status::Mapped, 6077, 6079),
+    ('\u{33BC}', Status::Mapped, 6079, 6082),
+    ('\u{33BD}', Status::Mapped, 6082, 6084),
+    ('\u{33BE}', Status::Mapped, 6084, 6086),
+    ('\u{33BF}', Status::Mapped, 6082, 6084),
+    ('\u{33C0}', Status::Mapped, 6086, 6089),
+    ('\u{33C1}', Status::Mapped, 6089, 6092),
+    ('\u{33C2}', Status::Disallowed, 0, 0),
+    ('\u{33C3}', Status::Mapped, 6092, 6094),
+    ('\u{33C4}', Status::Mapped, 6094, 6096),
+    ('\u{33C5}', Status::Mapped, 6096, 6098),
+    ('\u{33C6}', Status::Mapped, 6098, 6104),
+    ('\u{33C7}', Status::Disallowed, 0, 0),
+    ('\u{33C8}', Status::Mapped, 6104, 6106),
+    ('\u{33C9}', Status::Mapped, 6106, 6108),
+    ('\u{33CA}', Status::Mapped, 6108, 6110),
+    ('\u{33CB}', Status::Mapped, 6110, 6112),
+    ('\u{33CC}', Status::Mapped, 6112, 6114),
+    ('\u{33CD}', Status::Mapped, 6114, 6116),
+    ('\u{33CE}', Status::Mapped, 5993, 5995),
+    ('\u{33CF}', Status::Mapped, 6116, 6118),
+    ('\u{33D0}', Status::Mapped, 6118, 6120),
+    ('\u{33D1}', Status::Mapped, 6120, 6122),
+    ('\u{33D2}', Status::Mapped, 6122, 6125),
+    ('\u{33D3}', Status::Mapped, 6125, 6127),
+    ('\u{33D4}', Status::Mapped, 5934, 5936),
+    ('\u{33D5}', Status::Mapped, 6127, 6130),
+    ('\u{33D6}', Status::Mapped, 6130, 6133),
+    ('\u{33D7}', Status::Mapped, 6133, 6135),
+    ('\u{33D8}', Status::Disallowed, 0, 0),
+    ('\u{33D9}', Status::Mapped, 6135, 6138),
+    ('\u{33DA}', Status::Mapped, 6138, 6140),
+    ('\u{33DB}', Status::Mapped, 6140, 6142),
+    ('\u{33DC}', Status::Mapped, 6142, 6144),
+    ('\u{33DD}', Status::Mapped, 6144, 6146),
+    ('\u{33DE}', Status::Mapped, 6146, 6151),
+    ('\u{33DF}', Status::Mapped, 6151, 6156),
+    ('\u{33E0}', Status::Mapped, 6156, 6160),
+    ('\u{33E1}', Status::Mapped, 6160, 6164),
+    ('\u{33E2}', Status::Mapped, 6164, 6168),
+    ('\u{33E3}', Status::Mapped, 6168, 6172),
+    ('\u{33E4}', Status::Mapped, 6172, 6176),
+    ('\u{33E5}', Status::Mapped, 6176, 6180),
+    ('\u{33E6}', Status::Mapped, 6180, 6184),
+    ('\u{33E7}', Status::Mapped, 6184, 6188),
+    ('\u{33E8}', Status::Mapped, 6188, 6192),
+    ('\u{33E9}', Status::Mapped, 6192, 6197),
+    ('\u{33EA}', Status::Mapped, 6197, 6202),
+    ('\u{33EB}', Status::Mapped, 6202, 6207),
+    ('\u{33EC}', Status::Mapped, 6207, 6212),
+    ('\u{33ED}', Status::Mapped, 6212, 6217),
+    ('\u{33EE}', Status::Mapped, 6217, 6222),
+    ('\u{33EF}', Status::Mapped, 6222, 6227),
+    ('\u{33F0}', Status::Mapped, 6227, 6232),
+    ('\u{33F1}', Status::Mapped, 6232, 6237),
+    ('\u{33F2}', Status::Mapped, 6237, 6242),
+    ('\u{33F3}', Status::Mapped, 6242, 6247),
+    ('\u{33F4}', Status::Mapped, 6247, 6252),
+    ('\u{33F5}', Status::Mapped, 6252, 6257),
+    ('\u{33F6}', Status::Mapped, 6257, 6262),
+    ('\u{33F7}', Status::Mapped, 6262, 6267),
+    ('\u{33F8}', Status::Mapped, 6267, 6272),
+    ('\u{33F9}', Status::Mapped, 6272, 6277),
+    ('\u{33FA}', Status::Mapped, 6277, 6282),
+    ('\u{33FB}', Status::Mapped, 6282, 6287),
+    ('\u{33FC}', Status::Mapped, 6287, 6292),
+    ('\u{33FD}', Status::Mapped, 6292, 6297),
+    ('\u{33FE}', Status::Mapped, 6297, 6302),
+    ('\u{33FF}', Status::Mapped, 6302, 6305),
+    ('\u{3400}', Status::Valid, 0, 0),
+    ('\u{A48D}', Status::Disallowed, 0, 0),
+    ('\u{A490}', Status::Valid, 0, 0),
+    ('\u{A4C7}', Status::Disallowed, 0, 0),
+    ('\u{A4D0}', Status::Valid, 0, 0),
+    ('\u{A62C}', Status::Disallowed, 0, 0),
+    ('\u{A640}', Status::Mapped, 6305, 6308),
+    ('\u{A641}', Status::Valid, 0, 0),
+    ('\u{A642}', Status::Mapped, 6308, 6311),
+    ('\u{A643}', Status::Valid, 0, 0),
+    ('\u{A644}', Status::Mapped, 6311, 6314),
+    ('\u{A645}', Status::Valid, 0, 0),
+    ('\u{A646}', Status::Mapped, 6314, 6317),
+    ('\u{A647}', Status::Valid, 0, 0),
+    ('\u{A648}', Status::Mapped, 6317, 6320),
+    ('\u{A649}', Status::Valid, 0, 0),
+    ('\u{A64A}', Status::Mapped, 1296, 1299),
+    ('\u{A64B}', Status::Valid, 0, 0),
+    ('\u{A64C}', Status::Mapped, 6320, 6323),
+    ('\u{A64D}', Status::Valid, 0, 0),
+    ('\u{A64E}', Status::Mapped, 6323, 6326),
+    ('\u{A64F}', Status::Valid, 0, 0),
+    ('\u{A650}', Status::Mapped, 6326, 6329),
+    ('\u{A651}', Status::Valid, 0, 0),
+    ('\u{A652}', Status::Mapped, 6329, 6332),
+    ('\u{A653}', Status::Valid, 0, 0),
+    ('\u{A654}', Status::Mapped, 6332, 6335),
+    ('\u{A655}', Status::Valid, 0, 0),
+    ('\u{A656}', Status::Mapped, 6335, 6338),
+    ('\u{A657}', Status::Valid, 0, 0),
+    ('\u{A658}', Status::Mapped, 6338, 6341),
+    ('\u{A659}', Status::Valid, 0, 0),
+    ('\u{A65A}', Status::Mapped, 6341, 6344),
+    ('\u{A65B}', Status::Valid, 0, 0),
+    ('\u{A65C}', Status::Mapped, 6344, 6347),
+    ('\u{A65D}', Status::Valid, 0, 0),
+    ('\u{A65E}', Status::Mapped, 6347, 6350),
+    ('\u{A65F}', Status::Valid, 0, 0),
+    ('\u{A660}', Status::Mapped, 6350, 6353),
+    ('\u{A661}', Status::Valid, 0, 0),
+    ('\u{A662}', Status::Mapped, 6353, 6356),
+    ('\u{A663}', Status::Valid, 0, 0),
+    ('\u{A664}', Status::Mapped, 6356, 6359),
+    ('\u{A665}', Status::Valid, 0, 0),
+    ('\u{A666}', Status::Mapped, 6359, 6362),
+    ('\u{A667}', Status::Valid, 0, 0),
+    ('\u{A668}', Status::Mapped, 6362, 6365),
+    ('\u{A669}', Status::Valid, 0, 0),
+    ('\u{A66A}', Status::Mapped, 6365, 6368),
+    ('\u{A66B}', Status::Valid, 0, 0),
+    ('\u{A66C}', Status::Mapped, 6368, 6371),
+    ('\u{A66D}', Status::Valid, 0, 0),
+    ('\u{A680}', Status::Mapped, 6371, 6374),
+    ('\u{A681}', Status::Valid, 0, 0),
+    ('\u{A682}', Status::Mapped, 6374, 6377),
+    ('\u{A683}', Status::Valid, 0, 0),
+    ('\u{A684}', Status::Mapped, 6377, 6380),
+    ('\u{A685}', Status::Valid, 0, 0),
+    ('\u{A686}', Status::Mapped, 6380, 6383),
+    ('\u{A687}', Status::Valid, 0, 0),
+    ('\u{A688}', Status::Mapped, 6383, 6386),
+    ('\u{A689}', Status::Valid, 0, 0),
+    ('\u{A68A}', Status::Mapped, 6386, 6389),
+    ('\u{A68B}', Status::Valid, 0, 0),
+    ('\u{A68C}', Status::Mapped, 6389, 6392),
+    ('\u{A68D}', Status::Valid, 0, 0),
+    ('\u{A68E}', Status::Mapped, 6392, 6395),
+    ('\u{A68F}', Status::Valid, 0, 0),
+    ('\u{A690}', Status::Mapped, 6395, 6398),
+    ('\u{A691}', Status::Valid, 0, 0),
+    ('\u{A692}', Status::Mapped, 6398, 6401),
+    ('\u{A693}', Status::Valid, 0, 0),
+    ('\u{A694}', Status::Mapped, 6401, 6404),
+    ('\u{A695}', Status::Valid, 0, 0),
+    ('\u{A696}', Status::Mapped, 6404, 6407),
+    ('\u{A697}', Status::Valid, 0, 0),
+    ('\u{A698}', Status::Mapped, 6407, 6410),
+    ('\u{A699}', Status::Valid, 0, 0),
+    ('\u{A69A}', Status::Mapped, 6410, 6413),
+    ('\u{A69B}', Status::Valid, 0, 0),
+    ('\u{A69C}', Status::Mapped, 702, 704),
+    ('\u{A69D}', Status::Mapped, 706, 708),
+    ('\u{A69E}', Status::Valid, 0, 0),
+    ('\u{A6F8}', Status::Disallowed, 0, 0),
+    ('\u{A700}', Status::Valid, 0, 0),
+    ('\u{A722}', Status::Mapped, 6413, 6416),
+    ('\u{A723}', Status::Valid, 0, 0),
+    ('\u{A724}', Status::Mapped, 6416, 6419),
+    ('\u{A725}', Status::Valid, 0, 0),
+    ('\u{A726}', Status::Mapped, 6419, 6422),
+    ('\u{A727}', Status::Valid, 0, 0),
+    ('\u{A728}', Status::Mapped, 6422, 6425),
+    ('\u{A729}', Status::Valid, 0, 0),
+    ('\u{A72A}', Status::Mapped, 6425, 6428),
+    ('\u{A72B}', Status::Valid, 0, 0),
+    ('\u{A72C}', Status::Mapped, 6428, 6431),
+    ('\u{A72D}', Status::Valid, 0, 0),
+    ('\u{A72E}', Status::Mapped, 6431, 6434),
+    ('\u{A72F}', Status::Valid, 0, 0),
+    ('\u{A732}', Status::Mapped, 6434, 6437),
+    ('\u{A733}', Status::Valid, 0, 0),
+    ('\u{A734}', Status::Mapped, 6437, 6440),
+    ('\u{A735}', Status::Valid, 0, 0),
+    ('\u{A736}', Status::Mapped, 6440, 6443),
+    ('\u{A737}', Status::Valid, 0, 0),
+    ('\u{A738}', Status::Mapped, 6443, 6446),
+    ('\u{A739}', Status::Valid, 0, 0),
+    ('\u{A73A}', Status::Mapped, 6446, 6449),
+    ('\u{A73B}', Status::Valid, 0, 0),
+    ('\u{A73C}', Status::Mapped, 6449, 6452),
+    ('\u{A73D}', Status::Valid, 0, 0),
+    ('\u{A73E}', Status::Mapped, 6452, 6455),
+    ('\u{A73F}', Status::Valid, 0, 0),
+    ('\u{A740}', Status::Mapped, 6455, 6458),
+    ('\u{A741}', Status::Valid, 0, 0),
+    ('\u{A742}', Status::Mapped, 6458, 6461),
+    ('\u{A743}', Status::Valid, 0, 0),
+    ('\u{A744}', Status::Mapped, 6461, 6464),
+    ('\u{A745}', Status::Valid, 0, 0),
+    ('\u{A746}', Status::Mapped, 6464, 6467),
+    ('\u{A747}', Status::Valid, 0, 0),
+    ('\u{A748}', Status::Mapped, 6467, 6470),
+    ('\u{A749}', Status::Valid, 0, 0),
+    ('\u{A74A}', Status::Mapped, 6470, 6473),
+    ('\u{A74B}', Status::Valid, 0, 0),
+    ('\u{A74C}', Status::Mapped, 6473, 6476),
+    ('\u{A74D}', Status::Valid, 0, 0),
+    ('\u{A74E}', Status::Mapped, 6476, 6479),
+    ('\u{A74F}', Status::Valid, 0, 0),
+    ('\u{A750}', Status::Mapped, 6479, 6482),
+    ('\u{A751}', Status::Valid, 0, 0),
+    ('\u{A752}', Status::Mapped, 6482, 6485),
+    ('\u{A753}', Status::Valid, 0, 0),
+    ('\u{A754}', Status::Mapped, 6485, 6488),
+    ('\u{A755}', Status::Valid, 0, 0),
+    ('\u{A756}', Status::Mapped, 6488, 6491),
+    ('\u{A757}', Status::Valid, 0, 0),
+    ('\u{A758}', Status::Mapped, 6491, 6494),
+    ('\u{A759}', Status::Valid, 0, 0),
+    ('\u{A75A}', Status::Mapped, 6494, 6497),
+    ('\u{A75B}', Status::Valid, 0, 0),
+    ('\u{A75C}', Status::Mapped, 6497, 6500),
+    ('\u{A75D}', Status::Valid, 0, 0),
+    ('\u{A75E}', Status::Mapped, 6500, 6503),
+    ('\u{A75F}', Status::Valid, 0, 0),
+    ('\u{A760}', Status::Mapped, 6503, 6506),
+    ('\u{A761}', Status::Valid, 0, 0),
+    ('\u{A762}', Status::Mapped, 6506, 6509),
+    ('\u{A763}', Status::Valid, 0, 0),
+    ('\u{A764}', Status::Mapped, 6509, 6512),
+    ('\u{A765}', Status::Valid, 0, 0),
+    ('\u{A766}', Status::Mapped, 6512, 6515),
+    ('\u{A767}', Status::Valid, 0, 0),
+    ('\u{A768}', Status::Mapped, 6515, 6518),
+    ('\u{A769}', Status::Valid, 0, 0),
+    ('\u{A76A}', Status::Mapped, 6518, 6521),
+    ('\u{A76B}', Status::Valid, 0, 0),
+    ('\u{A76C}', Status::Mapped, 6521, 6524),
+    ('\u{A76D}', Status::Valid, 0, 0),
+    ('\u{A76E}', Status::Mapped, 6524, 6527),
+    ('\u{A76F}', Status::Valid, 0, 0),
+    ('\u{A770}', Status::Mapped, 6524, 6527),
+    ('\u{A771}', Status::Valid, 0, 0),
+    ('\u{A779}', Status::Mapped, 6527, 6530),
+    ('\u{A77A}', Status::Valid, 0, 0),
+    ('\u{A77B}', Status::Mapped, 6530, 6533),
+    ('\u{A77C}', Status::Valid, 0, 0),
+    ('\u{A77D}', Status::Mapped, 6533, 6536),
+    ('\u{A77E}', Status::Mapped, 6536, 6539),
+    ('\u{A77F}', Status::Valid, 0, 0),
+    ('\u{A780}', Status::Mapped, 6539, 6542),
+    ('\u{A781}', Status::Valid, 0, 0),
+    ('\u{A782}', Status::Mapped, 6542, 6545),
+    ('\u{A783}', Status::Valid, 0, 0),
+    ('\u{A784}', Status::Mapped, 6545, 6548),
+    ('\u{A785}', Status::Valid, 0, 0),
+    ('\u{A786}', Status::Mapped, 6548, 6551),
+    ('\u{A787}', Status::Valid, 0, 0),
+    ('\u{A78B}', Status::Mapped, 6551, 6554),
+    ('\u{A78C}', Status::Valid, 0, 0),
+    ('\u{A78D}', Status::Mapped, 1463, 1465),
+    ('\u{A78E}', Status::Valid, 0, 0),
+    ('\u{A790}', Status::Mapped, 6554, 6557),
+    ('\u{A791}', Status::Valid, 0, 0),
+    ('\u{A792}', Status::Mapped, 6557, 6560),
+    ('\u{A793}', Status::Valid, 0, 0),
+    ('\u{A796}', Status::Mapped, 6560, 6563),
+    ('\u{A797}', Status::Valid, 0, 0),
+    ('\u{A798}', Status::Mapped, 6563, 6566),
+    ('\u{A799}', Status::Valid, 0, 0),
+    ('\u{A79A}', Status::Mapped, 6566, 6569),
+    ('\u{A79B}', Status::Valid, 0, 0),
+    ('\u{A79C}', Status::Mapped, 6569, 6572),
+    ('\u{A79D}', Status::Valid, 0, 0),
+    ('\u{A79E}', Status::Mapped, 6572, 6575),
+    ('\u{A79F}', Status::Valid, 0, 0),
+    ('\u{A7A0}', Status::Mapped, 6575, 6578),
+    ('\u{A7A1}', Status::Valid, 0, 0),
+    ('\u{A7A2}', Status::Mapped, 6578, 6581),
+    ('\u{A7A3}', Status::Valid, 0, 0),
+    ('\u{A7A4}', Status::Mapped, 6581, 6584),
+    ('\u{A7A5}', Status::Valid, 0, 0),
+    ('\u{A7A6}', Status::Mapped, 6584, 6587),
+    ('\u{A7A7}', Status::Valid, 0, 0),
+    ('\u{A7A8}', Status::Mapped, 6587, 6590),
+    ('\u{A7A9}', Status::Valid, 0, 0),
+    ('\u{A7AA}', Status::Mapped, 459, 461),
+    ('\u{A7AB}', Status::Mapped, 1441, 1443),
+    ('\u{A7AC}', Status::Mapped, 1461, 1463),
+    ('\u{A7AD}', Status::Mapped, 6590, 6592),
+    ('\u{A7AE}', Status::Mapped, 1465, 1467),
+    ('\u{A7AF}', Status::Valid, 0, 0),
+    ('\u{A7B0}', Status::Mapped, 6592, 6594),
+    ('\u{A7B1}', Status::Mapped, 6594, 6596),
+    ('\u{A7B2}', Status::Mapped, 1470, 1472),
+    ('\u{A7B3}', Status::Mapped, 6596, 6599),
+    ('\u{A7B4}', Status::Mapped, 6599, 6602),
+    ('\u{A7B5}', Status::Valid, 0, 0),
+    ('\u{A7B6}', Status::Mapped, 6602, 6605),
+    ('\u{A7B7}', Status::Valid, 0, 0),
+    ('\u{A7B8}', Status::Mapped, 6605, 6608),
+    ('\u{A7B9}', Status::Valid, 0, 0),
+    ('\u{A7BA}', Status::Mapped, 6608, 6611),
+    ('\u{A7BB}', Status::Valid, 0, 0),
+    ('\u{A7BC}', Status::Mapped, 6611, 6614),
+    ('\u{A7BD}', Status::Valid, 0, 0),
+    ('\u{A7BE}', Status::Mapped, 6614, 6617),
+    ('\u{A7BF}', Status::Valid, 0, 0),
+    ('\u{A7C0}', Status::Mapped, 6617, 6620),
+    ('\u{A7C1}', Status::Valid, 0, 0),
+    ('\u{A7C2}', Status::Mapped, 6620, 6623),
+    ('\u{A7C3}', Status::Valid, 0, 0),
+    ('\u{A7C4}', Status::Mapped, 6623, 6626),
+    ('\u{A7C5}', Status::Mapped, 1489, 1491),
+    ('\u{A7C6}', Status::Mapped, 6626, 6629),
+    ('\u{A7C7}', Status::Mapped, 6629, 6632),
+    ('\u{A7C8}', Status::Valid, 0, 0),
+    ('\u{A7C9}', Status::Mapped, 6632, 6635),
+    ('\u{A7CA}', Status::Valid, 0, 0),
+    ('\u{A7CB}', Status::Disallowed, 0, 0),
+    ('\u{A7D0}', Status::Mapped, 6635, 6638),
+    ('\u{A7D1}', Status::Valid, 0, 0),
+    ('\u{A7D2}', Status::Disallowed, 0, 0),
+    ('\u{A7D3}', Status::Valid, 0, 0),
+    ('\u{A7D4}', Status::Disallowed, 0, 0),
+    ('\u{A7D5}', Status::Valid, 0, 0),
+    ('\u{A7D6}', Status::Mapped, 6638, 6641),
+    ('\u{A7D7}', Status::Valid, 0, 0),
+    ('\u{A7D8}', Status::Mapped, 6641, 6644),
+    ('\u{A7D9}', Status::Valid, 0, 0),
+    ('\u{A7DA}', Status::Disallowed, 0, 0),
+    ('\u{A7F2}', Status::Mapped, 2, 3),
+    ('\u{A7F3}', Status::Mapped, 5, 6),
+    ('\u{A7F4}', Status::Mapped, 16, 17),
+    ('\u{A7F5}', Status::Mapped, 6644, 6647),
+    ('\u{A7F6}', Status::Valid, 0, 0),
+    ('\u{A7F8}', Status::Mapped, 157, 159),
+    ('\u{A7F9}', Status::Mapped, 204, 206),
+    ('\u{A7FA}', Status::Valid, 0, 0),
+    ('\u{A82D}', Status::Disallowed, 0, 0),
+    ('\u{A830}', Status::Valid, 0, 0),
+    ('\u{A83A}', Status::Disallowed, 0, 0),
+    ('\u{A840}', Status::Valid, 0, 0),
+    ('\u{A878}', Status::Disallowed, 0, 0),
+    ('\u{A880}', Status::Valid, 0, 0),
+    ('\u{A8C6}', Status::Disallowed, 0, 0),
+    ('\u{A8CE}', Status::Valid, 0, 0),
+    ('\u{A8DA}', Status::Disallowed, 0, 0),
+    ('\u{A8E0}', Status::Valid, 0, 0),
+    ('\u{A954}', Status::Disallowed, 0, 0),
+    ('\u{A95F}', Status::Valid, 0, 0),
+    ('\u{A97D}', Status::Disallowed, 0, 0),
+    ('\u{A980}', Status::Valid, 0, 0),
+    ('\u{A9CE}', Status::Disallowed, 0, 0),
+    ('\u{A9CF}', Status::Valid, 0, 0),
+    ('\u{A9DA}', Status::Disallowed, 0, 0),
+    ('\u{A9DE}', Status::Valid, 0, 0),
+    ('\u{A9FF}', Status::Disallowed, 0, 0),
+    ('\u{AA00}', Status::Valid, 0, 0),
+    ('\u{AA37}', Status::Disallowed, 0, 0),
+    ('\u{AA40}', Status::Valid, 0, 0),
+    ('\u{AA4E}', Status::Disallowed, 0, 0),
+    ('\u{AA50}', Status::Valid, 0, 0),
+    ('\u{AA5A}', Status::Disallowed, 0, 0),
+    ('\u{AA5C}', Status::Valid, 0, 0),
+    ('\u{AAC3}', Status::Disallowed, 0, 0),
+    ('\u{AADB}', Status::Valid, 0, 0),
+    ('\u{AAF7}', Status::Disallowed, 0, 0),
+    ('\u{AB01}', Status::Valid, 0, 0),
+    ('\u{AB07}', Status::Disallowed, 0, 0),
+    ('\u{AB09}', Status::Valid, 0, 0),
+    ('\u{AB0F}', Status::Disallowed, 0, 0),
+    ('\u{AB11}', Status::Valid, 0, 0),
+    ('\u{AB17}', Status::Disallowed, 0, 0),
+    ('\u{AB20}', Status::Valid, 0, 0),
+    ('\u{AB27}', Status::Disallowed, 0, 0),
+    ('\u{AB28}', Status::Valid, 0, 0),
+    ('\u{AB2F}', Status::Disallowed, 0, 0),
+    ('\u{AB30}', Status::Valid, 0, 0),
+    ('\u{AB5C}', Status::Mapped, 6419, 6422),
+    ('\u{AB5D}', Status::Mapped, 6647, 6650),
+    ('\u{AB5E}', Status::Mapped, 2891, 2893),
+    ('\u{AB5F}', Status::Mapped, 6650, 6653),
+    ('\u{AB60}', Status::Valid, 0, 0),
+    ('\u{AB69}', Status::Mapped, 6653, 6655),
+    ('\u{AB6A}', Status::Valid, 0, 0),
+    ('\u{AB6C}', Status::Disallowed, 0, 0),
+    ('\u{AB70}', Status::Mapped, 6655, 6658),
+    ('\u{AB71}', Status::Mapped, 6658, 6661),
+    ('\u{AB72}', Status::Mapped, 6661, 6664),
+    ('\u{AB73}', Status::Mapped, 6664, 6667),
+    ('\u{AB74}', Status::Mapped, 6667, 6670),
+    ('\u{AB75}', Status::Mapped, 6670, 6673),
+    ('\u{AB76}', Status::Mapped, 6673, 6676),
+    ('\u{AB77}', Status::Mapped, 6676, 6679),
+    ('\u{AB78}', Status::Mapped, 6679, 6682),
+    ('\u{AB79}', Status::Mapped, 6682, 6685),
+    ('\u{AB7A}', Status::Mapped, 6685, 6688),
+    ('\u{AB7B}', Status::Mapped, 6688, 6691),
+    ('\u{AB7C}', Status::Mapped, 6691, 6694),
+    ('\u{AB7D}', Status::Mapped, 6694, 6697),
+    ('\u{AB7E}', Status::Mapped, 6697, 6700),
+    ('\u{AB7F}', Status::Mapped, 6700, 6703),
+    ('\u{AB80}', Status::Mapped, 6703, 6706),
+    ('\u{AB81}', Status::Mapped, 6706, 6709),
+    ('\u{AB82}', Status::Mapped, 6709, 6712),
+    ('\u{AB83}', Status::Mapped, 6712, 6715),
+    ('\u{AB84}', Status::Mapped, 6715, 6718),
+    ('\u{AB85}', Status::Mapped, 6718, 6721),
+    ('\u{AB86}', Status::Mapped, 6721, 6724),
+    ('\u{AB87}', Status::Mapped, 6724, 6727),
+    ('\u{AB88}', Status::Mapped, 6727, 6730),
+    ('\u{AB89}', Status::Mapped, 6730, 6733),
+    ('\u{AB8A}', Status::Mapped, 6733, 6736),
+    ('\u{AB8B}', Status::Mapped, 6736, 6739),
+    ('\u{AB8C}', Status::Mapped, 6739, 6742),
+    ('\u{AB8D}', Status::Mapped, 6742, 6745),
+    ('\u{AB8E}', Status::Mapped, 6745, 6748),
+    ('\u{AB8F}', Status::Mapped, 6748, 6751),
+    ('\u{AB90}', Status::Mapped, 6751, 6754),
+    ('\u{AB91}', Status::Mapped, 6754, 6757),
+    ('\u{AB92}', Status::Mapped, 6757, 6760),
+    ('\u{AB93}', Status::Mapped, 6760, 6763),
+    ('\u{AB94}', Status::Mapped, 6763, 6766),
+    ('\u{AB95}', Status::Mapped, 6766, 6769),
+    ('\u{AB96}', Status::Mapped, 6769, 6772),
+    ('\u{AB97}', Status::Mapped, 6772, 6775),
+    ('\u{AB98}', Status::Mapped, 6775, 6778),
+    ('\u{AB99}', Status::Mapped, 6778, 6781),
+    ('\u{AB9A}', Status::Mapped, 6781, 6784),
+    ('\u{AB9B}', Status::Mapped, 6784, 6787),
+    ('\u{AB9C}', Status::Mapped, 6787, 6790),
+    ('\u{AB9D}', Status::Mapped, 6790, 6793),
+    ('\u{AB9E}', Status::Mapped, 6793, 6796),
+    ('\u{AB9F}', Status::Mapped, 6796, 6799),
+    ('\u{ABA0}', Status::Mapped, 6799, 6802),
+    ('\u{ABA1}', Status::Mapped, 6802, 6805),
+    ('\u{ABA2}', Status::Mapped, 6805, 6808),
+    ('\u{ABA3}', Status::Mapped, 6808, 6811),
+    ('\u{ABA4}', Status::Mapped, 6811, 6814),
+    ('\u{ABA5}', Status::Mapped, 6814, 6817),
+    ('\u{ABA6}', Status::Mapped, 6817, 6820),
+    ('\u{ABA7}', Status::Mapped, 6820, 6823),
+    ('\u{ABA8}', Status::Mapped, 6823, 6826),
+    ('\u{ABA9}', Status::Mapped, 6826, 6829),
+    ('\u{ABAA}', Status::Mapped, 6829, 6832),
+    ('\u{ABAB}', Status::Mapped, 6832, 6835),
+    ('\u{ABAC}', Status::Mapped, 6835, 6838),
+    ('\u{ABAD}', Status::Mapped, 6838, 6841),
+    ('\u{ABAE}', Status::Mapped, 6841, 6844),
+    ('\u{ABAF}', Status::Mapped, 6844, 6847),
+    ('\u{ABB0}', Status::Mapped, 6847, 6850),
+    ('\u{ABB1}', Status::Mapped, 6850, 6853),
+    ('\u{ABB2}', Status::Mapped, 6853, 6856),
+    ('\u{ABB3}', Status::Mapped, 6856, 6859),
+    ('\u{ABB4}', Status::Mapped, 6859, 6862),
+    ('\u{ABB5}', Status::Mapped, 6862, 6865),
+    ('\u{ABB6}', Status::Mapped, 6865, 6868),
+    ('\u{ABB7}', Status::Mapped, 6868, 6871),
+    ('\u{ABB8}', Status::Mapped, 6871, 6874),
+    ('\u{ABB9}', Status::Mapped, 6874, 6877),
+    ('\u{ABBA}', Status::Mapped, 6877, 6880),
+    ('\u{ABBB}', Status::Mapped, 6880, 6883),
+    ('\u{ABBC}', Status::Mapped, 6883, 6886),
+    ('\u{ABBD}', Status::Mapped, 6886, 6889),
+    ('\u{ABBE}', Status::Mapped, 6889, 6892),
+    ('\u{ABBF}', Status::Mapped, 6892, 6895),
+    ('\u{ABC0}', Status::Valid, 0, 0),
+    ('\u{ABEE}', Status::Disallowed, 0, 0),
+    ('\u{ABF0}', Status::Valid, 0, 0),
+    ('\u{ABFA}', Status::Disallowed, 0, 0),
+    ('\u{AC00}', Status::Valid, 0, 0),
+    ('\u{D7A4}', Status::Disallowed, 0, 0),
+    ('\u{D7B0}', Status::Valid, 0, 0),
+    ('\u{D7C7}', Status::Disallowed, 0, 0),
+    ('\u{D7CB}', Status::Valid, 0, 0),
+    ('\u{D7FC}', Status::Disallowed, 0, 0),
+    ('\u{F900}', Status::Mapped, 6895, 6898),
+    ('\u{F901}', Status::Mapped, 6898, 6901),
+    ('\u{F902}', Status::Mapped, 3559, 3562),
+    ('\u{F903}', Status::Mapped, 6901, 6904),
+    ('\u{F904}', Status::Mapped, 6904, 6907),
+    ('\u{F905}', Status::Mapped, 6907, 6910),
+    ('\u{F906}', Status::Mapped, 6910, 6913),
+    ('\u{F907}', Status::Mapped, 3721, 3724),
+    ('\u{F909}', Status::Mapped, 6913, 6916),
+    ('\u{F90A}', Status::Mapped, 3583, 3586),
+    ('\u{F90B}', Status::Mapped, 6916, 6919),
+    ('\u{F90C}', Status::Mapped, 6919, 6922),
+    ('\u{F90D}', Status::Mapped, 6922, 6925),
+    ('\u{F90E}', Status::Mapped, 6925, 6928),
+    ('\u{F90F}', Status::Mapped, 6928, 6931),
+    ('\u{F910}', Status::Mapped, 6931, 6934),
+    ('\u{F911}', Status::Mapped, 6934, 6937),
+    ('\u{F912}', Status::Mapped, 6937, 6940),
+    ('\u{F913}', Status::Mapped, 6940, 6943),
+    ('\u{F914}', Status::Mapped, 6943, 6946),
+    ('\u{F915}', Status::Mapped, 6946, 6949),
+    ('\u{F916}', Status::Mapped, 6949, 6952),
+    ('\u{F917}', Status::Mapped, 6952, 6955),
+    ('\u{F918}', Status::Mapped, 6955, 6958),
+    ('\u{F919}', Status::Mapped, 6958, 6961),
+    ('\u{F91A}', Status::Mapped, 6961, 6964),
+    ('\u{F91B}', Status::Mapped, 6964, 6967),
+    ('\u{F91C}', Status::Mapped, 6967, 6970),
+    ('\u{F91D}', Status::Mapped, 6970, 6973),
+    ('\u{F91E}', Status::Mapped, 6973, 6976),
+    ('\u{F91F}', Status::Mapped, 6976, 6979),
+    ('\u{F920}', Status::Mapped, 6979, 6982),
+    ('\u{F921}', Status::Mapped, 6982, 6985),
+    ('\u{F922}', Status::Mapped, 6985, 6988),
+    ('\u{F923}', Status::Mapped, 6988, 6991),
+    ('\u{F924}', Status::Mapped, 6991, 6994),
+    ('\u{F925}', Status::Mapped, 6994, 6997),
+    ('\u{F926}', Status::Mapped, 6997, 7000),
+    ('\u{F927}', Status::Mapped, 7000, 7003),
+    ('\u{F928}', Status::Mapped, 7003, 7006),
+    ('\u{F929}', Status::Mapped, 7006, 7009),
+    ('\u{F92A}', Status::Mapped, 7009, 7012),
+    ('\u{F92B}', Status::Mapped, 7012, 7015),
+    ('\u{F92C}', Status::Mapped, 7015, 7018),
+    ('\u{F92D}', Status::Mapped, 7018, 7021),
+    ('\u{F92E}', Status::Mapped, 7021, 7024),
+    ('\u{F92F}', Status::Mapped, 7024, 7027),
+    ('\u{F930}', Status::Mapped, 7027, 7030),
+    ('\u{F931}', Status::Mapped, 7030, 7033),
+    ('\u{F932}', Status::Mapped, 7033, 7036),
+    ('\u{F933}', Status::Mapped, 7036, 7039),
+    ('\u{F934}', Status::Mapped, 3457, 3460),
+    ('\u{F935}', Status::Mapped, 7039, 7042),
+    ('\u{F936}', Status::Mapped, 7042, 7045),
+    ('\u{F937}', Status::Mapped, 7045, 7048),
+    ('\u{F938}', Status::Mapped, 7048, 7051),
+    ('\u{F939}', Status::Mapped, 7051, 7054),
+    ('\u{F93A}', Status::Mapped, 7054, 7057),
+    ('\u{F93B}', Status::Mapped, 7057, 7060),
+    ('\u{F93C}', Status::Mapped, 7060, 7063),
+    ('\u{F93D}', Status::Mapped, 7063, 7066),
+    ('\u{F93E}', Status::Mapped, 7066, 7069),
+    ('\u{F93F}', Status::Mapped, 7069, 7072),
+    ('\u{F940}', Status::Mapped, 3676, 3679),
+    ('\u{F941}', Status::Mapped, 7072, 7075),
+    ('\u{F942}', Status::Mapped, 7075, 7078),
+    ('\u{F943}', Status::Mapped, 7078, 7081),
+    ('\u{F944}', Status::Mapped, 7081, 7084),
+    ('\u{F945}', Status::Mapped, 7084, 7087),
+    ('\u{F946}', Status::Mapped, 7087, 7090),
+    ('\u{F947}', Status::Mapped, 7090, 7093),
+    ('\u{F948}', Status::Mapped, 7093, 7096),
+    ('\u{F949}', Status::Mapped, 7096, 7099),
+    ('\u{F94A}', Status::Mapped, 7099, 7102),
+    ('\u{F94B}', Status::Mapped, 7102, 7105),
+    ('\u{F94C}', Status::Mapped, 7105, 7108),
+    ('\u{F94D}', Status::Mapped, 7108, 7111),
+    ('\u{F94E}', Status::Mapped, 7111, 7114),
+    ('\u{F94F}', Status::Mapped, 7114, 7117),
+    ('\u{F950}', Status::Mapped, 7117, 7120),
+    ('\u{F951}', Status::Mapped, 7120, 7123),
+    ('\u{F952}', Status::Mapped, 7123, 7126),
+    ('\u{F953}', Status::Mapped, 7126, 7129),
+    ('\u{F954}', Status::Mapped, 7129, 7132),
+    ('\u{F955}', Status::Mapped, 7132, 7135),
+    ('\u{F956}', Status::Mapped, 7135, 7138),
+    ('\u{F957}', Status::Mapped, 7138, 7141),
+    ('\u{F958}', Status::Mapped, 7141, 7144),
+    ('\u{F959}', Status::Mapped, 7144, 7147),
+    ('\u{F95A}', Status::Mapped, 7147, 7150),
+    ('\u{F95B}', Status::Mapped, 7150, 7153),
+    ('\u{F95C}', Status::Mapped, 6943, 6946),
+    ('\u{F95D}', Status::Mapped, 7153, 7156),
+    ('\u{F95E}', Status::Mapped, 7156, 7159),
+    ('\u{F95F}', Status::Mapped, 7159, 7162),
+    ('\u{F960}', Status::Mapped, 7162, 7165),
+    ('\u{F961}', Status::Mapped, 7165, 7168),
+    ('\u{F962}', Status::Mapped, 7168, 7171),
+    ('\u{F963}', Status::Mapped, 7171, 7174),
+    ('\u{F964}', Status::Mapped, 7174, 7177),
+    ('\u{F965}', Status::Mapped, 7177, 7180),
+    ('\u{F966}', Status::Mapped, 7180, 7183),
+    ('\u{F967}', Status::Mapped, 7183, 7186),
+    ('\u{F968}', Status::Mapped, 7186, 7189),
+    ('\u{F969}', Status::Mapped, 7189, 7192),
+    ('\u{F96A}', Status::Mapped, 7192, 7195),
+    ('\u{F96B}', Status::Mapped, 7195, 7198),
+    ('\u{F96C}', Status::Mapped, 7198, 7201),
+    ('\u{F96D}', Status::Mapped, 7201, 7204),
+    ('\u{F96E}', Status::Mapped, 7204, 7207),
+    ('\u{F96F}', Status::Mapped, 7207, 7210),
+    ('\u{F970}', Status::Mapped, 7210, 7213),
+    ('\u{F971}', Status::Mapped, 3565, 3568),
+    ('\u{F972}', Status::Mapped, 7213, 7216),
+    ('\u{F973}', Status::Mapped, 7216, 7219),
+    ('\u{F974}', Status::Mapped, 7219, 7222),
+    ('\u{F975}', Status::Mapped, 7222, 7225),
+    ('\u{F976}', Status::Mapped, 7225, 7228),
+    ('\u{F977}', Status::Mapped, 7228, 7231),
+    ('\u{F978}', Status::Mapped, 7231, 7234),
+    ('\u{F979}', Status::Mapped, 7234, 7237),
+    ('\u{F97A}', Status::Mapped, 7237, 7240),
+    ('\u{F97B}', Status::Mapped, 7240, 7243),
+    ('\u{F97C}', Status::Mapped, 7243, 7246),
+    ('\u{F97D}', Status::Mapped, 7246, 7249),
+    ('\u{F97E}', Status::Mapped, 7249, 7252),
+    ('\u{F97F}', Status::Mapped, 7252, 7255),
+    ('\u{F980}', Status::Mapped, 7255, 7258),
+    ('\u{F981}', Status::Mapped, 3196, 3199),
+    ('\u{F982}', Status::Mapped, 7258, 7261),
+    ('\u{F983}', Status::Mapped, 7261, 7264),
+    ('\u{F984}', Status::Mapped, 7264, 7267),
+    ('\u{F985}', Status::Mapped, 7267, 7270),
+    ('\u{F986}', Status::Mapped, 7270, 7273),
+    ('\u{F987}', Status::Mapped, 7273, 7276),
+    ('\u{F988}', Status::Mapped, 7276, 7279),
+    ('\u{F989}', Status::Mapped, 7279, 7282),
+    ('\u{F98A}', Status::Mapped, 3139, 3142),
+    ('\u{F98B}', Status::Mapped, 7282, 7285),
+    ('\u{F98C}', Status::Mapped, 7285, 7288),
+    ('\u{F98D}', Status::Mapped, 7288, 7291),
+    ('\u{F98E}', Status::Mapped, 7291, 7294),
+    ('\u{F98F}', Status::Mapped, 7294, 7297),
+    ('\u{F990}', Status::Mapped, 7297, 7300),
+    ('\u{F991}', Status::Mapped, 7300, 7303),
+    ('\u{F992}', Status::Mapped, 7303, 7306),
+    ('\u{F993}', Status::Mapped, 7306, 7309),
+    ('\u{F994}', Status::Mapped, 7309, 7312),
+    ('\u{F995}', Status::Mapped, 7312, 7315),
+    ('\u{F996}', Status::Mapped, 7315, 7318),
+    ('\u{F997}', Status::Mapped, 7318, 7321),
+    ('\u{F998}', Status::Mapped, 7321, 7324),
+    ('\u{F999}', Status::Mapped, 7324, 7327),
+    ('\u{F99A}', Status::Mapped, 7327, 7330),
+    ('\u{F99B}', Status::Mapped, 7330, 7333),
+    ('\u{F99C}', Status::Mapped, 7333, 7336),
+    ('\u{F99D}', Status::Mapped, 7336, 7339),
+    ('\u{F99E}', Status::Mapped, 7339, 7342),
+    ('\u{F99F}', Status::Mapped, 7342, 7345),
+    ('\u{F9A0}', Status::Mapped, 7345, 7348),
+    ('\u{F9A1}', Status::Mapped, 7207, 7210),
+    ('\u{F9A2}', Status::Mapped, 7348, 7351),
+    ('\u{F9A3}', Status::Mapped, 7351, 7354),
+    ('\u{F9A4}', Status::Mapped, 7354, 7357),
+    ('\u{F9A5}', Status::Mapped, 7357, 7360),
+    ('\u{F9A6}', Status::Mapped, 7360, 7363),
+    ('\u{F9A7}', Status::Mapped, 7363, 7366),
+    ('\u{F9A8}', Status::Mapped, 7366, 7369),
+    ('\u{F9A9}', Status::Mapped, 7369, 7372),
+    ('\u{F9AA}', Status::Mapped, 7159, 7162),
+    ('\u{F9AB}', Status::Mapped, 7372, 7375),
+    ('\u{F9AC}', Status::Mapped, 7375, 7378),
+    ('\u{F9AD}', Status::Mapped, 7378, 7381),
+    ('\u{F9AE}', Status::Mapped, 7381, 7384),
+    ('\u{F9AF}', Status::Mapped, 7384, 7387),
+    ('\u{F9B0}', Status::Mapped, 7387, 7390),
+    ('\u{F9B1}', Status::Mapped, 7390, 7393),
+    ('\u{F9B2}', Status::Mapped, 7393, 7396),
+    ('\u{F9B3}', Status::Mapped, 7396, 7399),
+    ('\u{F9B4}', Status::Mapped, 7399, 7402),
+    ('\u{F9B5}', Status::Mapped, 7402, 7405),
+    ('\u{F9B6}', Status::Mapped, 7405, 7408),
+    ('\u{F9B7}', Status::Mapped, 7408, 7411),
+    ('\u{F9B8}', Status::Mapped, 7411, 7414),
+    ('\u{F9B9}', Status::Mapped, 7414, 7417),
+    ('\u{F9BA}', Status::Mapped, 7417, 7420),
+    ('\u{F9BB}', Status::Mapped, 7420, 7423),
+    ('\u{F9BC}', Status::Mapped, 7423, 7426),
+    ('\u{F9BD}', Status::Mapped, 7426, 7429),
+    ('\u{F9BE}', Status::Mapped, 7429, 7432),
+    ('\u{F9BF}', Status::Mapped, 6943, 6946),
+    ('\u{F9C0}', Status::Mapped, 7432, 7435),
+    ('\u{F9C1}', Status::Mapped, 7435, 7438),
+    ('\u{F9C2}', Status::Mapped, 7438, 7441),
+    ('\u{F9C3}', Status::Mapped, 7441, 7444),
+    ('\u{F9C4}', Status::Mapped, 3718, 3721),
+    ('\u{F9C5}', Status::Mapped, 7444, 7447),
+    ('\u{F9C6}', Status::Mapped, 7447, 7450),
+    ('\u{F9C7}', Status::Mapped, 7450, 7453),
+    ('\u{F9C8}', Status::Mapped, 7453, 7456),
+    ('\u{F9C9}', Status::Mapped, 7456, 7459),
+    ('\u{F9CA}', Status::Mapped, 7459, 7462),
+    ('\u{F9CB}', Status::Mapped, 7462, 7465),
+    ('\u{F9CC}', Status::Mapped, 7465, 7468),
+    ('\u{F9CD}', Status::Mapped, 7468, 7471),
+    ('\u{F9CE}', Status::Mapped, 7471, 7474),
+    ('\u{F9CF}', Status::Mapped, 7474, 7477),
+    ('\u{F9D0}', Status::Mapped, 7477, 7480),
+    ('\u{F9D1}', Status::Mapped, 4509, 4512),
+    ('\u{F9D2}', Status::Mapped, 7480, 7483),
+    ('\u{F9D3}', Status::Mapped, 7483, 7486),
+    ('\u{F9D4}', Status::Mapped, 7486, 7489),
+    ('\u{F9D5}', Status::Mapped, 7489, 7492),
+    ('\u{F9D6}', Status::Mapped, 7492, 7495),
+    ('\u{F9D7}', Status::Mapped, 7495, 7498),
+    ('\u{F9D8}', Status::Mapped, 7498, 7501),
+    ('\u{F9D9}', Status::Mapped, 7501, 7504),
+    ('\u{F9DA}', Status::Mapped, 7504, 7507),
+    ('\u{F9DB}', Status::Mapped, 7165, 7168),
+    ('\u{F9DC}', Status::Mapped, 7507, 7510),
+    ('\u{F9DD}', Status::Mapped, 7510, 7513),
+    ('\u{F9DE}', Status::Mapped, 7513, 7516),
+    ('\u{F9DF}', Status::Mapped, 7516, 7519),
+    ('\u{F9E0}', Status::Mapped, 7519, 7522),
+    ('\u{F9E1}', Status::Mapped, 7522, 7525),
+    ('\u{F9E2}', Status::Mapped, 7525, 7528),
+    ('\u{F9E3}', Status::Mapped, 7528, 7531),
+    ('\u{F9E4}', Status::Mapped, 7531, 7534),
+    ('\u{F9E5}', Status::Mapped, 7534, 7537),
+    ('\u{F9E6}', Status::Mapped, 7537, 7540),
+    ('\u{F9E7}', Status::Mapped, 7540, 7543),
+    ('\u{F9E8}', Status::Mapped, 7543, 7546),
+    ('\u{F9E9}', Status::Mapped, 3580, 3583),
+    ('\u{F9EA}', Status::Mapped, 7546, 7549),
+    ('\u{F9EB}', Status::Mapped, 7549, 7552),
+    ('\u{F9EC}', Status::Mapped, 7552, 7555),
+    ('\u{F9ED}', Status::Mapped, 7555, 7558),
+    ('\u{F9EE}', Status::Mapped, 7558, 7561),
+    ('\u{F9EF}', Status::Mapped, 7561, 7564),
+    ('\u{F9F0}', Status::Mapped, 7564, 7567),
+    ('\u{F9F1}', Status::Mapped, 7567, 7570),
+    ('\u{F9F2}', Status::Mapped, 7570, 7573),
+    ('\u{F9F3}', Status::Mapped, 7573, 7576),
+    ('\u{F9F4}', Status::Mapped, 7576, 7579),
+    ('\u{F9F5}', Status::Mapped, 7579, 7582),
+    ('\u{F9F6}', Status::Mapped, 7582, 7585),
+    ('\u{F9F7}', Status::Mapped, 3433, 3436),
+    ('\u{F9F8}', Status::Mapped, 7585, 7588),
+    ('\u{F9F9}', Status::Mapped, 7588, 7591),
+    ('\u{F9FA}', Status::Mapped, 7591, 7594),
+    ('\u{F9FB}', Status::Mapped, 7594, 7597),
+    ('\u{F9FC}', Status::Mapped, 7597, 7600),
+    ('\u{F9FD}', Status::Mapped, 7600, 7603),
+    ('\u{F9FE}', Status::Mapped, 7603, 7606),
+    ('\u{F9FF}', Status::Mapped, 7606, 7609),
+    ('\u{FA00}', Status::Mapped, 7609, 7612),
+    ('\u{FA01}', Status::Mapped, 7612, 7615),
+    ('\u{FA02}', Status::Mapped, 7615, 7618),
+    ('\u{FA03}', Status::Mapped, 7618, 7621),
+    ('\u{FA04}', Status::Mapped, 7621, 7624),
+    ('\u{FA05}', Status::Mapped, 7624, 7627),
+    ('\u{FA06}', Status::Mapped, 7627, 7630),
+    ('\u{FA07}', Status::Mapped, 7630, 7633),
+    ('\u{FA08}', Status::Mapped, 3514, 3517),
+    ('\u{FA09}', Status::Mapped, 7633, 7636),
+    ('\u{FA0A}', Status::Mapped, 3523, 3526),
+    ('\u{FA0B}', Status::Mapped, 7636, 7639),
+    ('\u{FA0C}', Status::Mapped, 7639, 7642),
+    ('\u{FA0D}', Status::Mapped, 7642, 7645),
+    ('\u{FA0E}', Status::Valid, 0, 0),
+    ('\u{FA10}', Status::Mapped, 7645, 7648),
+    ('\u{FA11}', Status::Valid, 0, 0),
+    ('\u{FA12}', Status::Mapped, 7648, 7651),
+    ('\u{FA13}', Status::Valid, 0, 0),
+    ('\u{FA15}', Status::Mapped, 7651, 7654),
+    ('\u{FA16}', Status::Mapped, 7654, 7657),
+    ('\u{FA17}', Status::Mapped, 7657, 7660),
+    ('\u{FA18}', Status::Mapped, 7660, 7663),
+    ('\u{FA19}', Status::Mapped, 7663, 7666),
+    ('\u{FA1A}', Status::Mapped, 7666, 7669),
+    ('\u{FA1B}', Status::Mapped, 7669, 7672),
+    ('\u{FA1C}', Status::Mapped, 7672, 7675),
+    ('\u{FA1D}', Status::Mapped, 7675, 7678),
+    ('\u{FA1E}', Status::Mapped, 3454, 3457),
+    ('\u{FA1F}', Status::Valid, 0, 0),
+    ('\u{FA20}', Status::Mapped, 7678, 7681),
+    ('\u{FA21}', Status::Valid, 0, 0),
+    ('\u{FA22}', Status::Mapped, 7681, 7684),
+    ('\u{FA23}', Status::Valid, 0, 0),
+    ('\u{FA25}', Status::Mapped, 7684, 7687),
+    ('\u{FA26}', Status::Mapped, 7687, 7690),
+    ('\u{FA27}', Status::Valid, 0, 0),
+    ('\u{FA2A}', Status::Mapped, 7690, 7693),
+    ('\u{FA2B}', Status::Mapped, 7693, 7696),
+    ('\u{FA2C}', Status::Mapped, 7696, 7699),
+    ('\u{FA2D}', Status::Mapped, 7699, 7702),
+    ('\u{FA2E}', Status::Mapped, 7702, 7705),
+    ('\u{FA2F}', Status::Mapped, 7705, 7708),
+    ('\u{FA30}', Status::Mapped, 7708, 7711),
+    ('\u{FA31}', Status::Mapped, 7711, 7714),
+    ('\u{FA32}', Status::Mapped, 7714, 7717),
+    ('\u{FA33}', Status::Mapped, 7717, 7720),
+    ('\u{FA34}', Status::Mapped, 7720, 7723),
+    ('\u{FA35}', Status::Mapped, 7723, 7726),
+    ('\u{FA36}', Status::Mapped, 7726, 7729),
+    ('\u{FA37}', Status::Mapped, 7729, 7732),
+    ('\u{FA38}', Status::Mapped, 7732, 7735),
+    ('\u{FA39}', Status::Mapped, 7735, 7738),
+    ('\u{FA3A}', Status::Mapped, 7738, 7741),
+    ('\u{FA3B}', Status::Mapped, 7741, 7744),
+    ('\u{FA3C}', Status::Mapped, 3217, 3220),
+    ('\u{FA3D}', Status::Mapped, 7744, 7747),
+    ('\u{FA3E}', Status::Mapped, 7747, 7750),
+    ('\u{FA3F}', Status::Mapped, 7750, 7753),
+    ('\u{FA40}', Status::Mapped, 7753, 7756),
+    ('\u{FA41}', Status::Mapped, 7756, 7759),
+    ('\u{FA42}', Status::Mapped, 7759, 7762),
+    ('\u{FA43}', Status::Mapped, 7762, 7765),
+    ('\u{FA44}', Status::Mapped, 7765, 7768),
+    ('\u{FA45}', Status::Mapped, 7768, 7771),
+    ('\u{FA46}', Status::Mapped, 7771, 7774),
+    ('\u{FA47}', Status::Mapped, 7774, 7777),
+    ('\u{FA48}', Status::Mapped, 7777, 7780),
+    ('\u{FA49}', Status::Mapped, 7780, 7783),
+    ('\u{FA4A}', Status::Mapped, 7783, 7786),
+    ('\u{FA4B}', Status::Mapped, 7786, 7789),
+    ('\u{FA4C}', Status::Mapped, 4524, 4527),
+    ('\u{FA4D}', Status::Mapped, 7789, 7792),
+    ('\u{FA4E}', Status::Mapped, 7792, 7795),
+    ('\u{FA4F}', Status::Mapped, 7795, 7798),
+    ('\u{FA50}', Status::Mapped, 7798, 7801),
+    ('\u{FA51}', Status::Mapped, 4536, 4539),
+    ('\u{FA52}', Status::Mapped, 7801, 7804),
+    ('\u{FA53}', Status::Mapped, 7804, 7807),
+    ('\u{FA54}', Status::Mapped, 7807, 7810),
+    ('\u{FA55}', Status::Mapped, 7810, 7813),
+    ('\u{FA56}', Status::Mapped, 7813, 7816),
+    ('\u{FA57}', Status::Mapped, 7315, 7318),
+    ('\u{FA58}', Status::Mapped, 7816, 7819),
+    ('\u{FA59}', Status::Mapped, 7819, 7822),
+    ('\u{FA5A}', Status::Mapped, 7822, 7825),
+    ('\u{FA5B}', Status::Mapped, 7825, 7828),
+    ('\u{FA5C}', Status::Mapped, 7828, 7831),
+    ('\u{FA5D}', Status::Mapped, 7831, 7834),
+    ('\u{FA5F}', Status::Mapped, 7834, 7837),
+    ('\u{FA60}', Status::Mapped, 7837, 7840),
+    ('\u{FA61}', Status::Mapped, 7840, 7843),
+    ('\u{FA62}', Status::Mapped, 7843, 7846),
+    ('\u{FA63}', Status::Mapped, 7846, 7849),
+    ('\u{FA64}', Status::Mapped, 7849, 7852),
+    ('\u{FA65}', Status::Mapped, 7852, 7855),
+    ('\u{FA66}', Status::Mapped, 7855, 7858),
+    ('\u{FA67}', Status::Mapped, 7684, 7687),
+    ('\u{FA68}', Status::Mapped, 7858, 7861),
+    ('\u{FA69}', Status::Mapped, 7861, 7864),
+    ('\u{FA6A}', Status::Mapped, 7864, 7867),
+    ('\u{FA6B}', Status::Mapped, 7867, 7870),
+    ('\u{FA6C}', Status::Mapped, 7870, 7874),
+    ('\u{FA6D}', Status::Mapped, 7874, 7877),
+    ('\u{FA6E}', Status::Disallowed, 0, 0),
+    ('\u{FA70}', Status::Mapped, 7877, 7880),
+    ('\u{FA71}', Status::Mapped, 7880, 7883),
+    ('\u{FA72}', Status::Mapped, 7883, 7886),
+    ('\u{FA73}', Status::Mapped, 7886, 7889),
+    ('\u{FA74}', Status::Mapped, 7889, 7892),
+    ('\u{FA75}', Status::Mapped, 7892, 7895),
+    ('\u{FA76}', Status::Mapped, 7895, 7898),
+    ('\u{FA77}', Status::Mapped, 7898, 7901),
+    ('\u{FA78}', Status::Mapped, 7726, 7729),
+    ('\u{FA79}', Status::Mapped, 7901, 7904),
+    ('\u{FA7A}', Status::Mapped, 7904, 7907),
+    ('\u{FA7B}', Status::Mapped, 7907, 7910),
+    ('\u{FA7C}', Status::Mapped, 7645, 7648),
+    ('\u{FA7D}', Status::Mapped, 7910, 7913),
+    ('\u{FA7E}', Status::Mapped, 7913, 7916),
+    ('\u{FA7F}', Status::Mapped, 7916, 7919),
+    ('\u{FA80}', Status::Mapped, 7919, 7922),
+    ('\u{FA81}', Status::Mapped, 7922, 7925),
+    ('\u{FA82}', Status::Mapped, 7925, 7928),
+    ('\u{FA83}', Status::Mapped, 7928, 7931),
+    ('\u{FA84}', Status::Mapped, 7931, 7934),
+    ('\u{FA85}', Status::Mapped, 7934, 7937),
+    ('\u{FA86}', Status::Mapped, 7937, 7940),
+    ('\u{FA87}', Status::Mapped, 7940, 7943),
+    ('\u{FA88}', Status::Mapped, 7943, 7946),
+    ('\u{FA89}', Status::Mapped, 7750, 7753),
+    ('\u{FA8A}', Status::Mapped, 7946, 7949),
+    ('\u{FA8B}', Status::Mapped, 7753, 7756),
+    ('\u{FA8C}', Status::Mapped, 7949, 7952),
+    ('\u{FA8D}', Status::Mapped, 7952, 7955),
+    ('\u{FA8E}', Status::Mapped, 7955, 7958),
+    ('\u{FA8F}', Status::Mapped, 7958, 7961),
+    ('\u{FA90}', Status::Mapped, 7961, 7964),
+    ('\u{FA91}', Status::Mapped, 7648, 7651),
+    ('\u{FA92}', Status::Mapped, 7006, 7009),
+    ('\u{FA93}', Status::Mapped, 7964, 7967),
+    ('\u{FA94}', Status::Mapped, 7967, 7970),
+    ('\u{FA95}', Status::Mapped, 3316, 3319),
+    ('\u{FA96}', Status::Mapped, 7210, 7213),
+    ('\u{FA97}', Status::Mapped, 7459, 7462),
+    ('\u{FA98}', Status::Mapped, 7970, 7973),
+    ('\u{FA99}', Status::Mapped, 7973, 7976),
+    ('\u{FA9A}', Status::Mapped, 7774, 7777),
+    ('\u{FA9B}', Status::Mapped, 7976, 7979),
+    ('\u{FA9C}', Status::Mapped, 7777, 7780),
+    ('\u{FA9D}', Status::Mapped, 7979, 7982),
+    ('\u{FA9E}', Status::Mapped, 7982, 7985),
+    ('\u{FA9F}', Status::Mapped, 7985, 7988),
+    ('\u{FAA0}', Status::Mapped, 7654, 7657),
+    ('\u{FAA1}', Status::Mapped, 7988, 7991),
+    ('\u{FAA2}', Status::Mapped, 7991, 7994),
+    ('\u{FAA3}', Status::Mapped, 7994, 7997),
+    ('\u{FAA4}', Status::Mapped, 7997, 8000),
+    ('\u{FAA5}', Status::Mapped, 8000, 8003),
+    ('\u{FAA6}', Status::Mapped, 7657, 7660),
+    ('\u{FAA7}', Status::Mapped, 8003, 8006),
+    ('\u{FAA8}', Status::Mapped, 8006, 8009),
+    ('\u{FAA9}', Status::Mapped, 8009, 8012),
+    ('\u{FAAA}', Status::Mapped, 8012, 8015),
+    ('\u{FAAB}', Status::Mapped, 8015, 8018),
+    ('\u{FAAC}', Status::Mapped, 8018, 8021),
+    ('\u{FAAD}', Status::Mapped, 7813, 7816),
+    ('\u{FAAE}', Status::Mapped, 8021, 8024),
+    ('\u{FAAF}', Status::Mapped, 8024, 8027),
+    ('\u{FAB0}', Status::Mapped, 7315, 7318),
+    ('\u{FAB1}', Status::Mapped, 8027, 8030),
+    ('\u{FAB2}', Status::Mapped, 7825, 7828),
+    ('\u{FAB3}', Status::Mapped, 8030, 8033),
+    ('\u{FAB4}', Status::Mapped, 8033, 8036),
+    ('\u{FAB5}', Status::Mapped, 8036, 8039),
+    ('\u{FAB6}', Status::Mapped, 8039, 8042),
+    ('\u{FAB7}', Status::Mapped, 8042, 8045),
+    ('\u{FAB8}', Status::Mapped, 7840, 7843),
+    ('\u{FAB9}', Status::Mapped, 8045, 8048),
+    ('\u{FABA}', Status::Mapped, 7681, 7684),
+    ('\u{FABB}', Status::Mapped, 8048, 8051),
+    ('\u{FABC}', Status::Mapped, 7843, 7846),
+    ('\u{FABD}', Status::Mapped, 7153, 7156),
+    ('\u{FABE}', Status::Mapped, 8051, 8054),
+    ('\u{FABF}', Status::Mapped, 7846, 7849),
+    ('\u{FAC0}', Status::Mapped, 8054, 8057),
+    ('\u{FAC1}', Status::Mapped, 7852, 7855),
+    ('\u{FAC2}', Status::Mapped, 8057, 8060),
+    ('\u{FAC3}', Status::Mapped, 8060, 8063),
+    ('\u{FAC4}', Status::Mapped, 8063, 8066),
+    ('\u{FAC5}', Status::Mapped, 8066, 8069),
+    ('\u{FAC6}', Status::Mapped, 8069, 8072),
+    ('\u{FAC7}', Status::Mapped, 7858, 7861),
+    ('\u{FAC8}', Status::Mapped, 7672, 7675),
+    ('\u{FAC9}', Status::Mapped, 8072, 8075),
+    ('\u{FACA}', Status::Mapped, 7861, 7864),
+    ('\u{FACB}', Status::Mapped, 8075, 8078),
+    ('\u{FACC}', Status::Mapped, 7864, 7867),
+    ('\u{FACD}', Status::Mapped, 8078, 8081),
+    ('\u{FACE}', Status::Mapped, 3721, 3724),
+    ('\u{FACF}', Status::Mapped, 8081, 8085),
+    ('\u{FAD0}', Status::Mapped, 8085, 8089),
+    ('\u{FAD1}', Status::Mapped, 8089, 8093),
+    ('\u{FAD2}', Status::Mapped, 8093, 8096),
+    ('\u{FAD3}', Status::Mapped, 8096, 8099),
+    ('\u{FAD4}', Status::Mapped, 8099, 8102),
+    ('\u{FAD5}', Status::Mapped, 8102, 8106),
+    ('\u{FAD6}', Status::Mapped, 8106, 8110),
+    ('\u{FAD7}', Status::Mapped, 8110, 8114),
+    ('\u{FAD8}', Status::Mapped, 8114, 8117),
+    ('\u{FAD9}', Status::Mapped, 8117, 8120),
+    ('\u{FADA}', Status::Disallowed, 0, 0),
+    ('\u{FB00}', Status::Mapped, 8120, 8122),
+    ('\u{FB01}', Status::Mapped, 8122, 8124),
+    ('\u{FB02}', Status::Mapped, 8124, 8126),
+    ('\u{FB03}', Status::Mapped, 8126, 8129),
+    ('\u{FB04}', Status::Mapped, 8129, 8132),
+    ('\u{FB05}', Status::Mapped, 8132, 8134),
+    ('\u{FB07}', Status::Disallowed, 0, 0),
+    ('\u{FB13}', Status::Mapped, 8134, 8138),
+    ('\u{FB14}', Status::Mapped, 8138, 8142),
+    ('\u{FB15}', Status::Mapped, 8142, 8146),
+    ('\u{FB16}', Status::Mapped, 8146, 8150),
+    ('\u{FB17}', Status::Mapped, 8150, 8154),
+    ('\u{FB18}', Status::Disallowed, 0, 0),
+    ('\u{FB1D}', Status::Mapped, 8154, 8158),
+    ('\u{FB1E}', Status::Valid, 0, 0),
+    ('\u{FB1F}', Status::Mapped, 8158, 8162),
+    ('\u{FB20}', Status::Mapped, 8162, 8164),
+    ('\u{FB21}', Status::Mapped, 2390, 2392),
+    ('\u{FB22}', Status::Mapped, 2396, 2398),
+    ('\u{FB23}', Status::Mapped, 8164, 8166),
+    ('\u{FB24}', Status::Mapped, 8166, 8168),
+    ('\u{FB25}', Status::Mapped, 8168, 8170),
+    ('\u{FB26}', Status::Mapped, 8170, 8172),
+    ('\u{FB27}', Status::Mapped, 8172, 8174),
+    ('\u{FB28}', Status::Mapped, 8174, 8176),
+    ('\u{FB29}', Status::Mapped, 2354, 2355),
+    ('\u{FB2A}', Status::Mapped, 8176, 8180),
+    ('\u{FB2B}', Status::Mapped, 8180, 8184),
+    ('\u{FB2C}', Status::Mapped, 8184, 8190),
+    ('\u{FB2D}', Status::Mapped, 8190, 8196),
+    ('\u{FB2E}', Status::Mapped, 8196, 8200),
+    ('\u{FB2F}', Status::Mapped, 8200, 8204),
+    ('\u{FB30}', Status::Mapped, 8204, 8208),
+    ('\u{FB31}', Status::Mapped, 8208, 8212),
+    ('\u{FB32}', Status::Mapped, 8212, 8216),
+    ('\u{FB33}', Status::Mapped, 8216, 8220),
+    ('\u{FB34}', Status::Mapped, 8220, 8224),
+    ('\u{FB35}', Status::Mapped, 8224, 8228),
+    ('\u{FB36}', Status::Mapped, 8228, 8232),
+    ('\u{FB37}', Status::Disallowed, 0, 0),
+    ('\u{FB38}', Status::Mapped, 8232, 8236),
+    ('\u{FB39}', Status::Mapped, 8236, 8240),
+    ('\u{FB3A}', Status::Mapped, 8240, 8244),
+    ('\u{FB3B}', Status::Mapped, 8244, 8248),
+    ('\u{FB3C}', Status::Mapped, 8248, 8252),
+    ('\u{FB3D}', Status::Disallowed, 0, 0),
+    ('\u{FB3E}', Status::Mapped, 8252, 8256),
+    ('\u{FB3F}', Status::Disallowed, 0, 0),
+    ('\u{FB40}', Status::Mapped, 8256, 8260),
+    ('\u{FB41}', Status::Mapped, 8260, 8264),
+    ('\u{FB42}', Status::Disallowed, 0, 0),
+    ('\u{FB43}', Status::Mapped, 8264, 8268),
+    ('\u{FB44}', Status::Mapped, 8268, 8272),
+    ('\u{FB45}', Status::Disallowed, 0, 0),
+    ('\u{FB46}', Status::Mapped, 8272, 8276),
+    ('\u{FB47}', Status::Mapped, 8276, 8280),
+    ('\u{FB48}', Status::Mapped, 8280, 8284),
+    ('\u{FB49}', Status::Mapped, 8284, 8288),
+    ('\u{FB4A}', Status::Mapped, 8288, 8292),
+    ('\u{FB4B}', Status::Mapped, 8292, 8296),
+    ('\u{FB4C}', Status::Mapped, 8296, 8300),
+    ('\u{FB4D}', Status::Mapped, 8300, 8304),
+    ('\u{FB4E}', Status::Mapped, 8304, 8308),
+    ('\u{FB4F}', Status::Mapped, 8308, 8312),
+    ('\u{FB50}', Status::Mapped, 8312, 8314),
+    ('\u{FB52}', Status::Mapped, 8314, 8316),
+    ('\u{FB56}', Status::Mapped, 8316, 8318),
+    ('\u{FB5A}', Status::Mapped, 8318, 8320),
+    ('\u{FB5E}', Status::Mapped, 8320, 8322),
+    ('\u{FB62}', Status::Mapped, 8322, 8324),
+    ('\u{FB66}', Status::Mapped, 8324, 8326),
+    ('\u{FB6A}', Status::Mapped, 8326, 8328),
+    ('\u{FB6E}', Status::Mapped, 8328, 8330),
+    ('\u{FB72}', Status::Mapped, 8330, 8332),
+    ('\u{FB76}', Status::Mapped, 8332, 8334),
+    ('\u{FB7A}', Status::Mapped, 8334, 8336),
+    ('\u{FB7E}', Status::Mapped, 8336, 8338),
+    ('\u{FB82}', Status::Mapped, 8338, 8340),
+    ('\u{FB84}', Status::Mapped, 8340, 8342),
+    ('\u{FB86}', Status::Mapped, 8342, 8344),
+    ('\u{FB88}', Status::Mapped, 8344, 8346),
+    ('\u{FB8A}', Status::Mapped, 8346, 8348),
+    ('\u{FB8C}', Status::Mapped, 8348, 8350),
+    ('\u{FB8E}', Status::Mapped, 8350, 8352),
+    ('\u{FB92}', Status::Mapped, 8352, 8354),
+    ('\u{FB96}', Status::Mapped, 8354, 8356),
+    ('\u{FB9A}', Status::Mapped, 8356, 8358),
+    ('\u{FB9E}', Status::Mapped, 8358, 8360),
+    ('\u{FBA0}', Status::Mapped, 8360, 8362),
+    ('\u{FBA4}', Status::Mapped, 8362, 8364),
+    ('\u{FBA6}', Status::Mapped, 8364, 8366),
+    ('\u{FBAA}', Status::Mapped, 8366, 8368),
+    ('\u{FBAE}', Status::Mapped, 8368, 8370),
+    ('\u{FBB0}', Status::Mapped, 8370, 8372),
+    ('\u{FBB2}', Status::Valid, 0, 0),
+    ('\u{FBC3}', Status::Disallowed, 0, 0),
+    ('\u{FBD3}', Status::Mapped, 8372, 8374),
+    ('\u{FBD7}', Status::Mapped, 8374, 8376),
+    ('\u{FBD9}', Status::Mapped, 8376, 8378),
+    ('\u{FBDB}', Status::Mapped, 8378, 8380),
+    ('\u{FBDD}', Status::Mapped, 1000, 1004),
+    ('\u{FBDE}', Status::Mapped, 8380, 8382),
+    ('\u{FBE0}', Status::Mapped, 8382, 8384),
+    ('\u{FBE2}', Status::Mapped, 8384, 8386),
+    ('\u{FBE4}', Status::Mapped, 8386, 8388),
+    ('\u{FBE8}', Status::Mapped, 8388, 8390),
+    ('\u{FBEA}', Status::Mapped, 8390, 8394),
+    ('\u{FBEC}', Status::Mapped, 8394, 8398),
+    ('\u{FBEE}', Status::Mapped, 8398, 8402),
+    ('\u{FBF0}', Status::Mapped, 8402, 8406),
+    ('\u{FBF2}', Status::Mapped, 8406, 8410),
+    ('\u{FBF4}', Status::Mapped, 8410, 8414),
+    ('\u{FBF6}', Status::Mapped, 8414, 8418),
+    ('\u{FBF9}', Status::Mapped, 8418, 8422),
+    ('\u{FBFC}', Status::Mapped, 8422, 8424),
+    ('\u{FC00}', Status::Mapped, 8424, 8428),
+    ('\u{FC01}', Status::Mapped, 8428, 8432),
+    ('\u{FC02}', Status::Mapped, 8432, 8436),
+    ('\u{FC03}', Status::Mapped, 8418, 8422),
+    ('\u{FC04}', Status::Mapped, 8436, 8440),
+    ('\u{FC05}', Status::Mapped, 8440, 8444),
+    ('\u{FC06}', Status::Mapped, 8444, 8448),
+    ('\u{FC07}', Status::Mapped, 8448, 8452),
+    ('\u{FC08}', Status::Mapped, 8452, 8456),
+    ('\u{FC09}', Status::Mapped, 8456, 8460),
+    ('\u{FC0A}', Status::Mapped, 8460, 8464),
+    ('\u{FC0B}', Status::Mapped, 8464, 8468),
+    ('\u{FC0C}', Status::Mapped, 8468, 8472),
+    ('\u{FC0D}', Status::Mapped, 8472, 8476),
+    ('\u{FC0E}', Status::Mapped, 8476, 8480),
+    ('\u{FC0F}', Status::Mapped, 8480, 8484),
+    ('\u{FC10}', Status::Mapped, 8484, 8488),
+    ('\u{FC11}', Status::Mapped, 8488, 8492),
+    ('\u{FC12}', Status::Mapped, 8492, 8496),
+    ('\u{FC13}', Status::Mapped, 8496, 8500),
+    ('\u{FC14}', Status::Mapped, 8500, 8504),
+    ('\u{FC15}', Status::Mapped, 8504, 8508),
+    ('\u{FC16}', Status::Mapped, 8508, 8512),
+    ('\u{FC17}', Status::Mapped, 8512, 8516),
+    ('\u{FC18}', Status::Mapped, 8516, 8520),
+    ('\u{FC19}', Status::Mapped, 8520, 8524),
+    ('\u{FC1A}', Status::Mapped, 8524, 8528),
+    ('\u{FC1B}', Status::Mapped, 8528, 8532),
+    ('\u{FC1C}', Status::Mapped, 8532, 8536),
+    ('\u{FC1D}', Status::Mapped, 8536, 8540),
+    ('\u{FC1E}', Status::Mapped, 8540, 8544),
+    ('\u{FC1F}', Status::Mapped, 8544, 8548),
+    ('\u{FC20}', Status::Mapped, 8548, 8552),
+    ('\u{FC21}', Status::Mapped, 8552, 8556),
+    ('\u{FC22}', Status::Mapped, 8556, 8560),
+    ('\u{FC23}', Status::Mapped, 8560, 8564),
+    ('\u{FC24}', Status::Mapped, 8564, 8568),
+    ('\u{FC25}', Status::Mapped, 8568, 8572),
+    ('\u{FC26}', Status::Mapped, 8572, 8576),
+    ('\u{FC27}', Status::Mapped, 8576, 8580),
+    ('\u{FC28}', Status::Mapped, 8580, 8584),
+    ('\u{FC29}', Status::Mapped, 8584, 8588),
+    ('\u{FC2A}', Status::Mapped, 8588, 8592),
+    ('\u{FC2B}', Status::Mapped, 8592, 8596),
+    ('\u{FC2C}', Status::Mapped, 8596, 8600),
+    ('\u{FC2D}', Status::Mapped, 8600, 8604),
+    ('\u{FC2E}', Status::Mapped, 8604, 8608),
+    ('\u{FC2F}', Status::Mapped, 8608, 8612),
+    ('\u{FC30}', Status::Mapped, 8612, 8616),
+    ('\u{FC31}', Status::Mapped, 8616, 8620),
+    ('\u{FC32}', Status::Mapped, 8620, 8624),
+    ('\u{FC33}', Status::Mapped, 8624, 8628),
+    ('\u{FC34}', Status::Mapped, 8628, 8632),
+    ('\u{FC35}', Status::Mapped, 8632, 8636),
+    ('\u{FC36}', Status::Mapped, 8636, 8640),
+    ('\u{FC37}', Status::Mapped, 8640, 8644),
+    ('\u{FC38}', Status::Mapped, 8644, 8648),
+    ('\u{FC39}', Status::Mapped, 8648, 8652),
+    ('\u{FC3A}', Status::Mapped, 8652, 8656),
+    ('\u{FC3B}', Status::Mapped, 8656, 8660),
+    ('\u{FC3C}', Status::Mapped, 8660, 8664),
+    ('\u{FC3D}', Status::Mapped, 8664, 8668),
+    ('\u{FC3E}', Status::Mapped, 8668, 8672),
+    ('\u{FC3F}', Status::Mapped, 8672, 8676),
+    ('\u{FC40}', Status::Mapped, 8676, 8680),
+    ('\u{FC41}', Status::Mapped, 8680, 8684),
+    ('\u{FC42}', Status::Mapped, 8684, 8688),
+    ('\u{FC43}', Status::Mapped, 8688, 8692),
+    ('\u{FC44}', Status::Mapped, 8692, 8696),
+    ('\u{FC45}', Status::Mapped, 8696, 8700),
+    ('\u{FC46}', Status::Mapped, 8700, 8704),
+    ('\u{FC47}', Status::Mapped, 8704, 8708),
+    ('\u{FC48}', Status::Mapped, 8708, 8712),
+    ('\u{FC49}', Status::Mapped, 8712, 8716),
+    ('\u{FC4A}', Status::Mapped, 8716, 8720),
+    ('\u{FC4B}', Status::Mapped, 8720, 8724),
+    ('\u{FC4C}', Status::Mapped, 8724, 8728),
+    ('\u{FC4D}', Status::Mapped, 8728, 8732),
+    ('\u{FC4E}', Status::Mapped, 8732, 8736),
+    ('\u{FC4F}', Status::Mapped, 8736, 8740),
+    ('\u{FC50}', Status::Mapped, 8740, 8744),
+    ('\u{FC51}', Status::Mapped, 8744, 8748),
+    ('\u{FC52}', Status::Mapped, 8748, 8752),
+    ('\u{FC53}', Status::Mapped, 8752, 8756),
+    ('\u{FC54}', Status::Mapped, 8756, 8760),
+    ('\u{FC55}', Status::Mapped, 8760, 8764),
+    ('\u{FC56}', Status::Mapped, 8764, 8768),
+    ('\u{FC57}', Status::Mapped, 8768, 8772),
+    ('\u{FC58}', Status::Mapped, 8772, 8776),
+    ('\u{FC59}', Status::Mapped, 8776, 8780),
+    ('\u{FC5A}', Status::Mapped, 8780, 8784),
+    ('\u{FC5B}', Status::Mapped, 8784, 8788),
+    ('\u{FC5C}', Status::Mapped, 8788, 8792),
+    ('\u{FC5D}', Status::Mapped, 8792, 8796),
+    ('\u{FC5E}', Status::Mapped, 8796, 8801),
+    ('\u{FC5F}', Status::Mapped, 8801, 8806),
+    ('\u{FC60}', Status::Mapped, 8806, 8811),
+    ('\u{FC61}', Status::Mapped, 8811, 8816),
+    ('\u{FC62}', Status::Mapped, 8816, 8821),
+    ('\u{FC63}', Status::Mapped, 8821, 8826),
+    ('\u{FC64}', Status::Mapped, 8826, 8830),
+    ('\u{FC65}', Status::Mapped, 8830, 8834),
+    ('\u{FC66}', Status::Mapped, 8432, 8436),
+    ('\u{FC67}', Status::Mapped, 8834, 8838),
+    ('\u{FC68}', Status::Mapped, 8418, 8422),
+    ('\u{FC69}', Status::Mapped, 8436, 8440),
+    ('\u{FC6A}', Status::Mapped, 8838, 8842),
+    ('\u{FC6B}', Status::Mapped, 8842, 8846),
+    ('\u{FC6C}', Status::Mapped, 8452, 8456),
+    ('\u{FC6D}', Status::Mapped, 8846, 8850),
+    ('\u{FC6E}', Status::Mapped, 8456, 8460),
+    ('\u{FC6F}', Status::Mapped, 8460, 8464),
+    ('\u{FC70}', Status::Mapped, 8850, 8854),
+    ('\u{FC71}', Status::Mapped, 8854, 8858),
+    ('\u{FC72}', Status::Mapped, 8476, 8480),
+    ('\u{FC73}', Status::Mapped, 8858, 8862),
+    ('\u{FC74}', Status::Mapped, 8480, 8484),
+    ('\u{FC75}', Status::Mapped, 8484, 8488),
+    ('\u{FC76}', Status::Mapped, 8862, 8866),
+    ('\u{FC77}', Status::Mapped, 8866, 8870),
+    ('\u{FC78}', Status::Mapped, 8492, 8496),
+    ('\u{FC79}', Status::Mapped, 8870, 8874),
+    ('\u{FC7A}', Status::Mapped, 8496, 8500),
+    ('\u{FC7B}', Status::Mapped, 8500, 8504),
+    ('\u{FC7C}', Status::Mapped, 8616, 8620),
+    ('\u{FC7D}', Status::Mapped, 8620, 8624),
+    ('\u{FC7E}', Status::Mapped, 8632, 8636),
+    ('\u{FC7F}', Status::Mapped, 8636, 8640),
+    ('\u{FC80}', Status::Mapped, 8640, 8644),
+    ('\u{FC81}', Status::Mapped, 8656, 8660),
+    ('\u{FC82}', Status::Mapped, 8660, 8664),
+    ('\u{FC83}', Status::Mapped, 8664, 8668),
+    ('\u{FC84}', Status::Mapped, 8668, 8672),
+    ('\u{FC85}', Status::Mapped, 8684, 8688),
+    ('\u{FC86}', Status::Mapped, 8688, 8692),
+    ('\u{FC87}', Status::Mapped, 8692, 8696),
+    ('\u{FC88}', Status::Mapped, 8874, 8878),
+    ('\u{FC89}', Status::Mapped, 8708, 8712),
+    ('\u{FC8A}', Status::Mapped, 8878, 8882),
+    ('\u{FC8B}', Status::Mapped, 8882, 8886),
+    ('\u{FC8C}', Status::Mapped, 8732, 8736),
+    ('\u{FC8D}', Status::Mapped, 8886, 8890),
+    ('\u{FC8E}', Status::Mapped, 8736, 8740),
+    ('\u{FC8F}', Status::Mapped, 8740, 8744),
+    ('\u{FC90}', Status::Mapped, 8792, 8796),
+    ('\u{FC91}', Status::Mapped, 8890, 8894),
+    ('\u{FC92}', Status::Mapped, 8894, 8898),
+    ('\u{FC93}', Status::Mapped, 8772, 8776),
+    ('\u{FC94}', Status::Mapped, 8898, 8902),
+    ('\u{FC95}', Status::Mapped, 8776, 8780),
+    ('\u{FC96}', Status::Mapped, 8780, 8784),
+    ('\u{FC97}', Status::Mapped, 8424, 8428),
+    ('\u{FC98}', Status::Mapped, 8428, 8432),
+    ('\u{FC99}', Status::Mapped, 8902, 8906),
+    ('\u{FC9A}', Status::Mapped, 8432, 8436),
+    ('\u{FC9B}', Status::Mapped, 8906, 8910),
+    ('\u{FC9C}', Status::Mapped, 8440, 8444),
+    ('\u{FC9D}', Status::Mapped, 8444, 8448),
+    ('\u{FC9E}', Status::Mapped, 8448, 8452),
+    ('\u{FC9F}', Status::Mapped, 8452, 8456),
+    ('\u{FCA0}', Status::Mapped, 8910, 8914),
+    ('\u{FCA1}', Status::Mapped, 8464, 8468),
+    ('\u{FCA2}', Status::Mapped, 8468, 8472),
+    ('\u{FCA3}', Status::Mapped, 8472, 8476),
+    ('\u{FCA4}', Status::Mapped, 8476, 8480),
+    ('\u{FCA5}', Status::Mapped, 8914, 8918),
+    ('\u{FCA6}', Status::Mapped, 8492, 8496),
+    ('\u{FCA7}', Status::Mapped, 8504, 8508),
+    ('\u{FCA8}', Status::Mapped, 8508, 8512),
+    ('\u{FCA9}', Status::Mapped, 8512, 8516),
+    ('\u{FCAA}', Status::Mapped, 8516, 8520),
+    ('\u{FCAB}', Status::Mapped, 8520, 8524),
+    ('\u{FCAC}', Status::Mapped, 8528, 8532),
+    ('\u{FCAD}', Status::Mapped, 8532, 8536),
+    ('\u{FCAE}', Status::Mapped, 8536, 8540),
+    ('\u{FCAF}', Status::Mapped, 8540, 8544),
+    ('\u{FCB0}', Status::Mapped, 8544, 8548),
+    ('\u{FCB1}', Status::Mapped, 8548, 8552),
+    ('\u{FCB2}', Status::Mapped, 8918, 8922),
+    ('\u{FCB3}', Status::Mapped, 8552, 8556),
+    ('\u{FCB4}', Status::Mapped, 8556, 8560),
+    ('\u{FCB5}', Status::Mapped, 8560, 8564),
+    ('\u{FCB6}', Status::Mapped, 8564, 8568),
+    ('\u{FCB7}', Status::Mapped, 8568, 8572),
+    ('\u{FCB8}', Status::Mapped, 8572, 8576),
+    ('\u{FCB9}', Status::Mapped, 8580, 8584),
+    ('\u{FCBA}', Status::Mapped, 8584, 8588),
+    ('\u{FCBB}', Status::Mapped, 8588, 8592),
+    ('\u{FCBC}', Status::Mapped, 8592, 8596),
+    ('\u{FCBD}', Status::Mapped, 8596, 8600),
+    ('\u{FCBE}', Status::Mapped, 8600, 8604),
+    ('\u{FCBF}', Status::Mapped, 8604, 8608),
+    ('\u{FCC0}', Status::Mapped, 8608, 8612),
+    ('\u{FCC1}', Status::Mapped, 8612, 8616),
+    ('\u{FCC2}', Status::Mapped, 8624, 8628),
+    ('\u{FCC3}', Status::Mapped, 8628, 8632),
+    ('\u{FCC4}', Status::Mapped, 8644, 8648),
+    ('\u{FCC5}', Status::Mapped, 8648, 8652),
+    ('\u{FCC6}', Status::Mapped, 8652, 8656),
+    ('\u{FCC7}', Status::Mapped, 8656, 8660),
+    ('\u{FCC8}', Status::Mapped, 8660, 8664),
+    ('\u{FCC9}', Status::Mapped, 8672, 8676),
+    ('\u{FCCA}', Status::Mapped, 8676, 8680),
+    ('\u{FCCB}', Status::Mapped, 8680, 8684),
+    ('\u{FCCC}', Status::Mapped, 8684, 8688),
+    ('\u{FCCD}', Status::Mapped, 8922, 8926),
+    ('\u{FCCE}', Status::Mapped, 8696, 8700),
+    ('\u{FCCF}', Status::Mapped, 8700, 8704),
+    ('\u{FCD0}', Status::Mapped, 8704, 8708),
+    ('\u{FCD1}', Status::Mapped, 8708, 8712),
+    ('\u{FCD2}', Status::Mapped, 8720, 8724),
+    ('\u{FCD3}', Status::Mapped, 8724, 8728),
+    ('\u{FCD4}', Status::Mapped, 8728, 8732),
+    ('\u{FCD5}', Status::Mapped, 8732, 8736),
+    ('\u{FCD6}', Status::Mapped, 8926, 8930),
+    ('\u{FCD7}', Status::Mapped, 8744, 8748),
+    ('\u{FCD8}', Status::Mapped, 8748, 8752),
+    ('\u{FCD9}', Status::Mapped, 8930, 8934),
+    ('\u{FCDA}', Status::Mapped, 8760, 8764),
+    ('\u{FCDB}', Status::Mapped, 8764, 8768),
+    ('\u{FCDC}', Status::Mapped, 8768, 8772),
+    ('\u{FCDD}', Status::Mapped, 8772, 8776),
+    ('\u{FCDE}', Status::Mapped, 8934, 8938),
+    ('\u{FCDF}', Status::Mapped, 8432, 8436),
+    ('\u{FCE0}', Status::Mapped, 8906, 8910),
+    ('\u{FCE1}', Status::Mapped, 8452, 8456),
+    ('\u{FCE2}', Status::Mapped, 8910, 8914),
+    ('\u{FCE3}', Status::Mapped, 8476, 8480),
+    ('\u{FCE4}', Status::Mapped, 8914, 8918),
+    ('\u{FCE5}', Status::Mapped, 8492, 8496),
+    ('\u{FCE6}', Status::Mapped, 8938, 8942),
+    ('\u{FCE7}', Status::Mapped, 8544, 8548),
+    ('\u{FCE8}', Status::Mapped, 8942, 8946),
+    ('\u{FCE9}', Status::Mapped, 8946, 8950),
+    ('\u{FCEA}', Status::Mapped, 8950, 8954),
+    ('\u{FCEB}', Status::Mapped, 8656, 8660),
+    ('\u{FCEC}', Status::Mapped, 8660, 8664),
+    ('\u{FCED}', Status::Mapped, 8684, 8688),
+    ('\u{FCEE}', Status::Mapped, 8732, 8736),
+    ('\u{FCEF}', Status::Mapped, 8926, 8930),
+    ('\u{FCF0}', Status::Mapped, 8772, 8776),
+    ('\u{FCF1}', Status::Mapped, 8934, 8938),
+    ('\u{FCF2}', Status::Mapped, 8954, 8960),
+    ('\u{FCF3}', Status::Mapped, 8960, 8966),
+    ('\u{FCF4}', Status::Mapped, 8966, 8972),
+    ('\u{FCF5}', Status::Mapped, 8972, 8976),
+    ('\u{FCF6}', Status::Mapped, 8976, 8980),
+    ('\u{FCF7}', Status::Mapped, 8980, 8984),
+    ('\u{FCF8}', Status::Mapped, 8984, 8988),
+    ('\u{FCF9}', Status::Mapped, 8988, 8992),
+    ('\u{FCFA}', Status::Mapped, 8992, 8996),
+    ('\u{FCFB}', Status::Mapped, 8996, 9000),
+    ('\u{FCFC}', Status::Mapped, 9000, 9004),
+    ('\u{FCFD}', Status::Mapped, 9004, 9008),
+    ('\u{FCFE}', Status::Mapped, 9008, 9012),
+    ('\u{FCFF}', Status::Mapped, 9012, 9016),
+    ('\u{FD00}', Status::Mapped, 9016, 9020),
+    ('\u{FD01}', Status::Mapped, 9020, 9024),
+    ('\u{FD02}', Status::Mapped, 9024, 9028),
+    ('\u{FD03}', Status::Mapped, 9028, 9032),
+    ('\u{FD04}', Status::Mapped, 9032, 9036),
+    ('\u{FD05}', Status::Mapped, 9036, 9040),
+    ('\u{FD06}', Status::Mapped, 9040, 9044),
+    ('\u{FD07}', Status::Mapped, 9044, 9048),
+    ('\u{FD08}', Status::Mapped, 9048, 9052),
+    ('\u{FD09}', Status::Mapped, 9052, 9056),
+    ('\u{FD0A}', Status::Mapped, 9056, 9060),
+    ('\u{FD0B}', Status::Mapped, 9060, 9064),
+    ('\u{FD0C}', Status::Mapped, 8946, 8950),
+    ('\u{FD0D}', Status::Mapped, 9064, 9068),
+    ('\u{FD0E}', Status::Mapped, 9068, 9072),
+    ('\u{FD0F}', Status::Mapped, 9072, 9076),
+    ('\u{FD10}', Status::Mapped, 9076, 9080),
+    ('\u{FD11}', Status::Mapped, 8972, 8976),
+    ('\u{FD12}', Status::Mapped, 8976, 8980),
+    ('\u{FD13}', Status::Mapped, 8980, 8984),
+    ('\u{FD14}', Status::Mapped, 8984, 8988),
+    ('\u{FD15}', Status::Mapped, 8988, 8992),
+    ('\u{FD16}', Status::Mapped, 8992, 8996),
+    ('\u{FD17}', Status::Mapped, 8996, 9000),
+    ('\u{FD18}', Status::Mapped, 9000, 9004),
+    ('\u{FD19}', Status::Mapped, 9004, 9008),
+    ('\u{FD1A}', Status::Mapped, 9008, 9012),
+    ('\u{FD1B}', Status::Mapped, 9012, 9016),
+    ('\u{FD1C}', Status::Mapped, 9016, 9020),
+    ('\u{FD1D}', Status::Mapped, 9020, 9024),
+    ('\u{FD1E}', Status::Mapped, 9024, 9028),
+    ('\u{FD1F}', Status::Mapped, 9028, 9032),
+    ('\u{FD20}', Status::Mapped, 9032, 9036),
+    ('\u{FD21}', Status::Mapped, 9036, 9040),
+    ('\u{FD22}', Status::Mapped, 9040, 9044),
+    ('\u{FD23}', Status::Mapped, 9044, 9048),
+    ('\u{FD24}', Status::Mapped, 9048, 9052),
+    ('\u{FD25}', Status::Mapped, 9052, 9056),
+    ('\u{FD26}', Status::Mapped, 9056, 9060),
+    ('\u{FD27}', Status::Mapped, 9060, 9064),
+    ('\u{FD28}', Status::Mapped, 8946, 8950),
+    ('\u{FD29}', Status::Mapped, 9064, 9068),
+    ('\u{FD2A}', Status::Mapped, 9068, 9072),
+    ('\u{FD2B}', Status::Mapped, 9072, 9076),
+    ('\u{FD2C}', Status::Mapped, 9076, 9080),
+    ('\u{FD2D}', Status::Mapped, 9052, 9056),
+    ('\u{FD2E}', Status::Mapped, 9056, 9060),
+    ('\u{FD2F}', Status::Mapped, 9060, 9064),
+    ('\u{FD30}', Status::Mapped, 8946, 8950),
+    ('\u{FD31}', Status::Mapped, 8942, 8946),
+    ('\u{FD32}', Status::Mapped, 8950, 8954),
+    ('\u{FD33}', Status::Mapped, 8576, 8580),
+    ('\u{FD34}', Status::Mapped, 8532, 8536),
+    ('\u{FD35}', Status::Mapped, 8536, 8540),
+    ('\u{FD36}', Status::Mapped, 8540, 8544),
+    ('\u{FD37}', Status::Mapped, 9052, 9056),
+    ('\u{FD38}', Status::Mapped, 9056, 9060),
+    ('\u{FD39}', Status::Mapped, 9060, 9064),
+    ('\u{FD3A}', Status::Mapped, 8576, 8580),
+    ('\u{FD3B}', Status::Mapped, 8580, 8584),
+    ('\u{FD3C}', Status::Mapped, 9080, 9084),
+    ('\u{FD3E}', Status::Valid, 0, 0),
+    ('\u{FD50}', Status::Mapped, 9084, 9090),
+    ('\u{FD51}', Status::Mapped, 9090, 9096),
+    ('\u{FD53}', Status::Mapped, 9096, 9102),
+    ('\u{FD54}', Status::Mapped, 9102, 9108),
+    ('\u{FD55}', Status::Mapped, 9108, 9114),
+    ('\u{FD56}', Status::Mapped, 9114, 9120),
+    ('\u{FD57}', Status::Mapped, 9120, 9126),
+    ('\u{FD58}', Status::Mapped, 9126, 9132),
+    ('\u{FD5A}', Status::Mapped, 9132, 9138),
+    ('\u{FD5B}', Status::Mapped, 9138, 9144),
+    ('\u{FD5C}', Status::Mapped, 9144, 9150),
+    ('\u{FD5D}', Status::Mapped, 9150, 9156),
+    ('\u{FD5E}', Status::Mapped, 9156, 9162),
+    ('\u{FD5F}', Status::Mapped, 9162, 9168),
+    ('\u{FD61}', Status::Mapped, 9168, 9174),
+    ('\u{FD62}', Status::Mapped, 9174, 9180),
+    ('\u{FD64}', Status::Mapped, 9180, 9186),
+    ('\u{FD66}', Status::Mapped, 9186, 9192),
+    ('\u{FD67}', Status::Mapped, 9192, 9198),
+    ('\u{FD69}', Status::Mapped, 9198, 9204),
+    ('\u{FD6A}', Status::Mapped, 9204, 9210),
+    ('\u{FD6C}', Status::Mapped, 9210, 9216),
+    ('\u{FD6E}', Status::Mapped, 9216, 9222),
+    ('\u{FD6F}', Status::Mapped, 9222, 9228),
+    ('\u{FD71}', Status::Mapped, 9228, 9234),
+    ('\u{FD73}', Status::Mapped, 9234, 9240),
+    ('\u{FD74}', Status::Mapped, 9240, 9246),
+    ('\u{FD75}', Status::Mapped, 9246, 9252),
+    ('\u{FD76}', Status::Mapped, 9252, 9258),
+    ('\u{FD78}', Status::Mapped, 9258, 9264),
+    ('\u{FD79}', Status::Mapped, 9264, 9270),
+    ('\u{FD7A}', Status::Mapped, 9270, 9276),
+    ('\u{FD7B}', Status::Mapped, 9276, 9282),
+    ('\u{FD7C}', Status::Mapped, 9282, 9288),
+    ('\u{FD7E}', Status::Mapped, 9288, 9294),
+    ('\u{FD7F}', Status::Mapped, 9294, 9300),
+    ('\u{FD80}', Status::Mapped, 9300, 9306),
+    ('\u{FD81}', Status::Mapped, 9306, 9312),
+    ('\u{FD82}', Status::Mapped, 9312, 9318),
+    ('\u{FD83}', Status::Mapped, 9318, 9324),
+    ('\u{FD85}', Status::Mapped, 9324, 9330),
+    ('\u{FD87}', Status::Mapped, 9330, 9336),
+    ('\u{FD89}', Status::Mapped, 9336, 9342),
+    ('\u{FD8A}', Status::Mapped, 9342, 9348),
+    ('\u{FD8B}', Status::Mapped, 9348, 9354),
+    ('\u{FD8C}', Status::Mapped, 9354, 9360),
+    ('\u{FD8D}', Status::Mapped, 9360, 9366),
+    ('\u{FD8E}', Status::Mapped, 9366, 9372),
+    ('\u{FD8F}', Status::Mapped, 9372, 9378),
+    ('\u{FD90}', Status::Disallowed, 0, 0),
+    ('\u{FD92}', Status::Mapped, 9378, 9384),
+    ('\u{FD93}', Status::Mapped, 9384, 9390),
+    ('\u{FD94}', Status::Mapped, 9390, 9396),
+    ('\u{FD95}', Status::Mapped, 9396, 9402),
+    ('\u{FD96}', Status::Mapped, 9402, 9408),
+    ('\u{FD97}', Status::Mapped, 9408, 9414),
+    ('\u{FD99}', Status::Mapped, 9414, 9420),
+    ('\u{FD9A}', Status::Mapped, 9420, 9426),
+    ('\u{FD9B}', Status::Mapped, 9426, 9432),
+    ('\u{FD9C}', Status::Mapped, 9432, 9438),
+    ('\u{FD9E}', Status::Mapped, 9438, 9444),
+    ('\u{FD9F}', Status::Mapped, 9444, 9450),
+    ('\u{FDA0}', Status::Mapped, 9450, 9456),
+    ('\u{FDA1}', Status::Mapped, 9456, 9462),
+    ('\u{FDA2}', Status::Mapped, 9462, 9468),
+    ('\u{FDA3}', Status::Mapped, 9468, 9474),
+    ('\u{FDA4}', Status::Mapped, 9474, 9480),
+    ('\u{FDA5}', Status::Mapped, 9480, 9486),
+    ('\u{FDA6}', Status::Mapped, 9486, 9492),
+    ('\u{FDA7}', Status::Mapped, 9492, 9498),
+    ('\u{FDA8}', Status::Mapped, 9498, 9504),
+    ('\u{FDA9}', Status::Mapped, 9504, 9510),
+    ('\u{FDAA}', Status::Mapped, 9510, 9516),
+    ('\u{FDAB}', Status::Mapped, 9516, 9522),
+    ('\u{FDAC}', Status::Mapped, 9522, 9528),
+    ('\u{FDAD}', Status::Mapped, 9528, 9534),
+    ('\u{FDAE}', Status::Mapped, 9534, 9540),
+    ('\u{FDAF}', Status::Mapped, 9540, 9546),
+    ('\u{FDB0}', Status::Mapped, 9546, 9552),
+    ('\u{FDB1}', Status::Mapped, 9552, 9558),
+    ('\u{FDB2}', Status::Mapped, 9558, 9564),
+    ('\u{FDB3}', Status::Mapped, 9564, 9570),
+    ('\u{FDB4}', Status::Mapped, 9288, 9294),
+    ('\u{FDB5}', Status::Mapped, 9300, 9306),
+    ('\u{FDB6}', Status::Mapped, 9570, 9576),
+    ('\u{FDB7}', Status::Mapped, 9576, 9582),
+    ('\u{FDB8}', Status::Mapped, 9582, 9588),
+    ('\u{FDB9}', Status::Mapped, 9588, 9594),
+    ('\u{FDBA}', Status::Mapped, 9594, 9600),
+    ('\u{FDBB}', Status::Mapped, 9600, 9606),
+    ('\u{FDBC}', Status::Mapped, 9594, 9600),
+    ('\u{FDBD}', Status::Mapped, 9582, 9588),
+    ('\u{FDBE}', Status::Mapped, 9606, 9612),
+    ('\u{FDBF}', Status::Mapped, 9612, 9618),
+    ('\u{FDC0}', Status::Mapped, 9618, 9624),
+    ('\u{FDC1}', Status::Mapped, 9624, 9630),
+    ('\u{FDC2}', Status::Mapped, 9630, 9636),
+    ('\u{FDC3}', Status::Mapped, 9600, 9606),
+    ('\u{FDC4}', Status::Mapped, 9246, 9252),
+    ('\u{FDC5}', Status::Mapped, 9186, 9192),
+    ('\u{FDC6}', Status::Mapped, 9636, 9642),
+    ('\u{FDC7}', Status::Mapped, 9642, 9648),
+    ('\u{FDC8}', Status::Disallowed, 0, 0),
+    ('\u{FDCF}', Status::Valid, 0, 0),
+    ('\u{FDD0}', Status::Disallowed, 0, 0),
+    ('\u{FDF0}', Status::Mapped, 9648, 9654),
+    ('\u{FDF1}', Status::Mapped, 9654, 9660),
+    ('\u{FDF2}', Status::Mapped, 9660, 9668),
+    ('\u{FDF3}', Status::Mapped, 9668, 9676),
+    ('\u{FDF4}', Status::Mapped, 9676, 9684),
+    ('\u{FDF5}', Status::Mapped, 9684, 9692),
+    ('\u{FDF6}', Status::Mapped, 9692, 9700),
+    ('\u{FDF7}', Status::Mapped, 9700, 9708),
+    ('\u{FDF8}', Status::Mapped, 9708, 9716),
+    ('\u{FDF9}', Status::Mapped, 9716, 9722),
+    ('\u{FDFA}', Status::Mapped, 9722, 9755),
+    ('\u{FDFB}', Status::Mapped, 9755, 9770),
+    ('\u{FDFC}', Status::Mapped, 9770, 9778),
+    ('\u{FDFD}', Status::Valid, 0, 0),
+    ('\u{FE00}', Status::Ignored, 0, 0),
+    ('\u{FE10}', Status::Mapped, 9778, 9779),
+    ('\u{FE11}', Status::Mapped, 9779, 9782),
+    ('\u{FE12}', Status::Disallowed, 0, 0),
+    ('\u{FE13}', Status::Mapped, 9782, 9783),
+    ('\u{FE14}', Status::Mapped, 510, 511),
+    ('\u{FE15}', Status::Mapped, 9783, 9784),
+    ('\u{FE16}', Status::Mapped, 9784, 9785),
+    ('\u{FE17}', Status::Mapped, 9785, 9788),
+    ('\u{FE18}', Status::Mapped, 9788, 9791),
+    ('\u{FE19}', Status::Disallowed, 0, 0),
+    ('\u{FE20}', Status::Valid, 0, 0),
+    ('\u{FE30}', Status::Disallowed, 0, 0),
+    ('\u{FE31}', Status::Mapped, 9791, 9794),
+    ('\u{FE32}', Status::Mapped, 9794, 9797),
+    ('\u{FE33}', Status::Mapped, 9797, 9798),
+    ('\u{FE35}', Status::Mapped, 2359, 2360),
+    ('\u{FE36}', Status::Mapped, 2360, 2361),
+    ('\u{FE37}', Status::Mapped, 9798, 9799),
+    ('\u{FE38}', Status::Mapped, 9799, 9800),
+    ('\u{FE39}', Status::Mapped, 9800, 9803),
+    ('\u{FE3A}', Status::Mapped, 9803, 9806),
+    ('\u{FE3B}', Status::Mapped, 9806, 9809),
+    ('\u{FE3C}', Status::Mapped, 9809, 9812),
+    ('\u{FE3D}', Status::Mapped, 9812, 9815),
+    ('\u{FE3E}', Status::Mapped, 9815, 9818),
+    ('\u{FE3F}', Status::Mapped, 2542, 2545),
+    ('\u{FE40}', Status::Mapped, 2545, 2548),
+    ('\u{FE41}', Status::Mapped, 9818, 9821),
+    ('\u{FE42}', Status::Mapped, 9821, 9824),
+    ('\u{FE43}', Status::Mapped, 9824, 9827),
+    ('\u{FE44}', Status::Mapped, 9827, 9830),
+    ('\u{FE45}', Status::Valid, 0, 0),
+    ('\u{FE47}', Status::Mapped, 9830, 9831),
+    ('\u{FE48}', Status::Mapped, 9831, 9832),
+    ('\u{FE49}', Status::Mapped, 2326, 2329),
+    ('\u{FE4D}', Status::Mapped, 9797, 9798),
+    ('\u{FE50}', Status::Mapped, 9778, 9779),
+    ('\u{FE51}', Status::Mapped, 9779, 9782),
+    ('\u{FE52}', Status::Disallowed, 0, 0),
+    ('\u{FE54}', Status::Mapped, 510, 511),
+    ('\u{FE55}', Status::Mapped, 9782, 9783),
+    ('\u{FE56}', Status::Mapped, 9784, 9785),
+    ('\u{FE57}', Status::Mapped, 9783, 9784),
+    ('\u{FE58}', Status::Mapped, 9791, 9794),
+    ('\u{FE59}', Status::Mapped, 2359, 2360),
+    ('\u{FE5A}', Status::Mapped, 2360, 2361),
+    ('\u{FE5B}', Status::Mapped, 9798, 9799),
+    ('\u{FE5C}', Status::Mapped, 9799, 9800),
+    ('\u{FE5D}', Status::Mapped, 9800, 9803),
+    ('\u{FE5E}', Status::Mapped, 9803, 9806),
+    ('\u{FE5F}', Status::Mapped, 9832, 9833),
+    ('\u{FE60}', Status::Mapped, 9833, 9834),
+    ('\u{FE61}', Status::Mapped, 9834, 9835),
+    ('\u{FE62}', Status::Mapped, 2354, 2355),
+    ('\u{FE63}', Status::Mapped, 9835, 9836),
+    ('\u{FE64}', Status::Mapped, 9836, 9837),
+    ('\u{FE65}', Status::Mapped, 9837, 9838),
+    ('\u{FE66}', Status::Mapped, 2358, 2359),
+    ('\u{FE67}', Status::Disallowed, 0, 0),
+    ('\u{FE68}', Status::Mapped, 9838, 9839),
+    ('\u{FE69}', Status::Mapped, 9839, 9840),
+    ('\u{FE6A}', Status::Mapped, 9840, 9841),
+    ('\u{FE6B}', Status::Mapped, 9841, 9842),
+    ('\u{FE6C}', Status::Disallowed, 0, 0),
+    ('\u{FE70}', Status::Mapped, 9842, 9845),
+    ('\u{FE71}', Status::Mapped, 9845, 9849),
+    ('\u{FE72}', Status::Mapped, 9849, 9852),
+    ('\u{FE73}', Status::Valid, 0, 0),
+    ('\u{FE74}', Status::Mapped, 9852, 9855),
+    ('\u{FE75}', Status::Disallowed, 0, 0),
+    ('\u{FE76}', Status::Mapped, 9855, 9858),
+    ('\u{FE77}', Status::Mapped, 9858, 9862),
+    ('\u{FE78}', Status::Mapped, 9862, 9865),
+    ('\u{FE79}', Status::Mapped, 9865, 9869),
+    ('\u{FE7A}', Status::Mapped, 9869, 9872),
+    ('\u{FE7B}', Status::Mapped, 9872, 9876),
+    ('\u{FE7C}', Status::Mapped, 9876, 9879),
+    ('\u{FE7D}', Status::Mapped, 9879, 9883),
+    ('\u{FE7E}', Status::Mapped, 9883, 9886),
+    ('\u{FE7F}', Status::Mapped, 9886, 9890),
+    ('\u{FE80}', Status::Mapped, 9890, 9892),
+    ('\u{FE81}', Status::Mapped, 9892, 9894),
+    ('\u{FE83}', Status::Mapped, 9894, 9896),
+    ('\u{FE85}', Status::Mapped, 9896, 9898),
+    ('\u{FE87}', Status::Mapped, 9898, 9900),
+    ('\u{FE89}', Status::Mapped, 9900, 9902),
+    ('\u{FE8D}', Status::Mapped, 9902, 9904),
+    ('\u{FE8F}', Status::Mapped, 9904, 9906),
+    ('\u{FE93}', Status::Mapped, 9906, 9908),
+    ('\u{FE95}', Status::Mapped, 9908, 9910),
+    ('\u{FE99}', Status::Mapped, 9910, 9912),
+    ('\u{FE9D}', Status::Mapped, 9912, 9914),
+    ('\u{FEA1}', Status::Mapped, 9914, 9916),
+    ('\u{FEA5}', Status::Mapped, 9916, 9918),
+    ('\u{FEA9}', Status::Mapped, 9918, 9920),
+    ('\u{FEAB}', Status::Mapped, 9920, 9922),
+    ('\u{FEAD}', Status::Mapped, 9922, 9924),
+    ('\u{FEAF}', Status::Mapped, 9924, 9926),
+    ('\u{FEB1}', Status::Mapped, 9926, 9928),
+    ('\u{FEB5}', Status::Mapped, 9928, 9930),
+    ('\u{FEB9}', Status::Mapped, 9930, 9932),
+    ('\u{FEBD}', Status::Mapped, 9932, 9934),
+    ('\u{FEC1}', Status::Mapped, 9934, 9936),
+    ('\u{FEC5}', Status::Mapped, 9936, 9938),
+    ('\u{FEC9}', Status::Mapped, 9938, 9940),
+    ('\u{FECD}', Status::Mapped, 9940, 9942),
+    ('\u{FED1}', Status::Mapped, 9942, 9944),
+    ('\u{FED5}', Status::Mapped, 9944, 9946),
+    ('\u{FED9}', Status::Mapped, 9946, 9948),
+    ('\u{FEDD}', Status::Mapped, 9948, 9950),
+    ('\u{FEE1}', Status::Mapped, 9950, 9952),
+    ('\u{FEE5}', Status::Mapped, 9952, 9954),
+    ('\u{FEE9}', Status::Mapped, 9954, 9956),
+    ('\u{FEED}', Status::Mapped, 9956, 9958),
+    ('\u{FEEF}', Status::Mapped, 8388, 8390),
+    ('\u{FEF1}', Status::Mapped, 9958, 9960),
+    ('\u{FEF5}', Status::Mapped, 9960, 9964),
+    ('\u{FEF7}', Status::Mapped, 9964, 9968),
+    ('\u{FEF9}', Status::Mapped, 9968, 9972),
+    ('\u{FEFB}', Status::Mapped, 9972, 9976),
+    ('\u{FEFD}', Status::Disallowed, 0, 0),
+    ('\u{FEFF}', Status::Ignored, 0, 0),
+    ('\u{FF00}', Status::Disallowed, 0, 0),
+    ('\u{FF01}', Status::Mapped, 9783, 9784),
+    ('\u{FF02}', Status::Mapped, 9976, 9977),
+    ('\u{FF03}', Status::Mapped, 9832, 9833),
+    ('\u{FF04}', Status::Mapped, 9839, 9840),
+    ('\u{FF05}', Status::Mapped, 9840, 9841),
+    ('\u{FF06}', Status::Mapped, 9833, 9834),
+    ('\u{FF07}', Status::Mapped, 9977, 9978),
+    ('\u{FF08}', Status::Mapped, 2359, 2360),
+    ('\u{FF09}', Status::Mapped, 2360, 2361),
+    ('\u{FF0A}', Status::Mapped, 9834, 9835),
+    ('\u{FF0B}', Status::Mapped, 2354, 2355),
+    ('\u{FF0C}', Status::Mapped, 9778, 9779),
+    ('\u{FF0D}', Status::Mapped, 9835, 9836),
+    ('\u{FF0E}', Status::Mapped, 3727, 3728),
+    ('\u{FF0F}', Status::Mapped, 9978, 9979),
+    ('\u{FF10}', Status::Mapped, 2347, 2348),
+    ('\u{FF11}', Status::Mapped, 43, 44),
+    ('\u{FF12}', Status::Mapped, 33, 34),
+    ('\u{FF13}', Status::Mapped, 34, 35),
+    ('\u{FF14}', Status::Mapped, 2348, 2349),
+    ('\u{FF15}', Status::Mapped, 2349, 2350),
+    ('\u{FF16}', Status::Mapped, 2350, 2351),
+    ('\u{FF17}', Status::Mapped, 2351, 2352),
+    ('\u{FF18}', Status::Mapped, 2352, 2353),
+    ('\u{FF19}', Status::Mapped, 2353, 2354),
+    ('\u{FF1A}', Status::Mapped, 9782, 9783),
+    ('\u{FF1B}', Status::Mapped, 510, 511),
+    ('\u{FF1C}', Status::Mapped, 9836, 9837),
+    ('\u{FF1D}', Status::Mapped, 2358, 2359),
+    ('\u{FF1E}', Status::Mapped, 9837, 9838),
+    ('\u{FF1F}', Status::Mapped, 9784, 9785),
+    ('\u{FF20}', Status::Mapped, 9841, 9842),
+    ('\u{FF21}', Status::Mapped, 0, 1),
+    ('\u{FF22}', Status::Mapped, 1, 2),
+    ('\u{FF23}', Status::Mapped, 2, 3),
+    ('\u{FF24}', Status::Mapped, 3, 4),
+    ('\u{FF25}', Status::Mapped, 4, 5),
+    ('\u{FF26}', Status::Mapped, 5, 6),
+    ('\u{FF27}', Status::Mapped, 6, 7),
+    ('\u{FF28}', Status::Mapped, 7, 8),
+    ('\u{FF29}', Status::Mapped, 8, 9),
+    ('\u{FF2A}', Status::Mapped, 9, 10),
+    ('\u{FF2B}', Status::Mapped, 10, 11),
+    ('\u{FF2C}', Status::Mapped, 11, 12),
+    ('\u{FF2D}', Status::Mapped, 12, 13),
+    ('\u{FF2E}', Status::Mapped, 13, 14),
+    ('\u{FF2F}', Status::Mapped, 14, 15),
+    ('\u{FF30}', Status::Mapped, 15, 16),
+    ('\u{FF31}', Status::Mapped, 16, 17),
+    ('\u{FF32}', Status::Mapped, 17, 18),
+    ('\u{FF33}', Status::Mapped, 18, 19),
+    ('\u{FF34}', Status::Mapped, 19, 20),
+    ('\u{FF35}', Status::Mapped, 20, 21),
+    ('\u{FF36}', Status::Mapped, 21, 22),
+    ('\u{FF37}', Status::Mapped, 22, 23),
+    ('\u{FF38}', Status::Mapped, 23, 24),
+    ('\u{FF39}', Status::Mapped, 24, 25),
+    ('\u{FF3A}', Status::Mapped, 25, 26),
+    ('\u{FF3B}', Status::Mapped, 9830, 9831),
+    ('\u{FF3C}', Status::Mapped, 9838, 9839),
+    ('\u{FF3D}', Status::Mapped, 9831, 9832),
+    ('\u{FF3E}', Status::Mapped, 9979, 9980),
+    ('\u{FF3F}', Status::Mapped, 9797, 9798),
+    ('\u{FF40}', Status::Mapped, 2260, 2261),
+    ('\u{FF41}', Status::Mapped, 0, 1),
+    ('\u{FF42}', Status::Mapped, 1, 2),
+    ('\u{FF43}', Status::Mapped, 2, 3),
+    ('\u{FF44}', Status::Mapped, 3, 4),
+    ('\u{FF45}', Status::Mapped, 4, 5),
+    ('\u{FF46}', Status::Mapped, 5, 6),
+    ('\u{FF47}', Status::Mapped, 6, 7),
+    ('\u{FF48}', Status::Mapped, 7, 8),
+    ('\u{FF49}', Status::Mapped, 8, 9),
+    ('\u{FF4A}', Status::Mapped, 9, 10),
+    ('\u{FF4B}', Status::Mapped, 10, 11),
+    ('\u{FF4C}', Status::Mapped, 11, 12),
+    ('\u{FF4D}', Status::Mapped, 12, 13),
+    ('\u{FF4E}', Status::Mapped, 13, 14),
+    ('\u{FF4F}', Status::Mapped, 14, 15),
+    ('\u{FF50}', Status::Mapped, 15, 16),
+    ('\u{FF51}', Status::Mapped, 16, 17),
+    ('\u{FF52}', Status::Mapped, 17, 18),
+    ('\u{FF53}', Status::Mapped, 18, 19),
+    ('\u{FF54}', Status::Mapped, 19, 20),
+    ('\u{FF55}', Status::Mapped, 20, 21),
+    ('\u{FF56}', Status::Mapped, 21, 22),
+    ('\u{FF57}', Status::Mapped, 22, 23),
+    ('\u{FF58}', Status::Mapped, 23, 24),
+    ('\u{FF59}', Status::Mapped, 24, 25),
+    ('\u{FF5A}', Status::Mapped, 25, 26),
+    ('\u{FF5B}', Status::Mapped, 9798, 9799),
+    ('\u{FF5C}', Status::Mapped, 9980, 9981),
+    ('\u{FF5D}', Status::Mapped, 9799, 9800),
+    ('\u{FF5E}', Status::Mapped, 9981, 9982),
+    ('\u{FF5F}', Status::Mapped, 9982, 9985),
+    ('\u{FF60}', Status::Mapped, 9985, 9988),
+    ('\u{FF61}', Status::Mapped, 3727, 3728),
+    ('\u{FF62}', Status::Mapped, 9818, 9821),
+    ('\u{FF63}', Status::Mapped, 9821, 9824),
+    ('\u{FF64}', Status::Mapped, 9779, 9782),
+    ('\u{FF65}', Status::Mapped, 9988, 9991),
+    ('\u{FF66}', Status::Mapped, 4831, 4834),
+    ('\u{FF67}', Status::Mapped, 9991, 9994),
+    ('\u{FF68}', Status::Mapped, 9994, 9997),
+    ('\u{FF69}', Status::Mapped, 9997, 10000),
+    ('\u{FF6A}', Status::Mapped, 10000, 10003),
+    ('\u{FF6B}', Status::Mapped, 10003, 10006),
+    ('\u{FF6C}', Status::Mapped, 10006, 10009),
+    ('\u{FF6D}', Status::Mapped, 10009, 10012),
+    ('\u{FF6E}', Status::Mapped, 10012, 10015),
+    ('\u{FF6F}', Status::Mapped, 10015, 10018),
+    ('\u{FF70}', Status::Mapped, 10018, 10021),
+    ('\u{FF71}', Status::Mapped, 4693, 4696),
+    ('\u{FF72}', Status::Mapped, 4696, 4699),
+    ('\u{FF73}', Status::Mapped, 4699, 4702),
+    ('\u{FF74}', Status::Mapped, 4702, 4705),
+    ('\u{FF75}', Status::Mapped, 4705, 4708),
+    ('\u{FF76}', Status::Mapped, 4708, 4711),
+    ('\u{FF77}', Status::Mapped, 4711, 4714),
+    ('\u{FF78}', Status::Mapped, 4714, 4717),
+    ('\u{FF79}', Status::Mapped, 4717, 4720),
+    ('\u{FF7A}', Status::Mapped, 4720, 4723),
+    ('\u{FF7B}', Status::Mapped, 4723, 4726),
+    ('\u{FF7C}', Status::Mapped, 4726, 4729),
+    ('\u{FF7D}', Status::Mapped, 4729, 4732),
+    ('\u{FF7E}', Status::Mapped, 4732, 4735),
+    ('\u{FF7F}', Status::Mapped, 4735, 4738),
+    ('\u{FF80}', Status::Mapped, 4738, 4741),
+    ('\u{FF81}', Status::Mapped, 4741, 4744),
+    ('\u{FF82}', Status::Mapped, 4744, 4747),
+    ('\u{FF83}', Status::Mapped, 4747, 4750),
+    ('\u{FF84}', Status::Mapped, 4750, 4753),
+    ('\u{FF85}', Status::Mapped, 4753, 4756),
+    ('\u{FF86}', Status::Mapped, 4756, 4759),
+    ('\u{FF87}', Status::Mapped, 4759, 4762),
+    ('\u{FF88}', Status::Mapped, 4762, 4765),
+    ('\u{FF89}', Status::Mapped, 4765, 4768),
+    ('\u{FF8A}', Status::Mapped, 4768, 4771),
+    ('\u{FF8B}', Status::Mapped, 4771, 4774),
+    ('\u{FF8C}', Status::Mapped, 4774, 4777),
+    ('\u{FF8D}', Status::Mapped, 4777, 4780),
+    ('\u{FF8E}', Status::Mapped, 4780, 4783),
+    ('\u{FF8F}', Status::Mapped, 4783, 4786),
+    ('\u{FF90}', Status::Mapped, 4786, 4789),
+    ('\u{FF91}', Status::Mapped, 4789, 4792),
+    ('\u{FF92}', Status::Mapped, 4792, 4795),
+    ('\u{FF93}', Status::Mapped, 4795, 4798),
+    ('\u{FF94}', Status::Mapped, 4798, 4801),
+    ('\u{FF95}', Status::Mapped, 4801, 4804),
+    ('\u{FF96}', Status::Mapped, 4804, 4807),
+    ('\u{FF97}', Status::Mapped, 4807, 4810),
+    ('\u{FF98}', Status::Mapped, 4810, 4813),
+    ('\u{FF99}', Status::Mapped, 4813, 4816),
+    ('\u{FF9A}', Status::Mapped, 4816, 4819),
+    ('\u{FF9B}', Status::Mapped, 4819, 4822),
+    ('\u{FF9C}', Status::Mapped, 4822, 4825),
+    ('\u{FF9D}', Status::Mapped, 10021, 10024),
+    ('\u{FF9E}', Status::Mapped, 10024, 10027),
+    ('\u{FF9F}', Status::Mapped, 10027, 10030),
+    ('\u{FFA0}', Status::Disallowed, 0, 0),
+    ('\u{FFA1}', Status::Mapped, 3757, 3760),
+    ('\u{FFA2}', Status::Mapped, 3760, 3763),
+    ('\u{FFA3}', Status::Mapped, 3763, 3766),
+    ('\u{FFA4}', Status::Mapped, 3766, 3769),
+    ('\u{FFA5}', Status::Mapped, 3769, 3772),
+    ('\u{FFA6}', Status::Mapped, 3772, 3775),
+    ('\u{FFA7}', Status::Mapped, 3775, 3778),
+    ('\u{FFA8}', Status::Mapped, 3778, 3781),
+    ('\u{FFA9}', Status::Mapped, 3781, 3784),
+    ('\u{FFAA}', Status::Mapped, 3784, 3787),
+    ('\u{FFAB}', Status::Mapped, 3787, 3790),
+    ('\u{FFAC}', Status::Mapped, 3790, 3793),
+    ('\u{FFAD}', Status::Mapped, 3793, 3796),
+    ('\u{FFAE}', Status::Mapped, 3796, 3799),
+    ('\u{FFAF}', Status::Mapped, 3799, 3802),
+    ('\u{FFB0}', Status::Mapped, 3802, 3805),
+    ('\u{FFB1}', Status::Mapped, 3805, 3808),
+    ('\u{FFB2}', Status::Mapped, 3808, 3811),
+    ('\u{FFB3}', Status::Mapped, 3811, 3814),
+    ('\u{FFB4}', Status::Mapped, 3814, 3817),
+    ('\u{FFB5}', Status::Mapped, 3817, 3820),
+    ('\u{FFB6}', Status::Mapped, 3820, 3823),
+    ('\u{FFB7}', Status::Mapped, 3823, 3826),
+    ('\u{FFB8}', Status::Mapped, 3826, 3829),
+    ('\u{FFB9}', Status::Mapped, 3829, 3832),
+    ('\u{FFBA}', Status::Mapped, 3832, 3835),
+    ('\u{FFBB}', Status::Mapped, 3835, 3838),
+    ('\u{FFBC}', Status::Mapped, 3838, 3841),
+    ('\u{FFBD}', Status::Mapped, 3841, 3844),
+    ('\u{FFBE}', Status::Mapped, 3844, 3847),
+    ('\u{FFBF}', Status::Disallowed, 0, 0),
+    ('\u{FFC2}', Status::Mapped, 3847, 3850),
+    ('\u{FFC3}', Status::Mapped, 3850, 3853),
+    ('\u{FFC4}', Status::Mapped, 3853, 3856),
+    ('\u{FFC5}', Status::Mapped, 3856, 3859),
+    ('\u{FFC6}', Status::Mapped, 3859, 3862),
+    ('\u{FFC7}', Status::Mapped, 3862, 3865),
+    ('\u{FFC8}', Status::Disallowed, 0, 0),
+    ('\u{FFCA}', Status::Mapped, 3865, 3868),
+    ('\u{FFCB}', Status::Mapped, 3868, 3871),
+    ('\u{FFCC}', Status::Mapped, 3871, 3874),
+    ('\u{FFCD}', Status::Mapped, 3874, 3877),
+    ('\u{FFCE}', Status::Mapped, 3877, 3880),
+    ('\u{FFCF}', Status::Mapped, 3880, 3883),
+    ('\u{FFD0}', Status::Disallowed, 0, 0),
+    ('\u{FFD2}', Status::Mapped, 3883, 3886),
+    ('\u{FFD3}', Status::Mapped, 3886, 3889),
+    ('\u{FFD4}', Status::Mapped, 3889, 3892),
+    ('\u{FFD5}', Status::Mapped, 3892, 3895),
+    ('\u{FFD6}', Status::Mapped, 3895, 3898),
+    ('\u{FFD7}', Status::Mapped, 3898, 3901),
+    ('\u{FFD8}', Status::Disallowed, 0, 0),
+    ('\u{FFDA}', Status::Mapped, 3901, 3904),
+    ('\u{FFDB}', Status::Mapped, 3904, 3907),
+    ('\u{FFDC}', Status::Mapped, 3907, 3910),
+    ('\u{FFDD}', Status::Disallowed, 0, 0),
+    ('\u{FFE0}', Status::Mapped, 10030, 10032),
+    ('\u{FFE1}', Status::Mapped, 10032, 10034),
+    ('\u{FFE2}', Status::Mapped, 10034, 10036),
+    ('\u{FFE3}', Status::Mapped, 30, 33),
+    ('\u{FFE4}', Status::Mapped, 10036, 10038),
+    ('\u{FFE5}', Status::Mapped, 10038, 10040),
+    ('\u{FFE6}', Status::Mapped, 10040, 10043),
+    ('\u{FFE7}', Status::Disallowed, 0, 0),
+    ('\u{FFE8}', Status::Mapped, 10043, 10046),
+    ('\u{FFE9}', Status::Mapped, 10046, 10049),
+    ('\u{FFEA}', Status::Mapped, 10049, 10052),
+    ('\u{FFEB}', Status::Mapped, 10052, 10055),
+    ('\u{FFEC}', Status::Mapped, 10055, 10058),
+    ('\u{FFED}', Status::Mapped, 10058, 10061),
+    ('\u{FFEE}', Status::Mapped, 10061, 10064),
+    ('\u{FFEF}', Status::Disallowed, 0, 0),
+    ('\u{10000}', Status::Valid, 0, 0),
+    ('\u{1000C}', Status::Disallowed, 0, 0),
+    ('\u{1000D}', Status::Valid, 0, 0),
+    ('\u{10027}', Status::Disallowed, 0, 0),
+    ('\u{10028}', Status::Valid, 0, 0),
+    ('\u{1003B}', Status::Disallowed, 0, 0),
+    ('\u{1003C}', Status::Valid, 0, 0),
+    ('\u{1003E}', Status::Disallowed, 0, 0),
+    ('\u{1003F}', Status::Valid, 0, 0),
+    ('\u{1004E}', Status::Disallowed, 0, 0),
+    ('\u{10050}', Status::Valid, 0, 0),
+    ('\u{1005E}', Status::Disallowed, 0, 0),
+    ('\u{10080}', Status::Valid, 0, 0),
+    ('\u{100FB}', Status::Disallowed, 0, 0),
+    ('\u{10100}', Status::Valid, 0, 0),
+    ('\u{10103}', Status::Disallowed, 0, 0),
+    ('\u{10107}', Status::Valid, 0, 0),
+    ('\u{10134}', Status::Disallowed, 0, 0),
+    ('\u{10137}', Status::Valid, 0, 0),
+    ('\u{1018F}', Status::Disallowed, 0, 0),
+    ('\u{10190}', Status::Valid, 0, 0),
+    ('\u{1019D}', Status::Disallowed, 0, 0),
+    ('\u{101A0}', Status::Valid, 0, 0),
+    ('\u{101A1}', Status::Disallowed, 0, 0),
+    ('\u{101D0}', Status::Valid, 0, 0),
+    ('\u{101FE}', Status::Disallowed, 0, 0),
+    ('\u{10280}', Status::Valid, 0, 0),
+    ('\u{1029D}', Status::Disallowed, 0, 0),
+    ('\u{102A0}', Status::Valid, 0, 0),
+    ('\u{102D1}', Status::Disallowed, 0, 0),
+    ('\u{102E0}', Status::Valid, 0, 0),
+    ('\u{102FC}', Status::Disallowed, 0, 0),
+    ('\u{10300}', Status::Valid, 0, 0),
+    ('\u{10324}', Status::Disallowed, 0, 0),
+    ('\u{1032D}', Status::Valid, 0, 0),
+    ('\u{1034B}', Status::Disallowed, 0, 0),
+    ('\u{10350}', Status::Valid, 0, 0),
+    ('\u{1037B}', Status::Disallowed, 0, 0),
+    ('\u{10380}', Status::Valid, 0, 0),
+    ('\u{1039E}', Status::Disallowed, 0, 0),
+    ('\u{1039F}', Status::Valid, 0, 0),
+    ('\u{103C4}', Status::Disallowed, 0, 0),
+    ('\u{103C8}', Status::Valid, 0, 0),
+    ('\u{103D6}', Status::Disallowed, 0, 0),
+    ('\u{10400}', Status::Mapped, 10064, 10068),
+    ('\u{10401}', Status::Mapped, 10068, 10072),
+    ('\u{10402}', Status::Mapped, 10072, 10076),
+    ('\u{10403}', Status::Mapped, 10076, 10080),
+    ('\u{10404}', Status::Mapped, 10080, 10084),
+    ('\u{10405}', Status::Mapped, 10084, 10088),
+    ('\u{10406}', Status::Mapped, 10088, 10092),
+    ('\u{10407}', Status::Mapped, 10092, 10096),
+    ('\u{10408}', Status::Mapped, 10096, 10100),
+    ('\u{10409}', Status::Mapped, 10100, 10104),
+    ('\u{1040A}', Status::Mapped, 10104, 10108),
+    ('\u{1040B}', Status::Mapped, 10108, 10112),
+    ('\u{1040C}', Status::Mapped, 10112, 10116),
+    ('\u{1040D}', Status::Mapped, 10116, 10120),
+    ('\u{1040E}', Status::Mapped, 10120, 10124),
+    ('\u{1040F}', Status::Mapped, 10124, 10128),
+    ('\u{10410}', Status::Mapped, 10128, 10132),
+    ('\u{10411}', Status::Mapped, 10132, 10136),
+    ('\u{10412}', Status::Mapped, 10136, 10140),
+    ('\u{10413}', Status::Mapped, 10140, 10144),
+    ('\u{10414}', Status::Mapped, 10144, 10148),
+    ('\u{10415}', Status::Mapped, 10148, 10152),
+    ('\u{10416}', Status::Mapped, 10152, 10156),
+    ('\u{10417}', Status::Mapped, 10156, 10160),
+    ('\u{10418}', Status::Mapped, 10160, 10164),
+    ('\u{10419}', Status::Mapped, 10164, 10168),
+    ('\u{1041A}', Status::Mapped, 10168, 10172),
+    ('\u{1041B}', Status::Mapped, 10172, 10176),
+    ('\u{1041C}', Status::Mapped, 10176, 10180),
+    ('\u{1041D}', Status::Mapped, 10180, 10184),
+    ('\u{1041E}', Status::Mapped, 10184, 10188),
+    ('\u{1041F}', Status::Mapped, 10188, 10192),
+    ('\u{10420}', Status::Mapped, 10192, 10196),
+    ('\u{10421}', Status::Mapped, 10196, 10200),
+    ('\u{10422}', Status::Mapped, 10200, 10204),
+    ('\u{10423}', Status::Mapped, 10204, 10208),
+    ('\u{10424}', Status::Mapped, 10208, 10212),
+    ('\u{10425}', Status::Mapped, 10212, 10216),
+    ('\u{10426}', Status::Mapped, 10216, 10220),
+    ('\u{10427}', Status::Mapped, 10220, 10224),
+    ('\u{10428}', Status::Valid, 0, 0),
+    ('\u{1049E}', Status::Disallowed, 0, 0),
+    ('\u{104A0}', Status::Valid, 0, 0),
+    ('\u{104AA}', Status::Disallowed, 0, 0),
+    ('\u{104B0}', Status::Mapped, 10224, 10228),
+    ('\u{104B1}', Status::Mapped, 10228, 10232),
+    ('\u{104B2}', Status::Mapped, 10232, 10236),
+    ('\u{104B3}', Status::Mapped, 10236, 10240),
+    ('\u{104B4}', Status::Mapped, 10240, 10244),
+    ('\u{104B5}', Status::Mapped, 10244, 10248),
+    ('\u{104B6}', Status::Mapped, 10248, 10252),
+    ('\u{104B7}', Status::Mapped, 10252, 10256),
+    ('\u{104B8}', Status::Mapped, 10256, 10260),
+    ('\u{104B9}', Status::Mapped, 10260, 10264),
+    ('\u{104BA}', Status::Mapped, 10264, 10268),
+    ('\u{104BB}', Status::Mapped, 10268, 10272),
+    ('\u{104BC}', Status::Mapped, 10272, 10276),
+    ('\u{104BD}', Status::Mapped, 10276, 10280),
+    ('\u{104BE}', Status::Mapped, 10280, 10284),
+    ('\u{104BF}', Status::Mapped, 10284, 10288),
+    ('\u{104C0}', Status::Mapped, 10288, 10292),
+    ('\u{104C1}', Status::Mapped, 10292, 10296),
+    ('\u{104C2}', Status::Mapped, 10296, 10300),
+    ('\u{104C3}', Status::Mapped, 10300, 10304),
+    ('\u{104C4}', Status::Mapped, 10304, 10308),
+    ('\u{104C5}', Status::Mapped, 10308, 10312),
+    ('\u{104C6}', Status::Mapped, 10312, 10316),
+    ('\u{104C7}', Status::Mapped, 10316, 10320),
+    ('\u{104C8}', Status::Mapped, 10320, 10324),
+    ('\u{104C9}', Status::Mapped, 10324, 10328),
+    ('\u{104CA}', Status::Mapped, 10328, 10332),
+    ('\u{104CB}', Status::Mapped, 10332, 10336),
+    ('\u{104CC}', Status::Mapped, 10336, 10340),
+    ('\u{104CD}', Status::Mapped, 10340, 10344),
+    ('\u{104CE}', Status::Mapped, 10344, 10348),
+    ('\u{104CF}', Status::Mapped, 10348, 10352),
+    ('\u{104D0}', Status::Mapped, 10352, 10356),
+    ('\u{104D1}', Status::Mapped, 10356, 10360),
+    ('\u{104D2}', Status::Mapped, 10360, 10364),
+    ('\u{104D3}', Status::Mapped, 10364, 10368),
+    ('\u{104D4}', Status::Disallowed, 0, 0),
+    ('\u{104D8}', Status::Valid, 0, 0),
+    ('\u{104FC}', Status::Disallowed, 0, 0),
+    ('\u{10500}', Status::Valid, 0, 0),
+    ('\u{10528}', Status::Disallowed, 0, 0),
+    ('\u{10530}', Status::Valid, 0, 0),
+    ('\u{10564}', Status::Disallowed, 0, 0),
+    ('\u{1056F}', Status::Valid, 0, 0),
+    ('\u{10570}', Status::Mapped, 10368, 10372),
+    ('\u{10571}', Status::Mapped, 10372, 10376),
+    ('\u{10572}', Status::Mapped, 10376, 10380),
+    ('\u{10573}', Status::Mapped, 10380, 10384),
+    ('\u{10574}', Status::Mapped, 10384, 10388),
+    ('\u{10575}', Status::Mapped, 10388, 10392),
+    ('\u{10576}', Status::Mapped, 10392, 10396),
+    ('\u{10577}', Status::Mapped, 10396, 10400),
+    ('\u{10578}', Status::Mapped, 10400, 10404),
+    ('\u{10579}', Status::Mapped, 10404, 10408),
+    ('\u{1057A}', Status::Mapped, 10408, 10412),
+    ('\u{1057B}', Status::Disallowed, 0, 0),
+    ('\u{1057C}', Status::Mapped, 10412, 10416),
+    ('\u{1057D}', Status::Mapped, 10416, 10420),
+    ('\u{1057E}', Status::Mapped, 10420, 10424),
+    ('\u{1057F}', Status::Mapped, 10424, 10428),
+    ('\u{10580}', Status::Mapped, 10428, 10432),
+    ('\u{10581}', Status::Mapped, 10432, 10436),
+    ('\u{10582}', Status::Mapped, 10436, 10440),
+    ('\u{10583}', Status::Mapped, 10440, 10444),
+    ('\u{10584}', Status::Mapped, 10444, 10448),
+    ('\u{10585}', Status::Mapped, 10448, 10452),
+    ('\u{10586}', Status::Mapped, 10452, 10456),
+    ('\u{10587}', Status::Mapped, 10456, 10460),
+    ('\u{10588}', Status::Mapped, 10460, 10464),
+    ('\u{10589}', Status::Mapped, 10464, 10468),
+    ('\u{1058A}', Status::Mapped, 10468, 10472),
+    ('\u{1058B}', Status::Disallowed, 0, 0),
+    ('\u{1058C}', Status::Mapped, 10472, 10476),
+    ('\u{1058D}', Status::Mapped, 10476, 10480),
+    ('\u{1058E}', Status::Mapped, 10480, 10484),
+    ('\u{1058F}', Status::Mapped, 10484, 10488),
+    ('\u{10590}', Status::Mapped, 10488, 10492),
+    ('\u{10591}', Status::Mapped, 10492, 10496),
+    ('\u{10592}', Status::Mapped, 10496, 10500),
+    ('\u{10593}', Status::Disallowed, 0, 0),
+    ('\u{10594}', Status::Mapped, 10500, 10504),
+    ('\u{10595}', Status::Mapped, 10504, 10508),
+    ('\u{10596}', Status::Disallowed, 0, 0),
+    ('\u{10597}', Status::Valid, 0, 0),
+    ('\u{105A2}', Status::Disallowed, 0, 0),
+    ('\u{105A3}', Status::Valid, 0, 0),
+    ('\u{105B2}', Status::Disallowed, 0, 0),
+    ('\u{105B3}', Status::Valid, 0, 0),
+    ('\u{105BA}', Status::Disallowed, 0, 0),
+    ('\u{105BB}', Status::Valid, 0, 0),
+    ('\u{105BD}', Status::Disallowed, 0, 0),
+    ('\u{10600}', Status::Valid, 0, 0),
+    ('\u{10737}', Status::Disallowed, 0, 0),
+    ('\u{10740}', Status::Valid, 0, 0),
+    ('\u{10756}', Status::Disallowed, 0, 0),
+    ('\u{10760}', Status::Valid, 0, 0),
+    ('\u{10768}', Status::Disallowed, 0, 0),
+    ('\u{10780}', Status::Valid, 0, 0),
+    ('\u{10781}', Status::Mapped, 10508, 10510),
+    ('\u{10782}', Status::Mapped, 10510, 10512),
+    ('\u{10783}', Status::Mapped, 71, 73),
+    ('\u{10784}', Status::Mapped, 10512, 10514),
+    ('\u{10785}', Status::Mapped, 250, 252),
+    ('\u{10786}', Status::Disallowed, 0, 0),
+    ('\u{10787}', Status::Mapped, 10514, 10516),
+    ('\u{10788}', Status::Mapped, 10516, 10519),
+    ('\u{10789}', Status::Mapped, 10519, 10521),
+    ('\u{1078A}', Status::Mapped, 10521, 10523),
+    ('\u{1078B}', Status::Mapped, 260, 262),
+    ('\u{1078C}', Status::Mapped, 262, 264),
+    ('\u{1078D}', Status::Mapped, 10523, 10526),
+    ('\u{1078E}', Status::Mapped, 10526, 10528),
+    ('\u{1078F}', Status::Mapped, 10528, 10530),
+    ('\u{10790}', Status::Mapped, 10530, 10532),
+    ('\u{10791}', Status::Mapped, 10532, 10534),
+    ('\u{10792}', Status::Mapped, 10534, 10536),
+    ('\u{10793}', Status::Mapped, 274, 276),
+    ('\u{10794}', Status::Mapped, 10536, 10538),
+    ('\u{10795}', Status::Mapped, 157, 159),
+    ('\u{10796}', Status::Mapped, 10538, 10540),
+    ('\u{10797}', Status::Mapped, 10540, 10542),
+    ('\u{10798}', Status::Mapped, 10542, 10544),
+    ('\u{10799}', Status::Mapped, 10544, 10546),
+    ('\u{1079A}', Status::Mapped, 10546, 10548),
+    ('\u{1079B}', Status::Mapped, 6590, 6592),
+    ('\u{1079C}', Status::Mapped, 10548, 10552),
+    ('\u{1079D}', Status::Mapped, 10552, 10555),
+    ('\u{1079E}', Status::Mapped, 10555, 10557),
+    ('\u{1079F}', Status::Mapped, 10557, 10561),
+    ('\u{107A0}', Status::Mapped, 10561, 10563),
+    ('\u{107A1}', Status::Mapped, 10563, 10567),
+    ('\u{107A2}', Status::Mapped, 105, 107),
+    ('\u{107A3}', Status::Mapped, 10567, 10569),
+    ('\u{107A4}', Status::Mapped, 10569, 10571),
+    ('\u{107A5}', Status::Mapped, 16, 17),
+    ('\u{107A6}', Status::Mapped, 10571, 10573),
+    ('\u{107A7}', Status::Mapped, 10573, 10577),
+    ('\u{107A8}', Status::Mapped, 2896, 2898),
+    ('\u{107A9}', Status::Mapped, 10577, 10579),
+    ('\u{107AA}', Status::Mapped, 296, 298),
+    ('\u{107AB}', Status::Mapped, 10579, 10581),
+    ('\u{107AC}', Status::Mapped, 10581, 10583),
+    ('\u{107AD}', Status::Mapped, 10583, 10586),
+    ('\u{107AE}', Status::Mapped, 10586, 10588),
+    ('\u{107AF}', Status::Mapped, 304, 306),
+    ('\u{107B0}', Status::Mapped, 10588, 10591),
+    ('\u{107B1}', Status::Disallowed, 0, 0),
+    ('\u{107B2}', Status::Mapped, 10591, 10593),
+    ('\u{107B3}', Status::Mapped, 10593, 10595),
+    ('\u{107B4}', Status::Mapped, 10595, 10597),
+    ('\u{107B5}', Status::Mapped, 10597, 10599),
+    ('\u{107B6}', Status::Mapped, 10599, 10601),
+    ('\u{107B7}', Status::Mapped, 10601, 10603),
+    ('\u{107B8}', Status::Mapped, 10603, 10605),
+    ('\u{107B9}', Status::Mapped, 10605, 10609),
+    ('\u{107BA}', Status::Mapped, 10609, 10613),
+    ('\u{107BB}', Status::Disallowed, 0, 0),
+    ('\u{10800}', Status::Valid, 0, 0),
+    ('\u{10806}', Status::Disallowed, 0, 0),
+    ('\u{10808}', Status::Valid, 0, 0),
+    ('\u{10809}', Status::Disallowed, 0, 0),
+    ('\u{1080A}', Status::Valid, 0, 0),
+    ('\u{10836}', Status::Disallowed, 0, 0),
+    ('\u{10837}', Status::Valid, 0, 0),
+    ('\u{10839}', Status::Disallowed, 0, 0),
+    ('\u{1083C}', Status::Valid, 0, 0),
+    ('\u{1083D}', Status::Disallowed, 0, 0),
+    ('\u{1083F}', Status::Valid, 0, 0),
+    ('\u{10856}', Status::Disallowed, 0, 0),
+    ('\u{10857}', Status::Valid, 0, 0),
+    ('\u{1089F}', Status::Disallowed, 0, 0),
+    ('\u{108A7}', Status::Valid, 0, 0),
+    ('\u{108B0}', Status::Disallowed, 0, 0),
+    ('\u{108E0}', Status::Valid, 0, 0),
+    ('\u{108F3}', Status::Disallowed, 0, 0),
+    ('\u{108F4}', Status::Valid, 0, 0),
+    ('\u{108F6}', Status::Disallowed, 0, 0),
+    ('\u{108FB}', Status::Valid, 0, 0),
+    ('\u{1091C}', Status::Disallowed, 0, 0),
+    ('\u{1091F}', Status::Valid, 0, 0),
+    ('\u{1093A}', Status::Disallowed, 0, 0),
+    ('\u{1093F}', Status::Valid, 0, 0),
+    ('\u{10940}', Status::Disallowed, 0, 0),
+    ('\u{10980}', Status::Valid, 0, 0),
+    ('\u{109B8}', Status::Disallowed, 0, 0),
+    ('\u{109BC}', Status::Valid, 0, 0),
+    ('\u{109D0}', Status::Disallowed, 0, 0),
+    ('\u{109D2}', Status::Valid, 0, 0),
+    ('\u{10A04}', Status::Disallowed, 0, 0),
+    ('\u{10A05}', Status::Valid, 0, 0),
+    ('\u{10A07}', Status::Disallowed, 0, 0),
+    ('\u{10A0C}', Status::Valid, 0, 0),
+    ('\u{10A14}', Status::Disallowed, 0, 0),
+    ('\u{10A15}', Status::Valid, 0, 0),
+    ('\u{10A18}', Status::Disallowed, 0, 0),
+    ('\u{10A19}', Status::Valid, 0, 0),
+    ('\u{10A36}', Status::Disallowed, 0, 0),
+    ('\u{10A38}', Status::Valid, 0, 0),
+    ('\u{10A3B}', Status::Disallowed, 0, 0),
+    ('\u{10A3F}', Status::Valid, 0, 0),
+    ('\u{10A49}', Status::Disallowed, 0, 0),
+    ('\u{10A50}', Status::Valid, 0, 0),
+    ('\u{10A59}', Status::Disallowed, 0, 0),
+    ('\u{10A60}', Status::Valid, 0, 0),
+    ('\u{10AA0}', Status::Disallowed, 0, 0),
+    ('\u{10AC0}', Status::Valid, 0, 0),
+    ('\u{10AE7}', Status::Disallowed, 0, 0),
+    ('\u{10AEB}', Status::Valid, 0, 0),
+    ('\u{10AF7}', Status::Disallowed, 0, 0),
+    ('\u{10B00}', Status::Valid, 0, 0),
+    ('\u{10B36}', Status::Disallowed, 0, 0),
+    ('\u{10B39}', Status::Valid, 0, 0),
+    ('\u{10B56}', Status::Disallowed, 0, 0),
+    ('\u{10B58}', Status::Valid, 0, 0),
+    ('\u{10B73}', Status::Disallowed, 0, 0),
+    ('\u{10B78}', Status::Valid, 0, 0),
+    ('\u{10B92}', Status::Disallowed, 0, 0),
+    ('\u{10B99}', Status::Valid, 0, 0),
+    ('\u{10B9D}', Status::Disallowed, 0, 0),
+    ('\u{10BA9}', Status::Valid, 0, 0),
+    ('\u{10BB0}', Status::Disallowed, 0, 0),
+    ('\u{10C00}', Status::Valid, 0, 0),
+    ('\u{10C49}', Status::Disallowed, 0, 0),
+    ('\u{10C80}', Status::Mapped, 10613, 10617),
+    ('\u{10C81}', Status::Mapped, 10617, 10621),
+    ('\u{10C82}', Status::Mapped, 10621, 10625),
+    ('\u{10C83}', Status::Mapped, 10625, 10629),
+    ('\u{10C84}', Status::Mapped, 10629, 10633),
+    ('\u{10C85}', Status::Mapped, 10633, 10637),
+    ('\u{10C86}', Status::Mapped, 10637, 10641),
+    ('\u{10C87}', Status::Mapped, 10641, 10645),
+    ('\u{10C88}', Status::Mapped, 10645, 10649),
+    ('\u{10C89}', Status::Mapped, 10649, 10653),
+    ('\u{10C8A}', Status::Mapped, 10653, 10657),
+    ('\u{10C8B}', Status::Mapped, 10657, 10661),
+    ('\u{10C8C}', Status::Mapped, 10661, 10665),
+    ('\u{10C8D}', Status::Mapped, 10665, 10669),
+    ('\u{10C8E}', Status::Mapped, 10669, 10673),
+    ('\u{10C8F}', Status::Mapped, 10673, 10677),
+    ('\u{10C90}', Status::Mapped, 10677, 10681),
+    ('\u{10C91}', Status::Mapped, 10681, 10685),
+    ('\u{10C92}', Status::Mapped, 10685, 10689),
+    ('\u{10C93}', Status::Mapped, 10689, 10693),
+    ('\u{10C94}', Status::Mapped, 10693, 10697),
+    ('\u{10C95}', Status::Mapped, 10697, 10701),
+    ('\u{10C96}', Status::Mapped, 10701, 10705),
+    ('\u{10C97}', Status::Mapped, 10705, 10709),
+    ('\u{10C98}', Status::Mapped, 10709, 10713),
+    ('\u{10C99}', Status::Mapped, 10713, 10717),
+    ('\u{10C9A}', Status::Mapped, 10717, 10721),
+    ('\u{10C9B}', Status::Mapped, 10721, 10725),
+    ('\u{10C9C}', Status::Mapped, 10725, 10729),
+    ('\u{10C9D}', Status::Mapped, 10729, 10733),
+    ('\u{10C9E}', Status::Mapped, 10733, 10737),
+    ('\u{10C9F}', Status::Mapped, 10737, 10741),
+    ('\u{10CA0}', Status::Mapped, 10741, 10745),
+    ('\u{10CA1}', Status::Mapped, 10745, 10749),
+    ('\u{10CA2}', Status::Mapped, 10749, 10753),
+    ('\u{10CA3}', Status::Mapped, 10753, 10757),
+    ('\u{10CA4}', Status::Mapped, 10757, 10761),
+    ('\u{10CA5}', Status::Mapped, 10761, 10765),
+    ('\u{10CA6}', Status::Mapped, 10765, 10769),
+    ('\u{10CA7}', Status::Mapped, 10769, 10773),
+    ('\u{10CA8}', Status::Mapped, 10773, 10777),
+    ('\u{10CA9}', Status::Mapped, 10777, 10781),
+    ('\u{10CAA}', Status::Mapped, 10781, 10785),
+    ('\u{10CAB}', Status::Mapped, 10785, 10789),
+    ('\u{10CAC}', Status::Mapped, 10789, 10793),
+    ('\u{10CAD}', Status::Mapped, 10793, 10797),
+    ('\u{10CAE}', Status::Mapped, 10797, 10801),
+    ('\u{10CAF}', Status::Mapped, 10801, 10805),
+    ('\u{10CB0}', Status::Mapped, 10805, 10809),
+    ('\u{10CB1}', Status::Mapped, 10809, 10813),
+    ('\u{10CB2}', Status::Mapped, 10813, 10817),
+    ('\u{10CB3}', Status::Disallowed, 0, 0),
+    ('\u{10CC0}', Status::Valid, 0, 0),
+    ('\u{10CF3}', Status::Disallowed, 0, 0),
+    ('\u{10CFA}', Status::Valid, 0, 0),
+    ('\u{10D28}', Status::Disallowed, 0, 0),
+    ('\u{10D30}', Status::Valid, 0, 0),
+    ('\u{10D3A}', Status::Disallowed, 0, 0),
+    ('\u{10E60}', Status::Valid, 0, 0),
+    ('\u{10E7F}', Status::Disallowed, 0, 0),
+    ('\u{10E80}', Status::Valid, 0, 0),
+    ('\u{10EAA}', Status::Disallowed, 0, 0),
+    ('\u{10EAB}', Status::Valid, 0, 0),
+    ('\u{10EAE}', Status::Disallowed, 0, 0),
+    ('\u{10EB0}', Status::Valid, 0, 0),
+    ('\u{10EB2}', Status::Disallowed, 0, 0),
+    ('\u{10EFD}', Status::Valid, 0, 0),
+    ('\u{10F28}', Status::Disallowed, 0, 0),
+    ('\u{10F30}', Status::Valid, 0, 0),
+    ('\u{10F5A}', Status::Disallowed, 0, 0),
+    ('\u{10F70}', Status::Valid, 0, 0),
+    ('\u{10F8A}', Status::Disallowed, 0, 0),
+    ('\u{10FB0}', Status::Valid, 0, 0),
+    ('\u{10FCC}', Status::Disallowed, 0, 0),
+    ('\u{10FE0}', Status::Valid, 0, 0),
+    ('\u{10FF7}', Status::Disallowed, 0, 0),
+    ('\u{11000}', Status::Valid, 0, 0),
+    ('\u{1104E}', Status::Disallowed, 0, 0),
+    ('\u{11052}', Status::Valid, 0, 0),
+    ('\u{11076}', Status::Disallowed, 0, 0),
+    ('\u{1107F}', Status::Valid, 0, 0),
+    ('\u{110BD}', Status::Disallowed, 0, 0),
+    ('\u{110BE}', Status::Valid, 0, 0),
+    ('\u{110C3}', Status::Disallowed, 0, 0),
+    ('\u{110D0}', Status::Valid, 0, 0),
+    ('\u{110E9}', Status::Disallowed, 0, 0),
+    ('\u{110F0}', Status::Valid, 0, 0),
+    ('\u{110FA}', Status::Disallowed, 0, 0),
+    ('\u{11100}', Status::Valid, 0, 0),
+    ('\u{11135}', Status::Disallowed, 0, 0),
+    ('\u{11136}', Status::Valid, 0, 0),
+    ('\u{11148}', Status::Disallowed, 0, 0),
+    ('\u{11150}', Status::Valid, 0, 0),
+    ('\u{11177}', Status::Disallowed, 0, 0),
+    ('\u{11180}', Status::Valid, 0, 0),
+    ('\u{111E0}', Status::Disallowed, 0, 0),
+    ('\u{111E1}', Status::Valid, 0, 0),
+    ('\u{111F5}', Status::Disallowed, 0, 0),
+    ('\u{11200}', Status::Valid, 0, 0),
+    ('\u{11212}', Status::Disallowed, 0, 0),
+    ('\u{11213}', Status::Valid, 0, 0),
+    ('\u{11242}', Status::Disallowed, 0, 0),
+    ('\u{11280}', Status::Valid, 0, 0),
+    ('\u{11287}', Status::Disallowed, 0, 0),
+    ('\u{11288}', Status::Valid, 0, 0),
+    ('\u{11289}', Status::Disallowed, 0, 0),
+    ('\u{1128A}', Status::Valid, 0, 0),
+    ('\u{1128E}', Status::Disallowed, 0, 0),
+    ('\u{1128F}', Status::Valid, 0, 0),
+    ('\u{1129E}', Status::Disallowed, 0, 0),
+    ('\u{1129F}', Status::Valid, 0, 0),
+    ('\u{112AA}', Status::Disallowed, 0, 0),
+    ('\u{112B0}', Status::Valid, 0, 0),
+    ('\u{112EB}', Status::Disallowed, 0, 0),
+    ('\u{112F0}', Status::Valid, 0, 0),
+    ('\u{112FA}', Status::Disallowed, 0, 0),
+    ('\u{11300}', Status::Valid, 0, 0),
+    ('\u{11304}', Status::Disallowed, 0, 0),
+    ('\u{11305}', Status::Valid, 0, 0),
+    ('\u{1130D}', Status::Disallowed, 0, 0),
+    ('\u{1130F}', Status::Valid, 0, 0),
+    ('\u{11311}', Status::Disallowed, 0, 0),
+    ('\u{11313}', Status::Valid, 0, 0),
+    ('\u{11329}', Status::Disallowed, 0, 0),
+    ('\u{1132A}', Status::Valid, 0, 0),
+    ('\u{11331}', Status::Disallowed, 0, 0),
+    ('\u{11332}', Status::Valid, 0, 0),
+    ('\u{11334}', Status::Disallowed, 0, 0),
+    ('\u{11335}', Status::Valid, 0, 0),
+    ('\u{1133A}', Status::Disallowed, 0, 0),
+    ('\u{1133B}', Status::Valid, 0, 0),
+    ('\u{11345}', Status::Disallowed, 0, 0),
+    ('\u{11347}', Status::Valid, 0, 0),
+    ('\u{11349}', Status::Disallowed, 0, 0),
+    ('\u{1134B}', Status::Valid, 0, 0),
+    ('\u{1134E}', Status::Disallowed, 0, 0),
+    ('\u{11350}', Status::Valid, 0, 0),
+    ('\u{11351}', Status::Disallowed, 0, 0),
+    ('\u{11357}', Status::Valid, 0, 0),
+    ('\u{11358}', Status::Disallowed, 0, 0),
+    ('\u{1135D}', Status::Valid, 0, 0),
+    ('\u{11364}', Status::Disallowed, 0, 0),
+    ('\u{11366}', Status::Valid, 0, 0),
+    ('\u{1136D}', Status::Disallowed, 0, 0),
+    ('\u{11370}', Status::Valid, 0, 0),
+    ('\u{11375}', Status::Disallowed, 0, 0),
+    ('\u{11400}', Status::Valid, 0, 0),
+    ('\u{1145C}', Status::Disallowed, 0, 0),
+    ('\u{1145D}', Status::Valid, 0, 0),
+    ('\u{11462}', Status::Disallowed, 0, 0),
+    ('\u{11480}', Status::Valid, 0, 0),
+    ('\u{114C8}', Status::Disallowed, 0, 0),
+    ('\u{114D0}', Status::Valid, 0, 0),
+    ('\u{114DA}', Status::Disallowed, 0, 0),
+    ('\u{11580}', Status::Valid, 0, 0),
+    ('\u{115B6}', Status::Disallowed, 0, 0),
+    ('\u{115B8}', Status::Valid, 0, 0),
+    ('\u{115DE}', Status::Disallowed, 0, 0),
+    ('\u{11600}', Status::Valid, 0, 0),
+    ('\u{11645}', Status::Disallowed, 0, 0),
+    ('\u{11650}', Status::Valid, 0, 0),
+    ('\u{1165A}', Status::Disallowed, 0, 0),
+    ('\u{11660}', Status::Valid, 0, 0),
+    ('\u{1166D}', Status::Disallowed, 0, 0),
+    ('\u{11680}', Status::Valid, 0, 0),
+    ('\u{116BA}', Status::Disallowed, 0, 0),
+    ('\u{116C0}', Status::Valid, 0, 0),
+    ('\u{116CA}', Status::Disallowed, 0, 0),
+    ('\u{11700}', Status::Valid, 0, 0),
+    ('\u{1171B}', Status::Disallowed, 0, 0),
+    ('\u{1171D}', Status::Valid, 0, 0),
+    ('\u{1172C}', Status::Disallowed, 0, 0),
+    ('\u{11730}', Status::Valid, 0, 0),
+    ('\u{11747}', Status::Disallowed, 0, 0),
+    ('\u{11800}', Status::Valid, 0, 0),
+    ('\u{1183C}', Status::Disallowed, 0, 0),
+    ('\u{118A0}', Status::Mapped, 10817, 10821),
+    ('\u{118A1}', Status::Mapped, 10821, 10825),
+    ('\u{118A2}', Status::Mapped, 10825, 10829),
+    ('\u{118A3}', Status::Mapped, 10829, 10833),
+    ('\u{118A4}', Status::Mapped, 10833, 10837),
+    ('\u{118A5}', Status::Mapped, 10837, 10841),
+    ('\u{118A6}', Status::Mapped, 10841, 10845),
+    ('\u{118A7}', Status::Mapped, 10845, 10849),
+    ('\u{118A8}', Status::Mapped, 10849, 10853),
+    ('\u{118A9}', Status::Mapped, 10853, 10857),
+    ('\u{118AA}', Status::Mapped, 10857, 10861),
+    ('\u{118AB}', Status::Mapped, 10861, 10865),
+    ('\u{118AC}', Status::Mapped, 10865, 10869),
+    ('\u{118AD}', Status::Mapped, 10869, 10873),
+    ('\u{118AE}', Status::Mapped, 10873, 10877),
+    ('\u{118AF}', Status::Mapped, 10877, 10881),
+    ('\u{118B0}', Status::Mapped, 10881, 10885),
+    ('\u{118B1}', Status::Mapped, 10885, 10889),
+    ('\u{118B2}', Status::Mapped, 10889, 10893),
+    ('\u{118B3}', Status::Mapped, 10893, 10897),
+    ('\u{118B4}', Status::Mapped, 10897, 10901),
+    ('\u{118B5}', Status::Mapped, 10901, 10905),
+    ('\u{118B6}', Status::Mapped, 10905, 10909),
+    ('\u{118B7}', Status::Mapped, 10909, 10913),
+    ('\u{118B8}', Status::Mapped, 10913, 10917),
+    ('\u{118B9}', Status::Mapped, 10917, 10921),
+    ('\u{118BA}', Status::Mapped, 10921, 10925),
+    ('\u{118BB}', Status::Mapped, 10925, 10929),
+    ('\u{118BC}', Status::Mapped, 10929, 10933),
+    ('\u{118BD}', Status::Mapped, 10933, 10937),
+    ('\u{118BE}', Status::Mapped, 10937, 10941),
+    ('\u{118BF}', Status::Mapped, 10941, 10945),
+    ('\u{118C0}', Status::Valid, 0, 0),
+    ('\u{118F3}', Status::Disallowed, 0, 0),
+    ('\u{118FF}', Status::Valid, 0, 0),
+    ('\u{11907}', Status::Disallowed, 0, 0),
+    ('\u{11909}', Status::Valid, 0, 0),
+    ('\u{1190A}', Status::Disallowed, 0, 0),
+    ('\u{1190C}', Status::Valid, 0, 0),
+    ('\u{11914}', Status::Disallowed, 0, 0),
+    ('\u{11915}', Status::Valid, 0, 0),
+    ('\u{11917}', Status::Disallowed, 0, 0),
+    ('\u{11918}', Status::Valid, 0, 0),
+    ('\u{11936}', Status::Disallowed, 0, 0),
+    ('\u{11937}', Status::Valid, 0, 0),
+    ('\u{11939}', Status::Disallowed, 0, 0),
+    ('\u{1193B}', Status::Valid, 0, 0),
+    ('\u{11947}', Status::Disallowed, 0, 0),
+    ('\u{11950}', Status::Valid, 0, 0),
+    ('\u{1195A}', Status::Disallowed, 0, 0),
+    ('\u{119A0}', Status::Valid, 0, 0),
+    ('\u{119A8}', Status::Disallowed, 0, 0),
+    ('\u{119AA}', Status::Valid, 0, 0),
+    ('\u{119D8}', Status::Disallowed, 0, 0),
+    ('\u{119DA}', Status::Valid, 0, 0),
+    ('\u{119E5}', Status::Disallowed, 0, 0),
+    ('\u{11A00}', Status::Valid, 0, 0),
+    ('\u{11A48}', Status::Disallowed, 0, 0),
+    ('\u{11A50}', Status::Valid, 0, 0),
+    ('\u{11AA3}', Status::Disallowed, 0, 0),
+    ('\u{11AB0}', Status::Valid, 0, 0),
+    ('\u{11AF9}', Status::Disallowed, 0, 0),
+    ('\u{11B00}', Status::Valid, 0, 0),
+    ('\u{11B0A}', Status::Disallowed, 0, 0),
+    ('\u{11C00}', Status::Valid, 0, 0),
+    ('\u{11C09}', Status::Disallowed, 0, 0),
+    ('\u{11C0A}', Status::Valid, 0, 0),
+    ('\u{11C37}', Status::Disallowed, 0, 0),
+    ('\u{11C38}', Status::Valid, 0, 0),
+    ('\u{11C46}', Status::Disallowed, 0, 0),
+    ('\u{11C50}', Status::Valid, 0, 0),
+    ('\u{11C6D}', Status::Disallowed, 0, 0),
+    ('\u{11C70}', Status::Valid, 0, 0),
+    ('\u{11C90}', Status::Disallowed, 0, 0),
+    ('\u{11C92}', Status::Valid, 0, 0),
+    ('\u{11CA8}', Status::Disallowed, 0, 0),
+    ('\u{11CA9}', Status::Valid, 0, 0),
+    ('\u{11CB7}', Status::Disallowed, 0, 0),
+    ('\u{11D00}', Status::Valid, 0, 0),
+    ('\u{11D07}', Status::Disallowed, 0, 0),
+    ('\u{11D08}', Status::Valid, 0, 0),
+    ('\u{11D0A}', Status::Disallowed, 0, 0),
+    ('\u{11D0B}', Status::Valid, 0, 0),
+    ('\u{11D37}', Status::Disallowed, 0, 0),
+    ('\u{11D3A}', Status::Valid, 0, 0),
+    ('\u{11D3B}', Status::Disallowed, 0, 0),
+    ('\u{11D3C}', Status::Valid, 0, 0),
+    ('\u{11D3E}', Status::Disallowed, 0, 0),
+    ('\u{11D3F}', Status::Valid, 0, 0),
+    ('\u{11D48}', Status::Disallowed, 0, 0),
+    ('\u{11D50}', Status::Valid, 0, 0),
+    ('\u{11D5A}', Status::Disallowed, 0, 0),
+    ('\u{11D60}', Status::Valid, 0, 0),
+    ('\u{11D66}', Status::Disallowed, 0, 0),
+    ('\u{11D67}', Status::Valid, 0, 0),
+    ('\u{11D69}', Status::Disallowed, 0, 0),
+    ('\u{11D6A}', Status::Valid, 0, 0),
+    ('\u{11D8F}', Status::Disallowed, 0, 0),
+    ('\u{11D90}', Status::Valid, 0, 0),
+    ('\u{11D92}', Status::Disallowed, 0, 0),
+    ('\u{11D93}', Status::Valid, 0, 0),
+    ('\u{11D99}', Status::Disallowed, 0, 0),
+    ('\u{11DA0}', Status::Valid, 0, 0),
+    ('\u{11DAA}', Status::Disallowed, 0, 0),
+    ('\u{11EE0}', Status::Valid, 0, 0),
+    ('\u{11EF9}', Status::Disallowed, 0, 0),
+    ('\u{11F00}', Status::Valid, 0, 0),
+    ('\u{11F11}', Status::Disallowed, 0, 0),
+    ('\u{11F12}', Status::Valid, 0, 0),
+    ('\u{11F3B}', Status::Disallowed, 0, 0),
+    ('\u{11F3E}', Status::Valid, 0, 0),
+    ('\u{11F5A}', Status::Disallowed, 0, 0),
+    ('\u{11FB0}', Status::Valid, 0, 0),
+    ('\u{11FB1}', Status::Disallowed, 0, 0),
+    ('\u{11FC0}', Status::Valid, 0, 0),
+    ('\u{11FF2}', Status::Disallowed, 0, 0),
+    ('\u{11FFF}', Status::Valid, 0, 0),
+    ('\u{1239A}', Status::Disallowed, 0, 0),
+    ('\u{12400}', Status::Valid, 0, 0),
+    ('\u{1246F}', Status::Disallowed, 0, 0),
+    ('\u{12470}', Status::Valid, 0, 0),
+    ('\u{12475}', Status::Disallowed, 0, 0),
+    ('\u{12480}', Status::Valid, 0, 0),
+    ('\u{12544}', Status::Disallowed, 0, 0),
+    ('\u{12F90}', Status::Valid, 0, 0),
+    ('\u{12FF3}', Status::Disallowed, 0, 0),
+    ('\u{13000}', Status::Valid, 0, 0),
+    ('\u{13430}', Status::Disallowed, 0, 0),
+    ('\u{13440}', Status::Valid, 0, 0),
+    ('\u{13456}', Status::Disallowed, 0, 0),
+    ('\u{14400}', Status::Valid, 0, 0),
+    ('\u{14647}', Status::Disallowed, 0, 0),
+    ('\u{16800}', Status::Valid, 0, 0),
+    ('\u{16A39}', Status::Disallowed, 0, 0),
+    ('\u{16A40}', Status::Valid, 0, 0),
+    ('\u{16A5F}', Status::Disallowed, 0, 0),
+    ('\u{16A60}', Status::Valid, 0, 0),
+    ('\u{16A6A}', Status::Disallowed, 0, 0),
+    ('\u{16A6E}', Status::Valid, 0, 0),
+    ('\u{16ABF}', Status::Disallowed, 0, 0),
+    ('\u{16AC0}', Status::Valid, 0, 0),
+    ('\u{16ACA}', Status::Disallowed, 0, 0),
+    ('\u{16AD0}', Status::Valid, 0, 0),
+    ('\u{16AEE}', Status::Disallowed, 0, 0),
+    ('\u{16AF0}', Status::Valid, 0, 0),
+    ('\u{16AF6}', Status::Disallowed, 0, 0),
+    ('\u{16B00}', Status::Valid, 0, 0),
+    ('\u{16B46}', Status::Disallowed, 0, 0),
+    ('\u{16B50}', Status::Valid, 0, 0),
+    ('\u{16B5A}', Status::Disallowed, 0, 0),
+    ('\u{16B5B}', Status::Valid, 0, 0),
+    ('\u{16B62}', Status::Disallowed, 0, 0),
+    ('\u{16B63}', Status::Valid, 0, 0),
+    ('\u{16B78}', Status::Disallowed, 0, 0),
+    ('\u{16B7D}', Status::Valid, 0, 0),
+    ('\u{16B90}', Status::Disallowed, 0, 0),
+    ('\u{16E40}', Status::Mapped, 10945, 10949),
+    ('\u{16E41}', Status::Mapped, 10949, 10953),
+    ('\u{16E42}', Status::Mapped, 10953, 10957),
+    ('\u{16E43}', Status::Mapped, 10957, 10961),
+    ('\u{16E44}', Status::Mapped, 10961, 10965),
+    ('\u{16E45}', Status::Mapped, 10965, 10969),
+    ('\u{16E46}', Status::Mapped, 10969, 10973),
+    ('\u{16E47}', Status::Mapped, 10973, 10977),
+    ('\u{16E48}', Status::Mapped, 10977, 10981),
+    ('\u{16E49}', Status::Mapped, 10981, 10985),
+    ('\u{16E4A}', Status::Mapped, 10985, 10989),
+    ('\u{16E4B}', Status::Mapped, 10989, 10993),
+    ('\u{16E4C}', Status::Mapped, 10993, 10997),
+    ('\u{16E4D}', Status::Mapped, 10997, 11001),
+    ('\u{16E4E}', Status::Mapped, 11001, 11005),
+    ('\u{16E4F}', Status::Mapped, 11005, 11009),
+    ('\u{16E50}', Status::Mapped, 11009, 11013),
+    ('\u{16E51}', Status::Mapped, 11013, 11017),
+    ('\u{16E52}', Status::Mapped, 11017, 11021),
+    ('\u{16E53}', Status::Mapped, 11021, 11025),
+    ('\u{16E54}', Status::Mapped, 11025, 11029),
+    ('\u{16E55}', Status::Mapped, 11029, 11033),
+    ('\u{16E56}', Status::Mapped, 11033, 11037),
+    ('\u{16E57}', Status::Mapped, 11037, 11041),
+    ('\u{16E58}', Status::Mapped, 11041, 11045),
+    ('\u{16E59}', Status::Mapped, 11045, 11049),
+    ('\u{16E5A}', Status::Mapped, 11049, 11053),
+    ('\u{16E5B}', Status::Mapped, 11053, 11057),
+    ('\u{16E5C}', Status::Mapped, 11057, 11061),
+    ('\u{16E5D}', Status::Mapped, 11061, 11065),
+    ('\u{16E5E}', Status::Mapped, 11065, 11069),
+    ('\u{16E5F}', Status::Mapped, 11069, 11073),
+    ('\u{16E60}', Status::Valid, 0, 0),
+    ('\u{16E9B}', Status::Disallowed, 0, 0),
+    ('\u{16F00}', Status::Valid, 0, 0),
+    ('\u{16F4B}', Status::Disallowed, 0, 0),
+    ('\u{16F4F}', Status::Valid, 0, 0),
+    ('\u{16F88}', Status::Disallowed, 0, 0),
+    ('\u{16F8F}', Status::Valid, 0, 0),
+    ('\u{16FA0}', Status::Disallowed, 0, 0),
+    ('\u{16FE0}', Status::Valid, 0, 0),
+    ('\u{16FE5}', Status::Disallowed, 0, 0),
+    ('\u{16FF0}', Status::Valid, 0, 0),
+    ('\u{16FF2}', Status::Disallowed, 0, 0),
+    ('\u{17000}', Status::Valid, 0, 0),
+    ('\u{187F8}', Status::Disallowed, 0, 0),
+    ('\u{18800}', Status::Valid, 0, 0),
+    ('\u{18CD6}', Status::Disallowed, 0, 0),
+    ('\u{18D00}', Status::Valid, 0, 0),
+    ('\u{18D09}', Status::Disallowed, 0, 0),
+    ('\u{1AFF0}', Status::Valid, 0, 0),
+    ('\u{1AFF4}', Status::Disallowed, 0, 0),
+    ('\u{1AFF5}', Status::Valid, 0, 0),
+    ('\u{1AFFC}', Status::Disallowed, 0, 0),
+    ('\u{1AFFD}', Status::Valid, 0, 0),
+    ('\u{1AFFF}', Status::Disallowed, 0, 0),
+    ('\u{1B000}', Status::Valid, 0, 0),
+    ('\u{1B123}', Status::Disallowed, 0, 0),
+    ('\u{1B132}', Status::Valid, 0, 0),
+    ('\u{1B133}', Status::Disallowed, 0, 0),
+    ('\u{1B150}', Status::Valid, 0, 0),
+    ('\u{1B153}', Status::Disallowed, 0, 0),
+    ('\u{1B155}', Status::Valid, 0, 0),
+    ('\u{1B156}', Status::Disallowed, 0, 0),
+    ('\u{1B164}', Status::Valid, 0, 0),
+    ('\u{1B168}', Status::Disallowed, 0, 0),
+    ('\u{1B170}', Status::Valid, 0, 0),
+    ('\u{1B2FC}', Status::Disallowed, 0, 0),
+    ('\u{1BC00}', Status::Valid, 0, 0),
+    ('\u{1BC6B}', Status::Disallowed, 0, 0),
+    ('\u{1BC70}', Status::Valid, 0, 0),
+    ('\u{1BC7D}', Status::Disallowed, 0, 0),
+    ('\u{1BC80}', Status::Valid, 0, 0),
+    ('\u{1BC89}', Status::Disallowed, 0, 0),
+    ('\u{1BC90}', Status::Valid, 0, 0),
+    ('\u{1BC9A}', Status::Disallowed, 0, 0),
+    ('\u{1BC9C}', Status::Valid, 0, 0),
+    ('\u{1BCA0}', Status::Ignored, 0, 0),
+    ('\u{1BCA4}', Status::Disallowed, 0, 0),
+    ('\u{1CF00}', Status::Valid, 0, 0),
+    ('\u{1CF2E}', Status::Disallowed, 0, 0),
+    ('\u{1CF30}', Status::Valid, 0, 0),
+    ('\u{1CF47}', Status::Disallowed, 0, 0),
+    ('\u{1CF50}', Status::Valid, 0, 0),
+    ('\u{1CFC4}', Status::Disallowed, 0, 0),
+    ('\u{1D000}', Status::Valid, 0, 0),
+    ('\u{1D0F6}', Status::Disallowed, 0, 0),
+    ('\u{1D100}', Status::Valid, 0, 0),
+    ('\u{1D127}', Status::Disallowed, 0, 0),
+    ('\u{1D129}', Status::Valid, 0, 0),
+    ('\u{1D15E}', Status::Mapped, 11073, 11081),
+    ('\u{1D15F}', Status::Mapped, 11081, 11089),
+    ('\u{1D160}', Status::Mapped, 11089, 11101),
+    ('\u{1D161}', Status::Mapped, 11101, 11113),
+    ('\u{1D162}', Status::Mapped, 11113, 11125),
+    ('\u{1D163}', Status::Mapped, 11125, 11137),
+    ('\u{1D164}', Status::Mapped, 11137, 11149),
+    ('\u{1D165}', Status::Valid, 0, 0),
+    ('\u{1D173}', Status::Disallowed, 0, 0),
+    ('\u{1D17B}', Status::Valid, 0, 0),
+    ('\u{1D1BB}', Status::Mapped, 11149, 11157),
+    ('\u{1D1BC}', Status::Mapped, 11157, 11165),
+    ('\u{1D1BD}', Status::Mapped, 11165, 11177),
+    ('\u{1D1BE}', Status::Mapped, 11177, 11189),
+    ('\u{1D1BF}', Status::Mapped, 11189, 11201),
+    ('\u{1D1C0}', Status::Mapped, 11201, 11213),
+    ('\u{1D1C1}', Status::Valid, 0, 0),
+    ('\u{1D1EB}', Status::Disallowed, 0, 0),
+    ('\u{1D200}', Status::Valid, 0, 0),
+    ('\u{1D246}', Status::Disallowed, 0, 0),
+    ('\u{1D2C0}', Status::Valid, 0, 0),
+    ('\u{1D2D4}', Status::Disallowed, 0, 0),
+    ('\u{1D2E0}', Status::Valid, 0, 0),
+    ('\u{1D2F4}', Status::Disallowed, 0, 0),
+    ('\u{1D300}', Status::Valid, 0, 0),
+    ('\u{1D357}', Status::Disallowed, 0, 0),
+    ('\u{1D360}', Status::Valid, 0, 0),
+    ('\u{1D379}', Status::Disallowed, 0, 0),
+    ('\u{1D400}', Status::Mapped, 0, 1),
+    ('\u{1D401}', Status::Mapped, 1, 2),
+    ('\u{1D402}', Status::Mapped, 2, 3),
+    ('\u{1D403}', Status::Mapped, 3, 4),
+    ('\u{1D404}', Status::Mapped, 4, 5),
+    ('\u{1D405}', Status::Mapped, 5, 6),
+    ('\u{1D406}', Status::Mapped, 6, 7),
+    ('\u{1D407}', Status::Mapped, 7, 8),
+    ('\u{1D408}', Status::Mapped, 8, 9),
+    ('\u{1D409}', Status::Mapped, 9, 10),
+    ('\u{1D40A}', Status::Mapped, 10, 11),
+    ('\u{1D40B}', Status::Mapped, 11, 12),
+    ('\u{1D40C}', Status::Mapped, 12, 13),
+    ('\u{1D40D}', Status::Mapped, 13, 14),
+    ('\u{1D40E}', Status::Mapped, 14, 15),
+    ('\u{1D40F}', Status::Mapped, 15, 16),
+    ('\u{1D410}', Status::Mapped, 16, 17),
+    ('\u{1D411}', Status::Mapped, 17, 18),
+    ('\u{1D412}', Status::Mapped, 18, 19),
+    ('\u{1D413}', Status::Mapped, 19, 20),
+    ('\u{1D414}', Status::Mapped, 20, 21),
+    ('\u{1D415}', Status::Mapped, 21, 22),
+    ('\u{1D416}', Status::Mapped, 22, 23),
+    ('\u{1D417}', Status::Mapped, 23, 24),
+    ('\u{1D418}', Status::Mapped, 24, 25),
+    ('\u{1D419}', Status::Mapped, 25, 26),
+    ('\u{1D41A}', Status::Mapped, 0, 1),
+    ('\u{1D41B}', Status::Mapped, 1, 2),
+    ('\u{1D41C}', Status::Mapped, 2, 3),
+    ('\u{1D41D}', Status::Mapped, 3, 4),
+    ('\u{1D41E}', Status::Mapped, 4, 5),
+    ('\u{1D41F}', Status::Mapped, 5, 6),
+    ('\u{1D420}', Status::Mapped, 6, 7),
+    ('\u{1D421}', Status::Mapped, 7, 8),
+    ('\u{1D422}', Status::Mapped, 8, 9),
+    ('\u{1D423}', Status::Mapped, 9, 10),
+    ('\u{1D424}', Status::Mapped, 10, 11),
+    ('\u{1D425}', Status::Mapped, 11, 12),
+    ('\u{1D426}', Status::Mapped, 12, 13),
+    ('\u{1D427}', Status::Mapped, 13, 14),
+    ('\u{1D428}', Status::Mapped, 14, 15),
+    ('\u{1D429}', Status::Mapped, 15, 16),
+    ('\u{1D42A}', Status::Mapped, 16, 17),
+    ('\u{1D42B}', Status::Mapped, 17, 18),
+    ('\u{1D42C}', Status::Mapped, 18, 19),
+    ('\u{1D42D}', Status::Mapped, 19, 20),
+    ('\u{1D42E}', Status::Mapped, 20, 21),
+    ('\u{1D42F}', Status::Mapped, 21, 22),
+    ('\u{1D430}', Status::Mapped, 22, 23),
+    ('\u{1D431}', Status::Mapped, 23, 24),
+    ('\u{1D432}', Status::Mapped, 24, 25),
+    ('\u{1D433}', Status::Mapped, 25, 26),
+    ('\u{1D434}', Status::Mapped, 0, 1),
+    ('\u{1D435}', Status::Mapped, 1, 2),
+    ('\u{1D436}', Status::Mapped, 2, 3),
+    ('\u{1D437}', Status::Mapped, 3, 4),
+    ('\u{1D438}', Status::Mapped, 4, 5),
+    ('\u{1D439}', Status::Mapped, 5, 6),
+    ('\u{1D43A}', Status::Mapped, 6, 7),
+    ('\u{1D43B}', Status::Mapped, 7, 8),
+    ('\u{1D43C}', Status::Mapped, 8, 9),
+    ('\u{1D43D}', Status::Mapped, 9, 10),
+    ('\u{1D43E}', Status::Mapped, 10, 11),
+    ('\u{1D43F}', Status::Mapped, 11, 12),
+    ('\u{1D440}', Status::Mapped, 12, 13),
+    ('\u{1D441}', Status::Mapped, 13, 14),
+    ('\u{1D442}', Status::Mapped, 14, 15),
+    ('\u{1D443}', Status::Mapped, 15, 16),
+    ('\u{1D444}', Status::Mapped, 16, 17),
+    ('\u{1D445}', Status::Mapped, 17, 18),
+    ('\u{1D446}', Status::Mapped, 18, 19),
+    ('\u{1D447}', Status::Mapped, 19, 20),
+    ('\u{1D448}', Status::Mapped, 20, 21),
+    ('\u{1D449}', Status::Mapped, 21, 22),
+    ('\u{1D44A}', Status::Mapped, 22, 23),
+    ('\u{1D44B}', Status::Mapped, 23, 24),
+    ('\u{1D44C}', Status::Mapped, 24, 25),
+    ('\u{1D44D}', Status::Mapped, 25, 26),
+    ('\u{1D44E}', Status::Mapped, 0, 1),
+    ('\u{1D44F}', Status::Mapped, 1, 2),
+    ('\u{1D450}', Status::Mapped, 2, 3),
+    ('\u{1D451}', Status::Mapped, 3, 4),
+    ('\u{1D452}', Status::Mapped, 4, 5),
+    ('\u{1D453}', Status::Mapped, 5, 6),
+    ('\u{1D454}', Status::Mapped, 6, 7),
+    ('\u{1D455}', Status::Disallowed, 0, 0),
+    ('\u{1D456}', Status::Mapped, 8, 9),
+    ('\u{1D457}', Status::Mapped, 9, 10),
+    ('\u{1D458}', Status::Mapped, 10, 11),
+    ('\u{1D459}', Status::Mapped, 11, 12),
+    ('\u{1D45A}', Status::Mapped, 12, 13),
+    ('\u{1D45B}', Status::Mapped, 13, 14),
+    ('\u{1D45C}', Status::Mapped, 14, 15),
+    ('\u{1D45D}', Status::Mapped, 15, 16),
+    ('\u{1D45E}', Status::Mapped, 16, 17),
+    ('\u{1D45F}', Status::Mapped, 17, 18),
+    ('\u{1D460}', Status::Mapped, 18, 19),
+    ('\u{1D461}', Status::Mapped, 19, 20),
+    ('\u{1D462}', Status::Mapped, 20, 21),
+    ('\u{1D463}', Status::Mapped, 21, 22),
+    ('\u{1D464}', Status::Mapped, 22, 23),
+    ('\u{1D465}', Status::Mapped, 23, 24),
+    ('\u{1D466}', Status::Mapped, 24, 25),
+    ('\u{1D467}', Status::Mapped, 25, 26),
+    ('\u{1D468}', Status::Mapped, 0, 1),
+    ('\u{1D469}', Status::Mapped, 1, 2),
+    ('\u{1D46A}', Status::Mapped, 2, 3),
+    ('\u{1D46B}', Status::Mapped, 3, 4),
+    ('\u{1D46C}', Status::Mapped, 4, 5),
+    ('\u{1D46D}', Status::Mapped, 5, 6),
+    ('\u{1D46E}', Status::Mapped, 6, 7),
+    ('\u{1D46F}', Status::Mapped, 7, 8),
+    ('\u{1D470}', Status::Mapped, 8, 9),
+    ('\u{1D471}', Status::Mapped, 9, 10),
+    ('\u{1D472}', Status::Mapped, 10, 11),
+    ('\u{1D473}', Status::Mapped, 11, 12),
+    ('\u{1D474}', Status::Mapped, 12, 13),
+    ('\u{1D475}', Status::Mapped, 13, 14),
+    ('\u{1D476}', Status::Mapped, 14, 15),
+    ('\u{1D477}', Status::Mapped, 15, 16),
+    ('\u{1D478}', Status::Mapped, 16, 17),
+    ('\u{1D479}', Status::Mapped, 17, 18),
+    ('\u{1D47A}', Status::Mapped, 18, 19),
+    ('\u{1D47B}', Status::Mapped, 19, 20),
+    ('\u{1D47C}', Status::Mapped, 20, 21),
+    ('\u{1D47D}', Status::Mapped, 21, 22),
+    ('\u{1D47E}', Status::Mapped, 22, 23),
+    ('\u{1D47F}', Status::Mapped, 23, 24),
+    ('\u{1D480}', Status::Mapped, 24, 25),
+    ('\u{1D481}', Status::Mapped, 25, 26),
+    ('\u{1D482}', Status::Mapped, 0, 1),
+    ('\u{1D483}', Status::Mapped, 1, 2),
+    ('\u{1D484}', Status::Mapped, 2, 3),
+    ('\u{1D485}', Status::Mapped, 3, 4),
+    ('\u{1D486}', Status::Mapped, 4, 5),
+    ('\u{1D487}', Status::Mapped, 5, 6),
+    ('\u{1D488}', Status::Mapped, 6, 7),
+    ('\u{1D489}', Status::Mapped, 7, 8),
+    ('\u{1D48A}', Status::Mapped, 8, 9),
+    ('\u{1D48B}', Status::Mapped, 9, 10),
+    ('\u{1D48C}', Status::Mapped, 10, 11),
+    ('\u{1D48D}', Status::Mapped, 11, 12),
+    ('\u{1D48E}', Status::Mapped, 12, 13),
+    ('\u{1D48F}', Status::Mapped, 13, 14),
+    ('\u{1D490}', Status::Mapped, 14, 15),
+    ('\u{1D491}', Status::Mapped, 15, 16),
+    ('\u{1D492}', Status::Mapped, 16, 17),
+    ('\u{1D493}', Status::Mapped, 17, 18),
+    ('\u{1D494}', Status::Mapped, 18, 19),
+    ('\u{1D495}', Status::Mapped, 19, 20),
+    ('\u{1D496}', Status::Mapped, 20, 21),
+    ('\u{1D497}', Status::Mapped, 21, 22),
+    ('\u{1D498}', Status::Mapped, 22, 23),
+    ('\u{1D499}', Status::Mapped, 23, 24),
+    ('\u{1D49A}', Status::Mapped, 24, 25),
+    ('\u{1D49B}', Status::Mapped, 25, 26),
+    ('\u{1D49C}', Status::Mapped, 0, 1),
+    ('\u{1D49D}', Status::Disallowed, 0, 0),
+    ('\u{1D49E}', Status::Mapped, 2, 3),
+    ('\u{1D49F}', Status::Mapped, 3, 4),
+    ('\u{1D4A0}', Status::Disallowed, 0, 0),
+    ('\u{1D4A2}', Status::Mapped, 6, 7),
+    ('\u{1D4A3}', Status::Disallowed, 0, 0),
+    ('\u{1D4A5}', Status::Mapped, 9, 10),
+    ('\u{1D4A6}', Status::Mapped, 10, 11),
+    ('\u{1D4A7}', Status::Disallowed, 0, 0),
+    ('\u{1D4A9}', Status::Mapped, 13, 14),
+    ('\u{1D4AA}', Status::Mapped, 14, 15),
+    ('\u{1D4AB}', Status::Mapped, 15, 16),
+    ('\u{1D4AC}', Status::Mapped, 16, 17),
+    ('\u{1D4AD}', Status::Disallowed, 0, 0),
+    ('\u{1D4AE}', Status::Mapped, 18, 19),
+    ('\u{1D4AF}', Status::Mapped, 19, 20),
+    ('\u{1D4B0}', Status::Mapped, 20, 21),
+    ('\u{1D4B1}', Status::Mapped, 21, 22),
+    ('\u{1D4B2}', Status::Mapped, 22, 23),
+    ('\u{1D4B3}', Status::Mapped, 23, 24),
+    ('\u{1D4B4}', Status::Mapped, 24, 25),
+    ('\u{1D4B5}', Status::Mapped, 25, 26),
+    ('\u{1D4B6}', Status::Mapped, 0, 1),
+    ('\u{1D4B7}', Status::Mapped, 1, 2),
+    ('\u{1D4B8}', Status::Mapped, 2, 3),
+    ('\u{1D4B9}', Status::Mapped, 3, 4),
+    ('\u{1D4BA}', Status::Disallowed, 0, 0),
+    ('\u{1D4BB}', Status::Mapped, 5, 6),
+    ('\u{1D4BC}', Status::Disallowed, 0, 0),
+    ('\u{1D4BD}', Status::Mapped, 7, 8),
+    ('\u{1D4BE}', Status::Mapped, 8, 9),
+    ('\u{1D4BF}', Status::Mapped, 9, 10),
+    ('\u{1D4C0}', Status::Mapped, 10, 11),
+    ('\u{1D4C1}', Status::Mapped, 11, 12),
+    ('\u{1D4C2}', Status::Mapped, 12, 13),
+    ('\u{1D4C3}', Status::Mapped, 13, 14),
+    ('\u{1D4C4}', Status::Disallowed, 0, 0),
+    ('\u{1D4C5}', Status::Mapped, 15, 16),
+    ('\u{1D4C6}', Status::Mapped, 16, 17),
+    ('\u{1D4C7}', Status::Mapped, 17, 18),
+    ('\u{1D4C8}', Status::Mapped, 18, 19),
+    ('\u{1D4C9}', Status::Mapped, 19, 20),
+    ('\u{1D4CA}', Status::Mapped, 20, 21),
+    ('\u{1D4CB}', Status::Mapped, 21, 22),
+    ('\u{1D4CC}', Status::Mapped, 22, 23),
+    ('\u{1D4CD}', Status::Mapped, 23, 24),
+    ('\u{1D4CE}', Status::Mapped, 24, 25),
+    ('\u{1D4CF}', Status::Mapped, 25, 26),
+    ('\u{1D4D0}', Status::Mapped, 0, 1),
+    ('\u{1D4D1}', Status::Mapped, 1, 2),
+    ('\u{1D4D2}', Status::Mapped, 2, 3),
+    ('\u{1D4D3}', Status::Mapped, 3, 4),
+    ('\u{1D4D4}', Status::Mapped, 4, 5),
+    ('\u{1D4D5}', Status::Mapped, 5, 6),
+    ('\u{1D4D6}', Status::Mapped, 6, 7),
+    ('\u{1D4D7}', Status::Mapped, 7, 8),
+    ('\u{1D4D8}', Status::Mapped, 8, 9),
+    ('\u{1D4D9}', Status::Mapped, 9, 10),
+    ('\u{1D4DA}', Status::Mapped, 10, 11),
+    ('\u{1D4DB}', Status::Mapped, 11, 12),
+    ('\u{1D4DC}', Status::Mapped, 12, 13),
+    ('\u{1D4DD}', Status::Mapped, 13, 14),
+    ('\u{1D4DE}', Status::Mapped, 14, 15),
+    ('\u{1D4DF}', Status::Mapped, 15, 16),
+    ('\u{1D4E0}', Status::Mapped, 16, 17),
+    ('\u{1D4E1}', Status::Mapped, 17, 18),
+    ('\u{1D4E2}', Status::Mapped, 18, 19),
+    ('\u{1D4E3}', Status::Mapped, 19, 20),
+    ('\u{1D4E4}', Status::Mapped, 20, 21),
+    ('\u{1D4E5}', Status::Mapped, 21, 22),
+    ('\u{1D4E6}', Status::Mapped, 22, 23),
+    ('\u{1D4E7}', Status::Mapped, 23, 24),
+    ('\u{1D4E8}', Status::Mapped, 24, 25),
+    ('\u{1D4E9}', Status::Mapped, 25, 26),
+    ('\u{1D4EA}', Status::Mapped, 0, 1),
+    ('\u{1D4EB}', Status::Mapped, 1, 2),
+    ('\u{1D4EC}', Status::Mapped, 2, 3),
+    ('\u{1D4ED}', Status::Mapped, 3, 4),
+    ('\u{1D4EE}', Status::Mapped, 4, 5),
+    ('\u{1D4EF}', Status::Mapped, 5, 6),
+    ('\u{1D4F0}', Status::Mapped, 6, 7),
+    ('\u{1D4F1}', Status::Mapped, 7, 8),
+    ('\u{1D4F2}', Status::Mapped, 8, 9),
+    ('\u{1D4F3}', Status::Mapped, 9, 10),
+    ('\u{1D4F4}', Status::Mapped, 10, 11),
+    ('\u{1D4F5}', Status::Mapped, 11, 12),
+    ('\u{1D4F6}', Status::Mapped, 12, 13),
+    ('\u{1D4F7}', Status::Mapped, 13, 14),
+    ('\u{1D4F8}', Status::Mapped, 14, 15),
+    ('\u{1D4F9}', Status::Mapped, 15, 16),
+    ('\u{1D4FA}', Status::Mapped, 16, 17),
+    ('\u{1D4FB}', Status::Mapped, 17, 18),
+    ('\u{1D4FC}', Status::Mapped, 18, 19),
+    ('\u{1D4FD}', Status::Mapped, 19, 20),
+    ('\u{1D4FE}', Status::Mapped, 20, 21),
+    ('\u{1D4FF}', Status::Mapped, 21, 22),
+    ('\u{1D500}', Status::Mapped, 22, 23),
+    ('\u{1D501}', Status::Mapped, 23, 24),
+    ('\u{1D502}', Status::Mapped, 24, 25),
+    ('\u{1D503}', Status::Mapped, 25, 26),
+    ('\u{1D504}', Status::Mapped, 0, 1),
+    ('\u{1D505}', Status::Mapped, 1, 2),
+    ('\u{1D506}', Status::Disallowed, 0, 0),
+    ('\u{1D507}', Status::Mapped, 3, 4),
+    ('\u{1D508}', Status::Mapped, 4, 5),
+    ('\u{1D509}', Status::Mapped, 5, 6),
+    ('\u{1D50A}', Status::Mapped, 6, 7),
+    ('\u{1D50B}', Status::Disallowed, 0, 0),
+    ('\u{1D50D}', Status::Mapped, 9, 10),
+    ('\u{1D50E}', Status::Mapped, 10, 11),
+    ('\u{1D50F}', Status::Mapped, 11, 12),
+    ('\u{1D510}', Status::Mapped, 12, 13),
+    ('\u{1D511}', Status::Mapped, 13, 14),
+    ('\u{1D512}', Status::Mapped, 14, 15),
+    ('\u{1D513}', Status::Mapped, 15, 16),
+    ('\u{1D514}', Status::Mapped, 16, 17),
+    ('\u{1D515}', Status::Disallowed, 0, 0),
+    ('\u{1D516}', Status::Mapped, 18, 19),
+    ('\u{1D517}', Status::Mapped, 19, 20),
+    ('\u{1D518}', Status::Mapped, 20, 21),
+    ('\u{1D519}', Status::Mapped, 21, 22),
+    ('\u{1D51A}', Status::Mapped, 22, 23),
+    ('\u{1D51B}', Status::Mapped, 23, 24),
+    ('\u{1D51C}', Status::Mapped, 24, 25),
+    ('\u{1D51D}', Status::Disallowed, 0, 0),
+    ('\u{1D51E}', Status::Mapped, 0, 1),
+    ('\u{1D51F}', Status::Mapped, 1, 2),
+    ('\u{1D520}', Status::Mapped, 2, 3),
+    ('\u{1D521}', Status::Mapped, 3, 4),
+    ('\u{1D522}', Status::Mapped, 4, 5),
+    ('\u{1D523}', Status::Mapped, 5, 6),
+    ('\u{1D524}', Status::Mapped, 6, 7),
+    ('\u{1D525}', Status::Mapped, 7, 8),
+    ('\u{1D526}', Status::Mapped, 8, 9),
+    ('\u{1D527}', Status::Mapped, 9, 10),
+    ('\u{1D528}', Status::Mapped, 10, 11),
+    ('\u{1D529}', Status::Mapped, 11, 12),
+    ('\u{1D52A}', Status::Mapped, 12, 13),
+    ('\u{1D52B}', Status::Mapped, 13, 14),
+    ('\u{1D52C}', Status::Mapped, 14, 15),
+    ('\u{1D52D}', Status::Mapped, 15, 16),
+    ('\u{1D52E}', Status::Mapped, 16, 17),
+    ('\u{1D52F}', Status::Mapped, 17, 18),
+    ('\u{1D530}', Status::Mapped, 18, 19),
+    ('\u{1D531}', Status::Mapped, 19, 20),
+    ('\u{1D532}', Status::Mapped, 20, 21),
+    ('\u{1D533}', Status::Mapped, 21, 22),
+    ('\u{1D534}', Status::Mapped, 22, 23),
+    ('\u{1D535}', Status::Mapped, 23, 24),
+    ('\u{1D536}', Status::Mapped, 24, 25),
+    ('\u{1D537}', Status::Mapped, 25, 26),
+    ('\u{1D538}', Status::Mapped, 0, 1),
+    ('\u{1D539}', Status::Mapped, 1, 2),
+    ('\u{1D53A}', Status::Disallowed, 0, 0),
+    ('\u{1D53B}', Status::Mapped, 3, 4),
+    ('\u{1D53C}', Status::Mapped, 4, 5),
+    ('\u{1D53D}', Status::Mapped, 5, 6),
+    ('\u{1D53E}', Status::Mapped, 6, 7),
+    ('\u{1D53F}', Status::Disallowed, 0, 0),
+    ('\u{1D540}', Status::Mapped, 8, 9),
+    ('\u{1D541}', Status::Mapped, 9, 10),
+    ('\u{1D542}', Status::Mapped, 10, 11),
+    ('\u{1D543}', Status::Mapped, 11, 12),
+    ('\u{1D544}', Status::Mapped, 12, 13),
+    ('\u{1D545}', Status::Disallowed, 0, 0),
+    ('\u{1D546}', Status::Mapped, 14, 15),
+    ('\u{1D547}', Status::Disallowed, 0, 0),
+    ('\u{1D54A}', Status::Mapped, 18, 19),
+    ('\u{1D54B}', Status::Mapped, 19, 20),
+    ('\u{1D54C}', Status::Mapped, 20, 21),
+    ('\u{1D54D}', Status::Mapped, 21, 22),
+    ('\u{1D54E}', Status::Mapped, 22, 23),
+    ('\u{1D54F}', Status::Mapped, 23, 24),
+    ('\u{1D550}', Status::Mapped, 24, 25),
+    ('\u{1D551}', Status::Disallowed, 0, 0),
+    ('\u{1D552}', Status::Mapped, 0, 1),
+    ('\u{1D553}', Status::Mapped, 1, 2),
+    ('\u{1D554}', Status::Mapped, 2, 3),
+    ('\u{1D555}', Status::Mapped, 3, 4),
+    ('\u{1D556}', Status::Mapped, 4, 5),
+    ('\u{1D557}', Status::Mapped, 5, 6),
+    ('\u{1D558}', Status::Mapped, 6, 7),
+    ('\u{1D559}', Status::Mapped, 7, 8),
+    ('\u{1D55A}', Status::Mapped, 8, 9),
+    ('\u{1D55B}', Status::Mapped, 9, 10),
+    ('\u{1D55C}', Status::Mapped, 10, 11),
+    ('\u{1D55D}', Status::Mapped, 11, 12),
+    ('\u{1D55E}', Status::Mapped, 12, 13),
+    ('\u{1D55F}', Status::Mapped, 13, 14),
+    ('\u{1D560}', Status::Mapped, 14, 15),
+    ('\u{1D561}', Status::Mapped, 15, 16),
+    ('\u{1D562}', Status::Mapped, 16, 17),
+    ('\u{1D563}', Status::Mapped, 17, 18),
+    ('\u{1D564}', Status::Mapped, 18, 19),
+    ('\u{1D565}', Status::Mapped, 19, 20),
+    ('\u{1D566}', Status::Mapped, 20, 21),
+    ('\u{1D567}', Status::Mapped, 21, 22),
+    ('\u{1D568}', Status::Mapped, 22, 23),
+    ('\u{1D569}', Status::Mapped, 23, 24),
+    ('\u{1D56A}', Status::Mapped, 24, 25),
+    ('\u{1D56B}', Status::Mapped, 25, 26),
+    ('\u{1D56C}', Status::Mapped, 0, 1),
+    ('\u{1D56D}', Status::Mapped, 1, 2),
+    ('\u{1D56E}', Status::Mapped, 2, 3),
+    ('\u{1D56F}', Status::Mapped, 3, 4),
+    ('\u{1D570}', Status::Mapped, 4, 5),
+    ('\u{1D571}', Status::Mapped, 5, 6),
+    ('\u{1D572}', Status::Mapped, 6, 7),
+    ('\u{1D573}', Status::Mapped, 7, 8),
+    ('\u{1D574}', Status::Mapped, 8, 9),
+    ('\u{1D575}', Status::Mapped, 9, 10),
+    ('\u{1D576}', Status::Mapped, 10, 11),
+    ('\u{1D577}', Status::Mapped, 11, 12),
+    ('\u{1D578}', Status::Mapped, 12, 13),
+    ('\u{1D579}', Status::Mapped, 13, 14),
+    ('\u{1D57A}', Status::Mapped, 14, 15),
+    ('\u{1D57B}', Status::Mapped, 15, 16),
+    ('\u{1D57C}', Status::Mapped, 16, 17),
+    ('\u{1D57D}', Status::Mapped, 17, 18),
+    ('\u{1D57E}', Status::Mapped, 18, 19),
+    ('\u{1D57F}', Status::Mapped, 19, 20),
+    ('\u{1D580}', Status::Mapped, 20, 21),
+    ('\u{1D581}', Status::Mapped, 21, 22),
+    ('\u{1D582}', Status::Mapped, 22, 23),
+    ('\u{1D583}', Status::Mapped, 23, 24),
+    ('\u{1D584}', Status::Mapped, 24, 25),
+    ('\u{1D585}', Status::Mapped, 25, 26),
+    ('\u{1D586}', Status::Mapped, 0, 1),
+    ('\u{1D587}', Status::Mapped, 1, 2),
+    ('\u{1D588}', Status::Mapped, 2, 3),
+    ('\u{1D589}', Status::Mapped, 3, 4),
+    ('\u{1D58A}', Status::Mapped, 4, 5),
+    ('\u{1D58B}', Status::Mapped, 5, 6),
+    ('\u{1D58C}', Status::Mapped, 6, 7),
+    ('\u{1D58D}', Status::Mapped, 7, 8),
+    ('\u{1D58E}', Status::Mapped, 8, 9),
+    ('\u{1D58F}', Status::Mapped, 9, 10),
+    ('\u{1D590}', Status::Mapped, 10, 11),
+    ('\u{1D591}', Status::Mapped, 11, 12),
+    ('\u{1D592}', Status::Mapped, 12, 13),
+    ('\u{1D593}', Status::Mapped, 13, 14),
+    ('\u{1D594}', Status::Mapped, 14, 15),
+    ('\u{1D595}', Status::Mapped, 15, 16),
+    ('\u{1D596}', Status::Mapped, 16, 17),
+    ('\u{1D597}', Status::Mapped, 17, 18),
+    ('\u{1D598}', Status::Mapped, 18, 19),
+    ('\u{1D599}', Status::Mapped, 19, 20),
+    ('\u{1D59A}', Status::Mapped, 20, 21),
+    ('\u{1D59B}', Status::Mapped, 21, 22),
+    ('\u{1D59C}', Status::Mapped, 22, 23),
+    ('\u{1D59D}', Status::Mapped, 23, 24),
+    ('\u{1D59E}', Status::Mapped, 24, 25),
+    ('\u{1D59F}', Status::Mapped, 25, 26),
+    ('\u{1D5A0}', Status::Mapped, 0, 1),
+    ('\u{1D5A1}', Status::Mapped, 1, 2),
+    ('\u{1D5A2}', Status::Mapped, 2, 3),
+    ('\u{1D5A3}', Status::Mapped, 3, 4),
+    ('\u{1D5A4}', Status::Mapped, 4, 5),
+    ('\u{1D5A5}', Status::Mapped, 5, 6),
+    ('\u{1D5A6}', Status::Mapped, 6, 7),
+    ('\u{1D5A7}', Status::Mapped, 7, 8),
+    ('\u{1D5A8}', Status::Mapped, 8, 9),
+    ('\u{1D5A9}', Status::Mapped, 9, 10),
+    ('\u{1D5AA}', Status::Mapped, 10, 11),
+    ('\u{1D5AB}', Status::Mapped, 11, 12),
+    ('\u{1D5AC}', Status::Mapped, 12, 13),
+    ('\u{1D5AD}', Status::Mapped, 13, 14),
+    ('\u{1D5AE}', Status::Mapped, 14, 15),
+    ('\u{1D5AF}', Status::Mapped, 15, 16),
+    ('\u{1D5B0}', Status::Mapped, 16, 17),
+    ('\u{1D5B1}', Status::Mapped, 17, 18),
+    ('\u{1D5B2}', Status::Mapped, 18, 19),
+    ('\u{1D5B3}', Status::Mapped, 19, 20),
+    ('\u{1D5B4}', Status::Mapped, 20, 21),
+    ('\u{1D5B5}', Status::Mapped, 21, 22),
+    ('\u{1D5B6}', Status::Mapped, 22, 23),
+    ('\u{1D5B7}', Status::Mapped, 23, 24),
+    ('\u{1D5B8}', Status::Mapped, 24, 25),
+    ('\u{1D5B9}', Status::Mapped, 25, 26),
+    ('\u{1D5BA}', Status::Mapped, 0, 1),
+    ('\u{1D5BB}', Status::Mapped, 1, 2),
+    ('\u{1D5BC}', Status::Mapped, 2, 3),
+    ('\u{1D5BD}', Status::Mapped, 3, 4),
+    ('\u{1D5BE}', Status::Mapped, 4, 5),
+    ('\u{1D5BF}', Status::Mapped, 5, 6),
+    ('\u{1D5C0}', Status::Mapped, 6, 7),
+    ('\u{1D5C1}', Status::Mapped, 7, 8),
+    ('\u{1D5C2}', Status::Mapped, 8, 9),
+    ('\u{1D5C3}', Status::Mapped, 9, 10),
+    ('\u{1D5C4}', Status::Mapped, 10, 11),
+    ('\u{1D5C5}', Status::Mapped, 11, 12),
+    ('\u{1D5C6}', Status::Mapped, 12, 13),
+    ('\u{1D5C7}', Status::Mapped, 13, 14),
+    ('\u{1D5C8}', Status::Mapped, 14, 15),
+    ('\u{1D5C9}', Status::Mapped, 15, 16),
+    ('\u{1D5CA}', Status::Mapped, 16, 17),
+    ('\u{1D5CB}', Status::Mapped, 17, 18),
+    ('\u{1D5CC}', Status::Mapped, 18, 19),
+    ('\u{1D5CD}', Status::Mapped, 19, 20),
+    ('\u{1D5CE}', Status::Mapped, 20, 21),
+    ('\u{1D5CF}', Status::Mapped, 21, 22),
+    ('\u{1D5D0}', Status::Mapped, 22, 23),
+    ('\u{1D5D1}', Status::Mapped, 23, 24),
+    ('\u{1D5D2}', Status::Mapped, 24, 25),
+    ('\u{1D5D3}', Status::Mapped, 25, 26),
+    ('\u{1D5D4}', Status::Mapped, 0, 1),
+    ('\u{1D5D5}', Status::Mapped, 1, 2),
+    ('\u{1D5D6}', Status::Mapped, 2, 3),
+    ('\u{1D5D7}', Status::Mapped, 3, 4),
+    ('\u{1D5D8}', Status::Mapped, 4, 5),
+    ('\u{1D5D9}', Status::Mapped, 5, 6),
+    ('\u{1D5DA}', Status::Mapped, 6, 7),
+    ('\u{1D5DB}', Status::Mapped, 7, 8),
+    ('\u{1D5DC}', Status::Mapped, 8, 9),
+    ('\u{1D5DD}', Status::Mapped, 9, 10),
+    ('\u{1D5DE}', Status::Mapped, 10, 11),
+    ('\u{1D5DF}', Status::Mapped, 11, 12),
+    ('\u{1D5E0}', Status::Mapped, 12, 13),
+    ('\u{1D5E1}', Status::Mapped, 13, 14),
+    ('\u{1D5E2}', Status::Mapped, 14, 15),
+    ('\u{1D5E3}', Status::Mapped, 15, 16),
+    ('\u{1D5E4}', Status::Mapped, 16, 17),
+    ('\u{1D5E5}', Status::Mapped, 17, 18),
+    ('\u{1D5E6}', Status::Mapped, 18, 19),
+    ('\u{1D5E7}', Status::Mapped, 19, 20),
+    ('\u{1D5E8}', Status::Mapped, 20, 21),
+    ('\u{1D5E9}', Status::Mapped, 21, 22),
+    ('\u{1D5EA}', Status::Mapped, 22, 23),
+    ('\u{1D5EB}', Status::Mapped, 23, 24),
+    ('\u{1D5EC}', Status::Mapped, 24, 25),
+    ('\u{1D5ED}', Status::Mapped, 25, 26),
+    ('\u{1D5EE}', Status::Mapped, 0, 1),
+    ('\u{1D5EF}', Status::Mapped, 1, 2),
+    ('\u{1D5F0}', Status::Mapped, 2, 3),
+    ('\u{1D5F1}', Status::Mapped, 3, 4),
+    ('\u{1D5F2}', Status::Mapped, 4, 5),
+    ('\u{1D5F3}', Status::Mapped, 5, 6),
+    ('\u{1D5F4}', Status::Mapped, 6, 7),
+    ('\u{1D5F5}', Status::Mapped, 7, 8),
+    ('\u{1D5F6}', Status::Mapped, 8, 9),
+    ('\u{1D5F7}', Status::Mapped, 9, 10),
+    ('\u{1D5F8}', Status::Mapped, 10, 11),
+    ('\u{1D5F9}', Status::Mapped, 11, 12),
+    ('\u{1D5FA}', Status::Mapped, 12, 13),
+    ('\u{1D5FB}', Status::Mapped, 13, 14),
+    ('\u{1D5FC}', Status::Mapped, 14, 15),
+    ('\u{1D5FD}', Status::Mapped, 15, 16),
+    ('\u{1D5FE}', Status::Mapped, 16, 17),
+    ('\u{1D5FF}', Status::Mapped, 17, 18),
+    ('\u{1D600}', Status::Mapped, 18, 19),
+    ('\u{1D601}', Status::Mapped, 19, 20),
+    ('\u{1D602}', Status::Mapped, 20, 21),
+    ('\u{1D603}', Status::Mapped, 21, 22),
+    ('\u{1D604}', Status::Mapped, 22, 23),
+    ('\u{1D605}', Status::Mapped, 23, 24),
+    ('\u{1D606}', Status::Mapped, 24, 25),
+    ('\u{1D607}', Status::Mapped, 25, 26),
+    ('\u{1D608}', Status::Mapped, 0, 1),
+    ('\u{1D609}', Status::Mapped, 1, 2),
+    ('\u{1D60A}', Status::Mapped, 2, 3),
+    ('\u{1D60B}', Status::Mapped, 3, 4),
+    ('\u{1D60C}', Status::Mapped, 4, 5),
+    ('\u{1D60D}', Status::Mapped, 5, 6),
+    ('\u{1D60E}', Status::Mapped, 6, 7),
+    ('\u{1D60F}', Status::Mapped, 7, 8),
+    ('\u{1D610}', Status::Mapped, 8, 9),
+    ('\u{1D611}', Status::Mapped, 9, 10),
+    ('\u{1D612}', Status::Mapped, 10, 11),
+    ('\u{1D613}', Status::Mapped, 11, 12),
+    ('\u{1D614}', Status::Mapped, 12, 13),
+    ('\u{1D615}', Status::Mapped, 13, 14),
+    ('\u{1D616}', Status::Mapped, 14, 15),
+    ('\u{1D617}', Status::Mapped, 15, 16),
+    ('\u{1D618}', Status::Mapped, 16, 17),
+    ('\u{1D619}', Status::Mapped, 17, 18),
+    ('\u{1D61A}', Status::Mapped, 18, 19),
+    ('\u{1D61B}', Status::Mapped, 19, 20),
+    ('\u{1D61C}', Status::Mapped, 20, 21),
+    ('\u{1D61D}', Status::Mapped, 21, 22),
+    ('\u{1D61E}', Status::Mapped, 22, 23),
+    ('\u{1D61F}', Status::Mapped, 23, 24),
+    ('\u{1D620}', Status::Mapped, 24, 25),
+    ('\u{1D621}', Status::Mapped, 25, 26),
+    ('\u{1D622}', Status::Mapped, 0, 1),
+    ('\u{1D623}', Status::Mapped, 1, 2),
+    ('\u{1D624}', Status::Mapped, 2, 3),
+    ('\u{1D625}', Status::Mapped, 3, 4),
+    ('\u{1D626}', Status::Mapped, 4, 5),
+    ('\u{1D627}', Status::Mapped, 5, 6),
+    ('\u{1D628}', Status::Mapped, 6, 7),
+    ('\u{1D629}', Status::Mapped, 7, 8),
+    ('\u{1D62A}', Status::Mapped, 8, 9),
+    ('\u{1D62B}', Status::Mapped, 9, 10),
+    ('\u{1D62C}', Status::Mapped, 10, 11),
+    ('\u{1D62D}', Status::Mapped, 11, 12),
+    ('\u{1D62E}', Status::Mapped, 12, 13),
+    ('\u{1D62F}', Status::Mapped, 13, 14),
+    ('\u{1D630}', Status::Mapped, 14, 15),
+    ('\u{1D631}', Status::Mapped, 15, 16),
+    ('\u{1D632}', Status::Mapped, 16, 17),
+    ('\u{1D633}', Status::Mapped, 17, 18),
+    ('\u{1D634}', Status::Mapped, 18, 19),
+    ('\u{1D635}', Status::Mapped, 19, 20),
+    ('\u{1D636}', Status::Mapped, 20, 21),
+    ('\u{1D637}', Status::Mapped, 21, 22),
+    ('\u{1D638}', Status::Mapped, 22, 23),
+    ('\u{1D639}', Status::Mapped, 23, 24),
+    ('\u{1D63A}', Status::Mapped, 24, 25),
+    ('\u{1D63B}', Status::Mapped, 25, 26),
+    ('\u{1D63C}', Status::Mapped, 0, 1),
+    ('\u{1D63D}', Status::Mapped, 1, 2),
+    ('\u{1D63E}', Status::Mapped, 2, 3),
+    ('\u{1D63F}', Status::Mapped, 3, 4),
+    ('\u{1D640}', Status::Mapped, 4, 5),
+    ('\u{1D641}', Status::Mapped, 5, 6),
+    ('\u{1D642}', Status::Mapped, 6, 7),
+    ('\u{1D643}', Status::Mapped, 7, 8),
+    ('\u{1D644}', Status::Mapped, 8, 9),
+    ('\u{1D645}', Status::Mapped, 9, 10),
+    ('\u{1D646}', Status::Mapped, 10, 11),
+    ('\u{1D647}', Status::Mapped, 11, 12),
+    ('\u{1D648}', Status::Mapped, 12, 13),
+    ('\u{1D649}', Status::Mapped, 13, 14),
+    ('\u{1D64A}', Status::Mapped, 14, 15),
+    ('\u{1D64B}', Status::Mapped, 15, 16),
+    ('\u{1D64C}', Status::Mapped, 16, 17),
+    ('\u{1D64D}', Status::Mapped, 17, 18),
+    ('\u{1D64E}', Status::Mapped, 18, 19),
+    ('\u{1D64F}', Status::Mapped, 19, 20),
+    ('\u{1D650}', Status::Mapped, 20, 21),
+    ('\u{1D651}', Status::Mapped, 21, 22),
+    ('\u{1D652}', Status::Mapped, 22, 23),
+    ('\u{1D653}', Status::Mapped, 23, 24),
+    ('\u{1D654}', Status::Mapped, 24, 25),
+    ('\u{1D655}', Status::Mapped, 25, 26),
+    ('\u{1D656}', Status::Mapped, 0, 1),
+    ('\u{1D657}', Status::Mapped, 1, 2),
+    ('\u{1D658}', Status::Mapped, 2, 3),
+    ('\u{1D659}', Status::Mapped, 3, 4),
+    ('\u{1D65A}', Status::Mapped, 4, 5),
+    ('\u{1D65B}', Status::Mapped, 5, 6),
+    ('\u{1D65C}', Status::Mapped, 6, 7),
+    ('\u{1D65D}', Status::Mapped, 7, 8),
+    ('\u{1D65E}', Status::Mapped, 8, 9),
+    ('\u{1D65F}', Status::Mapped, 9, 10),
+    ('\u{1D660}', Status::Mapped, 10, 11),
+    ('\u{1D661}', Status::Mapped, 11, 12),
+    ('\u{1D662}', Status::Mapped, 12, 13),
+    ('\u{1D663}', Status::Mapped, 13, 14),
+    ('\u{1D664}', Status::Mapped, 14, 15),
+    ('\u{1D665}', Status::Mapped, 15, 16),
+    ('\u{1D666}', Status::Mapped, 16, 17),
+    ('\u{1D667}', Status::Mapped, 17, 18),
+    ('\u{1D668}', Status::Mapped, 18, 19),
+    ('\u{1D669}', Status::Mapped, 19, 20),
+    ('\u{1D66A}', Status::Mapped, 20, 21),
+    ('\u{1D66B}', Status::Mapped, 21, 22),
+    ('\u{1D66C}', Status::Mapped, 22, 23),
+    ('\u{1D66D}', Status::Mapped, 23, 24),
+    ('\u{1D66E}', Status::Mapped, 24, 25),
+    ('\u{1D66F}', Status::Mapped, 25, 26),
+    ('\u{1D670}', Status::Mapped, 0, 1),
+    ('\u{1D671}', Status::Mapped, 1, 2),
+    ('\u{1D672}', Status::Mapped, 2, 3),
+    ('\u{1D673}', Status::Mapped, 3, 4),
+    ('\u{1D674}', Status::Mapped, 4, 5),
+    ('\u{1D675}', Status::Mapped, 5, 6),
+    ('\u{1D676}', Status::Mapped, 6, 7),
+    ('\u{1D677}', Status::Mapped, 7, 8),
+    ('\u{1D678}', Status::Mapped, 8, 9),
+    ('\u{1D679}', Status::Mapped, 9, 10),
+    ('\u{1D67A}', Status::Mapped, 10, 11),
+    ('\u{1D67B}', Status::Mapped, 11, 12),
+    ('\u{1D67C}', Status::Mapped, 12, 13),
+    ('\u{1D67D}', Status::Mapped, 13, 14),
+    ('\u{1D67E}', Status::Mapped, 14, 15),
+    ('\u{1D67F}', Status::Mapped, 15, 16),
+    ('\u{1D680}', Status::Mapped, 16, 17),
+    ('\u{1D681}', Status::Mapped, 17, 18),
+    ('\u{1D682}', Status::Mapped, 18, 19),
+    ('\u{1D683}', Status::Mapped, 19, 20),
+    ('\u{1D684}', Status::Mapped, 20, 21),
+    ('\u{1D685}', Status::Mapped, 21, 22),
+    ('\u{1D686}', Status::Mapped, 22, 23),
+    ('\u{1D687}', Status::Mapped, 23, 24),
+    ('\u{1D688}', Status::Mapped, 24, 25),
+    ('\u{1D689}', Status::Mapped, 25, 26),
+    ('\u{1D68A}', Status::Mapped, 0, 1),
+    ('\u{1D68B}', Status::Mapped, 1, 2),
+    ('\u{1D68C}', Status::Mapped, 2, 3),
+    ('\u{1D68D}', Status::Mapped, 3, 4),
+    ('\u{1D68E}', Status::Mapped, 4, 5),
+    ('\u{1D68F}', Status::Mapped, 5, 6),
+    ('\u{1D690}', Status::Mapped, 6, 7),
+    ('\u{1D691}', Status::Mapped, 7, 8),
+    ('\u{1D692}', Status::Mapped, 8, 9),
+    ('\u{1D693}', Status::Mapped, 9, 10),
+    ('\u{1D694}', Status::Mapped, 10, 11),
+    ('\u{1D695}', Status::Mapped, 11, 12),
+    ('\u{1D696}', Status::Mapped, 12, 13),
+    ('\u{1D697}', Status::Mapped, 13, 14),
+    ('\u{1D698}', Status::Mapped, 14, 15),
+    ('\u{1D699}', Status::Mapped, 15, 16),
+    ('\u{1D69A}', Status::Mapped, 16, 17),
+    ('\u{1D69B}', Status::Mapped, 17, 18),
+    ('\u{1D69C}', Status::Mapped, 18, 19),
+    ('\u{1D69D}', Status::Mapped, 19, 20),
+    ('\u{1D69E}', Status::Mapped, 20, 21),
+    ('\u{1D69F}', Status::Mapped, 21, 22),
+    ('\u{1D6A0}', Status::Mapped, 22, 23),
+    ('\u{1D6A1}', Status::Mapped, 23, 24),
+    ('\u{1D6A2}', Status::Mapped, 24, 25),
+    ('\u{1D6A3}', Status::Mapped, 25, 26),
+    ('\u{1D6A4}', Status::Mapped, 11213, 11215),
+    ('\u{1D6A5}', Status::Mapped, 11215, 11217),
+    ('\u{1D6A6}', Status::Disallowed, 0, 0),
+    ('\u{1D6A8}', Status::Mapped, 534, 536),
+    ('\u{1D6A9}', Status::Mapped, 536, 538),
+    ('\u{1D6AA}', Status::Mapped, 538, 540),
+    ('\u{1D6AB}', Status::Mapped, 540, 542),
+    ('\u{1D6AC}', Status::Mapped, 542, 544),
+    ('\u{1D6AD}', Status::Mapped, 544, 546),
+    ('\u{1D6AE}', Status::Mapped, 546, 548),
+    ('\u{1D6AF}', Status::Mapped, 548, 550),
+    ('\u{1D6B0}', Status::Mapped, 497, 499),
+    ('\u{1D6B1}', Status::Mapped, 550, 552),
+    ('\u{1D6B2}', Status::Mapped, 552, 554),
+    ('\u{1D6B3}', Status::Mapped, 38, 40),
+    ('\u{1D6B4}', Status::Mapped, 554, 556),
+    ('\u{1D6B5}', Status::Mapped, 556, 558),
+    ('\u{1D6B6}', Status::Mapped, 558, 560),
+    ('\u{1D6B7}', Status::Mapped, 560, 562),
+    ('\u{1D6B8}', Status::Mapped, 562, 564),
+    ('\u{1D6B9}', Status::Mapped, 548, 550),
+    ('\u{1D6BA}', Status::Mapped, 564, 566),
+    ('\u{1D6BB}', Status::Mapped, 566, 568),
+    ('\u{1D6BC}', Status::Mapped, 568, 570),
+    ('\u{1D6BD}', Status::Mapped, 570, 572),
+    ('\u{1D6BE}', Status::Mapped, 572, 574),
+    ('\u{1D6BF}', Status::Mapped, 574, 576),
+    ('\u{1D6C0}', Status::Mapped, 576, 578),
+    ('\u{1D6C1}', Status::Mapped, 11217, 11220),
+    ('\u{1D6C2}', Status::Mapped, 534, 536),
+    ('\u{1D6C3}', Status::Mapped, 536, 538),
+    ('\u{1D6C4}', Status::Mapped, 538, 540),
+    ('\u{1D6C5}', Status::Mapped, 540, 542),
+    ('\u{1D6C6}', Status::Mapped, 542, 544),
+    ('\u{1D6C7}', Status::Mapped, 544, 546),
+    ('\u{1D6C8}', Status::Mapped, 546, 548),
+    ('\u{1D6C9}', Status::Mapped, 548, 550),
+    ('\u{1D6CA}', Status::Mapped, 497, 499),
+    ('\u{1D6CB}', Status::Mapped, 550, 552),
+    ('\u{1D6CC}', Status::Mapped, 552, 554),
+    ('\u{1D6CD}', Status::Mapped, 38, 40),
+    ('\u{1D6CE}', Status::Mapped, 554, 556),
+    ('\u{1D6CF}', Status::Mapped, 556, 558),
+    ('\u{1D6D0}', Status::Mapped, 558, 560),
+    ('\u{1D6D1}', Status::Mapped, 560, 562),
+    ('\u{1D6D2}', Status::Mapped, 562, 564),
+    ('\u{1D6D3}', Status::Mapped, 564, 566),
+    ('\u{1D6D5}', Status::Mapped, 566, 568),
+    ('\u{1D6D6}', Status::Mapped, 568, 570),
+    ('\u{1D6D7}', Status::Mapped, 570, 572),
+    ('\u{1D6D8}', Status::Mapped, 572, 574),
+    ('\u{1D6D9}', Status::Mapped, 574, 576),
+    ('\u{1D6DA}', Status::Mapped, 576, 578),
+    ('\u{1D6DB}', Status::Mapped, 11220, 11223),
+    ('\u{1D6DC}', Status::Mapped, 542, 544),
+    ('\u{1D6DD}', Status::Mapped, 548, 550),
+    ('\u{1D6DE}', Status::Mapped, 550, 552),
+    ('\u{1D6DF}', Status::Mapped, 570, 572),
+    ('\u{1D6E0}', Status::Mapped, 562, 564),
+    ('\u{1D6E1}', Status::Mapped, 560, 562),
+    ('\u{1D6E2}', Status::Mapped, 534, 536),
+    ('\u{1D6E3}', Status::Mapped, 536, 538),
+    ('\u{1D6E4}', Status::Mapped, 538, 540),
+    ('\u{1D6E5}', Status::Mapped, 540, 542),
+    ('\u{1D6E6}', Status::Mapped, 542, 544),
+    ('\u{1D6E7}', Status::Mapped, 544, 546),
+    ('\u{1D6E8}', Status::Mapped, 546, 548),
+    ('\u{1D6E9}', Status::Mapped, 548, 550),
+    ('\u{1D6EA}', Status::Mapped, 497, 499),
+    ('\u{1D6EB}', Status::Mapped, 550, 552),
+    ('\u{1D6EC}', Status::Mapped, 552, 554),
+    ('\u{1D6ED}', Status::Mapped, 38, 40),
+    ('\u{1D6EE}', Status::Mapped, 554, 556),
+    ('\u{1D6EF}', Status::Mapped, 556, 558),
+    ('\u{1D6F0}', Status::Mapped, 558, 560),
+    ('\u{1D6F1}', Status::Mapped, 560, 562),
+    ('\u{1D6F2}', Status::Mapped, 562, 564),
+    ('\u{1D6F3}', Status::Mapped, 548, 550),
+    ('\u{1D6F4}', Status::Mapped, 564, 566),
+    ('\u{1D6F5}', Status::Mapped, 566, 568),
+    ('\u{1D6F6}', Status::Mapped, 568, 570),
+    ('\u{1D6F7}', Status::Mapped, 570, 572),
+    ('\u{1D6F8}', Status::Mapped, 572, 574),
+    ('\u{1D6F9}', Status::Mapped, 574, 576),
+    ('\u{1D6FA}', Status::Mapped, 576, 578),
+    ('\u{1D6FB}', Status::Mapped, 11217, 11220),
+    ('\u{1D6FC}', Status::Mapped, 534, 536),
+    ('\u{1D6FD}', Status::Mapped, 536, 538),
+    ('\u{1D6FE}', Status::Mapped, 538, 540),
+    ('\u{1D6FF}', Status::Mapped, 540, 542),
+    ('\u{1D700}', Status::Mapped, 542, 544),
+    ('\u{1D701}', Status::Mapped, 544, 546),
+    ('\u{1D702}', Status::Mapped, 546, 548),
+    ('\u{1D703}', Status::Mapped, 548, 550),
+    ('\u{1D704}', Status::Mapped, 497, 499),
+    ('\u{1D705}', Status::Mapped, 550, 552),
+    ('\u{1D706}', Status::Mapped, 552, 554),
+    ('\u{1D707}', Status::Mapped, 38, 40),
+    ('\u{1D708}', Status::Mapped, 554, 556),
+    ('\u{1D709}', Status::Mapped, 556, 558),
+    ('\u{1D70A}', Status::Mapped, 558, 560),
+    ('\u{1D70B}', Status::Mapped, 560, 562),
+    ('\u{1D70C}', Status::Mapped, 562, 564),
+    ('\u{1D70D}', Status::Mapped, 564, 566),
+    ('\u{1D70F}', Status::Mapped, 566, 568),
+    ('\u{1D710}', Status::Mapped, 568, 570),
+    ('\u{1D711}', Status::Mapped, 570, 572),
+    ('\u{1D712}', Status::Mapped, 572, 574),
+    ('\u{1D713}', Status::Mapped, 574, 576),
+    ('\u{1D714}', Status::Mapped, 576, 578),
+    ('\u{1D715}', Status::Mapped, 11220, 11223),
+    ('\u{1D716}', Status::Mapped, 542, 544),
+    ('\u{1D717}', Status::Mapped, 548, 550),
+    ('\u{1D718}', Status::Mapped, 550, 552),
+    ('\u{1D719}', Status::Mapped, 570, 572),
+    ('\u{1D71A}', Status::Mapped, 562, 564),
+    ('\u{1D71B}', Status::Mapped, 560, 562),
+    ('\u{1D71C}', Status::Mapped, 534, 536),
+    ('\u{1D71D}', Status::Mapped, 536, 538),
+    ('\u{1D71E}', Status::Mapped, 538, 540),
+    ('\u{1D71F}', Status::Mapped, 540, 542),
+    ('\u{1D720}', Status::Mapped, 542, 544),
+    ('\u{1D721}', Status::Mapped, 544, 546),
+    ('\u{1D722}', Status::Mapped, 546, 548),
+    ('\u{1D723}', Status::Mapped, 548, 550),
+    ('\u{1D724}', Status::Mapped, 497, 499),
+    ('\u{1D725}', Status::Mapped, 550, 552),
+    ('\u{1D726}', Status::Mapped, 552, 554),
+    ('\u{1D727}', Status::Mapped, 38, 40),
+    ('\u{1D728}', Status::Mapped, 554, 556),
+    ('\u{1D729}', Status::Mapped, 556, 558),
+    ('\u{1D72A}', Status::Mapped, 558, 560),
+    ('\u{1D72B}', Status::Mapped, 560, 562),
+    ('\u{1D72C}', Status::Mapped, 562, 564),
+    ('\u{1D72D}', Status::Mapped, 548, 550),
+    ('\u{1D72E}', Status::Mapped, 564, 566),
+    ('\u{1D72F}', Status::Mapped, 566, 568),
+    ('\u{1D730}', Status::Mapped, 568, 570),
+    ('\u{1D731}', Status::Mapped, 570, 572),
+    ('\u{1D732}', Status::Mapped, 572, 574),
+    ('\u{1D733}', Status::Mapped, 574, 576),
+    ('\u{1D734}', Status::Mapped, 576, 578),
+    ('\u{1D735}', Status::Mapped, 11217, 11220),
+    ('\u{1D736}', Status::Mapped, 534, 536),
+    ('\u{1D737}', Status::Mapped, 536, 538),
+    ('\u{1D738}', Status::Mapped, 538, 540),
+    ('\u{1D739}', Status::Mapped, 540, 542),
+    ('\u{1D73A}', Status::Mapped, 542, 544),
+    ('\u{1D73B}', Status::Mapped, 544, 546),
+    ('\u{1D73C}', Status::Mapped, 546, 548),
+    ('\u{1D73D}', Status::Mapped, 548, 550),
+    ('\u{1D73E}', Status::Mapped, 497, 499),
+    ('\u{1D73F}', Status::Mapped, 550, 552),
+    ('\u{1D740}', Status::Mapped, 552, 554),
+    ('\u{1D741}', Status::Mapped, 38, 40),
+    ('\u{1D742}', Status::Mapped, 554, 556),
+    ('\u{1D743}', Status::Mapped, 556, 558),
+    ('\u{1D744}', Status::Mapped, 558, 560),
+    ('\u{1D745}', Status::Mapped, 560, 562),
+    ('\u{1D746}', Status::Mapped, 562, 564),
+    ('\u{1D747}', Status::Mapped, 564, 566),
+    ('\u{1D749}', Status::Mapped, 566, 568),
+    ('\u{1D74A}', Status::Mapped, 568, 570),
+    ('\u{1D74B}', Status::Mapped, 570, 572),
+    ('\u{1D74C}', Status::Mapped, 572, 574),
+    ('\u{1D74D}', Status::Mapped, 574, 576),
+    ('\u{1D74E}', Status::Mapped, 576, 578),
+    ('\u{1D74F}', Status::Mapped, 11220, 11223),
+    ('\u{1D750}', Status::Mapped, 542, 544),
+    ('\u{1D751}', Status::Mapped, 548, 550),
+    ('\u{1D752}', Status::Mapped, 550, 552),
+    ('\u{1D753}', Status::Mapped, 570, 572),
+    ('\u{1D754}', Status::Mapped, 562, 564),
+    ('\u{1D755}', Status::Mapped, 560, 562),
+    ('\u{1D756}', Status::Mapped, 534, 536),
+    ('\u{1D757}', Status::Mapped, 536, 538),
+    ('\u{1D758}', Status::Mapped, 538, 540),
+    ('\u{1D759}', Status::Mapped, 540, 542),
+    ('\u{1D75A}', Status::Mapped, 542, 544),
+    ('\u{1D75B}', Status::Mapped, 544, 546),
+    ('\u{1D75C}', Status::Mapped, 546, 548),
+    ('\u{1D75D}', Status::Mapped, 548, 550),
+    ('\u{1D75E}', Status::Mapped, 497, 499),
+    ('\u{1D75F}', Status::Mapped, 550, 552),
+    ('\u{1D760}', Status::Mapped, 552, 554),
+    ('\u{1D761}', Status::Mapped, 38, 40),
+    ('\u{1D762}', Status::Mapped, 554, 556),
+    ('\u{1D763}', Status::Mapped, 556, 558),
+    ('\u{1D764}', Status::Mapped, 558, 560),
+    ('\u{1D765}', Status::Mapped, 560, 562),
+    ('\u{1D766}', Status::Mapped, 562, 564),
+    ('\u{1D767}', Status::Mapped, 548, 550),
+    ('\u{1D768}', Status::Mapped, 564, 566),
+    ('\u{1D769}', Status::Mapped, 566, 568),
+    ('\u{1D76A}', Status::Mapped, 568, 570),
+    ('\u{1D76B}', Status::Mapped, 570, 572),
+    ('\u{1D76C}', Status::Mapped, 572, 574),
+    ('\u{1D76D}', Status::Mapped, 574, 576),
+    ('\u{1D76E}', Status::Mapped, 576, 578),
+    ('\u{1D76F}', Status::Mapped, 11217, 11220),
+    ('\u{1D770}', Status::Mapped, 534, 536),
+    ('\u{1D771}', Status::Mapped, 536, 538),
+    ('\u{1D772}', Status::Mapped, 538, 540),
+    ('\u{1D773}', Status::Mapped, 540, 542),
+    ('\u{1D774}', Status::Mapped, 542, 544),
+    ('\u{1D775}', Status::Mapped, 544, 546),
+    ('\u{1D776}', Status::Mapped, 546, 548),
+    ('\u{1D777}', Status::Mapped, 548, 550),
+    ('\u{1D778}', Status::Mapped, 497, 499),
+    ('\u{1D779}', Status::Mapped, 550, 552),
+    ('\u{1D77A}', Status::Mapped, 552, 554),
+    ('\u{1D77B}', Status::Mapped, 38, 40),
+    ('\u{1D77C}', Status::Mapped, 554, 556),
+    ('\u{1D77D}', Status::Mapped, 556, 558),
+    ('\u{1D77E}', Status::Mapped, 558, 560),
+    ('\u{1D77F}', Status::Mapped, 560, 562),
+    ('\u{1D780}', Status::Mapped, 562, 564),
+    ('\u{1D781}', Status::Mapped, 564, 566),
+    ('\u{1D783}', Status::Mapped, 566, 568),
+    ('\u{1D784}', Status::Mapped, 568, 570),
+    ('\u{1D785}', Status::Mapped, 570, 572),
+    ('\u{1D786}', Status::Mapped, 572, 574),
+    ('\u{1D787}', Status::Mapped, 574, 576),
+    ('\u{1D788}', Status::Mapped, 576, 578),
+    ('\u{1D789}', Status::Mapped, 11220, 11223),
+    ('\u{1D78A}', Status::Mapped, 542, 544),
+    ('\u{1D78B}', Status::Mapped, 548, 550),
+    ('\u{1D78C}', Status::Mapped, 550, 552),
+    ('\u{1D78D}', Status::Mapped, 570, 572),
+    ('\u{1D78E}', Status::Mapped, 562, 564),
+    ('\u{1D78F}', Status::Mapped, 560, 562),
+    ('\u{1D790}', Status::Mapped, 534, 536),
+    ('\u{1D791}', Status::Mapped, 536, 538),
+    ('\u{1D792}', Status::Mapped, 538, 540),
+    ('\u{1D793}', Status::Mapped, 540, 542),
+    ('\u{1D794}', Status::Mapped, 542, 544),
+    ('\u{1D795}', Status::Mapped, 544, 546),
+    ('\u{1D796}', Status::Mapped, 546, 548),
+    ('\u{1D797}', Status::Mapped, 548, 550),
+    ('\u{1D798}', Status::Mapped, 497, 499),
+    ('\u{1D799}', Status::Mapped, 550, 552),
+    ('\u{1D79A}', Status::Mapped, 552, 554),
+    ('\u{1D79B}', Status::Mapped, 38, 40),
+    ('\u{1D79C}', Status::Mapped, 554, 556),
+    ('\u{1D79D}', Status::Mapped, 556, 558),
+    ('\u{1D79E}', Status::Mapped, 558, 560),
+    ('\u{1D79F}', Status::Mapped, 560, 562),
+    ('\u{1D7A0}', Status::Mapped, 562, 564),
+    ('\u{1D7A1}', Status::Mapped, 548, 550),
+    ('\u{1D7A2}', Status::Mapped, 564, 566),
+    ('\u{1D7A3}', Status::Mapped, 566, 568),
+    ('\u{1D7A4}', Status::Mapped, 568, 570),
+    ('\u{1D7A5}', Status::Mapped, 570, 572),
+    ('\u{1D7A6}', Status::Mapped, 572, 574),
+    ('\u{1D7A7}', Status::Mapped, 574, 576),
+    ('\u{1D7A8}', Status::Mapped, 576, 578),
+    ('\u{1D7A9}', Status::Mapped, 11217, 11220),
+    ('\u{1D7AA}', Status::Mapped, 534, 536),
+    ('\u{1D7AB}', Status::Mapped, 536, 538),
+    ('\u{1D7AC}', Status::Mapped, 538, 540),
+    ('\u{1D7AD}', Status::Mapped, 540, 542),
+    ('\u{1D7AE}', Status::Mapped, 542, 544),
+    ('\u{1D7AF}', Status::Mapped, 544, 546),
+    ('\u{1D7B0}', Status::Mapped, 546, 548),
+    ('\u{1D7B1}', Status::Mapped, 548, 550),
+    ('\u{1D7B2}', Status::Mapped, 497, 499),
+    ('\u{1D7B3}', Status::Mapped, 550, 552),
+    ('\u{1D7B4}', Status::Mapped, 552, 554),
+    ('\u{1D7B5}', Status::Mapped, 38, 40),
+    ('\u{1D7B6}', Status::Mapped, 554, 556),
+    ('\u{1D7B7}', Status::Mapped, 556, 558),
+    ('\u{1D7B8}', Status::Mapped, 558, 560),
+    ('\u{1D7B9}', Status::Mapped, 560, 562),
+    ('\u{1D7BA}', Status::Mapped, 562, 564),
+    ('\u{1D7BB}', Status::Mapped, 564, 566),
+    ('\u{1D7BD}', Status::Mapped, 566, 568),
+    ('\u{1D7BE}', Status::Mapped, 568, 570),
+    ('\u{1D7BF}', Status::Mapped, 570, 572),
+    ('\u{1D7C0}', Status::Mapped, 572, 574),
+    ('\u{1D7C1}', Status::Mapped, 574, 576),
+    ('\u{1D7C2}', Status::Mapped, 576, 578),
+    ('\u{1D7C3}', Status::Mapped, 11220, 11223),
+    ('\u{1D7C4}', Status::Mapped, 542, 544),
+    ('\u{1D7C5}', Status::Mapped, 548, 550),
+    ('\u{1D7C6}', Status::Mapped, 550, 552),
+    ('\u{1D7C7}', Status::Mapped, 570, 572),
+    ('\u{1D7C8}', Status::Mapped, 562, 564),
+    ('\u{1D7C9}', Status::Mapped, 560, 562),
+    ('\u{1D7CA}', Status::Mapped, 588, 590),
+    ('\u{1D7CC}', Status::Disallowed, 0, 0),
+    ('\u{1D7CE}', Status::Mapped, 2347, 2348),
+    ('\u{1D7CF}', Status::Mapped, 43, 44),
+    ('\u{1D7D0}', Status::Mapped, 33, 34),
+    ('\u{1D7D1}', Status::Mapped, 34, 35),
+    ('\u{1D7D2}', Status::Mapped, 2348, 2349),
+    ('\u{1D7D3}', Status::Mapped, 2349, 2350),
+    ('\u{1D7D4}', Status::Mapped, 2350, 2351),
+    ('\u{1D7D5}', Status::Mapped, 2351, 2352),
+    ('\u{1D7D6}', Status::Mapped, 2352, 2353),
+    ('\u{1D7D7}', Status::Mapped, 2353, 2354),
+    ('\u{1D7D8}', Status::Mapped, 2347, 2348),
+    ('\u{1D7D9}', Status::Mapped, 43, 44),
+    ('\u{1D7DA}', Status::Mapped, 33, 34),
+    ('\u{1D7DB}', Status::Mapped, 34, 35),
+    ('\u{1D7DC}', Status::Mapped, 2348, 2349),
+    ('\u{1D7DD}', Status::Mapped, 2349, 2350),
+    ('\u{1D7DE}', Status::Mapped, 2350, 2351),
+    ('\u{1D7DF}', Status::Mapped, 2351, 2352),
+    ('\u{1D7E0}', Status::Mapped, 2352, 2353),
+    ('\u{1D7E1}', Status::Mapped, 2353, 2354),
+    ('\u{1D7E2}', Status::Mapped, 2347, 2348),
+    ('\u{1D7E3}', Status::Mapped, 43, 44),
+    ('\u{1D7E4}', Status::Mapped, 33, 34),
+    ('\u{1D7E5}', Status::Mapped, 34, 35),
+    ('\u{1D7E6}', Status::Mapped, 2348, 2349),
+    ('\u{1D7E7}', Status::Mapped, 2349, 2350),
+    ('\u{1D7E8}', Status::Mapped, 2350, 2351),
+    ('\u{1D7E9}', Status::Mapped, 2351, 2352),
+    ('\u{1D7EA}', Status::Mapped, 2352, 2353),
+    ('\u{1D7EB}', Status::Mapped, 2353, 2354),
+    ('\u{1D7EC}', Status::Mapped, 2347, 2348),
+    ('\u{1D7ED}', Status::Mapped, 43, 44),
+    ('\u{1D7EE}', Status::Mapped, 33, 34),
+    ('\u{1D7EF}', Status::Mapped, 34, 35),
+    ('\u{1D7F0}', Status::Mapped, 2348, 2349),
+    ('\u{1D7F1}', Status::Mapped, 2349, 2350),
+    ('\u{1D7F2}', Status::Mapped, 2350, 2351),
+    ('\u{1D7F3}', Status::Mapped, 2351, 2352),
+    ('\u{1D7F4}', Status::Mapped, 2352, 2353),
+    ('\u{1D7F5}', Status::Mapped, 2353, 2354),
+    ('\u{1D7F6}', Status::Mapped, 2347, 2348),
+    ('\u{1D7F7}', Status::Mapped, 43, 44),
+    ('\u{1D7F8}', Status::Mapped, 33, 34),
+    ('\u{1D7F9}', Status::Mapped, 34, 35),
+    ('\u{1D7FA}', Status::Mapped, 2348, 2349),
+    ('\u{1D7FB}', Status::Mapped, 2349, 2350),
+    ('\u{1D7FC}', Status::Mapped, 2350, 2351),
+    ('\u{1D7FD}', Status::Mapped, 2351, 2352),
+    ('\u{1D7FE}', Status::Mapped, 2352, 2353),
+    ('\u{1D7FF}', Status::Mapped, 2353, 2354),
+    ('\u{1D800}', Status::Valid, 0, 0),
+    ('\u{1DA8C}', Status::Disallowed, 0, 0),
+    ('\u{1DA9B}', Status::Valid, 0, 0),
+    ('\u{1DAA0}', Status::Disallowed, 0, 0),
+    ('\u{1DAA1}', Status::Valid, 0, 0),
+    ('\u{1DAB0}', Status::Disallowed, 0, 0),
+    ('\u{1DF00}', Status::Valid, 0, 0),
+    ('\u{1DF1F}', Status::Disallowed, 0, 0),
+    ('\u{1DF25}', Status::Valid, 0, 0),
+    ('\u{1DF2B}', Status::Disallowed, 0, 0),
+    ('\u{1E000}', Status::Valid, 0, 0),
+    ('\u{1E007}', Status::Disallowed, 0, 0),
+    ('\u{1E008}', Status::Valid, 0, 0),
+    ('\u{1E019}', Status::Disallowed, 0, 0),
+    ('\u{1E01B}', Status::Valid, 0, 0),
+    ('\u{1E022}', Status::Disallowed, 0, 0),
+    ('\u{1E023}', Status::Valid, 0, 0),
+    ('\u{1E025}', Status::Disallowed, 0, 0),
+    ('\u{1E026}', Status::Valid, 0, 0),
+    ('\u{1E02B}', Status::Disallowed, 0, 0),
+    ('\u{1E030}', Status::Mapped, 650, 652),
+    ('\u{1E031}', Status::Mapped, 652, 654),
+    ('\u{1E032}', Status::Mapped, 654, 656),
+    ('\u{1E033}', Status::Mapped, 656, 658),
+    ('\u{1E034}', Status::Mapped, 658, 660),
+    ('\u{1E035}', Status::Mapped, 660, 662),
+    ('\u{1E036}', Status::Mapped, 662, 664),
+    ('\u{1E037}', Status::Mapped, 664, 666),
+    ('\u{1E038}', Status::Mapped, 666, 668),
+    ('\u{1E039}', Status::Mapped, 670, 672),
+    ('\u{1E03A}', Status::Mapped, 672, 674),
+    ('\u{1E03B}', Status::Mapped, 674, 676),
+    ('\u{1E03C}', Status::Mapped, 678, 680),
+    ('\u{1E03D}', Status::Mapped, 680, 682),
+    ('\u{1E03E}', Status::Mapped, 682, 684),
+    ('\u{1E03F}', Status::Mapped, 684, 686),
+    ('\u{1E040}', Status::Mapped, 686, 688),
+    ('\u{1E041}', Status::Mapped, 688, 690),
+    ('\u{1E042}', Status::Mapped, 690, 692),
+    ('\u{1E043}', Status::Mapped, 692, 694),
+    ('\u{1E044}', Status::Mapped, 694, 696),
+    ('\u{1E045}', Status::Mapped, 696, 698),
+    ('\u{1E046}', Status::Mapped, 698, 700),
+    ('\u{1E047}', Status::Mapped, 704, 706),
+    ('\u{1E048}', Status::Mapped, 708, 710),
+    ('\u{1E049}', Status::Mapped, 710, 712),
+    ('\u{1E04A}', Status::Mapped, 6383, 6386),
+    ('\u{1E04B}', Status::Mapped, 824, 826),
+    ('\u{1E04C}', Status::Mapped, 630, 632),
+    ('\u{1E04D}', Status::Mapped, 634, 636),
+    ('\u{1E04E}', Status::Mapped, 840, 842),
+    ('\u{1E04F}', Status::Mapped, 784, 786),
+    ('\u{1E050}', Status::Mapped, 11223, 11225),
+    ('\u{1E051}', Status::Mapped, 650, 652),
+    ('\u{1E052}', Status::Mapped, 652, 654),
+    ('\u{1E053}', Status::Mapped, 654, 656),
+    ('\u{1E054}', Status::Mapped, 656, 658),
+    ('\u{1E055}', Status::Mapped, 658, 660),
+    ('\u{1E056}', Status::Mapped, 660, 662),
+    ('\u{1E057}', Status::Mapped, 662, 664),
+    ('\u{1E058}', Status::Mapped, 664, 666),
+    ('\u{1E059}', Status::Mapped, 666, 668),
+    ('\u{1E05A}', Status::Mapped, 670, 672),
+    ('\u{1E05B}', Status::Mapped, 672, 674),
+    ('\u{1E05C}', Status::Mapped, 678, 680),
+    ('\u{1E05D}', Status::Mapped, 680, 682),
+    ('\u{1E05E}', Status::Mapped, 684, 686),
+    ('\u{1E05F}', Status::Mapped, 688, 690),
+    ('\u{1E060}', Status::Mapped, 690, 692),
+    ('\u{1E061}', Status::Mapped, 692, 694),
+    ('\u{1E062}', Status::Mapped, 694, 696),
+    ('\u{1E063}', Status::Mapped, 696, 698),
+    ('\u{1E064}', Status::Mapped, 698, 700),
+    ('\u{1E065}', Status::Mapped, 702, 704),
+    ('\u{1E066}', Status::Mapped, 704, 706),
+    ('\u{1E067}', Status::Mapped, 754, 756),
+    ('\u{1E068}', Status::Mapped, 630, 632),
+    ('\u{1E069}', Status::Mapped, 628, 630),
+    ('\u{1E06A}', Status::Mapped, 648, 650),
+    ('\u{1E06B}', Status::Mapped, 780, 782),
+    ('\u{1E06C}', Status::Mapped, 6326, 6329),
+    ('\u{1E06D}', Status::Mapped, 786, 788),
+    ('\u{1E06E}', Status::Disallowed, 0, 0),
+    ('\u{1E08F}', Status::Valid, 0, 0),
+    ('\u{1E090}', Status::Disallowed, 0, 0),
+    ('\u{1E100}', Status::Valid, 0, 0),
+    ('\u{1E12D}', Status::Disallowed, 0, 0),
+    ('\u{1E130}', Status::Valid, 0, 0),
+    ('\u{1E13E}', Status::Disallowed, 0, 0),
+    ('\u{1E140}', Status::Valid, 0, 0),
+    ('\u{1E14A}', Status::Disallowed, 0, 0),
+    ('\u{1E14E}', Status::Valid, 0, 0),
+    ('\u{1E150}', Status::Disallowed, 0, 0),
+    ('\u{1E290}', Status::Valid, 0, 0),
+    ('\u{1E2AF}', Status::Disallowed, 0, 0),
+    ('\u{1E2C0}', Status::Valid, 0, 0),
+    ('\u{1E2FA}', Status::Disallowed, 0, 0),
+    ('\u{1E2FF}', Status::Valid, 0, 0),
+    ('\u{1E300}', Status::Disallowed, 0, 0),
+    ('\u{1E4D0}', Status::Valid, 0, 0),
+    ('\u{1E4FA}', Status::Disallowed, 0, 0),
+    ('\u{1E7E0}', Status::Valid, 0, 0),
+    ('\u{1E7E7}', Status::Disallowed, 0, 0),
+    ('\u{1E7E8}', Status::Valid, 0, 0),
+    ('\u{1E7EC}', Status::Disallowed, 0, 0),
+    ('\u{1E7ED}', Status::Valid, 0, 0),
+    ('\u{1E7EF}', Status::Disallowed, 0, 0),
+    ('\u{1E7F0}', Status::Valid, 0, 0),
+    ('\u{1E7FF}', Status::Disallowed, 0, 0),
+    ('\u{1E800}', Status::Valid, 0, 0),
+    ('\u{1E8C5}', Status::Disallowed, 0, 0),
+    ('\u{1E8C7}', Status::Valid, 0, 0),
+    ('\u{1E8D7}', Status::Disallowed, 0, 0),
+    ('\u{1E900}', Status::Mapped, 11225, 11229),
+    ('\u{1E901}', Status::Mapped, 11229, 11233),
+    ('\u{1E902}', Status::Mapped, 11233, 11237),
+    ('\u{1E903}', Status::Mapped, 11237, 11241),
+    ('\u{1E904}', Status::Mapped, 11241, 11245),
+    ('\u{1E905}', Status::Mapped, 11245, 11249),
+    ('\u{1E906}', Status::Mapped, 11249, 11253),
+    ('\u{1E907}', Status::Mapped, 11253, 11257),
+    ('\u{1E908}', Status::Mapped, 11257, 11261),
+    ('\u{1E909}', Status::Mapped, 11261, 11265),
+    ('\u{1E90A}', Status::Mapped, 11265, 11269),
+    ('\u{1E90B}', Status::Mapped, 11269, 11273),
+    ('\u{1E90C}', Status::Mapped, 11273, 11277),
+    ('\u{1E90D}', Status::Mapped, 11277, 11281),
+    ('\u{1E90E}', Status::Mapped, 11281, 11285),
+    ('\u{1E90F}', Status::Mapped, 11285, 11289),
+    ('\u{1E910}', Status::Mapped, 11289, 11293),
+    ('\u{1E911}', Status::Mapped, 11293, 11297),
+    ('\u{1E912}', Status::Mapped, 11297, 11301),
+    ('\u{1E913}', Status::Mapped, 11301, 11305),
+    ('\u{1E914}', Status::Mapped, 11305, 11309),
+    ('\u{1E915}', Status::Mapped, 11309, 11313),
+    ('\u{1E916}', Status::Mapped, 11313, 11317),
+    ('\u{1E917}', Status::Mapped, 11317, 11321),
+    ('\u{1E918}', Status::Mapped, 11321, 11325),
+    ('\u{1E919}', Status::Mapped, 11325, 11329),
+    ('\u{1E91A}', Status::Mapped, 11329, 11333),
+    ('\u{1E91B}', Status::Mapped, 11333, 11337),
+    ('\u{1E91C}', Status::Mapped, 11337, 11341),
+    ('\u{1E91D}', Status::Mapped, 11341, 11345),
+    ('\u{1E91E}', Status::Mapped, 11345, 11349),
+    ('\u{1E91F}', Status::Mapped, 11349, 11353),
+    ('\u{1E920}', Status::Mapped, 11353, 11357),
+    ('\u{1E921}', Status::Mapped, 11357, 11361),
+    ('\u{1E922}', Status::Valid, 0, 0),
+    ('\u{1E94C}', Status::Disallowed, 0, 0),
+    ('\u{1E950}', Status::Valid, 0, 0),
+    ('\u{1E95A}', Status::Disallowed, 0, 0),
+    ('\u{1E95E}', Status::Valid, 0, 0),
+    ('\u{1E960}', Status::Disallowed, 0, 0),
+    ('\u{1EC71}', Status::Valid, 0, 0),
+    ('\u{1ECB5}', Status::Disallowed, 0, 0),
+    ('\u{1ED01}', Status::Valid, 0, 0),
+    ('\u{1ED3E}', Status::Disallowed, 0, 0),
+    ('\u{1EE00}', Status::Mapped, 9902, 9904),
+    ('\u{1EE01}', Status::Mapped, 9904, 9906),
+    ('\u{1EE02}', Status::Mapped, 9912, 9914),
+    ('\u{1EE03}', Status::Mapped, 9918, 9920),
+    ('\u{1EE04}', Status::Disallowed, 0, 0),
+    ('\u{1EE05}', Status::Mapped, 9956, 9958),
+    ('\u{1EE06}', Status::Mapped, 9924, 9926),
+    ('\u{1EE07}', Status::Mapped, 9914, 9916),
+    ('\u{1EE08}', Status::Mapped, 9934, 9936),
+    ('\u{1EE09}', Status::Mapped, 9958, 9960),
+    ('\u{1EE0A}', Status::Mapped, 9946, 9948),
+    ('\u{1EE0B}', Status::Mapped, 9948, 9950),
+    ('\u{1EE0C}', Status::Mapped, 9950, 9952),
+    ('\u{1EE0D}', Status::Mapped, 9952, 9954),
+    ('\u{1EE0E}', Status::Mapped, 9926, 9928),
+    ('\u{1EE0F}', Status::Mapped, 9938, 9940),
+    ('\u{1EE10}', Status::Mapped, 9942, 9944),
+    ('\u{1EE11}', Status::Mapped, 9930, 9932),
+    ('\u{1EE12}', Status::Mapped, 9944, 9946),
+    ('\u{1EE13}', Status::Mapped, 9922, 9924),
+    ('\u{1EE14}', Status::Mapped, 9928, 9930),
+    ('\u{1EE15}', Status::Mapped, 9908, 9910),
+    ('\u{1EE16}', Status::Mapped, 9910, 9912),
+    ('\u{1EE17}', Status::Mapped, 9916, 9918),
+    ('\u{1EE18}', Status::Mapped, 9920, 9922),
+    ('\u{1EE19}', Status::Mapped, 9932, 9934),
+    ('\u{1EE1A}', Status::Mapped, 9936, 9938),
+    ('\u{1EE1B}', Status::Mapped, 9940, 9942),
+    ('\u{1EE1C}', Status::Mapped, 11361, 11363),
+    ('\u{1EE1D}', Status::Mapped, 8358, 8360),
+    ('\u{1EE1E}', Status::Mapped, 11363, 11365),
+    ('\u{1EE1F}', Status::Mapped, 11365, 11367),
+    ('\u{1EE20}', Status::Disallowed, 0, 0),
+    ('\u{1EE21}', Status::Mapped, 9904, 9906),
+    ('\u{1EE22}', Status::Mapped, 9912, 9914),
+    ('\u{1EE23}', Status::Disallowed, 0, 0),
+    ('\u{1EE24}', Status::Mapped, 9954, 9956),
+    ('\u{1EE25}', Status::Disallowed, 0, 0),
+    ('\u{1EE27}', Status::Mapped, 9914, 9916),
+    ('\u{1EE28}', Status::Disallowed, 0, 0),
+    ('\u{1EE29}', Status::Mapped, 9958, 9960),
+    ('\u{1EE2A}', Status::Mapped, 9946, 9948),
+    ('\u{1EE2B}', Status::Mapped, 9948, 9950),
+    ('\u{1EE2C}', Status::Mapped, 9950, 9952),
+    ('\u{1EE2D}', Status::Mapped, 9952, 9954),
+    ('\u{1EE2E}', Status::Mapped, 9926, 9928),
+    ('\u{1EE2F}', Status::Mapped, 9938, 9940),
+    ('\u{1EE30}', Status::Mapped, 9942, 9944),
+    ('\u{1EE31}', Status::Mapped, 9930, 9932),
+    ('\u{1EE32}', Status::Mapped, 9944, 9946),
+    ('\u{1EE33}', Status::Disallowed, 0, 0),
+    ('\u{1EE34}', Status::Mapped, 9928, 9930),
+    ('\u{1EE35}', Status::Mapped, 9908, 9910),
+    ('\u{1EE36}', Status::Mapped, 9910, 9912),
+    ('\u{1EE37}', Status::Mapped, 9916, 9918),
+    ('\u{1EE38}', Status::Disallowed, 0, 0),
+    ('\u{1EE39}', Status::Mapped, 9932, 9934),
+    ('\u{1EE3A}', Status::Disallowed, 0, 0),
+    ('\u{1EE3B}', Status::Mapped, 9940, 9942),
+    ('\u{1EE3C}', Status::Disallowed, 0, 0),
+    ('\u{1EE42}', Status::Mapped, 9912, 9914),
+    ('\u{1EE43}', Status::Disallowed, 0, 0),
+    ('\u{1EE47}', Status::Mapped, 9914, 9916),
+    ('\u{1EE48}', Status::Disallowed, 0, 0),
+    ('\u{1EE49}', Status::Mapped, 9958, 9960),
+    ('\u{1EE4A}', Status::Disallowed, 0, 0),
+    ('\u{1EE4B}', Status::Mapped, 9948, 9950),
+    ('\u{1EE4C}', Status::Disallowed, 0, 0),
+    ('\u{1EE4D}', Status::Mapped, 9952, 9954),
+    ('\u{1EE4E}', Status::Mapped, 9926, 9928),
+    ('\u{1EE4F}', Status::Mapped, 9938, 9940),
+    ('\u{1EE50}', Status::Disallowed, 0, 0),
+    ('\u{1EE51}', Status::Mapped, 9930, 9932),
+    ('\u{1EE52}', Status::Mapped, 9944, 9946),
+    ('\u{1EE53}', Status::Disallowed, 0, 0),
+    ('\u{1EE54}', Status::Mapped, 9928, 9930),
+    ('\u{1EE55}', Status::Disallowed, 0, 0),
+    ('\u{1EE57}', Status::Mapped, 9916, 9918),
+    ('\u{1EE58}', Status::Disallowed, 0, 0),
+    ('\u{1EE59}', Status::Mapped, 9932, 9934),
+    ('\u{1EE5A}', Status::Disallowed, 0, 0),
+    ('\u{1EE5B}', Status::Mapped, 9940, 9942),
+    ('\u{1EE5C}', Status::Disallowed, 0, 0),
+    ('\u{1EE5D}', Status::Mapped, 8358, 8360),
+    ('\u{1EE5E}', Status::Disallowed, 0, 0),
+    ('\u{1EE5F}', Status::Mapped, 11365, 11367),
+    ('\u{1EE60}', Status::Disallowed, 0, 0),
+    ('\u{1EE61}', Status::Mapped, 9904, 9906),
+    ('\u{1EE62}', Status::Mapped, 9912, 9914),
+    ('\u{1EE63}', Status::Disallowed, 0, 0),
+    ('\u{1EE64}', Status::Mapped, 9954, 9956),
+    ('\u{1EE65}', Status::Disallowed, 0, 0),
+    ('\u{1EE67}', Status::Mapped, 9914, 9916),
+    ('\u{1EE68}', Status::Mapped, 9934, 9936),
+    ('\u{1EE69}', Status::Mapped, 9958, 9960),
+    ('\u{1EE6A}', Status::Mapped, 9946, 9948),
+    ('\u{1EE6B}', Status::Disallowed, 0, 0),
+    ('\u{1EE6C}', Status::Mapped, 9950, 9952),
+    ('\u{1EE6D}', Status::Mapped, 9952, 9954),
+    ('\u{1EE6E}', Status::Mapped, 9926, 9928),
+    ('\u{1EE6F}', Status::Mapped, 9938, 9940),
+    ('\u{1EE70}', Status::Mapped, 9942, 9944),
+    ('\u{1EE71}', Status::Mapped, 9930, 9932),
+    ('\u{1EE72}', Status::Mapped, 9944, 9946),
+    ('\u{1EE73}', Status::Disallowed, 0, 0),
+    ('\u{1EE74}', Status::Mapped, 9928, 9930),
+    ('\u{1EE75}', Status::Mapped, 9908, 9910),
+    ('\u{1EE76}', Status::Mapped, 9910, 9912),
+    ('\u{1EE77}', Status::Mapped, 9916, 9918),
+    ('\u{1EE78}', Status::Disallowed, 0, 0),
+    ('\u{1EE79}', Status::Mapped, 9932, 9934),
+    ('\u{1EE7A}', Status::Mapped, 9936, 9938),
+    ('\u{1EE7B}', Status::Mapped, 9940, 9942),
+    ('\u{1EE7C}', Status::Mapped, 11361, 11363),
+    ('\u{1EE7D}', Status::Disallowed, 0, 0),
+    ('\u{1EE7E}', Status::Mapped, 11363, 11365),
+    ('\u{1EE7F}', Status::Disallowed, 0, 0),
+    ('\u{1EE80}', Status::Mapped, 9902, 9904),
+    ('\u{1EE81}', Status::Mapped, 9904, 9906),
+    ('\u{1EE82}', Status::Mapped, 9912, 9914),
+    ('\u{1EE83}', Status::Mapped, 9918, 9920),
+    ('\u{1EE84}', Status::Mapped, 9954, 9956),
+    ('\u{1EE85}', Status::Mapped, 9956, 9958),
+    ('\u{1EE86}', Status::Mapped, 9924, 9926),
+    ('\u{1EE87}', Status::Mapped, 9914, 9916),
+    ('\u{1EE88}', Status::Mapped, 9934, 9936),
+    ('\u{1EE89}', Status::Mapped, 9958, 9960),
+    ('\u{1EE8A}', Status::Disallowed, 0, 0),
+    ('\u{1EE8B}', Status::Mapped, 9948, 9950),
+    ('\u{1EE8C}', Status::Mapped, 9950, 9952),
+    ('\u{1EE8D}', Status::Mapped, 9952, 9954),
+    ('\u{1EE8E}', Status::Mapped, 9926, 9928),
+    ('\u{1EE8F}', Status::Mapped, 9938, 9940),
+    ('\u{1EE90}', Status::Mapped, 9942, 9944),
+    ('\u{1EE91}', Status::Mapped, 9930, 9932),
+    ('\u{1EE92}', Status::Mapped, 9944, 9946),
+    ('\u{1EE93}', Status::Mapped, 9922, 9924),
+    ('\u{1EE94}', Status::Mapped, 9928, 9930),
+    ('\u{1EE95}', Status::Mapped, 9908, 9910),
+    ('\u{1EE96}', Status::Mapped, 9910, 9912),
+    ('\u{1EE97}', Status::Mapped, 9916, 9918),
+    ('\u{1EE98}', Status::Mapped, 9920, 9922),
+    ('\u{1EE99}', Status::Mapped, 9932, 9934),
+    ('\u{1EE9A}', Status::Mapped, 9936, 9938),
+    ('\u{1EE9B}', Status::Mapped, 9940, 9942),
+    ('\u{1EE9C}', Status::Disallowed, 0, 0),
+    ('\u{1EEA1}', Status::Mapped, 9904, 9906),
+    ('\u{1EEA2}', Status::Mapped, 9912, 9914),
+    ('\u{1EEA3}', Status::Mapped, 9918, 9920),
+    ('\u{1EEA4}', Status::Disallowed, 0, 0),
+    ('\u{1EEA5}', Status::Mapped, 9956, 9958),
+    ('\u{1EEA6}', Status::Mapped, 9924, 9926),
+    ('\u{1EEA7}', Status::Mapped, 9914, 9916),
+    ('\u{1EEA8}', Status::Mapped, 9934, 9936),
+    ('\u{1EEA9}', Status::Mapped, 9958, 9960),
+    ('\u{1EEAA}', Status::Disallowed, 0, 0),
+    ('\u{1EEAB}', Status::Mapped, 9948, 9950),
+    ('\u{1EEAC}', Status::Mapped, 9950, 9952),
+    ('\u{1EEAD}', Status::Mapped, 9952, 9954),
+    ('\u{1EEAE}', Status::Mapped, 9926, 9928),
+    ('\u{1EEAF}', Status::Mapped, 9938, 9940),
+    ('\u{1EEB0}', Status::Mapped, 9942, 9944),
+    ('\u{1EEB1}', Status::Mapped, 9930, 9932),
+    ('\u{1EEB2}', Status::Mapped, 9944, 9946),
+    ('\u{1EEB3}', Status::Mapped, 9922, 9924),
+    ('\u{1EEB4}', Status::Mapped, 9928, 9930),
+    ('\u{1EEB5}', Status::Mapped, 9908, 9910),
+    ('\u{1EEB6}', Status::Mapped, 9910, 9912),
+    ('\u{1EEB7}', Status::Mapped, 9916, 9918),
+    ('\u{1EEB8}', Status::Mapped, 9920, 9922),
+    ('\u{1EEB9}', Status::Mapped, 9932, 9934),
+    ('\u{1EEBA}', Status::Mapped, 9936, 9938),
+    ('\u{1EEBB}', Status::Mapped, 9940, 9942),
+    ('\u{1EEBC}', Status::Disallowed, 0, 0),
+    ('\u{1EEF0}', Status::Valid, 0, 0),
+    ('\u{1EEF2}', Status::Disallowed, 0, 0),
+    ('\u{1F000}', Status::Valid, 0, 0),
+    ('\u{1F02C}', Status::Disallowed, 0, 0),
+    ('\u{1F030}', Status::Valid, 0, 0),
+    ('\u{1F094}', Status::Disallowed, 0, 0),
+    ('\u{1F0A0}', Status::Valid, 0, 0),
+    ('\u{1F0AF}', Status::Disallowed, 0, 0),
+    ('\u{1F0B1}', Status::Valid, 0, 0),
+    ('\u{1F0C0}', Status::Disallowed, 0, 0),
+    ('\u{1F0C1}', Status::Valid, 0, 0),
+    ('\u{1F0D0}', Status::Disallowed, 0, 0),
+    ('\u{1F0D1}', Status::Valid, 0, 0),
+    ('\u{1F0F6}', Status::Disallowed, 0, 0),
+    ('\u{1F101}', Status::Mapped, 11367, 11369),
+    ('\u{1F102}', Status::Mapped, 11369, 11371),
+    ('\u{1F103}', Status::Mapped, 11371, 11373),
+    ('\u{1F104}', Status::Mapped, 11373, 11375),
+    ('\u{1F105}', Status::Mapped, 11375, 11377),
+    ('\u{1F106}', Status::Mapped, 11377, 11379),
+    ('\u{1F107}', Status::Mapped, 11379, 11381),
+    ('\u{1F108}', Status::Mapped, 11381, 11383),
+    ('\u{1F109}', Status::Mapped, 11383, 11385),
+    ('\u{1F10A}', Status::Mapped, 11385, 11387),
+    ('\u{1F10B}', Status::Valid, 0, 0),
+    ('\u{1F110}', Status::Mapped, 2641, 2644),
+    ('\u{1F111}', Status::Mapped, 2644, 2647),
+    ('\u{1F112}', Status::Mapped, 2647, 2650),
+    ('\u{1F113}', Status::Mapped, 2650, 2653),
+    ('\u{1F114}', Status::Mapped, 2653, 2656),
+    ('\u{1F115}', Status::Mapped, 2656, 2659),
+    ('\u{1F116}', Status::Mapped, 2659, 2662),
+    ('\u{1F117}', Status::Mapped, 2662, 2665),
+    ('\u{1F118}', Status::Mapped, 2665, 2668),
+    ('\u{1F119}', Status::Mapped, 2668, 2671),
+    ('\u{1F11A}', Status::Mapped, 2671, 2674),
+    ('\u{1F11B}', Status::Mapped, 2674, 2677),
+    ('\u{1F11C}', Status::Mapped, 2677, 2680),
+    ('\u{1F11D}', Status::Mapped, 2680, 2683),
+    ('\u{1F11E}', Status::Mapped, 2683, 2686),
+    ('\u{1F11F}', Status::Mapped, 2686, 2689),
+    ('\u{1F120}', Status::Mapped, 2689, 2692),
+    ('\u{1F121}', Status::Mapped, 2692, 2695),
+    ('\u{1F122}', Status::Mapped, 2695, 2698),
+    ('\u{1F123}', Status::Mapped, 2698, 2701),
+    ('\u{1F124}', Status::Mapped, 2701, 2704),
+    ('\u{1F125}', Status::Mapped, 2704, 2707),
+    ('\u{1F126}', Status::Mapped, 2707, 2710),
+    ('\u{1F127}', Status::Mapped, 2710, 2713),
+    ('\u{1F128}', Status::Mapped, 2713, 2716),
+    ('\u{1F129}', Status::Mapped, 2716, 2719),
+    ('\u{1F12A}', Status::Mapped, 11387, 11394),
+    ('\u{1F12B}', Status::Mapped, 2, 3),
+    ('\u{1F12C}', Status::Mapped, 17, 18),
+    ('\u{1F12D}', Status::Mapped, 6096, 6098),
+    ('\u{1F12E}', Status::Mapped, 11394, 11396),
+    ('\u{1F12F}', Status::Valid, 0, 0),
+    ('\u{1F130}', Status::Mapped, 0, 1),
+    ('\u{1F131}', Status::Mapped, 1, 2),
+    ('\u{1F132}', Status::Mapped, 2, 3),
+    ('\u{1F133}', Status::Mapped, 3, 4),
+    ('\u{1F134}', Status::Mapped, 4, 5),
+    ('\u{1F135}', Status::Mapped, 5, 6),
+    ('\u{1F136}', Status::Mapped, 6, 7),
+    ('\u{1F137}', Status::Mapped, 7, 8),
+    ('\u{1F138}', Status::Mapped, 8, 9),
+    ('\u{1F139}', Status::Mapped, 9, 10),
+    ('\u{1F13A}', Status::Mapped, 10, 11),
+    ('\u{1F13B}', Status::Mapped, 11, 12),
+    ('\u{1F13C}', Status::Mapped, 12, 13),
+    ('\u{1F13D}', Status::Mapped, 13, 14),
+    ('\u{1F13E}', Status::Mapped, 14, 15),
+    ('\u{1F13F}', Status::Mapped, 15, 16),
+    ('\u{1F140}', Status::Mapped, 16, 17),
+    ('\u{1F141}', Status::Mapped, 17, 18),
+    ('\u{1F142}', Status::Mapped, 18, 19),
+    ('\u{1F143}', Status::Mapped, 19, 20),
+    ('\u{1F144}', Status::Mapped, 20, 21),
+    ('\u{1F145}', Status::Mapped, 21, 22),
+    ('\u{1F146}', Status::Mapped, 22, 23),
+    ('\u{1F147}', Status::Mapped, 23, 24),
+    ('\u{1F148}', Status::Mapped, 24, 25),
+    ('\u{1F149}', Status::Mapped, 25, 26),
+    ('\u{1F14A}', Status::Mapped, 11396, 11398),
+    ('\u{1F14B}', Status::Mapped, 6071, 6073),
+    ('\u{1F14C}', Status::Mapped, 11398, 11400),
+    ('\u{1F14D}', Status::Mapped, 11400, 11402),
+    ('\u{1F14E}', Status::Mapped, 11402, 11405),
+    ('\u{1F14F}', Status::Mapped, 11405, 11407),
+    ('\u{1F150}', Status::Valid, 0, 0),
+    ('\u{1F16A}', Status::Mapped, 11407, 11409),
+    ('\u{1F16B}', Status::Mapped, 11409, 11411),
+    ('\u{1F16C}', Status::Mapped, 11411, 11413),
+    ('\u{1F16D}', Status::Valid, 0, 0),
+    ('\u{1F190}', Status::Mapped, 11413, 11415),
+    ('\u{1F191}', Status::Valid, 0, 0),
+    ('\u{1F1AE}', Status::Disallowed, 0, 0),
+    ('\u{1F1E6}', Status::Valid, 0, 0),
+    ('\u{1F200}', Status::Mapped, 11415, 11421),
+    ('\u{1F201}', Status::Mapped, 11421, 11427),
+    ('\u{1F202}', Status::Mapped, 4723, 4726),
+    ('\u{1F203}', Status::Disallowed, 0, 0),
+    ('\u{1F210}', Status::Mapped, 3274, 3277),
+    ('\u{1F211}', Status::Mapped, 11427, 11430),
+    ('\u{1F212}', Status::Mapped, 11430, 11433),
+    ('\u{1F213}', Status::Mapped, 11433, 11436),
+    ('\u{1F214}', Status::Mapped, 3103, 3106),
+    ('\u{1F215}', Status::Mapped, 11436, 11439),
+    ('\u{1F216}', Status::Mapped, 11439, 11442),
+    ('\u{1F217}', Status::Mapped, 4060, 4063),
+    ('\u{1F218}', Status::Mapped, 11442, 11445),
+    ('\u{1F219}', Status::Mapped, 11445, 11448),
+    ('\u{1F21A}', Status::Mapped, 11448, 11451),
+    ('\u{1F21B}', Status::Mapped, 7429, 7432),
+    ('\u{1F21C}', Status::Mapped, 11451, 11454),
+    ('\u{1F21D}', Status::Mapped, 11454, 11457),
+    ('\u{1F21E}', Status::Mapped, 11457, 11460),
+    ('\u{1F21F}', Status::Mapped, 11460, 11463),
+    ('\u{1F220}', Status::Mapped, 11463, 11466),
+    ('\u{1F221}', Status::Mapped, 11466, 11469),
+    ('\u{1F222}', Status::Mapped, 3382, 3385),
+    ('\u{1F223}', Status::Mapped, 11469, 11472),
+    ('\u{1F224}', Status::Mapped, 11472, 11475),
+    ('\u{1F225}', Status::Mapped, 11475, 11478),
+    ('\u{1F226}', Status::Mapped, 11478, 11481),
+    ('\u{1F227}', Status::Mapped, 11481, 11484),
+    ('\u{1F228}', Status::Mapped, 11484, 11487),
+    ('\u{1F229}', Status::Mapped, 3085, 3088),
+    ('\u{1F22A}', Status::Mapped, 4036, 4039),
+    ('\u{1F22B}', Status::Mapped, 11487, 11490),
+    ('\u{1F22C}', Status::Mapped, 4572, 4575),
+    ('\u{1F22D}', Status::Mapped, 4045, 4048),
+    ('\u{1F22E}', Status::Mapped, 4575, 4578),
+    ('\u{1F22F}', Status::Mapped, 11490, 11493),
+    ('\u{1F230}', Status::Mapped, 3550, 3553),
+    ('\u{1F231}', Status::Mapped, 11493, 11496),
+    ('\u{1F232}', Status::Mapped, 11496, 11499),
+    ('\u{1F233}', Status::Mapped, 11499, 11502),
+    ('\u{1F234}', Status::Mapped, 11502, 11505),
+    ('\u{1F235}', Status::Mapped, 11505, 11508),
+    ('\u{1F236}', Status::Mapped, 4521, 4524),
+    ('\u{1F237}', Status::Mapped, 3304, 3307),
+    ('\u{1F238}', Status::Mapped, 11508, 11511),
+    ('\u{1F239}', Status::Mapped, 11511, 11514),
+    ('\u{1F23A}', Status::Mapped, 11514, 11517),
+    ('\u{1F23B}', Status::Mapped, 11517, 11520),
+    ('\u{1F23C}', Status::Disallowed, 0, 0),
+    ('\u{1F240}', Status::Mapped, 11520, 11529),
+    ('\u{1F241}', Status::Mapped, 11529, 11538),
+    ('\u{1F242}', Status::Mapped, 11538, 11547),
+    ('\u{1F243}', Status::Mapped, 11547, 11556),
+    ('\u{1F244}', Status::Mapped, 11556, 11565),
+    ('\u{1F245}', Status::Mapped, 11565, 11574),
+    ('\u{1F246}', Status::Mapped, 11574, 11583),
+    ('\u{1F247}', Status::Mapped, 11583, 11592),
+    ('\u{1F248}', Status::Mapped, 11592, 11601),
+    ('\u{1F249}', Status::Disallowed, 0, 0),
+    ('\u{1F250}', Status::Mapped, 11601, 11604),
+    ('\u{1F251}', Status::Mapped, 11604, 11607),
+    ('\u{1F252}', Status::Disallowed, 0, 0),
+    ('\u{1F260}', Status::Valid, 0, 0),
+    ('\u{1F266}', Status::Disallowed, 0, 0),
+    ('\u{1F300}', Status::Valid, 0, 0),
+    ('\u{1F6D8}', Status::Disallowed, 0, 0),
+    ('\u{1F6DC}', Status::Valid, 0, 0),
+    ('\u{1F6ED}', Status::Disallowed, 0, 0),
+    ('\u{1F6F0}', Status::Valid, 0, 0),
+    ('\u{1F6FD}', Status::Disallowed, 0, 0),
+    ('\u{1F700}', Status::Valid, 0, 0),
+    ('\u{1F777}', Status::Disallowed, 0, 0),
+    ('\u{1F77B}', Status::Valid, 0, 0),
+    ('\u{1F7DA}', Status::Disallowed, 0, 0),
+    ('\u{1F7E0}', Status::Valid, 0, 0),
+    ('\u{1F7EC}', Status::Disallowed, 0, 0),
+    ('\u{1F7F0}', Status::Valid, 0, 0),
+    ('\u{1F7F1}', Status::Disallowed, 0, 0),
+    ('\u{1F800}', Status::Valid, 0, 0),
+    ('\u{1F80C}', Status::Disallowed, 0, 0),
+    ('\u{1F810}', Status::Valid, 0, 0),
+    ('\u{1F848}', Status::Disallowed, 0, 0),
+    ('\u{1F850}', Status::Valid, 0, 0),
+    ('\u{1F85A}', Status::Disallowed, 0, 0),
+    ('\u{1F860}', Status::Valid, 0, 0),
+    ('\u{1F888}', Status::Disallowed, 0, 0),
+    ('\u{1F890}', Status::Valid, 0, 0),
+    ('\u{1F8AE}', Status::Disallowed, 0, 0),
+    ('\u{1F8B0}', Status::Valid, 0, 0),
+    ('\u{1F8B2}', Status::Disallowed, 0, 0),
+    ('\u{1F900}', Status::Valid, 0, 0),
+    ('\u{1FA54}', Status::Disallowed, 0, 0),
+    ('\u{1FA60}', Status::Valid, 0, 0),
+    ('\u{1FA6E}', Status::Disallowed, 0, 0),
+    ('\u{1FA70}', Status::Valid, 0, 0),
+    ('\u{1FA7D}', Status::Disallowed, 0, 0),
+    ('\u{1FA80}', Status::Valid, 0, 0),
+    ('\u{1FA89}', Status::Disallowed, 0, 0),
+    ('\u{1FA90}', Status::Valid, 0, 0),
+    ('\u{1FABE}', Status::Disallowed, 0, 0),
+    ('\u{1FABF}', Status::Valid, 0, 0),
+    ('\u{1FAC6}', Status::Disallowed, 0, 0),
+    ('\u{1FACE}', Status::Valid, 0, 0),
+    ('\u{1FADC}', Status::Disallowed, 0, 0),
+    ('\u{1FAE0}', Status::Valid, 0, 0),
+    ('\u{1FAE9}', Status::Disallowed, 0, 0),
+    ('\u{1FAF0}', Status::Valid, 0, 0),
+    ('\u{1FAF9}', Status::Disallowed, 0, 0),
+    ('\u{1FB00}', Status::Valid, 0, 0),
+    ('\u{1FB93}', Status::Disallowed, 0, 0),
+    ('\u{1FB94}', Status::Valid, 0, 0),
+    ('\u{1FBCB}', Status::Disallowed, 0, 0),
+    ('\u{1FBF0}', Status::Mapped, 2347, 2348),
+    ('\u{1FBF1}', Status::Mapped, 43, 44),
+    ('\u{1FBF2}', Status::Mapped, 33, 34),
+    ('\u{1FBF3}', Status::Mapped, 34, 35),
+    ('\u{1FBF4}', Status::Mapped, 2348, 2349),
+    ('\u{1FBF5}', Status::Mapped, 2349, 2350),
+    ('\u{1FBF6}', Status::Mapped, 2350, 2351),
+    ('\u{1FBF7}', Status::Mapped, 2351, 2352),
+    ('\u{1FBF8}', Status::Mapped, 2352, 2353),
+    ('\u{1FBF9}', Status::Mapped, 2353, 2354),
+    ('\u{1FBFA}', Status::Disallowed, 0, 0),
+    ('\u{20000}', Status::Valid, 0, 0),
+    ('\u{2A6E0}', Status::Disallowed, 0, 0),
+    ('\u{2A700}', Status::Valid, 0, 0),
+    ('\u{2B73A}', Status::Disallowed, 0, 0),
+    ('\u{2B740}', Status::Valid, 0, 0),
+    ('\u{2B81E}', Status::Disallowed, 0, 0),
+    ('\u{2B820}', Status::Valid, 0, 0),
+    ('\u{2CEA2}', Status::Disallowed, 0, 0),
+    ('\u{2CEB0}', Status::Valid, 0, 0),
+    ('\u{2EBE1}', Status::Disallowed, 0, 0),
+    ('\u{2EBF0}', Status::Valid, 0, 0),
+    ('\u{2EE5E}', Status::Disallowed, 0, 0),
+    ('\u{2F800}', Status::Mapped, 11607, 11610),
+    ('\u{2F801}', Status::Mapped, 11610, 11613),
+    ('\u{2F802}', Status::Mapped, 11613, 11616),
+    ('\u{2F803}', Status::Mapped, 11616, 11620),
+    ('\u{2F804}', Status::Mapped, 11620, 11623),
+    ('\u{2F805}', Status::Mapped, 7708, 7711),
+    ('\u{2F806}', Status::Mapped, 11623, 11626),
+    ('\u{2F807}', Status::Mapped, 11626, 11629),
+    ('\u{2F808}', Status::Mapped, 11629, 11632),
+    ('\u{2F809}', Status::Mapped, 11632, 11635),
+    ('\u{2F80A}', Status::Mapped, 7711, 7714),
+    ('\u{2F80B}', Status::Mapped, 11635, 11638),
+    ('\u{2F80C}', Status::Mapped, 11638, 11641),
+    ('\u{2F80D}', Status::Mapped, 11641, 11645),
+    ('\u{2F80E}', Status::Mapped, 7714, 7717),
+    ('\u{2F80F}', Status::Mapped, 11645, 11648),
+    ('\u{2F810}', Status::Mapped, 11648, 11651),
+    ('\u{2F811}', Status::Mapped, 11651, 11654),
+    ('\u{2F812}', Status::Mapped, 11654, 11658),
+    ('\u{2F813}', Status::Mapped, 11658, 11661),
+    ('\u{2F814}', Status::Mapped, 11661, 11664),
+    ('\u{2F815}', Status::Mapped, 11457, 11460),
+    ('\u{2F816}', Status::Mapped, 11664, 11668),
+    ('\u{2F817}', Status::Mapped, 11668, 11671),
+    ('\u{2F818}', Status::Mapped, 11671, 11674),
+    ('\u{2F819}', Status::Mapped, 11674, 11677),
+    ('\u{2F81A}', Status::Mapped, 11677, 11680),
+    ('\u{2F81B}', Status::Mapped, 7880, 7883),
+    ('\u{2F81C}', Status::Mapped, 11680, 11684),
+    ('\u{2F81D}', Status::Mapped, 3133, 3136),
+    ('\u{2F81E}', Status::Mapped, 11684, 11687),
+    ('\u{2F81F}', Status::Mapped, 11687, 11690),
+    ('\u{2F820}', Status::Mapped, 11690, 11693),
+    ('\u{2F821}', Status::Mapped, 11693, 11696),
+    ('\u{2F822}', Status::Mapped, 11511, 11514),
+    ('\u{2F823}', Status::Mapped, 11696, 11699),
+    ('\u{2F824}', Status::Mapped, 11699, 11702),
+    ('\u{2F825}', Status::Mapped, 7895, 7898),
+    ('\u{2F826}', Status::Mapped, 7717, 7720),
+    ('\u{2F827}', Status::Mapped, 7720, 7723),
+    ('\u{2F828}', Status::Mapped, 7898, 7901),
+    ('\u{2F829}', Status::Mapped, 11702, 11705),
+    ('\u{2F82A}', Status::Mapped, 11705, 11708),
+    ('\u{2F82B}', Status::Mapped, 7171, 7174),
+    ('\u{2F82C}', Status::Mapped, 11708, 11711),
+    ('\u{2F82D}', Status::Mapped, 7723, 7726),
+    ('\u{2F82E}', Status::Mapped, 11711, 11714),
+    ('\u{2F82F}', Status::Mapped, 11714, 11717),
+    ('\u{2F830}', Status::Mapped, 11717, 11720),
+    ('\u{2F831}', Status::Mapped, 11720, 11723),
+    ('\u{2F834}', Status::Mapped, 11723, 11727),
+    ('\u{2F835}', Status::Mapped, 11727, 11730),
+    ('\u{2F836}', Status::Mapped, 11730, 11733),
+    ('\u{2F837}', Status::Mapped, 11733, 11736),
+    ('\u{2F838}', Status::Mapped, 11736, 11740),
+    ('\u{2F839}', Status::Mapped, 11740, 11743),
+    ('\u{2F83A}', Status::Mapped, 11743, 11746),
+    ('\u{2F83B}', Status::Mapped, 11746, 11749),
+    ('\u{2F83C}', Status::Mapped, 11749, 11752),
+    ('\u{2F83D}', Status::Mapped, 11752, 11755),
+    ('\u{2F83E}', Status::Mapped, 11755, 11758),
+    ('\u{2F83F}', Status::Mapped, 11758, 11761),
+    ('\u{2F840}', Status::Mapped, 11761, 11764),
+    ('\u{2F841}', Status::Mapped, 11764, 11767),
+    ('\u{2F842}', Status::Mapped, 11767, 11770),
+    ('\u{2F843}', Status::Mapped, 11770, 11773),
+    ('\u{2F844}', Status::Mapped, 11773, 11776),
+    ('\u{2F845}', Status::Mapped, 11776, 11779),
+    ('\u{2F847}', Status::Mapped, 7904, 7907),
+    ('\u{2F848}', Status::Mapped, 11779, 11782),
+    ('\u{2F849}', Status::Mapped, 11782, 11785),
+    ('\u{2F84A}', Status::Mapped, 11785, 11788),
+    ('\u{2F84B}', Status::Mapped, 11788, 11791),
+    ('\u{2F84C}', Status::Mapped, 7729, 7732),
+    ('\u{2F84D}', Status::Mapped, 11791, 11794),
+    ('\u{2F84E}', Status::Mapped, 11794, 11797),
+    ('\u{2F84F}', Status::Mapped, 11797, 11800),
+    ('\u{2F850}', Status::Mapped, 7609, 7612),
+    ('\u{2F851}', Status::Mapped, 11800, 11803),
+    ('\u{2F852}', Status::Mapped, 11803, 11806),
+    ('\u{2F853}', Status::Mapped, 11806, 11809),
+    ('\u{2F854}', Status::Mapped, 11809, 11812),
+    ('\u{2F855}', Status::Mapped, 11812, 11815),
+    ('\u{2F856}', Status::Mapped, 11815, 11818),
+    ('\u{2F857}', Status::Mapped, 11818, 11821),
+    ('\u{2F858}', Status::Mapped, 11821, 11824),
+    ('\u{2F859}', Status::Mapped, 11824, 11828),
+    ('\u{2F85A}', Status::Mapped, 11828, 11831),
+    ('\u{2F85B}', Status::Mapped, 11831, 11834),
+    ('\u{2F85C}', Status::Mapped, 11834, 11837),
+    ('\u{2F85D}', Status::Mapped, 11436, 11439),
+    ('\u{2F85E}', Status::Mapped, 11837, 11840),
+    ('\u{2F85F}', Status::Mapped, 11840, 11843),
+    ('\u{2F860}', Status::Mapped, 11843, 11847),
+    ('\u{2F861}', Status::Mapped, 11847, 11851),
+    ('\u{2F862}', Status::Mapped, 11851, 11854),
+    ('\u{2F863}', Status::Mapped, 11854, 11857),
+    ('\u{2F864}', Status::Mapped, 11857, 11860),
+    ('\u{2F865}', Status::Mapped, 11860, 11863),
+    ('\u{2F866}', Status::Mapped, 11863, 11866),
+    ('\u{2F867}', Status::Mapped, 11866, 11869),
+    ('\u{2F868}', Status::Disallowed, 0, 0),
+    ('\u{2F869}', Status::Mapped, 11869, 11872),
+    ('\u{2F86A}', Status::Mapped, 11872, 11875),
+    ('\u{2F86C}', Status::Mapped, 11875, 11879),
+    ('\u{2F86D}', Status::Mapped, 11879, 11882),
+    ('\u{2F86E}', Status::Mapped, 11882, 11885),
+    ('\u{2F86F}', Status::Mapped, 7159, 7162),
+    ('\u{2F870}', Status::Mapped, 11885, 11888),
+    ('\u{2F871}', Status::Mapped, 11888, 11892),
+    ('\u{2F872}', Status::Mapped, 11892, 11895),
+    ('\u{2F873}', Status::Mapped, 11895, 11898),
+    ('\u{2F874}', Status::Disallowed, 0, 0),
+    ('\u{2F875}', Status::Mapped, 3211, 3214),
+    ('\u{2F876}', Status::Mapped, 11898, 11901),
+    ('\u{2F877}', Status::Mapped, 11901, 11904),
+    ('\u{2F878}', Status::Mapped, 3217, 3220),
+    ('\u{2F879}', Status::Mapped, 11904, 11907),
+    ('\u{2F87A}', Status::Mapped, 11907, 11910),
+    ('\u{2F87B}', Status::Mapped, 11910, 11914),
+    ('\u{2F87C}', Status::Mapped, 11914, 11917),
+    ('\u{2F87D}', Status::Mapped, 11917, 11921),
+    ('\u{2F87E}', Status::Mapped, 11921, 11924),
+    ('\u{2F87F}', Status::Mapped, 11924, 11927),
+    ('\u{2F880}', Status::Mapped, 11927, 11930),
+    ('\u{2F881}', Status::Mapped, 11930, 11933),
+    ('\u{2F882}', Status::Mapped, 11933, 11936),
+    ('\u{2F883}', Status::Mapped, 11936, 11939),
+    ('\u{2F884}', Status::Mapped, 11939, 11942),
+    ('\u{2F885}', Status::Mapped, 11942, 11945),
+    ('\u{2F886}', Status::Mapped, 11945, 11948),
+    ('\u{2F887}', Status::Mapped, 11948, 11951),
+    ('\u{2F888}', Status::Mapped, 11951, 11954),
+    ('\u{2F889}', Status::Mapped, 11954, 11958),
+    ('\u{2F88A}', Status::Mapped, 11958, 11961),
+    ('\u{2F88B}', Status::Mapped, 11961, 11964),
+    ('\u{2F88C}', Status::Mapped, 11964, 11967),
+    ('\u{2F88D}', Status::Mapped, 11967, 11970),
+    ('\u{2F88E}', Status::Mapped, 7003, 7006),
+    ('\u{2F88F}', Status::Mapped, 11970, 11974),
+    ('\u{2F890}', Status::Mapped, 3247, 3250),
+    ('\u{2F891}', Status::Mapped, 11974, 11978),
+    ('\u{2F893}', Status::Mapped, 11978, 11981),
+    ('\u{2F894}', Status::Mapped, 11981, 11984),
+    ('\u{2F896}', Status::Mapped, 11984, 11987),
+    ('\u{2F897}', Status::Mapped, 11987, 11991),
+    ('\u{2F898}', Status::Mapped, 11991, 11995),
+    ('\u{2F899}', Status::Mapped, 11995, 11998),
+    ('\u{2F89A}', Status::Mapped, 11998, 12001),
+    ('\u{2F89B}', Status::Mapped, 12001, 12004),
+    ('\u{2F89C}', Status::Mapped, 12004, 12007),
+    ('\u{2F89D}', Status::Mapped, 12007, 12010),
+    ('\u{2F89E}', Status::Mapped, 12010, 12013),
+    ('\u{2F89F}', Status::Mapped, 12013, 12016),
+    ('\u{2F8A0}', Status::Mapped, 12016, 12019),
+    ('\u{2F8A1}', Status::Mapped, 12019, 12022),
+    ('\u{2F8A2}', Status::Mapped, 12022, 12025),
+    ('\u{2F8A3}', Status::Mapped, 7744, 7747),
+    ('\u{2F8A4}', Status::Mapped, 12025, 12029),
+    ('\u{2F8A5}', Status::Mapped, 12029, 12032),
+    ('\u{2F8A6}', Status::Mapped, 12032, 12035),
+    ('\u{2F8A7}', Status::Mapped, 12035, 12038),
+    ('\u{2F8A8}', Status::Mapped, 7940, 7943),
+    ('\u{2F8A9}', Status::Mapped, 12035, 12038),
+    ('\u{2F8AA}', Status::Mapped, 12038, 12041),
+    ('\u{2F8AB}', Status::Mapped, 7750, 7753),
+    ('\u{2F8AC}', Status::Mapped, 12041, 12044),
+    ('\u{2F8AD}', Status::Mapped, 12044, 12047),
+    ('\u{2F8AE}', Status::Mapped, 12047, 12050),
+    ('\u{2F8AF}', Status::Mapped, 12050, 12053),
+    ('\u{2F8B0}', Status::Mapped, 7753, 7756),
+    ('\u{2F8B1}', Status::Mapped, 6922, 6925),
+    ('\u{2F8B2}', Status::Mapped, 12053, 12056),
+    ('\u{2F8B3}', Status::Mapped, 12056, 12059),
+    ('\u{2F8B4}', Status::Mapped, 12059, 12062),
+    ('\u{2F8B5}', Status::Mapped, 12062, 12065),
+    ('\u{2F8B6}', Status::Mapped, 12065, 12068),
+    ('\u{2F8B7}', Status::Mapped, 12068, 12071),
+    ('\u{2F8B8}', Status::Mapped, 12071, 12075),
+    ('\u{2F8B9}', Status::Mapped, 12075, 12078),
+    ('\u{2F8BA}', Status::Mapped, 12078, 12081),
+    ('\u{2F8BB}', Status::Mapped, 12081, 12084),
+    ('\u{2F8BC}', Status::Mapped, 12084, 12087),
+    ('\u{2F8BD}', Status::Mapped, 12087, 12090),
+    ('\u{2F8BE}', Status::Mapped, 12090, 12094),
+    ('\u{2F8BF}', Status::Mapped, 12094, 12097),
+    ('\u{2F8C0}', Status::Mapped, 12097, 12100),
+    ('\u{2F8C1}', Status::Mapped, 12100, 12103),
+    ('\u{2F8C2}', Status::Mapped, 12103, 12106),
+    ('\u{2F8C3}', Status::Mapped, 12106, 12109),
+    ('\u{2F8C4}', Status::Mapped, 12109, 12112),
+    ('\u{2F8C5}', Status::Mapped, 12112, 12115),
+    ('\u{2F8C6}', Status::Mapped, 12115, 12118),
+    ('\u{2F8C7}', Status::Mapped, 12118, 12121),
+    ('\u{2F8C8}', Status::Mapped, 7756, 7759),
+    ('\u{2F8C9}', Status::Mapped, 12121, 12124),
+    ('\u{2F8CA}', Status::Mapped, 12124, 12128),
+    ('\u{2F8CB}', Status::Mapped, 12128, 12131),
+    ('\u{2F8CC}', Status::Mapped, 12131, 12134),
+    ('\u{2F8CD}', Status::Mapped, 12134, 12137),
+    ('\u{2F8CE}', Status::Mapped, 12137, 12140),
+    ('\u{2F8CF}', Status::Mapped, 7762, 7765),
+    ('\u{2F8D0}', Status::Mapped, 12140, 12143),
+    ('\u{2F8D1}', Status::Mapped, 12143, 12146),
+    ('\u{2F8D2}', Status::Mapped, 12146, 12149),
+    ('\u{2F8D3}', Status::Mapped, 12149, 12152),
+    ('\u{2F8D4}', Status::Mapped, 12152, 12155),
+    ('\u{2F8D5}', Status::Mapped, 12155, 12158),
+    ('\u{2F8D6}', Status::Mapped, 12158, 12161),
+    ('\u{2F8D7}', Status::Mapped, 12161, 12164),
+    ('\u{2F8D8}', Status::Mapped, 7006, 7009),
+    ('\u{2F8D9}', Status::Mapped, 7964, 7967),
+    ('\u{2F8DA}', Status::Mapped, 12164, 12167),
+    ('\u{2F8DB}', Status::Mapped, 12167, 12170),
+    ('\u{2F8DC}', Status::Mapped, 12170, 12173),
+    ('\u{2F8DD}', Status::Mapped, 12173, 12177),
+    ('\u{2F8DE}', Status::Mapped, 12177, 12180),
+    ('\u{2F8DF}', Status::Mapped, 12180, 12183),
+    ('\u{2F8E0}', Status::Mapped, 12183, 12186),
+    ('\u{2F8E1}', Status::Mapped, 12186, 12189),
+    ('\u{2F8E2}', Status::Mapped, 7765, 7768),
+    ('\u{2F8E3}', Status::Mapped, 12189, 12193),
+    ('\u{2F8E4}', Status::Mapped, 12193, 12196),
+    ('\u{2F8E5}', Status::Mapped, 12196, 12199),
+    ('\u{2F8E6}', Status::Mapped, 12199, 12202),
+    ('\u{2F8E7}', Status::Mapped, 8093, 8096),
+    ('\u{2F8E8}', Status::Mapped, 12202, 12205),
+    ('\u{2F8E9}', Status::Mapped, 12205, 12208),
+    ('\u{2F8EA}', Status::Mapped, 12208, 12211),
+    ('\u{2F8EB}', Status::Mapped, 12211, 12214),
+    ('\u{2F8EC}', Status::Mapped, 12214, 12218),
+    ('\u{2F8ED}', Status::Mapped, 12218, 12221),
+    ('\u{2F8EE}', Status::Mapped, 12221, 12224),
+    ('\u{2F8EF}', Status::Mapped, 12224, 12227),
+    ('\u{2F8F0}', Status::Mapped, 12227, 12231),
+    ('\u{2F8F1}', Status::Mapped, 12231, 12234),
+    ('\u{2F8F2}', Status::Mapped, 12234, 12237),
+    ('\u{2F8F3}', Status::Mapped, 12237, 12240),
+    ('\u{2F8F4}', Status::Mapped, 12240, 12243),
+    ('\u{2F8F5}', Status::Mapped, 7210, 7213),
+    ('\u{2F8F6}', Status::Mapped, 12243, 12246),
+    ('\u{2F8F7}', Status::Mapped, 12246, 12250),
+    ('\u{2F8F8}', Status::Mapped, 12250, 12254),
+    ('\u{2F8F9}', Status::Mapped, 12254, 12258),
+    ('\u{2F8FA}', Status::Mapped, 12258, 12261),
+    ('\u{2F8FB}', Status::Mapped, 12261, 12265),
+    ('\u{2F8FC}', Status::Mapped, 12265, 12268),
+    ('\u{2F8FD}', Status::Mapped, 12268, 12271),
+    ('\u{2F8FE}', Status::Mapped, 12271, 12274),
+    ('\u{2F8FF}', Status::Mapped, 12274, 12277),
+    ('\u{2F900}', Status::Mapped, 12277, 12280),
+    ('\u{2F901}', Status::Mapped, 7768, 7771),
+    ('\u{2F902}', Status::Mapped, 7459, 7462),
+    ('\u{2F903}', Status::Mapped, 12280, 12283),
+    ('\u{2F904}', Status::Mapped, 12283, 12286),
+    ('\u{2F905}', Status::Mapped, 12286, 12289),
+    ('\u{2F906}', Status::Mapped, 12289, 12293),
+    ('\u{2F907}', Status::Mapped, 12293, 12296),
+    ('\u{2F908}', Status::Mapped, 12296, 12299),
+    ('\u{2F909}', Status::Mapped, 12299, 12302),
+    ('\u{2F90A}', Status::Mapped, 12302, 12305),
+    ('\u{2F90B}', Status::Mapped, 7973, 7976),
+    ('\u{2F90C}', Status::Mapped, 12305, 12308),
+    ('\u{2F90D}', Status::Mapped, 12308, 12312),
+    ('\u{2F90E}', Status::Mapped, 12312, 12315),
+    ('\u{2F90F}', Status::Mapped, 12315, 12318),
+    ('\u{2F910}', Status::Mapped, 12318, 12322),
+    ('\u{2F911}', Status::Mapped, 12322, 12326),
+    ('\u{2F912}', Status::Mapped, 12326, 12329),
+    ('\u{2F913}', Status::Mapped, 12329, 12332),
+    ('\u{2F914}', Status::Mapped, 7976, 7979),
+    ('\u{2F915}', Status::Mapped, 12332, 12335),
+    ('\u{2F916}', Status::Mapped, 12335, 12338),
+    ('\u{2F917}', Status::Mapped, 12338, 12341),
+    ('\u{2F918}', Status::Mapped, 12341, 12344),
+    ('\u{2F919}', Status::Mapped, 12344, 12347),
+    ('\u{2F91A}', Status::Mapped, 12347, 12350),
+    ('\u{2F91B}', Status::Mapped, 12350, 12354),
+    ('\u{2F91C}', Status::Mapped, 12354, 12357),
+    ('\u{2F91D}', Status::Mapped, 12357, 12361),
+    ('\u{2F91E}', Status::Mapped, 12361, 12364),
+    ('\u{2F91F}', Status::Disallowed, 0, 0),
+    ('\u{2F920}', Status::Mapped, 12364, 12367),
+    ('\u{2F921}', Status::Mapped, 7982, 7985),
+    ('\u{2F922}', Status::Mapped, 12367, 12370),
+    ('\u{2F923}', Status::Mapped, 12370, 12374),
+    ('\u{2F924}', Status::Mapped, 12374, 12377),
+    ('\u{2F925}', Status::Mapped, 12377, 12380),
+    ('\u{2F926}', Status::Mapped, 12380, 12384),
+    ('\u{2F927}', Status::Mapped, 12384, 12388),
+    ('\u{2F928}', Status::Mapped, 12388, 12391),
+    ('\u{2F929}', Status::Mapped, 12391, 12394),
+    ('\u{2F92A}', Status::Mapped, 12394, 12397),
+    ('\u{2F92B}', Status::Mapped, 12397, 12400),
+    ('\u{2F92C}', Status::Mapped, 12400, 12403),
+    ('\u{2F92E}', Status::Mapped, 12403, 12406),
+    ('\u{2F92F}', Status::Mapped, 12406, 12409),
+    ('\u{2F930}', Status::Mapped, 7988, 7991),
+    ('\u{2F931}', Status::Mapped, 12409, 12412),
+    ('\u{2F932}', Status::Mapped, 12412, 12415),
+    ('\u{2F933}', Status::Mapped, 12415, 12418),
+    ('\u{2F934}', Status::Mapped, 12418, 12421),
+    ('\u{2F935}', Status::Mapped, 12421, 12425),
+    ('\u{2F936}', Status::Mapped, 12425, 12428),
+    ('\u{2F937}', Status::Mapped, 12428, 12432),
+    ('\u{2F938}', Status::Mapped, 7168, 7171),
+    ('\u{2F939}', Status::Mapped, 12432, 12436),
+    ('\u{2F93A}', Status::Mapped, 12436, 12439),
+    ('\u{2F93B}', Status::Mapped, 12439, 12443),
+    ('\u{2F93C}', Status::Mapped, 12443, 12447),
+    ('\u{2F93D}', Status::Mapped, 12447, 12451),
+    ('\u{2F93E}', Status::Mapped, 12451, 12454),
+    ('\u{2F93F}', Status::Mapped, 12454, 12457),
+    ('\u{2F940}', Status::Mapped, 8006, 8009),
+    ('\u{2F941}', Status::Mapped, 12457, 12461),
+    ('\u{2F942}', Status::Mapped, 12461, 12465),
+    ('\u{2F943}', Status::Mapped, 12465, 12469),
+    ('\u{2F944}', Status::Mapped, 12469, 12473),
+    ('\u{2F945}', Status::Mapped, 12473, 12476),
+    ('\u{2F946}', Status::Mapped, 12476, 12479),
+    ('\u{2F948}', Status::Mapped, 8009, 8012),
+    ('\u{2F949}', Status::Mapped, 8099, 8102),
+    ('\u{2F94A}', Status::Mapped, 12479, 12482),
+    ('\u{2F94B}', Status::Mapped, 12482, 12485),
+    ('\u{2F94C}', Status::Mapped, 12485, 12488),
+    ('\u{2F94D}', Status::Mapped, 12488, 12492),
+    ('\u{2F94E}', Status::Mapped, 12492, 12495),
+    ('\u{2F94F}', Status::Mapped, 7057, 7060),
+    ('\u{2F950}', Status::Mapped, 8015, 8018),
+    ('\u{2F951}', Status::Mapped, 12495, 12498),
+    ('\u{2F952}', Status::Mapped, 12498, 12502),
+    ('\u{2F953}', Status::Mapped, 7798, 7801),
+    ('\u{2F954}', Status::Mapped, 12502, 12506),
+    ('\u{2F955}', Status::Mapped, 12506, 12510),
+    ('\u{2F956}', Status::Mapped, 7669, 7672),
+    ('\u{2F957}', Status::Mapped, 12510, 12513),
+    ('\u{2F958}', Status::Mapped, 12513, 12516),
+    ('\u{2F959}', Status::Mapped, 7807, 7810),
+    ('\u{2F95A}', Status::Mapped, 12516, 12519),
+    ('\u{2F95B}', Status::Mapped, 12519, 12522),
+    ('\u{2F95C}', Status::Mapped, 12522, 12526),
+    ('\u{2F95D}', Status::Mapped, 12526, 12530),
+    ('\u{2F95F}', Status::Disallowed, 0, 0),
+    ('\u{2F960}', Status::Mapped, 12530, 12533),
+    ('\u{2F961}', Status::Mapped, 12533, 12537),
+    ('\u{2F962}', Status::Mapped, 12537, 12540),
+    ('\u{2F963}', Status::Mapped, 12540, 12543),
+    ('\u{2F964}', Status::Mapped, 12543, 12546),
+    ('\u{2F965}', Status::Mapped, 12546, 12550),
+    ('\u{2F966}', Status::Mapped, 12550, 12553),
+    ('\u{2F967}', Status::Mapped, 12553, 12556),
+    ('\u{2F968}', Status::Mapped, 12556, 12559),
+    ('\u{2F969}', Status::Mapped, 12559, 12562),
+    ('\u{2F96A}', Status::Mapped, 12562, 12565),
+    ('\u{2F96B}', Status::Mapped, 12565, 12569),
+    ('\u{2F96C}', Status::Mapped, 12569, 12572),
+    ('\u{2F96D}', Status::Mapped, 12572, 12575),
+    ('\u{2F96E}', Status::Mapped, 12575, 12578),
+    ('\u{2F96F}', Status::Mapped, 12578, 12581),
+    ('\u{2F970}', Status::Mapped, 12581, 12584),
+    ('\u{2F971}', Status::Mapped, 12584, 12587),
+    ('\u{2F972}', Status::Mapped, 12587, 12591),
+    ('\u{2F973}', Status::Mapped, 12591, 12595),
+    ('\u{2F974}', Status::Mapped, 12595, 12598),
+    ('\u{2F975}', Status::Mapped, 12598, 12602),
+    ('\u{2F976}', Status::Mapped, 12602, 12605),
+    ('\u{2F977}', Status::Mapped, 12605, 12609),
+    ('\u{2F978}', Status::Mapped, 12609, 12612),
+    ('\u{2F979}', Status::Mapped, 12612, 12615),
+    ('\u{2F97A}', Status::Mapped, 7825, 7828),
+    ('\u{2F97B}', Status::Mapped, 12615, 12619),
+    ('\u{2F97C}', Status::Mapped, 12619, 12623),
+    ('\u{2F97D}', Status::Mapped, 12623, 12626),
+    ('\u{2F97E}', Status::Mapped, 12626, 12630),
+    ('\u{2F97F}', Status::Mapped, 12630, 12633),
+    ('\u{2F980}', Status::Mapped, 12633, 12637),
+    ('\u{2F981}', Status::Mapped, 12637, 12640),
+    ('\u{2F982}', Status::Mapped, 12640, 12643),
+    ('\u{2F983}', Status::Mapped, 12643, 12646),
+    ('\u{2F984}', Status::Mapped, 12646, 12649),
+    ('\u{2F985}', Status::Mapped, 12649, 12652),
+    ('\u{2F986}', Status::Mapped, 12652, 12655),
+    ('\u{2F987}', Status::Mapped, 12655, 12659),
+    ('\u{2F988}', Status::Mapped, 12659, 12663),
+    ('\u{2F989}', Status::Mapped, 12663, 12667),
+    ('\u{2F98A}', Status::Mapped, 12667, 12671),
+    ('\u{2F98B}', Status::Mapped, 11978, 11981),
+    ('\u{2F98C}', Status::Mapped, 12671, 12674),
+    ('\u{2F98D}', Status::Mapped, 12674, 12677),
+    ('\u{2F98E}', Status::Mapped, 12677, 12680),
+    ('\u{2F98F}', Status::Mapped, 12680, 12683),
+    ('\u{2F990}', Status::Mapped, 12683, 12686),
+    ('\u{2F991}', Status::Mapped, 12686, 12689),
+    ('\u{2F992}', Status::Mapped, 12689, 12692),
+    ('\u{2F993}', Status::Mapped, 12692, 12695),
+    ('\u{2F994}', Status::Mapped, 12695, 12698),
+    ('\u{2F995}', Status::Mapped, 12698, 12701),
+    ('\u{2F996}', Status::Mapped, 12701, 12704),
+    ('\u{2F997}', Status::Mapped, 12704, 12708),
+    ('\u{2F998}', Status::Mapped, 7219, 7222),
+    ('\u{2F999}', Status::Mapped, 12708, 12711),
+    ('\u{2F99A}', Status::Mapped, 12711, 12714),
+    ('\u{2F99B}', Status::Mapped, 12714, 12717),
+    ('\u{2F99C}', Status::Mapped, 12717, 12720),
+    ('\u{2F99D}', Status::Mapped, 12720, 12723),
+    ('\u{2F99E}', Status::Mapped, 12723, 12726),
+    ('\u{2F99F}', Status::Mapped, 7834, 7837),
+    ('\u{2F9A0}', Status::Mapped, 12726, 12729),
+    ('\u{2F9A1}', Status::Mapped, 12729, 12732),
+    ('\u{2F9A2}', Status::Mapped, 12732, 12735),
+    ('\u{2F9A3}', Status::Mapped, 12735, 12738),
+    ('\u{2F9A4}', Status::Mapped, 12738, 12742),
+    ('\u{2F9A5}', Status::Mapped, 12742, 12746),
+    ('\u{2F9A6}', Status::Mapped, 12746, 12750),
+    ('\u{2F9A7}', Status::Mapped, 12750, 12753),
+    ('\u{2F9A8}', Status::Mapped, 12753, 12756),
+    ('\u{2F9A9}', Status::Mapped, 12756, 12759),
+    ('\u{2F9AA}', Status::Mapped, 12759, 12762),
+    ('\u{2F9AB}', Status::Mapped, 12762, 12766),
+    ('\u{2F9AC}', Status::Mapped, 12766, 12769),
+    ('\u{2F9AD}', Status::Mapped, 12769, 12773),
+    ('\u{2F9AE}', Status::Mapped, 12773, 12776),
+    ('\u{2F9AF}', Status::Mapped, 12776, 12779),
+    ('\u{2F9B0}', Status::Mapped, 12779, 12783),
+    ('\u{2F9B1}', Status::Mapped, 12783, 12787),
+    ('\u{2F9B2}', Status::Mapped, 12787, 12790),
+    ('\u{2F9B3}', Status::Mapped, 12790, 12793),
+    ('\u{2F9B4}', Status::Mapped, 7042, 7045),
+    ('\u{2F9B5}', Status::Mapped, 12793, 12796),
+    ('\u{2F9B6}', Status::Mapped, 12796, 12799),
+    ('\u{2F9B7}', Status::Mapped, 12799, 12802),
+    ('\u{2F9B8}', Status::Mapped, 12802, 12805),
+    ('\u{2F9B9}', Status::Mapped, 12805, 12808),
+    ('\u{2F9BA}', Status::Mapped, 12808, 12811),
+    ('\u{2F9BB}', Status::Mapped, 8036, 8039),
+    ('\u{2F9BC}', Status::Mapped, 12811, 12814),
+    ('\u{2F9BD}', Status::Mapped, 12814, 12817),
+    ('\u{2F9BE}', Status::Mapped, 12817, 12820),
+    ('\u{2F9BF}', Status::Disallowed, 0, 0),
+    ('\u{2F9C0}', Status::Mapped, 12820, 12823),
+    ('\u{2F9C1}', Status::Mapped, 12823, 12826),
+    ('\u{2F9C2}', Status::Mapped, 12826, 12829),
+    ('\u{2F9C3}', Status::Mapped, 12829, 12832),
+    ('\u{2F9C4}', Status::Mapped, 3517, 3520),
+    ('\u{2F9C5}', Status::Mapped, 12832, 12836),
+    ('\u{2F9C6}', Status::Mapped, 12836, 12839),
+    ('\u{2F9C7}', Status::Mapped, 12839, 12842),
+    ('\u{2F9C8}', Status::Mapped, 12842, 12845),
+    ('\u{2F9C9}', Status::Mapped, 12845, 12848),
+    ('\u{2F9CA}', Status::Mapped, 12848, 12851),
+    ('\u{2F9CB}', Status::Mapped, 12851, 12855),
+    ('\u{2F9CC}', Status::Mapped, 12855, 12859),
+    ('\u{2F9CD}', Status::Mapped, 12859, 12862),
+    ('\u{2F9CE}', Status::Mapped, 12862, 12865),
+    ('\u{2F9CF}', Status::Mapped, 12865, 12868),
+    ('\u{2F9D0}', Status::Mapped, 8051, 8054),
+    ('\u{2F9D1}', Status::Mapped, 8054, 8057),
+    ('\u{2F9D2}', Status::Mapped, 3538, 3541),
+    ('\u{2F9D3}', Status::Mapped, 12868, 12872),
+    ('\u{2F9D4}', Status::Mapped, 12872, 12875),
+    ('\u{2F9D5}', Status::Mapped, 12875, 12878),
+    ('\u{2F9D6}', Status::Mapped, 12878, 12881),
+    ('\u{2F9D7}', Status::Mapped, 12881, 12884),
+    ('\u{2F9D8}', Status::Mapped, 12884, 12888),
+    ('\u{2F9D9}', Status::Mapped, 12888, 12892),
+    ('\u{2F9DA}', Status::Mapped, 12892, 12895),
+    ('\u{2F9DB}', Status::Mapped, 12895, 12898),
+    ('\u{2F9DC}', Status::Mapped, 12898, 12901),
+    ('\u{2F9DD}', Status::Mapped, 12901, 12905),
+    ('\u{2F9DE}', Status::Mapped, 12905, 12908),
+    ('\u{2F9DF}', Status::Mapped, 8057, 8060),
+    ('\u{2F9E0}', Status::Mapped, 12908, 12912),
+    ('\u{2F9E1}', Status::Mapped, 12912, 12916),
+    ('\u{2F9E2}', Status::Mapped, 12916, 12919),
+    ('\u{2F9E3}', Status::Mapped, 12919, 12922),
+    ('\u{2F9E4}', Status::Mapped, 12922, 12925),
+    ('\u{2F9E5}', Status::Mapped, 12925, 12929),
+    ('\u{2F9E6}', Status::Mapped, 12929, 12932),
+    ('\u{2F9E7}', Status::Mapped, 12932, 12935),
+    ('\u{2F9E8}', Status::Mapped, 12935, 12938),
+    ('\u{2F9E9}', Status::Mapped, 12938, 12941),
+    ('\u{2F9EA}', Status::Mapped, 12941, 12944),
+    ('\u{2F9EB}', Status::Mapped, 12944, 12947),
+    ('\u{2F9EC}', Status::Mapped, 12947, 12950),
+    ('\u{2F9ED}', Status::Mapped, 12950, 12954),
+    ('\u{2F9EE}', Status::Mapped, 12954, 12957),
+    ('\u{2F9EF}', Status::Mapped, 12957, 12960),
+    ('\u{2F9F0}', Status::Mapped, 12960, 12963),
+    ('\u{2F9F1}', Status::Mapped, 12963, 12967),
+    ('\u{2F9F2}', Status::Mapped, 12967, 12970),
+    ('\u{2F9F3}', Status::Mapped, 12970, 12973),
+    ('\u{2F9F4}', Status::Mapped, 12973, 12976),
+    ('\u{2F9F5}', Status::Mapped, 12976, 12979),
+    ('\u{2F9F6}', Status::Mapped, 12979, 12983),
+    ('\u{2F9F7}', Status::Mapped, 12983, 12987),
+    ('\u{2F9F8}', Status::Mapped, 12987, 12990),
+    ('\u{2F9F9}', Status::Mapped, 12990, 12993),
+    ('\u{2F9FA}', Status::Mapped, 12993, 12996),
+    ('\u{2F9FB}', Status::Mapped, 12996, 13000),
+    ('\u{2F9FC}', Status::Mapped, 13000, 13003),
+    ('\u{2F9FD}', Status::Mapped, 13003, 13007),
+    ('\u{2F9FE}', Status::Mapped, 8075, 8078),
+    ('\u{2FA00}', Status::Mapped, 13007, 13010),
+    ('\u{2FA01}', Status::Mapped, 13010, 13014),
+    ('\u{2FA02}', Status::Mapped, 13014, 13017),
+    ('\u{2FA03}', Status::Mapped, 13017, 13020),
+    ('\u{2FA04}', Status::Mapped, 13020, 13023),
+    ('\u{2FA05}', Status::Mapped, 13023, 13026),
+    ('\u{2FA06}', Status::Mapped, 13026, 13029),
+    ('\u{2FA07}', Status::Mapped, 13029, 13032),
+    ('\u{2FA08}', Status::Mapped, 13032, 13035),
+    ('\u{2FA09}', Status::Mapped, 13035, 13039),
+    ('\u{2FA0A}', Status::Mapped, 8078, 8081),
+    ('\u{2FA0B}', Status::Mapped, 13039, 13042),
+    ('\u{2FA0C}', Status::Mapped, 13042, 13045),
+    ('\u{2FA0D}', Status::Mapped, 13045, 13048),
+    ('\u{2FA0E}', Status::Mapped, 13048, 13051),
+    ('\u{2FA0F}', Status::Mapped, 13051, 13054),
+    ('\u{2FA10}', Status::Mapped, 13054, 13058),
+    ('\u{2FA11}', Status::Mapped, 13058, 13061),
+    ('\u{2FA12}', Status::Mapped, 13061, 13065),
+    ('\u{2FA13}', Status::Mapped, 13065, 13069),
+    ('\u{2FA14}', Status::Mapped, 13069, 13073),
+    ('\u{2FA15}', Status::Mapped, 3682, 3685),
+    ('\u{2FA16}', Status::Mapped, 13073, 13076),
+    ('\u{2FA17}', Status::Mapped, 3694, 3697),
+    ('\u{2FA18}', Status::Mapped, 13076, 13079),
+    ('\u{2FA19}', Status::Mapped, 13079, 13082),
+    ('\u{2FA1A}', Status::Mapped, 13082, 13085),
+    ('\u{2FA1B}', Status::Mapped, 13085, 13088),
+    ('\u{2FA1C}', Status::Mapped, 3709, 3712),
+    ('\u{2FA1D}', Status::Mapped, 13088, 13092),
+    ('\u{2FA1E}', Status::Disallowed, 0, 0),
+    ('\u{30000}', Status::Valid, 0, 0),
+    ('\u{3134B}', Status::Disallowed, 0, 0),
+    ('\u{31350}', Status::Valid, 0, 0),
+    ('\u{323B0}', Status::Disallowed, 0, 0),
+    ('\u{E0100}', Status::Ignored, 0, 0),
+    ('\u{E01F0}', Status::Disallowed, 0, 0),
+];
+
+/// Concatenated full canonical decompositions.
+pub(super) static DECOMPOSED: &str = "A\u{300}A\u{301}A\u{302}A\u{303}A\u{308}A\u{30a}C\u{327}E\u{300}E\u{301}E\u{302}E\u{308}I\u{300}I\u{301}I\u{302}I\u{308}N\u{303}O\u{300}O\u{301}O\u{302}O\u{303}O\u{308}U\u{300}U\u{301}U\u{302}U\u{308}Y\u{301}a\u{300}a\u{301}a\u{302}a\u{303}a\u{308}a\u{30a}c\u{327}e\u{300}e\u{301}e\u{302}e\u{308}i\u{300}i\u{301}i\u{302}i\u{308}n\u{303}o\u{300}o\u{301}o\u{302}o\u{303}o\u{308}u\u{300}u\u{301}u\u{302}u\u{308}y\u{301}y\u{308}A\u{304}a\u{304}A\u{306}a\u{306}A\u{328}a\u{328}C\u{301}c\u{301}C\u{302}c\u{302}C\u{307}c\u{307}C\u{30c}c\u{30c}D\u{30c}d\u{30c}E\u{304}e\u{304}E\u{306}e\u{306}E\u{307}e\u{307}E\u{328}e\u{328}E\u{30c}e\u{30c}G\u{302}g\u{302}G\u{306}g\u{306}G\u{307}g\u{307}G\u{327}g\u{327}H\u{302}h\u{302}I\u{303}i\u{303}I\u{304}i\u{304}I\u{306}i\u{306}I\u{328}i\u{328}I\u{307}J\u{302}j\u{302}K\u{327}k\u{327}L\u{301}l\u{301}L\u{327}l\u{327}L\u{30c}l\u{30c}N\u{301}n\u{301}N\u{327}n\u{327}N\u{30c}n\u{30c}O\u{304}o\u{304}O\u{306}o\u{306}O\u{30b}o\u{30b}R\u{301}r\u{301}R\u{327}r\u{327}R\u{30c}r\u{30c}S\u{301}s\u{301}S\u{302}s\u{302}S\u{327}s\u{327}S\u{30c}s\u{30c}T\u{327}t\u{327}T\u{30c}t\u{30c}U\u{303}u\u{303}U\u{304}u\u{304}U\u{306}u\u{306}U\u{30a}u\u{30a}U\u{30b}u\u{30b}U\u{328}u\u{328}W\u{302}w\u{302}Y\u{302}y\u{302}Y\u{308}Z\u{301}z\u{301}Z\u{307}z\u{307}Z\u{30c}z\u{30c}O\u{31b}o\u{31b}U\u{31b}u\u{31b}A\u{30c}a\u{30c}I\u{30c}i\u{30c}O\u{30c}o\u{30c}U\u{30c}u\u{30c}U\u{308}\u{304}u\u{308}\u{304}U\u{308}\u{301}u\u{308}\u{301}U\u{308}\u{30c}u\u{308}\u{30c}U\u{308}\u{300}u\u{308}\u{300}A\u{308}\u{304}a\u{308}\u{304}A\u{307}\u{304}a\u{307}\u{304}\u{c6}\u{304}\u{e6}\u{304}G\u{30c}g\u{30c}K\u{30c}k\u{30c}O\u{328}o\u{328}O\u{328}\u{304}o\u{328}\u{304}\u{1b7}\u{30c}\u{292}\u{30c}j\u{30c}G\u{301}g\u{301}N\u{300}n\u{300}A\u{30a}\u{301}a\u{30a}\u{301}\u{c6}\u{301}\u{e6}\u{301}\u{d8}\u{301}\u{f8}\u{301}A\u{30f}a\u{30f}A\u{311}a\u{311}E\u{30f}e\u{30f}E\u{311}e\u{311}I\u{30f}i\u{30f}I\u{311}i\u{311}O\u{30f}o\u{30f}O\u{311}o\u{311}R\u{30f}r\u{30f}R\u{311}r\u{311}U\u{30f}u\u{30f}U\u{311}u\u{311}S\u{326}s\u{326}T\u{326}t\u{326}H\u{30c}h\u{30c}A\u{307}a\u{307}E\u{327}e\u{327}O\u{308}\u{304}o\u{308}\u{304}O\u{303}\u{304}o\u{303}\u{304}O\u{307}o\u{307}O\u{307}\u{304}o\u{307}\u{304}Y\u{304}y\u{304}\u{300}\u{301}\u{313}\u{308}\u{301}\u{2b9};\u{a8}\u{301}\u{391}\u{301}\u{b7}\u{395}\u{301}\u{397}\u{301}\u{399}\u{301}\u{39f}\u{301}\u{3a5}\u{301}\u{3a9}\u{301}\u{3b9}\u{308}\u{301}\u{399}\u{308}\u{3a5}\u{308}\u{3b1}\u{301}\u{3b5}\u{301}\u{3b7}\u{301}\u{3b9}\u{301}\u{3c5}\u{308}\u{301}\u{3b9}\u{308}\u{3c5}\u{308}\u{3bf}\u{301}\u{3c5}\u{301}\u{3c9}\u{301}\u{3d2}\u{301}\u{3d2}\u{308}\u{415}\u{300}\u{415}\u{308}\u{413}\u{301}\u{406}\u{308}\u{41a}\u{301}\u{418}\u{300}\u{423}\u{306}\u{418}\u{306}\u{438}\u{306}\u{435}\u{300}\u{435}\u{308}\u{433}\u{301}\u{456}\u{308}\u{43a}\u{301}\u{438}\u{300}\u{443}\u{306}\u{474}\u{30f}\u{475}\u{30f}\u{416}\u{306}\u{436}\u{306}\u{410}\u{306}\u{430}\u{306}\u{410}\u{308}\u{430}\u{308}\u{415}\u{306}\u{435}\u{306}\u{4d8}\u{308}\u{4d9}\u{308}\u{416}\u{308}\u{436}\u{308}\u{417}\u{308}\u{437}\u{308}\u{418}\u{304}\u{438}\u{304}\u{418}\u{308}\u{438}\u{308}\u{41e}\u{308}\u{43e}\u{308}\u{4e8}\u{308}\u{4e9}\u{308}\u{42d}\u{308}\u{44d}\u{308}\u{423}\u{304}\u{443}\u{304}\u{423}\u{308}\u{443}\u{308}\u{423}\u{30b}\u{443}\u{30b}\u{427}\u{308}\u{447}\u{308}\u{42b}\u{308}\u{44b}\u{308}\u{627}\u{653}\u{627}\u{654}\u{648}\u{654}\u{627}\u{655}\u{64a}\u{654}\u{6d5}\u{654}\u{6c1}\u{654}\u{6d2}\u{654}\u{928}\u{93c}\u{930}\u{93c}\u{933}\u{93c}\u{915}\u{93c}\u{916}\u{93c}\u{917}\u{93c}\u{91c}\u{93c}\u{921}\u{93c}\u{922}\u{93c}\u{92b}\u{93c}\u{92f}\u{93c}\u{9c7}\u{9be}\u{9c7}\u{9d7}\u{9a1}\u{9bc}\u{9a2}\u{9bc}\u{9af}\u{9bc}\u{a32}\u{a3c}\u{a38}\u{a3c}\u{a16}\u{a3c}\u{a17}\u{a3c}\u{a1c}\u{a3c}\u{a2b}\u{a3c}\u{b47}\u{b56}\u{b47}\u{b3e}\u{b47}\u{b57}\u{b21}\u{b3c}\u{b22}\u{b3c}\u{b92}\u{bd7}\u{bc6}\u{bbe}\u{bc7}\u{bbe}\u{bc6}\u{bd7}\u{c46}\u{c56}\u{cbf}\u{cd5}\u{cc6}\u{cd5}\u{cc6}\u{cd6}\u{cc6}\u{cc2}\u{cc6}\u{cc2}\u{cd5}\u{d46}\u{d3e}\u{d47}\u{d3e}\u{d46}\u{d57}\u{dd9}\u{dca}\u{dd9}\u{dcf}\u{dd9}\u{dcf}\u{dca}\u{dd9}\u{ddf}\u{f42}\u{fb7}\u{f4c}\u{fb7}\u{f51}\u{fb7}\u{f56}\u{fb7}\u{f5b}\u{fb7}\u{f40}\u{fb5}\u{f71}\u{f72}\u{f71}\u{f74}\u{fb2}\u{f80}\u{fb3}\u{f80}\u{f71}\u{f80}\u{f92}\u{fb7}\u{f9c}\u{fb7}\u{fa1}\u{fb7}\u{fa6}\u{fb7}\u{fab}\u{fb7}\u{f90}\u{fb5}\u{1025}\u{102e}\u{1b05}\u{1b35}\u{1b07}\u{1b35}\u{1b09}\u{1b35}\u{1b0b}\u{1b35}\u{1b0d}\u{1b35}\u{1b11}\u{1b35}\u{1b3a}\u{1b35}\u{1b3c}\u{1b35}\u{1b3e}\u{1b35}\u{1b3f}\u{1b35}\u{1b42}\u{1b35}A\u{325}a\u{325}B\u{307}b\u{307}B\u{323}b\u{323}B\u{331}b\u{331}C\u{327}\u{301}c\u{327}\u{301}D\u{307}d\u{307}D\u{323}d\u{323}D\u{331}d\u{331}D\u{327}d\u{327}D\u{32d}d\u{32d}E\u{304}\u{300}e\u{304}\u{300}E\u{304}\u{301}e\u{304}\u{301}E\u{32d}e\u{32d}E\u{330}e\u{330}E\u{327}\u{306}e\u{327}\u{306}F\u{307}f\u{307}G\u{304}g\u{304}H\u{307}h\u{307}H\u{323}h\u{323}H\u{308}h\u{308}H\u{327}h\u{327}H\u{32e}h\u{32e}I\u{330}i\u{330}I\u{308}\u{301}i\u{308}\u{301}K\u{301}k\u{301}K\u{323}k\u{323}K\u{331}k\u{331}L\u{323}l\u{323}L\u{323}\u{304}l\u{323}\u{304}L\u{331}l\u{331}L\u{32d}l\u{32d}M\u{301}m\u{301}M\u{307}m\u{307}M\u{323}m\u{323}N\u{307}n\u{307}N\u{323}n\u{323}N\u{331}n\u{331}N\u{32d}n\u{32d}O\u{303}\u{301}o\u{303}\u{301}O\u{303}\u{308}o\u{303}\u{308}O\u{304}\u{300}o\u{304}\u{300}O\u{304}\u{301}o\u{304}\u{301}P\u{301}p\u{301}P\u{307}p\u{307}R\u{307}r\u{307}R\u{323}r\u{323}R\u{323}\u{304}r\u{323}\u{304}R\u{331}r\u{331}S\u{307}s\u{307}S\u{323}s\u{323}S\u{301}\u{307}s\u{301}\u{307}S\u{30c}\u{307}s\u{30c}\u{307}S\u{323}\u{307}s\u{323}\u{307}T\u{307}t\u{307}T\u{323}t\u{323}T\u{331}t\u{331}T\u{32d}t\u{32d}U\u{324}u\u{324}U\u{330}u\u{330}U\u{32d}u\u{32d}U\u{303}\u{301}u\u{303}\u{301}U\u{304}\u{308}u\u{304}\u{308}V\u{303}v\u{303}V\u{323}v\u{323}W\u{300}w\u{300}W\u{301}w\u{301}W\u{308}w\u{308}W\u{307}w\u{307}W\u{323}w\u{323}X\u{307}x\u{307}X\u{308}x\u{308}Y\u{307}y\u{307}Z\u{302}z\u{302}Z\u{323}z\u{323}Z\u{331}z\u{331}h\u{331}t\u{308}w\u{30a}y\u{30a}\u{17f}\u{307}A\u{323}a\u{323}A\u{309}a\u{309}A\u{302}\u{301}a\u{302}\u{301}A\u{302}\u{300}a\u{302}\u{300}A\u{302}\u{309}a\u{302}\u{309}A\u{302}\u{303}a\u{302}\u{303}A\u{323}\u{302}a\u{323}\u{302}A\u{306}\u{301}a\u{306}\u{301}A\u{306}\u{300}a\u{306}\u{300}A\u{306}\u{309}a\u{306}\u{309}A\u{306}\u{303}a\u{306}\u{303}A\u{323}\u{306}a\u{323}\u{306}E\u{323}e\u{323}E\u{309}e\u{309}E\u{303}e\u{303}E\u{302}\u{301}e\u{302}\u{301}E\u{302}\u{300}e\u{302}\u{300}E\u{302}\u{309}e\u{302}\u{309}E\u{302}\u{303}e\u{302}\u{303}E\u{323}\u{302}e\u{323}\u{302}I\u{309}i\u{309}I\u{323}i\u{323}O\u{323}o\u{323}O\u{309}o\u{309}O\u{302}\u{301}o\u{302}\u{301}O\u{302}\u{300}o\u{302}\u{300}O\u{302}\u{309}o\u{302}\u{309}O\u{302}\u{303}o\u{302}\u{303}O\u{323}\u{302}o\u{323}\u{302}O\u{31b}\u{301}o\u{31b}\u{301}O\u{31b}\u{300}o\u{31b}\u{300}O\u{31b}\u{309}o\u{31b}\u{309}O\u{31b}\u{303}o\u{31b}\u{303}O\u{31b}\u{323}o\u{31b}\u{323}U\u{323}u\u{323}U\u{309}u\u{309}U\u{31b}\u{301}u\u{31b}\u{301}U\u{31b}\u{300}u\u{31b}\u{300}U\u{31b}\u{309}u\u{31b}\u{309}U\u{31b}\u{303}u\u{31b}\u{303}U\u{31b}\u{323}u\u{31b}\u{323}Y\u{300}y\u{300}Y\u{323}y\u{323}Y\u{309}y\u{309}Y\u{303}y\u{303}\u{3b1}\u{313}\u{3b1}\u{314}\u{3b1}\u{313}\u{300}\u{3b1}\u{314}\u{300}\u{3b1}\u{313}\u{301}\u{3b1}\u{314}\u{301}\u{3b1}\u{313}\u{342}\u{3b1}\u{314}\u{342}\u{391}\u{313}\u{391}\u{314}\u{391}\u{313}\u{300}\u{391}\u{314}\u{300}\u{391}\u{313}\u{301}\u{391}\u{314}\u{301}\u{391}\u{313}\u{342}\u{391}\u{314}\u{342}\u{3b5}\u{313}\u{3b5}\u{314}\u{3b5}\u{313}\u{300}\u{3b5}\u{314}\u{300}\u{3b5}\u{313}\u{301}\u{3b5}\u{314}\u{301}\u{395}\u{313}\u{395}\u{314}\u{395}\u{313}\u{300}\u{395}\u{314}\u{300}\u{395}\u{313}\u{301}\u{395}\u{314}\u{301}\u{3b7}\u{313}\u{3b7}\u{314}\u{3b7}\u{313}\u{300}\u{3b7}\u{314}\u{300}\u{3b7}\u{313}\u{301}\u{3b7}\u{314}\u{301}\u{3b7}\u{313}\u{342}\u{3b7}\u{314}\u{342}\u{397}\u{313}\u{397}\u{314}\u{397}\u{313}\u{300}\u{397}\u{314}\u{300}\u{397}\u{313}\u{301}\u{397}\u{314}\u{301}\u{397}\u{313}\u{342}\u{397}\u{314}\u{342}\u{3b9}\u{313}\u{3b9}\u{314}\u{3b9}\u{313}\u{300}\u{3b9}\u{314}\u{300}\u{3b9}\u{313}\u{301}\u{3b9}\u{314}\u{301}\u{3b9}\u{313}\u{342}\u{3b9}\u{314}\u{342}\u{399}\u{313}\u{399}\u{314}\u{399}\u{313}\u{300}\u{399}\u{314}\u{300}\u{399}\u{313}\u{301}\u{399}\u{314}\u{301}\u{399}\u{313}\u{342}\u{399}\u{314}\u{342}\u{3bf}\u{313}\u{3bf}\u{314}\u{3bf}\u{313}\u{300}\u{3bf}\u{314}\u{300}\u{3bf}\u{313}\u{301}\u{3bf}\u{314}\u{301}\u{39f}\u{313}\u{39f}\u{314}\u{39f}\u{313}\u{300}\u{39f}\u{314}\u{300}\u{39f}\u{313}\u{301}\u{39f}\u{314}\u{301}\u{3c5}\u{313}\u{3c5}\u{314}\u{3c5}\u{313}\u{300}\u{3c5}\u{314}\u{300}\u{3c5}\u{313}\u{301}\u{3c5}\u{314}\u{301}\u{3c5}\u{313}\u{342}\u{3c5}\u{314}\u{342}\u{3a5}\u{314}\u{3a5}\u{314}\u{300}\u{3a5}\u{314}\u{301}\u{3a5}\u{314}\u{342}\u{3c9}\u{313}\u{3c9}\u{314}\u{3c9}\u{313}\u{300}\u{3c9}\u{314}\u{300}\u{3c9}\u{313}\u{301}\u{3c9}\u{314}\u{301}\u{3c9}\u{313}\u{342}\u{3c9}\u{314}\u{342}\u{3a9}\u{313}\u{3a9}\u{314}\u{3a9}\u{313}\u{300}\u{3a9}\u{314}\u{300}\u{3a9}\u{313}\u{301}\u{3a9}\u{314}\u{301}\u{3a9}\u{313}\u{342}\u{3a9}\u{314}\u{342}\u{3b1}\u{300}\u{3b5}\u{300}\u{3b7}\u{300}\u{3b9}\u{300}\u{3bf}\u{300}\u{3c5}\u{300}\u{3c9}\u{300}\u{3b1}\u{313}\u{345}\u{3b1}\u{314}\u{345}\u{3b1}\u{313}\u{300}\u{345}\u{3b1}\u{314}\u{300}\u{345}\u{3b1}\u{313}\u{301}\u{345}\u{3b1}\u{314}\u{301}\u{345}\u{3b1}\u{313}\u{342}\u{345}\u{3b1}\u{314}\u{342}\u{345}\u{391}\u{313}\u{345}\u{391}\u{314}\u{345}\u{391}\u{313}\u{300}\u{345}\u{391}\u{314}\u{300}\u{345}\u{391}\u{313}\u{301}\u{345}\u{391}\u{314}\u{301}\u{345}\u{391}\u{313}\u{342}\u{345}\u{391}\u{314}\u{342}\u{345}\u{3b7}\u{313}\u{345}\u{3b7}\u{314}\u{345}\u{3b7}\u{313}\u{300}\u{345}\u{3b7}\u{314}\u{300}\u{345}\u{3b7}\u{313}\u{301}\u{345}\u{3b7}\u{314}\u{301}\u{345}\u{3b7}\u{313}\u{342}\u{345}\u{3b7}\u{314}\u{342}\u{345}\u{397}\u{313}\u{345}\u{397}\u{314}\u{345}\u{397}\u{313}\u{300}\u{345}\u{397}\u{314}\u{300}\u{345}\u{397}\u{313}\u{301}\u{345}\u{397}\u{314}\u{301}\u{345}\u{397}\u{313}\u{342}\u{345}\u{397}\u{314}\u{342}\u{345}\u{3c9}\u{313}\u{345}\u{3c9}\u{314}\u{345}\u{3c9}\u{313}\u{300}\u{345}\u{3c9}\u{314}\u{300}\u{345}\u{3c9}\u{313}\u{301}\u{345}\u{3c9}\u{314}\u{301}\u{345}\u{3c9}\u{313}\u{342}\u{345}\u{3c9}\u{314}\u{342}\u{345}\u{3a9}\u{313}\u{345}\u{3a9}\u{314}\u{345}\u{3a9}\u{313}\u{300}\u{345}\u{3a9}\u{314}\u{300}\u{345}\u{3a9}\u{313}\u{301}\u{345}\u{3a9}\u{314}\u{301}\u{345}\u{3a9}\u{313}\u{342}\u{345}\u{3a9}\u{314}\u{342}\u{345}\u{3b1}\u{306}\u{3b1}\u{304}\u{3b1}\u{300}\u{345}\u{3b1}\u{345}\u{3b1}\u{301}\u{345}\u{3b1}\u{342}\u{3b1}\u{342}\u{345}\u{391}\u{306}\u{391}\u{304}\u{391}\u{300}\u{391}\u{345}\u{3b9}\u{a8}\u{342}\u{3b7}\u{300}\u{345}\u{3b7}\u{345}\u{3b7}\u{301}\u{345}\u{3b7}\u{342}\u{3b7}\u{342}\u{345}\u{395}\u{300}\u{397}\u{300}\u{397}\u{345}\u{1fbf}\u{300}\u{1fbf}\u{301}\u{1fbf}\u{342}\u{3b9}\u{306}\u{3b9}\u{304}\u{3b9}\u{308}\u{300}\u{3b9}\u{342}\u{3b9}\u{308}\u{342}\u{399}\u{306}\u{399}\u{304}\u{399}\u{300}\u{1ffe}\u{300}\u{1ffe}\u{301}\u{1ffe}\u{342}\u{3c5}\u{306}\u{3c5}\u{304}\u{3c5}\u{308}\u{300}\u{3c1}\u{313}\u{3c1}\u{314}\u{3c5}\u{342}\u{3c5}\u{308}\u{342}\u{3a5}\u{306}\u{3a5}\u{304}\u{3a5}\u{300}\u{3a1}\u{314}\u{a8}\u{300}`\u{3c9}\u{300}\u{345}\u{3c9}\u{345}\u{3c9}\u{301}\u{345}\u{3c9}\u{342}\u{3c9}\u{342}\u{345}\u{39f}\u{300}\u{3a9}\u{300}\u{3a9}\u{345}\u{b4}\u{2002}\u{2003}\u{3a9}K\u{2190}\u{338}\u{2192}\u{338}\u{2194}\u{338}\u{21d0}\u{338}\u{21d4}\u{338}\u{21d2}\u{338}\u{2203}\u{338}\u{2208}\u{338}\u{220b}\u{338}\u{2223}\u{338}\u{2225}\u{338}\u{223c}\u{338}\u{2243}\u{338}\u{2245}\u{338}\u{2248}\u{338}=\u{338}\u{2261}\u{338}\u{224d}\u{338}<\u{338}>\u{338}\u{2264}\u{338}\u{2265}\u{338}\u{2272}\u{338}\u{2273}\u{338}\u{2276}\u{338}\u{2277}\u{338}\u{227a}\u{338}\u{227b}\u{338}\u{2282}\u{338}\u{2283}\u{338}\u{2286}\u{338}\u{2287}\u{338}\u{22a2}\u{338}\u{22a8}\u{338}\u{22a9}\u{338}\u{22ab}\u{338}\u{227c}\u{338}\u{227d}\u{338}\u{2291}\u{338}\u{2292}\u{338}\u{22b2}\u{338}\u{22b3}\u{338}\u{22b4}\u{338}\u{22b5}\u{338}\u{3008}\u{3009}\u{2add}\u{338}\u{304b}\u{3099}\u{304d}\u{3099}\u{304f}\u{3099}\u{3051}\u{3099}\u{3053}\u{3099}\u{3055}\u{3099}\u{3057}\u{3099}\u{3059}\u{3099}\u{305b}\u{3099}\u{305d}\u{3099}\u{305f}\u{3099}\u{3061}\u{3099}\u{3064}\u{3099}\u{3066}\u{3099}\u{3068}\u{3099}\u{306f}\u{3099}\u{306f}\u{309a}\u{3072}\u{3099}\u{3072}\u{309a}\u{3075}\u{3099}\u{3075}\u{309a}\u{3078}\u{3099}\u{3078}\u{309a}\u{307b}\u{3099}\u{307b}\u{309a}\u{3046}\u{3099}\u{309d}\u{3099}\u{30ab}\u{3099}\u{30ad}\u{3099}\u{30af}\u{3099}\u{30b1}\u{3099}\u{30b3}\u{3099}\u{30b5}\u{3099}\u{30b7}\u{3099}\u{30b9}\u{3099}\u{30bb}\u{3099}\u{30bd}\u{3099}\u{30bf}\u{3099}\u{30c1}\u{3099}\u{30c4}\u{3099}\u{30c6}\u{3099}\u{30c8}\u{3099}\u{30cf}\u{3099}\u{30cf}\u{309a}\u{30d2}\u{3099}\u{30d2}\u{309a}\u{30d5}\u{3099}\u{30d5}\u{309a}\u{30d8}\u{3099}\u{30d8}\u{309a}\u{30db}\u{3099}\u{30db}\u{309a}\u{30a6}\u{3099}\u{30ef}\u{3099}\u{30f0}\u{3099}\u{30f1}\u{3099}\u{30f2}\u{3099}\u{30fd}\u{3099}\u{8c48}\u{66f4}\u{8eca}\u{8cc8}\u{6ed1}\u{4e32}\u{53e5}\u{9f9c}\u{5951}\u{91d1}\u{5587}\u{5948}\u{61f6}\u{7669}\u{7f85}\u{863f}\u{87ba}\u{88f8}\u{908f}\u{6a02}\u{6d1b}\u{70d9}\u{73de}\u{843d}\u{916a}\u{99f1}\u{4e82}\u{5375}\u{6b04}\u{721b}\u{862d}\u{9e1e}\u{5d50}\u{6feb}\u{85cd}\u{8964}\u{62c9}\u{81d8}\u{881f}\u{5eca}\u{6717}\u{6d6a}\u{72fc}\u{90ce}\u{4f86}\u{51b7}\u{52de}\u{64c4}\u{6ad3}\u{7210}\u{76e7}\u{8001}\u{8606}\u{865c}\u{8def}\u{9732}\u{9b6f}\u{9dfa}\u{788c}\u{797f}\u{7da0}\u{83c9}\u{9304}\u{9e7f}\u{8ad6}\u{58df}\u{5f04}\u{7c60}\u{807e}\u{7262}\u{78ca}\u{8cc2}\u{96f7}\u{58d8}\u{5c62}\u{6a13}\u{6dda}\u{6f0f}\u{7d2f}\u{7e37}\u{964b}\u{52d2}\u{808b}\u{51dc}\u{51cc}\u{7a1c}\u{7dbe}\u{83f1}\u{9675}\u{8b80}\u{62cf}\u{8afe}\u{4e39}\u{5be7}\u{6012}\u{7387}\u{7570}\u{5317}\u{78fb}\u{4fbf}\u{5fa9}\u{4e0d}\u{6ccc}\u{6578}\u{7d22}\u{53c3}\u{585e}\u{7701}\u{8449}\u{8aaa}\u{6bba}\u{8fb0}\u{6c88}\u{62fe}\u{82e5}\u{63a0}\u{7565}\u{4eae}\u{5169}\u{51c9}\u{6881}\u{7ce7}\u{826f}\u{8ad2}\u{91cf}\u{52f5}\u{5442}\u{5973}\u{5eec}\u{65c5}\u{6ffe}\u{792a}\u{95ad}\u{9a6a}\u{9e97}\u{9ece}\u{529b}\u{66c6}\u{6b77}\u{8f62}\u{5e74}\u{6190}\u{6200}\u{649a}\u{6f23}\u{7149}\u{7489}\u{79ca}\u{7df4}\u{806f}\u{8f26}\u{84ee}\u{9023}\u{934a}\u{5217}\u{52a3}\u{54bd}\u{70c8}\u{88c2}\u{5ec9}\u{5ff5}\u{637b}\u{6bae}\u{7c3e}\u{7375}\u{4ee4}\u{56f9}\u{5dba}\u{601c}\u{73b2}\u{7469}\u{7f9a}\u{8046}\u{9234}\u{96f6}\u{9748}\u{9818}\u{4f8b}\u{79ae}\u{91b4}\u{96b8}\u{60e1}\u{4e86}\u{50da}\u{5bee}\u{5c3f}\u{6599}\u{71ce}\u{7642}\u{84fc}\u{907c}\u{9f8d}\u{6688}\u{962e}\u{5289}\u{677b}\u{67f3}\u{6d41}\u{6e9c}\u{7409}\u{7559}\u{786b}\u{7d10}\u{985e}\u{516d}\u{622e}\u{9678}\u{502b}\u{5d19}\u{6dea}\u{8f2a}\u{5f8b}\u{6144}\u{6817}\u{9686}\u{5229}\u{540f}\u{5c65}\u{6613}\u{674e}\u{68a8}\u{6ce5}\u{7406}\u{75e2}\u{7f79}\u{88cf}\u{88e1}\u{91cc}\u{96e2}\u{533f}\u{6eba}\u{541d}\u{71d0}\u{7498}\u{85fa}\u{96a3}\u{9c57}\u{9e9f}\u{6797}\u{6dcb}\u{81e8}\u{7acb}\u{7b20}\u{7c92}\u{72c0}\u{7099}\u{8b58}\u{4ec0}\u{8336}\u{523a}\u{5207}\u{5ea6}\u{62d3}\u{7cd6}\u{5b85}\u{6d1e}\u{66b4}\u{8f3b}\u{884c}\u{964d}\u{898b}\u{5ed3}\u{5140}\u{55c0}\u{585a}\u{6674}\u{51de}\u{732a}\u{76ca}\u{793c}\u{795e}\u{7965}\u{798f}\u{9756}\u{7cbe}\u{7fbd}\u{8612}\u{8af8}\u{9038}\u{90fd}\u{98ef}\u{98fc}\u{9928}\u{9db4}\u{90de}\u{96b7}\u{4fae}\u{50e7}\u{514d}\u{52c9}\u{52e4}\u{5351}\u{559d}\u{5606}\u{5668}\u{5840}\u{58a8}\u{5c64}\u{5c6e}\u{6094}\u{6168}\u{618e}\u{61f2}\u{654f}\u{65e2}\u{6691}\u{6885}\u{6d77}\u{6e1a}\u{6f22}\u{716e}\u{722b}\u{7422}\u{7891}\u{793e}\u{7949}\u{7948}\u{7950}\u{7956}\u{795d}\u{798d}\u{798e}\u{7a40}\u{7a81}\u{7bc0}\u{7e09}\u{7e41}\u{7f72}\u{8005}\u{81ed}\u{8279}\u{8457}\u{8910}\u{8996}\u{8b01}\u{8b39}\u{8cd3}\u{8d08}\u{8fb6}\u{96e3}\u{97ff}\u{983b}\u{6075}\u{242ee}\u{8218}\u{4e26}\u{51b5}\u{5168}\u{4f80}\u{5145}\u{5180}\u{52c7}\u{52fa}\u{5555}\u{5599}\u{55e2}\u{58b3}\u{5944}\u{5954}\u{5a62}\u{5b28}\u{5ed2}\u{5ed9}\u{5f69}\u{5fad}\u{60d8}\u{614e}\u{6108}\u{6160}\u{6234}\u{63c4}\u{641c}\u{6452}\u{6556}\u{671b}\u{6756}\u{6b79}\u{6edb}\u{6ecb}\u{701e}\u{77a7}\u{7235}\u{72af}\u{7471}\u{7506}\u{753b}\u{761d}\u{761f}\u{76db}\u{76f4}\u{774a}\u{7740}\u{78cc}\u{7ab1}\u{7c7b}\u{7d5b}\u{7f3e}\u{8352}\u{83ef}\u{8779}\u{8941}\u{8986}\u{8abf}\u{8acb}\u{8aed}\u{8b8a}\u{8f38}\u{9072}\u{9199}\u{9276}\u{967c}\u{97db}\u{980b}\u{9b12}\u{2284a}\u{22844}\u{233d5}\u{3b9d}\u{4018}\u{4039}\u{25249}\u{25cd0}\u{27ed3}\u{9f43}\u{9f8e}\u{5d9}\u{5b4}\u{5f2}\u{5b7}\u{5e9}\u{5c1}\u{5e9}\u{5c2}\u{5e9}\u{5bc}\u{5c1}\u{5e9}\u{5bc}\u{5c2}\u{5d0}\u{5b7}\u{5d0}\u{5b8}\u{5d0}\u{5bc}\u{5d1}\u{5bc}\u{5d2}\u{5bc}\u{5d3}\u{5bc}\u{5d4}\u{5bc}\u{5d5}\u{5bc}\u{5d6}\u{5bc}\u{5d8}\u{5bc}\u{5d9}\u{5bc}\u{5da}\u{5bc}\u{5db}\u{5bc}\u{5dc}\u{5bc}\u{5de}\u{5bc}\u{5e0}\u{5bc}\u{5e1}\u{5bc}\u{5e3}\u{5bc}\u{5e4}\u{5bc}\u{5e6}\u{5bc}\u{5e7}\u{5bc}\u{5e8}\u{5bc}\u{5e9}\u{5bc}\u{5ea}\u{5bc}\u{5d5}\u{5b9}\u{5d1}\u{5bf}\u{5db}\u{5bf}\u{5e4}\u{5bf}\u{11099}\u{110ba}\u{1109b}\u{110ba}\u{110a5}\u{110ba}\u{11131}\u{11127}\u{11132}\u{11127}\u{11347}\u{1133e}\u{11347}\u{11357}\u{114b9}\u{114ba}\u{114b9}\u{114b0}\u{114b9}\u{114bd}\u{115b8}\u{115af}\u{115b9}\u{115af}\u{11935}\u{11930}\u{1d157}\u{1d165}\u{1d158}\u{1d165}\u{1d158}\u{1d165}\u{1d16e}\u{1d158}\u{1d165}\u{1d16f}\u{1d158}\u{1d165}\u{1d170}\u{1d158}\u{1d165}\u{1d171}\u{1d158}\u{1d165}\u{1d172}\u{1d1b9}\u{1d165}\u{1d1ba}\u{1d165}\u{1d1b9}\u{1d165}\u{1d16e}\u{1d1ba}\u{1d165}\u{1d16e}\u{1d1b9}\u{1d165}\u{1d16f}\u{1d1ba}\u{1d165}\u{1d16f}\u{4e3d}\u{4e38}\u{4e41}\u{20122}\u{4f60}\u{4fbb}\u{5002}\u{507a}\u{5099}\u{50cf}\u{349e}\u{2063a}\u{5154}\u{5164}\u{5177}\u{2051c}\u{34b9}\u{5167}\u{518d}\u{2054b}\u{5197}\u{51a4}\u{4ecc}\u{51ac}\u{291df}\u{51f5}\u{5203}\u{34df}\u{523b}\u{5246}\u{5272}\u{5277}\u{3515}\u{5305}\u{5306}\u{5349}\u{535a}\u{5373}\u{537d}\u{537f}\u{20a2c}\u{7070}\u{53ca}\u{53df}\u{20b63}\u{53eb}\u{53f1}\u{5406}\u{549e}\u{5438}\u{5448}\u{5468}\u{54a2}\u{54f6}\u{5510}\u{5553}\u{5563}\u{5584}\u{55ab}\u{55b3}\u{55c2}\u{5716}\u{5717}\u{5651}\u{5674}\u{58ee}\u{57ce}\u{57f4}\u{580d}\u{578b}\u{5832}\u{5831}\u{58ac}\u{214e4}\u{58f2}\u{58f7}\u{5906}\u{591a}\u{5922}\u{5962}\u{216a8}\u{216ea}\u{59ec}\u{5a1b}\u{5a27}\u{59d8}\u{5a66}\u{36ee}\u{36fc}\u{5b08}\u{5b3e}\u{219c8}\u{5bc3}\u{5bd8}\u{5bf3}\u{21b18}\u{5bff}\u{5c06}\u{5f53}\u{5c22}\u{3781}\u{5c60}\u{5cc0}\u{5c8d}\u{21de4}\u{5d43}\u{21de6}\u{5d6e}\u{5d6b}\u{5d7c}\u{5de1}\u{5de2}\u{382f}\u{5dfd}\u{5e28}\u{5e3d}\u{5e69}\u{3862}\u{22183}\u{387c}\u{5eb0}\u{5eb3}\u{5eb6}\u{2a392}\u{5efe}\u{22331}\u{8201}\u{5f22}\u{38c7}\u{232b8}\u{261da}\u{5f62}\u{5f6b}\u{38e3}\u{5f9a}\u{5fcd}\u{5fd7}\u{5ff9}\u{6081}\u{393a}\u{391c}\u{226d4}\u{60c7}\u{6148}\u{614c}\u{617a}\u{61b2}\u{61a4}\u{61af}\u{61de}\u{6210}\u{621b}\u{625d}\u{62b1}\u{62d4}\u{6350}\u{22b0c}\u{633d}\u{62fc}\u{6368}\u{6383}\u{63e4}\u{22bf1}\u{6422}\u{63c5}\u{63a9}\u{3a2e}\u{6469}\u{647e}\u{649d}\u{6477}\u{3a6c}\u{656c}\u{2300a}\u{65e3}\u{66f8}\u{6649}\u{3b19}\u{3b08}\u{3ae4}\u{5192}\u{5195}\u{6700}\u{669c}\u{80ad}\u{43d9}\u{6721}\u{675e}\u{6753}\u{233c3}\u{3b49}\u{67fa}\u{6785}\u{6852}\u{2346d}\u{688e}\u{681f}\u{6914}\u{6942}\u{69a3}\u{69ea}\u{6aa8}\u{236a3}\u{6adb}\u{3c18}\u{6b21}\u{238a7}\u{6b54}\u{3c4e}\u{6b72}\u{6b9f}\u{6bbb}\u{23a8d}\u{21d0b}\u{23afa}\u{6c4e}\u{23cbc}\u{6cbf}\u{6ccd}\u{6c67}\u{6d16}\u{6d3e}\u{6d69}\u{6d78}\u{6d85}\u{23d1e}\u{6d34}\u{6e2f}\u{6e6e}\u{3d33}\u{6ec7}\u{23ed1}\u{6df9}\u{6f6e}\u{23f5e}\u{23f8e}\u{6fc6}\u{7039}\u{701b}\u{3d96}\u{704a}\u{707d}\u{7077}\u{70ad}\u{20525}\u{7145}\u{24263}\u{719c}\u{243ab}\u{7228}\u{7250}\u{24608}\u{7280}\u{7295}\u{24735}\u{24814}\u{737a}\u{738b}\u{3eac}\u{73a5}\u{3eb8}\u{7447}\u{745c}\u{7485}\u{74ca}\u{3f1b}\u{7524}\u{24c36}\u{753e}\u{24c92}\u{2219f}\u{7610}\u{24fa1}\u{24fb8}\u{25044}\u{3ffc}\u{4008}\u{250f3}\u{250f2}\u{25119}\u{25133}\u{771e}\u{771f}\u{778b}\u{4046}\u{4096}\u{2541d}\u{784e}\u{40e3}\u{25626}\u{2569a}\u{256c5}\u{79eb}\u{412f}\u{7a4a}\u{7a4f}\u{2597c}\u{25aa7}\u{7aee}\u{4202}\u{25bab}\u{7bc6}\u{7bc9}\u{4227}\u{25c80}\u{7cd2}\u{42a0}\u{7ce8}\u{7ce3}\u{7d00}\u{25f86}\u{7d63}\u{4301}\u{7dc7}\u{7e02}\u{7e45}\u{4334}\u{26228}\u{26247}\u{4359}\u{262d9}\u{7f7a}\u{2633e}\u{7f95}\u{7ffa}\u{264da}\u{26523}\u{8060}\u{265a8}\u{8070}\u{2335f}\u{43d5}\u{80b2}\u{8103}\u{440b}\u{813e}\u{5ab5}\u{267a7}\u{267b5}\u{23393}\u{2339c}\u{8204}\u{8f9e}\u{446b}\u{8291}\u{828b}\u{829d}\u{52b3}\u{82b1}\u{82b3}\u{82bd}\u{82e6}\u{26b3c}\u{831d}\u{8363}\u{83ad}\u{8323}\u{83bd}\u{83e7}\u{8353}\u{83ca}\u{83cc}\u{83dc}\u{26c36}\u{26d6b}\u{26cd5}\u{452b}\u{84f1}\u{84f3}\u{8516}\u{273ca}\u{8564}\u{26f2c}\u{455d}\u{4561}\u{26fb1}\u{270d2}\u{456b}\u{8650}\u{8667}\u{8669}\u{86a9}\u{8688}\u{870e}\u{86e2}\u{8728}\u{876b}\u{8786}\u{45d7}\u{87e1}\u{8801}\u{45f9}\u{8860}\u{8863}\u{27667}\u{88d7}\u{88de}\u{4635}\u{88fa}\u{34bb}\u{278ae}\u{27966}\u{46be}\u{46c7}\u{8aa0}\u{8c55}\u{27ca8}\u{8cab}\u{8cc1}\u{8d1b}\u{8d77}\u{27f2f}\u{20804}\u{8dcb}\u{8dbc}\u{8df0}\u{208de}\u{8ed4}\u{285d2}\u{285ed}\u{9094}\u{90f1}\u{9111}\u{2872e}\u{911b}\u{9238}\u{92d7}\u{92d8}\u{927c}\u{93f9}\u{9415}\u{28bfa}\u{958b}\u{4995}\u{95b7}\u{28d77}\u{49e6}\u{96c3}\u{5db2}\u{9723}\u{29145}\u{2921a}\u{4a6e}\u{4a76}\u{97e0}\u{2940a}\u{4ab2}\u{29496}\u{9829}\u{295b6}\u{98e2}\u{4b33}\u{9929}\u{99a7}\u{99c2}\u{99fe}\u{4bce}\u{29b30}\u{9c40}\u{9cfd}\u{4cce}\u{4ced}\u{9d67}\u{2a0ce}\u{4cf8}\u{2a105}\u{2a20e}\u{2a291}\u{9ebb}\u{4d56}\u{9ef9}\u{9efe}\u{9f05}\u{9f0f}\u{9f16}\u{9f3b}\u{2a600}";
+
+/// Code points with a canonical decomposition other than Hangul syllables,
+/// and the bounds of their full decomposition in [`DECOMPOSED`].
+pub(super) static DECOMPOSITION: &[(char, u16, u16)] = &[
+    ('\u{C0}', 0, 3),
+    ('\u{C1}', 3, 6),
+    ('\u{C2}', 6, 9),
+    ('\u{C3}', 9, 12),
+    ('\u{C4}', 12, 15),
+    ('\u{C5}', 15, 18),
+    ('\u{C7}', 18, 21),
+    ('\u{C8}', 21, 24),
+    ('\u{C9}', 24, 27),
+    ('\u{CA}', 27, 30),
+    ('\u{CB}', 30, 33),
+    ('\u{CC}', 33, 36),
+    ('\u{CD}', 36, 39),
+    ('\u{CE}', 39, 42),
+    ('\u{CF}', 42, 45),
+    ('\u{D1}', 45, 48),
+    ('\u{D2}', 48, 51),
+    ('\u{D3}', 51, 54),
+    ('\u{D4}', 54, 57),
+    ('\u{D5}', 57, 60),
+    ('\u{D6}', 60, 63),
+    ('\u{D9}', 63, 66),
+    ('\u{DA}', 66, 69),
+    ('\u{DB}', 69, 72),
+    ('\u{DC}', 72, 75),
+    ('\u{DD}', 75, 78),
+    ('\u{E0}', 78, 81),
+    ('\u{E1}', 81, 84),
+    ('\u{E2}', 84, 87),
+    ('\u{E3}', 87, 90),
+    ('\u{E4}', 90, 93),
+    ('\u{E5}', 93, 96),
+    ('\u{E7}', 96, 99),
+    ('\u{E8}', 99, 102),
+    ('\u{E9}', 102, 105),
+    ('\u{EA}', 105, 108),
+    ('\u{EB}', 108, 111),
+    ('\u{EC}', 111, 114),
+    ('\u{ED}', 114, 117),
+    ('\u{EE}', 117, 120),
+    ('\u{EF}', 120, 123),
+    ('\u{F1}', 123, 126),
+    ('\u{F2}', 126, 129),
+    ('\u{F3}', 129, 132),
+    ('\u{F4}', 132, 135),
+    ('\u{F5}', 135, 138),
+    ('\u{F6}', 138, 141),
+    ('\u{F9}', 141, 144),
+    ('\u{FA}', 144, 147),
+    ('\u{FB}', 147, 150),
+    ('\u{FC}', 150, 153),
+    ('\u{FD}', 153, 156),
+    ('\u{FF}', 156, 159),
+    ('\u{100}', 159, 162),
+    ('\u{101}', 162, 165),
+    ('\u{102}', 165, 168),
+    ('\u{103}', 168, 171),
+    ('\u{104}', 171, 174),
+    ('\u{105}', 174, 177),
+    ('\u{106}', 177, 180),
+    ('\u{107}', 180, 183),
+    ('\u{108}', 183, 186),
+    ('\u{109}', 186, 189),
+    ('\u{10A}', 189, 192),
+    ('\u{10B}', 192, 195),
+    ('\u{10C}', 195, 198),
+    ('\u{10D}', 198, 201),
+    ('\u{10E}', 201, 204),
+    ('\u{10F}', 204, 207),
+    ('\u{112}', 207, 210),
+    ('\u{113}', 210, 213),
+    ('\u{114}', 213, 216),
+    ('\u{115}', 216, 219),
+    ('\u{116}', 219, 222),
+    ('\u{117}', 222, 225),
+    ('\u{118}', 225, 228),
+    ('\u{119}', 228, 231),
+    ('\u{11A}', 231, 234),
+    ('\u{11B}', 234, 237),
+    ('\u{11C}', 237, 240),
+    ('\u{11D}', 240, 243),
+    ('\u{11E}', 243, 246),
+    ('\u{11F}', 246, 249),
+    ('\u{120}', 249, 252),
+    ('\u{121}', 252, 255),
+    ('\u{122}', 255, 258),
+    ('\u{123}', 258, 261),
+    ('\u{124}', 261, 264),
+    ('\u{125}', 264, 267),
+    ('\u{128}', 267, 270),
+    ('\u{129}', 270, 273),
+    ('\u{12A}', 273, 276),
+    ('\u{12B}', 276, 279),
+    ('\u{12C}', 279, 282),
+    ('\u{12D}', 282, 285),
+    ('\u{12E}', 285, 288),
+    ('\u{12F}', 288, 291),
+    ('\u{130}', 291, 294),
+    ('\u{134}', 294, 297),
+    ('\u{135}', 297, 300),
+    ('\u{136}', 300, 303),
+    ('\u{137}', 303, 306),
+    ('\u{139}', 306, 309),
+    ('\u{13A}', 309, 312),
+    ('\u{13B}', 312, 315),
+    ('\u{13C}', 315, 318),
+    ('\u{13D}', 318, 321),
+    ('\u{13E}', 321, 324),
+    ('\u{143}', 324, 327),
+    ('\u{144}', 327, 330),
+    ('\u{145}', 330, 333),
+    ('\u{146}', 333, 336),
+    ('\u{147}', 336, 339),
+    ('\u{148}', 339, 342),
+    ('\u{14C}', 342, 345),
+    ('\u{14D}', 345, 348),
+    ('\u{14E}', 348, 351),
+    ('\u{14F}', 351, 354),
+    ('\u{150}', 354, 357),
+    ('\u{151}', 357, 360),
+    ('\u{154}', 360, 363),
+    ('\u{155}', 363, 366),
+    ('\u{156}', 366, 369),
+    ('\u{157}', 369, 372),
+    ('\u{158}', 372, 375),
+    ('\u{159}', 375, 378),
+    ('\u{15A}', 378, 381),
+    ('\u{15B}', 381, 384),
+    ('\u{15C}', 384, 387),
+    ('\u{15D}', 387, 390),
+    ('\u{15E}', 390, 393),
+    ('\u{15F}', 393, 396),
+    ('\u{160}', 396, 399),
+    ('\u{161}', 399, 402),
+    ('\u{162}', 402, 405),
+    ('\u{163}', 405, 408),
+    ('\u{164}', 408, 411),
+    ('\u{165}', 411, 414),
+    ('\u{168}', 414, 417),
+    ('\u{169}', 417, 420),
+    ('\u{16A}', 420, 423),
+    ('\u{16B}', 423, 426),
+    ('\u{16C}', 426, 429),
+    ('\u{16D}', 429, 432),
+    ('\u{16E}', 432, 435),
+    ('\u{16F}', 435, 438),
+    ('\u{170}', 438, 441),
+    ('\u{171}', 441, 444),
+    ('\u{172}', 444, 447),
+    ('\u{173}', 447, 450),
+    ('\u{174}', 450, 453),
+    ('\u{175}', 453, 456),
+    ('\u{176}', 456, 459),
+    ('\u{177}', 459, 462),
+    ('\u{178}', 462, 465),
+    ('\u{179}', 465, 468),
+    ('\u{17A}', 468, 471),
+    ('\u{17B}', 471, 474),
+    ('\u{17C}', 474, 477),
+    ('\u{17D}', 477, 480),
+    ('\u{17E}', 480, 483),
+    ('\u{1A0}', 483, 486),
+    ('\u{1A1}', 486, 489),
+    ('\u{1AF}', 489, 492),
+    ('\u{1B0}', 492, 495),
+    ('\u{1CD}', 495, 498),
+    ('\u{1CE}', 498, 501),
+    ('\u{1CF}', 501, 504),
+    ('\u{1D0}', 504, 507),
+    ('\u{1D1}', 507, 510),
+    ('\u{1D2}', 510, 513),
+    ('\u{1D3}', 513, 516),
+    ('\u{1D4}', 516, 519),
+    ('\u{1D5}', 519, 524),
+    ('\u{1D6}', 524, 529),
+    ('\u{1D7}', 529, 534),
+    ('\u{1D8}', 534, 539),
+    ('\u{1D9}', 539, 544),
+    ('\u{1DA}', 544, 549),
+    ('\u{1DB}', 549, 554),
+    ('\u{1DC}', 554, 559),
+    ('\u{1DE}', 559, 564),
+    ('\u{1DF}', 564, 569),
+    ('\u{1E0}', 569, 574),
+    ('\u{1E1}', 574, 579),
+    ('\u{1E2}', 579, 583),
+    ('\u{1E3}', 583, 587),
+    ('\u{1E6}', 587, 590),
+    ('\u{1E7}', 590, 593),
+    ('\u{1E8}', 593, 596),
+    ('\u{1E9}', 596, 599),
+    ('\u{1EA}', 599, 602),
+    ('\u{1EB}', 602, 605),
+    ('\u{1EC}', 605, 610),
+    ('\u{1ED}', 610, 615),
+    ('\u{1EE}', 615, 619),
+    ('\u{1EF}', 619, 623),
+    ('\u{1F0}', 623, 626),
+    ('\u{1F4}', 626, 629),
+    ('\u{1F5}', 629, 632),
+    ('\u{1F8}', 632, 635),
+    ('\u{1F9}', 635, 638),
+    ('\u{1FA}', 638, 643),
+    ('\u{1FB}', 643, 648),
+    ('\u{1FC}', 648, 652),
+    ('\u{1FD}', 652, 656),
+    ('\u{1FE}', 656, 660),
+    ('\u{1FF}', 660, 664),
+    ('\u{200}', 664, 667),
+    ('\u{201}', 667, 670),
+    ('\u{202}', 670, 673),
+    ('\u{203}', 673, 676),
+    ('\u{204}', 676, 679),
+    ('\u{205}', 679, 682),
+    ('\u{206}', 682, 685),
+    ('\u{207}', 685, 688),
+    ('\u{208}', 688, 691),
+    ('\u{209}', 691, 694),
+    ('\u{20A}', 694, 697),
+    ('\u{20B}', 697, 700),
+    ('\u{20C}', 700, 703),
+    ('\u{20D}', 703, 706),
+    ('\u{20E}', 706, 709),
+    ('\u{20F}', 709, 712),
+    ('\u{210}', 712, 715),
+    ('\u{211}', 715, 718),
+    ('\u{212}', 718, 721),
+    ('\u{213}', 721, 724),
+    ('\u{214}', 724, 727),
+    ('\u{215}', 727, 730),
+    ('\u{216}', 730, 733),
+    ('\u{217}', 733, 736),
+    ('\u{218}', 736, 739),
+    ('\u{219}', 739, 742),
+    ('\u{21A}', 742, 745),
+    ('\u{21B}', 745, 748),
+    ('\u{21E}', 748, 751),
+    ('\u{21F}', 751, 754),
+    ('\u{226}', 754, 757),
+    ('\u{227}', 757, 760),
+    ('\u{228}', 760, 763),
+    ('\u{229}', 763, 766),
+    ('\u{22A}', 766, 771),
+    ('\u{22B}', 771, 776),
+    ('\u{22C}', 776, 781),
+    ('\u{22D}', 781, 786),
+    ('\u{22E}', 786, 789),
+    ('\u{22F}', 789, 792),
+    ('\u{230}', 792, 797),
+    ('\u{231}', 797, 802),
+    ('\u{232}', 802, 805),
+    ('\u{233}', 805, 808),
+    ('\u{340}', 808, 810),
+    ('\u{341}', 810, 812),
+    ('\u{343}', 812, 814),
+    ('\u{344}', 814, 818),
+    ('\u{374}', 818, 820),
+    ('\u{37E}', 820, 821),
+    ('\u{385}', 821, 825),
+    ('\u{386}', 825, 829),
+    ('\u{387}', 829, 831),
+    ('\u{388}', 831, 835),
+    ('\u{389}', 835, 839),
+    ('\u{38A}', 839, 843),
+    ('\u{38C}', 843, 847),
+    ('\u{38E}', 847, 851),
+    ('\u{38F}', 851, 855),
+    ('\u{390}', 855, 861),
+    ('\u{3AA}', 861, 865),
+    ('\u{3AB}', 865, 869),
+    ('\u{3AC}', 869, 873),
+    ('\u{3AD}', 873, 877),
+    ('\u{3AE}', 877, 881),
+    ('\u{3AF}', 881, 885),
+    ('\u{3B0}', 885, 891),
+    ('\u{3CA}', 891, 895),
+    ('\u{3CB}', 895, 899),
+    ('\u{3CC}', 899, 903),
+    ('\u{3CD}', 903, 907),
+    ('\u{3CE}', 907, 911),
+    ('\u{3D3}', 911, 915),
+    ('\u{3D4}', 915, 919),
+    ('\u{400}', 919, 923),
+    ('\u{401}', 923, 927),
+    ('\u{403}', 927, 931),
+    ('\u{407}', 931, 935),
+    ('\u{40C}', 935, 939),
+    ('\u{40D}', 939, 943),
+    ('\u{40E}', 943, 947),
+    ('\u{419}', 947, 951),
+    ('\u{439}', 951, 955),
+    ('\u{450}', 955, 959),
+    ('\u{451}', 959, 963),
+    ('\u{453}', 963, 967),
+    ('\u{457}', 967, 971),
+    ('\u{45C}', 971, 975),
+    ('\u{45D}', 975, 979),
+    ('\u{45E}', 979, 983),
+    ('\u{476}', 983, 987),
+    ('\u{477}', 987, 991),
+    ('\u{4C1}', 991, 995),
+    ('\u{4C2}', 995, 999),
+    ('\u{4D0}', 999, 1003),
+    ('\u{4D1}', 1003, 1007),
+    ('\u{4D2}', 1007, 1011),
+    ('\u{4D3}', 1011, 1015),
+    ('\u{4D6}', 1015, 1019),
+    ('\u{4D7}', 1019, 1023),
+    ('\u{4DA}', 1023, 1027),
+    ('\u{4DB}', 1027, 1031),
+    ('\u{4DC}', 1031, 1035),
+    ('\u{4DD}', 1035, 1039),
+    ('\u{4DE}', 1039, 1043),
+    ('\u{4DF}', 1043, 1047),
+    ('\u{4E2}', 1047, 1051),
+    ('\u{4E3}', 1051, 1055),
+    ('\u{4E4}', 1055, 1059),
+    ('\u{4E5}', 1059, 1063),
+    ('\u{4E6}', 1063, 1067),
+    ('\u{4E7}', 1067, 1071),
+    ('\u{4EA}', 1071, 1075),
+    ('\u{4EB}', 1075, 1079),
+    ('\u{4EC}', 1079, 1083),
+    ('\u{4ED}', 1083, 1087),
+    ('\u{4EE}', 1087, 1091),
+    ('\u{4EF}', 1091, 1095),
+    ('\u{4F0}', 1095, 1099),
+    ('\u{4F1}', 1099, 1103),
+    ('\u{4F2}', 1103, 1107),
+    ('\u{4F3}', 1107, 1111),
+    ('\u{4F4}', 1111, 1115),
+    ('\u{4F5}', 1115, 1119),
+    ('\u{4F8}', 1119, 1123),
+    ('\u{4F9}', 1123, 1127),
+    ('\u{622}', 1127, 1131),
+    ('\u{623}', 1131, 1135),
+    ('\u{624}', 1135, 1139),
+    ('\u{625}', 1139, 1143),
+    ('\u{626}', 1143, 1147),
+    ('\u{6C0}', 1147, 1151),
+    ('\u{6C2}', 1151, 1155),
+    ('\u{6D3}', 1155, 1159),
+    ('\u{929}', 1159, 1165),
+    ('\u{931}', 1165, 1171),
+    ('\u{934}', 1171, 1177),
+    ('\u{958}', 1177, 1183),
+    ('\u{959}', 1183, 1189),
+    ('\u{95A}', 1189, 1195),
+    ('\u{95B}', 1195, 1201),
+    ('\u{95C}', 1201, 1207),
+    ('\u{95D}', 1207, 1213),
+    ('\u{95E}', 1213, 1219),
+    ('\u{95F}', 1219, 1225),
+    ('\u{9CB}', 1225, 1231),
+    ('\u{9CC}', 1231, 1237),
+    ('\u{9DC}', 1237, 1243),
+    ('\u{9DD}', 1243, 1249),
+    ('\u{9DF}', 1249, 1255),
+    ('\u{A33}', 1255, 1261),
+    ('\u{A36}', 1261, 1267),
+    ('\u{A59}', 1267, 1273),
+    ('\u{A5A}', 1273, 1279),
+    ('\u{A5B}', 1279, 1285),
+    ('\u{A5E}', 1285, 1291),
+    ('\u{B48}', 1291, 1297),
+    ('\u{B4B}', 1297, 1303),
+    ('\u{B4C}', 1303, 1309),
+    ('\u{B5C}', 1309, 1315),
+    ('\u{B5D}', 1315, 1321),
+    ('\u{B94}', 1321, 1327),
+    ('\u{BCA}', 1327, 1333),
+    ('\u{BCB}', 1333, 1339),
+    ('\u{BCC}', 1339, 1345),
+    ('\u{C48}', 1345, 1351),
+    ('\u{CC0}', 1351, 1357),
+    ('\u{CC7}', 1357, 1363),
+    ('\u{CC8}', 1363, 1369),
+    ('\u{CCA}', 1369, 1375),
+    ('\u{CCB}', 1375, 1384),
+    ('\u{D4A}', 1384, 1390),
+    ('\u{D4B}', 1390, 1396),
+    ('\u{D4C}', 1396, 1402),
+    ('\u{DDA}', 1402, 1408),
+    ('\u{DDC}', 1408, 1414),
+    ('\u{DDD}', 1414, 1423),
+    ('\u{DDE}', 1423, 1429),
+    ('\u{F43}', 1429, 1435),
+    ('\u{F4D}', 1435, 1441),
+    ('\u{F52}', 1441, 1447),
+    ('\u{F57}', 1447, 1453),
+    ('\u{F5C}', 1453, 1459),
+    ('\u{F69}', 1459, 1465),
+    ('\u{F73}', 1465, 1471),
+    ('\u{F75}', 1471, 1477),
+    ('\u{F76}', 1477, 1483),
+    ('\u{F78}', 1483, 1489),
+    ('\u{F81}', 1489, 1495),
+    ('\u{F93}', 1495, 1501),
+    ('\u{F9D}', 1501, 1507),
+    ('\u{FA2}', 1507, 1513),
+    ('\u{FA7}', 1513, 1519),
+    ('\u{FAC}', 1519, 1525),
+    ('\u{FB9}', 1525, 1531),
+    ('\u{1026}', 1531, 1537),
+    ('\u{1B06}', 1537, 1543),
+    ('\u{1B08}', 1543, 1549),
+    ('\u{1B0A}', 1549, 1555),
+    ('\u{1B0C}', 1555, 1561),
+    ('\u{1B0E}', 1561, 1567),
+    ('\u{1B12}', 1567, 1573),
+    ('\u{1B3B}', 1573, 1579),
+    ('\u{1B3D}', 1579, 1585),
+    ('\u{1B40}', 1585, 1591),
+    ('\u{1B41}', 1591, 1597),
+    ('\u{1B43}', 1597, 1603),
+    ('\u{1E00}', 1603, 1606),
+    ('\u{1E01}', 1606, 1609),
+    ('\u{1E02}', 1609, 1612),
+    ('\u{1E03}', 1612, 1615),
+    ('\u{1E04}', 1615, 1618),
+    ('\u{1E05}', 1618, 1621),
+    ('\u{1E06}', 1621, 1624),
+    ('\u{1E07}', 1624, 1627),
+    ('\u{1E08}', 1627, 1632),
+    ('\u{1E09}', 1632, 1637),
+    ('\u{1E0A}', 1637, 1640),
+    ('\u{1E0B}', 1640, 1643),
+    ('\u{1E0C}', 1643, 1646),
+    ('\u{1E0D}', 1646, 1649),
+    ('\u{1E0E}', 1649, 1652),
+    ('\u{1E0F}', 1652, 1655),
+    ('\u{1E10}', 1655, 1658),
+    ('\u{1E11}', 1658, 1661),
+    ('\u{1E12}', 1661, 1664),
+    ('\u{1E13}', 1664, 1667),
+    ('\u{1E14}', 1667, 1672),
+    ('\u{1E15}', 1672, 1677),
+    ('\u{1E16}', 1677, 1682),
+    ('\u{1E17}', 1682, 1687),
+    ('\u{1E18}', 1687, 1690),
+    ('\u{1E19}', 1690, 1693),
+    ('\u{1E1A}', 1693, 1696),
+    ('\u{1E1B}', 1696, 1699),
+    ('\u{1E1C}', 1699, 1704),
+    ('\u{1E1D}', 1704, 1709),
+    ('\u{1E1E}', 1709, 1712),
+    ('\u{1E1F}', 1712, 1715),
+    ('\u{1E20}', 1715, 1718),
+    ('\u{1E21}', 1718, 1721),
+    ('\u{1E22}', 1721, 1724),
+    ('\u{1E23}', 1724, 1727),
+    ('\u{1E24}', 1727, 1730),
+    ('\u{1E25}', 1730, 1733),
+    ('\u{1E26}', 1733, 1736),
+    ('\u{1E27}', 1736, 1739),
+    ('\u{1E28}', 1739, 1742),
+    ('\u{1E29}', 1742, 1745),
+    ('\u{1E2A}', 1745, 1748),
+    ('\u{1E2B}', 1748, 1751),
+    ('\u{1E2C}', 1751, 1754),
+    ('\u{1E2D}', 1754, 1757),
+    ('\u{1E2E}', 1757, 1762),
+    ('\u{1E2F}', 1762, 1767),
+    ('\u{1E30}', 1767, 1770),
+    ('\u{1E31}', 1770, 1773),
+    ('\u{1E32}', 1773, 1776),
+    ('\u{1E33}', 1776, 1779),
+    ('\u{1E34}', 1779, 1782),
+    ('\u{1E35}', 1782, 1785),
+    ('\u{1E36}', 1785, 1788),
+    ('\u{1E37}', 1788, 1791),
+    ('\u{1E38}', 1791, 1796),
+    ('\u{1E39}', 1796, 1801),
+    ('\u{1E3A}', 1801, 1804),
+    ('\u{1E3B}', 1804, 1807),
+    ('\u{1E3C}', 1807, 1810),
+    ('\u{1E3D}', 1810, 1813),
+    ('\u{1E3E}', 1813, 1816),
+    ('\u{1E3F}', 1816, 1819),
+    ('\u{1E40}', 1819, 1822),
+    ('\u{1E41}', 1822, 1825),
+    ('\u{1E42}', 1825, 1828),
+    ('\u{1E43}', 1828, 1831),
+    ('\u{1E44}', 1831, 1834),
+    ('\u{1E45}', 1834, 1837),
+    ('\u{1E46}', 1837, 1840),
+    ('\u{1E47}', 1840, 1843),
+    ('\u{1E48}', 1843, 1846),
+    ('\u{1E49}', 1846, 1849),
+    ('\u{1E4A}', 1849, 1852),
+    ('\u{1E4B}', 1852, 1855),
+    ('\u{1E4C}', 1855, 1860),
+    ('\u{1E4D}', 1860, 1865),
+    ('\u{1E4E}', 1865, 1870),
+    ('\u{1E4F}', 1870, 1875),
+    ('\u{1E50}', 1875, 1880),
+    ('\u{1E51}', 1880, 1885),
+    ('\u{1E52}', 1885, 1890),
+    ('\u{1E53}', 1890, 1895),
+    ('\u{1E54}', 1895, 1898),
+    ('\u{1E55}', 1898, 1901),
+    ('\u{1E56}', 1901, 1904),
+    ('\u{1E57}', 1904, 1907),
+    ('\u{1E58}', 1907, 1910),
+    ('\u{1E59}', 1910, 1913),
+    ('\u{1E5A}', 1913, 1916),
+    ('\u{1E5B}', 1916, 1919),
+    ('\u{1E5C}', 1919, 1924),
+    ('\u{1E5D}', 1924, 1929),
+    ('\u{1E5E}', 1929, 1932),
+    ('\u{1E5F}', 1932, 1935),
+    ('\u{1E60}', 1935, 1938),
+    ('\u{1E61}', 1938, 1941),
+    ('\u{1E62}', 1941, 1944),
+    ('\u{1E63}', 1944, 1947),
+    ('\u{1E64}', 1947, 1952),
+    ('\u{1E65}', 1952, 1957),
+    ('\u{1E66}', 1957, 1962),
+    ('\u{1E67}', 1962, 1967),
+    ('\u{1E68}', 1967, 1972),
+    ('\u{1E69}', 1972, 1977),
+    ('\u{1E6A}', 1977, 1980),
+    ('\u{1E6B}', 1980, 1983),
+    ('\u{1E6C}', 1983, 1986),
+    ('\u{1E6D}', 1986, 1989),
+    ('\u{1E6E}', 1989, 1992),
+    ('\u{1E6F}', 1992, 1995),
+    ('\u{1E70}', 1995, 1998),
+    ('\u{1E71}', 1998, 2001),
+    ('\u{1E72}', 2001, 2004),
+    ('\u{1E73}', 2004, 2007),
+    ('\u{1E74}', 2007, 2010),
+    ('\u{1E75}', 2010, 2013),
+    ('\u{1E76}', 2013, 2016),
+    ('\u{1E77}', 2016, 2019),
+    ('\u{1E78}', 2019, 2024),
+    ('\u{1E79}', 2024, 2029),
+    ('\u{1E7A}', 2029, 2034),
+    ('\u{1E7B}', 2034, 2039),
+    ('\u{1E7C}', 2039, 2042),
+    ('\u{1E7D}', 2042, 2045),
+    ('\u{1E7E}', 2045, 2048),
+    ('\u{1E7F}', 2048, 2051),
+    ('\u{1E80}', 2051, 2054),
+    ('\u{1E81}', 2054, 2057),
+    ('\u{1E82}', 2057, 2060),
+    ('\u{1E83}', 2060, 2063),
+    ('\u{1E84}', 2063, 2066),
+    ('\u{1E85}', 2066, 2069),
+    ('\u{1E86}', 2069, 2072),
+    ('\u{1E87}', 2072, 2075),
+    ('\u{1E88}', 2075, 2078),
+    ('\u{1E89}', 2078, 2081),
+    ('\u{1E8A}', 2081, 2084),
+    ('\u{1E8B}', 2084, 2087),
+    ('\u{1E8C}', 2087, 2090),
+    ('\u{1E8D}', 2090, 2093),
+    ('\u{1E8E}', 2093, 2096),
+    ('\u{1E8F}', 2096, 2099),
+    ('\u{1E90}', 2099, 2102),
+    ('\u{1E91}', 2102, 2105),
+    ('\u{1E92}', 2105, 2108),
+    ('\u{1E93}', 2108, 2111),
+    ('\u{1E94}', 2111, 2114),
+    ('\u{1E95}', 2114, 2117),
+    ('\u{1E96}', 2117, 2120),
+    ('\u{1E97}', 2120, 2123),
+    ('\u{1E98}', 2123, 2126),
+    ('\u{1E99}', 2126, 2129),
+    ('\u{1E9B}', 2129, 2133),
+    ('\u{1EA0}', 2133, 2136),
+    ('\u{1EA1}', 2136, 2139),
+    ('\u{1EA2}', 2139, 2142),
+    ('\u{1EA3}', 2142, 2145),
+    ('\u{1EA4}', 2145, 2150),
+    ('\u{1EA5}', 2150, 2155),
+    ('\u{1EA6}', 2155, 2160),
+    ('\u{1EA7}', 2160, 2165),
+    ('\u{1EA8}', 2165, 2170),
+    ('\u{1EA9}', 2170, 2175),
+    ('\u{1EAA}', 2175, 2180),
+    ('\u{1EAB}', 2180, 2185),
+    ('\u{1EAC}', 2185, 2190),
+    ('\u{1EAD}', 2190, 2195),
+    ('\u{1EAE}', 2195, 2200),
+    ('\u{1EAF}', 2200, 2205),
+    ('\u{1EB0}', 2205, 2210),
+    ('\u{1EB1}', 2210, 2215),
+    ('\u{1EB2}', 2215, 2220),
+    ('\u{1EB3}', 2220, 2225),
+    ('\u{1EB4}', 2225, 2230),
+    ('\u{1EB5}', 2230, 2235),
+    ('\u{1EB6}', 2235, 2240),
+    ('\u{1EB7}', 2240, 2245),
+    ('\u{1EB8}', 2245, 2248),
+    ('\u{1EB9}', 2248, 2251),
+    ('\u{1EBA}', 2251, 2254),
+    ('\u{1EBB}', 2254, 2257),
+    ('\u{1EBC}', 2257, 2260),
+    ('\u{1EBD}', 2260, 2263),
+    ('\u{1EBE}', 2263, 2268),
+    ('\u{1EBF}', 2268, 2273),
+    ('\u{1EC0}', 2273, 2278),
+    ('\u{1EC1}', 2278, 2283),
+    ('\u{1EC2}', 2283, 2288),
+    ('\u{1EC3}', 2288, 2293),
+    ('\u{1EC4}', 2293, 2298),
+    ('\u{1EC5}', 2298, 2303),
+    ('\u{1EC6}', 2303, 2308),
+    ('\u{1EC7}', 2308, 2313),
+    ('\u{1EC8}', 2313, 2316),
+    ('\u{1EC9}', 2316, 2319),
+    ('\u{1ECA}', 2319, 2322),
+    ('\u{1ECB}', 2322, 2325),
+    ('\u{1ECC}', 2325, 2328),
+    ('\u{1ECD}', 2328, 2331),
+    ('\u{1ECE}', 2331, 2334),
+    ('\u{1ECF}', 2334, 2337),
+    ('\u{1ED0}', 2337, 2342),
+    ('\u{1ED1}', 2342, 2347),
+    ('\u{1ED2}', 2347, 2352),
+    ('\u{1ED3}', 2352, 2357),
+    ('\u{1ED4}', 2357, 2362),
+    ('\u{1ED5}', 2362, 2367),
+    ('\u{1ED6}', 2367, 2372),
+    ('\u{1ED7}', 2372, 2377),
+    ('\u{1ED8}', 2377, 2382),
+    ('\u{1ED9}', 2382, 2387),
+    ('\u{1EDA}', 2387, 2392),
+    ('\u{1EDB}', 2392, 2397),
+    ('\u{1EDC}', 2397, 2402),
+    ('\u{1EDD}', 2402, 2407),
+    ('\u{1EDE}', 2407, 2412),
+    ('\u{1EDF}', 2412, 2417),
+    ('\u{1EE0}', 2417, 2422),
+    ('\u{1EE1}', 2422, 2427),
+    ('\u{1EE2}', 2427, 2432),
+    ('\u{1EE3}', 2432, 2437),
+    ('\u{1EE4}', 2437, 2440),
+    ('\u{1EE5}', 2440, 2443),
+    ('\u{1EE6}', 2443, 2446),
+    ('\u{1EE7}', 2446, 2449),
+    ('\u{1EE8}', 2449, 2454),
+    ('\u{1EE9}', 2454, 2459),
+    ('\u{1EEA}', 2459, 2464),
+    ('\u{1EEB}', 2464, 2469),
+    ('\u{1EEC}', 2469, 2474),
+    ('\u{1EED}', 2474, 2479),
+    ('\u{1EEE}', 2479, 2484),
+    ('\u{1EEF}', 2484, 2489),
+    ('\u{1EF0}', 2489, 2494),
+    ('\u{1EF1}', 2494, 2499),
+    ('\u{1EF2}', 2499, 2502),
+    ('\u{1EF3}', 2502, 2505),
+    ('\u{1EF4}', 2505, 2508),
+    ('\u{1EF5}', 2508, 2511),
+    ('\u{1EF6}', 2511, 2514),
+    ('\u{1EF7}', 2514, 2517),
+    ('\u{1EF8}', 2517, 2520),
+    ('\u{1EF9}', 2520, 2523),
+    ('\u{1F00}', 2523, 2527),
+    ('\u{1F01}', 2527, 2531),
+    ('\u{1F02}', 2531, 2537),
+    ('\u{1F03}', 2537, 2543),
+    ('\u{1F04}', 2543, 2549),
+    ('\u{1F05}', 2549, 2555),
+    ('\u{1F06}', 2555, 2561),
+    ('\u{1F07}', 2561, 2567),
+    ('\u{1F08}', 2567, 2571),
+    ('\u{1F09}', 2571, 2575),
+    ('\u{1F0A}', 2575, 2581),
+    ('\u{1F0B}', 2581, 2587),
+    ('\u{1F0C}', 2587, 2593),
+    ('\u{1F0D}', 2593, 2599),
+    ('\u{1F0E}', 2599, 2605),
+    ('\u{1F0F}', 2605, 2611),
+    ('\u{1F10}', 2611, 2615),
+    ('\u{1F11}', 2615, 2619),
+    ('\u{1F12}', 2619, 2625),
+    ('\u{1F13}', 2625, 2631),
+    ('\u{1F14}', 2631, 2637),
+    ('\u{1F15}', 2637, 2643),
+    ('\u{1F18}', 2643, 2647),
+    ('\u{1F19}', 2647, 2651),
+    ('\u{1F1A}', 2651, 2657),
+    ('\u{1F1B}', 2657, 2663),
+    ('\u{1F1C}', 2663, 2669),
+    ('\u{1F1D}', 2669, 2675),
+    ('\u{1F20}', 2675, 2679),
+    ('\u{1F21}', 2679, 2683),
+    ('\u{1F22}', 2683, 2689),
+    ('\u{1F23}', 2689, 2695),
+    ('\u{1F24}', 2695, 2701),
+    ('\u{1F25}', 2701, 2707),
+    ('\u{1F26}', 2707, 2713),
+    ('\u{1F27}', 2713, 2719),
+    ('\u{1F28}', 2719, 2723),
+    ('\u{1F29}', 2723, 2727),
+    ('\u{1F2A}', 2727, 2733),
+    ('\u{1F2B}', 2733, 2739),
+    ('\u{1F2C}', 2739, 2745),
+    ('\u{1F2D}', 2745, 2751),
+    ('\u{1F2E}', 2751, 2757),
+    ('\u{1F2F}', 2757, 2763),
+    ('\u{1F30}', 2763, 2767),
+    ('\u{1F31}', 2767, 2771),
+    ('\u{1F32}', 2771, 2777),
+    ('\u{1F33}', 2777, 2783),
+    ('\u{1F34}', 2783, 2789),
+    ('\u{1F35}', 2789, 2795),
+    ('\u{1F36}', 2795, 2801),
+    ('\u{1F37}', 2801, 2807),
+    ('\u{1F38}', 2807, 2811),
+    ('\u{1F39}', 2811, 2815),
+    ('\u{1F3A}', 2815, 2821),
+    ('\u{1F3B}', 2821, 2827),
+    ('\u{1F3C}', 2827, 2833),
+    ('\u{1F3D}', 2833, 2839),
+    ('\u{1F3E}', 2839, 2845),
+    ('\u{1F3F}', 2845, 2851),
+    ('\u{1F40}', 2851, 2855),
+    ('\u{1F41}', 2855, 2859),
+    ('\u{1F42}', 2859, 2865),
+    ('\u{1F43}', 2865, 2871),
+    ('\u{1F44}', 2871, 2877),
+    ('\u{1F45}', 2877, 2883),
+    ('\u{1F48}', 2883, 2887),
+    ('\u{1F49}', 2887, 2891),
+    ('\u{1F4A}', 2891, 2897),
+    ('\u{1F4B}', 2897, 2903),
+    ('\u{1F4C}', 2903, 2909),
+    ('\u{1F4D}', 2909, 2915),
+    ('\u{1F50}', 2915, 2919),
+    ('\u{1F51}', 2919, 2923),
+    ('\u{1F52}', 2923, 2929),
+    ('\u{1F53}', 2929, 2935),
+    ('\u{1F54}', 2935, 2941),
+    ('\u{1F55}', 2941, 2947),
+    ('\u{1F56}', 2947, 2953),
+    ('\u{1F57}', 2953, 2959),
+    ('\u{1F59}', 2959, 2963),
+    ('\u{1F5B}', 2963, 2969),
+    ('\u{1F5D}', 2969, 2975),
+    ('\u{1F5F}', 2975, 2981),
+    ('\u{1F60}', 2981, 2985),
+    ('\u{1F61}', 2985, 2989),
+    ('\u{1F62}', 2989, 2995),
+    ('\u{1F63}', 2995, 3001),
+    ('\u{1F64}', 3001, 3007),
+    ('\u{1F65}', 3007, 3013),
+    ('\u{1F66}', 3013, 3019),
+    ('\u{1F67}', 3019, 3025),
+    ('\u{1F68}', 3025, 3029),
+    ('\u{1F69}', 3029, 3033),
+    ('\u{1F6A}', 3033, 3039),
+    ('\u{1F6B}', 3039, 3045),
+    ('\u{1F6C}', 3045, 3051),
+    ('\u{1F6D}', 3051, 3057),
+    ('\u{1F6E}', 3057, 3063),
+    ('\u{1F6F}', 3063, 3069),
+    ('\u{1F70}', 3069, 3073),
+    ('\u{1F71}', 869, 873),
+    ('\u{1F72}', 3073, 3077),
+    ('\u{1F73}', 873, 877),
+    ('\u{1F74}', 3077, 3081),
+    ('\u{1F75}', 877, 881),
+    ('\u{1F76}', 3081, 3085),
+    ('\u{1F77}', 881, 885),
+    ('\u{1F78}', 3085, 3089),
+    ('\u{1F79}', 899, 903),
+    ('\u{1F7A}', 3089, 3093),
+    ('\u{1F7B}', 903, 907),
+    ('\u{1F7C}', 3093, 3097),
+    ('\u{1F7D}', 907, 911),
+    ('\u{1F80}', 3097, 3103),
+    ('\u{1F81}', 3103, 3109),
+    ('\u{1F82}', 3109, 3117),
+    ('\u{1F83}', 3117, 3125),
+    ('\u{1F84}', 3125, 3133),
+    ('\u{1F85}', 3133, 3141),
+    ('\u{1F86}', 3141, 3149),
+    ('\u{1F87}', 3149, 3157),
+    ('\u{1F88}', 3157, 3163),
+    ('\u{1F89}', 3163, 3169),
+    ('\u{1F8A}', 3169, 3177),
+    ('\u{1F8B}', 3177, 3185),
+    ('\u{1F8C}', 3185, 3193),
+    ('\u{1F8D}', 3193, 3201),
+    ('\u{1F8E}', 3201, 3209),
+    ('\u{1F8F}', 3209, 3217),
+    ('\u{1F90}', 3217, 3223),
+    ('\u{1F91}', 3223, 3229),
+    ('\u{1F92}', 3229, 3237),
+    ('\u{1F93}', 3237, 3245),
+    ('\u{1F94}', 3245, 3253),
+    ('\u{1F95}', 3253, 3261),
+    ('\u{1F96}', 3261, 3269),
+    ('\u{1F97}', 3269, 3277),
+    ('\u{1F98}', 3277, 3283),
+    ('\u{1F99}', 3283, 3289),
+    ('\u{1F9A}', 3289, 3297),
+    ('\u{1F9B}', 3297, 3305),
+    ('\u{1F9C}', 3305, 3313),
+    ('\u{1F9D}', 3313, 3321),
+    ('\u{1F9E}', 3321, 3329),
+    ('\u{1F9F}', 3329, 3337),
+    ('\u{1FA0}', 3337, 3343),
+    ('\u{1FA1}', 3343, 3349),
+    ('\u{1FA2}', 3349, 3357),
+    ('\u{1FA3}', 3357, 3365),
+    ('\u{1FA4}', 3365, 3373),
+    ('\u{1FA5}', 3373, 3381),
+    ('\u{1FA6}', 3381, 3389),
+    ('\u{1FA7}', 3389, 3397),
+    ('\u{1FA8}', 3397, 3403),
+    ('\u{1FA9}', 3403, 3409),
+    ('\u{1FAA}', 3409, 3417),
+    ('\u{1FAB}', 3417, 3425),
+    ('\u{1FAC}', 3425, 3433),
+    ('\u{1FAD}', 3433, 3441),
+    ('\u{1FAE}', 3441, 3449),
+    ('\u{1FAF}', 3449, 3457),
+    ('\u{1FB0}', 3457, 3461),
+    ('\u{1FB1}', 3461, 3465),
+    ('\u{1FB2}', 3465, 3471),
+    ('\u{1FB3}', 3471, 3475),
+    ('\u{1FB4}', 3475, 3481),
+    ('\u{1FB6}', 3481, 3485),
+    ('\u{1FB7}', 3485, 3491),
+    ('\u{1FB8}', 3491, 3495),
+    ('\u{1FB9}', 3495, 3499),
+    ('\u{1FBA}', 3499, 3503),
+    ('\u{1FBB}', 825, 829),
+    ('\u{1FBC}', 3503, 3507),
+    ('\u{1FBE}', 3507, 3509),
+    ('\u{1FC1}', 3509, 3513),
+    ('\u{1FC2}', 3513, 3519),
+    ('\u{1FC3}', 3519, 3523),
+    ('\u{1FC4}', 3523, 3529),
+    ('\u{1FC6}', 3529, 3533),
+    ('\u{1FC7}', 3533, 3539),
+    ('\u{1FC8}', 3539, 3543),
+    ('\u{1FC9}', 831, 835),
+    ('\u{1FCA}', 3543, 3547),
+    ('\u{1FCB}', 835, 839),
+    ('\u{1FCC}', 3547, 3551),
+    ('\u{1FCD}', 3551, 3556),
+    ('\u{1FCE}', 3556, 3561),
+    ('\u{1FCF}', 3561, 3566),
+    ('\u{1FD0}', 3566, 3570),
+    ('\u{1FD1}', 3570, 3574),
+    ('\u{1FD2}', 3574, 3580),
+    ('\u{1FD3}', 855, 861),
+    ('\u{1FD6}', 3580, 3584),
+    ('\u{1FD7}', 3584, 3590),
+    ('\u{1FD8}', 3590, 3594),
+    ('\u{1FD9}', 3594, 3598),
+    ('\u{1FDA}', 3598, 3602),
+    ('\u{1FDB}', 839, 843),
+    ('\u{1FDD}', 3602, 3607),
+    ('\u{1FDE}', 3607, 3612),
+    ('\u{1FDF}', 3612, 3617),
+    ('\u{1FE0}', 3617, 3621),
+    ('\u{1FE1}', 3621, 3625),
+    ('\u{1FE2}', 3625, 3631),
+    ('\u{1FE3}', 885, 891),
+    ('\u{1FE4}', 3631, 3635),
+    ('\u{1FE5}', 3635, 3639),
+    ('\u{1FE6}', 3639, 3643),
+    ('\u{1FE7}', 3643, 3649),
+    ('\u{1FE8}', 3649, 3653),
+    ('\u{1FE9}', 3653, 3657),
+    ('\u{1FEA}', 3657, 3661),
+    ('\u{1FEB}', 847, 851),
+    ('\u{1FEC}', 3661, 3665),
+    ('\u{1FED}', 3665, 3669),
+    ('\u{1FEE}', 821, 825),
+    ('\u{1FEF}', 3669, 3670),
+    ('\u{1FF2}', 3670, 3676),
+    ('\u{1FF3}', 3676, 3680),
+    ('\u{1FF4}', 3680, 3686),
+    ('\u{1FF6}', 3686, 3690),
+    ('\u{1FF7}', 3690, 3696),
+    ('\u{1FF8}', 3696, 3700),
+    ('\u{1FF9}', 843, 847),
+    ('\u{1FFA}', 3700, 3704),
+    ('\u{1FFB}', 851, 855),
+    ('\u{1FFC}', 3704, 3708),
+    ('\u{1FFD}', 3708, 3710),
+    ('\u{2000}', 3710, 3713),
+    ('\u{2001}', 3713, 3716),
+    ('\u{2126}', 3716, 3718),
+    ('\u{212A}', 3718, 3719),
+    ('\u{212B}', 15, 18),
+    ('\u{219A}', 3719, 3724),
+    ('\u{219B}', 3724, 3729),
+    ('\u{21AE}', 3729, 3734),
+    ('\u{21CD}', 3734, 3739),
+    ('\u{21CE}', 3739, 3744),
+    ('\u{21CF}', 3744, 3749),
+    ('\u{2204}', 3749, 3754),
+    ('\u{2209}', 3754, 3759),
+    ('\u{220C}', 3759, 3764),
+    ('\u{2224}', 3764, 3769),
+    ('\u{2226}', 3769, 3774),
+    ('\u{2241}', 3774, 3779),
+    ('\u{2244}', 3779, 3784),
+    ('\u{2247}', 3784, 3789),
+    ('\u{2249}', 3789, 3794),
+    ('\u{2260}', 3794, 3797),
+    ('\u{2262}', 3797, 3802),
+    ('\u{226D}', 3802, 3807),
+    ('\u{226E}', 3807, 3810),
+    ('\u{226F}', 3810, 3813),
+    ('\u{2270}', 3813, 3818),
+    ('\u{2271}', 3818, 3823),
+    ('\u{2274}', 3823, 3828),
+    ('\u{2275}', 3828, 3833),
+    ('\u{2278}', 3833, 3838),
+    ('\u{2279}', 3838, 3843),
+    ('\u{2280}', 3843, 3848),
+    ('\u{2281}', 3848, 3853),
+    ('\u{2284}', 3853, 3858),
+    ('\u{2285}', 3858, 3863),
+    ('\u{2288}', 3863, 3868),
+    ('\u{2289}', 3868, 3873),
+    ('\u{22AC}', 3873, 3878),
+    ('\u{22AD}', 3878, 3883),
+    ('\u{22AE}', 3883, 3888),
+    ('\u{22AF}', 3888, 3893),
+    ('\u{22E0}', 3893, 3898),
+    ('\u{22E1}', 3898, 3903),
+    ('\u{22E2}', 3903, 3908),
+    ('\u{22E3}', 3908, 3913),
+    ('\u{22EA}', 3913, 3918),
+    ('\u{22EB}', 3918, 3923),
+    ('\u{22EC}', 3923, 3928),
+    ('\u{22ED}', 3928, 3933),
+    ('\u{2329}', 3933, 3936),
+    ('\u{232A}', 3936, 3939),
+    ('\u{2ADC}', 3939, 3944),
+    ('\u{304C}', 3944, 3950),
+    ('\u{304E}', 3950, 3956),
+    ('\u{3050}', 3956, 3962),
+    ('\u{3052}', 3962, 3968),
+    ('\u{3054}', 3968, 3974),
+    ('\u{3056}', 3974, 3980),
+    ('\u{3058}', 3980, 3986),
+    ('\u{305A}', 3986, 3992),
+    ('\u{305C}', 3992, 3998),
+    ('\u{305E}', 3998, 4004),
+    ('\u{3060}', 4004, 4010),
+    ('\u{3062}', 4010, 4016),
+    ('\u{3065}', 4016, 4022),
+    ('\u{3067}', 4022, 4028),
+    ('\u{3069}', 4028, 4034),
+    ('\u{3070}', 4034, 4040),
+    ('\u{3071}', 4040, 4046),
+    ('\u{3073}', 4046, 4052),
+    ('\u{3074}', 4052, 4058),
+    ('\u{3076}', 4058, 4064),
+    ('\u{3077}', 4064, 4070),
+    ('\u{3079}', 4070, 4076),
+    ('\u{307A}', 4076, 4082),
+    ('\u{307C}', 4082, 4088),
+    ('\u{307D}', 4088, 4094),
+    ('\u{3094}', 4094, 4100),
+    ('\u{309E}', 4100, 4106),
+    ('\u{30AC}', 4106, 4112),
+    ('\u{30AE}', 4112, 4118),
+    ('\u{30B0}', 4118, 4124),
+    ('\u{30B2}', 4124, 4130),
+    ('\u{30B4}', 4130, 4136),
+    ('\u{30B6}', 4136, 4142),
+    ('\u{30B8}', 4142, 4148),
+    ('\u{30BA}', 4148, 4154),
+    ('\u{30BC}', 4154, 4160),
+    ('\u{30BE}', 4160, 4166),
+    ('\u{30C0}', 4166, 4172),
+    ('\u{30C2}', 4172, 4178),
+    ('\u{30C5}', 4178, 4184),
+    ('\u{30C7}', 4184, 4190),
+    ('\u{30C9}', 4190, 4196),
+    ('\u{30D0}', 4196, 4202),
+    ('\u{30D1}', 4202, 4208),
+    ('\u{30D3}', 4208, 4214),
+    ('\u{30D4}', 4214, 4220),
+    ('\u{30D6}', 4220, 4226),
+    ('\u{30D7}', 4226, 4232),
+    ('\u{30D9}', 4232, 4238),
+    ('\u{30DA}', 4238, 4244),
+    ('\u{30DC}', 4244, 4250),
+    ('\u{30DD}', 4250, 4256),
+    ('\u{30F4}', 4256, 4262),
+    ('\u{30F7}', 4262, 4268),
+    ('\u{30F8}', 4268, 4274),
+    ('\u{30F9}', 4274, 4280),
+    ('\u{30FA}', 4280, 4286),
+    ('\u{30FE}', 4286, 4292),
+    ('\u{F900}', 4292, 4295),
+    ('\u{F901}', 4295, 4298),
+    ('\u{F902}', 4298, 4301),
+    ('\u{F903}', 4301, 4304),
+    ('\u{F904}', 4304, 4307),
+    ('\u{F905}', 4307, 4310),
+    ('\u{F906}', 4310, 4313),
+    ('\u{F907}', 4313, 4316),
+    ('\u{F908}', 4313, 4316),
+    ('\u{F909}', 4316, 4319),
+    ('\u{F90A}', 4319, 4322),
+    ('\u{F90B}', 4322, 4325),
+    ('\u{F90C}', 4325, 4328),
+    ('\u{F90D}', 4328, 4331),
+    ('\u{F90E}', 4331, 4334),
+    ('\u{F90F}', 4334, 4337),
+    ('\u{F910}', 4337, 4340),
+    ('\u{F911}', 4340, 4343),
+    ('\u{F912}', 4343, 4346),
+    ('\u{F913}', 4346, 4349),
+    ('\u{F914}', 4349, 4352),
+    ('\u{F915}', 4352, 4355),
+    ('\u{F916}', 4355, 4358),
+    ('\u{F917}', 4358, 4361),
+    ('\u{F918}', 4361, 4364),
+    ('\u{F919}', 4364, 4367),
+    ('\u{F91A}', 4367, 4370),
+    ('\u{F91B}', 4370, 4373),
+    ('\u{F91C}', 4373, 4376),
+    ('\u{F91D}', 4376, 4379),
+    ('\u{F91E}', 4379, 4382),
+    ('\u{F91F}', 4382, 4385),
+    ('\u{F920}', 4385, 4388),
+    ('\u{F921}', 4388, 4391),
+    ('\u{F922}', 4391, 4394),
+    ('\u{F923}', 4394, 4397),
+    ('\u{F924}', 4397, 4400),
+    ('\u{F925}', 4400, 4403),
+    ('\u{F926}', 4403, 4406),
+    ('\u{F927}', 4406, 4409),
+    ('\u{F928}', 4409, 4412),
+    ('\u{F929}', 4412, 4415),
+    ('\u{F92A}', 4415, 4418),
+    ('\u{F92B}', 4418, 4421),
+    ('\u{F92C}', 4421, 4424),
+    ('\u{F92D}', 4424, 4427),
+    ('\u{F92E}', 4427, 4430),
+    ('\u{F92F}', 4430, 4433),
+    ('\u{F930}', 4433, 4436),
+    ('\u{F931}', 4436, 4439),
+    ('\u{F932}', 4439, 4442),
+    ('\u{F933}', 4442, 4445),
+    ('\u{F934}', 4445, 4448),
+    ('\u{F935}', 4448, 4451),
+    ('\u{F936}', 4451, 4454),
+    ('\u{F937}', 4454, 4457),
+    ('\u{F938}', 4457, 4460),
+    ('\u{F939}', 4460, 4463),
+    ('\u{F93A}', 4463, 4466),
+    ('\u{F93B}', 4466, 4469),
+    ('\u{F93C}', 4469, 4472),
+    ('\u{F93D}', 4472, 4475),
+    ('\u{F93E}', 4475, 4478),
+    ('\u{F93F}', 4478, 4481),
+    ('\u{F940}', 4481, 4484),
+    ('\u{F941}', 4484, 4487),
+    ('\u{F942}', 4487, 4490),
+    ('\u{F943}', 4490, 4493),
+    ('\u{F944}', 4493, 4496),
+    ('\u{F945}', 4496, 4499),
+    ('\u{F946}', 4499, 4502),
+    ('\u{F947}', 4502, 4505),
+    ('\u{F948}', 4505, 4508),
+    ('\u{F949}', 4508, 4511),
+    ('\u{F94A}', 4511, 4514),
+    ('\u{F94B}', 4514, 4517),
+    ('\u{F94C}', 4517, 4520),
+    ('\u{F94D}', 4520, 4523),
+    ('\u{F94E}', 4523, 4526),
+    ('\u{F94F}', 4526, 4529),
+    ('\u{F950}', 4529, 4532),
+    ('\u{F951}', 4532, 4535),
+    ('\u{F952}', 4535, 4538),
+    ('\u{F953}', 4538, 4541),
+    ('\u{F954}', 4541, 4544),
+    ('\u{F955}', 4544, 4547),
+    ('\u{F956}', 4547, 4550),
+    ('\u{F957}', 4550, 4553),
+    ('\u{F958}', 4553, 4556),
+    ('\u{F959}', 4556, 4559),
+    ('\u{F95A}', 4559, 4562),
+    ('\u{F95B}', 4562, 4565),
+    ('\u{F95C}', 4349, 4352),
+    ('\u{F95D}', 4565, 4568),
+    ('\u{F95E}', 4568, 4571),
+    ('\u{F95F}', 4571, 4574),
+    ('\u{F960}', 4574, 4577),
+    ('\u{F961}', 4577, 4580),
+    ('\u{F962}', 4580, 4583),
+    ('\u{F963}', 4583, 4586),
+    ('\u{F964}', 4586, 4589),
+    ('\u{F965}', 4589, 4592),
+    ('\u{F966}', 4592, 4595),
+    ('\u{F967}', 4595, 4598),
+    ('\u{F968}', 4598, 4601),
+    ('\u{F969}', 4601, 4604),
+    ('\u{F96A}', 4604, 4607),
+    ('\u{F96B}', 4607, 4610),
+    ('\u{F96C}', 4610, 4613),
+    ('\u{F96D}', 4613, 4616),
+    ('\u{F96E}', 4616, 4619),
+    ('\u{F96F}', 4619, 4622),
+    ('\u{F970}', 4622, 4625),
+    ('\u{F971}', 4625, 4628),
+    ('\u{F972}', 4628, 4631),
+    ('\u{F973}', 4631, 4634),
+    ('\u{F974}', 4634, 4637),
+    ('\u{F975}', 4637, 4640),
+    ('\u{F976}', 4640, 4643),
+    ('\u{F977}', 4643, 4646),
+    ('\u{F978}', 4646, 4649),
+    ('\u{F979}', 4649, 4652),
+    ('\u{F97A}', 4652, 4655),
+    ('\u{F97B}', 4655, 4658),
+    ('\u{F97C}', 4658, 4661),
+    ('\u{F97D}', 4661, 4664),
+    ('\u{F97E}', 4664, 4667),
+    ('\u{F97F}', 4667, 4670),
+    ('\u{F980}', 4670, 4673),
+    ('\u{F981}', 4673, 4676),
+    ('\u{F982}', 4676, 4679),
+    ('\u{F983}', 4679, 4682),
+    ('\u{F984}', 4682, 4685),
+    ('\u{F985}', 4685, 4688),
+    ('\u{F986}', 4688, 4691),
+    ('\u{F987}', 4691, 4694),
+    ('\u{F988}', 4694, 4697),
+    ('\u{F989}', 4697, 4700),
+    ('\u{F98A}', 4700, 4703),
+    ('\u{F98B}', 4703, 4706),
+    ('\u{F98C}', 4706, 4709),
+    ('\u{F98D}', 4709, 4712),
+    ('\u{F98E}', 4712, 4715),
+    ('\u{F98F}', 4715, 4718),
+    ('\u{F990}', 4718, 4721),
+    ('\u{F991}', 4721, 4724),
+    ('\u{F992}', 4724, 4727),
+    ('\u{F993}', 4727, 4730),
+    ('\u{F994}', 4730, 4733),
+    ('\u{F995}', 4733, 4736),
+    ('\u{F996}', 4736, 4739),
+    ('\u{F997}', 4739, 4742),
+    ('\u{F998}', 4742, 4745),
+    ('\u{F999}', 4745, 4748),
+    ('\u{F99A}', 4748, 4751),
+    ('\u{F99B}', 4751, 4754),
+    ('\u{F99C}', 4754, 4757),
+    ('\u{F99D}', 4757, 4760),
+    ('\u{F99E}', 4760, 4763),
+    ('\u{F99F}', 4763, 4766),
+    ('\u{F9A0}', 4766, 4769),
+    ('\u{F9A1}', 4619, 4622),
+    ('\u{F9A2}', 4769, 4772),
+    ('\u{F9A3}', 4772, 4775),
+    ('\u{F9A4}', 4775, 4778),
+    ('\u{F9A5}', 4778, 4781),
+    ('\u{F9A6}', 4781, 4784),
+    ('\u{F9A7}', 4784, 4787),
+    ('\u{F9A8}', 4787, 4790),
+    ('\u{F9A9}', 4790, 4793),
+    ('\u{F9AA}', 4571, 4574),
+    ('\u{F9AB}', 4793, 4796),
+    ('\u{F9AC}', 4796, 4799),
+    ('\u{F9AD}', 4799, 4802),
+    ('\u{F9AE}', 4802, 4805),
+    ('\u{F9AF}', 4805, 4808),
+    ('\u{F9B0}', 4808, 4811),
+    ('\u{F9B1}', 4811, 4814),
+    ('\u{F9B2}', 4814, 4817),
+    ('\u{F9B3}', 4817, 4820),
+    ('\u{F9B4}', 4820, 4823),
+    ('\u{F9B5}', 4823, 4826),
+    ('\u{F9B6}', 4826, 4829),
+    ('\u{F9B7}', 4829, 4832),
+    ('\u{F9B8}', 4832, 4835),
+    ('\u{F9B9}', 4835, 4838),
+    ('\u{F9BA}', 4838, 4841),
+    ('\u{F9BB}', 4841, 4844),
+    ('\u{F9BC}', 4844, 4847),
+    ('\u{F9BD}', 4847, 4850),
+    ('\u{F9BE}', 4850, 4853),
+    ('\u{F9BF}', 4349, 4352),
+    ('\u{F9C0}', 4853, 4856),
+    ('\u{F9C1}', 4856, 4859),
+    ('\u{F9C2}', 4859, 4862),
+    ('\u{F9C3}', 4862, 4865),
+    ('\u{F9C4}', 4865, 4868),
+    ('\u{F9C5}', 4868, 4871),
+    ('\u{F9C6}', 4871, 4874),
+    ('\u{F9C7}', 4874, 4877),
+    ('\u{F9C8}', 4877, 4880),
+    ('\u{F9C9}', 4880, 4883),
+    ('\u{F9CA}', 4883, 4886),
+    ('\u{F9CB}', 4886, 4889),
+    ('\u{F9CC}', 4889, 4892),
+    ('\u{F9CD}', 4892, 4895),
+    ('\u{F9CE}', 4895, 4898),
+    ('\u{F9CF}', 4898, 4901),
+    ('\u{F9D0}', 4901, 4904),
+    ('\u{F9D1}', 4904, 4907),
+    ('\u{F9D2}', 4907, 4910),
+    ('\u{F9D3}', 4910, 4913),
+    ('\u{F9D4}', 4913, 4916),
+    ('\u{F9D5}', 4916, 4919),
+    ('\u{F9D6}', 4919, 4922),
+    ('\u{F9D7}', 4922, 4925),
+    ('\u{F9D8}', 4925, 4928),
+    ('\u{F9D9}', 4928, 4931),
+    ('\u{F9DA}', 4931, 4934),
+    ('\u{F9DB}', 4577, 4580),
+    ('\u{F9DC}', 4934, 4937),
+    ('\u{F9DD}', 4937, 4940),
+    ('\u{F9DE}', 4940, 4943),
+    ('\u{F9DF}', 4943, 4946),
+    ('\u{F9E0}', 4946, 4949),
+    ('\u{F9E1}', 4949, 4952),
+    ('\u{F9E2}', 4952, 4955),
+    ('\u{F9E3}', 4955, 4958),
+    ('\u{F9E4}', 4958, 4961),
+    ('\u{F9E5}', 4961, 4964),
+    ('\u{F9E6}', 4964, 4967),
+    ('\u{F9E7}', 4967, 4970),
+    ('\u{F9E8}', 4970, 4973),
+    ('\u{F9E9}', 4973, 4976),
+    ('\u{F9EA}', 4976, 4979),
+    ('\u{F9EB}', 4979, 4982),
+    ('\u{F9EC}', 4982, 4985),
+    ('\u{F9ED}', 4985, 4988),
+    ('\u{F9EE}', 4988, 4991),
+    ('\u{F9EF}', 4991, 4994),
+    ('\u{F9F0}', 4994, 4997),
+    ('\u{F9F1}', 4997, 5000),
+    ('\u{F9F2}', 5000, 5003),
+    ('\u{F9F3}', 5003, 5006),
+    ('\u{F9F4}', 5006, 5009),
+    ('\u{F9F5}', 5009, 5012),
+    ('\u{F9F6}', 5012, 5015),
+    ('\u{F9F7}', 5015, 5018),
+    ('\u{F9F8}', 5018, 5021),
+    ('\u{F9F9}', 5021, 5024),
+    ('\u{F9FA}', 5024, 5027),
+    ('\u{F9FB}', 5027, 5030),
+    ('\u{F9FC}', 5030, 5033),
+    ('\u{F9FD}', 5033, 5036),
+    ('\u{F9FE}', 5036, 5039),
+    ('\u{F9FF}', 5039, 5042),
+    ('\u{FA00}', 5042, 5045),
+    ('\u{FA01}', 5045, 5048),
+    ('\u{FA02}', 5048, 5051),
+    ('\u{FA03}', 5051, 5054),
+    ('\u{FA04}', 5054, 5057),
+    ('\u{FA05}', 5057, 5060),
+    ('\u{FA06}', 5060, 5063),
+    ('\u{FA07}', 5063, 5066),
+    ('\u{FA08}', 5066, 5069),
+    ('\u{FA09}', 5069, 5072),
+    ('\u{FA0A}', 5072, 5075),
+    ('\u{FA0B}', 5075, 5078),
+    ('\u{FA0C}', 5078, 5081),
+    ('\u{FA0D}', 5081, 5084),
+    ('\u{FA10}', 5084, 5087),
+    ('\u{FA12}', 5087, 5090),
+    ('\u{FA15}', 5090, 5093),
+    ('\u{FA16}', 5093, 5096),
+    ('\u{FA17}', 5096, 5099),
+    ('\u{FA18}', 5099, 5102),
+    ('\u{FA19}', 5102, 5105),
+    ('\u{FA1A}', 5105, 5108),
+    ('\u{FA1B}', 5108, 5111),
+    ('\u{FA1C}', 5111, 5114),
+    ('\u{FA1D}', 5114, 5117),
+    ('\u{FA1E}', 5117, 5120),
+    ('\u{FA20}', 5120, 5123),
+    ('\u{FA22}', 5123, 5126),
+    ('\u{FA25}', 5126, 5129),
+    ('\u{FA26}', 5129, 5132),
+    ('\u{FA2A}', 5132, 5135),
+    ('\u{FA2B}', 5135, 5138),
+    ('\u{FA2C}', 5138, 5141),
+    ('\u{FA2D}', 5141, 5144),
+    ('\u{FA2E}', 5144, 5147),
+    ('\u{FA2F}', 5147, 5150),
+    ('\u{FA30}', 5150, 5153),
+    ('\u{FA31}', 5153, 5156),
+    ('\u{FA32}', 5156, 5159),
+    ('\u{FA33}', 5159, 5162),
+    ('\u{FA34}', 5162, 5165),
+    ('\u{FA35}', 5165, 5168),
+    ('\u{FA36}', 5168, 5171),
+    ('\u{FA37}', 5171, 5174),
+    ('\u{FA38}', 5174, 5177),
+    ('\u{FA39}', 5177, 5180),
+    ('\u{FA3A}', 5180, 5183),
+    ('\u{FA3B}', 5183, 5186),
+    ('\u{FA3C}', 5186, 5189),
+    ('\u{FA3D}', 5189, 5192),
+    ('\u{FA3E}', 5192, 5195),
+    ('\u{FA3F}', 5195, 5198),
+    ('\u{FA40}', 5198, 5201),
+    ('\u{FA41}', 5201, 5204),
+    ('\u{FA42}', 5204, 5207),
+    ('\u{FA43}', 5207, 5210),
+    ('\u{FA44}', 5210, 5213),
+    ('\u{FA45}', 5213, 5216),
+    ('\u{FA46}', 5216, 5219),
+    ('\u{FA47}', 5219, 5222),
+    ('\u{FA48}', 5222, 5225),
+    ('\u{FA49}', 5225, 5228),
+    ('\u{FA4A}', 5228, 5231),
+    ('\u{FA4B}', 5231, 5234),
+    ('\u{FA4C}', 5234, 5237),
+    ('\u{FA4D}', 5237, 5240),
+    ('\u{FA4E}', 5240, 5243),
+    ('\u{FA4F}', 5243, 5246),
+    ('\u{FA50}', 5246, 5249),
+    ('\u{FA51}', 5249, 5252),
+    ('\u{FA52}', 5252, 5255),
+    ('\u{FA53}', 5255, 5258),
+    ('\u{FA54}', 5258, 5261),
+    ('\u{FA55}', 5261, 5264),
+    ('\u{FA56}', 5264, 5267),
+    ('\u{FA57}', 4736, 4739),
+    ('\u{FA58}', 5267, 5270),
+    ('\u{FA59}', 5270, 5273),
+    ('\u{FA5A}', 5273, 5276),
+    ('\u{FA5B}', 5276, 5279),
+    ('\u{FA5C}', 5279, 5282),
+    ('\u{FA5D}', 5282, 5285),
+    ('\u{FA5E}', 5282, 5285),
+    ('\u{FA5F}', 5285, 5288),
+    ('\u{FA60}', 5288, 5291),
+    ('\u{FA61}', 5291, 5294),
+    ('\u{FA62}', 5294, 5297),
+    ('\u{FA63}', 5297, 5300),
+    ('\u{FA64}', 5300, 5303),
+    ('\u{FA65}', 5303, 5306),
+    ('\u{FA66}', 5306, 5309),
+    ('\u{FA67}', 5126, 5129),
+    ('\u{FA68}', 5309, 5312),
+    ('\u{FA69}', 5312, 5315),
+    ('\u{FA6A}', 5315, 5318),
+    ('\u{FA6B}', 5318, 5321),
+    ('\u{FA6C}', 5321, 5325),
+    ('\u{FA6D}', 5325, 5328),
+    ('\u{FA70}', 5328, 5331),
+    ('\u{FA71}', 5331, 5334),
+    ('\u{FA72}', 5334, 5337),
+    ('\u{FA73}', 5337, 5340),
+    ('\u{FA74}', 5340, 5343),
+    ('\u{FA75}', 5343, 5346),
+    ('\u{FA76}', 5346, 5349),
+    ('\u{FA77}', 5349, 5352),
+    ('\u{FA78}', 5168, 5171),
+    ('\u{FA79}', 5352, 5355),
+    ('\u{FA7A}', 5355, 5358),
+    ('\u{FA7B}', 5358, 5361),
+    ('\u{FA7C}', 5084, 5087),
+    ('\u{FA7D}', 5361, 5364),
+    ('\u{FA7E}', 5364, 5367),
+    ('\u{FA7F}', 5367, 5370),
+    ('\u{FA80}', 5370, 5373),
+    ('\u{FA81}', 5373, 5376),
+    ('\u{FA82}', 5376, 5379),
+    ('\u{FA83}', 5379, 5382),
+    ('\u{FA84}', 5382, 5385),
+    ('\u{FA85}', 5385, 5388),
+    ('\u{FA86}', 5388, 5391),
+    ('\u{FA87}', 5391, 5394),
+    ('\u{FA88}', 5394, 5397),
+    ('\u{FA89}', 5195, 5198),
+    ('\u{FA8A}', 5397, 5400),
+    ('\u{FA8B}', 5198, 5201),
+    ('\u{FA8C}', 5400, 5403),
+    ('\u{FA8D}', 5403, 5406),
+    ('\u{FA8E}', 5406, 5409),
+    ('\u{FA8F}', 5409, 5412),
+    ('\u{FA90}', 5412, 5415),
+    ('\u{FA91}', 5087, 5090),
+    ('\u{FA92}', 4412, 4415),
+    ('\u{FA93}', 5415, 5418),
+    ('\u{FA94}', 5418, 5421),
+    ('\u{FA95}', 5421, 5424),
+    ('\u{FA96}', 4622, 4625),
+    ('\u{FA97}', 4883, 4886),
+    ('\u{FA98}', 5424, 5427),
+    ('\u{FA99}', 5427, 5430),
+    ('\u{FA9A}', 5219, 5222),
+    ('\u{FA9B}', 5430, 5433),
+    ('\u{FA9C}', 5222, 5225),
+    ('\u{FA9D}', 5433, 5436),
+    ('\u{FA9E}', 5436, 5439),
+    ('\u{FA9F}', 5439, 5442),
+    ('\u{FAA0}', 5093, 5096),
+    ('\u{FAA1}', 5442, 5445),
+    ('\u{FAA2}', 5445, 5448),
+    ('\u{FAA3}', 5448, 5451),
+    ('\u{FAA4}', 5451, 5454),
+    ('\u{FAA5}', 5454, 5457),
+    ('\u{FAA6}', 5096, 5099),
+    ('\u{FAA7}', 5457, 5460),
+    ('\u{FAA8}', 5460, 5463),
+    ('\u{FAA9}', 5463, 5466),
+    ('\u{FAAA}', 5466, 5469),
+    ('\u{FAAB}', 5469, 5472),
+    ('\u{FAAC}', 5472, 5475),
+    ('\u{FAAD}', 5264, 5267),
+    ('\u{FAAE}', 5475, 5478),
+    ('\u{FAAF}', 5478, 5481),
+    ('\u{FAB0}', 4736, 4739),
+    ('\u{FAB1}', 5481, 5484),
+    ('\u{FAB2}', 5276, 5279),
+    ('\u{FAB3}', 5484, 5487),
+    ('\u{FAB4}', 5487, 5490),
+    ('\u{FAB5}', 5490, 5493),
+    ('\u{FAB6}', 5493, 5496),
+    ('\u{FAB7}', 5496, 5499),
+    ('\u{FAB8}', 5291, 5294),
+    ('\u{FAB9}', 5499, 5502),
+    ('\u{FABA}', 5123, 5126),
+    ('\u{FABB}', 5502, 5505),
+    ('\u{FABC}', 5294, 5297),
+    ('\u{FABD}', 4565, 4568),
+    ('\u{FABE}', 5505, 5508),
+    ('\u{FABF}', 5297, 5300),
+    ('\u{FAC0}', 5508, 5511),
+    ('\u{FAC1}', 5303, 5306),
+    ('\u{FAC2}', 5511, 5514),
+    ('\u{FAC3}', 5514, 5517),
+    ('\u{FAC4}', 5517, 5520),
+    ('\u{FAC5}', 5520, 5523),
+    ('\u{FAC6}', 5523, 5526),
+    ('\u{FAC7}', 5309, 5312),
+    ('\u{FAC8}', 5111, 5114),
+    ('\u{FAC9}', 5526, 5529),
+    ('\u{FACA}', 5312, 5315),
+    ('\u{FACB}', 5529, 5532),
+    ('\u{FACC}', 5315, 5318),
+    ('\u{FACD}', 5532, 5535),
+    ('\u{FACE}', 4313, 4316),
+    ('\u{FACF}', 5535, 5539),
+    ('\u{FAD0}', 5539, 5543),
+    ('\u{FAD1}', 5543, 5547),
+    ('\u{FAD2}', 5547, 5550),
+    ('\u{FAD3}', 5550, 5553),
+    ('\u{FAD4}', 5553, 5556),
+    ('\u{FAD5}', 5556, 5560),
+    ('\u{FAD6}', 5560, 5564),
+    ('\u{FAD7}', 5564, 5568),
+    ('\u{FAD8}', 5568, 5571),
+    ('\u{FAD9}', 5571, 5574),
+    ('\u{FB1D}', 5574, 5578),
+    ('\u{FB1F}', 5578, 5582),
+    ('\u{FB2A}', 5582, 5586),
+    ('\u{FB2B}', 5586, 5590),
+    ('\u{FB2C}', 5590, 5596),
+    ('\u{FB2D}', 5596, 5602),
+    ('\u{FB2E}', 5602, 5606),
+    ('\u{FB2F}', 5606, 5610),
+    ('\u{FB30}', 5610, 5614),
+    ('\u{FB31}', 5614, 5618),
+    ('\u{FB32}', 5618, 5622),
+    ('\u{FB33}', 5622, 5626),
+    ('\u{FB34}', 5626, 5630),
+    ('\u{FB35}', 5630, 5634),
+    ('\u{FB36}', 5634, 5638),
+    ('\u{FB38}', 5638, 5642),
+    ('\u{FB39}', 5642, 5646),
+    ('\u{FB3A}', 5646, 5650),
+    ('\u{FB3B}', 5650, 5654),
+    ('\u{FB3C}', 5654, 5658),
+    ('\u{FB3E}', 5658, 5662),
+    ('\u{FB40}', 5662, 5666),
+    ('\u{FB41}', 5666, 5670),
+    ('\u{FB43}', 5670, 5674),
+    ('\u{FB44}', 5674, 5678),
+    ('\u{FB46}', 5678, 5682),
+    ('\u{FB47}', 5682, 5686),
+    ('\u{FB48}', 5686, 5690),
+    ('\u{FB49}', 5690, 5694),
+    ('\u{FB4A}', 5694, 5698),
+    ('\u{FB4B}', 5698, 5702),
+    ('\u{FB4C}', 5702, 5706),
+    ('\u{FB4D}', 5706, 5710),
+    ('\u{FB4E}', 5710, 5714),
+    ('\u{1109A}', 5714, 5722),
+    ('\u{1109C}', 5722, 5730),
+    ('\u{110AB}', 5730, 5738),
+    ('\u{1112E}', 5738, 5746),
+    ('\u{1112F}', 5746, 5754),
+    ('\u{1134B}', 5754, 5762),
+    ('\u{1134C}', 5762, 5770),
+    ('\u{114BB}', 5770, 5778),
+    ('\u{114BC}', 5778, 5786),
+    ('\u{114BE}', 5786, 5794),
+    ('\u{115BA}', 5794, 5802),
+    ('\u{115BB}', 5802, 5810),
+    ('\u{11938}', 5810, 5818),
+    ('\u{1D15E}', 5818, 5826),
+    ('\u{1D15F}', 5826, 5834),
+    ('\u{1D160}', 5834, 5846),
+    ('\u{1D161}', 5846, 5858),
+    ('\u{1D162}', 5858, 5870),
+    ('\u{1D163}', 5870, 5882),
+    ('\u{1D164}', 5882, 5894),
+    ('\u{1D1BB}', 5894, 5902),
+    ('\u{1D1BC}', 5902, 5910),
+    ('\u{1D1BD}', 5910, 5922),
+    ('\u{1D1BE}', 5922, 5934),
+    ('\u{1D1BF}', 5934, 5946),
+    ('\u{1D1C0}', 5946, 5958),
+    ('\u{2F800}', 5958, 5961),
+    ('\u{2F801}', 5961, 5964),
+    ('\u{2F802}', 5964, 5967),
+    ('\u{2F803}', 5967, 5971),
+    ('\u{2F804}', 5971, 5974),
+    ('\u{2F805}', 5150, 5153),
+    ('\u{2F806}', 5974, 5977),
+    ('\u{2F807}', 5977, 5980),
+    ('\u{2F808}', 5980, 5983),
+    ('\u{2F809}', 5983, 5986),
+    ('\u{2F80A}', 5153, 5156),
+    ('\u{2F80B}', 5986, 5989),
+    ('\u{2F80C}', 5989, 5992),
+    ('\u{2F80D}', 5992, 5996),
+    ('\u{2F80E}', 5156, 5159),
+    ('\u{2F80F}', 5996, 5999),
+    ('\u{2F810}', 5999, 6002),
+    ('\u{2F811}', 6002, 6005),
+    ('\u{2F812}', 6005, 6009),
+    ('\u{2F813}', 6009, 6012),
+    ('\u{2F814}', 6012, 6015),
+    ('\u{2F815}', 6015, 6018),
+    ('\u{2F816}', 6018, 6022),
+    ('\u{2F817}', 6022, 6025),
+    ('\u{2F818}', 6025, 6028),
+    ('\u{2F819}', 6028, 6031),
+    ('\u{2F81A}', 6031, 6034),
+    ('\u{2F81B}', 5331, 5334),
+    ('\u{2F81C}', 6034, 6038),
+    ('\u{2F81D}', 6038, 6041),
+    ('\u{2F81E}', 6041, 6044),
+    ('\u{2F81F}', 6044, 6047),
+    ('\u{2F820}', 6047, 6050),
+    ('\u{2F821}', 6050, 6053),
+    ('\u{2F822}', 6053, 6056),
+    ('\u{2F823}', 6056, 6059),
+    ('\u{2F824}', 6059, 6062),
+    ('\u{2F825}', 5346, 5349),
+    ('\u{2F826}', 5159, 5162),
+    ('\u{2F827}', 5162, 5165),
+    ('\u{2F828}', 5349, 5352),
+    ('\u{2F829}', 6062, 6065),
+    ('\u{2F82A}', 6065, 6068),
+    ('\u{2F82B}', 4583, 4586),
+    ('\u{2F82C}', 6068, 6071),
+    ('\u{2F82D}', 5165, 5168),
+    ('\u{2F82E}', 6071, 6074),
+    ('\u{2F82F}', 6074, 6077),
+    ('\u{2F830}', 6077, 6080),
+    ('\u{2F831}', 6080, 6083),
+    ('\u{2F832}', 6080, 6083),
+    ('\u{2F833}', 6080, 6083),
+    ('\u{2F834}', 6083, 6087),
+    ('\u{2F835}', 6087, 6090),
+    ('\u{2F836}', 6090, 6093),
+    ('\u{2F837}', 6093, 6096),
+    ('\u{2F838}', 6096, 6100),
+    ('\u{2F839}', 6100, 6103),
+    ('\u{2F83A}', 6103, 6106),
+    ('\u{2F83B}', 6106, 6109),
+    ('\u{2F83C}', 6109, 6112),
+    ('\u{2F83D}', 6112, 6115),
+    ('\u{2F83E}', 6115, 6118),
+    ('\u{2F83F}', 6118, 6121),
+    ('\u{2F840}', 6121, 6124),
+    ('\u{2F841}', 6124, 6127),
+    ('\u{2F842}', 6127, 6130),
+    ('\u{2F843}', 6130, 6133),
+    ('\u{2F844}', 6133, 6136),
+    ('\u{2F845}', 6136, 6139),
+    ('\u{2F846}', 6136, 6139),
+    ('\u{2F847}', 5355, 5358),
+    ('\u{2F848}', 6139, 6142),
+    ('\u{2F849}', 6142, 6145),
+    ('\u{2F84A}', 6145, 6148),
+    ('\u{2F84B}', 6148, 6151),
+    ('\u{2F84C}', 5171, 5174),
+    ('\u{2F84D}', 6151, 6154),
+    ('\u{2F84E}', 6154, 6157),
+    ('\u{2F84F}', 6157, 6160),
+    ('\u{2F850}', 5042, 5045),
+    ('\u{2F851}', 6160, 6163),
+    ('\u{2F852}', 6163, 6166),
+    ('\u{2F853}', 6166, 6169),
+    ('\u{2F854}', 6169, 6172),
+    ('\u{2F855}', 6172, 6175),
+    ('\u{2F856}', 6175, 6178),
+    ('\u{2F857}', 6178, 6181),
+    ('\u{2F858}', 6181, 6184),
+    ('\u{2F859}', 6184, 6188),
+    ('\u{2F85A}', 6188, 6191),
+    ('\u{2F85B}', 6191, 6194),
+    ('\u{2F85C}', 6194, 6197),
+    ('\u{2F85D}', 6197, 6200),
+    ('\u{2F85E}', 6200, 6203),
+    ('\u{2F85F}', 6203, 6206),
+    ('\u{2F860}', 6206, 6210),
+    ('\u{2F861}', 6210, 6214),
+    ('\u{2F862}', 6214, 6217),
+    ('\u{2F863}', 6217, 6220),
+    ('\u{2F864}', 6220, 6223),
+    ('\u{2F865}', 6223, 6226),
+    ('\u{2F866}', 6226, 6229),
+    ('\u{2F867}', 6229, 6232),
+    ('\u{2F868}', 6232, 6235),
+    ('\u{2F869}', 6235, 6238),
+    ('\u{2F86A}', 6238, 6241),
+    ('\u{2F86B}', 6238, 6241),
+    ('\u{2F86C}', 6241, 6245),
+    ('\u{2F86D}', 6245, 6248),
+    ('\u{2F86E}', 6248, 6251),
+    ('\u{2F86F}', 4571, 4574),
+    ('\u{2F870}', 6251, 6254),
+    ('\u{2F871}', 6254, 6258),
+    ('\u{2F872}', 6258, 6261),
+    ('\u{2F873}', 6261, 6264),
+    ('\u{2F874}', 6264, 6267),
+    ('\u{2F875}', 6267, 6270),
+    ('\u{2F876}', 6270, 6273),
+    ('\u{2F877}', 6273, 6276),
+    ('\u{2F878}', 5186, 5189),
+    ('\u{2F879}', 6276, 6279),
+    ('\u{2F87A}', 6279, 6282),
+    ('\u{2F87B}', 6282, 6286),
+    ('\u{2F87C}', 6286, 6289),
+    ('\u{2F87D}', 6289, 6293),
+    ('\u{2F87E}', 6293, 6296),
+    ('\u{2F87F}', 6296, 6299),
+    ('\u{2F880}', 6299, 6302),
+    ('\u{2F881}', 6302, 6305),
+    ('\u{2F882}', 6305, 6308),
+    ('\u{2F883}', 6308, 6311),
+    ('\u{2F884}', 6311, 6314),
+    ('\u{2F885}', 6314, 6317),
+    ('\u{2F886}', 6317, 6320),
+    ('\u{2F887}', 6320, 6323),
+    ('\u{2F888}', 6323, 6326),
+    ('\u{2F889}', 6326, 6330),
+    ('\u{2F88A}', 6330, 6333),
+    ('\u{2F88B}', 6333, 6336),
+    ('\u{2F88C}', 6336, 6339),
+    ('\u{2F88D}', 6339, 6342),
+    ('\u{2F88E}', 4409, 4412),
+    ('\u{2F88F}', 6342, 6346),
+    ('\u{2F890}', 6346, 6349),
+    ('\u{2F891}', 6349, 6353),
+    ('\u{2F892}', 6349, 6353),
+    ('\u{2F893}', 6353, 6356),
+    ('\u{2F894}', 6356, 6359),
+    ('\u{2F895}', 6356, 6359),
+    ('\u{2F896}', 6359, 6362),
+    ('\u{2F897}', 6362, 6366),
+    ('\u{2F898}', 6366, 6370),
+    ('\u{2F899}', 6370, 6373),
+    ('\u{2F89A}', 6373, 6376),
+    ('\u{2F89B}', 6376, 6379),
+    ('\u{2F89C}', 6379, 6382),
+    ('\u{2F89D}', 6382, 6385),
+    ('\u{2F89E}', 6385, 6388),
+    ('\u{2F89F}', 6388, 6391),
+    ('\u{2F8A0}', 6391, 6394),
+    ('\u{2F8A1}', 6394, 6397),
+    ('\u{2F8A2}', 6397, 6400),
+    ('\u{2F8A3}', 5189, 5192),
+    ('\u{2F8A4}', 6400, 6404),
+    ('\u{2F8A5}', 6404, 6407),
+    ('\u{2F8A6}', 6407, 6410),
+    ('\u{2F8A7}', 6410, 6413),
+    ('\u{2F8A8}', 5391, 5394),
+    ('\u{2F8A9}', 6410, 6413),
+    ('\u{2F8AA}', 6413, 6416),
+    ('\u{2F8AB}', 5195, 5198),
+    ('\u{2F8AC}', 6416, 6419),
+    ('\u{2F8AD}', 6419, 6422),
+    ('\u{2F8AE}', 6422, 6425),
+    ('\u{2F8AF}', 6425, 6428),
+    ('\u{2F8B0}', 5198, 5201),
+    ('\u{2F8B1}', 4328, 4331),
+    ('\u{2F8B2}', 6428, 6431),
+    ('\u{2F8B3}', 6431, 6434),
+    ('\u{2F8B4}', 6434, 6437),
+    ('\u{2F8B5}', 6437, 6440),
+    ('\u{2F8B6}', 6440, 6443),
+    ('\u{2F8B7}', 6443, 6446),
+    ('\u{2F8B8}', 6446, 6450),
+    ('\u{2F8B9}', 6450, 6453),
+    ('\u{2F8BA}', 6453, 6456),
+    ('\u{2F8BB}', 6456, 6459),
+    ('\u{2F8BC}', 6459, 6462),
+    ('\u{2F8BD}', 6462, 6465),
+    ('\u{2F8BE}', 6465, 6469),
+    ('\u{2F8BF}', 6469, 6472),
+    ('\u{2F8C0}', 6472, 6475),
+    ('\u{2F8C1}', 6475, 6478),
+    ('\u{2F8C2}', 6478, 6481),
+    ('\u{2F8C3}', 6481, 6484),
+    ('\u{2F8C4}', 6484, 6487),
+    ('\u{2F8C5}', 6487, 6490),
+    ('\u{2F8C6}', 6490, 6493),
+    ('\u{2F8C7}', 6493, 6496),
+    ('\u{2F8C8}', 5201, 5204),
+    ('\u{2F8C9}', 6496, 6499),
+    ('\u{2F8CA}', 6499, 6503),
+    ('\u{2F8CB}', 6503, 6506),
+    ('\u{2F8CC}', 6506, 6509),
+    ('\u{2F8CD}', 6509, 6512),
+    ('\u{2F8CE}', 6512, 6515),
+    ('\u{2F8CF}', 5207, 5210),
+    ('\u{2F8D0}', 6515, 6518),
+    ('\u{2F8D1}', 6518, 6521),
+    ('\u{2F8D2}', 6521, 6524),
+    ('\u{2F8D3}', 6524, 6527),
+    ('\u{2F8D4}', 6527, 6530),
+    ('\u{2F8D5}', 6530, 6533),
+    ('\u{2F8D6}', 6533, 6536),
+    ('\u{2F8D7}', 6536, 6539),
+    ('\u{2F8D8}', 4412, 4415),
+    ('\u{2F8D9}', 5415, 5418),
+    ('\u{2F8DA}', 6539, 6542),
+    ('\u{2F8DB}', 6542, 6545),
+    ('\u{2F8DC}', 6545, 6548),
+    ('\u{2F8DD}', 6548, 6552),
+    ('\u{2F8DE}', 6552, 6555),
+    ('\u{2F8DF}', 6555, 6558),
+    ('\u{2F8E0}', 6558, 6561),
+    ('\u{2F8E1}', 6561, 6564),
+    ('\u{2F8E2}', 5210, 5213),
+    ('\u{2F8E3}', 6564, 6568),
+    ('\u{2F8E4}', 6568, 6571),
+    ('\u{2F8E5}', 6571, 6574),
+    ('\u{2F8E6}', 6574, 6577),
+    ('\u{2F8E7}', 5547, 5550),
+    ('\u{2F8E8}', 6577, 6580),
+    ('\u{2F8E9}', 6580, 6583),
+    ('\u{2F8EA}', 6583, 6586),
+    ('\u{2F8EB}', 6586, 6589),
+    ('\u{2F8EC}', 6589, 6593),
+    ('\u{2F8ED}', 6593, 6596),
+    ('\u{2F8EE}', 6596, 6599),
+    ('\u{2F8EF}', 6599, 6602),
+    ('\u{2F8F0}', 6602, 6606),
+    ('\u{2F8F1}', 6606, 6609),
+    ('\u{2F8F2}', 6609, 6612),
+    ('\u{2F8F3}', 6612, 6615),
+    ('\u{2F8F4}', 6615, 6618),
+    ('\u{2F8F5}', 4622, 4625),
+    ('\u{2F8F6}', 6618, 6621),
+    ('\u{2F8F7}', 6621, 6625),
+    ('\u{2F8F8}', 6625, 6629),
+    ('\u{2F8F9}', 6629, 6633),
+    ('\u{2F8FA}', 6633, 6636),
+    ('\u{2F8FB}', 6636, 6640),
+    ('\u{2F8FC}', 6640, 6643),
+    ('\u{2F8FD}', 6643, 6646),
+    ('\u{2F8FE}', 6646, 6649),
+    ('\u{2F8FF}', 6649, 6652),
+    ('\u{2F900}', 6652, 6655),
+    ('\u{2F901}', 5213, 5216),
+    ('\u{2F902}', 4883, 4886),
+    ('\u{2F903}', 6655, 6658),
+    ('\u{2F904}', 6658, 6661),
+    ('\u{2F905}', 6661, 6664),
+    ('\u{2F906}', 6664, 6668),
+    ('\u{2F907}', 6668, 6671),
+    ('\u{2F908}', 6671, 6674),
+    ('\u{2F909}', 6674, 6677),
+    ('\u{2F90A}', 6677, 6680),
+    ('\u{2F90B}', 5427, 5430),
+    ('\u{2F90C}', 6680, 6683),
+    ('\u{2F90D}', 6683, 6687),
+    ('\u{2F90E}', 6687, 6690),
+    ('\u{2F90F}', 6690, 6693),
+    ('\u{2F910}', 6693, 6697),
+    ('\u{2F911}', 6697, 6701),
+    ('\u{2F912}', 6701, 6704),
+    ('\u{2F913}', 6704, 6707),
+    ('\u{2F914}', 5430, 5433),
+    ('\u{2F915}', 6707, 6710),
+    ('\u{2F916}', 6710, 6713),
+    ('\u{2F917}', 6713, 6716),
+    ('\u{2F918}', 6716, 6719),
+    ('\u{2F919}', 6719, 6722),
+    ('\u{2F91A}', 6722, 6725),
+    ('\u{2F91B}', 6725, 6729),
+    ('\u{2F91C}', 6729, 6732),
+    ('\u{2F91D}', 6732, 6736),
+    ('\u{2F91E}', 6736, 6739),
+    ('\u{2F91F}', 6739, 6743),
+    ('\u{2F920}', 6743, 6746),
+    ('\u{2F921}', 5436, 5439),
+    ('\u{2F922}', 6746, 6749),
+    ('\u{2F923}', 6749, 6753),
+    ('\u{2F924}', 6753, 6756),
+    ('\u{2F925}', 6756, 6759),
+    ('\u{2F926}', 6759, 6763),
+    ('\u{2F927}', 6763, 6767),
+    ('\u{2F928}', 6767, 6770),
+    ('\u{2F929}', 6770, 6773),
+    ('\u{2F92A}', 6773, 6776),
+    ('\u{2F92B}', 6776, 6779),
+    ('\u{2F92C}', 6779, 6782),
+    ('\u{2F92D}', 6779, 6782),
+    ('\u{2F92E}', 6782, 6785),
+    ('\u{2F92F}', 6785, 6788),
+    ('\u{2F930}', 5442, 5445),
+    ('\u{2F931}', 6788, 6791),
+    ('\u{2F932}', 6791, 6794),
+    ('\u{2F933}', 6794, 6797),
+    ('\u{2F934}', 6797, 6800),
+    ('\u{2F935}', 6800, 6804),
+    ('\u{2F936}', 6804, 6807),
+    ('\u{2F937}', 6807, 6811),
+    ('\u{2F938}', 4580, 4583),
+    ('\u{2F939}', 6811, 6815),
+    ('\u{2F93A}', 6815, 6818),
+    ('\u{2F93B}', 6818, 6822),
+    ('\u{2F93C}', 6822, 6826),
+    ('\u{2F93D}', 6826, 6830),
+    ('\u{2F93E}', 6830, 6833),
+    ('\u{2F93F}', 6833, 6836),
+    ('\u{2F940}', 5460, 5463),
+    ('\u{2F941}', 6836, 6840),
+    ('\u{2F942}', 6840, 6844),
+    ('\u{2F943}', 6844, 6848),
+    ('\u{2F944}', 6848, 6852),
+    ('\u{2F945}', 6852, 6855),
+    ('\u{2F946}', 6855, 6858),
+    ('\u{2F947}', 6855, 6858),
+    ('\u{2F948}', 5463, 5466),
+    ('\u{2F949}', 5553, 5556),
+    ('\u{2F94A}', 6858, 6861),
+    ('\u{2F94B}', 6861, 6864),
+    ('\u{2F94C}', 6864, 6867),
+    ('\u{2F94D}', 6867, 6871),
+    ('\u{2F94E}', 6871, 6874),
+    ('\u{2F94F}', 4466, 4469),
+    ('\u{2F950}', 5469, 5472),
+    ('\u{2F951}', 6874, 6877),
+    ('\u{2F952}', 6877, 6881),
+    ('\u{2F953}', 5246, 5249),
+    ('\u{2F954}', 6881, 6885),
+    ('\u{2F955}', 6885, 6889),
+    ('\u{2F956}', 5108, 5111),
+    ('\u{2F957}', 6889, 6892),
+    ('\u{2F958}', 6892, 6895),
+    ('\u{2F959}', 5258, 5261),
+    ('\u{2F95A}', 6895, 6898),
+    ('\u{2F95B}', 6898, 6901),
+    ('\u{2F95C}', 6901, 6905),
+    ('\u{2F95D}', 6905, 6909),
+    ('\u{2F95E}', 6905, 6909),
+    ('\u{2F95F}', 6909, 6912),
+    ('\u{2F960}', 6912, 6915),
+    ('\u{2F961}', 6915, 6919),
+    ('\u{2F962}', 6919, 6922),
+    ('\u{2F963}', 6922, 6925),
+    ('\u{2F964}', 6925, 6928),
+    ('\u{2F965}', 6928, 6932),
+    ('\u{2F966}', 6932, 6935),
+    ('\u{2F967}', 6935, 6938),
+    ('\u{2F968}', 6938, 6941),
+    ('\u{2F969}', 6941, 6944),
+    ('\u{2F96A}', 6944, 6947),
+    ('\u{2F96B}', 6947, 6951),
+    ('\u{2F96C}', 6951, 6954),
+    ('\u{2F96D}', 6954, 6957),
+    ('\u{2F96E}', 6957, 6960),
+    ('\u{2F96F}', 6960, 6963),
+    ('\u{2F970}', 6963, 6966),
+    ('\u{2F971}', 6966, 6969),
+    ('\u{2F972}', 6969, 6973),
+    ('\u{2F973}', 6973, 6977),
+    ('\u{2F974}', 6977, 6980),
+    ('\u{2F975}', 6980, 6984),
+    ('\u{2F976}', 6984, 6987),
+    ('\u{2F977}', 6987, 6991),
+    ('\u{2F978}', 6991, 6994),
+    ('\u{2F979}', 6994, 6997),
+    ('\u{2F97A}', 5276, 5279),
+    ('\u{2F97B}', 6997, 7001),
+    ('\u{2F97C}', 7001, 7005),
+    ('\u{2F97D}', 7005, 7008),
+    ('\u{2F97E}', 7008, 7012),
+    ('\u{2F97F}', 7012, 7015),
+    ('\u{2F980}', 7015, 7019),
+    ('\u{2F981}', 7019, 7022),
+    ('\u{2F982}', 7022, 7025),
+    ('\u{2F983}', 7025, 7028),
+    ('\u{2F984}', 7028, 7031),
+    ('\u{2F985}', 7031, 7034),
+    ('\u{2F986}', 7034, 7037),
+    ('\u{2F987}', 7037, 7041),
+    ('\u{2F988}', 7041, 7045),
+    ('\u{2F989}', 7045, 7049),
+    ('\u{2F98A}', 7049, 7053),
+    ('\u{2F98B}', 6353, 6356),
+    ('\u{2F98C}', 7053, 7056),
+    ('\u{2F98D}', 7056, 7059),
+    ('\u{2F98E}', 7059, 7062),
+    ('\u{2F98F}', 7062, 7065),
+    ('\u{2F990}', 7065, 7068),
+    ('\u{2F991}', 7068, 7071),
+    ('\u{2F992}', 7071, 7074),
+    ('\u{2F993}', 7074, 7077),
+    ('\u{2F994}', 7077, 7080),
+    ('\u{2F995}', 7080, 7083),
+    ('\u{2F996}', 7083, 7086),
+    ('\u{2F997}', 7086, 7090),
+    ('\u{2F998}', 4634, 4637),
+    ('\u{2F999}', 7090, 7093),
+    ('\u{2F99A}', 7093, 7096),
+    ('\u{2F99B}', 7096, 7099),
+    ('\u{2F99C}', 7099, 7102),
+    ('\u{2F99D}', 7102, 7105),
+    ('\u{2F99E}', 7105, 7108),
+    ('\u{2F99F}', 5285, 5288),
+    ('\u{2F9A0}', 7108, 7111),
+    ('\u{2F9A1}', 7111, 7114),
+    ('\u{2F9A2}', 7114, 7117),
+    ('\u{2F9A3}', 7117, 7120),
+    ('\u{2F9A4}', 7120, 7124),
+    ('\u{2F9A5}', 7124, 7128),
+    ('\u{2F9A6}', 7128, 7132),
+    ('\u{2F9A7}', 7132, 7135),
+    ('\u{2F9A8}', 7135, 7138),
+    ('\u{2F9A9}', 7138, 7141),
+    ('\u{2F9AA}', 7141, 7144),
+    ('\u{2F9AB}', 7144, 7148),
+    ('\u{2F9AC}', 7148, 7151),
+    ('\u{2F9AD}', 7151, 7155),
+    ('\u{2F9AE}', 7155, 7158),
+    ('\u{2F9AF}', 7158, 7161),
+    ('\u{2F9B0}', 7161, 7165),
+    ('\u{2F9B1}', 7165, 7169),
+    ('\u{2F9B2}', 7169, 7172),
+    ('\u{2F9B3}', 7172, 7175),
+    ('\u{2F9B4}', 4451, 4454),
+    ('\u{2F9B5}', 7175, 7178),
+    ('\u{2F9B6}', 7178, 7181),
+    ('\u{2F9B7}', 7181, 7184),
+    ('\u{2F9B8}', 7184, 7187),
+    ('\u{2F9B9}', 7187, 7190),
+    ('\u{2F9BA}', 7190, 7193),
+    ('\u{2F9BB}', 5490, 5493),
+    ('\u{2F9BC}', 7193, 7196),
+    ('\u{2F9BD}', 7196, 7199),
+    ('\u{2F9BE}', 7199, 7202),
+    ('\u{2F9BF}', 7202, 7205),
+    ('\u{2F9C0}', 7205, 7208),
+    ('\u{2F9C1}', 7208, 7211),
+    ('\u{2F9C2}', 7211, 7214),
+    ('\u{2F9C3}', 7214, 7217),
+    ('\u{2F9C4}', 7217, 7220),
+    ('\u{2F9C5}', 7220, 7224),
+    ('\u{2F9C6}', 7224, 7227),
+    ('\u{2F9C7}', 7227, 7230),
+    ('\u{2F9C8}', 7230, 7233),
+    ('\u{2F9C9}', 7233, 7236),
+    ('\u{2F9CA}', 7236, 7239),
+    ('\u{2F9CB}', 7239, 7243),
+    ('\u{2F9CC}', 7243, 7247),
+    ('\u{2F9CD}', 7247, 7250),
+    ('\u{2F9CE}', 7250, 7253),
+    ('\u{2F9CF}', 7253, 7256),
+    ('\u{2F9D0}', 5505, 5508),
+    ('\u{2F9D1}', 5508, 5511),
+    ('\u{2F9D2}', 7256, 7259),
+    ('\u{2F9D3}', 7259, 7263),
+    ('\u{2F9D4}', 7263, 7266),
+    ('\u{2F9D5}', 7266, 7269),
+    ('\u{2F9D6}', 7269, 7272),
+    ('\u{2F9D7}', 7272, 7275),
+    ('\u{2F9D8}', 7275, 7279),
+    ('\u{2F9D9}', 7279, 7283),
+    ('\u{2F9DA}', 7283, 7286),
+    ('\u{2F9DB}', 7286, 7289),
+    ('\u{2F9DC}', 7289, 7292),
+    ('\u{2F9DD}', 7292, 7296),
+    ('\u{2F9DE}', 7296, 7299),
+    ('\u{2F9DF}', 5511, 5514),
+    ('\u{2F9E0}', 7299, 7303),
+    ('\u{2F9E1}', 7303, 7307),
+    ('\u{2F9E2}', 7307, 7310),
+    ('\u{2F9E3}', 7310, 7313),
+    ('\u{2F9E4}', 7313, 7316),
+    ('\u{2F9E5}', 7316, 7320),
+    ('\u{2F9E6}', 7320, 7323),
+    ('\u{2F9E7}', 7323, 7326),
+    ('\u{2F9E8}', 7326, 7329),
+    ('\u{2F9E9}', 7329, 7332),
+    ('\u{2F9EA}', 7332, 7335),
+    ('\u{2F9EB}', 7335, 7338),
+    ('\u{2F9EC}', 7338, 7341),
+    ('\u{2F9ED}', 7341, 7345),
+    ('\u{2F9EE}', 7345, 7348),
+    ('\u{2F9EF}', 7348, 7351),
+    ('\u{2F9F0}', 7351, 7354),
+    ('\u{2F9F1}', 7354, 7358),
+    ('\u{2F9F2}', 7358, 7361),
+    ('\u{2F9F3}', 7361, 7364),
+    ('\u{2F9F4}', 7364, 7367),
+    ('\u{2F9F5}', 7367, 7370),
+    ('\u{2F9F6}', 7370, 7374),
+    ('\u{2F9F7}', 7374, 7378),
+    ('\u{2F9F8}', 7378, 7381),
+    ('\u{2F9F9}', 7381, 7384),
+    ('\u{2F9FA}', 7384, 7387),
+    ('\u{2F9FB}', 7387, 7391),
+    ('\u{2F9FC}', 7391, 7394),
+    ('\u{2F9FD}', 7394, 7398),
+    ('\u{2F9FE}', 5529, 5532),
+    ('\u{2F9FF}', 5529, 5532),
+    ('\u{2FA00}', 7398, 7401),
+    ('\u{2FA01}', 7401, 7405),
+    ('\u{2FA02}', 7405, 7408),
+    ('\u{2FA03}', 7408, 7411),
+    ('\u{2FA04}', 7411, 7414),
+    ('\u{2FA05}', 7414, 7417),
+    ('\u{2FA06}', 7417, 7420),
+    ('\u{2FA07}', 7420, 7423),
+    ('\u{2FA08}', 7423, 7426),
+    ('\u{2FA09}', 7426, 7430),
+    ('\u{2FA0A}', 5532, 5535),
+    ('\u{2FA0B}', 7430, 7433),
+    ('\u{2FA0C}', 7433, 7436),
+    ('\u{2FA0D}', 7436, 7439),
+    ('\u{2FA0E}', 7439, 7442),
+    ('\u{2FA0F}', 7442, 7445),
+    ('\u{2FA10}', 7445, 7449),
+    ('\u{2FA11}', 7449, 7452),
+    ('\u{2FA12}', 7452, 7456),
+    ('\u{2FA13}', 7456, 7460),
+    ('\u{2FA14}', 7460, 7464),
+    ('\u{2FA15}', 7464, 7467),
+    ('\u{2FA16}', 7467, 7470),
+    ('\u{2FA17}', 7470, 7473),
+    ('\u{2FA18}', 7473, 7476),
+    ('\u{2FA19}', 7476, 7479),
+    ('\u{2FA1A}', 7479, 7482),
+    ('\u{2FA1B}', 7482, 7485),
+    ('\u{2FA1C}', 7485, 7488),
+    ('\u{2FA1D}', 7488, 7492),
+];
+
+/// Pairs of code points that compose into a primary composite
+/// other than Hangul syllables, sorted.
+pub(super) static COMPOSITION: &[(char, char, char)] = &[
+    ('\u{3C}', '\u{338}', '\u{226E}'),
+    ('\u{3D}', '\u{338}', '\u{2260}'),
+    ('\u{3E}', '\u{338}', '\u{226F}'),
+    ('\u{41}', '\u{300}', '\u{C0}'),
+    ('\u{41}', '\u{301}', '\u{C1}'),
+    ('\u{41}', '\u{302}', '\u{C2}'),
+    ('\u{41}', '\u{303}', '\u{C3}'),
+    ('\u{41}', '\u{304}', '\u{100}'),
+    ('\u{41}', '\u{306}', '\u{102}'),
+    ('\u{41}', '\u{307}', '\u{226}'),
+    ('\u{41}', '\u{308}', '\u{C4}'),
+    ('\u{41}', '\u{309}', '\u{1EA2}'),
+    ('\u{41}', '\u{30A}', '\u{C5}'),
+    ('\u{41}', '\u{30C}', '\u{1CD}'),
+    ('\u{41}', '\u{30F}', '\u{200}'),
+    ('\u{41}', '\u{311}', '\u{202}'),
+    ('\u{41}', '\u{323}', '\u{1EA0}'),
+    ('\u{41}', '\u{325}', '\u{1E00}'),
+    ('\u{41}', '\u{328}', '\u{104}'),
+    ('\u{42}', '\u{307}', '\u{1E02}'),
+    ('\u{42}', '\u{323}', '\u{1E04}'),
+    ('\u{42}', '\u{331}', '\u{1E06}'),
+    ('\u{43}', '\u{301}', '\u{106}'),
+    ('\u{43}', '\u{302}', '\u{108}'),
+    ('\u{43}', '\u{307}', '\u{10A}'),
+    ('\u{43}', '\u{30C}', '\u{10C}'),
+    ('\u{43}', '\u{327}', '\u{C7}'),
+    ('\u{44}', '\u{307}', '\u{1E0A}'),
+    ('\u{44}', '\u{30C}', '\u{10E}'),
+    ('\u{44}', '\u{323}', '\u{1E0C}'),
+    ('\u{44}', '\u{327}', '\u{1E10}'),
+    ('\u{44}', '\u{32D}', '\u{1E12}'),
+    ('\u{44}', '\u{331}', '\u{1E0E}'),
+    ('\u{45}', '\u{300}', '\u{C8}'),
+    ('\u{45}', '\u{301}', '\u{C9}'),
+    ('\u{45}', '\u{302}', '\u{CA}'),
+    ('\u{45}', '\u{303}', '\u{1EBC}'),
+    ('\u{45}', '\u{304}', '\u{112}'),
+    ('\u{45}', '\u{306}', '\u{114}'),
+    ('\u{45}', '\u{307}', '\u{116}'),
+    ('\u{45}', '\u{308}', '\u{CB}'),
+    ('\u{45}', '\u{309}', '\u{1EBA}'),
+    ('\u{45}', '\u{30C}', '\u{11A}'),
+    ('\u{45}', '\u{30F}', '\u{204}'),
+    ('\u{45}', '\u{311}', '\u{206}'),
+    ('\u{45}', '\u{323}', '\u{1EB8}'),
+    ('\u{45}', '\u{327}', '\u{228}'),
+    ('\u{45}', '\u{328}', '\u{118}'),
+    ('\u{45}', '\u{32D}', '\u{1E18}'),
+    ('\u{45}', '\u{330}', '\u{1E1A}'),
+    ('\u{46}', '\u{307}', '\u{1E1E}'),
+    ('\u{47}', '\u{301}', '\u{1F4}'),
+    ('\u{47}', '\u{302}', '\u{11C}'),
+    ('\u{47}', '\u{304}', '\u{1E20}'),
+    ('\u{47}', '\u{306}', '\u{11E}'),
+    ('\u{47}', '\u{307}', '\u{120}'),
+    ('\u{47}', '\u{30C}', '\u{1E6}'),
+    ('\u{47}', '\u{327}', '\u{122}'),
+    ('\u{48}', '\u{302}', '\u{124}'),
+    ('\u{48}', '\u{307}', '\u{1E22}'),
+    ('\u{48}', '\u{308}', '\u{1E26}'),
+    ('\u{48}', '\u{30C}', '\u{21E}'),
+    ('\u{48}', '\u{323}', '\u{1E24}'),
+    ('\u{48}', '\u{327}', '\u{1E28}'),
+    ('\u{48}', '\u{32E}', '\u{1E2A}'),
+    ('\u{49}', '\u{300}', '\u{CC}'),
+    ('\u{49}', '\u{301}', '\u{CD}'),
+    ('\u{49}', '\u{302}', '\u{CE}'),
+    ('\u{49}', '\u{303}', '\u{128}'),
+    ('\u{49}', '\u{304}', '\u{12A}'),
+    ('\u{49}', '\u{306}', '\u{12C}'),
+    ('\u{49}', '\u{307}', '\u{130}'),
+    ('\u{49}', '\u{308}', '\u{CF}'),
+    ('\u{49}', '\u{309}', '\u{1EC8}'),
+    ('\u{49}', '\u{30C}', '\u{1CF}'),
+    ('\u{49}', '\u{30F}', '\u{208}'),
+    ('\u{49}', '\u{311}', '\u{20A}'),
+    ('\u{49}', '\u{323}', '\u{1ECA}'),
+    ('\u{49}', '\u{328}', '\u{12E}'),
+    ('\u{49}', '\u{330}', '\u{1E2C}'),
+    ('\u{4A}', '\u{302}', '\u{134}'),
+    ('\u{4B}', '\u{301}', '\u{1E30}'),
+    ('\u{4B}', '\u{30C}', '\u{1E8}'),
+    ('\u{4B}', '\u{323}', '\u{1E32}'),
+    ('\u{4B}', '\u{327}', '\u{136}'),
+    ('\u{4B}', '\u{331}', '\u{1E34}'),
+    ('\u{4C}', '\u{301}', '\u{139}'),
+    ('\u{4C}', '\u{30C}', '\u{13D}'),
+    ('\u{4C}', '\u{323}', '\u{1E36}'),
+    ('\u{4C}', '\u{327}', '\u{13B}'),
+    ('\u{4C}', '\u{32D}', '\u{1E3C}'),
+    ('\u{4C}', '\u{331}', '\u{1E3A}'),
+    ('\u{4D}', '\u{301}', '\u{1E3E}'),
+    ('\u{4D}', '\u{307}', '\u{1E40}'),
+    ('\u{4D}', '\u{323}', '\u{1E42}'),
+    ('\u{4E}', '\u{300}', '\u{1F8}'),
+    ('\u{4E}', '\u{301}', '\u{143}'),
+    ('\u{4E}', '\u{303}', '\u{D1}'),
+    ('\u{4E}', '\u{307}', '\u{1E44}'),
+    ('\u{4E}', '\u{30C}', '\u{147}'),
+    ('\u{4E}', '\u{323}', '\u{1E46}'),
+    ('\u{4E}', '\u{327}', '\u{145}'),
+    ('\u{4E}', '\u{32D}', '\u{1E4A}'),
+    ('\u{4E}', '\u{331}', '\u{1E48}'),
+    ('\u{4F}', '\u{300}', '\u{D2}'),
+    ('\u{4F}', '\u{301}', '\u{D3}'),
+    ('\u{4F}', '\u{302}', '\u{D4}'),
+    ('\u{4F}', '\u{303}', '\u{D5}'),
+    ('\u{4F}', '\u{304}', '\u{14C}'),
+    ('\u{4F}', '\u{306}', '\u{14E}'),
+    ('\u{4F}', '\u{307}', '\u{22E}'),
+    ('\u{4F}', '\u{308}', '\u{D6}'),
+    ('\u{4F}', '\u{309}', '\u{1ECE}'),
+    ('\u{4F}', '\u{30B}', '\u{150}'),
+    ('\u{4F}', '\u{30C}', '\u{1D1}'),
+    ('\u{4F}', '\u{30F}', '\u{20C}'),
+    ('\u{4F}', '\u{311}', '\u{20E}'),
+    ('\u{4F}', '\u{31B}', '\u{1A0}'),
+    ('\u{4F}', '\u{323}', '\u{1ECC}'),
+    ('\u{4F}', '\u{328}', '\u{1EA}'),
+    ('\u{50}', '\u{301}', '\u{1E54}'),
+    ('\u{50}', '\u{307}', '\u{1E56}'),
+    ('\u{52}', '\u{301}', '\u{154}'),
+    ('\u{52}', '\u{307}', '\u{1E58}'),
+    ('\u{52}', '\u{30C}', '\u{158}'),
+    ('\u{52}', '\u{30F}', '\u{210}'),
+    ('\u{52}', '\u{311}', '\u{212}'),
+    ('\u{52}', '\u{323}', '\u{1E5A}'),
+    ('\u{52}', '\u{327}', '\u{156}'),
+    ('\u{52}', '\u{331}', '\u{1E5E}'),
+    ('\u{53}', '\u{301}', '\u{15A}'),
+    ('\u{53}', '\u{302}', '\u{15C}'),
+    ('\u{53}', '\u{307}', '\u{1E60}'),
+    ('\u{53}', '\u{30C}', '\u{160}'),
+    ('\u{53}', '\u{323}', '\u{1E62}'),
+    ('\u{53}', '\u{326}', '\u{218}'),
+    ('\u{53}', '\u{327}', '\u{15E}'),
+    ('\u{54}', '\u{307}', '\u{1E6A}'),
+    ('\u{54}', '\u{30C}', '\u{164}'),
+    ('\u{54}', '\u{323}', '\u{1E6C}'),
+    ('\u{54}', '\u{326}', '\u{21A}'),
+    ('\u{54}', '\u{327}', '\u{162}'),
+    ('\u{54}', '\u{32D}', '\u{1E70}'),
+    ('\u{54}', '\u{331}', '\u{1E6E}'),
+    ('\u{55}', '\u{300}', '\u{D9}'),
+    ('\u{55}', '\u{301}', '\u{DA}'),
+    ('\u{55}', '\u{302}', '\u{DB}'),
+    ('\u{55}', '\u{303}', '\u{168}'),
+    ('\u{55}', '\u{304}', '\u{16A}'),
+    ('\u{55}', '\u{306}', '\u{16C}'),
+    ('\u{55}', '\u{308}', '\u{DC}'),
+    ('\u{55}', '\u{309}', '\u{1EE6}'),
+    ('\u{55}', '\u{30A}', '\u{16E}'),
+    ('\u{55}', '\u{30B}', '\u{170}'),
+    ('\u{55}', '\u{30C}', '\u{1D3}'),
+    ('\u{55}', '\u{30F}', '\u{214}'),
+    ('\u{55}', '\u{311}', '\u{216}'),
+    ('\u{55}', '\u{31B}', '\u{1AF}'),
+    ('\u{55}', '\u{323}', '\u{1EE4}'),
+    ('\u{55}', '\u{324}', '\u{1E72}'),
+    ('\u{55}', '\u{328}', '\u{172}'),
+    ('\u{55}', '\u{32D}', '\u{1E76}'),
+    ('\u{55}', '\u{330}', '\u{1E74}'),
+    ('\u{56}', '\u{303}', '\u{1E7C}'),
+    ('\u{56}', '\u{323}', '\u{1E7E}'),
+    ('\u{57}', '\u{300}', '\u{1E80}'),
+    ('\u{57}', '\u{301}', '\u{1E82}'),
+    ('\u{57}', '\u{302}', '\u{174}'),
+    ('\u{57}', '\u{307}', '\u{1E86}'),
+    ('\u{57}', '\u{308}', '\u{1E84}'),
+    ('\u{57}', '\u{323}', '\u{1E88}'),
+    ('\u{58}', '\u{307}', '\u{1E8A}'),
+    ('\u{58}', '\u{308}', '\u{1E8C}'),
+    ('\u{59}', '\u{300}', '\u{1EF2}'),
+    ('\u{59}', '\u{301}', '\u{DD}'),
+    ('\u{59}', '\u{302}', '\u{176}'),
+    ('\u{59}', '\u{303}', '\u{1EF8}'),
+    ('\u{59}', '\u{304}', '\u{232}'),
+    ('\u{59}', '\u{307}', '\u{1E8E}'),
+    ('\u{59}', '\u{308}', '\u{178}'),
+    ('\u{59}', '\u{309}', '\u{1EF6}'),
+    ('\u{59}', '\u{323}', '\u{1EF4}'),
+    ('\u{5A}', '\u{301}', '\u{179}'),
+    ('\u{5A}', '\u{302}', '\u{1E90}'),
+    ('\u{5A}', '\u{307}', '\u{17B}'),
+    ('\u{5A}', '\u{30C}', '\u{17D}'),
+    ('\u{5A}', '\u{323}', '\u{1E92}'),
+    ('\u{5A}', '\u{331}', '\u{1E94}'),
+    ('\u{61}', '\u{300}', '\u{E0}'),
+    ('\u{61}', '\u{301}', '\u{E1}'),
+    ('\u{61}', '\u{302}', '\u{E2}'),
+    ('\u{61}', '\u{303}', '\u{E3}'),
+    ('\u{61}', '\u{304}', '\u{101}'),
+    ('\u{61}', '\u{306}', '\u{103}'),
+    ('\u{61}', '\u{307}', '\u{227}'),
+    ('\u{61}', '\u{308}', '\u{E4}'),
+    ('\u{61}', '\u{309}', '\u{1EA3}'),
+    ('\u{61}', '\u{30A}', '\u{E5}'),
+    ('\u{61}', '\u{30C}', '\u{1CE}'),
+    ('\u{61}', '\u{30F}', '\u{201}'),
+    ('\u{61}', '\u{311}', '\u{203}'),
+    ('\u{61}', '\u{323}', '\u{1EA1}'),
+    ('\u{61}', '\u{325}', '\u{1E01}'),
+    ('\u{61}', '\u{328}', '\u{105}'),
+    ('\u{62}', '\u{307}', '\u{1E03}'),
+    ('\u{62}', '\u{323}', '\u{1E05}'),
+    ('\u{62}', '\u{331}', '\u{1E07}'),
+    ('\u{63}', '\u{301}', '\u{107}'),
+    ('\u{63}', '\u{302}', '\u{109}'),
+    ('\u{63}', '\u{307}', '\u{10B}'),
+    ('\u{63}', '\u{30C}', '\u{10D}'),
+    ('\u{63}', '\u{327}', '\u{E7}'),
+    ('\u{64}', '\u{307}', '\u{1E0B}'),
+    ('\u{64}', '\u{30C}', '\u{10F}'),
+    ('\u{64}', '\u{323}', '\u{1E0D}'),
+    ('\u{64}', '\u{327}', '\u{1E11}'),
+    ('\u{64}', '\u{32D}', '\u{1E13}'),
+    ('\u{64}', '\u{331}', '\u{1E0F}'),
+    ('\u{65}', '\u{300}', '\u{E8}'),
+    ('\u{65}', '\u{301}', '\u{E9}'),
+    ('\u{65}', '\u{302}', '\u{EA}'),
+    ('\u{65}', '\u{303}', '\u{1EBD}'),
+    ('\u{65}', '\u{304}', '\u{113}'),
+    ('\u{65}', '\u{306}', '\u{115}'),
+    ('\u{65}', '\u{307}', '\u{117}'),
+    ('\u{65}', '\u{308}', '\u{EB}'),
+    ('\u{65}', '\u{309}', '\u{1EBB}'),
+    ('\u{65}', '\u{30C}', '\u{11B}'),
+    ('\u{65}', '\u{30F}', '\u{205}'),
+    ('\u{65}', '\u{311}', '\u{207}'),
+    ('\u{65}', '\u{323}', '\u{1EB9}'),
+    ('\u{65}', '\u{327}', '\u{229}'),
+    ('\u{65}', '\u{328}', '\u{119}'),
+    ('\u{65}', '\u{32D}', '\u{1E19}'),
+    ('\u{65}', '\u{330}', '\u{1E1B}'),
+    ('\u{66}', '\u{307}', '\u{1E1F}'),
+    ('\u{67}', '\u{301}', '\u{1F5}'),
+    ('\u{67}', '\u{302}', '\u{11D}'),
+    ('\u{67}', '\u{304}', '\u{1E21}'),
+    ('\u{67}', '\u{306}', '\u{11F}'),
+    ('\u{67}', '\u{307}', '\u{121}'),
+    ('\u{67}', '\u{30C}', '\u{1E7}'),
+    ('\u{67}', '\u{327}', '\u{123}'),
+    ('\u{68}', '\u{302}', '\u{125}'),
+    ('\u{68}', '\u{307}', '\u{1E23}'),
+    ('\u{68}', '\u{308}', '\u{1E27}'),
+    ('\u{68}', '\u{30C}', '\u{21F}'),
+    ('\u{68}', '\u{323}', '\u{1E25}'),
+    ('\u{68}', '\u{327}', '\u{1E29}'),
+    ('\u{68}', '\u{32E}', '\u{1E2B}'),
+    ('\u{68}', '\u{331}', '\u{1E96}'),
+    ('\u{69}', '\u{300}', '\u{EC}'),
+    ('\u{69}', '\u{301}', '\u{ED}'),
+    ('\u{69}', '\u{302}', '\u{EE}'),
+    ('\u{69}', '\u{303}', '\u{129}'),
+    ('\u{69}', '\u{304}', '\u{12B}'),
+    ('\u{69}', '\u{306}', '\u{12D}'),
+    ('\u{69}', '\u{308}', '\u{EF}'),
+    ('\u{69}', '\u{309}', '\u{1EC9}'),
+    ('\u{69}', '\u{30C}', '\u{1D0}'),
+    ('\u{69}', '\u{30F}', '\u{209}'),
+    ('\u{69}', '\u{311}', '\u{20B}'),
+    ('\u{69}', '\u{323}', '\u{1ECB}'),
+    ('\u{69}', '\u{328}', '\u{12F}'),
+    ('\u{69}', '\u{330}', '\u{1E2D}'),
+    ('\u{6A}', '\u{302}', '\u{135}'),
+    ('\u{6A}', '\u{30C}', '\u{1F0}'),
+    ('\u{6B}', '\u{301}', '\u{1E31}'),
+    ('\u{6B}', '\u{30C}', '\u{1E9}'),
+    ('\u{6B}', '\u{323}', '\u{1E33}'),
+    ('\u{6B}', '\u{327}', '\u{137}'),
+    ('\u{6B}', '\u{331}', '\u{1E35}'),
+    ('\u{6C}', '\u{301}', '\u{13A}'),
+    ('\u{6C}', '\u{30C}', '\u{13E}'),
+    ('\u{6C}', '\u{323}', '\u{1E37}'),
+    ('\u{6C}', '\u{327}', '\u{13C}'),
+    ('\u{6C}', '\u{32D}', '\u{1E3D}'),
+    ('\u{6C}', '\u{331}', '\u{1E3B}'),
+    ('\u{6D}', '\u{301}', '\u{1E3F}'),
+    ('\u{6D}', '\u{307}', '\u{1E41}'),
+    ('\u{6D}', '\u{323}', '\u{1E43}'),
+    ('\u{6E}', '\u{300}', '\u{1F9}'),
+    ('\u{6E}', '\u{301}', '\u{144}'),
+    ('\u{6E}', '\u{303}', '\u{F1}'),
+    ('\u{6E}', '\u{307}', '\u{1E45}'),
+    ('\u{6E}', '\u{30C}', '\u{148}'),
+    ('\u{6E}', '\u{323}', '\u{1E47}'),
+    ('\u{6E}', '\u{327}', '\u{146}'),
+    ('\u{6E}', '\u{32D}', '\u{1E4B}'),
+    ('\u{6E}', '\u{331}', '\u{1E49}'),
+    ('\u{6F}', '\u{300}', '\u{F2}'),
+    ('\u{6F}', '\u{301}', '\u{F3}'),
+    ('\u{6F}', '\u{302}', '\u{F4}'),
+    ('\u{6F}', '\u{303}', '\u{F5}'),
+    ('\u{6F}', '\u{304}', '\u{14D}'),
+    ('\u{6F}', '\u{306}', '\u{14F}'),
+    ('\u{6F}', '\u{307}', '\u{22F}'),
+    ('\u{6F}', '\u{308}', '\u{F6}'),
+    ('\u{6F}', '\u{309}', '\u{1ECF}'),
+    ('\u{6F}', '\u{30B}', '\u{151}'),
+    ('\u{6F}', '\u{30C}', '\u{1D2}'),
+    ('\u{6F}', '\u{30F}', '\u{20D}'),
+    ('\u{6F}', '\u{311}', '\u{20F}'),
+    ('\u{6F}', '\u{31B}', '\u{1A1}'),
+    ('\u{6F}', '\u{323}', '\u{1ECD}'),
+    ('\u{6F}', '\u{328}', '\u{1EB}'),
+    ('\u{70}', '\u{301}', '\u{1E55}'),
+    ('\u{70}', '\u{307}', '\u{1E57}'),
+    ('\u{72}', '\u{301}', '\u{155}'),
+    ('\u{72}', '\u{307}', '\u{1E59}'),
+    ('\u{72}', '\u{30C}', '\u{159}'),
+    ('\u{72}', '\u{30F}', '\u{211}'),
+    ('\u{72}', '\u{311}', '\u{213}'),
+    ('\u{72}', '\u{323}', '\u{1E5B}'),
+    ('\u{72}', '\u{327}', '\u{157}'),
+    ('\u{72}', '\u{331}', '\u{1E5F}'),
+    ('\u{73}', '\u{301}', '\u{15B}'),
+    ('\u{73}', '\u{302}', '\u{15D}'),
+    ('\u{73}', '\u{307}', '\u{1E61}'),
+    ('\u{73}', '\u{30C}', '\u{161}'),
+    ('\u{73}', '\u{323}', '\u{1E63}'),
+    ('\u{73}', '\u{326}', '\u{219}'),
+    ('\u{73}', '\u{327}', '\u{15F}'),
+    ('\u{74}', '\u{307}', '\u{1E6B}'),
+    ('\u{74}', '\u{308}', '\u{1E97}'),
+    ('\u{74}', '\u{30C}', '\u{165}'),
+    ('\u{74}', '\u{323}', '\u{1E6D}'),
+    ('\u{74}', '\u{326}', '\u{21B}'),
+    ('\u{74}', '\u{327}', '\u{163}'),
+    ('\u{74}', '\u{32D}', '\u{1E71}'),
+    ('\u{74}', '\u{331}', '\u{1E6F}'),
+    ('\u{75}', '\u{300}', '\u{F9}'),
+    ('\u{75}', '\u{301}', '\u{FA}'),
+    ('\u{75}', '\u{302}', '\u{FB}'),
+    ('\u{75}', '\u{303}', '\u{169}'),
+    ('\u{75}', '\u{304}', '\u{16B}'),
+    ('\u{75}', '\u{306}', '\u{16D}'),
+    ('\u{75}', '\u{308}', '\u{FC}'),
+    ('\u{75}', '\u{309}', '\u{1EE7}'),
+    ('\u{75}', '\u{30A}', '\u{16F}'),
+    ('\u{75}', '\u{30B}', '\u{171}'),
+    ('\u{75}', '\u{30C}', '\u{1D4}'),
+    ('\u{75}', '\u{30F}', '\u{215}'),
+    ('\u{75}', '\u{311}', '\u{217}'),
+    ('\u{75}', '\u{31B}', '\u{1B0}'),
+    ('\u{75}', '\u{323}', '\u{1EE5}'),
+    ('\u{75}', '\u{324}', '\u{1E73}'),
+    ('\u{75}', '\u{328}', '\u{173}'),
+    ('\u{75}', '\u{32D}', '\u{1E77}'),
+    ('\u{75}', '\u{330}', '\u{1E75}'),
+    ('\u{76}', '\u{303}', '\u{1E7D}'),
+    ('\u{76}', '\u{323}', '\u{1E7F}'),
+    ('\u{77}', '\u{300}', '\u{1E81}'),
+    ('\u{77}', '\u{301}', '\u{1E83}'),
+    ('\u{77}', '\u{302}', '\u{175}'),
+    ('\u{77}', '\u{307}', '\u{1E87}'),
+    ('\u{77}', '\u{308}', '\u{1E85}'),
+    ('\u{77}', '\u{30A}', '\u{1E98}'),
+    ('\u{77}', '\u{323}', '\u{1E89}'),
+    ('\u{78}', '\u{307}', '\u{1E8B}'),
+    ('\u{78}', '\u{308}', '\u{1E8D}'),
+    ('\u{79}', '\u{300}', '\u{1EF3}'),
+    ('\u{79}', '\u{301}', '\u{FD}'),
+    ('\u{79}', '\u{302}', '\u{177}'),
+    ('\u{79}', '\u{303}', '\u{1EF9}'),
+    ('\u{79}', '\u{304}', '\u{233}'),
+    ('\u{79}', '\u{307}', '\u{1E8F}'),
+    ('\u{79}', '\u{308}', '\u{FF}'),
+    ('\u{79}', '\u{309}', '\u{1EF7}'),
+    ('\u{79}', '\u{30A}', '\u{1E99}'),
+    ('\u{79}', '\u{323}', '\u{1EF5}'),
+    ('\u{7A}', '\u{301}', '\u{17A}'),
+    ('\u{7A}', '\u{302}', '\u{1E91}'),
+    ('\u{7A}', '\u{307}', '\u{17C}'),
+    ('\u{7A}', '\u{30C}', '\u{17E}'),
+    ('\u{7A}', '\u{323}', '\u{1E93}'),
+    ('\u{7A}', '\u{331}', '\u{1E95}'),
+    ('\u{A8}', '\u{300}', '\u{1FED}'),
+    ('\u{A8}', '\u{301}', '\u{385}'),
+    ('\u{A8}', '\u{342}', '\u{1FC1}'),
+    ('\u{C2}', '\u{300}', '\u{1EA6}'),
+    ('\u{C2}', '\u{301}', '\u{1EA4}'),
+    ('\u{C2}', '\u{303}', '\u{1EAA}'),
+    ('\u{C2}', '\u{309}', '\u{1EA8}'),
+    ('\u{C4}', '\u{304}', '\u{1DE}'),
+    ('\u{C5}', '\u{301}', '\u{1FA}'),
+    ('\u{C6}', '\u{301}', '\u{1FC}'),
+    ('\u{C6}', '\u{304}', '\u{1E2}'),
+    ('\u{C7}', '\u{301}', '\u{1E08}'),
+    ('\u{CA}', '\u{300}', '\u{1EC0}'),
+    ('\u{CA}', '\u{301}', '\u{1EBE}'),
+    ('\u{CA}', '\u{303}', '\u{1EC4}'),
+    ('\u{CA}', '\u{309}', '\u{1EC2}'),
+    ('\u{CF}', '\u{301}', '\u{1E2E}'),
+    ('\u{D4}', '\u{300}', '\u{1ED2}'),
+    ('\u{D4}', '\u{301}', '\u{1ED0}'),
+    ('\u{D4}', '\u{303}', '\u{1ED6}'),
+    ('\u{D4}', '\u{309}', '\u{1ED4}'),
+    ('\u{D5}', '\u{301}', '\u{1E4C}'),
+    ('\u{D5}', '\u{304}', '\u{22C}'),
+    ('\u{D5}', '\u{308}', '\u{1E4E}'),
+    ('\u{D6}', '\u{304}', '\u{22A}'),
+    ('\u{D8}', '\u{301}', '\u{1FE}'),
+    ('\u{DC}', '\u{300}', '\u{1DB}'),
+    ('\u{DC}', '\u{301}', '\u{1D7}'),
+    ('\u{DC}', '\u{304}', '\u{1D5}'),
+    ('\u{DC}', '\u{30C}', '\u{1D9}'),
+    ('\u{E2}', '\u{300}', '\u{1EA7}'),
+    ('\u{E2}', '\u{301}', '\u{1EA5}'),
+    ('\u{E2}', '\u{303}', '\u{1EAB}'),
+    ('\u{E2}', '\u{309}', '\u{1EA9}'),
+    ('\u{E4}', '\u{304}', '\u{1DF}'),
+    ('\u{E5}', '\u{301}', '\u{1FB}'),
+    ('\u{E6}', '\u{301}', '\u{1FD}'),
+    ('\u{E6}', '\u{304}', '\u{1E3}'),
+    ('\u{E7}', '\u{301}', '\u{1E09}'),
+    ('\u{EA}', '\u{300}', '\u{1EC1}'),
+    ('\u{EA}', '\u{301}', '\u{1EBF}'),
+    ('\u{EA}', '\u{303}', '\u{1EC5}'),
+    ('\u{EA}', '\u{309}', '\u{1EC3}'),
+    ('\u{EF}', '\u{301}', '\u{1E2F}'),
+    ('\u{F4}', '\u{300}', '\u{1ED3}'),
+    ('\u{F4}', '\u{301}', '\u{1ED1}'),
+    ('\u{F4}', '\u{303}', '\u{1ED7}'),
+    ('\u{F4}', '\u{309}', '\u{1ED5}'),
+    ('\u{F5}', '\u{301}', '\u{1E4D}'),
+    ('\u{F5}', '\u{304}', '\u{22D}'),
+    ('\u{F5}', '\u{308}', '\u{1E4F}'),
+    ('\u{F6}', '\u{304}', '\u{22B}'),
+    ('\u{F8}', '\u{301}', '\u{1FF}'),
+    ('\u{FC}', '\u{300}', '\u{1DC}'),
+    ('\u{FC}', '\u{301}', '\u{1D8}'),
+    ('\u{FC}', '\u{304}', '\u{1D6}'),
+    ('\u{FC}', '\u{30C}', '\u{1DA}'),
+    ('\u{102}', '\u{300}', '\u{1EB0}'),
+    ('\u{102}', '\u{301}', '\u{1EAE}'),
+    ('\u{102}', '\u{303}', '\u{1EB4}'),
+    ('\u{102}', '\u{309}', '\u{1EB2}'),
+    ('\u{103}', '\u{300}', '\u{1EB1}'),
+    ('\u{103}', '\u{301}', '\u{1EAF}'),
+    ('\u{103}', '\u{303}', '\u{1EB5}'),
+    ('\u{103}', '\u{309}', '\u{1EB3}'),
+    ('\u{112}', '\u{300}', '\u{1E14}'),
+    ('\u{112}', '\u{301}', '\u{1E16}'),
+    ('\u{113}', '\u{300}', '\u{1E15}'),
+    ('\u{113}', '\u{301}', '\u{1E17}'),
+    ('\u{14C}', '\u{300}', '\u{1E50}'),
+    ('\u{14C}', '\u{301}', '\u{1E52}'),
+    ('\u{14D}', '\u{300}', '\u{1E51}'),
+    ('\u{14D}', '\u{301}', '\u{1E53}'),
+    ('\u{15A}', '\u{307}', '\u{1E64}'),
+    ('\u{15B}', '\u{307}', '\u{1E65}'),
+    ('\u{160}', '\u{307}', '\u{1E66}'),
+    ('\u{161}', '\u{307}', '\u{1E67}'),
+    ('\u{168}', '\u{301}', '\u{1E78}'),
+    ('\u{169}', '\u{301}', '\u{1E79}'),
+    ('\u{16A}', '\u{308}', '\u{1E7A}'),
+    ('\u{16B}', '\u{308}', '\u{1E7B}'),
+    ('\u{17F}', '\u{307}', '\u{1E9B}'),
+    ('\u{1A0}', '\u{300}', '\u{1EDC}'),
+    ('\u{1A0}', '\u{301}', '\u{1EDA}'),
+    ('\u{1A0}', '\u{303}', '\u{1EE0}'),
+    ('\u{1A0}', '\u{309}', '\u{1EDE}'),
+    ('\u{1A0}', '\u{323}', '\u{1EE2}'),
+    ('\u{1A1}', '\u{300}', '\u{1EDD}'),
+    ('\u{1A1}', '\u{301}', '\u{1EDB}'),
+    ('\u{1A1}', '\u{303}', '\u{1EE1}'),
+    ('\u{1A1}', '\u{309}', '\u{1EDF}'),
+    ('\u{1A1}', '\u{323}', '\u{1EE3}'),
+    ('\u{1AF}', '\u{300}', '\u{1EEA}'),
+    ('\u{1AF}', '\u{301}', '\u{1EE8}'),
+    ('\u{1AF}', '\u{303}', '\u{1EEE}'),
+    ('\u{1AF}', '\u{309}', '\u{1EEC}'),
+    ('\u{1AF}', '\u{323}', '\u{1EF0}'),
+    ('\u{1B0}', '\u{300}', '\u{1EEB}'),
+    ('\u{1B0}', '\u{301}', '\u{1EE9}'),
+    ('\u{1B0}', '\u{303}', '\u{1EEF}'),
+    ('\u{1B0}', '\u{309}', '\u{1EED}'),
+    ('\u{1B0}', '\u{323}', '\u{1EF1}'),
+    ('\u{1B7}', '\u{30C}', '\u{1EE}'),
+    ('\u{1EA}', '\u{304}', '\u{1EC}'),
+    ('\u{1EB}', '\u{304}', '\u{1ED}'),
+    ('\u{226}', '\u{304}', '\u{1E0}'),
+    ('\u{227}', '\u{304}', '\u{1E1}'),
+    ('\u{228}', '\u{306}', '\u{1E1C}'),
+    ('\u{229}', '\u{306}', '\u{1E1D}'),
+    ('\u{22E}', '\u{304}', '\u{230}'),
+    ('\u{22F}', '\u{304}', '\u{231}'),
+    ('\u{292}', '\u{30C}', '\u{1EF}'),
+    ('\u{391}', '\u{300}', '\u{1FBA}'),
+    ('\u{391}', '\u{301}', '\u{386}'),
+    ('\u{391}', '\u{304}', '\u{1FB9}'),
+    ('\u{391}', '\u{306}', '\u{1FB8}'),
+    ('\u{391}', '\u{313}', '\u{1F08}'),
+    ('\u{391}', '\u{314}', '\u{1F09}'),
+    ('\u{391}', '\u{345}', '\u{1FBC}'),
+    ('\u{395}', '\u{300}', '\u{1FC8}'),
+    ('\u{395}', '\u{301}', '\u{388}'),
+    ('\u{395}', '\u{313}', '\u{1F18}'),
+    ('\u{395}', '\u{314}', '\u{1F19}'),
+    ('\u{397}', '\u{300}', '\u{1FCA}'),
+    ('\u{397}', '\u{301}', '\u{389}'),
+    ('\u{397}', '\u{313}', '\u{1F28}'),
+    ('\u{397}', '\u{314}', '\u{1F29}'),
+    ('\u{397}', '\u{345}', '\u{1FCC}'),
+    ('\u{399}', '\u{300}', '\u{1FDA}'),
+    ('\u{399}', '\u{301}', '\u{38A}'),
+    ('\u{399}', '\u{304}', '\u{1FD9}'),
+    ('\u{399}', '\u{306}', '\u{1FD8}'),
+    ('\u{399}', '\u{308}', '\u{3AA}'),
+    ('\u{399}', '\u{313}', '\u{1F38}'),
+    ('\u{399}', '\u{314}', '\u{1F39}'),
+    ('\u{39F}', '\u{300}', '\u{1FF8}'),
+    ('\u{39F}', '\u{301}', '\u{38C}'),
+    ('\u{39F}', '\u{313}', '\u{1F48}'),
+    ('\u{39F}', '\u{314}', '\u{1F49}'),
+    ('\u{3A1}', '\u{314}', '\u{1FEC}'),
+    ('\u{3A5}', '\u{300}', '\u{1FEA}'),
+    ('\u{3A5}', '\u{301}', '\u{38E}'),
+    ('\u{3A5}', '\u{304}', '\u{1FE9}'),
+    ('\u{3A5}', '\u{306}', '\u{1FE8}'),
+    ('\u{3A5}', '\u{308}', '\u{3AB}'),
+    ('\u{3A5}', '\u{314}', '\u{1F59}'),
+    ('\u{3A9}', '\u{300}', '\u{1FFA}'),
+    ('\u{3A9}', '\u{301}', '\u{38F}'),
+    ('\u{3A9}', '\u{313}', '\u{1F68}'),
+    ('\u{3A9}', '\u{314}', '\u{1F69}'),
+    ('\u{3A9}', '\u{345}', '\u{1FFC}'),
+    ('\u{3AC}', '\u{345}', '\u{1FB4}'),
+    ('\u{3AE}', '\u{345}', '\u{1FC4}'),
+    ('\u{3B1}', '\u{300}', '\u{1F70}'),
+    ('\u{3B1}', '\u{301}', '\u{3AC}'),
+    ('\u{3B1}', '\u{304}', '\u{1FB1}'),
+    ('\u{3B1}', '\u{306}', '\u{1FB0}'),
+    ('\u{3B1}', '\u{313}', '\u{1F00}'),
+    ('\u{3B1}', '\u{314}', '\u{1F01}'),
+    ('\u{3B1}', '\u{342}', '\u{1FB6}'),
+    ('\u{3B1}', '\u{345}', '\u{1FB3}'),
+    ('\u{3B5}', '\u{300}', '\u{1F72}'),
+    ('\u{3B5}', '\u{301}', '\u{3AD}'),
+    ('\u{3B5}', '\u{313}', '\u{1F10}'),
+    ('\u{3B5}', '\u{314}', '\u{1F11}'),
+    ('\u{3B7}', '\u{300}', '\u{1F74}'),
+    ('\u{3B7}', '\u{301}', '\u{3AE}'),
+    ('\u{3B7}', '\u{313}', '\u{1F20}'),
+    ('\u{3B7}', '\u{314}', '\u{1F21}'),
+    ('\u{3B7}', '\u{342}', '\u{1FC6}'),
+    ('\u{3B7}', '\u{345}', '\u{1FC3}'),
+    ('\u{3B9}', '\u{300}', '\u{1F76}'),
+    ('\u{3B9}', '\u{301}', '\u{3AF}'),
+    ('\u{3B9}', '\u{304}', '\u{1FD1}'),
+    ('\u{3B9}', '\u{306}', '\u{1FD0}'),
+    ('\u{3B9}', '\u{308}', '\u{3CA}'),
+    ('\u{3B9}', '\u{313}', '\u{1F30}'),
+    ('\u{3B9}', '\u{314}', '\u{1F31}'),
+    ('\u{3B9}', '\u{342}', '\u{1FD6}'),
+    ('\u{3BF}', '\u{300}', '\u{1F78}'),
+    ('\u{3BF}', '\u{301}', '\u{3CC}'),
+    ('\u{3BF}', '\u{313}', '\u{1F40}'),
+    ('\u{3BF}', '\u{314}', '\u{1F41}'),
+    ('\u{3C1}', '\u{313}', '\u{1FE4}'),
+    ('\u{3C1}', '\u{314}', '\u{1FE5}'),
+    ('\u{3C5}', '\u{300}', '\u{1F7A}'),
+    ('\u{3C5}', '\u{301}', '\u{3CD}'),
+    ('\u{3C5}', '\u{304}', '\u{1FE1}'),
+    ('\u{3C5}', '\u{306}', '\u{1FE0}'),
+    ('\u{3C5}', '\u{308}', '\u{3CB}'),
+    ('\u{3C5}', '\u{313}', '\u{1F50}'),
+    ('\u{3C5}', '\u{314}', '\u{1F51}'),
+    ('\u{3C5}', '\u{342}', '\u{1FE6}'),
+    ('\u{3C9}', '\u{300}', '\u{1F7C}'),
+    ('\u{3C9}', '\u{301}', '\u{3CE}'),
+    ('\u{3C9}', '\u{313}', '\u{1F60}'),
+    ('\u{3C9}', '\u{314}', '\u{1F61}'),
+    ('\u{3C9}', '\u{342}', '\u{1FF6}'),
+    ('\u{3C9}', '\u{345}', '\u{1FF3}'),
+    ('\u{3CA}', '\u{300}', '\u{1FD2}'),
+    ('\u{3CA}', '\u{301}', '\u{390}'),
+    ('\u{3CA}', '\u{342}', '\u{1FD7}'),
+    ('\u{3CB}', '\u{300}', '\u{1FE2}'),
+    ('\u{3CB}', '\u{301}', '\u{3B0}'),
+    ('\u{3CB}', '\u{342}', '\u{1FE7}'),
+    ('\u{3CE}', '\u{345}', '\u{1FF4}'),
+    ('\u{3D2}', '\u{301}', '\u{3D3}'),
+    ('\u{3D2}', '\u{308}', '\u{3D4}'),
+    ('\u{406}', '\u{308}', '\u{407}'),
+    ('\u{410}', '\u{306}', '\u{4D0}'),
+    ('\u{410}', '\u{308}', '\u{4D2}'),
+    ('\u{413}', '\u{301}', '\u{403}'),
+    ('\u{415}', '\u{300}', '\u{400}'),
+    ('\u{415}', '\u{306}', '\u{4D6}'),
+    ('\u{415}', '\u{308}', '\u{401}'),
+    ('\u{416}', '\u{306}', '\u{4C1}'),
+    ('\u{416}', '\u{308}', '\u{4DC}'),
+    ('\u{417}', '\u{308}', '\u{4DE}'),
+    ('\u{418}', '\u{300}', '\u{40D}'),
+    ('\u{418}', '\u{304}', '\u{4E2}'),
+    ('\u{418}', '\u{306}', '\u{419}'),
+    ('\u{418}', '\u{308}', '\u{4E4}'),
+    ('\u{41A}', '\u{301}', '\u{40C}'),
+    ('\u{41E}', '\u{308}', '\u{4E6}'),
+    ('\u{423}', '\u{304}', '\u{4EE}'),
+    ('\u{423}', '\u{306}', '\u{40E}'),
+    ('\u{423}', '\u{308}', '\u{4F0}'),
+    ('\u{423}', '\u{30B}', '\u{4F2}'),
+    ('\u{427}', '\u{308}', '\u{4F4}'),
+    ('\u{42B}', '\u{308}', '\u{4F8}'),
+    ('\u{42D}', '\u{308}', '\u{4EC}'),
+    ('\u{430}', '\u{306}', '\u{4D1}'),
+    ('\u{430}', '\u{308}', '\u{4D3}'),
+    ('\u{433}', '\u{301}', '\u{453}'),
+    ('\u{435}', '\u{300}', '\u{450}'),
+    ('\u{435}', '\u{306}', '\u{4D7}'),
+    ('\u{435}', '\u{308}', '\u{451}'),
+    ('\u{436}', '\u{306}', '\u{4C2}'),
+    ('\u{436}', '\u{308}', '\u{4DD}'),
+    ('\u{437}', '\u{308}', '\u{4DF}'),
+    ('\u{438}', '\u{300}', '\u{45D}'),
+    ('\u{438}', '\u{304}', '\u{4E3}'),
+    ('\u{438}', '\u{306}', '\u{439}'),
+    ('\u{438}', '\u{308}', '\u{4E5}'),
+    ('\u{43A}', '\u{301}', '\u{45C}'),
+    ('\u{43E}', '\u{308}', '\u{4E7}'),
+    ('\u{443}', '\u{304}', '\u{4EF}'),
+    ('\u{443}', '\u{306}', '\u{45E}'),
+    ('\u{443}', '\u{308}', '\u{4F1}'),
+    ('\u{443}', '\u{30B}', '\u{4F3}'),
+    ('\u{447}', '\u{308}', '\u{4F5}'),
+    ('\u{44B}', '\u{308}', '\u{4F9}'),
+    ('\u{44D}', '\u{308}', '\u{4ED}'),
+    ('\u{456}', '\u{308}', '\u{457}'),
+    ('\u{474}', '\u{30F}', '\u{476}'),
+    ('\u{475}', '\u{30F}', '\u{477}'),
+    ('\u{4D8}', '\u{308}', '\u{4DA}'),
+    ('\u{4D9}', '\u{308}', '\u{4DB}'),
+    ('\u{4E8}', '\u{308}', '\u{4EA}'),
+    ('\u{4E9}', '\u{308}', '\u{4EB}'),
+    ('\u{627}', '\u{653}', '\u{622}'),
+    ('\u{627}', '\u{654}', '\u{623}'),
+    ('\u{627}', '\u{655}', '\u{625}'),
+    ('\u{648}', '\u{654}', '\u{624}'),
+    ('\u{64A}', '\u{654}', '\u{626}'),
+    ('\u{6C1}', '\u{654}', '\u{6C2}'),
+    ('\u{6D2}', '\u{654}', '\u{6D3}'),
+    ('\u{6D5}', '\u{654}', '\u{6C0}'),
+    ('\u{928}', '\u{93C}', '\u{929}'),
+    ('\u{930}', '\u{93C}', '\u{931}'),
+    ('\u{933}', '\u{93C}', '\u{934}'),
+    ('\u{9C7}', '\u{9BE}', '\u{9CB}'),
+    ('\u{9C7}', '\u{9D7}', '\u{9CC}'),
+    ('\u{B47}', '\u{B3E}', '\u{B4B}'),
+    ('\u{B47}', '\u{B56}', '\u{B48}'),
+    ('\u{B47}', '\u{B57}', '\u{B4C}'),
+    ('\u{B92}', '\u{BD7}', '\u{B94}'),
+    ('\u{BC6}', '\u{BBE}', '\u{BCA}'),
+    ('\u{BC6}', '\u{BD7}', '\u{BCC}'),
+    ('\u{BC7}', '\u{BBE}', '\u{BCB}'),
+    ('\u{C46}', '\u{C56}', '\u{C48}'),
+    ('\u{CBF}', '\u{CD5}', '\u{CC0}'),
+    ('\u{CC6}', '\u{CC2}', '\u{CCA}'),
+    ('\u{CC6}', '\u{CD5}', '\u{CC7}'),
+    ('\u{CC6}', '\u{CD6}', '\u{CC8}'),
+    ('\u{CCA}', '\u{CD5}', '\u{CCB}'),
+    ('\u{D46}', '\u{D3E}', '\u{D4A}'),
+    ('\u{D46}', '\u{D57}', '\u{D4C}'),
+    ('\u{D47}', '\u{D3E}', '\u{D4B}'),
+    ('\u{DD9}', '\u{DCA}', '\u{DDA}'),
+    ('\u{DD9}', '\u{DCF}', '\u{DDC}'),
+    ('\u{DD9}', '\u{DDF}', '\u{DDE}'),
+    ('\u{DDC}', '\u{DCA}', '\u{DDD}'),
+    ('\u{1025}', '\u{102E}', '\u{1026}'),
+    ('\u{1B05}', '\u{1B35}', '\u{1B06}'),
+    ('\u{1B07}', '\u{1B35}', '\u{1B08}'),
+    ('\u{1B09}', '\u{1B35}', '\u{1B0A}'),
+    ('\u{1B0B}', '\u{1B35}', '\u{1B0C}'),
+    ('\u{1B0D}', '\u{1B35}', '\u{1B0E}'),
+    ('\u{1B11}', '\u{1B35}', '\u{1B12}'),
+    ('\u{1B3A}', '\u{1B35}', '\u{1B3B}'),
+    ('\u{1B3C}', '\u{1B35}', '\u{1B3D}'),
+    ('\u{1B3E}', '\u{1B35}', '\u{1B40}'),
+    ('\u{1B3F}', '\u{1B35}', '\u{1B41}'),
+    ('\u{1B42}', '\u{1B35}', '\u{1B43}'),
+    ('\u{1E36}', '\u{304}', '\u{1E38}'),
+    ('\u{1E37}', '\u{304}', '\u{1E39}'),
+    ('\u{1E5A}', '\u{304}', '\u{1E5C}'),
+    ('\u{1E5B}', '\u{304}', '\u{1E5D}'),
+    ('\u{1E62}', '\u{307}', '\u{1E68}'),
+    ('\u{1E63}', '\u{307}', '\u{1E69}'),
+    ('\u{1EA0}', '\u{302}', '\u{1EAC}'),
+    ('\u{1EA0}', '\u{306}', '\u{1EB6}'),
+    ('\u{1EA1}', '\u{302}', '\u{1EAD}'),
+    ('\u{1EA1}', '\u{306}', '\u{1EB7}'),
+    ('\u{1EB8}', '\u{302}', '\u{1EC6}'),
+    ('\u{1EB9}', '\u{302}', '\u{1EC7}'),
+    ('\u{1ECC}', '\u{302}', '\u{1ED8}'),
+    ('\u{1ECD}', '\u{302}', '\u{1ED9}'),
+    ('\u{1F00}', '\u{300}', '\u{1F02}'),
+    ('\u{1F00}', '\u{301}', '\u{1F04}'),
+    ('\u{1F00}', '\u{342}', '\u{1F06}'),
+    ('\u{1F00}', '\u{345}', '\u{1F80}'),
+    ('\u{1F01}', '\u{300}', '\u{1F03}'),
+    ('\u{1F01}', '\u{301}', '\u{1F05}'),
+    ('\u{1F01}', '\u{342}', '\u{1F07}'),
+    ('\u{1F01}', '\u{345}', '\u{1F81}'),
+    ('\u{1F02}', '\u{345}', '\u{1F82}'),
+    ('\u{1F03}', '\u{345}', '\u{1F83}'),
+    ('\u{1F04}', '\u{345}', '\u{1F84}'),
+    ('\u{1F05}', '\u{345}', '\u{1F85}'),
+    ('\u{1F06}', '\u{345}', '\u{1F86}'),
+    ('\u{1F07}', '\u{345}', '\u{1F87}'),
+    ('\u{1F08}', '\u{300}', '\u{1F0A}'),
+    ('\u{1F08}', '\u{301}', '\u{1F0C}'),
+    ('\u{1F08}', '\u{342}', '\u{1F0E}'),
+    ('\u{1F08}', '\u{345}', '\u{1F88}'),
+    ('\u{1F09}', '\u{300}', '\u{1F0B}'),
+    ('\u{1F09}', '\u{301}', '\u{1F0D}'),
+    ('\u{1F09}', '\u{342}', '\u{1F0F}'),
+    ('\u{1F09}', '\u{345}', '\u{1F89}'),
+    ('\u{1F0A}', '\u{345}', '\u{1F8A}'),
+    ('\u{1F0B}', '\u{345}', '\u{1F8B}'),
+    ('\u{1F0C}', '\u{345}', '\u{1F8C}'),
+    ('\u{1F0D}', '\u{345}', '\u{1F8D}'),
+    ('\u{1F0E}', '\u{345}', '\u{1F8E}'),
+    ('\u{1F0F}', '\u{345}', '\u{1F8F}'),
+    ('\u{1F10}', '\u{300}', '\u{1F12}'),
+    ('\u{1F10}', '\u{301}', '\u{1F14}'),
+    ('\u{1F11}', '\u{300}', '\u{1F13}'),
+    ('\u{1F11}', '\u{301}', '\u{1F15}'),
+    ('\u{1F18}', '\u{300}', '\u{1F1A}'),
+    ('\u{1F18}', '\u{301}', '\u{1F1C}'),
+    ('\u{1F19}', '\u{300}', '\u{1F1B}'),
+    ('\u{1F19}', '\u{301}', '\u{1F1D}'),
+    ('\u{1F20}', '\u{300}', '\u{1F22}'),
+    ('\u{1F20}', '\u{301}', '\u{1F24}'),
+    ('\u{1F20}', '\u{342}', '\u{1F26}'),
+    ('\u{1F20}', '\u{345}', '\u{1F90}'),
+    ('\u{1F21}', '\u{300}', '\u{1F23}'),
+    ('\u{1F21}', '\u{301}', '\u{1F25}'),
+    ('\u{1F21}', '\u{342}', '\u{1F27}'),
+    ('\u{1F21}', '\u{345}', '\u{1F91}'),
+    ('\u{1F22}', '\u{345}', '\u{1F92}'),
+    ('\u{1F23}', '\u{345}', '\u{1F93}'),
+    ('\u{1F24}', '\u{345}', '\u{1F94}'),
+    ('\u{1F25}', '\u{345}', '\u{1F95}'),
+    ('\u{1F26}', '\u{345}', '\u{1F96}'),
+    ('\u{1F27}', '\u{345}', '\u{1F97}'),
+    ('\u{1F28}', '\u{300}', '\u{1F2A}'),
+    ('\u{1F28}', '\u{301}', '\u{1F2C}'),
+    ('\u{1F28}', '\u{342}', '\u{1F2E}'),
+    ('\u{1F28}', '\u{345}', '\u{1F98}'),
+    ('\u{1F29}', '\u{300}', '\u{1F2B}'),
+    ('\u{1F29}', '\u{301}', '\u{1F2D}'),
+    ('\u{1F29}', '\u{342}', '\u{1F2F}'),
+    ('\u{1F29}', '\u{345}', '\u{1F99}'),
+    ('\u{1F2A}', '\u{345}', '\u{1F9A}'),
+    ('\u{1F2B}', '\u{345}', '\u{1F9B}'),
+    ('\u{1F2C}', '\u{345}', '\u{1F9C}'),
+    ('\u{1F2D}', '\u{345}', '\u{1F9D}'),
+    ('\u{1F2E}', '\u{345}', '\u{1F9E}'),
+    ('\u{1F2F}', '\u{345}', '\u{1F9F}'),
+    ('\u{1F30}', '\u{300}', '\u{1F32}'),
+    ('\u{1F30}', '\u{301}', '\u{1F34}'),
+    ('\u{1F30}', '\u{342}', '\u{1F36}'),
+    ('\u{1F31}', '\u{300}', '\u{1F33}'),
+    ('\u{1F31}', '\u{301}', '\u{1F35}'),
+    ('\u{1F31}', '\u{342}', '\u{1F37}'),
+    ('\u{1F38}', '\u{300}', '\u{1F3A}'),
+    ('\u{1F38}', '\u{301}', '\u{1F3C}'),
+    ('\u{1F38}', '\u{342}', '\u{1F3E}'),
+    ('\u{1F39}', '\u{300}', '\u{1F3B}'),
+    ('\u{1F39}', '\u{301}', '\u{1F3D}'),
+    ('\u{1F39}', '\u{342}', '\u{1F3F}'),
+    ('\u{1F40}', '\u{300}', '\u{1F42}'),
+    ('\u{1F40}', '\u{301}', '\u{1F44}'),
+    ('\u{1F41}', '\u{300}', '\u{1F43}'),
+    ('\u{1F41}', '\u{301}', '\u{1F45}'),
+    ('\u{1F48}', '\u{300}', '\u{1F4A}'),
+    ('\u{1F48}', '\u{301}', '\u{1F4C}'),
+    ('\u{1F49}', '\u{300}', '\u{1F4B}'),
+    ('\u{1F49}', '\u{301}', '\u{1F4D}'),
+    ('\u{1F50}', '\u{300}', '\u{1F52}'),
+    ('\u{1F50}', '\u{301}', '\u{1F54}'),
+    ('\u{1F50}', '\u{342}', '\u{1F56}'),
+    ('\u{1F51}', '\u{300}', '\u{1F53}'),
+    ('\u{1F51}', '\u{301}', '\u{1F55}'),
+    ('\u{1F51}', '\u{342}', '\u{1F57}'),
+    ('\u{1F59}', '\u{300}', '\u{1F5B}'),
+    ('\u{1F59}', '\u{301}', '\u{1F5D}'),
+    ('\u{1F59}', '\u{342}', '\u{1F5F}'),
+    ('\u{1F60}', '\u{300}', '\u{1F62}'),
+    ('\u{1F60}', '\u{301}', '\u{1F64}'),
+    ('\u{1F60}', '\u{342}', '\u{1F66}'),
+    ('\u{1F60}', '\u{345}', '\u{1FA0}'),
+    ('\u{1F61}', '\u{300}', '\u{1F63}'),
+    ('\u{1F61}', '\u{301}', '\u{1F65}'),
+    ('\u{1F61}', '\u{342}', '\u{1F67}'),
+    ('\u{1F61}', '\u{345}', '\u{1FA1}'),
+    ('\u{1F62}', '\u{345}', '\u{1FA2}'),
+    ('\u{1F63}', '\u{345}', '\u{1FA3}'),
+    ('\u{1F64}', '\u{345}', '\u{1FA4}'),
+    ('\u{1F65}', '\u{345}', '\u{1FA5}'),
+    ('\u{1F66}', '\u{345}', '\u{1FA6}'),
+    ('\u{1F67}', '\u{345}', '\u{1FA7}'),
+    ('\u{1F68}', '\u{300}', '\u{1F6A}'),
+    ('\u{1F68}', '\u{301}', '\u{1F6C}'),
+    ('\u{1F68}', '\u{342}', '\u{1F6E}'),
+    ('\u{1F68}', '\u{345}', '\u{1FA8}'),
+    ('\u{1F69}', '\u{300}', '\u{1F6B}'),
+    ('\u{1F69}', '\u{301}', '\u{1F6D}'),
+    ('\u{1F69}', '\u{342}', '\u{1F6F}'),
+    ('\u{1F69}', '\u{345}', '\u{1FA9}'),
+    ('\u{1F6A}', '\u{345}', '\u{1FAA}'),
+    ('\u{1F6B}', '\u{345}', '\u{1FAB}'),
+    ('\u{1F6C}', '\u{345}', '\u{1FAC}'),
+    ('\u{1F6D}', '\u{345}', '\u{1FAD}'),
+    ('\u{1F6E}', '\u{345}', '\u{1FAE}'),
+    ('\u{1F6F}', '\u{345}', '\u{1FAF}'),
+    ('\u{1F70}', '\u{345}', '\u{1FB2}'),
+    ('\u{1F74}', '\u{345}', '\u{1FC2}'),
+    ('\u{1F7C}', '\u{345}', '\u{1FF2}'),
+    ('\u{1FB6}', '\u{345}', '\u{1FB7}'),
+    ('\u{1FBF}', '\u{300}', '\u{1FCD}'),
+    ('\u{1FBF}', '\u{301}', '\u{1FCE}'),
+    ('\u{1FBF}', '\u{342}', '\u{1FCF}'),
+    ('\u{1FC6}', '\u{345}', '\u{1FC7}'),
+    ('\u{1FF6}', '\u{345}', '\u{1FF7}'),
+    ('\u{1FFE}', '\u{300}', '\u{1FDD}'),
+    ('\u{1FFE}', '\u{301}', '\u{1FDE}'),
+    ('\u{1FFE}', '\u{342}', '\u{1FDF}'),
+    ('\u{2190}', '\u{338}', '\u{219A}'),
+    ('\u{2192}', '\u{338}', '\u{219B}'),
+    ('\u{2194}', '\u{338}', '\u{21AE}'),
+    ('\u{21D0}', '\u{338}', '\u{21CD}'),
+    ('\u{21D2}', '\u{338}', '\u{21CF}'),
+    ('\u{21D4}', '\u{338}', '\u{21CE}'),
+    ('\u{2203}', '\u{338}', '\u{2204}'),
+    ('\u{2208}', '\u{338}', '\u{2209}'),
+    ('\u{220B}', '\u{338}', '\u{220C}'),
+    ('\u{2223}', '\u{338}', '\u{2224}'),
+    ('\u{2225}', '\u{338}', '\u{2226}'),
+    ('\u{223C}', '\u{338}', '\u{2241}'),
+    ('\u{2243}', '\u{338}', '\u{2244}'),
+    ('\u{2245}', '\u{338}', '\u{2247}'),
+    ('\u{2248}', '\u{338}', '\u{2249}'),
+    ('\u{224D}', '\u{338}', '\u{226D}'),
+    ('\u{2261}', '\u{338}', '\u{2262}'),
+    ('\u{2264}', '\u{338}', '\u{2270}'),
+    ('\u{2265}', '\u{338}', '\u{2271}'),
+    ('\u{2272}', '\u{338}', '\u{2274}'),
+    ('\u{2273}', '\u{338}', '\u{2275}'),
+    ('\u{2276}', '\u{338}', '\u{2278}'),
+    ('\u{2277}', '\u{338}', '\u{2279}'),
+    ('\u{227A}', '\u{338}', '\u{2280}'),
+    ('\u{227B}', '\u{338}', '\u{2281}'),
+    ('\u{227C}', '\u{338}', '\u{22E0}'),
+    ('\u{227D}', '\u{338}', '\u{22E1}'),
+    ('\u{2282}', '\u{338}', '\u{2284}'),
+    ('\u{2283}', '\u{338}', '\u{2285}'),
+    ('\u{2286}', '\u{338}', '\u{2288}'),
+    ('\u{2287}', '\u{338}', '\u{2289}'),
+    ('\u{2291}', '\u{338}', '\u{22E2}'),
+    ('\u{2292}', '\u{338}', '\u{22E3}'),
+    ('\u{22A2}', '\u{338}', '\u{22AC}'),
+    ('\u{22A8}', '\u{338}', '\u{22AD}'),
+    ('\u{22A9}', '\u{338}', '\u{22AE}'),
+    ('\u{22AB}', '\u{338}', '\u{22AF}'),
+    ('\u{22B2}', '\u{338}', '\u{22EA}'),
+    ('\u{22B3}', '\u{338}', '\u{22EB}'),
+    ('\u{22B4}', '\u{338}', '\u{22EC}'),
+    ('\u{22B5}', '\u{338}', '\u{22ED}'),
+    ('\u{3046}', '\u{3099}', '\u{3094}'),
+    ('\u{304B}', '\u{3099}', '\u{304C}'),
+    ('\u{304D}', '\u{3099}', '\u{304E}'),
+    ('\u{304F}', '\u{3099}', '\u{3050}'),
+    ('\u{3051}', '\u{3099}', '\u{3052}'),
+    ('\u{3053}', '\u{3099}', '\u{3054}'),
+    ('\u{3055}', '\u{3099}', '\u{3056}'),
+    ('\u{3057}', '\u{3099}', '\u{3058}'),
+    ('\u{3059}', '\u{3099}', '\u{305A}'),
+    ('\u{305B}', '\u{3099}', '\u{305C}'),
+    ('\u{305D}', '\u{3099}', '\u{305E}'),
+    ('\u{305F}', '\u{3099}', '\u{3060}'),
+    ('\u{3061}', '\u{3099}', '\u{3062}'),
+    ('\u{3064}', '\u{3099}', '\u{3065}'),
+    ('\u{3066}', '\u{3099}', '\u{3067}'),
+    ('\u{3068}', '\u{3099}', '\u{3069}'),
+    ('\u{306F}', '\u{3099}', '\u{3070}'),
+    ('\u{306F}', '\u{309A}', '\u{3071}'),
+    ('\u{3072}', '\u{3099}', '\u{3073}'),
+    ('\u{3072}', '\u{309A}', '\u{3074}'),
+    ('\u{3075}', '\u{3099}', '\u{3076}'),
+    ('\u{3075}', '\u{309A}', '\u{3077}'),
+    ('\u{3078}', '\u{3099}', '\u{3079}'),
+    ('\u{3078}', '\u{309A}', '\u{307A}'),
+    ('\u{307B}', '\u{3099}', '\u{307C}'),
+    ('\u{307B}', '\u{309A}', '\u{307D}'),
+    ('\u{309D}', '\u{3099}', '\u{309E}'),
+    ('\u{30A6}', '\u{3099}', '\u{30F4}'),
+    ('\u{30AB}', '\u{3099}', '\u{30AC}'),
+    ('\u{30AD}', '\u{3099}', '\u{30AE}'),
+    ('\u{30AF}', '\u{3099}', '\u{30B0}'),
+    ('\u{30B1}', '\u{3099}', '\u{30B2}'),
+    ('\u{30B3}', '\u{3099}', '\u{30B4}'),
+    ('\u{30B5}', '\u{3099}', '\u{30B6}'),
+    ('\u{30B7}', '\u{3099}', '\u{30B8}'),
+    ('\u{30B9}', '\u{3099}', '\u{30BA}'),
+    ('\u{30BB}', '\u{3099}', '\u{30BC}'),
+    ('\u{30BD}', '\u{3099}', '\u{30BE}'),
+    ('\u{30BF}', '\u{3099}', '\u{30C0}'),
+    ('\u{30C1}', '\u{3099}', '\u{30C2}'),
+    ('\u{30C4}', '\u{3099}', '\u{30C5}'),
+    ('\u{30C6}', '\u{3099}', '\u{30C7}'),
+    ('\u{30C8}', '\u{3099}', '\u{30C9}'),
+    ('\u{30CF}', '\u{3099}', '\u{30D0}'),
+    ('\u{30CF}', '\u{309A}', '\u{30D1}'),
+    ('\u{30D2}', '\u{3099}', '\u{30D3}'),
+    ('\u{30D2}', '\u{309A}', '\u{30D4}'),
+    ('\u{30D5}', '\u{3099}', '\u{30D6}'),
+    ('\u{30D5}', '\u{309A}', '\u{30D7}'),
+    ('\u{30D8}', '\u{3099}', '\u{30D9}'),
+    ('\u{30D8}', '\u{309A}', '\u{30DA}'),
+    ('\u{30DB}', '\u{3099}', '\u{30DC}'),
+    ('\u{30DB}', '\u{309A}', '\u{30DD}'),
+    ('\u{30EF}', '\u{3099}', '\u{30F7}'),
+    ('\u{30F0}', '\u{3099}', '\u{30F8}'),
+    ('\u{30F1}', '\u{3099}', '\u{30F9}'),
+    ('\u{30F2}', '\u{3099}', '\u{30FA}'),
+    ('\u{30FD}', '\u{3099}', '\u{30FE}'),
+    ('\u{11099}', '\u{110BA}', '\u{1109A}'),
+    ('\u{1109B}', '\u{110BA}', '\u{1109C}'),
+    ('\u{110A5}', '\u{110BA}', '\u{110AB}'),
+    ('\u{11131}', '\u{11127}', '\u{1112E}'),
+    ('\u{11132}', '\u{11127}', '\u{1112F}'),
+    ('\u{11347}', '\u{1133E}', '\u{1134B}'),
+    ('\u{11347}', '\u{11357}', '\u{1134C}'),
+    ('\u{114B9}', '\u{114B0}', '\u{114BC}'),
+    ('\u{114B9}', '\u{114BA}', '\u{114BB}'),
+    ('\u{114B9}', '\u{114BD}', '\u{114BE}'),
+    ('\u{115B8}', '\u{115AF}', '\u{115BA}'),
+    ('\u{115B9}', '\u{115AF}', '\u{115BB}'),
+    ('\u{11935}', '\u{11930}', '\u{11938}'),
+];
+
+/// Ranges of code points with a non-zero canonical combining class.
+pub(super) static COMBINING_CLASS: &[(char, char, u8)] = &[
+    ('\u{300}', '\u{314}', 230),
+    ('\u{315}', '\u{315}', 232),
+    ('\u{316}', '\u{319}', 220),
+    ('\u{31A}', '\u{31A}', 232),
+    ('\u{31B}', '\u{31B}', 216),
+    ('\u{31C}', '\u{320}', 220),
+    ('\u{321}', '\u{322}', 202),
+    ('\u{323}', '\u{326}', 220),
+    ('\u{327}', '\u{328}', 202),
+    ('\u{329}', '\u{333}', 220),
+    ('\u{334}', '\u{338}', 1),
+    ('\u{339}', '\u{33C}', 220),
+    ('\u{33D}', '\u{344}', 230),
+    ('\u{345}', '\u{345}', 240),
+    ('\u{346}', '\u{346}', 230),
+    ('\u{347}', '\u{349}', 220),
+    ('\u{34A}', '\u{34C}', 230),
+    ('\u{34D}', '\u{34E}', 220),
+    ('\u{350}', '\u{352}', 230),
+    ('\u{353}', '\u{356}', 220),
+    ('\u{357}', '\u{357}', 230),
+    ('\u{358}', '\u{358}', 232),
+    ('\u{359}', '\u{35A}', 220),
+    ('\u{35B}', '\u{35B}', 230),
+    ('\u{35C}', '\u{35C}', 233),
+    ('\u{35D}', '\u{35E}', 234),
+    ('\u{35F}', '\u{35F}', 233),
+    ('\u{360}', '\u{361}', 234),
+    ('\u{362}', '\u{362}', 233),
+    ('\u{363}', '\u{36F}', 230),
+    ('\u{483}', '\u{487}', 230),
+    ('\u{591}', '\u{591}', 220),
+    ('\u{592}', '\u{595}', 230),
+    ('\u{596}', '\u{596}', 220),
+    ('\u{597}', '\u{599}', 230),
+    ('\u{59A}', '\u{59A}', 222),
+    ('\u{59B}', '\u{59B}', 220),
+    ('\u{59C}', '\u{5A1}', 230),
+    ('\u{5A2}', '\u{5A7}', 220),
+    ('\u{5A8}', '\u{5A9}', 230),
+    ('\u{5AA}', '\u{5AA}', 220),
+    ('\u{5AB}', '\u{5AC}', 230),
+    ('\u{5AD}', '\u{5AD}', 222),
+    ('\u{5AE}', '\u{5AE}', 228),
+    ('\u{5AF}', '\u{5AF}', 230),
+    ('\u{5B0}', '\u{5B0}', 10),
+    ('\u{5B1}', '\u{5B1}', 11),
+    ('\u{5B2}', '\u{5B2}', 12),
+    ('\u{5B3}', '\u{5B3}', 13),
+    ('\u{5B4}', '\u{5B4}', 14),
+    ('\u{5B5}', '\u{5B5}', 15),
+    ('\u{5B6}', '\u{5B6}', 16),
+    ('\u{5B7}', '\u{5B7}', 17),
+    ('\u{5B8}', '\u{5B8}', 18),
+    ('\u{5B9}', '\u{5BA}', 19),
+    ('\u{5BB}', '\u{5BB}', 20),
+    ('\u{5BC}', '\u{5BC}', 21),
+    ('\u{5BD}', '\u{5BD}', 22),
+    ('\u{5BF}', '\u{5BF}', 23),
+    ('\u{5C1}', '\u{5C1}', 24),
+    ('\u{5C2}', '\u{5C2}', 25),
+    ('\u{5C4}', '\u{5C4}', 230),
+    ('\u{5C5}', '\u{5C5}', 220),
+    ('\u{5C7}', '\u{5C7}', 18),
+    ('\u{610}', '\u{617}', 230),
+    ('\u{618}', '\u{618}', 30),
+    ('\u{619}', '\u{619}', 31),
+    ('\u{61A}', '\u{61A}', 32),
+    ('\u{64B}', '\u{64B}', 27),
+    ('\u{64C}', '\u{64C}', 28),
+    ('\u{64D}', '\u{64D}', 29),
+    ('\u{64E}', '\u{64E}', 30),
+    ('\u{64F}', '\u{64F}', 31),
+    ('\u{650}', '\u{650}', 32),
+    ('\u{651}', '\u{651}', 33),
+    ('\u{652}', '\u{652}', 34),
+    ('\u{653}', '\u{654}', 230),
+    ('\u{655}', '\u{656}', 220),
+    ('\u{657}', '\u{65B}', 230),
+    ('\u{65C}', '\u{65C}', 220),
+    ('\u{65D}', '\u{65E}', 230),
+    ('\u{65F}', '\u{65F}', 220),
+    ('\u{670}', '\u{670}', 35),
+    ('\u{6D6}', '\u{6DC}', 230),
+    ('\u{6DF}', '\u{6E2}', 230),
+    ('\u{6E3}', '\u{6E3}', 220),
+    ('\u{6E4}', '\u{6E4}', 230),
+    ('\u{6E7}', '\u{6E8}', 230),
+    ('\u{6EA}', '\u{6EA}', 220),
+    ('\u{6EB}', '\u{6EC}', 230),
+    ('\u{6ED}', '\u{6ED}', 220),
+    ('\u{711}', '\u{711}', 36),
+    ('\u{730}', '\u{730}', 230),
+    ('\u{731}', '\u{731}', 220),
+    ('\u{732}', '\u{733}', 230),
+    ('\u{734}', '\u{734}', 220),
+    ('\u{735}', '\u{736}', 230),
+    ('\u{737}', '\u{739}', 220),
+    ('\u{73A}', '\u{73A}', 230),
+    ('\u{73B}', '\u{73C}', 220),
+    ('\u{73D}', '\u{73D}', 230),
+    ('\u{73E}', '\u{73E}', 220),
+    ('\u{73F}', '\u{741}', 230),
+    ('\u{742}', '\u{742}', 220),
+    ('\u{743}', '\u{743}', 230),
+    ('\u{744}', '\u{744}', 220),
+    ('\u{745}', '\u{745}', 230),
+    ('\u{746}', '\u{746}', 220),
+    ('\u{747}', '\u{747}', 230),
+    ('\u{748}', '\u{748}', 220),
+    ('\u{749}', '\u{74A}', 230),
+    ('\u{7EB}', '\u{7F1}', 230),
+    ('\u{7F2}', '\u{7F2}', 220),
+    ('\u{7F3}', '\u{7F3}', 230),
+    ('\u{7FD}', '\u{7FD}', 220),
+    ('\u{816}', '\u{819}', 230),
+    ('\u{81B}', '\u{823}', 230),
+    ('\u{825}', '\u{827}', 230),
+    ('\u{829}', '\u{82D}', 230),
+    ('\u{859}', '\u{85B}', 220),
+    ('\u{898}', '\u{898}', 230),
+    ('\u{899}', '\u{89B}', 220),
+    ('\u{89C}', '\u{89F}', 230),
+    ('\u{8CA}', '\u{8CE}', 230),
+    ('\u{8CF}', '\u{8D3}', 220),
+    ('\u{8D4}', '\u{8E1}', 230),
+    ('\u{8E3}', '\u{8E3}', 220),
+    ('\u{8E4}', '\u{8E5}', 230),
+    ('\u{8E6}', '\u{8E6}', 220),
+    ('\u{8E7}', '\u{8E8}', 230),
+    ('\u{8E9}', '\u{8E9}', 220),
+    ('\u{8EA}', '\u{8EC}', 230),
+    ('\u{8ED}', '\u{8EF}', 220),
+    ('\u{8F0}', '\u{8F0}', 27),
+    ('\u{8F1}', '\u{8F1}', 28),
+    ('\u{8F2}', '\u{8F2}', 29),
+    ('\u{8F3}', '\u{8F5}', 230),
+    ('\u{8F6}', '\u{8F6}', 220),
+    ('\u{8F7}', '\u{8F8}', 230),
+    ('\u{8F9}', '\u{8FA}', 220),
+    ('\u{8FB}', '\u{8FF}', 230),
+    ('\u{93C}', '\u{93C}', 7),
+    ('\u{94D}', '\u{94D}', 9),
+    ('\u{951}', '\u{951}', 230),
+    ('\u{952}', '\u{952}', 220),
+    ('\u{953}', '\u{954}', 230),
+    ('\u{9BC}', '\u{9BC}', 7),
+    ('\u{9CD}', '\u{9CD}', 9),
+    ('\u{9FE}', '\u{9FE}', 230),
+    ('\u{A3C}', '\u{A3C}', 7),
+    ('\u{A4D}', '\u{A4D}', 9),
+    ('\u{ABC}', '\u{ABC}', 7),
+    ('\u{ACD}', '\u{ACD}', 9),
+    ('\u{B3C}', '\u{B3C}', 7),
+    ('\u{B4D}', '\u{B4D}', 9),
+    ('\u{BCD}', '\u{BCD}', 9),
+    ('\u{C3C}', '\u{C3C}', 7),
+    ('\u{C4D}', '\u{C4D}', 9),
+    ('\u{C55}', '\u{C55}', 84),
+    ('\u{C56}', '\u{C56}', 91),
+    ('\u{CBC}', '\u{CBC}', 7),
+    ('\u{CCD}', '\u{CCD}', 9),
+    ('\u{D3B}', '\u{D3C}', 9),
+    ('\u{D4D}', '\u{D4D}', 9),
+    ('\u{DCA}', '\u{DCA}', 9),
+    ('\u{E38}', '\u{E39}', 103),
+    ('\u{E3A}', '\u{E3A}', 9),
+    ('\u{E48}', '\u{E4B}', 107),
+    ('\u{EB8}', '\u{EB9}', 118),
+    ('\u{EBA}', '\u{EBA}', 9),
+    ('\u{EC8}', '\u{ECB}', 122),
+    ('\u{F18}', '\u{F19}', 220),
+    ('\u{F35}', '\u{F35}', 220),
+    ('\u{F37}', '\u{F37}', 220),
+    ('\u{F39}', '\u{F39}', 216),
+    ('\u{F71}', '\u{F71}', 129),
+    ('\u{F72}', '\u{F72}', 130),
+    ('\u{F74}', '\u{F74}', 132),
+    ('\u{F7A}', '\u{F7D}', 130),
+    ('\u{F80}', '\u{F80}', 130),
+    ('\u{F82}', '\u{F83}', 230),
+    ('\u{F84}', '\u{F84}', 9),
+    ('\u{F86}', '\u{F87}', 230),
+    ('\u{FC6}', '\u{FC6}', 220),
+    ('\u{1037}', '\u{1037}', 7),
+    ('\u{1039}', '\u{103A}', 9),
+    ('\u{108D}', '\u{108D}', 220),
+    ('\u{135D}', '\u{135F}', 230),
+    ('\u{1714}', '\u{1715}', 9),
+    ('\u{1734}', '\u{1734}', 9),
+    ('\u{17D2}', '\u{17D2}', 9),
+    ('\u{17DD}', '\u{17DD}', 230),
+    ('\u{18A9}', '\u{18A9}', 228),
+    ('\u{1939}', '\u{1939}', 222),
+    ('\u{193A}', '\u{193A}', 230),
+    ('\u{193B}', '\u{193B}', 220),
+    ('\u{1A17}', '\u{1A17}', 230),
+    ('\u{1A18}', '\u{1A18}', 220),
+    ('\u{1A60}', '\u{1A60}', 9),
+    ('\u{1A75}', '\u{1A7C}', 230),
+    ('\u{1A7F}', '\u{1A7F}', 220),
+    ('\u{1AB0}', '\u{1AB4}', 230),
+    ('\u{1AB5}', '\u{1ABA}', 220),
+    ('\u{1ABB}', '\u{1ABC}', 230),
+    ('\u{1ABD}', '\u{1ABD}', 220),
+    ('\u{1ABF}', '\u{1AC0}', 220),
+    ('\u{1AC1}', '\u{1AC2}', 230),
+    ('\u{1AC3}', '\u{1AC4}', 220),
+    ('\u{1AC5}', '\u{1AC9}', 230),
+    ('\u{1ACA}', '\u{1ACA}', 220),
+    ('\u{1ACB}', '\u{1ACE}', 230),
+    ('\u{1B34}', '\u{1B34}', 7),
+    ('\u{1B44}', '\u{1B44}', 9),
+    ('\u{1B6B}', '\u{1B6B}', 230),
+    ('\u{1B6C}', '\u{1B6C}', 220),
+    ('\u{1B6D}', '\u{1B73}', 230),
+    ('\u{1BAA}', '\u{1BAB}', 9),
+    ('\u{1BE6}', '\u{1BE6}', 7),
+    ('\u{1BF2}', '\u{1BF3}', 9),
+    ('\u{1C37}', '\u{1C37}', 7),
+    ('\u{1CD0}', '\u{1CD2}', 230),
+    ('\u{1CD4}', '\u{1CD4}', 1),
+    ('\u{1CD5}', '\u{1CD9}', 220),
+    ('\u{1CDA}', '\u{1CDB}', 230),
+    ('\u{1CDC}', '\u{1CDF}', 220),
+    ('\u{1CE0}', '\u{1CE0}', 230),
+    ('\u{1CE2}', '\u{1CE8}', 1),
+    ('\u{1CED}', '\u{1CED}', 220),
+    ('\u{1CF4}', '\u{1CF4}', 230),
+    ('\u{1CF8}', '\u{1CF9}', 230),
+    ('\u{1DC0}', '\u{1DC1}', 230),
+    ('\u{1DC2}', '\u{1DC2}', 220),
+    ('\u{1DC3}', '\u{1DC9}', 230),
+    ('\u{1DCA}', '\u{1DCA}', 220),
+    ('\u{1DCB}', '\u{1DCC}', 230),
+    ('\u{1DCD}', '\u{1DCD}', 234),
+    ('\u{1DCE}', '\u{1DCE}', 214),
+    ('\u{1DCF}', '\u{1DCF}', 220),
+    ('\u{1DD0}', '\u{1DD0}', 202),
+    ('\u{1DD1}', '\u{1DF5}', 230),
+    ('\u{1DF6}', '\u{1DF6}', 232),
+    ('\u{1DF7}', '\u{1DF8}', 228),
+    ('\u{1DF9}', '\u{1DF9}', 220),
+    ('\u{1DFA}', '\u{1DFA}', 218),
+    ('\u{1DFB}', '\u{1DFB}', 230),
+    ('\u{1DFC}', '\u{1DFC}', 233),
+    ('\u{1DFD}', '\u{1DFD}', 220),
+    ('\u{1DFE}', '\u{1DFE}', 230),
+    ('\u{1DFF}', '\u{1DFF}', 220),
+    ('\u{20D0}', '\u{20D1}', 230),
+    ('\u{20D2}', '\u{20D3}', 1),
+    ('\u{20D4}', '\u{20D7}', 230),
+    ('\u{20D8}', '\u{20DA}', 1),
+    ('\u{20DB}', '\u{20DC}', 230),
+    ('\u{20E1}', '\u{20E1}', 230),
+    ('\u{20E5}', '\u{20E6}', 1),
+    ('\u{20E7}', '\u{20E7}', 230),
+    ('\u{20E8}', '\u{20E8}', 220),
+    ('\u{20E9}', '\u{20E9}', 230),
+    ('\u{20EA}', '\u{20EB}', 1),
+    ('\u{20EC}', '\u{20EF}', 220),
+    ('\u{20F0}', '\u{20F0}', 230),
+    ('\u{2CEF}', '\u{2CF1}', 230),
+    ('\u{2D7F}', '\u{2D7F}', 9),
+    ('\u{2DE0}', '\u{2DFF}', 230),
+    ('\u{302A}', '\u{302A}', 218),
+    ('\u{302B}', '\u{302B}', 228),
+    ('\u{302C}', '\u{302C}', 232),
+    ('\u{302D}', '\u{302D}', 222),
+    ('\u{302E}', '\u{302F}', 224),
+    ('\u{3099}', '\u{309A}', 8),
+    ('\u{A66F}', '\u{A66F}', 230),
+    ('\u{A674}', '\u{A67D}', 230),
+    ('\u{A69E}', '\u{A69F}', 230),
+    ('\u{A6F0}', '\u{A6F1}', 230),
+    ('\u{A806}', '\u{A806}', 9),
+    ('\u{A82C}', '\u{A82C}', 9),
+    ('\u{A8C4}', '\u{A8C4}', 9),
+    ('\u{A8E0}', '\u{A8F1}', 230),
+    ('\u{A92B}', '\u{A92D}', 220),
+    ('\u{A953}', '\u{A953}', 9),
+    ('\u{A9B3}', '\u{A9B3}', 7),
+    ('\u{A9C0}', '\u{A9C0}', 9),
+    ('\u{AAB0}', '\u{AAB0}', 230),
+    ('\u{AAB2}', '\u{AAB3}', 230),
+    ('\u{AAB4}', '\u{AAB4}', 220),
+    ('\u{AAB7}', '\u{AAB8}', 230),
+    ('\u{AABE}', '\u{AABF}', 230),
+    ('\u{AAC1}', '\u{AAC1}', 230),
+    ('\u{AAF6}', '\u{AAF6}', 9),
+    ('\u{ABED}', '\u{ABED}', 9),
+    ('\u{FB1E}', '\u{FB1E}', 26),
+    ('\u{FE20}', '\u{FE26}', 230),
+    ('\u{FE27}', '\u{FE2D}', 220),
+    ('\u{FE2E}', '\u{FE2F}', 230),
+    ('\u{101FD}', '\u{101FD}', 220),
+    ('\u{102E0}', '\u{102E0}', 220),
+    ('\u{10376}', '\u{1037A}', 230),
+    ('\u{10A0D}', '\u{10A0D}', 220),
+    ('\u{10A0F}', '\u{10A0F}', 230),
+    ('\u{10A38}', '\u{10A38}', 230),
+    ('\u{10A39}', '\u{10A39}', 1),
+    ('\u{10A3A}', '\u{10A3A}', 220),
+    ('\u{10A3F}', '\u{10A3F}', 9),
+    ('\u{10AE5}', '\u{10AE5}', 230),
+    ('\u{10AE6}', '\u{10AE6}', 220),
+    ('\u{10D24}', '\u{10D27}', 230),
+    ('\u{10EAB}', '\u{10EAC}', 230),
+    ('\u{10EFD}', '\u{10EFF}', 220),
+    ('\u{10F46}', '\u{10F47}', 220),
+    ('\u{10F48}', '\u{10F4A}', 230),
+    ('\u{10F4B}', '\u{10F4B}', 220),
+    ('\u{10F4C}', '\u{10F4C}', 230),
+    ('\u{10F4D}', '\u{10F50}', 220),
+    ('\u{10F82}', '\u{10F82}', 230),
+    ('\u{10F83}', '\u{10F83}', 220),
+    ('\u{10F84}', '\u{10F84}', 230),
+    ('\u{10F85}', '\u{10F85}', 220),
+    ('\u{11046}', '\u{11046}', 9),
+    ('\u{11070}', '\u{11070}', 9),
+    ('\u{1107F}', '\u{1107F}', 9),
+    ('\u{110B9}', '\u{110B9}', 9),
+    ('\u{110BA}', '\u{110BA}', 7),
+    ('\u{11100}', '\u{11102}', 230),
+    ('\u{11133}', '\u{11134}', 9),
+    ('\u{11173}', '\u{11173}', 7),
+    ('\u{111C0}', '\u{111C0}', 9),
+    ('\u{111CA}', '\u{111CA}', 7),
+    ('\u{11235}', '\u{11235}', 9),
+    ('\u{11236}', '\u{11236}', 7),
+    ('\u{112E9}', '\u{112E9}', 7),
+    ('\u{112EA}', '\u{112EA}', 9),
+    ('\u{1133B}', '\u{1133C}', 7),
+    ('\u{1134D}', '\u{1134D}', 9),
+    ('\u{11366}', '\u{1136C}', 230),
+    ('\u{11370}', '\u{11374}', 230),
+    ('\u{11442}', '\u{11442}', 9),
+    ('\u{11446}', '\u{11446}', 7),
+    ('\u{1145E}', '\u{1145E}', 230),
+    ('\u{114C2}', '\u{114C2}', 9),
+    ('\u{114C3}', '\u{114C3}', 7),
+    ('\u{115BF}', '\u{115BF}', 9),
+    ('\u{115C0}', '\u{115C0}', 7),
+    ('\u{1163F}', '\u{1163F}', 9),
+    ('\u{116B6}', '\u{116B6}', 9),
+    ('\u{116B7}', '\u{116B7}', 7),
+    ('\u{1172B}', '\u{1172B}', 9),
+    ('\u{11839}', '\u{11839}', 9),
+    ('\u{1183A}', '\u{1183A}', 7),
+    ('\u{1193D}', '\u{1193E}', 9),
+    ('\u{11943}', '\u{11943}', 7),
+    ('\u{119E0}', '\u{119E0}', 9),
+    ('\u{11A34}', '\u{11A34}', 9),
+    ('\u{11A47}', '\u{11A47}', 9),
+    ('\u{11A99}', '\u{11A99}', 9),
+    ('\u{11C3F}', '\u{11C3F}', 9),
+    ('\u{11D42}', '\u{11D42}', 7),
+    ('\u{11D44}', '\u{11D45}', 9),
+    ('\u{11D97}', '\u{11D97}', 9),
+    ('\u{11F41}', '\u{11F42}', 9),
+    ('\u{16AF0}', '\u{16AF4}', 1),
+    ('\u{16B30}', '\u{16B36}', 230),
+    ('\u{16FF0}', '\u{16FF1}', 6),
+    ('\u{1BC9E}', '\u{1BC9E}', 1),
+    ('\u{1D165}', '\u{1D166}', 216),
+    ('\u{1D167}', '\u{1D169}', 1),
+    ('\u{1D16D}', '\u{1D16D}', 226),
+    ('\u{1D16E}', '\u{1D172}', 216),
+    ('\u{1D17B}', '\u{1D182}', 220),
+    ('\u{1D185}', '\u{1D189}', 230),
+    ('\u{1D18A}', '\u{1D18B}', 220),
+    ('\u{1D1AA}', '\u{1D1AD}', 230),
+    ('\u{1D242}', '\u{1D244}', 230),
+    ('\u{1E000}', '\u{1E006}', 230),
+    ('\u{1E008}', '\u{1E018}', 230),
+    ('\u{1E01B}', '\u{1E021}', 230),
+    ('\u{1E023}', '\u{1E024}', 230),
+    ('\u{1E026}', '\u{1E02A}', 230),
+    ('\u{1E08F}', '\u{1E08F}', 230),
+    ('\u{1E130}', '\u{1E136}', 230),
+    ('\u{1E2AE}', '\u{1E2AE}', 230),
+    ('\u{1E2EC}', '\u{1E2EF}', 230),
+    ('\u{1E4EC}', '\u{1E4ED}', 232),
+    ('\u{1E4EE}', '\u{1E4EE}', 220),
+    ('\u{1E4EF}', '\u{1E4EF}', 230),
+    ('\u{1E8D0}', '\u{1E8D6}', 220),
+    ('\u{1E944}', '\u{1E949}', 230),
+    ('\u{1E94A}', '\u{1E94A}', 7),
+];
+
+/// Ranges of code points with the general category `Mark`.
+pub(super) static MARK: &[(char, char, ())] = &[
+    ('\u{300}', '\u{36F}', ()),
+    ('\u{483}', '\u{489}', ()),
+    ('\u{591}', '\u{5BD}', ()),
+    ('\u{5BF}', '\u{5BF}', ()),
+    ('\u{5C1}', '\u{5C2}', ()),
+    ('\u{5C4}', '\u{5C5}', ()),
+    ('\u{5C7}', '\u{5C7}', ()),
+    ('\u{610}', '\u{61A}', ()),
+    ('\u{64B}', '\u{65F}', ()),
+    ('\u{670}', '\u{670}', ()),
+    ('\u{6D6}', '\u{6DC}', ()),
+    ('\u{6DF}', '\u{6E4}', ()),
+    ('\u{6E7}', '\u{6E8}', ()),
+    ('\u{6EA}', '\u{6ED}', ()),
+    ('\u{711}', '\u{711}', ()),
+    ('\u{730}', '\u{74A}', ()),
+    ('\u{7A6}', '\u{7B0}', ()),
+    ('\u{7EB}', '\u{7F3}', ()),
+    ('\u{7FD}', '\u{7FD}', ()),
+    ('\u{816}', '\u{819}', ()),
+    ('\u{81B}', '\u{823}', ()),
+    ('\u{825}', '\u{827}', ()),
+    ('\u{829}', '\u{82D}', ()),
+    ('\u{859}', '\u{85B}', ()),
+    ('\u{898}', '\u{89F}', ()),
+    ('\u{8CA}', '\u{8E1}', ()),
+    ('\u{8E3}', '\u{903}', ()),
+    ('\u{93A}', '\u{93C}', ()),
+    ('\u{93E}', '\u{94F}', ()),
+    ('\u{951}', '\u{957}', ()),
+    ('\u{962}', '\u{963}', ()),
+    ('\u{981}', '\u{983}', ()),
+    ('\u{9BC}', '\u{9BC}', ()),
+    ('\u{9BE}', '\u{9C4}', ()),
+    ('\u{9C7}', '\u{9C8}', ()),
+    ('\u{9CB}', '\u{9CD}', ()),
+    ('\u{9D7}', '\u{9D7}', ()),
+    ('\u{9E2}', '\u{9E3}', ()),
+    ('\u{9FE}', '\u{9FE}', ()),
+    ('\u{A01}', '\u{A03}', ()),
+    ('\u{A3C}', '\u{A3C}', ()),
+    ('\u{A3E}', '\u{A42}', ()),
+    ('\u{A47}', '\u{A48}', ()),
+    ('\u{A4B}', '\u{A4D}', ()),
+    ('\u{A51}', '\u{A51}', ()),
+    ('\u{A70}', '\u{A71}', ()),
+    ('\u{A75}', '\u{A75}', ()),
+    ('\u{A81}', '\u{A83}', ()),
+    ('\u{ABC}', '\u{ABC}', ()),
+    ('\u{ABE}', '\u{AC5}', ()),
+    ('\u{AC7}', '\u{AC9}', ()),
+    ('\u{ACB}', '\u{ACD}', ()),
+    ('\u{AE2}', '\u{AE3}', ()),
+    ('\u{AFA}', '\u{AFF}', ()),
+    ('\u{B01}', '\u{B03}', ()),
+    ('\u{B3C}', '\u{B3C}', ()),
+    ('\u{B3E}', '\u{B44}', ()),
+    ('\u{B47}', '\u{B48}', ()),
+    ('\u{B4B}', '\u{B4D}', ()),
+    ('\u{B55}', '\u{B57}', ()),
+    ('\u{B62}', '\u{B63}', ()),
+    ('\u{B82}', '\u{B82}', ()),
+    ('\u{BBE}', '\u{BC2}', ()),
+    ('\u{BC6}', '\u{BC8}', ()),
+    ('\u{BCA}', '\u{BCD}', ()),
+    ('\u{BD7}', '\u{BD7}', ()),
+    ('\u{C00}', '\u{C04}', ()),
+    ('\u{C3C}', '\u{C3C}', ()),
+    ('\u{C3E}', '\u{C44}', ()),
+    ('\u{C46}', '\u{C48}', ()),
+    ('\u{C4A}', '\u{C4D}', ()),
+    ('\u{C55}', '\u{C56}', ()),
+    ('\u{C62}', '\u{C63}', ()),
+    ('\u{C81}', '\u{C83}', ()),
+    ('\u{CBC}', '\u{CBC}', ()),
+    ('\u{CBE}', '\u{CC4}', ()),
+    ('\u{CC6}', '\u{CC8}', ()),
+    ('\u{CCA}', '\u{CCD}', ()),
+    ('\u{CD5}', '\u{CD6}', ()),
+    ('\u{CE2}', '\u{CE3}', ()),
+    ('\u{CF3}', '\u{CF3}', ()),
+    ('\u{D00}', '\u{D03}', ()),
+    ('\u{D3B}', '\u{D3C}', ()),
+    ('\u{D3E}', '\u{D44}', ()),
+    ('\u{D46}', '\u{D48}', ()),
+    ('\u{D4A}', '\u{D4D}', ()),
+    ('\u{D57}', '\u{D57}', ()),
+    ('\u{D62}', '\u{D63}', ()),
+    ('\u{D81}', '\u{D83}', ()),
+    ('\u{DCA}', '\u{DCA}', ()),
+    ('\u{DCF}', '\u{DD4}', ()),
+    ('\u{DD6}', '\u{DD6}', ()),
+    ('\u{DD8}', '\u{DDF}', ()),
+    ('\u{DF2}', '\u{DF3}', ()),
+    ('\u{E31}', '\u{E31}', ()),
+    ('\u{E34}', '\u{E3A}', ()),
+    ('\u{E47}', '\u{E4E}', ()),
+    ('\u{EB1}', '\u{EB1}', ()),
+    ('\u{EB4}', '\u{EBC}', ()),
+    ('\u{EC8}', '\u{ECE}', ()),
+    ('\u{F18}', '\u{F19}', ()),
+    ('\u{F35}', '\u{F35}', ()),
+    ('\u{F37}', '\u{F37}', ()),
+    ('\u{F39}', '\u{F39}', ()),
+    ('\u{F3E}', '\u{F3F}', ()),
+    ('\u{F71}', '\u{F84}', ()),
+    ('\u{F86}', '\u{F87}', ()),
+    ('\u{F8D}', '\u{F97}', ()),
+    ('\u{F99}', '\u{FBC}', ()),
+    ('\u{FC6}', '\u{FC6}', ()),
+    ('\u{102B}', '\u{103E}', ()),
+    ('\u{1056}', '\u{1059}', ()),
+    ('\u{105E}', '\u{1060}', ()),
+    ('\u{1062}', '\u{1064}', ()),
+    ('\u{1067}', '\u{106D}', ()),
+    ('\u{1071}', '\u{1074}', ()),
+    ('\u{1082}', '\u{108D}', ()),
+    ('\u{108F}', '\u{108F}', ()),
+    ('\u{109A}', '\u{109D}', ()),
+    ('\u{135D}', '\u{135F}', ()),
+    ('\u{1712}', '\u{1715}', ()),
+    ('\u{1732}', '\u{1734}', ()),
+    ('\u{1752}', '\u{1753}', ()),
+    ('\u{1772}', '\u{1773}', ()),
+    ('\u{17B4}', '\u{17D3}', ()),
+    ('\u{17DD}', '\u{17DD}', ()),
+    ('\u{180B}', '\u{180D}', ()),
+    ('\u{180F}', '\u{180F}', ()),
+    ('\u{1885}', '\u{1886}', ()),
+    ('\u{18A9}', '\u{18A9}', ()),
+    ('\u{1920}', '\u{192B}', ()),
+    ('\u{1930}', '\u{193B}', ()),
+    ('\u{1A17}', '\u{1A1B}', ()),
+    ('\u{1A55}', '\u{1A5E}', ()),
+    ('\u{1A60}', '\u{1A7C}', ()),
+    ('\u{1A7F}', '\u{1A7F}', ()),
+    ('\u{1AB0}', '\u{1ACE}', ()),
+    ('\u{1B00}', '\u{1B04}', ()),
+    ('\u{1B34}', '\u{1B44}', ()),
+    ('\u{1B6B}', '\u{1B73}', ()),
+    ('\u{1B80}', '\u{1B82}', ()),
+    ('\u{1BA1}', '\u{1BAD}', ()),
+    ('\u{1BE6}', '\u{1BF3}', ()),
+    ('\u{1C24}', '\u{1C37}', ()),
+    ('\u{1CD0}', '\u{1CD2}', ()),
+    ('\u{1CD4}', '\u{1CE8}', ()),
+    ('\u{1CED}', '\u{1CED}', ()),
+    ('\u{1CF4}', '\u{1CF4}', ()),
+    ('\u{1CF7}', '\u{1CF9}', ()),
+    ('\u{1DC0}', '\u{1DFF}', ()),
+    ('\u{20D0}', '\u{20F0}', ()),
+    ('\u{2CEF}', '\u{2CF1}', ()),
+    ('\u{2D7F}', '\u{2D7F}', ()),
+    ('\u{2DE0}', '\u{2DFF}', ()),
+    ('\u{302A}', '\u{302F}', ()),
+    ('\u{3099}', '\u{309A}', ()),
+    ('\u{A66F}', '\u{A672}', ()),
+    ('\u{A674}', '\u{A67D}', ()),
+    ('\u{A69E}', '\u{A69F}', ()),
+    ('\u{A6F0}', '\u{A6F1}', ()),
+    ('\u{A802}', '\u{A802}', ()),
+    ('\u{A806}', '\u{A806}', ()),
+    ('\u{A80B}', '\u{A80B}', ()),
+    ('\u{A823}', '\u{A827}', ()),
+    ('\u{A82C}', '\u{A82C}', ()),
+    ('\u{A880}', '\u{A881}', ()),
+    ('\u{A8B4}', '\u{A8C5}', ()),
+    ('\u{A8E0}', '\u{A8F1}', ()),
+    ('\u{A8FF}', '\u{A8FF}', ()),
+    ('\u{A926}', '\u{A92D}', ()),
+    ('\u{A947}', '\u{A953}', ()),
+    ('\u{A980}', '\u{A983}', ()),
+    ('\u{A9B3}', '\u{A9C0}', ()),
+    ('\u{A9E5}', '\u{A9E5}', ()),
+    ('\u{AA29}', '\u{AA36}', ()),
+    ('\u{AA43}', '\u{AA43}', ()),
+    ('\u{AA4C}', '\u{AA4D}', ()),
+    ('\u{AA7B}', '\u{AA7D}', ()),
+    ('\u{AAB0}', '\u{AAB0}', ()),
+    ('\u{AAB2}', '\u{AAB4}', ()),
+    ('\u{AAB7}', '\u{AAB8}', ()),
+    ('\u{AABE}', '\u{AABF}', ()),
+    ('\u{AAC1}', '\u{AAC1}', ()),
+    ('\u{AAEB}', '\u{AAEF}', ()),
+    ('\u{AAF5}', '\u{AAF6}', ()),
+    ('\u{ABE3}', '\u{ABEA}', ()),
+    ('\u{ABEC}', '\u{ABED}', ()),
+    ('\u{FB1E}', '\u{FB1E}', ()),
+    ('\u{FE00}', '\u{FE0F}', ()),
+    ('\u{FE20}', '\u{FE2F}', ()),
+    ('\u{101FD}', '\u{101FD}', ()),
+    ('\u{102E0}', '\u{102E0}', ()),
+    ('\u{10376}', '\u{1037A}', ()),
+    ('\u{10A01}', '\u{10A03}', ()),
+    ('\u{10A05}', '\u{10A06}', ()),
+    ('\u{10A0C}', '\u{10A0F}', ()),
+    ('\u{10A38}', '\u{10A3A}', ()),
+    ('\u{10A3F}', '\u{10A3F}', ()),
+    ('\u{10AE5}', '\u{10AE6}', ()),
+    ('\u{10D24}', '\u{10D27}', ()),
+    ('\u{10EAB}', '\u{10EAC}', ()),
+    ('\u{10EFD}', '\u{10EFF}', ()),
+    ('\u{10F46}', '\u{10F50}', ()),
+    ('\u{10F82}', '\u{10F85}', ()),
+    ('\u{11000}', '\u{11002}', ()),
+    ('\u{11038}', '\u{11046}', ()),
+    ('\u{11070}', '\u{11070}', ()),
+    ('\u{11073}', '\u{11074}', ()),
+    ('\u{1107F}', '\u{11082}', ()),
+    ('\u{110B0}', '\u{110BA}', ()),
+    ('\u{110C2}', '\u{110C2}', ()),
+    ('\u{11100}', '\u{11102}', ()),
+    ('\u{11127}', '\u{11134}', ()),
+    ('\u{11145}', '\u{11146}', ()),
+    ('\u{11173}', '\u{11173}', ()),
+    ('\u{11180}', '\u{11182}', ()),
+    ('\u{111B3}', '\u{111C0}', ()),
+    ('\u{111C9}', '\u{111CC}', ()),
+    ('\u{111CE}', '\u{111CF}', ()),
+    ('\u{1122C}', '\u{11237}', ()),
+    ('\u{1123E}', '\u{1123E}', ()),
+    ('\u{11241}', '\u{11241}', ()),
+    ('\u{112DF}', '\u{112EA}', ()),
+    ('\u{11300}', '\u{11303}', ()),
+    ('\u{1133B}', '\u{1133C}', ()),
+    ('\u{1133E}', '\u{11344}', ()),
+    ('\u{11347}', '\u{11348}', ()),
+    ('\u{1134B}', '\u{1134D}', ()),
+    ('\u{11357}', '\u{11357}', ()),
+    ('\u{11362}', '\u{11363}', ()),
+    ('\u{11366}', '\u{1136C}', ()),
+    ('\u{11370}', '\u{11374}', ()),
+    ('\u{11435}', '\u{11446}', ()),
+    ('\u{1145E}', '\u{1145E}', ()),
+    ('\u{114B0}', '\u{114C3}', ()),
+    ('\u{115AF}', '\u{115B5}', ()),
+    ('\u{115B8}', '\u{115C0}', ()),
+    ('\u{115DC}', '\u{115DD}', ()),
+    ('\u{11630}', '\u{11640}', ()),
+    ('\u{116AB}', '\u{116B7}', ()),
+    ('\u{1171D}', '\u{1172B}', ()),
+    ('\u{1182C}', '\u{1183A}', ()),
+    ('\u{11930}', '\u{11935}', ()),
+    ('\u{11937}', '\u{11938}', ()),
+    ('\u{1193B}', '\u{1193E}', ()),
+    ('\u{11940}', '\u{11940}', ()),
+    ('\u{11942}', '\u{11943}', ()),
+    ('\u{119D1}', '\u{119D7}', ()),
+    ('\u{119DA}', '\u{119E0}', ()),
+    ('\u{119E4}', '\u{119E4}', ()),
+    ('\u{11A01}', '\u{11A0A}', ()),
+    ('\u{11A33}', '\u{11A39}', ()),
+    ('\u{11A3B}', '\u{11A3E}', ()),
+    ('\u{11A47}', '\u{11A47}', ()),
+    ('\u{11A51}', '\u{11A5B}', ()),
+    ('\u{11A8A}', '\u{11A99}', ()),
+    ('\u{11C2F}', '\u{11C36}', ()),
+    ('\u{11C38}', '\u{11C3F}', ()),
+    ('\u{11C92}', '\u{11CA7}', ()),
+    ('\u{11CA9}', '\u{11CB6}', ()),
+    ('\u{11D31}', '\u{11D36}', ()),
+    ('\u{11D3A}', '\u{11D3A}', ()),
+    ('\u{11D3C}', '\u{11D3D}', ()),
+    ('\u{11D3F}', '\u{11D45}', ()),
+    ('\u{11D47}', '\u{11D47}', ()),
+    ('\u{11D8A}', '\u{11D8E}', ()),
+    ('\u{11D90}', '\u{11D91}', ()),
+    ('\u{11D93}', '\u{11D97}', ()),
+    ('\u{11EF3}', '\u{11EF6}', ()),
+    ('\u{11F00}', '\u{11F01}', ()),
+    ('\u{11F03}', '\u{11F03}', ()),
+    ('\u{11F34}', '\u{11F3A}', ()),
+    ('\u{11F3E}', '\u{11F42}', ()),
+    ('\u{13440}', '\u{13440}', ()),
+    ('\u{13447}', '\u{13455}', ()),
+    ('\u{16AF0}', '\u{16AF4}', ()),
+    ('\u{16B30}', '\u{16B36}', ()),
+    ('\u{16F4F}', '\u{16F4F}', ()),
+    ('\u{16F51}', '\u{16F87}', ()),
+    ('\u{16F8F}', '\u{16F92}', ()),
+    ('\u{16FE4}', '\u{16FE4}', ()),
+    ('\u{16FF0}', '\u{16FF1}', ()),
+    ('\u{1BC9D}', '\u{1BC9E}', ()),
+    ('\u{1CF00}', '\u{1CF2D}', ()),
+    ('\u{1CF30}', '\u{1CF46}', ()),
+    ('\u{1D165}', '\u{1D169}', ()),
+    ('\u{1D16D}', '\u{1D172}', ()),
+    ('\u{1D17B}', '\u{1D182}', ()),
+    ('\u{1D185}', '\u{1D18B}', ()),
+    ('\u{1D1AA}', '\u{1D1AD}', ()),
+    ('\u{1D242}', '\u{1D244}', ()),
+    ('\u{1DA00}', '\u{1DA36}', ()),
+    ('\u{1DA3B}', '\u{1DA6C}', ()),
+    ('\u{1DA75}', '\u{1DA75}', ()),
+    ('\u{1DA84}', '\u{1DA84}', ()),
+    ('\u{1DA9B}', '\u{1DA9F}', ()),
+    ('\u{1DAA1}', '\u{1DAAF}', ()),
+    ('\u{1E000}', '\u{1E006}', ()),
+    ('\u{1E008}', '\u{1E018}', ()),
+    ('\u{1E01B}', '\u{1E021}', ()),
+    ('\u{1E023}', '\u{1E024}', ()),
+    ('\u{1E026}', '\u{1E02A}', ()),
+    ('\u{1E08F}', '\u{1E08F}', ()),
+    ('\u{1E130}', '\u{1E136}', ()),
+    ('\u{1E2AE}', '\u{1E2AE}', ()),
+    ('\u{1E2EC}', '\u{1E2EF}', ()),
+    ('\u{1E4EC}', '\u{1E4EF}', ()),
+    ('\u{1E8D0}', '\u{1E8D6}', ()),
+    ('\u{1E944}', '\u{1E94A}', ()),
+    ('\u{E0100}', '\u{E01EF}', ()),
+];
+
+/// Ranges of code points with a bidirectional class other than `L`
+/// that is relevant to the Bidi Rule.
+pub(super) static BIDI_CLASS: &[(char, char, BidiClass)] = &[
+    ('\u{0}', '\u{8}', BidiClass::BN),
+    ('\u{E}', '\u{1B}', BidiClass::BN),
+    ('\u{21}', '\u{22}', BidiClass::ON),
+    ('\u{23}', '\u{25}', BidiClass::ET),
+    ('\u{26}', '\u{2A}', BidiClass::ON),
+    ('\u{2B}', '\u{2B}', BidiClass::ES),
+    ('\u{2C}', '\u{2C}', BidiClass::CS),
+    ('\u{2D}', '\u{2D}', BidiClass::ES),
+    ('\u{2E}', '\u{2F}', BidiClass::CS),
+    ('\u{30}', '\u{39}', BidiClass::EN),
+    ('\u{3A}', '\u{3A}', BidiClass::CS),
+    ('\u{3B}', '\u{40}', BidiClass::ON),
+    ('\u{5B}', '\u{60}', BidiClass::ON),
+    ('\u{7B}', '\u{7E}', BidiClass::ON),
+    ('\u{7F}', '\u{84}', BidiClass::BN),
+    ('\u{86}', '\u{9F}', BidiClass::BN),
+    ('\u{A0}', '\u{A0}', BidiClass::CS),
+    ('\u{A1}', '\u{A1}', BidiClass::ON),
+    ('\u{A2}', '\u{A5}', BidiClass::ET),
+    ('\u{A6}', '\u{A9}', BidiClass::ON),
+    ('\u{AB}', '\u{AC}', BidiClass::ON),
+    ('\u{AD}', '\u{AD}', BidiClass::BN),
+    ('\u{AE}', '\u{AF}', BidiClass::ON),
+    ('\u{B0}', '\u{B1}', BidiClass::ET),
+    ('\u{B2}', '\u{B3}', BidiClass::EN),
+    ('\u{B4}', '\u{B4}', BidiClass::ON),
+    ('\u{B6}', '\u{B8}', BidiClass::ON),
+    ('\u{B9}', '\u{B9}', BidiClass::EN),
+    ('\u{BB}', '\u{BF}', BidiClass::ON),
+    ('\u{D7}', '\u{D7}', BidiClass::ON),
+    ('\u{F7}', '\u{F7}', BidiClass::ON),
+    ('\u{2B9}', '\u{2BA}', BidiClass::ON),
+    ('\u{2C2}', '\u{2CF}', BidiClass::ON),
+    ('\u{2D2}', '\u{2DF}', BidiClass::ON),
+    ('\u{2E5}', '\u{2ED}', BidiClass::ON),
+    ('\u{2EF}', '\u{2FF}', BidiClass::ON),
+    ('\u{300}', '\u{36F}', BidiClass::NSM),
+    ('\u{374}', '\u{375}', BidiClass::ON),
+    ('\u{37E}', '\u{37E}', BidiClass::ON),
+    ('\u{384}', '\u{385}', BidiClass::ON),
+    ('\u{387}', '\u{387}', BidiClass::ON),
+    ('\u{3F6}', '\u{3F6}', BidiClass::ON),
+    ('\u{483}', '\u{489}', BidiClass::NSM),
+    ('\u{58A}', '\u{58A}', BidiClass::ON),
+    ('\u{58D}', '\u{58E}', BidiClass::ON),
+    ('\u{58F}', '\u{58F}', BidiClass::ET),
+    ('\u{591}', '\u{5BD}', BidiClass::NSM),
+    ('\u{5BE}', '\u{5BE}', BidiClass::R),
+    ('\u{5BF}', '\u{5BF}', BidiClass::NSM),
+    ('\u{5C0}', '\u{5C0}', BidiClass::R),
+    ('\u{5C1}', '\u{5C2}', BidiClass::NSM),
+    ('\u{5C3}', '\u{5C3}', BidiClass::R),
+    ('\u{5C4}', '\u{5C5}', BidiClass::NSM),
+    ('\u{5C6}', '\u{5C6}', BidiClass::R),
+    ('\u{5C7}', '\u{5C7}', BidiClass::NSM),
+    ('\u{5D0}', '\u{5EA}', BidiClass::R),
+    ('\u{5EF}', '\u{5F4}', BidiClass::R),
+    ('\u{600}', '\u{605}', BidiClass::AN),
+    ('\u{606}', '\u{607}', BidiClass::ON),
+    ('\u{608}', '\u{608}', BidiClass::AL),
+    ('\u{609}', '\u{60A}', BidiClass::ET),
+    ('\u{60B}', '\u{60B}', BidiClass::AL),
+    ('\u{60C}', '\u{60C}', BidiClass::CS),
+    ('\u{60D}', '\u{60D}', BidiClass::AL),
+    ('\u{60E}', '\u{60F}', BidiClass::ON),
+    ('\u{610}', '\u{61A}', BidiClass::NSM),
+    ('\u{61B}', '\u{64A}', BidiClass::AL),
+    ('\u{64B}', '\u{65F}', BidiClass::NSM),
+    ('\u{660}', '\u{669}', BidiClass::AN),
+    ('\u{66A}', '\u{66A}', BidiClass::ET),
+    ('\u{66B}', '\u{66C}', BidiClass::AN),
+    ('\u{66D}', '\u{66F}', BidiClass::AL),
+    ('\u{670}', '\u{670}', BidiClass::NSM),
+    ('\u{671}', '\u{6D5}', BidiClass::AL),
+    ('\u{6D6}', '\u{6DC}', BidiClass::NSM),
+    ('\u{6DD}', '\u{6DD}', BidiClass::AN),
+    ('\u{6DE}', '\u{6DE}', BidiClass::ON),
+    ('\u{6DF}', '\u{6E4}', BidiClass::NSM),
+    ('\u{6E5}', '\u{6E6}', BidiClass::AL),
+    ('\u{6E7}', '\u{6E8}', BidiClass::NSM),
+    ('\u{6E9}', '\u{6E9}', BidiClass::ON),
+    ('\u{6EA}', '\u{6ED}', BidiClass::NSM),
+    ('\u{6EE}', '\u{6EF}', BidiClass::AL),
+    ('\u{6F0}', '\u{6F9}', BidiClass::EN),
+    ('\u{6FA}', '\u{70D}', BidiClass::AL),
+    ('\u{70F}', '\u{710}', BidiClass::AL),
+    ('\u{711}', '\u{711}', BidiClass::NSM),
+    ('\u{712}', '\u{72F}', BidiClass::AL),
+    ('\u{730}', '\u{74A}', BidiClass::NSM),
+    ('\u{74D}', '\u{7A5}', BidiClass::AL),
+    ('\u{7A6}', '\u{7B0}', BidiClass::NSM),
+    ('\u{7B1}', '\u{7B1}', BidiClass::AL),
+    ('\u{7C0}', '\u{7EA}', BidiClass::R),
+    ('\u{7EB}', '\u{7F3}', BidiClass::NSM),
+    ('\u{7F4}', '\u{7F5}', BidiClass::R),
+    ('\u{7F6}', '\u{7F9}', BidiClass::ON),
+    ('\u{7FA}', '\u{7FA}', BidiClass::R),
+    ('\u{7FD}', '\u{7FD}', BidiClass::NSM),
+    ('\u{7FE}', '\u{815}', BidiClass::R),
+    ('\u{816}', '\u{819}', BidiClass::NSM),
+    ('\u{81A}', '\u{81A}', BidiClass::R),
+    ('\u{81B}', '\u{823}', BidiClass::NSM),
+    ('\u{824}', '\u{824}', BidiClass::R),
+    ('\u{825}', '\u{827}', BidiClass::NSM),
+    ('\u{828}', '\u{828}', BidiClass::R),
+    ('\u{829}', '\u{82D}', BidiClass::NSM),
+    ('\u{830}', '\u{83E}', BidiClass::R),
+    ('\u{840}', '\u{858}', BidiClass::R),
+    ('\u{859}', '\u{85B}', BidiClass::NSM),
+    ('\u{85E}', '\u{85E}', BidiClass::R),
+    ('\u{860}', '\u{86A}', BidiClass::AL),
+    ('\u{870}', '\u{88E}', BidiClass::AL),
+    ('\u{890}', '\u{891}', BidiClass::AN),
+    ('\u{898}', '\u{89F}', BidiClass::NSM),
+    ('\u{8A0}', '\u{8C9}', BidiClass::AL),
+    ('\u{8CA}', '\u{8E1}', BidiClass::NSM),
+    ('\u{8E2}', '\u{8E2}', BidiClass::AN),
+    ('\u{8E3}', '\u{902}', BidiClass::NSM),
+    ('\u{93A}', '\u{93A}', BidiClass::NSM),
+    ('\u{93C}', '\u{93C}', BidiClass::NSM),
+    ('\u{941}', '\u{948}', BidiClass::NSM),
+    ('\u{94D}', '\u{94D}', BidiClass::NSM),
+    ('\u{951}', '\u{957}', BidiClass::NSM),
+    ('\u{962}', '\u{963}', BidiClass::NSM),
+    ('\u{981}', '\u{981}', BidiClass::NSM),
+    ('\u{9BC}', '\u{9BC}', BidiClass::NSM),
+    ('\u{9C1}', '\u{9C4}', BidiClass::NSM),
+    ('\u{9CD}', '\u{9CD}', BidiClass::NSM),
+    ('\u{9E2}', '\u{9E3}', BidiClass::NSM),
+    ('\u{9F2}', '\u{9F3}', BidiClass::ET),
+    ('\u{9FB}', '\u{9FB}', BidiClass::ET),
+    ('\u{9FE}', '\u{9FE}', BidiClass::NSM),
+    ('\u{A01}', '\u{A02}', BidiClass::NSM),
+    ('\u{A3C}', '\u{A3C}', BidiClass::NSM),
+    ('\u{A41}', '\u{A42}', BidiClass::NSM),
+    ('\u{A47}', '\u{A48}', BidiClass::NSM),
+    ('\u{A4B}', '\u{A4D}', BidiClass::NSM),
+    ('\u{A51}', '\u{A51}', BidiClass::NSM),
+    ('\u{A70}', '\u{A71}', BidiClass::NSM),
+    ('\u{A75}', '\u{A75}', BidiClass::NSM),
+    ('\u{A81}', '\u{A82}', BidiClass::NSM),
+    ('\u{ABC}', '\u{ABC}', BidiClass::NSM),
+    ('\u{AC1}', '\u{AC5}', BidiClass::NSM),
+    ('\u{AC7}', '\u{AC8}', BidiClass::NSM),
+    ('\u{ACD}', '\u{ACD}', BidiClass::NSM),
+    ('\u{AE2}', '\u{AE3}', BidiClass::NSM),
+    ('\u{AF1}', '\u{AF1}', BidiClass::ET),
+    ('\u{AFA}', '\u{AFF}', BidiClass::NSM),
+    ('\u{B01}', '\u{B01}', BidiClass::NSM),
+    ('\u{B3C}', '\u{B3C}', BidiClass::NSM),
+    ('\u{B3F}', '\u{B3F}', BidiClass::NSM),
+    ('\u{B41}', '\u{B44}', BidiClass::NSM),
+    ('\u{B4D}', '\u{B4D}', BidiClass::NSM),
+    ('\u{B55}', '\u{B56}', BidiClass::NSM),
+    ('\u{B62}', '\u{B63}', BidiClass::NSM),
+    ('\u{B82}', '\u{B82}', BidiClass::NSM),
+    ('\u{BC0}', '\u{BC0}', BidiClass::NSM),
+    ('\u{BCD}', '\u{BCD}', BidiClass::NSM),
+    ('\u{BF3}', '\u{BF8}', BidiClass::ON),
+    ('\u{BF9}', '\u{BF9}', BidiClass::ET),
+    ('\u{BFA}', '\u{BFA}', BidiClass::ON),
+    ('\u{C00}', '\u{C00}', BidiClass::NSM),
+    ('\u{C04}', '\u{C04}', BidiClass::NSM),
+    ('\u{C3C}', '\u{C3C}', BidiClass::NSM),
+    ('\u{C3E}', '\u{C40}', BidiClass::NSM),
+    ('\u{C46}', '\u{C48}', BidiClass::NSM),
+    ('\u{C4A}', '\u{C4D}', BidiClass::NSM),
+    ('\u{C55}', '\u{C56}', BidiClass::NSM),
+    ('\u{C62}', '\u{C63}', BidiClass::NSM),
+    ('\u{C78}', '\u{C7E}', BidiClass::ON),
+    ('\u{C81}', '\u{C81}', BidiClass::NSM),
+    ('\u{CBC}', '\u{CBC}', BidiClass::NSM),
+    ('\u{CCC}', '\u{CCD}', BidiClass::NSM),
+    ('\u{CE2}', '\u{CE3}', BidiClass::NSM),
+    ('\u{D00}', '\u{D01}', BidiClass::NSM),
+    ('\u{D3B}', '\u{D3C}', BidiClass::NSM),
+    ('\u{D41}', '\u{D44}', BidiClass::NSM),
+    ('\u{D4D}', '\u{D4D}', BidiClass::NSM),
+    ('\u{D62}', '\u{D63}', BidiClass::NSM),
+    ('\u{D81}', '\u{D81}', BidiClass::NSM),
+    ('\u{DCA}', '\u{DCA}', BidiClass::NSM),
+    ('\u{DD2}', '\u{DD4}', BidiClass::NSM),
+    ('\u{DD6}', '\u{DD6}', BidiClass::NSM),
+    ('\u{E31}', '\u{E31}', BidiClass::NSM),
+    ('\u{E34}', '\u{E3A}', BidiClass::NSM),
+    ('\u{E3F}', '\u{E3F}', BidiClass::ET),
+    ('\u{E47}', '\u{E4E}', BidiClass::NSM),
+    ('\u{EB1}', '\u{EB1}', BidiClass::NSM),
+    ('\u{EB4}', '\u{EBC}', BidiClass::NSM),
+    ('\u{EC8}', '\u{ECE}', BidiClass::NSM),
+    ('\u{F18}', '\u{F19}', BidiClass::NSM),
+    ('\u{F35}', '\u{F35}', BidiClass::NSM),
+    ('\u{F37}', '\u{F37}', BidiClass::NSM),
+    ('\u{F39}', '\u{F39}', BidiClass::NSM),
+    ('\u{F3A}', '\u{F3D}', BidiClass::ON),
+    ('\u{F71}', '\u{F7E}', BidiClass::NSM),
+    ('\u{F80}', '\u{F84}', BidiClass::NSM),
+    ('\u{F86}', '\u{F87}', BidiClass::NSM),
+    ('\u{F8D}', '\u{F97}', BidiClass::NSM),
+    ('\u{F99}', '\u{FBC}', BidiClass::NSM),
+    ('\u{FC6}', '\u{FC6}', BidiClass::NSM),
+    ('\u{102D}', '\u{1030}', BidiClass::NSM),
+    ('\u{1032}', '\u{1037}', BidiClass::NSM),
+    ('\u{1039}', '\u{103A}', BidiClass::NSM),
+    ('\u{103D}', '\u{103E}', BidiClass::NSM),
+    ('\u{1058}', '\u{1059}', BidiClass::NSM),
+    ('\u{105E}', '\u{1060}', BidiClass::NSM),
+    ('\u{1071}', '\u{1074}', BidiClass::NSM),
+    ('\u{1082}', '\u{1082}', BidiClass::NSM),
+    ('\u{1085}', '\u{1086}', BidiClass::NSM),
+    ('\u{108D}', '\u{108D}', BidiClass::NSM),
+    ('\u{109D}', '\u{109D}', BidiClass::NSM),
+    ('\u{135D}', '\u{135F}', BidiClass::NSM),
+    ('\u{1390}', '\u{1399}', BidiClass::ON),
+    ('\u{1400}', '\u{1400}', BidiClass::ON),
+    ('\u{169B}', '\u{169C}', BidiClass::ON),
+    ('\u{1712}', '\u{1714}', BidiClass::NSM),
+    ('\u{1732}', '\u{1733}', BidiClass::NSM),
+    ('\u{1752}', '\u{1753}', BidiClass::NSM),
+    ('\u{1772}', '\u{1773}', BidiClass::NSM),
+    ('\u{17B4}', '\u{17B5}', BidiClass::NSM),
+    ('\u{17B7}', '\u{17BD}', BidiClass::NSM),
+    ('\u{17C6}', '\u{17C6}', BidiClass::NSM),
+    ('\u{17C9}', '\u{17D3}', BidiClass::NSM),
+    ('\u{17DB}', '\u{17DB}', BidiClass::ET),
+    ('\u{17DD}', '\u{17DD}', BidiClass::NSM),
+    ('\u{17F0}', '\u{17F9}', BidiClass::ON),
+    ('\u{1800}', '\u{180A}', BidiClass::ON),
+    ('\u{180B}', '\u{180D}', BidiClass::NSM),
+    ('\u{180E}', '\u{180E}', BidiClass::BN),
+    ('\u{180F}', '\u{180F}', BidiClass::NSM),
+    ('\u{1885}', '\u{1886}', BidiClass::NSM),
+    ('\u{18A9}', '\u{18A9}', BidiClass::NSM),
+    ('\u{1920}', '\u{1922}', BidiClass::NSM),
+    ('\u{1927}', '\u{1928}', BidiClass::NSM),
+    ('\u{1932}', '\u{1932}', BidiClass::NSM),
+    ('\u{1939}', '\u{193B}', BidiClass::NSM),
+    ('\u{1940}', '\u{1940}', BidiClass::ON),
+    ('\u{1944}', '\u{1945}', BidiClass::ON),
+    ('\u{19DE}', '\u{19FF}', BidiClass::ON),
+    ('\u{1A17}', '\u{1A18}', BidiClass::NSM),
+    ('\u{1A1B}', '\u{1A1B}', BidiClass::NSM),
+    ('\u{1A56}', '\u{1A56}', BidiClass::NSM),
+    ('\u{1A58}', '\u{1A5E}', BidiClass::NSM),
+    ('\u{1A60}', '\u{1A60}', BidiClass::NSM),
+    ('\u{1A62}', '\u{1A62}', BidiClass::NSM),
+    ('\u{1A65}', '\u{1A6C}', BidiClass::NSM),
+    ('\u{1A73}', '\u{1A7C}', BidiClass::NSM),
+    ('\u{1A7F}', '\u{1A7F}', BidiClass::NSM),
+    ('\u{1AB0}', '\u{1ACE}', BidiClass::NSM),
+    ('\u{1B00}', '\u{1B03}', BidiClass::NSM),
+    ('\u{1B34}', '\u{1B34}', BidiClass::NSM),
+    ('\u{1B36}', '\u{1B3A}', BidiClass::NSM),
+    ('\u{1B3C}', '\u{1B3C}', BidiClass::NSM),
+    ('\u{1B42}', '\u{1B42}', BidiClass::NSM),
+    ('\u{1B6B}', '\u{1B73}', BidiClass::NSM),
+    ('\u{1B80}', '\u{1B81}', BidiClass::NSM),
+    ('\u{1BA2}', '\u{1BA5}', BidiClass::NSM),
+    ('\u{1BA8}', '\u{1BA9}', BidiClass::NSM),
+    ('\u{1BAB}', '\u{1BAD}', BidiClass::NSM),
+    ('\u{1BE6}', '\u{1BE6}', BidiClass::NSM),
+    ('\u{1BE8}', '\u{1BE9}', BidiClass::NSM),
+    ('\u{1BED}', '\u{1BED}', BidiClass::NSM),
+    ('\u{1BEF}', '\u{1BF1}', BidiClass::NSM),
+    ('\u{1C2C}', '\u{1C33}', BidiClass::NSM),
+    ('\u{1C36}', '\u{1C37}', BidiClass::NSM),
+    ('\u{1CD0}', '\u{1CD2}', BidiClass::NSM),
+    ('\u{1CD4}', '\u{1CE0}', BidiClass::NSM),
+    ('\u{1CE2}', '\u{1CE8}', BidiClass::NSM),
+    ('\u{1CED}', '\u{1CED}', BidiClass::NSM),
+    ('\u{1CF4}', '\u{1CF4}', BidiClass::NSM),
+    ('\u{1CF8}', '\u{1CF9}', BidiClass::NSM),
+    ('\u{1DC0}', '\u{1DFF}', BidiClass::NSM),
+    ('\u{1FBD}', '\u{1FBD}', BidiClass::ON),
+    ('\u{1FBF}', '\u{1FC1}', BidiClass::ON),
+    ('\u{1FCD}', '\u{1FCF}', BidiClass::ON),
+    ('\u{1FDD}', '\u{1FDF}', BidiClass::ON),
+    ('\u{1FED}', '\u{1FEF}', BidiClass::ON),
+    ('\u{1FFD}', '\u{1FFE}', BidiClass::ON),
+    ('\u{200B}', '\u{200D}', BidiClass::BN),
+    ('\u{200F}', '\u{200F}', BidiClass::R),
+    ('\u{2010}', '\u{2027}', BidiClass::ON),
+    ('\u{202F}', '\u{202F}', BidiClass::CS),
+    ('\u{2030}', '\u{2034}', BidiClass::ET),
+    ('\u{2035}', '\u{2043}', BidiClass::ON),
+    ('\u{2044}', '\u{2044}', BidiClass::CS),
+    ('\u{2045}', '\u{205E}', BidiClass::ON),
+    ('\u{2060}', '\u{2064}', BidiClass::BN),
+    ('\u{206A}', '\u{206F}', BidiClass::BN),
+    ('\u{2070}', '\u{2070}', BidiClass::EN),
+    ('\u{2074}', '\u{2079}', BidiClass::EN),
+    ('\u{207A}', '\u{207B}', BidiClass::ES),
+    ('\u{207C}', '\u{207E}', BidiClass::ON),
+    ('\u{2080}', '\u{2089}', BidiClass::EN),
+    ('\u{208A}', '\u{208B}', BidiClass::ES),
+    ('\u{208C}', '\u{208E}', BidiClass::ON),
+    ('\u{20A0}', '\u{20C0}', BidiClass::ET),
+    ('\u{20D0}', '\u{20F0}', BidiClass::NSM),
+    ('\u{2100}', '\u{2101}', BidiClass::ON),
+    ('\u{2103}', '\u{2106}', BidiClass::ON),
+    ('\u{2108}', '\u{2109}', BidiClass::ON),
+    ('\u{2114}', '\u{2114}', BidiClass::ON),
+    ('\u{2116}', '\u{2118}', BidiClass::ON),
+    ('\u{211E}', '\u{2123}', BidiClass::ON),
+    ('\u{2125}', '\u{2125}', BidiClass::ON),
+    ('\u{2127}', '\u{2127}', BidiClass::ON),
+    ('\u{2129}', '\u{2129}', BidiClass::ON),
+    ('\u{212E}', '\u{212E}', BidiClass::ET),
+    ('\u{213A}', '\u{213B}', BidiClass::ON),
+    ('\u{2140}', '\u{2144}', BidiClass::ON),
+    ('\u{214A}', '\u{214D}', BidiClass::ON),
+    ('\u{2150}', '\u{215F}', BidiClass::ON),
+    ('\u{2189}', '\u{218B}', BidiClass::ON),
+    ('\u{2190}', '\u{2211}', BidiClass::ON),
+    ('\u{2212}', '\u{2212}', BidiClass::ES),
+    ('\u{2213}', '\u{2213}', BidiClass::ET),
+    ('\u{2214}', '\u{2335}', BidiClass::ON),
+    ('\u{237B}', '\u{2394}', BidiClass::ON),
+    ('\u{2396}', '\u{2426}', BidiClass::ON),
+    ('\u{2440}', '\u{244A}', BidiClass::ON),
+    ('\u{2460}', '\u{2487}', BidiClass::ON),
+    ('\u{2488}', '\u{249B}', BidiClass::EN),
+    ('\u{24EA}', '\u{26AB}', BidiClass::ON),
+    ('\u{26AD}', '\u{27FF}', BidiClass::ON),
+    ('\u{2900}', '\u{2B73}', BidiClass::ON),
+    ('\u{2B76}', '\u{2B95}', BidiClass::ON),
+    ('\u{2B97}', '\u{2BFF}', BidiClass::ON),
+    ('\u{2CE5}', '\u{2CEA}', BidiClass::ON),
+    ('\u{2CEF}', '\u{2CF1}', BidiClass::NSM),
+    ('\u{2CF9}', '\u{2CFF}', BidiClass::ON),
+    ('\u{2D7F}', '\u{2D7F}', BidiClass::NSM),
+    ('\u{2DE0}', '\u{2DFF}', BidiClass::NSM),
+    ('\u{2E00}', '\u{2E5D}', BidiClass::ON),
+    ('\u{2E80}', '\u{2E99}', BidiClass::ON),
+    ('\u{2E9B}', '\u{2EF3}', BidiClass::ON),
+    ('\u{2F00}', '\u{2FD5}', BidiClass::ON),
+    ('\u{2FF0}', '\u{2FFF}', BidiClass::ON),
+    ('\u{3001}', '\u{3004}', BidiClass::ON),
+    ('\u{3008}', '\u{3020}', BidiClass::ON),
+    ('\u{302A}', '\u{302D}', BidiClass::NSM),
+    ('\u{3030}', '\u{3030}', BidiClass::ON),
+    ('\u{3036}', '\u{3037}', BidiClass::ON),
+    ('\u{303D}', '\u{303F}', BidiClass::ON),
+    ('\u{3099}', '\u{309A}', BidiClass::NSM),
+    ('\u{309B}', '\u{309C}', BidiClass::ON),
+    ('\u{30A0}', '\u{30A0}', BidiClass::ON),
+    ('\u{30FB}', '\u{30FB}', BidiClass::ON),
+    ('\u{31C0}', '\u{31E3}', BidiClass::ON),
+    ('\u{31EF}', '\u{31EF}', BidiClass::ON),
+    ('\u{321D}', '\u{321E}', BidiClass::ON),
+    ('\u{3250}', '\u{325F}', BidiClass::ON),
+    ('\u{327C}', '\u{327E}', BidiClass::ON),
+    ('\u{32B1}', '\u{32BF}', BidiClass::ON),
+    ('\u{32CC}', '\u{32CF}', BidiClass::ON),
+    ('\u{3377}', '\u{337A}', BidiClass::ON),
+    ('\u{33DE}', '\u{33DF}', BidiClass::ON),
+    ('\u{33FF}', '\u{33FF}', BidiClass::ON),
+    ('\u{4DC0}', '\u{4DFF}', BidiClass::ON),
+    ('\u{A490}', '\u{A4C6}', BidiClass::ON),
+    ('\u{A60D}', '\u{A60F}', BidiClass::ON),
+    ('\u{A66F}', '\u{A672}', BidiClass::NSM),
+    ('\u{A673}', '\u{A673}', BidiClass::ON),
+    ('\u{A674}', '\u{A67D}', BidiClass::NSM),
+    ('\u{A67E}', '\u{A67F}', BidiClass::ON),
+    ('\u{A69E}', '\u{A69F}', BidiClass::NSM),
+    ('\u{A6F0}', '\u{A6F1}', BidiClass::NSM),
+    ('\u{A700}', '\u{A721}', BidiClass::ON),
+    ('\u{A788}', '\u{A788}', BidiClass::ON),
+    ('\u{A802}', '\u{A802}', BidiClass::NSM),
+    ('\u{A806}', '\u{A806}', BidiClass::NSM),
+    ('\u{A80B}', '\u{A80B}', BidiClass::NSM),
+    ('\u{A825}', '\u{A826}', BidiClass::NSM),
+    ('\u{A828}', '\u{A82B}', BidiClass::ON),
+    ('\u{A82C}', '\u{A82C}', BidiClass::NSM),
+    ('\u{A838}', '\u{A839}', BidiClass::ET),
+    ('\u{A874}', '\u{A877}', BidiClass::ON),
+    ('\u{A8C4}', '\u{A8C5}', BidiClass::NSM),
+    ('\u{A8E0}', '\u{A8F1}', BidiClass::NSM),
+    ('\u{A8FF}', '\u{A8FF}', BidiClass::NSM),
+    ('\u{A926}', '\u{A92D}', BidiClass::NSM),
+    ('\u{A947}', '\u{A951}', BidiClass::NSM),
+    ('\u{A980}', '\u{A982}', BidiClass::NSM),
+    ('\u{A9B3}', '\u{A9B3}', BidiClass::NSM),
+    ('\u{A9B6}', '\u{A9B9}', BidiClass::NSM),
+    ('\u{A9BC}', '\u{A9BD}', BidiClass::NSM),
+    ('\u{A9E5}', '\u{A9E5}', BidiClass::NSM),
+    ('\u{AA29}', '\u{AA2E}', BidiClass::NSM),
+    ('\u{AA31}', '\u{AA32}', BidiClass::NSM),
+    ('\u{AA35}', '\u{AA36}', BidiClass::NSM),
+    ('\u{AA43}', '\u{AA43}', BidiClass::NSM),
+    ('\u{AA4C}', '\u{AA4C}', BidiClass::NSM),
+    ('\u{AA7C}', '\u{AA7C}', BidiClass::NSM),
+    ('\u{AAB0}', '\u{AAB0}', BidiClass::NSM),
+    ('\u{AAB2}', '\u{AAB4}', BidiClass::NSM),
+    ('\u{AAB7}', '\u{AAB8}', BidiClass::NSM),
+    ('\u{AABE}', '\u{AABF}', BidiClass::NSM),
+    ('\u{AAC1}', '\u{AAC1}', BidiClass::NSM),
+    ('\u{AAEC}', '\u{AAED}', BidiClass::NSM),
+    ('\u{AAF6}', '\u{AAF6}', BidiClass::NSM),
+    ('\u{AB6A}', '\u{AB6B}', BidiClass::ON),
+    ('\u{ABE5}', '\u{ABE5}', BidiClass::NSM),
+    ('\u{ABE8}', '\u{ABE8}', BidiClass::NSM),
+    ('\u{ABED}', '\u{ABED}', BidiClass::NSM),
+    ('\u{FB1D}', '\u{FB1D}', BidiClass::R),
+    ('\u{FB1E}', '\u{FB1E}', BidiClass::NSM),
+    ('\u{FB1F}', '\u{FB28}', BidiClass::R),
+    ('\u{FB29}', '\u{FB29}', BidiClass::ES),
+    ('\u{FB2A}', '\u{FB36}', BidiClass::R),
+    ('\u{FB38}', '\u{FB3C}', BidiClass::R),
+    ('\u{FB3E}', '\u{FB3E}', BidiClass::R),
+    ('\u{FB40}', '\u{FB41}', BidiClass::R),
+    ('\u{FB43}', '\u{FB44}', BidiClass::R),
+    ('\u{FB46}', '\u{FB4F}', BidiClass::R),
+    ('\u{FB50}', '\u{FBC2}', BidiClass::AL),
+    ('\u{FBD3}', '\u{FD3D}', BidiClass::AL),
+    ('\u{FD3E}', '\u{FD4F}', BidiClass::ON),
+    ('\u{FD50}', '\u{FD8F}', BidiClass::AL),
+    ('\u{FD92}', '\u{FDC7}', BidiClass::AL),
+    ('\u{FDCF}', '\u{FDCF}', BidiClass::ON),
+    ('\u{FDF0}', '\u{FDFC}', BidiClass::AL),
+    ('\u{FDFD}', '\u{FDFF}', BidiClass::ON),
+    ('\u{FE00}', '\u{FE0F}', BidiClass::NSM),
+    ('\u{FE10}', '\u{FE19}', BidiClass::ON),
+    ('\u{FE20}', '\u{FE2F}', BidiClass::NSM),
+    ('\u{FE30}', '\u{FE4F}', BidiClass::ON),
+    ('\u{FE50}', '\u{FE50}', BidiClass::CS),
+    ('\u{FE51}', '\u{FE51}', BidiClass::ON),
+    ('\u{FE52}', '\u{FE52}', BidiClass::CS),
+    ('\u{FE54}', '\u{FE54}', BidiClass::ON),
+    ('\u{FE55}', '\u{FE55}', BidiClass::CS),
+    ('\u{FE56}', '\u{FE5E}', BidiClass::ON),
+    ('\u{FE5F}', '\u{FE5F}', BidiClass::ET),
+    ('\u{FE60}', '\u{FE61}', BidiClass::ON),
+    ('\u{FE62}', '\u{FE63}', BidiClass::ES),
+    ('\u{FE64}', '\u{FE66}', BidiClass::ON),
+    ('\u{FE68}', '\u{FE68}', BidiClass::ON),
+    ('\u{FE69}', '\u{FE6A}', BidiClass::ET),
+    ('\u{FE6B}', '\u{FE6B}', BidiClass::ON),
+    ('\u{FE70}', '\u{FE74}', BidiClass::AL),
+    ('\u{FE76}', '\u{FEFC}', BidiClass::AL),
+    ('\u{FEFF}', '\u{FEFF}', BidiClass::BN),
+    ('\u{FF01}', '\u{FF02}', BidiClass::ON),
+    ('\u{FF03}', '\u{FF05}', BidiClass::ET),
+    ('\u{FF06}', '\u{FF0A}', BidiClass::ON),
+    ('\u{FF0B}', '\u{FF0B}', BidiClass::ES),
+    ('\u{FF0C}', '\u{FF0C}', BidiClass::CS),
+    ('\u{FF0D}', '\u{FF0D}', BidiClass::ES),
+    ('\u{FF0E}', '\u{FF0F}', BidiClass::CS),
+    ('\u{FF10}', '\u{FF19}', BidiClass::EN),
+    ('\u{FF1A}', '\u{FF1A}', BidiClass::CS),
+    ('\u{FF1B}', '\u{FF20}', BidiClass::ON),
+    ('\u{FF3B}', '\u{FF40}', BidiClass::ON),
+    ('\u{FF5B}', '\u{FF65}', BidiClass::ON),
+    ('\u{FFE0}', '\u{FFE1}', BidiClass::ET),
+    ('\u{FFE2}', '\u{FFE4}', BidiClass::ON),
+    ('\u{FFE5}', '\u{FFE6}', BidiClass::ET),
+    ('\u{FFE8}', '\u{FFEE}', BidiClass::ON),
+    ('\u{FFF9}', '\u{FFFD}', BidiClass::ON),
+    ('\u{10101}', '\u{10101}', BidiClass::ON),
+    ('\u{10140}', '\u{1018C}', BidiClass::ON),
+    ('\u{10190}', '\u{1019C}', BidiClass::ON),
+    ('\u{101A0}', '\u{101A0}', BidiClass::ON),
+    ('\u{101FD}', '\u{101FD}', BidiClass::NSM),
+    ('\u{102E0}', '\u{102E0}', BidiClass::NSM),
+    ('\u{102E1}', '\u{102FB}', BidiClass::EN),
+    ('\u{10376}', '\u{1037A}', BidiClass::NSM),
+    ('\u{10800}', '\u{10805}', BidiClass::R),
+    ('\u{10808}', '\u{10808}', BidiClass::R),
+    ('\u{1080A}', '\u{10835}', BidiClass::R),
+    ('\u{10837}', '\u{10838}', BidiClass::R),
+    ('\u{1083C}', '\u{1083C}', BidiClass::R),
+    ('\u{1083F}', '\u{10855}', BidiClass::R),
+    ('\u{10857}', '\u{1089E}', BidiClass::R),
+    ('\u{108A7}', '\u{108AF}', BidiClass::R),
+    ('\u{108E0}', '\u{108F2}', BidiClass::R),
+    ('\u{108F4}', '\u{108F5}', BidiClass::R),
+    ('\u{108FB}', '\u{1091B}', BidiClass::R),
+    ('\u{1091F}', '\u{1091F}', BidiClass::ON),
+    ('\u{10920}', '\u{10939}', BidiClass::R),
+    ('\u{1093F}', '\u{1093F}', BidiClass::R),
+    ('\u{10980}', '\u{109B7}', BidiClass::R),
+    ('\u{109BC}', '\u{109CF}', BidiClass::R),
+    ('\u{109D2}', '\u{10A00}', BidiClass::R),
+    ('\u{10A01}', '\u{10A03}', BidiClass::NSM),
+    ('\u{10A05}', '\u{10A06}', BidiClass::NSM),
+    ('\u{10A0C}', '\u{10A0F}', BidiClass::NSM),
+    ('\u{10A10}', '\u{10A13}', BidiClass::R),
+    ('\u{10A15}', '\u{10A17}', BidiClass::R),
+    ('\u{10A19}', '\u{10A35}', BidiClass::R),
+    ('\u{10A38}', '\u{10A3A}', BidiClass::NSM),
+    ('\u{10A3F}', '\u{10A3F}', BidiClass::NSM),
+    ('\u{10A40}', '\u{10A48}', BidiClass::R),
+    ('\u{10A50}', '\u{10A58}', BidiClass::R),
+    ('\u{10A60}', '\u{10A9F}', BidiClass::R),
+    ('\u{10AC0}', '\u{10AE4}', BidiClass::R),
+    ('\u{10AE5}', '\u{10AE6}', BidiClass::NSM),
+    ('\u{10AEB}', '\u{10AF6}', BidiClass::R),
+    ('\u{10B00}', '\u{10B35}', BidiClass::R),
+    ('\u{10B39}', '\u{10B3F}', BidiClass::ON),
+    ('\u{10B40}', '\u{10B55}', BidiClass::R),
+    ('\u{10B58}', '\u{10B72}', BidiClass::R),
+    ('\u{10B78}', '\u{10B91}', BidiClass::R),
+    ('\u{10B99}', '\u{10B9C}', BidiClass::R),
+    ('\u{10BA9}', '\u{10BAF}', BidiClass::R),
+    ('\u{10C00}', '\u{10C48}', BidiClass::R),
+    ('\u{10C80}', '\u{10CB2}', BidiClass::R),
+    ('\u{10CC0}', '\u{10CF2}', BidiClass::R),
+    ('\u{10CFA}', '\u{10CFF}', BidiClass::R),
+    ('\u{10D00}', '\u{10D23}', BidiClass::AL),
+    ('\u{10D24}', '\u{10D27}', BidiClass::NSM),
+    ('\u{10D30}', '\u{10D39}', BidiClass::AN),
+    ('\u{10E60}', '\u{10E7E}', BidiClass::AN),
+    ('\u{10E80}', '\u{10EA9}', BidiClass::R),
+    ('\u{10EAB}', '\u{10EAC}', BidiClass::NSM),
+    ('\u{10EAD}', '\u{10EAD}', BidiClass::R),
+    ('\u{10EB0}', '\u{10EB1}', BidiClass::R),
+    ('\u{10EFD}', '\u{10EFF}', BidiClass::NSM),
+    ('\u{10F00}', '\u{10F27}', BidiClass::R),
+    ('\u{10F30}', '\u{10F45}', BidiClass::AL),
+    ('\u{10F46}', '\u{10F50}', BidiClass::NSM),
+    ('\u{10F51}', '\u{10F59}', BidiClass::AL),
+    ('\u{10F70}', '\u{10F81}', BidiClass::R),
+    ('\u{10F82}', '\u{10F85}', BidiClass::NSM),
+    ('\u{10F86}', '\u{10F89}', BidiClass::R),
+    ('\u{10FB0}', '\u{10FCB}', BidiClass::R),
+    ('\u{10FE0}', '\u{10FF6}', BidiClass::R),
+    ('\u{11001}', '\u{11001}', BidiClass::NSM),
+    ('\u{11038}', '\u{11046}', BidiClass::NSM),
+    ('\u{11052}', '\u{11065}', BidiClass::ON),
+    ('\u{11070}', '\u{11070}', BidiClass::NSM),
+    ('\u{11073}', '\u{11074}', BidiClass::NSM),
+    ('\u{1107F}', '\u{11081}', BidiClass::NSM),
+    ('\u{110B3}', '\u{110B6}', BidiClass::NSM),
+    ('\u{110B9}', '\u{110BA}', BidiClass::NSM),
+    ('\u{110C2}', '\u{110C2}', BidiClass::NSM),
+    ('\u{11100}', '\u{11102}', BidiClass::NSM),
+    ('\u{11127}', '\u{1112B}', BidiClass::NSM),
+    ('\u{1112D}', '\u{11134}', BidiClass::NSM),
+    ('\u{11173}', '\u{11173}', BidiClass::NSM),
+    ('\u{11180}', '\u{11181}', BidiClass::NSM),
+    ('\u{111B6}', '\u{111BE}', BidiClass::NSM),
+    ('\u{111C9}', '\u{111CC}', BidiClass::NSM),
+    ('\u{111CF}', '\u{111CF}', BidiClass::NSM),
+    ('\u{1122F}', '\u{11231}', BidiClass::NSM),
+    ('\u{11234}', '\u{11234}', BidiClass::NSM),
+    ('\u{11236}', '\u{11237}', BidiClass::NSM),
+    ('\u{1123E}', '\u{1123E}', BidiClass::NSM),
+    ('\u{11241}', '\u{11241}', BidiClass::NSM),
+    ('\u{112DF}', '\u{112DF}', BidiClass::NSM),
+    ('\u{112E3}', '\u{112EA}', BidiClass::NSM),
+    ('\u{11300}', '\u{11301}', BidiClass::NSM),
+    ('\u{1133B}', '\u{1133C}', BidiClass::NSM),
+    ('\u{11340}', '\u{11340}', BidiClass::NSM),
+    ('\u{11366}', '\u{1136C}', BidiClass::NSM),
+    ('\u{11370}', '\u{11374}', BidiClass::NSM),
+    ('\u{11438}', '\u{1143F}', BidiClass::NSM),
+    ('\u{11442}', '\u{11444}', BidiClass::NSM),
+    ('\u{11446}', '\u{11446}', BidiClass::NSM),
+    ('\u{1145E}', '\u{1145E}', BidiClass::NSM),
+    ('\u{114B3}', '\u{114B8}', BidiClass::NSM),
+    ('\u{114BA}', '\u{114BA}', BidiClass::NSM),
+    ('\u{114BF}', '\u{114C0}', BidiClass::NSM),
+    ('\u{114C2}', '\u{114C3}', BidiClass::NSM),
+    ('\u{115B2}', '\u{115B5}', BidiClass::NSM),
+    ('\u{115BC}', '\u{115BD}', BidiClass::NSM),
+    ('\u{115BF}', '\u{115C0}', BidiClass::NSM),
+    ('\u{115DC}', '\u{115DD}', BidiClass::NSM),
+    ('\u{11633}', '\u{1163A}', BidiClass::NSM),
+    ('\u{1163D}', '\u{1163D}', BidiClass::NSM),
+    ('\u{1163F}', '\u{11640}', BidiClass::NSM),
+    ('\u{11660}', '\u{1166C}', BidiClass::ON),
+    ('\u{116AB}', '\u{116AB}', BidiClass::NSM),
+    ('\u{116AD}', '\u{116AD}', BidiClass::NSM),
+    ('\u{116B0}', '\u{116B5}', BidiClass::NSM),
+    ('\u{116B7}', '\u{116B7}', BidiClass::NSM),
+    ('\u{1171D}', '\u{1171F}', BidiClass::NSM),
+    ('\u{11722}', '\u{11725}', BidiClass::NSM),
+    ('\u{11727}', '\u{1172B}', BidiClass::NSM),
+    ('\u{1182F}', '\u{11837}', BidiClass::NSM),
+    ('\u{11839}', '\u{1183A}', BidiClass::NSM),
+    ('\u{1193B}', '\u{1193C}', BidiClass::NSM),
+    ('\u{1193E}', '\u{1193E}', BidiClass::NSM),
+    ('\u{11943}', '\u{11943}', BidiClass::NSM),
+    ('\u{119D4}', '\u{119D7}', BidiClass::NSM),
+    ('\u{119DA}', '\u{119DB}', BidiClass::NSM),
+    ('\u{119E0}', '\u{119E0}', BidiClass::NSM),
+    ('\u{11A01}', '\u{11A06}', BidiClass::NSM),
+    ('\u{11A09}', '\u{11A0A}', BidiClass::NSM),
+    ('\u{11A33}', '\u{11A38}', BidiClass::NSM),
+    ('\u{11A3B}', '\u{11A3E}', BidiClass::NSM),
+    ('\u{11A47}', '\u{11A47}', BidiClass::NSM),
+    ('\u{11A51}', '\u{11A56}', BidiClass::NSM),
+    ('\u{11A59}', '\u{11A5B}', BidiClass::NSM),
+    ('\u{11A8A}', '\u{11A96}', BidiClass::NSM),
+    ('\u{11A98}', '\u{11A99}', BidiClass::NSM),
+    ('\u{11C30}', '\u{11C36}', BidiClass::NSM),
+    ('\u{11C38}', '\u{11C3D}', BidiClass::NSM),
+    ('\u{11C92}', '\u{11CA7}', BidiClass::NSM),
+    ('\u{11CAA}', '\u{11CB0}', BidiClass::NSM),
+    ('\u{11CB2}', '\u{11CB3}', BidiClass::NSM),
+    ('\u{11CB5}', '\u{11CB6}', BidiClass::NSM),
+    ('\u{11D31}', '\u{11D36}', BidiClass::NSM),
+    ('\u{11D3A}', '\u{11D3A}', BidiClass::NSM),
+    ('\u{11D3C}', '\u{11D3D}', BidiClass::NSM),
+    ('\u{11D3F}', '\u{11D45}', BidiClass::NSM),
+    ('\u{11D47}', '\u{11D47}', BidiClass::NSM),
+    ('\u{11D90}', '\u{11D91}', BidiClass::NSM),
+    ('\u{11D95}', '\u{11D95}', BidiClass::NSM),
+    ('\u{11D97}', '\u{11D97}', BidiClass::NSM),
+    ('\u{11EF3}', '\u{11EF4}', BidiClass::NSM),
+    ('\u{11F00}', '\u{11F01}', BidiClass::NSM),
+    ('\u{11F36}', '\u{11F3A}', BidiClass::NSM),
+    ('\u{11F40}', '\u{11F40}', BidiClass::NSM),
+    ('\u{11F42}', '\u{11F42}', BidiClass::NSM),
+    ('\u{11FD5}', '\u{11FDC}', BidiClass::ON),
+    ('\u{11FDD}', '\u{11FE0}', BidiClass::ET),
+    ('\u{11FE1}', '\u{11FF1}', BidiClass::ON),
+    ('\u{13440}', '\u{13440}', BidiClass::NSM),
+    ('\u{13447}', '\u{13455}', BidiClass::NSM),
+    ('\u{16AF0}', '\u{16AF4}', BidiClass::NSM),
+    ('\u{16B30}', '\u{16B36}', BidiClass::NSM),
+    ('\u{16F4F}', '\u{16F4F}', BidiClass::NSM),
+    ('\u{16F8F}', '\u{16F92}', BidiClass::NSM),
+    ('\u{16FE2}', '\u{16FE2}', BidiClass::ON),
+    ('\u{16FE4}', '\u{16FE4}', BidiClass::NSM),
+    ('\u{1BC9D}', '\u{1BC9E}', BidiClass::NSM),
+    ('\u{1BCA0}', '\u{1BCA3}', BidiClass::BN),
+    ('\u{1CF00}', '\u{1CF2D}', BidiClass::NSM),
+    ('\u{1CF30}', '\u{1CF46}', BidiClass::NSM),
+    ('\u{1D167}', '\u{1D169}', BidiClass::NSM),
+    ('\u{1D173}', '\u{1D17A}', BidiClass::BN),
+    ('\u{1D17B}', '\u{1D182}', BidiClass::NSM),
+    ('\u{1D185}', '\u{1D18B}', BidiClass::NSM),
+    ('\u{1D1AA}', '\u{1D1AD}', BidiClass::NSM),
+    ('\u{1D1E9}', '\u{1D1EA}', BidiClass::ON),
+    ('\u{1D200}', '\u{1D241}', BidiClass::ON),
+    ('\u{1D242}', '\u{1D244}', BidiClass::NSM),
+    ('\u{1D245}', '\u{1D245}', BidiClass::ON),
+    ('\u{1D300}', '\u{1D356}', BidiClass::ON),
+    ('\u{1D6DB}', '\u{1D6DB}', BidiClass::ON),
+    ('\u{1D715}', '\u{1D715}', BidiClass::ON),
+    ('\u{1D74F}', '\u{1D74F}', BidiClass::ON),
+    ('\u{1D789}', '\u{1D789}', BidiClass::ON),
+    ('\u{1D7C3}', '\u{1D7C3}', BidiClass::ON),
+    ('\u{1D7CE}', '\u{1D7FF}', BidiClass::EN),
+    ('\u{1DA00}', '\u{1DA36}', BidiClass::NSM),
+    ('\u{1DA3B}', '\u{1DA6C}', BidiClass::NSM),
+    ('\u{1DA75}', '\u{1DA75}', BidiClass::NSM),
+    ('\u{1DA84}', '\u{1DA84}', BidiClass::NSM),
+    ('\u{1DA9B}', '\u{1DA9F}', BidiClass::NSM),
+    ('\u{1DAA1}', '\u{1DAAF}', BidiClass::NSM),
+    ('\u{1E000}', '\u{1E006}', BidiClass::NSM),
+    ('\u{1E008}', '\u{1E018}', BidiClass::NSM),
+    ('\u{1E01B}', '\u{1E021}', BidiClass::NSM),
+    ('\u{1E023}', '\u{1E024}', BidiClass::NSM),
+    ('\u{1E026}', '\u{1E02A}', BidiClass::NSM),
+    ('\u{1E08F}', '\u{1E08F}', BidiClass::NSM),
+    ('\u{1E130}', '\u{1E136}', BidiClass::NSM),
+    ('\u{1E2AE}', '\u{1E2AE}', BidiClass::NSM),
+    ('\u{1E2EC}', '\u{1E2EF}', BidiClass::NSM),
+    ('\u{1E2FF}', '\u{1E2FF}', BidiClass::ET),
+    ('\u{1E4EC}', '\u{1E4EF}', BidiClass::NSM),
+    ('\u{1E800}', '\u{1E8C4}', BidiClass::R),
+    ('\u{1E8C7}', '\u{1E8CF}', BidiClass::R),
+    ('\u{1E8D0}', '\u{1E8D6}', BidiClass::NSM),
+    ('\u{1E900}', '\u{1E943}', BidiClass::R),
+    ('\u{1E944}', '\u{1E94A}', BidiClass::NSM),
+    ('\u{1E94B}', '\u{1E94B}', BidiClass::R),
+    ('\u{1E950}', '\u{1E959}', BidiClass::R),
+    ('\u{1E95E}', '\u{1E95F}', BidiClass::R),
+    ('\u{1EC71}', '\u{1ECB4}', BidiClass::AL),
+    ('\u{1ED01}', '\u{1ED3D}', BidiClass::AL),
+    ('\u{1EE00}', '\u{1EE03}', BidiClass::AL),
+    ('\u{1EE05}', '\u{1EE1F}', BidiClass::AL),
+    ('\u{1EE21}', '\u{1EE22}', BidiClass::AL),
+    ('\u{1EE24}', '\u{1EE24}', BidiClass::AL),
+    ('\u{1EE27}', '\u{1EE27}', BidiClass::AL),
+    ('\u{1EE29}', '\u{1EE32}', BidiClass::AL),
+    ('\u{1EE34}', '\u{1EE37}', BidiClass::AL),
+    ('\u{1EE39}', '\u{1EE39}', BidiClass::AL),
+    ('\u{1EE3B}', '\u{1EE3B}', BidiClass::AL),
+    ('\u{1EE42}', '\u{1EE42}', BidiClass::AL),
+    ('\u{1EE47}', '\u{1EE47}', BidiClass::AL),
+    ('\u{1EE49}', '\u{1EE49}', BidiClass::AL),
+    ('\u{1EE4B}', '\u{1EE4B}', BidiClass::AL),
+    ('\u{1EE4D}', '\u{1EE4F}', BidiClass::AL),
+    ('\u{1EE51}', '\u{1EE52}', BidiClass::AL),
+    ('\u{1EE54}', '\u{1EE54}', BidiClass::AL),
+    ('\u{1EE57}', '\u{1EE57}', BidiClass::AL),
+    ('\u{1EE59}', '\u{1EE59}', BidiClass::AL),
+    ('\u{1EE5B}', '\u{1EE5B}', BidiClass::AL),
+    ('\u{1EE5D}', '\u{1EE5D}', BidiClass::AL),
+    ('\u{1EE5F}', '\u{1EE5F}', BidiClass::AL),
+    ('\u{1EE61}', '\u{1EE62}', BidiClass::AL),
+    ('\u{1EE64}', '\u{1EE64}', BidiClass::AL),
+    ('\u{1EE67}', '\u{1EE6A}', BidiClass::AL),
+    ('\u{1EE6C}', '\u{1EE72}', BidiClass::AL),
+    ('\u{1EE74}', '\u{1EE77}', BidiClass::AL),
+    ('\u{1EE79}', '\u{1EE7C}', BidiClass::AL),
+    ('\u{1EE7E}', '\u{1EE7E}', BidiClass::AL),
+    ('\u{1EE80}', '\u{1EE89}', BidiClass::AL),
+    ('\u{1EE8B}', '\u{1EE9B}', BidiClass::AL),
+    ('\u{1EEA1}', '\u{1EEA3}', BidiClass::AL),
+    ('\u{1EEA5}', '\u{1EEA9}', BidiClass::AL),
+    ('\u{1EEAB}', '\u{1EEBB}', BidiClass::AL),
+    ('\u{1EEF0}', '\u{1EEF1}', BidiClass::ON),
+    ('\u{1F000}', '\u{1F02B}', BidiClass::ON),
+    ('\u{1F030}', '\u{1F093}', BidiClass::ON),
+    ('\u{1F0A0}', '\u{1F0AE}', BidiClass::ON),
+    ('\u{1F0B1}', '\u{1F0BF}', BidiClass::ON),
+    ('\u{1F0C1}', '\u{1F0CF}', BidiClass::ON),
+    ('\u{1F0D1}', '\u{1F0F5}', BidiClass::ON),
+    ('\u{1F100}', '\u{1F10A}', BidiClass::EN),
+    ('\u{1F10B}', '\u{1F10F}', BidiClass::ON),
+    ('\u{1F12F}', '\u{1F12F}', BidiClass::ON),
+    ('\u{1F16A}', '\u{1F16F}', BidiClass::ON),
+    ('\u{1F1AD}', '\u{1F1AD}', BidiClass::ON),
+    ('\u{1F260}', '\u{1F265}', BidiClass::ON),
+    ('\u{1F300}', '\u{1F6D7}', BidiClass::ON),
+    ('\u{1F6DC}', '\u{1F6EC}', BidiClass::ON),
+    ('\u{1F6F0}', '\u{1F6FC}', BidiClass::ON),
+    ('\u{1F700}', '\u{1F776}', BidiClass::ON),
+    ('\u{1F77B}', '\u{1F7D9}', BidiClass::ON),
+    ('\u{1F7E0}', '\u{1F7EB}', BidiClass::ON),
+    ('\u{1F7F0}', '\u{1F7F0}', BidiClass::ON),
+    ('\u{1F800}', '\u{1F80B}', BidiClass::ON),
+    ('\u{1F810}', '\u{1F847}', BidiClass::ON),
+    ('\u{1F850}', '\u{1F859}', BidiClass::ON),
+    ('\u{1F860}', '\u{1F887}', BidiClass::ON),
+    ('\u{1F890}', '\u{1F8AD}', BidiClass::ON),
+    ('\u{1F8B0}', '\u{1F8B1}', BidiClass::ON),
+    ('\u{1F900}', '\u{1FA53}', BidiClass::ON),
+    ('\u{1FA60}', '\u{1FA6D}', BidiClass::ON),
+    ('\u{1FA70}', '\u{1FA7C}', BidiClass::ON),
+    ('\u{1FA80}', '\u{1FA88}', BidiClass::ON),
+    ('\u{1FA90}', '\u{1FABD}', BidiClass::ON),
+    ('\u{1FABF}', '\u{1FAC5}', BidiClass::ON),
+    ('\u{1FACE}', '\u{1FADB}', BidiClass::ON),
+    ('\u{1FAE0}', '\u{1FAE8}', BidiClass::ON),
+    ('\u{1FAF0}', '\u{1FAF8}', BidiClass::ON),
+    ('\u{1FB00}', '\u{1FB92}', BidiClass::ON),
+    ('\u{1FB94}', '\u{1FBCA}', BidiClass::ON),
+    ('\u{1FBF0}', '\u{1FBF9}', BidiClass::EN),
+    ('\u{E0001}', '\u{E0001}', BidiClass::BN),
+    ('\u{E0020}', '\u{E007F}', BidiClass::BN),
+    ('\u{E0100}', '\u{E01EF}', BidiClass::NSM),
+];
+
+/// Ranges of code points with a joining type relevant to the ContextJ rules.
+pub(super) static JOINING_TYPE: &[(char, char, JoiningType)] = &[
+    ('\u{AD}', '\u{AD}', JoiningType::T),
+    ('\u{300}', '\u{36F}', JoiningType::T),
+    ('\u{483}', '\u{489}', JoiningType::T),
+    ('\u{591}', '\u{5BD}', JoiningType::T),
+    ('\u{5BF}', '\u{5BF}', JoiningType::T),
+    ('\u{5C1}', '\u{5C2}', JoiningType::T),
+    ('\u{5C4}', '\u{5C5}', JoiningType::T),
+    ('\u{5C7}', '\u{5C7}', JoiningType::T),
+    ('\u{610}', '\u{61A}', JoiningType::T),
+    ('\u{61C}', '\u{61C}', JoiningType::T),
+    ('\u{620}', '\u{620}', JoiningType::D),
+    ('\u{622}', '\u{625}', JoiningType::R),
+    ('\u{626}', '\u{626}', JoiningType::D),
+    ('\u{627}', '\u{627}', JoiningType::R),
+    ('\u{628}', '\u{628}', JoiningType::D),
+    ('\u{629}', '\u{629}', JoiningType::R),
+    ('\u{62A}', '\u{62E}', JoiningType::D),
+    ('\u{62F}', '\u{632}', JoiningType::R),
+    ('\u{633}', '\u{63F}', JoiningType::D),
+    ('\u{641}', '\u{647}', JoiningType::D),
+    ('\u{648}', '\u{648}', JoiningType::R),
+    ('\u{649}', '\u{64A}', JoiningType::D),
+    ('\u{64B}', '\u{65F}', JoiningType::T),
+    ('\u{66E}', '\u{66F}', JoiningType::D),
+    ('\u{670}', '\u{670}', JoiningType::T),
+    ('\u{671}', '\u{673}', JoiningType::R),
+    ('\u{675}', '\u{677}', JoiningType::R),
+    ('\u{678}', '\u{687}', JoiningType::D),
+    ('\u{688}', '\u{699}', JoiningType::R),
+    ('\u{69A}', '\u{6BF}', JoiningType::D),
+    ('\u{6C0}', '\u{6C0}', JoiningType::R),
+    ('\u{6C1}', '\u{6C2}', JoiningType::D),
+    ('\u{6C3}', '\u{6CB}', JoiningType::R),
+    ('\u{6CC}', '\u{6CC}', JoiningType::D),
+    ('\u{6CD}', '\u{6CD}', JoiningType::R),
+    ('\u{6CE}', '\u{6CE}', JoiningType::D),
+    ('\u{6CF}', '\u{6CF}', JoiningType::R),
+    ('\u{6D0}', '\u{6D1}', JoiningType::D),
+    ('\u{6D2}', '\u{6D3}', JoiningType::R),
+    ('\u{6D5}', '\u{6D5}', JoiningType::R),
+    ('\u{6D6}', '\u{6DC}', JoiningType::T),
+    ('\u{6DF}', '\u{6E4}', JoiningType::T),
+    ('\u{6E7}', '\u{6E8}', JoiningType::T),
+    ('\u{6EA}', '\u{6ED}', JoiningType::T),
+    ('\u{6EE}', '\u{6EF}', JoiningType::R),
+    ('\u{6FA}', '\u{6FC}', JoiningType::D),
+    ('\u{6FF}', '\u{6FF}', JoiningType::D),
+    ('\u{70F}', '\u{70F}', JoiningType::T),
+    ('\u{710}', '\u{710}', JoiningType::R),
+    ('\u{711}', '\u{711}', JoiningType::T),
+    ('\u{712}', '\u{714}', JoiningType::D),
+    ('\u{715}', '\u{719}', JoiningType::R),
+    ('\u{71A}', '\u{71D}', JoiningType::D),
+    ('\u{71E}', '\u{71E}', JoiningType::R),
+    ('\u{71F}', '\u{727}', JoiningType::D),
+    ('\u{728}', '\u{728}', JoiningType::R),
+    ('\u{729}', '\u{729}', JoiningType::D),
+    ('\u{72A}', '\u{72A}', JoiningType::R),
+    ('\u{72B}', '\u{72B}', JoiningType::D),
+    ('\u{72C}', '\u{72C}', JoiningType::R),
+    ('\u{72D}', '\u{72E}', JoiningType::D),
+    ('\u{72F}', '\u{72F}', JoiningType::R),
+    ('\u{730}', '\u{74A}', JoiningType::T),
+    ('\u{74D}', '\u{74D}', JoiningType::R),
+    ('\u{74E}', '\u{758}', JoiningType::D),
+    ('\u{759}', '\u{75B}', JoiningType::R),
+    ('\u{75C}', '\u{76A}', JoiningType::D),
+    ('\u{76B}', '\u{76C}', JoiningType::R),
+    ('\u{76D}', '\u{770}', JoiningType::D),
+    ('\u{771}', '\u{771}', JoiningType::R),
+    ('\u{772}', '\u{772}', JoiningType::D),
+    ('\u{773}', '\u{774}', JoiningType::R),
+    ('\u{775}', '\u{777}', JoiningType::D),
+    ('\u{778}', '\u{779}', JoiningType::R),
+    ('\u{77A}', '\u{77F}', JoiningType::D),
+    ('\u{7A6}', '\u{7B0}', JoiningType::T),
+    ('\u{7CA}', '\u{7EA}', JoiningType::D),
+    ('\u{7EB}', '\u{7F3}', JoiningType::T),
+    ('\u{7FD}', '\u{7FD}', JoiningType::T),
+    ('\u{816}', '\u{819}', JoiningType::T),
+    ('\u{81B}', '\u{823}', JoiningType::T),
+    ('\u{825}', '\u{827}', JoiningType::T),
+    ('\u{829}', '\u{82D}', JoiningType::T),
+    ('\u{840}', '\u{840}', JoiningType::R),
+    ('\u{841}', '\u{845}', JoiningType::D),
+    ('\u{846}', '\u{847}', JoiningType::R),
+    ('\u{848}', '\u{848}', JoiningType::D),
+    ('\u{849}', '\u{849}', JoiningType::R),
+    ('\u{84A}', '\u{853}', JoiningType::D),
+    ('\u{854}', '\u{854}', JoiningType::R),
+    ('\u{855}', '\u{855}', JoiningType::D),
+    ('\u{856}', '\u{858}', JoiningType::R),
+    ('\u{859}', '\u{85B}', JoiningType::T),
+    ('\u{860}', '\u{860}', JoiningType::D),
+    ('\u{862}', '\u{865}', JoiningType::D),
+    ('\u{867}', '\u{867}', JoiningType::R),
+    ('\u{868}', '\u{868}', JoiningType::D),
+    ('\u{869}', '\u{86A}', JoiningType::R),
+    ('\u{870}', '\u{882}', JoiningType::R),
+    ('\u{886}', '\u{886}', JoiningType::D),
+    ('\u{889}', '\u{88D}', JoiningType::D),
+    ('\u{88E}', '\u{88E}', JoiningType::R),
+    ('\u{898}', '\u{89F}', JoiningType::T),
+    ('\u{8A0}', '\u{8A9}', JoiningType::D),
+    ('\u{8AA}', '\u{8AC}', JoiningType::R),
+    ('\u{8AE}', '\u{8AE}', JoiningType::R),
+    ('\u{8AF}', '\u{8B0}', JoiningType::D),
+    ('\u{8B1}', '\u{8B2}', JoiningType::R),
+    ('\u{8B3}', '\u{8B8}', JoiningType::D),
+    ('\u{8B9}', '\u{8B9}', JoiningType::R),
+    ('\u{8BA}', '\u{8C8}', JoiningType::D),
+    ('\u{8CA}', '\u{8E1}', JoiningType::T),
+    ('\u{8E3}', '\u{902}', JoiningType::T),
+    ('\u{93A}', '\u{93A}', JoiningType::T),
+    ('\u{93C}', '\u{93C}', JoiningType::T),
+    ('\u{941}', '\u{948}', JoiningType::T),
+    ('\u{94D}', '\u{94D}', JoiningType::T),
+    ('\u{951}', '\u{957}', JoiningType::T),
+    ('\u{962}', '\u{963}', JoiningType::T),
+    ('\u{981}', '\u{981}', JoiningType::T),
+    ('\u{9BC}', '\u{9BC}', JoiningType::T),
+    ('\u{9C1}', '\u{9C4}', JoiningType::T),
+    ('\u{9CD}', '\u{9CD}', JoiningType::T),
+    ('\u{9E2}', '\u{9E3}', JoiningType::T),
+    ('\u{9FE}', '\u{9FE}', JoiningType::T),
+    ('\u{A01}', '\u{A02}', JoiningType::T),
+    ('\u{A3C}', '\u{A3C}', JoiningType::T),
+    ('\u{A41}', '\u{A42}', JoiningType::T),
+    ('\u{A47}', '\u{A48}', JoiningType::T),
+    ('\u{A4B}', '\u{A4D}', JoiningType::T),
+    ('\u{A51}', '\u{A51}', JoiningType::T),
+    ('\u{A70}', '\u{A71}', JoiningType::T),
+    ('\u{A75}', '\u{A75}', JoiningType::T),
+    ('\u{A81}', '\u{A82}', JoiningType::T),
+    ('\u{ABC}', '\u{ABC}', JoiningType::T),
+    ('\u{AC1}', '\u{AC5}', JoiningType::T),
+    ('\u{AC7}', '\u{AC8}', JoiningType::T),
+    ('\u{ACD}', '\u{ACD}', JoiningType::T),
+    ('\u{AE2}', '\u{AE3}', JoiningType::T),
+    ('\u{AFA}', '\u{AFF}', JoiningType::T),
+    ('\u{B01}', '\u{B01}', JoiningType::T),
+    ('\u{B3C}', '\u{B3C}', JoiningType::T),
+    ('\u{B3F}', '\u{B3F}', JoiningType::T),
+    ('\u{B41}', '\u{B44}', JoiningType::T),
+    ('\u{B4D}', '\u{B4D}', JoiningType::T),
+    ('\u{B55}', '\u{B56}', JoiningType::T),
+    ('\u{B62}', '\u{B63}', JoiningType::T),
+    ('\u{B82}', '\u{B82}', JoiningType::T),
+    ('\u{BC0}', '\u{BC0}', JoiningType::T),
+    ('\u{BCD}', '\u{BCD}', JoiningType::T),
+    ('\u{C00}', '\u{C00}', JoiningType::T),
+    ('\u{C04}', '\u{C04}', JoiningType::T),
+    ('\u{C3C}', '\u{C3C}', JoiningType::T),
+    ('\u{C3E}', '\u{C40}', JoiningType::T),
+    ('\u{C46}', '\u{C48}', JoiningType::T),
+    ('\u{C4A}', '\u{C4D}', JoiningType::T),
+    ('\u{C55}', '\u{C56}', JoiningType::T),
+    ('\u{C62}', '\u{C63}', JoiningType::T),
+    ('\u{C81}', '\u{C81}', JoiningType::T),
+    ('\u{CBC}', '\u{CBC}', JoiningType::T),
+    ('\u{CBF}', '\u{CBF}', JoiningType::T),
+    ('\u{CC6}', '\u{CC6}', JoiningType::T),
+    ('\u{CCC}', '\u{CCD}', JoiningType::T),
+    ('\u{CE2}', '\u{CE3}', JoiningType::T),
+    ('\u{D00}', '\u{D01}', JoiningType::T),
+    ('\u{D3B}', '\u{D3C}', JoiningType::T),
+    ('\u{D41}', '\u{D44}', JoiningType::T),
+    ('\u{D4D}', '\u{D4D}', JoiningType::T),
+    ('\u{D62}', '\u{D63}', JoiningType::T),
+    ('\u{D81}', '\u{D81}', JoiningType::T),
+    ('\u{DCA}', '\u{DCA}', JoiningType::T),
+    ('\u{DD2}', '\u{DD4}', JoiningType::T),
+    ('\u{DD6}', '\u{DD6}', JoiningType::T),
+    ('\u{E31}', '\u{E31}', JoiningType::T),
+    ('\u{E34}', '\u{E3A}', JoiningType::T),
+    ('\u{E47}', '\u{E4E}', JoiningType::T),
+    ('\u{EB1}', '\u{EB1}', JoiningType::T),
+    ('\u{EB4}', '\u{EBC}', JoiningType::T),
+    ('\u{EC8}', '\u{ECE}', JoiningType::T),
+    ('\u{F18}', '\u{F19}', JoiningType::T),
+    ('\u{F35}', '\u{F35}', JoiningType::T),
+    ('\u{F37}', '\u{F37}', JoiningType::T),
+    ('\u{F39}', '\u{F39}', JoiningType::T),
+    ('\u{F71}', '\u{F7E}', JoiningType::T),
+    ('\u{F80}', '\u{F84}', JoiningType::T),
+    ('\u{F86}', '\u{F87}', JoiningType::T),
+    ('\u{F8D}', '\u{F97}', JoiningType::T),
+    ('\u{F99}', '\u{FBC}', JoiningType::T),
+    ('\u{FC6}', '\u{FC6}', JoiningType::T),
+    ('\u{102D}', '\u{1030}', JoiningType::T),
+    ('\u{1032}', '\u{1037}', JoiningType::T),
+    ('\u{1039}', '\u{103A}', JoiningType::T),
+    ('\u{103D}', '\u{103E}', JoiningType::T),
+    ('\u{1058}', '\u{1059}', JoiningType::T),
+    ('\u{105E}', '\u{1060}', JoiningType::T),
+    ('\u{1071}', '\u{1074}', JoiningType::T),
+    ('\u{1082}', '\u{1082}', JoiningType::T),
+    ('\u{1085}', '\u{1086}', JoiningType::T),
+    ('\u{108D}', '\u{108D}', JoiningType::T),
+    ('\u{109D}', '\u{109D}', JoiningType::T),
+    ('\u{135D}', '\u{135F}', JoiningType::T),
+    ('\u{1712}', '\u{1714}', JoiningType::T),
+    ('\u{1732}', '\u{1733}', JoiningType::T),
+    ('\u{1752}', '\u{1753}', JoiningType::T),
+    ('\u{1772}', '\u{1773}', JoiningType::T),
+    ('\u{17B4}', '\u{17B5}', JoiningType::T),
+    ('\u{17B7}', '\u{17BD}', JoiningType::T),
+    ('\u{17C6}', '\u{17C6}', JoiningType::T),
+    ('\u{17C9}', '\u{17D3}', JoiningType::T),
+    ('\u{17DD}', '\u{17DD}', JoiningType::T),
+    ('\u{1807}', '\u{1807}', JoiningType::D),
+    ('\u{180B}', '\u{180D}', JoiningType::T),
+    ('\u{180F}', '\u{180F}', JoiningType::T),
+    ('\u{1820}', '\u{1878}', JoiningType::D),
+    ('\u{1885}', '\u{1886}', JoiningType::T),
+    ('\u{1887}', '\u{18A8}', JoiningType::D),
+    ('\u{18A9}', '\u{18A9}', JoiningType::T),
+    ('\u{18AA}', '\u{18AA}', JoiningType::D),
+    ('\u{1920}', '\u{1922}', JoiningType::T),
+    ('\u{1927}', '\u{1928}', JoiningType::T),
+    ('\u{1932}', '\u{1932}', JoiningType::T),
+    ('\u{1939}', '\u{193B}', JoiningType::T),
+    ('\u{1A17}', '\u{1A18}', JoiningType::T),
+    ('\u{1A1B}', '\u{1A1B}', JoiningType::T),
+    ('\u{1A56}', '\u{1A56}', JoiningType::T),
+    ('\u{1A58}', '\u{1A5E}', JoiningType::T),
+    ('\u{1A60}', '\u{1A60}', JoiningType::T),
+    ('\u{1A62}', '\u{1A62}', JoiningType::T),
+    ('\u{1A65}', '\u{1A6C}', JoiningType::T),
+    ('\u{1A73}', '\u{1A7C}', JoiningType::T),
+    ('\u{1A7F}', '\u{1A7F}', JoiningType::T),
+    ('\u{1AB0}', '\u{1ACE}', JoiningType::T),
+    ('\u{1B00}', '\u{1B03}', JoiningType::T),
+    ('\u{1B34}', '\u{1B34}', JoiningType::T),
+    ('\u{1B36}', '\u{1B3A}', JoiningType::T),
+    ('\u{1B3C}', '\u{1B3C}', JoiningType::T),
+    ('\u{1B42}', '\u{1B42}', JoiningType::T),
+    ('\u{1B6B}', '\u{1B73}', JoiningType::T),
+    ('\u{1B80}', '\u{1B81}', JoiningType::T),
+    ('\u{1BA2}', '\u{1BA5}', JoiningType::T),
+    ('\u{1BA8}', '\u{1BA9}', JoiningType::T),
+    ('\u{1BAB}', '\u{1BAD}', JoiningType::T),
+    ('\u{1BE6}', '\u{1BE6}', JoiningType::T),
+    ('\u{1BE8}', '\u{1BE9}', JoiningType::T),
+    ('\u{1BED}', '\u{1BED}', JoiningType::T),
+    ('\u{1BEF}', '\u{1BF1}', JoiningType::T),
+    ('\u{1C2C}', '\u{1C33}', JoiningType::T),
+    ('\u{1C36}', '\u{1C37}', JoiningType::T),
+    ('\u{1CD0}', '\u{1CD2}', JoiningType::T),
+    ('\u{1CD4}', '\u{1CE0}', JoiningType::T),
+    ('\u{1CE2}', '\u{1CE8}', JoiningType::T),
+    ('\u{1CED}', '\u{1CED}', JoiningType::T),
+    ('\u{1CF4}', '\u{1CF4}', JoiningType::T),
+    ('\u{1CF8}', '\u{1CF9}', JoiningType::T),
+    ('\u{1DC0}', '\u{1DFF}', JoiningType::T),
+    ('\u{200B}', '\u{200B}', JoiningType::T),
+    ('\u{200E}', '\u{200F}', JoiningType::T),
+    ('\u{202A}', '\u{202E}', JoiningType::T),
+    ('\u{2060}', '\u{2064}', JoiningType::T),
+    ('\u{206A}', '\u{206F}', JoiningType::T),
+    ('\u{20D0}', '\u{20F0}', JoiningType::T),
+    ('\u{2CEF}', '\u{2CF1}', JoiningType::T),
+    ('\u{2D7F}', '\u{2D7F}', JoiningType::T),
+    ('\u{2DE0}', '\u{2DFF}', JoiningType::T),
+    ('\u{302A}', '\u{302D}', JoiningType::T),
+    ('\u{3099}', '\u{309A}', JoiningType::T),
+    ('\u{A66F}', '\u{A672}', JoiningType::T),
+    ('\u{A674}', '\u{A67D}', JoiningType::T),
+    ('\u{A69E}', '\u{A69F}', JoiningType::T),
+    ('\u{A6F0}', '\u{A6F1}', JoiningType::T),
+    ('\u{A802}', '\u{A802}', JoiningType::T),
+    ('\u{A806}', '\u{A806}', JoiningType::T),
+    ('\u{A80B}', '\u{A80B}', JoiningType::T),
+    ('\u{A825}', '\u{A826}', JoiningType::T),
+    ('\u{A82C}', '\u{A82C}', JoiningType::T),
+    ('\u{A840}', '\u{A871}', JoiningType::D),
+    ('\u{A872}', '\u{A872}', JoiningType::L),
+    ('\u{A8C4}', '\u{A8C5}', JoiningType::T),
+    ('\u{A8E0}', '\u{A8F1}', JoiningType::T),
+    ('\u{A8FF}', '\u{A8FF}', JoiningType::T),
+    ('\u{A926}', '\u{A92D}', JoiningType::T),
+    ('\u{A947}', '\u{A951}', JoiningType::T),
+    ('\u{A980}', '\u{A982}', JoiningType::T),
+    ('\u{A9B3}', '\u{A9B3}', JoiningType::T),
+    ('\u{A9B6}', '\u{A9B9}', JoiningType::T),
+    ('\u{A9BC}', '\u{A9BD}', JoiningType::T),
+    ('\u{A9E5}', '\u{A9E5}', JoiningType::T),
+    ('\u{AA29}', '\u{AA2E}', JoiningType::T),
+    ('\u{AA31}', '\u{AA32}', JoiningType::T),
+    ('\u{AA35}', '\u{AA36}', JoiningType::T),
+    ('\u{AA43}', '\u{AA43}', JoiningType::T),
+    ('\u{AA4C}', '\u{AA4C}', JoiningType::T),
+    ('\u{AA7C}', '\u{AA7C}', JoiningType::T),
+    ('\u{AAB0}', '\u{AAB0}', JoiningType::T),
+    ('\u{AAB2}', '\u{AAB4}', JoiningType::T),
+    ('\u{AAB7}', '\u{AAB8}', JoiningType::T),
+    ('\u{AABE}', '\u{AABF}', JoiningType::T),
+    ('\u{AAC1}', '\u{AAC1}', JoiningType::T),
+    ('\u{AAEC}', '\u{AAED}', JoiningType::T),
+    ('\u{AAF6}', '\u{AAF6}', JoiningType::T),
+    ('\u{ABE5}', '\u{ABE5}', JoiningType::T),
+    ('\u{ABE8}', '\u{ABE8}', JoiningType::T),
+    ('\u{ABED}', '\u{ABED}', JoiningType::T),
+    ('\u{FB1E}', '\u{FB1E}', JoiningType::T),
+    ('\u{FE00}', '\u{FE0F}', JoiningType::T),
+    ('\u{FE20}', '\u{FE2F}', JoiningType::T),
+    ('\u{FEFF}', '\u{FEFF}', JoiningType::T),
+    ('\u{FFF9}', '\u{FFFB}', JoiningType::T),
+    ('\u{101FD}', '\u{101FD}', JoiningType::T),
+    ('\u{102E0}', '\u{102E0}', JoiningType::T),
+    ('\u{10376}', '\u{1037A}', JoiningType::T),
+    ('\u{10A01}', '\u{10A03}', JoiningType::T),
+    ('\u{10A05}', '\u{10A06}', JoiningType::T),
+    ('\u{10A0C}', '\u{10A0F}', JoiningType::T),
+    ('\u{10A38}', '\u{10A3A}', JoiningType::T),
+    ('\u{10A3F}', '\u{10A3F}', JoiningType::T),
+    ('\u{10AC0}', '\u{10AC4}', JoiningType::D),
+    ('\u{10AC5}', '\u{10AC5}', JoiningType::R),
+    ('\u{10AC7}', '\u{10AC7}', JoiningType::R),
+    ('\u{10AC9}', '\u{10ACA}', JoiningType::R),
+    ('\u{10ACD}', '\u{10ACD}', JoiningType::L),
+    ('\u{10ACE}', '\u{10AD2}', JoiningType::R),
+    ('\u{10AD3}', '\u{10AD6}', JoiningType::D),
+    ('\u{10AD7}', '\u{10AD7}', JoiningType::L),
+    ('\u{10AD8}', '\u{10ADC}', JoiningType::D),
+    ('\u{10ADD}', '\u{10ADD}', JoiningType::R),
+    ('\u{10ADE}', '\u{10AE0}', JoiningType::D),
+    ('\u{10AE1}', '\u{10AE1}', JoiningType::R),
+    ('\u{10AE4}', '\u{10AE4}', JoiningType::R),
+    ('\u{10AE5}', '\u{10AE6}', JoiningType::T),
+    ('\u{10AEB}', '\u{10AEE}', JoiningType::D),
+    ('\u{10AEF}', '\u{10AEF}', JoiningType::R),
+    ('\u{10B80}', '\u{10B80}', JoiningType::D),
+    ('\u{10B81}', '\u{10B81}', JoiningType::R),
+    ('\u{10B82}', '\u{10B82}', JoiningType::D),
+    ('\u{10B83}', '\u{10B85}', JoiningType::R),
+    ('\u{10B86}', '\u{10B88}', JoiningType::D),
+    ('\u{10B89}', '\u{10B89}', JoiningType::R),
+    ('\u{10B8A}', '\u{10B8B}', JoiningType::D),
+    ('\u{10B8C}', '\u{10B8C}', JoiningType::R),
+    ('\u{10B8D}', '\u{10B8D}', JoiningType::D),
+    ('\u{10B8E}', '\u{10B8F}', JoiningType::R),
+    ('\u{10B90}', '\u{10B90}', JoiningType::D),
+    ('\u{10B91}', '\u{10B91}', JoiningType::R),
+    ('\u{10BA9}', '\u{10BAC}', JoiningType::R),
+    ('\u{10BAD}', '\u{10BAE}', JoiningType::D),
+    ('\u{10D00}', '\u{10D00}', JoiningType::L),
+    ('\u{10D01}', '\u{10D21}', JoiningType::D),
+    ('\u{10D22}', '\u{10D22}', JoiningType::R),
+    ('\u{10D23}', '\u{10D23}', JoiningType::D),
+    ('\u{10D24}', '\u{10D27}', JoiningType::T),
+    ('\u{10EAB}', '\u{10EAC}', JoiningType::T),
+    ('\u{10EFD}', '\u{10EFF}', JoiningType::T),
+    ('\u{10F30}', '\u{10F32}', JoiningType::D),
+    ('\u{10F33}', '\u{10F33}', JoiningType::R),
+    ('\u{10F34}', '\u{10F44}', JoiningType::D),
+    ('\u{10F46}', '\u{10F50}', JoiningType::T),
+    ('\u{10F51}', '\u{10F53}', JoiningType::D),
+    ('\u{10F54}', '\u{10F54}', JoiningType::R),
+    ('\u{10F70}', '\u{10F73}', JoiningType::D),
+    ('\u{10F74}', '\u{10F75}', JoiningType::R),
+    ('\u{10F76}', '\u{10F81}', JoiningType::D),
+    ('\u{10F82}', '\u{10F85}', JoiningType::T),
+    ('\u{10FB0}', '\u{10FB0}', JoiningType::D),
+    ('\u{10FB2}', '\u{10FB3}', JoiningType::D),
+    ('\u{10FB4}', '\u{10FB6}', JoiningType::R),
+    ('\u{10FB8}', '\u{10FB8}', JoiningType::D),
+    ('\u{10FB9}', '\u{10FBA}', JoiningType::R),
+    ('\u{10FBB}', '\u{10FBC}', JoiningType::D),
+    ('\u{10FBD}', '\u{10FBD}', JoiningType::R),
+    ('\u{10FBE}', '\u{10FBF}', JoiningType::D),
+    ('\u{10FC1}', '\u{10FC1}', JoiningType::D),
+    ('\u{10FC2}', '\u{10FC3}', JoiningType::R),
+    ('\u{10FC4}', '\u{10FC4}', JoiningType::D),
+    ('\u{10FC9}', '\u{10FC9}', JoiningType::R),
+    ('\u{10FCA}', '\u{10FCA}', JoiningType::D),
+    ('\u{10FCB}', '\u{10FCB}', JoiningType::L),
+    ('\u{11001}', '\u{11001}', JoiningType::T),
+    ('\u{11038}', '\u{11046}', JoiningType::T),
+    ('\u{11070}', '\u{11070}', JoiningType::T),
+    ('\u{11073}', '\u{11074}', JoiningType::T),
+    ('\u{1107F}', '\u{11081}', JoiningType::T),
+    ('\u{110B3}', '\u{110B6}', JoiningType::T),
+    ('\u{110B9}', '\u{110BA}', JoiningType::T),
+    ('\u{110C2}', '\u{110C2}', JoiningType::T),
+    ('\u{11100}', '\u{11102}', JoiningType::T),
+    ('\u{11127}', '\u{1112B}', JoiningType::T),
+    ('\u{1112D}', '\u{11134}', JoiningType::T),
+    ('\u{11173}', '\u{11173}', JoiningType::T),
+    ('\u{11180}', '\u{11181}', JoiningType::T),
+    ('\u{111B6}', '\u{111BE}', JoiningType::T),
+    ('\u{111C9}', '\u{111CC}', JoiningType::T),
+    ('\u{111CF}', '\u{111CF}', JoiningType::T),
+    ('\u{1122F}', '\u{11231}', JoiningType::T),
+    ('\u{11234}', '\u{11234}', JoiningType::T),
+    ('\u{11236}', '\u{11237}', JoiningType::T),
+    ('\u{1123E}', '\u{1123E}', JoiningType::T),
+    ('\u{11241}', '\u{11241}', JoiningType::T),
+    ('\u{112DF}', '\u{112DF}', JoiningType::T),
+    ('\u{112E3}', '\u{112EA}', JoiningType::T),
+    ('\u{11300}', '\u{11301}', JoiningType::T),
+    ('\u{1133B}', '\u{1133C}', JoiningType::T),
+    ('\u{11340}', '\u{11340}', JoiningType::T),
+    ('\u{11366}', '\u{1136C}', JoiningType::T),
+    ('\u{11370}', '\u{11374}', JoiningType::T),
+    ('\u{11438}', '\u{1143F}', JoiningType::T),
+    ('\u{11442}', '\u{11444}', JoiningType::T),
+    ('\u{11446}', '\u{11446}', JoiningType::T),
+    ('\u{1145E}', '\u{1145E}', JoiningType::T),
+    ('\u{114B3}', '\u{114B8}', JoiningType::T),
+    ('\u{114BA}', '\u{114BA}', JoiningType::T),
+    ('\u{114BF}', '\u{114C0}', JoiningType::T),
+    ('\u{114C2}', '\u{114C3}', JoiningType::T),
+    ('\u{115B2}', '\u{115B5}', JoiningType::T),
+    ('\u{115BC}', '\u{115BD}', JoiningType::T),
+    ('\u{115BF}', '\u{115C0}', JoiningType::T),
+    ('\u{115DC}', '\u{115DD}', JoiningType::T),
+    ('\u{11633}', '\u{1163A}', JoiningType::T),
+    ('\u{1163D}', '\u{1163D}', JoiningType::T),
+    ('\u{1163F}', '\u{11640}', JoiningType::T),
+    ('\u{116AB}', '\u{116AB}', JoiningType::T),
+    ('\u{116AD}', '\u{116AD}', JoiningType::T),
+    ('\u{116B0}', '\u{116B5}', JoiningType::T),
+    ('\u{116B7}', '\u{116B7}', JoiningType::T),
+    ('\u{1171D}', '\u{1171F}', JoiningType::T),
+    ('\u{11722}', '\u{11725}', JoiningType::T),
+    ('\u{11727}', '\u{1172B}', JoiningType::T),
+    ('\u{1182F}', '\u{11837}', JoiningType::T),
+    ('\u{11839}', '\u{1183A}', JoiningType::T),
+    ('\u{1193B}', '\u{1193C}', JoiningType::T),
+    ('\u{1193E}', '\u{1193E}', JoiningType::T),
+    ('\u{11943}', '\u{11943}', JoiningType::T),
+    ('\u{119D4}', '\u{119D7}', JoiningType::T),
+    ('\u{119DA}', '\u{119DB}', JoiningType::T),
+    ('\u{119E0}', '\u{119E0}', JoiningType::T),
+    ('\u{11A01}', '\u{11A0A}', JoiningType::T),
+    ('\u{11A33}', '\u{11A38}', JoiningType::T),
+    ('\u{11A3B}', '\u{11A3E}', JoiningType::T),
+    ('\u{11A47}', '\u{11A47}', JoiningType::T),
+    ('\u{11A51}', '\u{11A56}', JoiningType::T),
+    ('\u{11A59}', '\u{11A5B}', JoiningType::T),
+    ('\u{11A8A}', '\u{11A96}', JoiningType::T),
+    ('\u{11A98}', '\u{11A99}', JoiningType::T),
+    ('\u{11C30}', '\u{11C36}', JoiningType::T),
+    ('\u{11C38}', '\u{11C3D}', JoiningType::T),
+    ('\u{11C3F}', '\u{11C3F}', JoiningType::T),
+    ('\u{11C92}', '\u{11CA7}', JoiningType::T),
+    ('\u{11CAA}', '\u{11CB0}', JoiningType::T),
+    ('\u{11CB2}', '\u{11CB3}', JoiningType::T),
+    ('\u{11CB5}', '\u{11CB6}', JoiningType::T),
+    ('\u{11D31}', '\u{11D36}', JoiningType::T),
+    ('\u{11D3A}', '\u{11D3A}', JoiningType::T),
+    ('\u{11D3C}', '\u{11D3D}', JoiningType::T),
+    ('\u{11D3F}', '\u{11D45}', JoiningType::T),
+    ('\u{11D47}', '\u{11D47}', JoiningType::T),
+    ('\u{11D90}', '\u{11D91}', JoiningType::T),
+    ('\u{11D95}', '\u{11D95}', JoiningType::T),
+    ('\u{11D97}', '\u{11D97}', JoiningType::T),
+    ('\u{11EF3}', '\u{11EF4}', JoiningType::T),
+    ('\u{11F00}', '\u{11F01}', JoiningType::T),
+    ('\u{11F36}', '\u{11F3A}', JoiningType::T),
+    ('\u{11F40}', '\u{11F40}', JoiningType::T),
+    ('\u{11F42}', '\u{11F42}', JoiningType::T),
+    ('\u{13430}', '\u{13440}', JoiningType::T),
+    ('\u{13447}', '\u{13455}', JoiningType::T),
+    ('\u{16AF0}', '\u{16AF4}', JoiningType::T),
+    ('\u{16B30}', '\u{16B36}', JoiningType::T),
+    ('\u{16F4F}', '\u{16F4F}', JoiningType::T),
+    ('\u{16F8F}', '\u{16F92}', JoiningType::T),
+    ('\u{16FE4}', '\u{16FE4}', JoiningType::T),
+    ('\u{1BC9D}', '\u{1BC9E}', JoiningType::T),
+    ('\u{1BCA0}', '\u{1BCA3}', JoiningType::T),
+    ('\u{1CF00}', '\u{1CF2D}', JoiningType::T),
+    ('\u{1CF30}', '\u{1CF46}', JoiningType::T),
+    ('\u{1D167}', '\u{1D169}', JoiningType::T),
+    ('\u{1D173}', '\u{1D182}', JoiningType::T),
+    ('\u{1D185}', '\u{1D18B}', JoiningType::T),
+    ('\u{1D1AA}', '\u{1D1AD}', JoiningType::T),
+    ('\u{1D242}', '\u{1D244}', JoiningType::T),
+    ('\u{1DA00}', '\u{1DA36}', JoiningType::T),
+    ('\u{1DA3B}', '\u{1DA6C}', JoiningType::T),
+    ('\u{1DA75}', '\u{1DA75}', JoiningType::T),
+    ('\u{1DA84}', '\u{1DA84}', JoiningType::T),
+    ('\u{1DA9B}', '\u{1DA9F}', JoiningType::T),
+    ('\u{1DAA1}', '\u{1DAAF}', JoiningType::T),
+    ('\u{1E000}', '\u{1E006}', JoiningType::T),
+    ('\u{1E008}', '\u{1E018}', JoiningType::T),
+    ('\u{1E01B}', '\u{1E021}', JoiningType::T),
+    ('\u{1E023}', '\u{1E024}', JoiningType::T),
+    ('\u{1E026}', '\u{1E02A}', JoiningType::T),
+    ('\u{1E08F}', '\u{1E08F}', JoiningType::T),
+    ('\u{1E130}', '\u{1E136}', JoiningType::T),
+    ('\u{1E2AE}', '\u{1E2AE}', JoiningType::T),
+    ('\u{1E2EC}', '\u{1E2EF}', JoiningType::T),
+    ('\u{1E4EC}', '\u{1E4EF}', JoiningType::T),
+    ('\u{1E8D0}', '\u{1E8D6}', JoiningType::T),
+    ('\u{1E900}', '\u{1E943}', JoiningType::D),
+    ('\u{1E944}', '\u{1E94B}', JoiningType::T),
+    ('\u{E0001}', '\u{E0001}', JoiningType::T),
+    ('\u{E0020}', '\u{E007F}', JoiningType::T),
+    ('\u{E0100}', '\u{E01EF}', JoiningType::T),
+];
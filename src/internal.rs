@@ -1,6 +1,6 @@
 #![allow(missing_debug_implementations)]
 
-use crate::{error::ParseError, parser, Uri};
+use crate::{error::ParseError, parser, Iri, Uri};
 use alloc::string::String;
 use core::{num::NonZeroUsize, ops, str};
 
@@ -12,6 +12,7 @@ pub trait Value: Default {}
 impl Value for &str {}
 impl Value for String {}
 
+#[derive(Clone, Copy)]
 pub struct NoInput;
 
 pub trait ToUri {
@@ -27,7 +28,7 @@ impl<'a> ToUri for &'a str {
 
     #[inline]
     fn to_uri(self) -> Result<Uri<Self::Val>, Self::Err> {
-        let meta = parser::parse(self.as_bytes())?;
+        let meta = parser::parse::<false>(self.as_bytes())?;
         Ok(Uri { val: self, meta })
     }
 }
@@ -38,13 +39,44 @@ impl ToUri for String {
 
     #[inline]
     fn to_uri(self) -> Result<Uri<Self::Val>, Self::Err> {
-        match parser::parse(self.as_bytes()) {
+        match parser::parse::<false>(self.as_bytes()) {
             Ok(meta) => Ok(Uri { val: self, meta }),
             Err(e) => Err(e.with_input(self)),
         }
     }
 }
 
+pub trait ToIri {
+    type Val: Value;
+    type Err;
+
+    fn to_iri(self) -> Result<Iri<Self::Val>, Self::Err>;
+}
+
+impl<'a> ToIri for &'a str {
+    type Val = &'a str;
+    type Err = ParseError;
+
+    #[inline]
+    fn to_iri(self) -> Result<Iri<Self::Val>, Self::Err> {
+        let meta = parser::parse::<true>(self.as_bytes())?;
+        Ok(Iri::new(self, meta))
+    }
+}
+
+impl ToIri for String {
+    type Val = String;
+    type Err = ParseError<String>;
+
+    #[inline]
+    fn to_iri(self) -> Result<Iri<Self::Val>, Self::Err> {
+        match parser::parse::<true>(self.as_bytes()) {
+            Ok(meta) => Ok(Iri::new(self, meta)),
+            Err(e) => Err(e.with_input(self)),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Default)]
 pub struct Meta {
     // The index of the trailing colon.
@@ -0,0 +1,471 @@
+use crate::{
+    component::{Authority, Scheme},
+    encoding::{
+        decode_octet,
+        encoder::{IFragment, IPath, IQuery, IRegName, IUserinfo},
+        table, EStr, Table,
+    },
+    error::ParseError,
+    internal::{HostMeta, Meta, ToIri, Value},
+    Uri,
+};
+use alloc::string::String;
+use borrow_or_share::{BorrowOrShare, Bos};
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    hash,
+    num::NonZeroUsize,
+    str::{self, FromStr},
+};
+
+/// An [IRI reference] defined in RFC 3987.
+///
+/// [IRI reference]: https://datatracker.ietf.org/doc/html/rfc3987/#section-2.2
+///
+/// An IRI reference is a URI reference that may additionally contain
+/// non-ASCII characters: [`ucschar`]s in any component except the scheme
+/// and the port, and [`iprivate`]s in the query.
+///
+/// [`ucschar`]: crate::encoding::Table::allows_ucschar
+/// [`iprivate`]: crate::encoding::Table::allows_iprivate
+///
+/// # Variants
+///
+/// Two variants of `Iri` are available: `Iri<&str>` (borrowed) and `Iri<String>` (owned).
+/// See the documentation of [`Uri`] for more details.
+///
+/// # Comparison
+///
+/// `Iri`s are compared [lexicographically](Ord#lexicographical-comparison)
+/// by their byte values. Normalization is **not** performed prior to comparison.
+///
+/// # Examples
+///
+/// Parse and extract components from an IRI reference:
+///
+/// ```
+/// use fluent_uri::Iri;
+///
+/// let iri = Iri::parse("http://用户@例子.中国/路径?查询#片段")?;
+///
+/// assert_eq!(iri.scheme().unwrap().as_str(), "http");
+/// let auth = iri.authority().unwrap();
+/// assert_eq!(auth.userinfo().unwrap(), "用户");
+/// assert_eq!(auth.host(), "例子.中国");
+/// assert_eq!(iri.path(), "/路径");
+/// assert_eq!(iri.query().unwrap(), "查询");
+/// assert_eq!(iri.fragment().unwrap(), "片段");
+/// # Ok::<_, fluent_uri::error::ParseError>(())
+/// ```
+///
+/// Convert between `Iri` and [`Uri`]:
+///
+/// ```
+/// use fluent_uri::{Iri, Uri};
+///
+/// let iri = Iri::parse("http://résumé.example.org/?q=café")?;
+/// let uri = iri.to_uri();
+/// assert_eq!(uri, "http://r%C3%A9sum%C3%A9.example.org/?q=caf%C3%A9");
+/// assert_eq!(uri.to_iri(), iri);
+/// # Ok::<_, fluent_uri::error::ParseError>(())
+/// ```
+#[derive(Clone, Copy)]
+pub struct Iri<T> {
+    /// The IRI reference laid out as a `Uri`, whose value
+    /// may contain non-ASCII characters.
+    ///
+    /// This field must never be exposed as a `Uri`.
+    inner: Uri<T>,
+}
+
+impl<T> Iri<T> {
+    pub(crate) fn new(val: T, meta: Meta) -> Self {
+        Iri {
+            inner: Uri { val, meta },
+        }
+    }
+
+    /// Parses an IRI reference from a string into an `Iri`.
+    ///
+    /// The return type is
+    ///
+    /// - `Result<Iri<&str>, ParseError>` for `I = &str`;
+    /// - `Result<Iri<String>, ParseError<String>>` for `I = String`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the string does not match
+    /// the [`IRI-reference`] ABNF rule from RFC 3987.
+    ///
+    /// From a [`ParseError<String>`], you may recover or strip the input
+    /// by calling [`into_input`] or [`strip_input`] on it.
+    ///
+    /// [`IRI-reference`]: https://datatracker.ietf.org/doc/html/rfc3987/#section-2.2
+    /// [`into_input`]: ParseError::into_input
+    /// [`strip_input`]: ParseError::strip_input
+    pub fn parse<I>(input: I) -> Result<Self, I::Err>
+    where
+        I: ToIri<Val = T>,
+    {
+        input.to_iri()
+    }
+}
+
+impl Iri<String> {
+    /// Borrows this `Iri<String>` as `Iri<&str>`.
+    #[allow(clippy::should_implement_trait)]
+    #[inline]
+    #[must_use]
+    pub fn borrow(&self) -> Iri<&str> {
+        Iri {
+            inner: self.inner.borrow(),
+        }
+    }
+
+    /// Consumes this `Iri<String>` and yields the underlying [`String`].
+    #[inline]
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.inner.val
+    }
+}
+
+impl Iri<&str> {
+    /// Creates a new `Iri<String>` by cloning the contents of this `Iri<&str>`.
+    #[inline]
+    #[must_use]
+    pub fn to_owned(&self) -> Iri<String> {
+        Iri {
+            inner: self.inner.to_owned(),
+        }
+    }
+}
+
+impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Iri<T> {
+    /// Returns the IRI reference as a string slice.
+    #[must_use]
+    pub fn as_str(&'i self) -> &'o str {
+        self.inner.as_str()
+    }
+
+    /// Returns the optional [scheme] component.
+    ///
+    /// Note that the scheme component is *case-insensitive*.
+    /// See the documentation of [`Scheme`] for more details on comparison.
+    ///
+    /// [scheme]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.1
+    #[must_use]
+    pub fn scheme(&'i self) -> Option<&'o Scheme> {
+        self.inner.scheme()
+    }
+
+    /// Returns the optional [authority] component.
+    ///
+    /// [authority]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.2
+    #[must_use]
+    pub fn authority(&self) -> Option<&Authority<T, IUserinfo, IRegName>> {
+        if self.inner.auth_meta.is_some() {
+            Some(Authority::new(&self.inner))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the [path] component.
+    ///
+    /// The path component is always present, although it may be empty.
+    ///
+    /// [path]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.3
+    #[must_use]
+    pub fn path(&'i self) -> &'o EStr<IPath> {
+        self.inner
+            .eslice(self.inner.path_bounds.0, self.inner.path_bounds.1)
+    }
+
+    /// Returns the optional [query] component.
+    ///
+    /// [query]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.4
+    #[must_use]
+    pub fn query(&'i self) -> Option<&'o EStr<IQuery>> {
+        self.inner
+            .query_end
+            .map(|i| self.inner.eslice(self.inner.path_bounds.1 + 1, i.get()))
+    }
+
+    /// Returns the optional [fragment] component.
+    ///
+    /// [fragment]: https://datatracker.ietf.org/doc/html/rfc3986/#section-3.5
+    #[must_use]
+    pub fn fragment(&'i self) -> Option<&'o EStr<IFragment>> {
+        self.inner
+            .fragment_start()
+            .map(|i| self.inner.eslice(i, self.inner.len()))
+    }
+
+    /// Checks whether the IRI reference is a [relative reference],
+    /// i.e., without a scheme.
+    ///
+    /// [relative reference]: https://datatracker.ietf.org/doc/html/rfc3987/#section-2.2
+    #[must_use]
+    pub fn is_relative_reference(&self) -> bool {
+        self.inner.is_relative_reference()
+    }
+
+    /// Checks whether the IRI reference is an [absolute IRI], i.e.,
+    /// with a scheme and without a fragment.
+    ///
+    /// [absolute IRI]: https://datatracker.ietf.org/doc/html/rfc3987/#section-2.2
+    #[must_use]
+    pub fn is_absolute_iri(&self) -> bool {
+        self.inner.is_absolute_uri()
+    }
+
+    /// Converts the IRI reference to a URI reference by percent-encoding
+    /// every non-ASCII character, as described in
+    /// [Section 3.1 of RFC 3987](https://datatracker.ietf.org/doc/html/rfc3987/#section-3.1).
+    ///
+    /// The conversion is lossless: each non-ASCII character is replaced by
+    /// the percent-encoded octets of its UTF-8 encoding with uppercase
+    /// hexadecimal digits, and everything else is preserved as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::Iri;
+    ///
+    /// let iri = Iri::parse("http://example.com/ほげ?ふが")?;
+    /// assert_eq!(
+    ///     iri.to_uri(),
+    ///     "http://example.com/%E3%81%BB%E3%81%92?%E3%81%B5%E3%81%8C"
+    /// );
+    /// # Ok::<_, fluent_uri::error::ParseError>(())
+    /// ```
+    #[must_use]
+    pub fn to_uri(&self) -> Uri<String> {
+        let (val, meta) = map_components(self.as_ref(), |buf, s, _| {
+            for &x in s.as_bytes() {
+                if x.is_ascii() {
+                    buf.push(x as char);
+                } else {
+                    table::push_pct_encoded(buf, x);
+                }
+            }
+        });
+        Uri { val, meta }
+    }
+}
+
+impl<T: Bos<str>> Iri<T> {
+    fn as_ref(&self) -> Iri<&str> {
+        Iri {
+            inner: self.inner.as_ref(),
+        }
+    }
+}
+
+/// Converts a URI reference to an IRI reference by decoding every sequence of
+/// percent-encoded octets that forms the UTF-8 encoding of a character
+/// allowed in its component.
+pub(crate) fn uri_to_iri(u: Uri<&str>) -> Iri<String> {
+    let (val, meta) = map_components(Iri { inner: u }, |buf, s, table| {
+        let bytes = s.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] != b'%' {
+                buf.push_str(&s[i..=i]);
+                i += 1;
+                continue;
+            }
+            match decode_char(&bytes[i..]) {
+                // Bidi formatting characters must not appear in an IRI.
+                Some((ch, len))
+                    if table.allows_code_point(ch as u32)
+                        && !matches!(ch, '\u{200e}' | '\u{200f}' | '\u{202a}'..='\u{202e}') =>
+                {
+                    buf.push(ch);
+                    i += len;
+                }
+                _ => {
+                    buf.push_str(&s[i..i + 3]);
+                    i += 3;
+                }
+            }
+        }
+    });
+    Iri::new(val, meta)
+}
+
+/// Decodes a character from the percent-encoded octets at the start of `s`
+/// and returns it along with the number of bytes it takes up in `s`.
+fn decode_char(s: &[u8]) -> Option<(char, usize)> {
+    let len = match decode_octet(s[1], s[2]) {
+        0xc2..=0xdf => 2,
+        0xe0..=0xef => 3,
+        0xf0..=0xf4 => 4,
+        _ => return None,
+    };
+
+    let mut octets = [0; 4];
+    for (j, octet) in octets[..len].iter_mut().enumerate() {
+        match s.get(j * 3..j * 3 + 3) {
+            Some(&[b'%', hi, lo]) => *octet = decode_octet(hi, lo),
+            _ => return None,
+        }
+    }
+
+    let ch = str::from_utf8(&octets[..len]).ok()?.chars().next()?;
+    Some((ch, len * 3))
+}
+
+/// Rebuilds an IRI reference, mapping the contents of every component
+/// that may contain non-ASCII characters with `f`.
+///
+/// The table passed to `f` is the one of the component being mapped.
+fn map_components(iri: Iri<&str>, mut f: impl FnMut(&mut String, &str, &Table)) -> (String, Meta) {
+    let mut buf = String::with_capacity(iri.as_str().len());
+    let mut meta = Meta::default();
+
+    if let Some(scheme) = iri.scheme() {
+        buf.push_str(scheme.as_str());
+        meta.scheme_end = NonZeroUsize::new(buf.len());
+        buf.push(':');
+    }
+
+    if let Some(auth) = iri.authority() {
+        buf.push_str("//");
+
+        if let Some(userinfo) = auth.userinfo() {
+            f(&mut buf, userinfo.as_str(), table::IUSERINFO);
+            buf.push('@');
+        }
+
+        let mut auth_meta = *auth.meta();
+        auth_meta.host_bounds.0 = buf.len();
+        match auth_meta.host_meta {
+            HostMeta::RegName => f(&mut buf, auth.host(), table::IREG_NAME),
+            _ => buf.push_str(auth.host()),
+        }
+        auth_meta.host_bounds.1 = buf.len();
+        meta.auth_meta = Some(auth_meta);
+
+        if let Some(port) = auth.port() {
+            buf.push(':');
+            buf.push_str(port.as_str());
+        }
+    }
+
+    meta.path_bounds.0 = buf.len();
+    f(&mut buf, iri.path().as_str(), table::IPATH);
+    meta.path_bounds.1 = buf.len();
+
+    if let Some(query) = iri.query() {
+        buf.push('?');
+        f(&mut buf, query.as_str(), table::IQUERY);
+        meta.query_end = NonZeroUsize::new(buf.len());
+    }
+
+    if let Some(fragment) = iri.fragment() {
+        buf.push('#');
+        f(&mut buf, fragment.as_str(), table::IFRAGMENT);
+    }
+
+    (buf, meta)
+}
+
+impl<T: Value> Default for Iri<T> {
+    /// Creates an empty IRI reference.
+    fn default() -> Self {
+        Iri {
+            inner: Uri::default(),
+        }
+    }
+}
+
+impl<T: Bos<str>, U: Bos<str>> PartialEq<Iri<U>> for Iri<T> {
+    fn eq(&self, other: &Iri<U>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<T: Bos<str>> PartialEq<str> for Iri<T> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<T: Bos<str>> PartialEq<Iri<T>> for str {
+    fn eq(&self, other: &Iri<T>) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl<T: Bos<str>> PartialEq<&str> for Iri<T> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<T: Bos<str>> PartialEq<Iri<T>> for &str {
+    fn eq(&self, other: &Iri<T>) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl<T: Bos<str>> Eq for Iri<T> {}
+
+impl<T: Bos<str>> hash::Hash for Iri<T> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl<T: Bos<str>> PartialOrd for Iri<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Bos<str>> Ord for Iri<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<T: Bos<str>> AsRef<str> for Iri<T> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<T: Bos<str>> Borrow<str> for Iri<T> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<Iri<&str>> for Iri<String> {
+    #[inline]
+    fn from(iri: Iri<&str>) -> Self {
+        iri.to_owned()
+    }
+}
+
+impl<T> From<Uri<T>> for Iri<T> {
+    /// Converts a `Uri` to an `Iri` without decoding anything.
+    ///
+    /// This is always possible since every URI reference is an IRI reference.
+    #[inline]
+    fn from(uri: Uri<T>) -> Self {
+        Iri { inner: uri }
+    }
+}
+
+impl FromStr for Iri<String> {
+    type Err = ParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Iri::parse(s).map(|iri| iri.to_owned())
+    }
+}
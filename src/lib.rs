@@ -22,10 +22,15 @@
 //!
 //! [RFC 3986]: https://datatracker.ietf.org/doc/html/rfc3986/
 //!
+//! IRI references defined in [RFC 3987] are supported through [`Iri`].
+//!
+//! [RFC 3987]: https://datatracker.ietf.org/doc/html/rfc3987/
+//!
 //! **Examples:** [Parsing](Uri#examples). [Building](Builder#examples).
 //! [Reference resolution](Uri::resolve_against). [Normalization](Uri::normalize).
 //! [Percent-decoding](crate::encoding::EStr#examples).
 //! [Percent-encoding](crate::encoding::EString#examples).
+//! [Template expansion](crate::template).
 //!
 //! # Guidance for crate users
 //!
@@ -49,20 +54,52 @@
 //!   and [`Authority::to_socket_addrs`]. Disabling `std` while enabling `net`
 //!   requires [`core::net`] and a minimum Rust version of `1.77`.
 //!
+//! - `idna`: Enables [internationalized domain name](crate::idna) processing.
+//!   Required for [`Authority::host_ascii`] and [`Authority::host_unicode`],
+//!   and for passing a Unicode domain name to [`Builder::host`].
+//!
+//! - `psl`: Enables [public suffix](crate::psl) lookup, with an embedded copy
+//!   of the Public Suffix List. The list is licensed under the
+//!   [Mozilla Public License 2.0](https://mozilla.org/MPL/2.0/), hence the
+//!   `MIT AND MPL-2.0` license expression of this crate.
+//!
+//! - `serde`: Implements [`Serialize`] and [`Deserialize`] for [`Uri`], [`EStr`]
+//!   and [`EString`], validating the input on deserialization. `Uri<&str>` and
+//!   `&EStr<E>` are deserialized without copying and thus require borrowed input.
+//!
+//! [`Serialize`]: https://docs.rs/serde/latest/serde/trait.Serialize.html
+//! [`Deserialize`]: https://docs.rs/serde/latest/serde/trait.Deserialize.html
+//! [`EStr`]: encoding::EStr
+//! [`EString`]: encoding::EString
 //! [`Error`]: std::error::Error
 //! [`Host`]: component::Host
 
 mod builder;
 pub mod component;
+mod edit;
 pub mod encoding;
 pub mod error;
 mod fmt;
+#[cfg(feature = "idna")]
+pub mod idna;
 mod internal;
+mod iri;
 mod normalizer;
+pub mod origin;
 mod parser;
+#[cfg(feature = "psl")]
+pub mod psl;
 mod resolver;
+pub mod scheme;
+#[cfg(feature = "serde")]
+mod serde;
+pub mod template;
 
-pub use builder::Builder;
+pub use builder::{Builder, DynBuilder};
+pub use iri::Iri;
+pub use normalizer::{NormalizeOptions, NormalizedHash, TrailingSlash};
+pub use parser::{Diagnostics, Fixup, Fixups, ParseOptions};
+pub use resolver::ResolveOptions;
 
 #[cfg(feature = "std")]
 extern crate std;
@@ -77,7 +114,7 @@ use core::net;
 
 use alloc::{borrow::ToOwned, string::String};
 use borrow_or_share::{BorrowOrShare, Bos};
-use builder::BuilderStart;
+use builder::{state::Modify, BuilderStart};
 use component::{Authority, Scheme};
 use core::{
     borrow::Borrow,
@@ -91,6 +128,7 @@ use encoding::{
 };
 use error::{ParseError, ResolveError};
 use internal::{Meta, ToUri, Value};
+use scheme::SchemeRegistry;
 
 /// A [URI reference] defined in RFC 3986.
 ///
@@ -209,6 +247,38 @@ impl Uri<String> {
         Builder::new()
     }
 
+    /// Parses a URI reference leniently from a string with all fixups enabled.
+    ///
+    /// This is a shortcut for [`ParseOptions::new().parse(s)`][parse],
+    /// where details on the fixups applied can be found.
+    ///
+    /// [parse]: ParseOptions::parse
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the input still does not match the [`URI-reference`]
+    /// ABNF rule from RFC 3986 after the fixups are applied.
+    ///
+    /// [`URI-reference`]: https://datatracker.ietf.org/doc/html/rfc3986/#section-4.1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::{Fixup, Uri};
+    ///
+    /// let (uri, fixups) = Uri::parse_lenient("http:example.com/über uns")?;
+    /// assert_eq!(uri, "http://example.com/%C3%BCber%20uns");
+    /// assert!(fixups.iter().eq([Fixup::FixedAuthoritySlashes, Fixup::EncodedInvalidChar]));
+    ///
+    /// let (uri, fixups) = Uri::parse_lenient("http://example.com/")?;
+    /// assert_eq!(uri, "http://example.com/");
+    /// assert!(fixups.is_empty());
+    /// # Ok::<_, fluent_uri::error::ParseError<String>>(())
+    /// ```
+    pub fn parse_lenient(s: &str) -> Result<(Uri<String>, Fixups), ParseError<String>> {
+        ParseOptions::new().parse(s)
+    }
+
     /// Borrows this `Uri<String>` as `Uri<&str>`.
     #[allow(clippy::should_implement_trait)]
     #[inline]
@@ -229,6 +299,15 @@ impl Uri<String> {
 }
 
 impl Uri<&str> {
+    /// Parses a URI reference from a string, collecting all errors
+    /// instead of stopping at the first one.
+    ///
+    /// See [`Diagnostics`] for details.
+    #[must_use]
+    pub fn diagnose(s: &str) -> Diagnostics {
+        Diagnostics::new(s)
+    }
+
     /// Creates a new `Uri<String>` by cloning the contents of this `Uri<&str>`.
     #[inline]
     #[must_use]
@@ -474,7 +553,8 @@ impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Uri<T> {
     ///   normalizing the base URI and then resolving `"."` against it yields `"foo:/"`.
     ///
     /// No normalization except the removal of dot segments will be performed.
-    /// Use [`normalize`] if need be.
+    /// Use [`normalize`] if need be. Use [`ResolveOptions`] for non-strict
+    /// resolution or to reject path underflow.
     ///
     /// [absolute URI]: Self::is_absolute_uri
     /// [rootless]: EStr::<Path>::is_rootless
@@ -502,7 +582,54 @@ impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Uri<T> {
     /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// ```
     pub fn resolve_against<U: Bos<str>>(&self, base: &Uri<U>) -> Result<Uri<String>, ResolveError> {
-        resolver::resolve(base.as_ref(), self.as_ref())
+        ResolveOptions::new().resolve(self, base)
+    }
+
+    /// Computes a URI reference that resolves against the given base
+    /// URI to this URI, i.e., the inverse of [`resolve_against`].
+    ///
+    /// The shortest of the following forms that applies is returned:
+    ///
+    /// - A same-document reference such as `""` or `"#frag"`.
+    /// - A query-only reference such as `"?query"` when the paths are equal.
+    /// - A network-path reference such as `"//host/path"` when the authorities differ.
+    /// - A relative-path reference such as `"../c/d"`, or an absolute-path reference
+    ///   such as `"/c/d"` if shorter. A `"./"` is prepended to a relative-path
+    ///   reference whose first segment would otherwise contain `':'` or be empty.
+    /// - This URI itself.
+    ///
+    /// Returns `None` if no URI reference resolves against the base to this URI,
+    /// e.g., when either of them is not an absolute URI, or when the path of this
+    /// URI contains dot segments, which are removed on resolution.
+    ///
+    /// [`resolve_against`]: Self::resolve_against
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::Uri;
+    ///
+    /// let base = Uri::parse("http://example.com/a/b/c?q")?;
+    ///
+    /// let target = Uri::parse("http://example.com/a/d/e")?;
+    /// assert_eq!(target.make_relative_to(&base).unwrap(), "../d/e");
+    ///
+    /// let target = Uri::parse("http://example.com/a/b/c?q#frag")?;
+    /// assert_eq!(target.make_relative_to(&base).unwrap(), "#frag");
+    ///
+    /// let target = Uri::parse("http://example.com/a/b/x:y")?;
+    /// assert_eq!(target.make_relative_to(&base).unwrap(), "./x:y");
+    ///
+    /// let target = Uri::parse("https://example.com/a")?;
+    /// assert_eq!(target.make_relative_to(&base).unwrap(), "https://example.com/a");
+    ///
+    /// let target = Uri::parse("http://example.com/a/../b")?;
+    /// assert!(target.make_relative_to(&base).is_none());
+    /// # Ok::<_, fluent_uri::error::ParseError>(())
+    /// ```
+    #[must_use]
+    pub fn make_relative_to<U: Bos<str>>(&self, base: &Uri<U>) -> Option<Uri<String>> {
+        resolver::make_relative(base.as_ref(), self.as_ref())
     }
 
     /// Normalizes the URI reference.
@@ -524,6 +651,7 @@ impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Uri<T> {
     ///   `"//"`, prepend `"/."` to the path.
     ///
     /// This method is idempotent: `self.normalize()` equals `self.normalize().normalize()`.
+    /// Use [`NormalizeOptions`] to toggle each of the steps above.
     ///
     /// [`remove_dot_segments`]: https://datatracker.ietf.org/doc/html/rfc3986/#section-5.2.4
     /// [`resolve_against`]: Self::resolve_against
@@ -539,7 +667,162 @@ impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Uri<T> {
     /// ```
     #[must_use]
     pub fn normalize(&self) -> Uri<String> {
-        normalizer::normalize(self.as_ref())
+        NormalizeOptions::new().normalize(self)
+    }
+
+    /// Checks whether the URI reference is equivalent to another one
+    /// after normalization.
+    ///
+    /// This method returns the same as `self.normalize() == other.normalize()`
+    /// but streams through the components without allocation. Use
+    /// [`NormalizedHash`] to hash URI references consistently with this method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::Uri;
+    ///
+    /// let a = Uri::parse("HTTP://www.%65xample.com/a/./b/../c?%7e")?;
+    /// let b = Uri::parse("http://WWW.EXAMPLE.COM:/a/c?~")?;
+    /// assert!(a.normalized_eq(&b));
+    ///
+    /// let c = Uri::parse("http://www.example.com/a/c?%7E%2F")?;
+    /// assert!(!a.normalized_eq(&c));
+    /// # Ok::<_, fluent_uri::error::ParseError>(())
+    /// ```
+    #[must_use]
+    pub fn normalized_eq<U: Bos<str>>(&self, other: &Uri<U>) -> bool {
+        normalizer::Normalized::new(self.as_ref()).eq(&normalizer::Normalized::new(other.as_ref()))
+    }
+
+    /// Normalizes the URI reference with scheme-specific rules from the registry.
+    ///
+    /// This method applies the syntax-based normalization described at [`normalize`],
+    /// followed by the scheme-based normalization described in
+    /// [Section 6.2.3 of RFC 3986](https://datatracker.ietf.org/doc/html/rfc3986/#section-6.2.3)
+    /// if the registry knows the scheme:
+    ///
+    /// - If the port equals the [default port], remove it along with its `':'` delimiter.
+    /// - If the authority is present, the path is empty and [an empty path is
+    ///   equivalent to `"/"`][empty_path_as_root], replace the path with `"/"`.
+    /// - If the query is empty and [an empty query is equivalent to an absent
+    ///   one][remove_empty_query], remove its `'?'` delimiter.
+    ///
+    /// This method is idempotent for the same registry.
+    ///
+    /// [`normalize`]: Self::normalize
+    /// [default port]: crate::scheme::SchemeRules::default_port
+    /// [empty_path_as_root]: crate::scheme::SchemeRules::empty_path_as_root
+    /// [remove_empty_query]: crate::scheme::SchemeRules::remove_empty_query
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::{scheme::WELL_KNOWN, Uri};
+    ///
+    /// let uri = Uri::parse("HTTPS://example.com:443")?;
+    /// assert_eq!(uri.normalize_with(WELL_KNOWN), "https://example.com/");
+    ///
+    /// let uri = Uri::parse("http://example.com:443")?;
+    /// assert_eq!(uri.normalize_with(WELL_KNOWN), "http://example.com:443/");
+    /// # Ok::<_, fluent_uri::error::ParseError>(())
+    /// ```
+    #[must_use]
+    pub fn normalize_with<R: SchemeRegistry + ?Sized>(&self, registry: &R) -> Uri<String> {
+        NormalizeOptions::new().normalize_with(self, registry)
+    }
+
+    /// Converts the URI reference to an IRI reference.
+    ///
+    /// This method applies the conversion described in
+    /// [Section 3.2 of RFC 3987](https://datatracker.ietf.org/doc/html/rfc3987/#section-3.2):
+    /// every sequence of percent-encoded octets that forms the UTF-8 encoding
+    /// of a character allowed in its component (a [`ucschar`], or an [`iprivate`]
+    /// in the query) is decoded, while all other octets are left intact.
+    /// Percent-encoded bidirectional formatting characters are not decoded, as
+    /// required by [Section 4.1 of RFC 3987](https://datatracker.ietf.org/doc/html/rfc3987/#section-4.1).
+    ///
+    /// For any `Iri` without percent-encoded non-ASCII characters
+    /// or bidirectional formatting characters, it holds that
+    /// `iri.to_uri().to_iri()` equals `iri`. Note that [`Iri::parse`] accepts
+    /// bidirectional formatting characters, which stay percent-encoded
+    /// after the round trip.
+    ///
+    /// [`ucschar`]: crate::encoding::Table::allows_ucschar
+    /// [`iprivate`]: crate::encoding::Table::allows_iprivate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::Uri;
+    ///
+    /// let uri = Uri::parse("http://example.com/%E3%81%BB%E3%81%92%20%FF?%EE%80%80")?;
+    /// assert_eq!(uri.to_iri(), "http://example.com/ほげ%20%FF?\u{e000}");
+    /// # Ok::<_, fluent_uri::error::ParseError>(())
+    /// ```
+    #[must_use]
+    pub fn to_iri(&self) -> Iri<String> {
+        iri::uri_to_iri(self.as_ref())
+    }
+
+    /// Returns the [origin] of the URI reference.
+    ///
+    /// The `ftp`, `http`, `https`, `ws` and `wss` schemes have a tuple origin
+    /// consisting of the lowercase scheme, the [normalized] host and the port,
+    /// with the default port of the scheme applied. A URI reference with any
+    /// other scheme (including `file`), no authority, an empty or IPvFuture host,
+    /// or a port that does not fit into `u16` has a new opaque origin.
+    ///
+    /// [origin]: https://url.spec.whatwg.org/#concept-url-origin
+    /// [normalized]: Self::normalize
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::Uri;
+    ///
+    /// let uri = Uri::parse("http://%65xample.COM:8080/path")?;
+    /// assert_eq!(uri.origin().to_string(), "http://example.com:8080");
+    ///
+    /// let uri = Uri::parse("mailto:user@example.com")?;
+    /// assert!(uri.origin().is_opaque());
+    /// # Ok::<_, fluent_uri::error::ParseError>(())
+    /// ```
+    #[cfg(target_has_atomic = "ptr")]
+    #[must_use]
+    pub fn origin(&self) -> origin::Origin {
+        origin::origin(self)
+    }
+
+    /// Checks whether the URI reference is of the same origin as another one.
+    ///
+    /// This is equivalent to `self.origin() == other.origin()`, and therefore
+    /// always returns `false` when either has an opaque origin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::Uri;
+    ///
+    /// let uri = Uri::parse("https://example.com/a")?;
+    /// assert!(uri.same_origin(&Uri::parse("HTTPS://EXAMPLE.COM:443/b")?));
+    /// assert!(!uri.same_origin(&Uri::parse("https://example.com:8443/a")?));
+    /// assert!(!uri.same_origin(&Uri::parse("https://sub.example.com/a")?));
+    ///
+    /// let uri = Uri::parse("file:///a")?;
+    /// assert!(!uri.same_origin(&uri));
+    /// # Ok::<_, fluent_uri::error::ParseError>(())
+    /// ```
+    #[must_use]
+    pub fn same_origin<U: Bos<str>>(&self, other: &Uri<U>) -> bool {
+        origin::same_origin(self, other)
+    }
+
+    /// Creates a builder pre-populated with the components of the URI reference.
+    ///
+    /// This is equivalent to [`Builder::from_uri`].
+    pub fn to_builder(&self) -> Builder<Modify> {
+        Builder::from_uri(self)
     }
 }
 
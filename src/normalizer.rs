@@ -1,12 +1,201 @@
 use crate::{
-    encoding::{decode_octet, table::UNRESERVED},
+    component::Scheme,
+    encoding::{decode_octet, table::UNRESERVED, EStr},
     internal::{HostMeta, Meta},
-    parser, resolver, Uri,
+    parser,
+    resolver::{self, classify_segment, SegKind},
+    scheme::{SchemeRegistry, SchemeRules},
+    Uri,
 };
-use alloc::string::String;
-use core::{fmt::Write, num::NonZeroUsize};
+use alloc::{string::String, vec::Vec};
+use borrow_or_share::Bos;
+use core::{
+    fmt::Write,
+    hash::{Hash, Hasher},
+    num::NonZeroUsize,
+};
+
+/// Policy on the trailing slash of a path, used by [`NormalizeOptions::trailing_slash`].
+///
+/// The policy is not applied to the path of a URI that contains no authority
+/// and has a rootless path, e.g., `"mailto:user@example.com"`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TrailingSlash {
+    /// Keeps the path as is.
+    Keep,
+    /// Appends a slash to a non-empty path that does not end with one.
+    Add,
+    /// Removes all trailing slashes from a path, unless it would become empty.
+    Remove,
+}
+
+/// Options for normalizing URI references.
+///
+/// This struct is created by [`NormalizeOptions::new`], which yields the same
+/// behavior as [`Uri::normalize`]. Each step of normalization may be toggled
+/// individually:
+///
+/// - [`case`] (enabled by default): Uppercases the hexadecimal digits within all
+///   percent-encoded octets and lowercases the scheme and the host except the
+///   percent-encoded octets.
+/// - [`percent_encoding`] (enabled by default): Decodes any percent-encoded
+///   octet that corresponds to an unreserved character.
+/// - [`remove_dot_segments`] (enabled by default): Removes dot segments from
+///   the path of a URI that contains a scheme and an absolute path.
+/// - [`remove_dot_segments_in_relative`] (disabled by default): Also removes
+///   dot segments from the path of a relative reference, keeping any leading
+///   double-dot segments of a relative path, e.g., turning `"../a/./b/../c"`
+///   into `"../a/c"`. A `"./"` is prepended to the output if it would otherwise
+///   be empty, start with `'/'`, or contain `':'` in the first segment.
+///   Takes effect only if [`remove_dot_segments`] is enabled.
+/// - [`canonicalize_ipv6`] (enabled by default): Turns any IPv6 literal address
+///   into its canonical form as per [RFC 5952](https://datatracker.ietf.org/doc/html/rfc5952/).
+/// - [`trailing_slash`] (defaults to [`TrailingSlash::Keep`]): Sets the policy
+///   on the trailing slash of the path.
+/// - [`remove_empty_components`] (disabled by default): Removes an empty
+///   userinfo, query or fragment along with its delimiter.
+///
+/// Regardless of the options, the `':'` delimiter of an empty port is removed,
+/// and `"/."` is prepended to a path that would start with `"//"` when the
+/// authority is absent.
+///
+/// [`case`]: Self::case
+/// [`percent_encoding`]: Self::percent_encoding
+/// [`remove_dot_segments`]: Self::remove_dot_segments
+/// [`remove_dot_segments_in_relative`]: Self::remove_dot_segments_in_relative
+/// [`canonicalize_ipv6`]: Self::canonicalize_ipv6
+/// [`trailing_slash`]: Self::trailing_slash
+/// [`remove_empty_components`]: Self::remove_empty_components
+///
+/// # Examples
+///
+/// ```
+/// use fluent_uri::{NormalizeOptions, TrailingSlash, Uri};
+///
+/// let uri = Uri::parse("HTTP://[0:0::1]/%7e/a/../b/?#")?;
+///
+/// let opts = NormalizeOptions::new()
+///     .percent_encoding(false)
+///     .canonicalize_ipv6(false)
+///     .trailing_slash(TrailingSlash::Remove)
+///     .remove_empty_components(true);
+/// assert_eq!(opts.normalize(&uri), "http://[0:0::1]/%7E/b");
+///
+/// let uri = Uri::parse("./a/../%2e%2E/b")?;
+/// let opts = NormalizeOptions::new().remove_dot_segments_in_relative(true);
+/// assert_eq!(opts.normalize(&uri), "../b");
+/// # Ok::<_, fluent_uri::error::ParseError>(())
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct NormalizeOptions {
+    case: bool,
+    percent_encoding: bool,
+    remove_dot_segments: bool,
+    remove_dot_segments_in_relative: bool,
+    canonicalize_ipv6: bool,
+    trailing_slash: TrailingSlash,
+    remove_empty_components: bool,
+}
+
+impl NormalizeOptions {
+    /// Creates a new `NormalizeOptions` that behaves like [`Uri::normalize`].
+    pub const fn new() -> Self {
+        NormalizeOptions {
+            case: true,
+            percent_encoding: true,
+            remove_dot_segments: true,
+            remove_dot_segments_in_relative: false,
+            canonicalize_ipv6: true,
+            trailing_slash: TrailingSlash::Keep,
+            remove_empty_components: false,
+        }
+    }
+
+    /// Sets whether to normalize the case of the hexadecimal digits
+    /// within percent-encoded octets, the scheme and the host.
+    pub const fn case(mut self, enabled: bool) -> Self {
+        self.case = enabled;
+        self
+    }
+
+    /// Sets whether to decode percent-encoded octets that correspond to unreserved characters.
+    pub const fn percent_encoding(mut self, enabled: bool) -> Self {
+        self.percent_encoding = enabled;
+        self
+    }
+
+    /// Sets whether to remove dot segments from the path of a URI
+    /// that contains a scheme and an absolute path.
+    pub const fn remove_dot_segments(mut self, enabled: bool) -> Self {
+        self.remove_dot_segments = enabled;
+        self
+    }
+
+    /// Sets whether to also remove dot segments from the path of a relative reference.
+    pub const fn remove_dot_segments_in_relative(mut self, enabled: bool) -> Self {
+        self.remove_dot_segments_in_relative = enabled;
+        self
+    }
+
+    /// Sets whether to turn IPv6 literal addresses into their canonical form.
+    pub const fn canonicalize_ipv6(mut self, enabled: bool) -> Self {
+        self.canonicalize_ipv6 = enabled;
+        self
+    }
+
+    /// Sets the policy on the trailing slash of the path.
+    pub const fn trailing_slash(mut self, policy: TrailingSlash) -> Self {
+        self.trailing_slash = policy;
+        self
+    }
+
+    /// Sets whether to remove an empty userinfo, query or fragment along with its delimiter.
+    pub const fn remove_empty_components(mut self, enabled: bool) -> Self {
+        self.remove_empty_components = enabled;
+        self
+    }
+
+    /// Normalizes a URI reference with the options.
+    ///
+    /// This method is idempotent: `opts.normalize(u)` equals
+    /// `opts.normalize(&opts.normalize(u))`.
+    #[must_use]
+    pub fn normalize<T: Bos<str>>(&self, u: &Uri<T>) -> Uri<String> {
+        normalize(u.as_ref(), self, None)
+    }
+
+    /// Normalizes a URI reference with the options and scheme-specific
+    /// rules from the registry.
+    ///
+    /// See [`Uri::normalize_with`] for the scheme-based normalization performed.
+    #[must_use]
+    pub fn normalize_with<T: Bos<str>, R: SchemeRegistry + ?Sized>(
+        &self,
+        u: &Uri<T>,
+        registry: &R,
+    ) -> Uri<String> {
+        let u = u.as_ref();
+        let rules = u.scheme().and_then(|scheme| registry.rules(scheme));
+        normalize(u, self, rules)
+    }
+}
+
+impl Default for NormalizeOptions {
+    /// Creates a new `NormalizeOptions` that behaves like [`Uri::normalize`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub(crate) fn normalize(
+    u: Uri<&str>,
+    opts: &NormalizeOptions,
+    rules: Option<SchemeRules>,
+) -> Uri<String> {
+    let rules = rules.unwrap_or_default();
+    let keep_empty = |s: &&str| !s.is_empty() || !opts.remove_empty_components;
 
-pub(crate) fn normalize(u: Uri<&str>) -> Uri<String> {
     // For "a://[::ffff:5:9]/" the capacity is not enough,
     // but it's fine since this rarely happens.
     let mut buf = String::with_capacity(u.as_str().len());
@@ -14,20 +203,28 @@ pub(crate) fn normalize(u: Uri<&str>) -> Uri<String> {
     let path = u.path().as_str();
     let mut path_buf = String::with_capacity(path.len());
 
-    if u.scheme_end.is_some() && path.starts_with('/') {
-        normalize_estr(&mut buf, path, false);
+    let remove_dot_segments = opts.remove_dot_segments
+        && (u.scheme_end.is_some() || opts.remove_dot_segments_in_relative);
+    if remove_dot_segments && path.starts_with('/') {
+        normalize_estr(&mut buf, path, false, opts);
         resolver::remove_dot_segments(&mut path_buf, &buf);
         buf.clear();
+    } else if remove_dot_segments && u.scheme_end.is_none() && !path.is_empty() {
+        normalize_estr(&mut buf, path, false, opts);
+        remove_relative_dot_segments(&mut path_buf, &buf);
+        buf.clear();
     } else {
-        // Don't remove dot segments from relative reference or rootless path.
-        normalize_estr(&mut path_buf, path, false);
+        // Don't remove dot segments from rootless path.
+        normalize_estr(&mut path_buf, path, false, opts);
     }
 
     let mut meta = Meta::default();
 
     if let Some(scheme) = u.scheme() {
         buf.push_str(scheme.as_str());
-        buf.make_ascii_lowercase();
+        if opts.case {
+            buf.make_ascii_lowercase();
+        }
         meta.scheme_end = NonZeroUsize::new(buf.len());
         buf.push(':');
     }
@@ -35,92 +232,173 @@ pub(crate) fn normalize(u: Uri<&str>) -> Uri<String> {
     if let Some(auth) = u.authority() {
         buf.push_str("//");
 
-        if let Some(userinfo) = auth.userinfo() {
-            normalize_estr(&mut buf, userinfo.as_str(), false);
+        if let Some(userinfo) = auth.userinfo().map(|s| s.as_str()).filter(keep_empty) {
+            normalize_estr(&mut buf, userinfo, false, opts);
             buf.push('@');
         }
 
         let mut auth_meta = *auth.meta();
         auth_meta.host_bounds.0 = buf.len();
-        match auth_meta.host_meta {
-            // An IPv4 address is always canonical.
-            HostMeta::Ipv4(..) => buf.push_str(auth.host()),
-            #[cfg(feature = "net")]
-            HostMeta::Ipv6(addr) => write!(buf, "[{addr}]").unwrap(),
-            #[cfg(not(feature = "net"))]
-            HostMeta::Ipv6() => {
-                buf.push('[');
-                write_v6(&mut buf, parser::parse_v6(&auth.host().as_bytes()[1..]));
-                buf.push(']');
-            }
-            HostMeta::IpvFuture => {
-                let start = buf.len();
-                buf.push_str(auth.host());
-
-                buf[start..].make_ascii_lowercase();
-            }
-            HostMeta::RegName => {
-                let start = buf.len();
-                let host = auth.host();
-                normalize_estr(&mut buf, host, true);
-
-                if buf.len() < start + host.len() {
-                    // Only reparse when the length is less than before.
-                    auth_meta.host_meta = parser::parse_v4_or_reg_name(&buf.as_bytes()[start..]);
-                }
-            }
-        }
+        auth_meta.host_meta = normalize_host(&mut buf, auth.host(), auth_meta.host_meta, opts);
         auth_meta.host_bounds.1 = buf.len();
         meta.auth_meta = Some(auth_meta);
 
         if let Some(port) = auth.port() {
-            if !port.is_empty() {
+            let is_default = rules.default_port.is_some()
+                && auth.port_to_u16().ok().flatten() == rules.default_port;
+            if !port.is_empty() && !is_default {
                 buf.push(':');
                 buf.push_str(port.as_str());
             }
         }
+
+        if path_buf.is_empty() && rules.empty_path_as_root {
+            path_buf.push('/');
+        }
+    }
+
+    let opaque = u.scheme_end.is_some() && u.auth_meta.is_none() && !path.starts_with('/');
+    if !opaque {
+        match opts.trailing_slash {
+            TrailingSlash::Keep => {}
+            TrailingSlash::Add => {
+                if !path_buf.is_empty() && !path_buf.ends_with('/') {
+                    path_buf.push('/');
+                }
+            }
+            TrailingSlash::Remove => {
+                let trimmed_len = path_buf.trim_end_matches('/').len();
+                path_buf.truncate(trimmed_len.max(1).min(path_buf.len()));
+            }
+        }
     }
 
     meta.path_bounds.0 = buf.len();
     // Make sure that the output is a valid URI reference.
-    if u.scheme_end.is_some() && u.auth_meta.is_none() && path_buf.starts_with("//") {
+    if u.auth_meta.is_none() && path_buf.starts_with("//") {
         buf.push_str("/.");
     }
     buf.push_str(&path_buf);
     meta.path_bounds.1 = buf.len();
 
-    if let Some(query) = u.query() {
+    let remove_empty_query = rules.remove_empty_query || opts.remove_empty_components;
+    if let Some(query) = u.query().filter(|q| !(q.is_empty() && remove_empty_query)) {
         buf.push('?');
-        normalize_estr(&mut buf, query.as_str(), false);
+        normalize_estr(&mut buf, query.as_str(), false, opts);
         meta.query_end = NonZeroUsize::new(buf.len());
     }
 
-    if let Some(fragment) = u.fragment() {
+    if let Some(fragment) = u.fragment().map(|s| s.as_str()).filter(keep_empty) {
         buf.push('#');
-        normalize_estr(&mut buf, fragment.as_str(), false);
+        normalize_estr(&mut buf, fragment, false, opts);
     }
 
     Uri { val: buf, meta }
 }
 
-fn normalize_estr(buf: &mut String, s: &str, to_lowercase: bool) {
+/// Appends a normalized host, returning its metadata.
+pub(crate) fn normalize_host(
+    buf: &mut String,
+    host: &str,
+    host_meta: HostMeta,
+    opts: &NormalizeOptions,
+) -> HostMeta {
+    match host_meta {
+        // An IPv4 address is always canonical.
+        HostMeta::Ipv4(..) => buf.push_str(host),
+        #[cfg(feature = "net")]
+        HostMeta::Ipv6(addr) if opts.canonicalize_ipv6 => write!(buf, "[{addr}]").unwrap(),
+        #[cfg(not(feature = "net"))]
+        HostMeta::Ipv6() if opts.canonicalize_ipv6 => {
+            buf.push('[');
+            write_v6(buf, parser::parse_v6(&host.as_bytes()[1..]));
+            buf.push(']');
+        }
+        HostMeta::Ipv6(..) | HostMeta::IpvFuture => {
+            let start = buf.len();
+            buf.push_str(host);
+
+            if opts.case {
+                buf[start..].make_ascii_lowercase();
+            }
+        }
+        HostMeta::RegName => {
+            let start = buf.len();
+            normalize_estr(buf, host, opts.case, opts);
+
+            if buf.len() < start + host.len() {
+                // Only reparse when the length is less than before.
+                return parser::parse_v4_or_reg_name(&buf.as_bytes()[start..]);
+            }
+        }
+    }
+    host_meta
+}
+
+/// Removes dot segments from a relative path, keeping any leading double-dot segments.
+fn remove_relative_dot_segments(buf: &mut String, path: &str) {
+    let mut segs: Vec<&str> = Vec::new();
+    let mut iter = path.split('/').peekable();
+
+    while let Some(seg) = iter.next() {
+        match classify_segment(seg) {
+            SegKind::Dot => {}
+            SegKind::DoubleDot => {
+                if segs.last().map_or(false, |last| {
+                    matches!(classify_segment(last), SegKind::Normal)
+                }) {
+                    segs.pop();
+                } else {
+                    segs.push(seg);
+                }
+            }
+            SegKind::Normal => {
+                segs.push(seg);
+                continue;
+            }
+        }
+        // Keep the trailing slash after a dot segment.
+        if iter.peek().is_none() {
+            segs.push("");
+        }
+    }
+
+    let start = buf.len();
+    for (i, seg) in segs.iter().enumerate() {
+        if i > 0 {
+            buf.push('/');
+        }
+        buf.push_str(seg);
+    }
+
+    let out = &buf[start..];
+    if out.is_empty() || out.starts_with('/') || segs[0].contains(':') {
+        buf.insert_str(start, "./");
+    }
+}
+
+fn normalize_estr(buf: &mut String, s: &str, to_lowercase: bool, opts: &NormalizeOptions) {
     let s = s.as_bytes();
     let mut i = 0;
 
     while i < s.len() {
         let mut x = s[i];
         if x == b'%' {
-            let (hi, lo) = (s[i + 1], s[i + 2]);
+            let (mut hi, mut lo) = (s[i + 1], s[i + 2]);
             let mut octet = decode_octet(hi, lo);
-            if UNRESERVED.allows(octet) {
+            if opts.percent_encoding && UNRESERVED.allows(octet) {
                 if to_lowercase {
                     octet = octet.to_ascii_lowercase();
                 }
                 buf.push(octet as char);
             } else {
+                if opts.case {
+                    hi = hi.to_ascii_uppercase();
+                    lo = lo.to_ascii_uppercase();
+                }
                 buf.push('%');
-                buf.push(hi.to_ascii_uppercase() as char);
-                buf.push(lo.to_ascii_uppercase() as char);
+                buf.push(hi as char);
+                buf.push(lo as char);
             }
             i += 3;
         } else {
@@ -133,6 +411,312 @@ fn normalize_estr(buf: &mut String, s: &str, to_lowercase: bool) {
     }
 }
 
+/// A wrapper that compares and hashes a URI reference as if it were normalized.
+///
+/// Two wrapped URI references `a` and `b` are equal if and only if
+/// `a.normalize()` equals `b.normalize()`, which is checked by streaming
+/// through the components without allocation. See [`Uri::normalized_eq`].
+///
+/// # Examples
+///
+/// ```
+/// use fluent_uri::{NormalizedHash, Uri};
+/// use std::collections::HashSet;
+///
+/// let mut set = HashSet::new();
+/// set.insert(NormalizedHash(Uri::parse("http://example.com/a/b")?));
+///
+/// assert!(set.contains(&NormalizedHash(Uri::parse("HTTP://EXAMPLE.COM/a/./c/../%62")?)));
+/// assert!(!set.contains(&NormalizedHash(Uri::parse("http://example.com/a/B")?)));
+/// # Ok::<_, fluent_uri::error::ParseError>(())
+/// ```
+#[derive(Clone, Copy)]
+pub struct NormalizedHash<T>(pub Uri<T>);
+
+impl<T: Bos<str>, U: Bos<str>> PartialEq<NormalizedHash<U>> for NormalizedHash<T> {
+    fn eq(&self, other: &NormalizedHash<U>) -> bool {
+        self.0.normalized_eq(&other.0)
+    }
+}
+
+impl<T: Bos<str>> Eq for NormalizedHash<T> {}
+
+impl<T: Bos<str>> Hash for NormalizedHash<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Normalized::new(self.0.as_ref()).hash(state);
+    }
+}
+
+/// Separates the normalized bytes when hashing, which are all ASCII.
+const SEP: u8 = 0xff;
+
+/// View of a URI reference in its normalized form.
+pub(crate) struct Normalized<'a> {
+    scheme: Option<&'a str>,
+    authority: Option<NormalizedAuthority<'a>>,
+    path: NormalizedPath<'a>,
+    query: Option<&'a str>,
+    fragment: Option<&'a str>,
+}
+
+struct NormalizedAuthority<'a> {
+    userinfo: Option<&'a str>,
+    host: NormalizedHost<'a>,
+    /// Empty port is treated as absent.
+    port: Option<&'a str>,
+}
+
+enum NormalizedHost<'a> {
+    Ipv6([u16; 8]),
+    /// Any other host, lowercased on normalization.
+    Other(&'a str),
+}
+
+enum NormalizedPath<'a> {
+    /// Path with percent-encoded octets normalized.
+    Raw(&'a str),
+    /// Absolute path with dot segments removed in addition.
+    DotRemoved(&'a str),
+}
+
+impl<'a> Normalized<'a> {
+    pub(crate) fn new(u: Uri<&'a str>) -> Self {
+        let authority = u.authority().map(|auth| {
+            let host = match auth.meta().host_meta {
+                #[cfg(feature = "net")]
+                HostMeta::Ipv6(addr) => NormalizedHost::Ipv6(addr.segments()),
+                #[cfg(not(feature = "net"))]
+                HostMeta::Ipv6() => {
+                    NormalizedHost::Ipv6(parser::parse_v6(&auth.host().as_bytes()[1..]))
+                }
+                _ => NormalizedHost::Other(auth.host()),
+            };
+            NormalizedAuthority {
+                userinfo: auth.userinfo().map(EStr::as_str),
+                host,
+                port: auth
+                    .port()
+                    .map(EStr::as_str)
+                    .filter(|port| !port.is_empty()),
+            }
+        });
+
+        let path = u.path().as_str();
+        let path = if u.scheme_end.is_some() && path.starts_with('/') {
+            NormalizedPath::DotRemoved(path)
+        } else {
+            NormalizedPath::Raw(path)
+        };
+
+        Normalized {
+            scheme: u.scheme().map(Scheme::as_str),
+            authority,
+            path,
+            query: u.query().map(EStr::as_str),
+            fragment: u.fragment().map(EStr::as_str),
+        }
+    }
+
+    pub(crate) fn eq(&self, other: &Normalized<'_>) -> bool {
+        fn opt_eq(a: Option<&str>, b: Option<&str>, to_lowercase: bool) -> bool {
+            match (a, b) {
+                (Some(a), Some(b)) => estr_eq(a, b, to_lowercase),
+                (None, None) => true,
+                _ => false,
+            }
+        }
+
+        let authority_eq = match (&self.authority, &other.authority) {
+            (Some(a), Some(b)) => {
+                let host_eq = match (&a.host, &b.host) {
+                    (NormalizedHost::Ipv6(a), NormalizedHost::Ipv6(b)) => a == b,
+                    (NormalizedHost::Other(a), NormalizedHost::Other(b)) => estr_eq(a, b, true),
+                    _ => false,
+                };
+                opt_eq(a.userinfo, b.userinfo, false) && host_eq && a.port == b.port
+            }
+            (None, None) => true,
+            _ => false,
+        };
+
+        let path_eq = match (&self.path, &other.path) {
+            (NormalizedPath::Raw(a), NormalizedPath::Raw(b)) => estr_eq(a, b, false),
+            (NormalizedPath::DotRemoved(a), NormalizedPath::DotRemoved(b)) => {
+                let (mut a, mut b) = (RevSegments::new(a), RevSegments::new(b));
+                loop {
+                    match (a.next(), b.next()) {
+                        (Some(a), Some(b)) if estr_eq(a, b, false) => {}
+                        (None, None) => break true,
+                        _ => break false,
+                    }
+                }
+            }
+            _ => false,
+        };
+
+        opt_eq(self.scheme, other.scheme, true)
+            && authority_eq
+            && path_eq
+            && opt_eq(self.query, other.query, false)
+            && opt_eq(self.fragment, other.fragment, false)
+    }
+
+    pub(crate) fn hash<H: Hasher>(&self, state: &mut H) {
+        fn write_estr<H: Hasher>(state: &mut H, s: &str, to_lowercase: bool) {
+            for x in NormalizedBytes::new(s, to_lowercase) {
+                state.write_u8(x);
+            }
+            state.write_u8(SEP);
+        }
+
+        fn write_opt<H: Hasher>(state: &mut H, s: Option<&str>, to_lowercase: bool) {
+            state.write_u8(s.is_some().into());
+            if let Some(s) = s {
+                write_estr(state, s, to_lowercase);
+            }
+        }
+
+        write_opt(state, self.scheme, true);
+
+        state.write_u8(self.authority.is_some().into());
+        if let Some(auth) = &self.authority {
+            write_opt(state, auth.userinfo, false);
+            match &auth.host {
+                NormalizedHost::Ipv6(segments) => {
+                    state.write_u8(0);
+                    segments.hash(state);
+                }
+                NormalizedHost::Other(host) => {
+                    state.write_u8(1);
+                    write_estr(state, host, true);
+                }
+            }
+            auth.port.hash(state);
+        }
+
+        match self.path {
+            NormalizedPath::Raw(path) => {
+                state.write_u8(0);
+                write_estr(state, path, false);
+            }
+            NormalizedPath::DotRemoved(path) => {
+                state.write_u8(1);
+                for seg in RevSegments::new(path) {
+                    write_estr(state, seg, false);
+                }
+            }
+        }
+
+        write_opt(state, self.query, false);
+        write_opt(state, self.fragment, false);
+    }
+}
+
+fn estr_eq(a: &str, b: &str, to_lowercase: bool) -> bool {
+    NormalizedBytes::new(a, to_lowercase).eq(NormalizedBytes::new(b, to_lowercase))
+}
+
+/// Iterator over the bytes of a percent-encoded string as normalized by `normalize_estr`.
+struct NormalizedBytes<'a> {
+    rest: &'a [u8],
+    to_lowercase: bool,
+    hex: [u8; 2],
+    hex_len: usize,
+}
+
+impl<'a> NormalizedBytes<'a> {
+    fn new(s: &'a str, to_lowercase: bool) -> Self {
+        NormalizedBytes {
+            rest: s.as_bytes(),
+            to_lowercase,
+            hex: [0; 2],
+            hex_len: 0,
+        }
+    }
+}
+
+impl Iterator for NormalizedBytes<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.hex_len > 0 {
+            self.hex_len -= 1;
+            return Some(self.hex[1 - self.hex_len]);
+        }
+
+        let (&x, rest) = self.rest.split_first()?;
+        self.rest = rest;
+        let x = if x == b'%' {
+            let (hi, lo) = (rest[0], rest[1]);
+            self.rest = &rest[2..];
+            let octet = decode_octet(hi, lo);
+            if !UNRESERVED.allows(octet) {
+                self.hex = [hi.to_ascii_uppercase(), lo.to_ascii_uppercase()];
+                self.hex_len = 2;
+                return Some(b'%');
+            }
+            octet
+        } else {
+            x
+        };
+        Some(if self.to_lowercase {
+            x.to_ascii_lowercase()
+        } else {
+            x
+        })
+    }
+}
+
+/// Iterator over the segments of an absolute path with dot segments removed,
+/// in reverse order. The output path is the segments joined with `'/'`.
+///
+/// Walking backwards allows a double-dot segment to be applied by
+/// skipping the next normal segment, without any buffer.
+struct RevSegments<'a> {
+    iter: core::str::RSplit<'a, char>,
+    skip: usize,
+    trailing: bool,
+    root: bool,
+}
+
+impl<'a> RevSegments<'a> {
+    fn new(path: &'a str) -> Self {
+        let rest = &path[1..];
+        let last = rest.rsplit('/').next().unwrap();
+        RevSegments {
+            iter: rest.rsplit('/'),
+            skip: 0,
+            // A trailing dot segment leaves a trailing slash.
+            trailing: !matches!(classify_segment(last), SegKind::Normal),
+            root: true,
+        }
+    }
+}
+
+impl<'a> Iterator for RevSegments<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if self.trailing {
+            self.trailing = false;
+            return Some("");
+        }
+        for seg in self.iter.by_ref() {
+            match classify_segment(seg) {
+                SegKind::Dot => {}
+                SegKind::DoubleDot => self.skip += 1,
+                SegKind::Normal if self.skip > 0 => self.skip -= 1,
+                SegKind::Normal => return Some(seg),
+            }
+        }
+        if self.root {
+            self.root = false;
+            return Some("");
+        }
+        None
+    }
+}
+
 // Taken from `impl Display for Ipv6Addr`.
 #[cfg(not(feature = "net"))]
 fn write_v6(buf: &mut String, segments: [u16; 8]) {
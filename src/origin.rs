@@ -0,0 +1,173 @@
+//! Origins of URIs as defined in the [HTML Standard].
+//!
+//! [HTML Standard]: https://html.spec.whatwg.org/multipage/browsers.html#origin
+//!
+//! # Examples
+//!
+//! ```
+//! use fluent_uri::{origin::Origin, Uri};
+//!
+//! let uri = Uri::parse("HTTPS://Example.COM:443/path?query")?;
+//! let origin = uri.origin();
+//! assert_eq!(origin.to_string(), "https://example.com");
+//!
+//! let Origin::Tuple(tuple) = &origin else { unreachable!() };
+//! assert_eq!(tuple.scheme().as_str(), "https");
+//! assert_eq!(tuple.host(), "example.com");
+//! assert_eq!(tuple.port(), 443);
+//!
+//! assert!(uri.same_origin(&Uri::parse("https://example.com/other")?));
+//! assert!(!uri.same_origin(&Uri::parse("http://example.com/")?));
+//!
+//! let origin = Uri::parse("file:///etc/hosts")?.origin();
+//! assert!(origin.is_opaque());
+//! assert_eq!(origin.to_string(), "null");
+//! # Ok::<_, fluent_uri::error::ParseError>(())
+//! ```
+
+use crate::{
+    component::Scheme,
+    internal::HostMeta,
+    normalizer::{self, NormalizeOptions},
+    scheme::{SchemeRegistry, WELL_KNOWN},
+    Uri,
+};
+use alloc::string::{String, ToString};
+use borrow_or_share::Bos;
+
+/// The origin of a URI.
+///
+/// An origin is obtained with [`Uri::origin`]. Two origins are equal if they
+/// are both tuple origins with equal components, or if they are both the same
+/// opaque origin. Since each call to [`Uri::origin`] creates a new opaque
+/// origin when the URI has no tuple origin, such a URI is not of the same
+/// origin as any other URI, nor as itself.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Origin {
+    /// A tuple origin consisting of a scheme, a host and a port.
+    Tuple(TupleOrigin),
+    /// An opaque origin, which is only equal to itself.
+    Opaque(OpaqueOrigin),
+}
+
+impl Origin {
+    /// Creates a new opaque origin distinct from all others.
+    ///
+    /// This method is only available on targets with pointer-sized atomics,
+    /// which are needed to keep a global counter.
+    #[cfg(target_has_atomic = "ptr")]
+    #[must_use]
+    pub fn new_opaque() -> Origin {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        Origin::Opaque(OpaqueOrigin(COUNTER.fetch_add(1, Ordering::Relaxed)))
+    }
+
+    /// Checks whether the origin is a tuple origin.
+    #[must_use]
+    pub fn is_tuple(&self) -> bool {
+        matches!(self, Origin::Tuple(_))
+    }
+
+    /// Checks whether the origin is an opaque origin.
+    #[must_use]
+    pub fn is_opaque(&self) -> bool {
+        matches!(self, Origin::Opaque(_))
+    }
+
+    /// Returns the [ASCII serialization] of the origin, which is
+    /// `"null"` for an opaque origin.
+    ///
+    /// This is the same as the [`Display`](core::fmt::Display) output.
+    ///
+    /// [ASCII serialization]: https://html.spec.whatwg.org/multipage/browsers.html#ascii-serialisation-of-an-origin
+    #[must_use]
+    pub fn ascii_serialization(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// A tuple origin consisting of a scheme, a host and a port.
+///
+/// The scheme is lowercase, the host is in its [normalized](Uri::normalize) form,
+/// and the port is the default port of the scheme if not explicitly specified.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TupleOrigin {
+    scheme: String,
+    host: String,
+    port: u16,
+    default_port: u16,
+}
+
+impl TupleOrigin {
+    /// Returns the scheme.
+    #[must_use]
+    pub fn scheme(&self) -> &Scheme {
+        Scheme::new_validated(&self.scheme)
+    }
+
+    /// Returns the host, with any IPv6 literal address enclosed in brackets.
+    #[must_use]
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Returns the port, with the default port of the scheme applied.
+    #[must_use]
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Checks whether the port is the default port of the scheme.
+    #[must_use]
+    pub fn is_default_port(&self) -> bool {
+        self.port == self.default_port
+    }
+}
+
+/// An opaque origin, which is only equal to itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OpaqueOrigin(usize);
+
+/// Computes the origin of a URI reference.
+///
+/// Only the `ftp`, `http`, `https`, `ws` and `wss` schemes have tuple origins,
+/// provided that the host is a non-empty registered name or an IP address and
+/// that the port fits into `u16`. All other URI references, including those with
+/// the `file` scheme, have opaque origins.
+#[cfg(target_has_atomic = "ptr")]
+pub(crate) fn origin<T: Bos<str>>(u: &Uri<T>) -> Origin {
+    tuple_origin(u).map_or_else(Origin::new_opaque, Origin::Tuple)
+}
+
+/// Checks whether two URI references are of the same origin,
+/// without creating opaque origins.
+pub(crate) fn same_origin<T: Bos<str>, U: Bos<str>>(a: &Uri<T>, b: &Uri<U>) -> bool {
+    match (tuple_origin(a), tuple_origin(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
+    }
+}
+
+fn tuple_origin<T: Bos<str>>(u: &Uri<T>) -> Option<TupleOrigin> {
+    let scheme = u.scheme()?;
+    let default_port = WELL_KNOWN.rules(scheme)?.default_port?;
+    let auth = u.authority()?;
+
+    let host_meta = auth.meta().host_meta;
+    if matches!(host_meta, HostMeta::IpvFuture) || auth.host().is_empty() {
+        return None;
+    }
+    let port = auth.port_to_u16().ok()?.unwrap_or(default_port);
+
+    let mut host = String::with_capacity(auth.host().len());
+    normalizer::normalize_host(&mut host, auth.host(), host_meta, &NormalizeOptions::new());
+
+    Some(TupleOrigin {
+        scheme: scheme.as_str().to_ascii_lowercase(),
+        host,
+        port,
+        default_port,
+    })
+}
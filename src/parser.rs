@@ -1,40 +1,85 @@
 use crate::{
-    encoding::{table::*, Table, OCTET_TABLE_LO},
+    encoding::{next_code_point, table::*, Table, OCTET_TABLE_LO},
+    error::{Component, ParseError, ParseErrorKind},
     internal::{AuthMeta, HostMeta, Meta, NoInput},
+    Uri,
 };
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
 use core::{
     num::NonZeroUsize,
-    ops::{Deref, DerefMut},
+    ops::{Deref, DerefMut, Range},
     str,
 };
 
-type Result<T> = core::result::Result<T, crate::error::ParseError>;
+type Result<T> = core::result::Result<T, ParseError>;
 
-/// Returns immediately with an error.
-macro_rules! err {
-    ($index:expr, $kind:ident) => {
-        return Err(crate::error::ParseError {
+/// Creates an error.
+///
+/// The component and the unexpected character are filled in by `Parser::complete`.
+macro_rules! error {
+    ($index:expr, $kind:ident, $expected:expr) => {
+        crate::error::ParseError {
             index: $index,
             kind: crate::error::ParseErrorKind::$kind,
+            component: Component::Scheme,
+            expected: $expected.into(),
+            ch: None,
             input: NoInput,
-        })
+        }
     };
 }
 
-pub(crate) fn parse(bytes: &[u8]) -> Result<Meta> {
-    let mut parser = Parser {
-        reader: Reader::new(bytes),
-        out: Meta::default(),
+/// Returns immediately with an error.
+macro_rules! err {
+    ($index:expr, $kind:ident) => {
+        err!($index, $kind, None)
+    };
+    ($index:expr, $kind:ident, $expected:expr) => {
+        return Err(error!($index, $kind, $expected))
     };
-    parser.parse_from_scheme()?;
-    Ok(parser.out)
 }
 
+/// Parses a URI reference, or an IRI reference when `UCS` is `true`.
+pub(crate) fn parse<const UCS: bool>(bytes: &[u8]) -> Result<Meta> {
+    let mut parser = Parser::<UCS>::new(bytes, None);
+    match parser.parse_from_scheme() {
+        Ok(()) => Ok(parser.out),
+        Err(e) => Err(parser.complete(e)),
+    }
+}
+
+/// Parses a URI reference, or an IRI reference when `UCS` is `true`,
+/// collecting all errors along with a best-effort output.
+///
+/// The errors are sorted by index, with at most one error at each index.
+pub(crate) fn parse_all<const UCS: bool>(bytes: &[u8]) -> (Meta, Vec<ParseError>) {
+    let mut parser = Parser::<UCS>::new(bytes, Some(Vec::new()));
+    let res = parser.parse_from_scheme();
+
+    let mut errors = parser.errors.take().unwrap();
+    if let Err(e) = res {
+        errors.push(parser.complete(e));
+    }
+    errors.sort_by_key(|e| e.index);
+    errors.dedup_by_key(|e| e.index);
+    (parser.out, errors)
+}
+
+const RIGHT_BRACKET: &Table = &Table::gen(b"]");
+const DOT: &Table = &Table::gen(b".");
+const SLASH: &Table = &Table::gen(b"/");
+const IP_LITERAL_START: &Table = &HEXDIG.or(&Table::gen(b":vV"));
+
 /// URI parser.
 ///
 /// # Invariants
 ///
-/// `pos <= len`, `pos` is non-decreasing and `bytes[..pos]` is ASCII.
+/// `pos <= len`, `pos` is non-decreasing and `bytes[..pos]` is ASCII
+/// if `UCS` is `false`, or valid UTF-8 otherwise.
+///
+/// When collecting errors, the parser skips past recoverable errors and the
+/// latter invariant no longer holds. The output is then only a best effort
+/// and must never be used to construct a `Uri`.
 ///
 /// # Preconditions and guarantees
 ///
@@ -43,12 +88,16 @@ pub(crate) fn parse(bytes: &[u8]) -> Result<Meta> {
 /// Start and finish parsing by calling `parse_from_scheme`.
 /// The following are guaranteed when parsing succeeds:
 ///
-/// - `bytes` is ASCII.
+/// - `bytes` is ASCII if `UCS` is `false`.
 /// - All output indexes are within bounds and correctly ordered.
 /// - All URI components defined by output indexes are validated.
-struct Parser<'a> {
+struct Parser<'a, const UCS: bool> {
     reader: Reader<'a>,
     out: Meta,
+    // The component being parsed, for error reporting.
+    comp: Component,
+    // The errors recorded so far, if collecting errors.
+    errors: Option<Vec<ParseError>>,
 }
 
 struct Reader<'a> {
@@ -56,7 +105,7 @@ struct Reader<'a> {
     pos: usize,
 }
 
-impl<'a> Deref for Parser<'a> {
+impl<'a, const UCS: bool> Deref for Parser<'a, UCS> {
     type Target = Reader<'a>;
 
     fn deref(&self) -> &Self::Target {
@@ -64,7 +113,7 @@ impl<'a> Deref for Parser<'a> {
     }
 }
 
-impl<'a> DerefMut for Parser<'a> {
+impl<'a, const UCS: bool> DerefMut for Parser<'a, UCS> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.reader
     }
@@ -143,27 +192,36 @@ impl<'a> Reader<'a> {
             if x == b'%' {
                 // This cannot overflow as the maximum length of `bytes` is `isize::MAX`.
                 if i + 2 >= self.len() {
-                    err!(i, InvalidOctet);
+                    err!(i, InvalidOctet, HEXDIG);
                 }
 
                 let (hi, lo) = (self.get(i + 1), self.get(i + 2));
 
                 if HEXDIG.get(hi) & HEXDIG.get(lo) == 0 {
-                    err!(i, InvalidOctet);
+                    err!(i, InvalidOctet, HEXDIG);
                 }
                 // INVARIANT: Since `i + 2 < len`, it holds that `i + 3 <= len`.
                 i += 3;
-            } else {
+            } else if x.is_ascii() {
                 if !table.allows(x) {
                     break;
                 }
                 f(i, x);
                 // INVARIANT: Since `i < len`, it holds that `i + 1 <= len`.
                 i += 1;
+            } else {
+                // Only IRI tables allow non-ASCII characters.
+                let (cp, len) = next_code_point(self.bytes, i);
+                if !table.allows_code_point(cp) {
+                    break;
+                }
+                // INVARIANT: The character is within bounds since `bytes` is valid UTF-8.
+                i += len;
             }
         }
 
-        // INVARIANT: `i` is non-decreasing and all bytes read are ASCII.
+        // INVARIANT: `i` is non-decreasing and all bytes read are ASCII,
+        // or valid UTF-8 when reading with an IRI table.
         self.pos = i;
         Ok(())
     }
@@ -283,8 +341,8 @@ impl<'a> Reader<'a> {
         Some(Seg::Normal(x, colon))
     }
 
-    fn read_v4_or_reg_name(&mut self) -> Result<HostMeta> {
-        Ok(match (self.read_v4(), self.read(REG_NAME)?) {
+    fn read_v4_or_reg_name(&mut self, reg_name: &Table) -> Result<HostMeta> {
+        Ok(match (self.read_v4(), self.read(reg_name)?) {
             (Some(_addr), false) => HostMeta::Ipv4(
                 #[cfg(feature = "net")]
                 _addr.into(),
@@ -341,10 +399,10 @@ impl<'a> Reader<'a> {
         });
     }
 
-    fn read_host(&mut self) -> Result<HostMeta> {
+    fn read_host(&mut self, reg_name: &Table) -> Result<HostMeta> {
         match self.read_ip_literal()? {
             Some(host) => Ok(host),
-            None => self.read_v4_or_reg_name(),
+            None => self.read_v4_or_reg_name(reg_name),
         }
     }
 
@@ -368,20 +426,28 @@ impl<'a> Reader<'a> {
         };
 
         if !self.read_str("]") {
-            err!(self.pos, UnexpectedChar);
+            err!(self.pos, UnexpectedChar, RIGHT_BRACKET);
         }
         Ok(Some(meta))
     }
 
     fn read_ipv_future(&mut self) -> Result<()> {
-        if let Some(b'v' | b'V') = self.peek(0) {
-            // INVARIANT: Skipping "v" or "V" is fine.
-            self.skip(1);
-            if self.read(HEXDIG)? && self.read_str(".") && self.read(IPV_FUTURE)? {
-                return Ok(());
-            }
+        if !matches!(self.peek(0), Some(b'v' | b'V')) {
+            // Neither an IPv6 address nor an IPvFuture.
+            err!(self.pos, UnexpectedChar, IP_LITERAL_START);
+        }
+        // INVARIANT: Skipping "v" or "V" is fine.
+        self.skip(1);
+        if !self.read(HEXDIG)? {
+            err!(self.pos, UnexpectedChar, HEXDIG);
         }
-        err!(self.pos, UnexpectedChar);
+        if !self.read_str(".") {
+            err!(self.pos, UnexpectedChar, DOT);
+        }
+        if !self.read(IPV_FUTURE)? {
+            err!(self.pos, UnexpectedChar, IPV_FUTURE);
+        }
+        Ok(())
     }
 }
 
@@ -401,17 +467,169 @@ pub(crate) fn parse_v6(bytes: &[u8]) -> [u16; 8] {
     Reader::new(bytes).read_v6().unwrap()
 }
 
-impl<'a> Parser<'a> {
+/// Selects the IRI table when parsing an IRI reference.
+const fn select<const UCS: bool>(uri: &'static Table, iri: &'static Table) -> &'static Table {
+    if UCS {
+        iri
+    } else {
+        uri
+    }
+}
+
+impl<'a, const UCS: bool> Parser<'a, UCS> {
+    fn new(bytes: &'a [u8], errors: Option<Vec<ParseError>>) -> Self {
+        Parser {
+            reader: Reader::new(bytes),
+            out: Meta::default(),
+            comp: Component::Scheme,
+            errors,
+        }
+    }
+
+    /// Fills in the component and the unexpected character of an error.
+    fn complete(&self, mut e: ParseError) -> ParseError {
+        e.component = self.comp;
+        if let (ParseErrorKind::UnexpectedChar, Some(_)) = (e.kind, self.bytes.get(e.index)) {
+            // The error index always points to the first byte of a character.
+            e.ch = char::from_u32(next_code_point(self.bytes, e.index).0);
+        }
+        e
+    }
+
+    /// Records the error and returns `Ok` if collecting errors,
+    /// or returns the error otherwise.
+    fn recover(&mut self, e: ParseError) -> Result<()> {
+        let e = self.complete(e);
+        match &mut self.errors {
+            Some(errors) => {
+                errors.push(e);
+                Ok(())
+            }
+            None => Err(e),
+        }
+    }
+
+    fn collecting(&self) -> bool {
+        self.errors.is_some()
+    }
+
+    fn skip_char(&mut self) {
+        let (_, len) = next_code_point(self.bytes, self.pos);
+        self.skip(len);
+    }
+
+    /// Reads a component with the table.
+    ///
+    /// When collecting errors, records and skips past any invalid octet
+    /// or unexpected character until a byte in `stop` or the end of input.
+    fn read_component(
+        &mut self,
+        table: &'static Table,
+        stop: &[u8],
+        mut f: impl FnMut(usize, u8),
+    ) -> Result<()> {
+        loop {
+            let res = if table.allows_enc() {
+                self.read_enc(table, &mut f)
+            } else {
+                self.read(table).map(|_| ())
+            };
+            if let Err(e) = res {
+                let i = e.index;
+                self.recover(e)?;
+                // Skip the percent character.
+                self.pos = i + 1;
+                continue;
+            }
+
+            match self.peek(0) {
+                Some(x) if self.collecting() && !stop.contains(&x) => {
+                    self.recover(error!(self.pos, UnexpectedChar, table))?;
+                    self.skip_char();
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    /// Records and skips past every character until a byte in `stop`
+    /// or the end of input.
+    fn skip_unexpected(&mut self, stop: &[u8], expected: Option<&'static Table>) -> Result<()> {
+        while let Some(x) = self.peek(0) {
+            if stop.contains(&x) {
+                break;
+            }
+            self.recover(error!(self.pos, UnexpectedChar, expected))?;
+            self.skip_char();
+        }
+        Ok(())
+    }
+
+    /// Reads the host, or records the error and skips past
+    /// the invalid host if collecting errors.
+    fn read_host_or_recover(&mut self) -> Result<HostMeta> {
+        let reg_name = select::<UCS>(REG_NAME, IREG_NAME);
+        let start = self.pos;
+        match self.read_host(reg_name) {
+            Ok(meta) => Ok(meta),
+            Err(e) => {
+                let i = e.index;
+                self.recover(e)?;
+                if self.bytes.get(start) == Some(&b'[') {
+                    self.skip_ip_literal();
+                } else {
+                    self.pos = i + 1;
+                    self.read_component(reg_name, b":/?#", |_, _| {})?;
+                }
+                Ok(HostMeta::RegName)
+            }
+        }
+    }
+
+    /// Skips past the next ']' or up to the end of authority.
+    fn skip_ip_literal(&mut self) {
+        while let Some(x) = self.peek(0) {
+            if matches!(x, b'/' | b'?' | b'#') {
+                break;
+            }
+            self.skip_char();
+            if x == b']' {
+                break;
+            }
+        }
+    }
+
+    /// Reads the port if any, and when collecting errors, records and skips
+    /// past any unexpected character after the host or the port.
+    ///
+    /// Returns the end of host.
+    fn read_port_or_recover(&mut self, host_start: usize) -> Result<usize> {
+        if self.collecting() {
+            if self.bytes.get(host_start) == Some(&b'[') {
+                self.skip_unexpected(b":/?#", None)?;
+            } else {
+                // We may have stopped at an invalid octet or an unexpected character.
+                self.read_component(select::<UCS>(REG_NAME, IREG_NAME), b":/?#", |_, _| {})?;
+            }
+        }
+        let host_end = self.pos;
+        self.read_port();
+        if self.collecting() && self.pos != host_end {
+            self.comp = Component::Port;
+            self.skip_unexpected(b"/?#", Some(DIGIT))?;
+        }
+        Ok(host_end)
+    }
+
     fn parse_from_scheme(&mut self) -> Result<()> {
         self.read(SCHEME)?;
 
         if self.peek(0) == Some(b':') {
             // Scheme starts with a letter.
-            if self.pos > 0 && self.get(0).is_ascii_alphabetic() {
-                self.out.scheme_end = NonZeroUsize::new(self.pos);
-            } else {
-                err!(0, UnexpectedChar);
+            if self.pos == 0 || !self.get(0).is_ascii_alphabetic() {
+                self.recover(error!(0, UnexpectedChar, ALPHA))?;
             }
+            self.out.scheme_end = NonZeroUsize::new(self.pos);
 
             // INVARIANT: Skipping ":" is fine.
             self.skip(1);
@@ -437,33 +655,53 @@ impl<'a> Parser<'a> {
         let mut colon_i = 0;
 
         let auth_start = self.pos;
+        self.comp = Component::Host;
 
         // `USERINFO` contains userinfo, reg-name, ':', and port.
-        self.read_enc(USERINFO, |i, x| {
-            if x == b':' {
-                colon_cnt += 1;
-                colon_i = i;
+        // When collecting errors, do not skip past the start of an IP literal.
+        if !self.collecting() || self.peek(0) != Some(b'[') {
+            if self.collecting() && self.userinfo_follows() {
+                self.comp = Component::Userinfo;
+            }
+            let res = self.read_component(select::<UCS>(USERINFO, IUSERINFO), b"/?#@", |i, x| {
+                if x == b':' {
+                    colon_cnt += 1;
+                    colon_i = i;
+                }
+            });
+            if res.is_err() && self.userinfo_follows() {
+                self.comp = Component::Userinfo;
             }
-        })?;
+            res?;
+        }
 
         if self.peek(0) == Some(b'@') {
             // Userinfo present.
             // INVARIANT: Skipping "@" is fine.
             self.skip(1);
+            self.comp = Component::Host;
 
             let host_start = self.pos;
-            let meta = self.read_host()?;
-            host = (host_start, self.pos, meta);
-
-            self.read_port();
+            let meta = self.read_host_or_recover()?;
+            let host_end = self.read_port_or_recover(host_start)?;
+            host = (host_start, host_end, meta);
         } else if self.pos == auth_start {
             // Nothing read. We're now at the start of an IP literal or the path.
-            if let Some(meta) = self.read_ip_literal()? {
-                host = (auth_start, self.pos, meta);
-                self.read_port();
-            } else {
+            let res = self.read_ip_literal();
+            if let Ok(None) = res {
                 // Empty authority.
                 host = (self.pos, self.pos, HostMeta::RegName);
+            } else {
+                let meta = match res {
+                    Ok(meta) => meta.unwrap(),
+                    Err(e) => {
+                        self.recover(e)?;
+                        self.skip_ip_literal();
+                        HostMeta::RegName
+                    }
+                };
+                let host_end = self.read_port_or_recover(auth_start)?;
+                host = (auth_start, host_end, meta);
             }
         } else {
             // The whole authority read. Try to parse the host and port.
@@ -472,15 +710,20 @@ impl<'a> Parser<'a> {
                 0 => self.pos,
                 // Host and port.
                 1 => {
-                    for i in colon_i + 1..self.pos {
-                        if !self.get(i).is_ascii_digit() {
-                            err!(i, UnexpectedChar);
-                        }
+                    if let Some(i) =
+                        (colon_i + 1..self.pos).find(|&i| !self.get(i).is_ascii_digit())
+                    {
+                        self.comp = Component::Port;
+                        self.recover(error!(i, UnexpectedChar, DIGIT))?;
                     }
                     colon_i
                 }
                 // Multiple colons.
-                _ => err!(colon_i, UnexpectedChar),
+                _ => {
+                    let expected = select::<UCS>(REG_NAME, IREG_NAME);
+                    self.recover(error!(colon_i, UnexpectedChar, expected))?;
+                    self.pos
+                }
             };
 
             let meta = parse_v4_or_reg_name(&self.bytes[auth_start..host_end]);
@@ -495,46 +738,532 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_from_path(&mut self, kind: PathKind) -> Result<()> {
+        self.comp = Component::Path;
+        let path = select::<UCS>(PATH, IPATH);
         self.out.path_bounds = match kind {
             PathKind::General => {
                 let start = self.pos;
-                self.read(PATH)?;
+                self.read_component(path, b"?#", |_, _| {})?;
                 (start, self.pos)
             }
             PathKind::AbEmpty => {
                 let start = self.pos;
+                self.read_component(path, b"?#", |_, _| {})?;
                 // Either empty or starting with '/'.
-                if self.read(PATH)? && self.get(start) != b'/' {
-                    err!(start, UnexpectedChar);
+                if self.pos > start && self.get(start) != b'/' {
+                    self.recover(error!(start, UnexpectedChar, SLASH))?;
                 }
                 (start, self.pos)
             }
             PathKind::ContinuedNoScheme => {
-                self.read(SEGMENT_NZ_NC)?;
+                let segment = select::<UCS>(SEGMENT_NZ_NC, ISEGMENT_NZ_NC);
+                self.read_component(segment, b":/?#", |_, _| {})?;
 
                 if self.peek(0) == Some(b':') {
                     // In a relative reference, the first path
                     // segment cannot contain a colon character.
-                    err!(self.pos, UnexpectedChar);
+                    self.recover(error!(self.pos, UnexpectedChar, segment))?;
                 }
 
-                self.read(PATH)?;
+                self.read_component(path, b"?#", |_, _| {})?;
                 (0, self.pos)
             }
         };
 
+        let mut table = path;
+
         if self.read_str("?") {
-            self.read(QUERY)?;
+            self.comp = Component::Query;
+            table = select::<UCS>(QUERY, IQUERY);
+            self.read_component(table, b"#", |_, _| {})?;
             self.out.query_end = NonZeroUsize::new(self.pos);
         }
 
         if self.read_str("#") {
-            self.read(FRAGMENT)?;
+            self.comp = Component::Fragment;
+            table = select::<UCS>(FRAGMENT, IFRAGMENT);
+            self.read_component(table, b"", |_, _| {})?;
         }
 
         if self.has_remaining() {
-            err!(self.pos, UnexpectedChar);
+            let (start, end) = self.out.path_bounds;
+            if self.comp == Component::Path && start == end && start == self.pos {
+                if let Some(auth_meta) = &self.out.auth_meta {
+                    // The character directly follows the authority.
+                    let expected = if auth_meta.host_bounds.1 == start {
+                        self.comp = Component::Host;
+                        match auth_meta.host_meta {
+                            HostMeta::Ipv6(..) | HostMeta::IpvFuture => None,
+                            _ => Some(select::<UCS>(REG_NAME, IREG_NAME)),
+                        }
+                    } else {
+                        self.comp = Component::Port;
+                        Some(DIGIT)
+                    };
+                    err!(self.pos, UnexpectedChar, expected);
+                }
+            }
+            err!(self.pos, UnexpectedChar, table);
         }
         Ok(())
     }
+
+    /// Checks whether an '@' follows before the end of authority,
+    /// in which case we're reading the userinfo.
+    fn userinfo_follows(&self) -> bool {
+        self.bytes[self.pos..]
+            .iter()
+            .take_while(|&&x| !matches!(x, b'/' | b'?' | b'#'))
+            .any(|&x| x == b'@')
+    }
+}
+
+/// Diagnostics from parsing a URI reference with [`Uri::diagnose`].
+///
+/// Unlike [`Uri::parse`] which stops at the first error, [`Uri::diagnose`] continues
+/// past recoverable errors, namely invalid percent-encoded octets and characters
+/// not allowed in a component, so that every problem in the input can be
+/// reported at once. The component bounds are determined on a best-effort basis.
+///
+/// # Examples
+///
+/// ```
+/// use fluent_uri::{error::Component, Uri};
+///
+/// let s = "http://exa mple.com:8o/a%zz?b c#d";
+/// let diag = Uri::diagnose(s);
+/// assert!(!diag.is_ok());
+///
+/// let errors: Vec<_> = diag
+///     .errors()
+///     .iter()
+///     .map(|e| (e.index(), e.component()))
+///     .collect();
+/// assert_eq!(
+///     errors,
+///     [
+///         (10, Component::Host),
+///         (21, Component::Port),
+///         (24, Component::Path),
+///         (29, Component::Query),
+///     ]
+/// );
+///
+/// assert_eq!(diag.bounds(Component::Host), Some(7..19));
+/// assert_eq!(diag.bounds(Component::Path), Some(22..27));
+/// assert_eq!(&s[diag.bounds(Component::Fragment).unwrap()], "d");
+/// ```
+#[derive(Clone)]
+pub struct Diagnostics {
+    pub(crate) meta: Meta,
+    pub(crate) len: usize,
+    pub(crate) errors: Vec<ParseError>,
+}
+
+impl Diagnostics {
+    pub(crate) fn new(s: &str) -> Self {
+        let (meta, errors) = parse_all::<false>(s.as_bytes());
+        Diagnostics {
+            meta,
+            len: s.len(),
+            errors,
+        }
+    }
+
+    /// Checks whether no error was found, i.e., the input is a valid URI reference.
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns the errors found, sorted by index.
+    ///
+    /// There is at most one error at each index.
+    #[must_use]
+    pub fn errors(&self) -> &[ParseError] {
+        &self.errors
+    }
+
+    /// Consumes the diagnostics and yields the errors found, sorted by index.
+    #[must_use]
+    pub fn into_errors(self) -> Vec<ParseError> {
+        self.errors
+    }
+
+    /// Returns the best-effort byte range of a component in the input,
+    /// excluding any delimiter.
+    ///
+    /// Returns `None` if the component is not present.
+    #[must_use]
+    pub fn bounds(&self, comp: Component) -> Option<Range<usize>> {
+        let meta = &self.meta;
+        let auth_start = || meta.scheme_end.map_or(0, |i| i.get() + 1) + 2;
+        let (path_start, path_end) = meta.path_bounds;
+        match comp {
+            Component::Scheme => meta.scheme_end.map(|i| 0..i.get()),
+            Component::Userinfo => meta.auth_meta.and_then(|auth| {
+                let start = auth_start();
+                (auth.host_bounds.0 > start).then(|| start..auth.host_bounds.0 - 1)
+            }),
+            Component::Host => meta
+                .auth_meta
+                .map(|auth| auth.host_bounds.0..auth.host_bounds.1),
+            Component::Port => meta.auth_meta.and_then(|auth| {
+                let host_end = auth.host_bounds.1;
+                (host_end < path_start).then(|| host_end + 1..path_start)
+            }),
+            Component::Path => Some(path_start..path_end),
+            Component::Query => meta.query_end.map(|i| path_end + 1..i.get()),
+            Component::Fragment => {
+                let start = meta.query_end.map_or(path_end, |i| i.get());
+                (start < self.len).then(|| start + 1..self.len)
+            }
+        }
+    }
+}
+
+/// Options for parsing a URI reference leniently from untrusted input.
+///
+/// This struct is created by [`ParseOptions::new`], which enables all of the fixups
+/// below. Each fixup is modeled after the preprocessing steps of the [WHATWG URL
+/// Standard][whatwg] and may be disabled individually:
+///
+/// - [`trim_c0_control_or_space`]: Removes any leading and trailing
+///   C0 control or space, i.e., characters in the range `U+0000` to `U+0020`.
+/// - [`remove_tab_or_newline`]: Removes all ASCII tab or newline characters.
+/// - [`backslash_as_slash`]: Treats `'\'` as `'/'` before the query and the
+///   fragment when the scheme is [special].
+/// - [`fix_authority_slashes`]: Makes sure that exactly two slashes follow
+///   the scheme when it is [special] and is not `file`, e.g., turning
+///   `"http:example.com"` into `"http://example.com"`.
+/// - [`encode_invalid_chars`]: Percent-encodes any character that is not allowed
+///   in its component, as well as any `'%'` that is not followed by two
+///   hexadecimal digits and any `':'` in the first path segment of a
+///   relative-path reference.
+///
+/// The fixed-up input is then parsed strictly into a [`Uri<String>`] along with
+/// a report of the [`Fixups`] applied. Note that no normalization is performed.
+///
+/// [whatwg]: https://url.spec.whatwg.org/#concept-basic-url-parser
+/// [special]: https://url.spec.whatwg.org/#special-scheme
+/// [`trim_c0_control_or_space`]: Self::trim_c0_control_or_space
+/// [`remove_tab_or_newline`]: Self::remove_tab_or_newline
+/// [`backslash_as_slash`]: Self::backslash_as_slash
+/// [`fix_authority_slashes`]: Self::fix_authority_slashes
+/// [`encode_invalid_chars`]: Self::encode_invalid_chars
+///
+/// # Examples
+///
+/// ```
+/// use fluent_uri::{Fixup, ParseOptions, Uri};
+///
+/// let (uri, fixups) = Uri::parse_lenient("  http:\\\\example.com\\a b?c d#e#f\n")?;
+/// assert_eq!(uri, "http://example.com/a%20b?c%20d#e%23f");
+/// assert!(fixups.contains(Fixup::TrimmedC0ControlOrSpace));
+/// assert!(fixups.contains(Fixup::ReplacedBackslash));
+/// assert!(fixups.contains(Fixup::EncodedInvalidChar));
+///
+/// let (uri, fixups) = ParseOptions::new()
+///     .encode_invalid_chars(false)
+///     .parse("http:example.com/foo")?;
+/// assert_eq!(uri, "http://example.com/foo");
+/// assert!(fixups.contains(Fixup::FixedAuthoritySlashes));
+///
+/// assert!(ParseOptions::new().encode_invalid_chars(false).parse("a b").is_err());
+/// # Ok::<_, fluent_uri::error::ParseError<String>>(())
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct ParseOptions {
+    trim_c0_control_or_space: bool,
+    remove_tab_or_newline: bool,
+    backslash_as_slash: bool,
+    fix_authority_slashes: bool,
+    encode_invalid_chars: bool,
+}
+
+impl ParseOptions {
+    /// Creates a new `ParseOptions` with all fixups enabled.
+    pub const fn new() -> Self {
+        ParseOptions {
+            trim_c0_control_or_space: true,
+            remove_tab_or_newline: true,
+            backslash_as_slash: true,
+            fix_authority_slashes: true,
+            encode_invalid_chars: true,
+        }
+    }
+
+    /// Sets whether to remove any leading and trailing C0 control or space.
+    pub const fn trim_c0_control_or_space(mut self, enabled: bool) -> Self {
+        self.trim_c0_control_or_space = enabled;
+        self
+    }
+
+    /// Sets whether to remove all ASCII tab or newline characters.
+    pub const fn remove_tab_or_newline(mut self, enabled: bool) -> Self {
+        self.remove_tab_or_newline = enabled;
+        self
+    }
+
+    /// Sets whether to treat `'\'` as `'/'` before the query and the fragment
+    /// when the scheme is special.
+    pub const fn backslash_as_slash(mut self, enabled: bool) -> Self {
+        self.backslash_as_slash = enabled;
+        self
+    }
+
+    /// Sets whether to make sure that exactly two slashes follow
+    /// the scheme when it is special and is not `file`.
+    pub const fn fix_authority_slashes(mut self, enabled: bool) -> Self {
+        self.fix_authority_slashes = enabled;
+        self
+    }
+
+    /// Sets whether to percent-encode characters not allowed in their component.
+    pub const fn encode_invalid_chars(mut self, enabled: bool) -> Self {
+        self.encode_invalid_chars = enabled;
+        self
+    }
+
+    /// Parses a URI reference leniently from a string with the options.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the input still does not match the [`URI-reference`]
+    /// ABNF rule from RFC 3986 after the enabled fixups are applied,
+    /// in which case the error index refers to the fixed-up input,
+    /// which may be recovered by calling [`into_input`] on the error.
+    ///
+    /// [`URI-reference`]: https://datatracker.ietf.org/doc/html/rfc3986/#section-4.1
+    /// [`into_input`]: ParseError::into_input
+    pub fn parse(
+        &self,
+        s: &str,
+    ) -> core::result::Result<(Uri<String>, Fixups), ParseError<String>> {
+        let mut fixups = Fixups::default();
+        let buf = self.preprocess(s, &mut fixups);
+        match parse::<false>(buf.as_bytes()) {
+            Ok(meta) => Ok((Uri { val: buf, meta }, fixups)),
+            Err(e) => Err(e.with_input(buf)),
+        }
+    }
+
+    fn preprocess(&self, mut s: &str, fixups: &mut Fixups) -> String {
+        if self.trim_c0_control_or_space {
+            let trimmed = s.trim_matches(|c| c <= ' ');
+            if trimmed.len() != s.len() {
+                fixups.insert(Fixup::TrimmedC0ControlOrSpace);
+            }
+            s = trimmed;
+        }
+
+        let mut buf = if self.remove_tab_or_newline && s.contains(['\t', '\n', '\r']) {
+            fixups.insert(Fixup::RemovedTabOrNewline);
+            s.chars()
+                .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+                .collect()
+        } else {
+            s.to_owned()
+        };
+
+        let mut reader = Reader::new(buf.as_bytes());
+        let scheme_len = match reader.read(SCHEME) {
+            Ok(true) if buf.as_bytes()[0].is_ascii_alphabetic() && reader.read_str(":") => {
+                reader.pos - 1
+            }
+            _ => 0,
+        };
+        let scheme = &buf[..scheme_len];
+        let special = SPECIAL_SCHEMES
+            .iter()
+            .any(|x| x.eq_ignore_ascii_case(scheme));
+        let file = scheme.eq_ignore_ascii_case("file");
+
+        if special && self.backslash_as_slash {
+            let end = buf.find(['?', '#']).unwrap_or(buf.len());
+            if buf[..end].contains('\\') {
+                fixups.insert(Fixup::ReplacedBackslash);
+                let replaced = buf[..end].replace('\\', "/");
+                buf.replace_range(..end, &replaced);
+            }
+        }
+
+        if special && self.fix_authority_slashes && !file {
+            let rest = &buf[scheme_len + 1..];
+            let slashes = rest.len() - rest.trim_start_matches('/').len();
+            if slashes != 2 {
+                fixups.insert(Fixup::FixedAuthoritySlashes);
+                buf.replace_range(scheme_len + 1..scheme_len + 1 + slashes, "//");
+            }
+        }
+
+        if self.encode_invalid_chars {
+            let mut out = String::with_capacity(buf.len());
+            encode_components(&mut out, &buf, scheme_len, fixups);
+            buf = out;
+        }
+        buf
+    }
+}
+
+impl Default for ParseOptions {
+    /// Creates a new `ParseOptions` with all fixups enabled.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Special schemes defined in the WHATWG URL Standard.
+const SPECIAL_SCHEMES: [&str; 6] = ["ftp", "file", "http", "https", "ws", "wss"];
+
+/// Rebuilds a URI reference from a string with a scheme of the given length,
+/// percent-encoding characters not allowed in their component.
+fn encode_components(buf: &mut String, s: &str, scheme_len: usize, fixups: &mut Fixups) {
+    let mut rest = s;
+    if scheme_len != 0 {
+        buf.push_str(&s[..=scheme_len]);
+        rest = &s[scheme_len + 1..];
+    }
+
+    let (before_frag, fragment) = match rest.split_once('#') {
+        Some((a, b)) => (a, Some(b)),
+        None => (rest, None),
+    };
+    let (before_query, query) = match before_frag.split_once('?') {
+        Some((a, b)) => (a, Some(b)),
+        None => (before_frag, None),
+    };
+
+    let mut path = before_query;
+    if let Some(rem) = before_query.strip_prefix("//") {
+        let auth_end = rem.find('/').unwrap_or(rem.len());
+        let (auth, rem) = rem.split_at(auth_end);
+        path = rem;
+
+        buf.push_str("//");
+        let host_port = match auth.rfind('@') {
+            Some(i) => {
+                encode_invalid(buf, &auth[..i], USERINFO, fixups);
+                buf.push('@');
+                &auth[i + 1..]
+            }
+            None => auth,
+        };
+
+        // Leave an IP literal and the port intact and let the parser validate them.
+        let host_end = if host_port.starts_with('[') {
+            host_port.find(']').map_or(host_port.len(), |i| i + 1)
+        } else {
+            host_port.rfind(':').unwrap_or(host_port.len())
+        };
+        if host_port.starts_with('[') {
+            buf.push_str(&host_port[..host_end]);
+        } else {
+            encode_invalid(buf, &host_port[..host_end], REG_NAME, fixups);
+        }
+        buf.push_str(&host_port[host_end..]);
+    } else if scheme_len == 0 {
+        // In a relative-path reference, the first path segment cannot contain a colon.
+        let first_seg_end = path.find('/').unwrap_or(path.len());
+        if path[..first_seg_end].contains(':') {
+            encode_invalid(buf, &path[..first_seg_end], SEGMENT_NZ_NC, fixups);
+            path = &path[first_seg_end..];
+        }
+    }
+
+    encode_invalid(buf, path, PATH, fixups);
+    if let Some(query) = query {
+        buf.push('?');
+        encode_invalid(buf, query, QUERY, fixups);
+    }
+    if let Some(fragment) = fragment {
+        buf.push('#');
+        encode_invalid(buf, fragment, FRAGMENT, fixups);
+    }
+}
+
+/// Appends a string onto `buf`, percent-encoding any byte not allowed by the table
+/// and any `'%'` that does not start a percent-encoded octet.
+fn encode_invalid(buf: &mut String, s: &str, table: &Table, fixups: &mut Fixups) {
+    let s = s.as_bytes();
+    for (i, &x) in s.iter().enumerate() {
+        let allowed = if x == b'%' {
+            matches!(s.get(i + 1..i + 3), Some(&[hi, lo]) if HEXDIG.allows(hi) && HEXDIG.allows(lo))
+        } else {
+            table.allows(x)
+        };
+        if allowed {
+            buf.push(x as char);
+        } else {
+            fixups.insert(Fixup::EncodedInvalidChar);
+            push_pct_encoded(buf, x);
+        }
+    }
+}
+
+/// A fixup applied when parsing a URI reference leniently.
+///
+/// See [`ParseOptions`] for details.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Fixup {
+    /// Leading or trailing C0 control or space was removed.
+    TrimmedC0ControlOrSpace,
+    /// ASCII tab or newline characters were removed.
+    RemovedTabOrNewline,
+    /// Backslashes were replaced with slashes.
+    ReplacedBackslash,
+    /// The slashes following a special scheme were fixed.
+    FixedAuthoritySlashes,
+    /// Invalid characters were percent-encoded.
+    EncodedInvalidChar,
+}
+
+impl Fixup {
+    const ALL: [Fixup; 5] = [
+        Fixup::TrimmedC0ControlOrSpace,
+        Fixup::RemovedTabOrNewline,
+        Fixup::ReplacedBackslash,
+        Fixup::FixedAuthoritySlashes,
+        Fixup::EncodedInvalidChar,
+    ];
+
+    const fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// A set of fixups applied when parsing a URI reference leniently.
+///
+/// This struct is created by [`ParseOptions::parse`].
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Fixups {
+    bits: u8,
+}
+
+impl Fixups {
+    fn insert(&mut self, fixup: Fixup) {
+        self.bits |= fixup.bit();
+    }
+
+    /// Checks whether the given fixup was applied.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, fixup: Fixup) -> bool {
+        self.bits & fixup.bit() != 0
+    }
+
+    /// Checks whether no fixup was applied, i.e., the input is a valid URI reference.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Returns an iterator over the fixups applied.
+    pub fn iter(&self) -> impl Iterator<Item = Fixup> {
+        let fixups = *self;
+        Fixup::ALL.into_iter().filter(move |&x| fixups.contains(x))
+    }
 }
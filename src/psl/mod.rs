@@ -0,0 +1,250 @@
+//! Public suffixes and registrable domains.
+//!
+//! A [`List`] is parsed from the standard `.dat` format of the
+//! [Public Suffix List] and determines the public suffix and the registrable
+//! domain of a domain name with the [algorithm] described on its website,
+//! including wildcard and exception rules. A copy of the list is embedded and
+//! available through [`List::embedded`]; this copy is subject to the terms of
+//! the [Mozilla Public License, v. 2.0][MPL].
+//!
+//! Domain names are matched against rules case-insensitively for ASCII letters.
+//! Since rules for internationalized domain names are written with U-labels,
+//! a domain name with A-labels only matches such a rule when the `idna`
+//! crate feature is enabled, in which case the A-label form of each such rule
+//! is also added to a `List`.
+//!
+//! [Public Suffix List]: https://publicsuffix.org/
+//! [algorithm]: https://publicsuffix.org/list/#list-format
+//! [MPL]: https://mozilla.org/MPL/2.0/
+//!
+//! # Examples
+//!
+//! ```
+//! use fluent_uri::{psl::List, Uri};
+//!
+//! let list = List::embedded();
+//! assert_eq!(list.public_suffix("www.example.co.uk"), Some("co.uk"));
+//! assert_eq!(list.registrable_domain("www.example.co.uk"), Some("example.co.uk"));
+//! assert_eq!(list.registrable_domain("co.uk"), None);
+//!
+//! let uri = Uri::parse("https://user@WWW.Example.COM:8080/")?;
+//! let domain = list.domain(uri.authority().unwrap().host_parsed()).unwrap();
+//! assert_eq!(domain.public_suffix(), "COM");
+//! assert_eq!(domain.registrable_domain(), Some("Example.COM"));
+//! # Ok::<_, fluent_uri::error::ParseError>(())
+//! ```
+
+use crate::{component::Host, encoding::Encoder};
+use alloc::{borrow::Cow, collections::BTreeMap, vec::Vec};
+
+const EMBEDDED: &str = include_str!("public_suffix_list.dat");
+
+/// A Public Suffix List.
+///
+/// This struct is created by [`List::parse`] or [`List::embedded`].
+/// As parsing a list takes time, a `List` should be created once and reused.
+#[derive(Clone, Debug, Default)]
+pub struct List<'a> {
+    rules: BTreeMap<Cow<'a, str>, Rules>,
+}
+
+/// Rules that apply to a suffix.
+#[derive(Clone, Copy, Debug, Default)]
+struct Rules {
+    /// The suffix is a public suffix.
+    normal: bool,
+    /// Any suffix with one more label is a public suffix.
+    wildcard: bool,
+    /// The suffix is not a public suffix, overriding other rules.
+    exception: bool,
+}
+
+impl<'a> List<'a> {
+    /// Parses a list in the standard `.dat` format.
+    ///
+    /// Each line is only read up to the first whitespace. Empty lines and
+    /// lines starting with `"//"` are ignored, as are malformed rules.
+    #[must_use]
+    pub fn parse(dat: &'a str) -> Self {
+        let mut list = List::default();
+        for line in dat.lines() {
+            let rule = line.split_whitespace().next().unwrap_or("");
+            if rule.is_empty() || rule.starts_with("//") {
+                continue;
+            }
+
+            let (suffix, set): (_, fn(&mut Rules)) = if let Some(rest) = rule.strip_prefix('!') {
+                (rest, |r| r.exception = true)
+            } else if let Some(rest) = rule.strip_prefix("*.") {
+                (rest, |r| r.wildcard = true)
+            } else {
+                (rule, |r| r.normal = true)
+            };
+            if suffix
+                .split('.')
+                .any(|label| label.is_empty() || label.contains('*'))
+            {
+                continue;
+            }
+
+            #[cfg(feature = "idna")]
+            if !suffix.is_ascii() {
+                if let Ok(ascii) = crate::idna::to_ascii(suffix) {
+                    set(list.rules.entry(Cow::Owned(ascii)).or_default());
+                }
+            }
+            let suffix = if suffix.bytes().any(|x| x.is_ascii_uppercase()) {
+                Cow::Owned(suffix.to_ascii_lowercase())
+            } else {
+                Cow::Borrowed(suffix)
+            };
+            set(list.rules.entry(suffix).or_default());
+        }
+        list
+    }
+
+    /// Returns the number of rules in the list.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Checks whether the list contains no rules.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Returns the public suffix of a domain name.
+    ///
+    /// Returns `None` if the domain name is empty or contains an empty label
+    /// other than the root label. A trailing dot is included in the output.
+    #[must_use]
+    pub fn public_suffix<'n>(&self, name: &'n str) -> Option<&'n str> {
+        self.find(name)
+            .map(|(suffix_start, _)| &name[suffix_start..])
+    }
+
+    /// Returns the registrable domain of a domain name, i.e., its public suffix
+    /// with one more label.
+    ///
+    /// Returns `None` if the domain name is itself a public suffix, is empty,
+    /// or contains an empty label other than the root label.
+    /// A trailing dot is included in the output.
+    #[must_use]
+    pub fn registrable_domain<'n>(&self, name: &'n str) -> Option<&'n str> {
+        self.find(name)?.1.map(|start| &name[start..])
+    }
+
+    /// Looks up a host in the list.
+    ///
+    /// A registered name is percent-decoded before lookup. Returns `None` if the
+    /// host is not a registered name, if the percent-decoded registered name is
+    /// not valid UTF-8, or if the [public suffix](Self::public_suffix) is `None`.
+    #[must_use]
+    pub fn domain<'n, E: Encoder>(&self, host: Host<'n, E>) -> Option<Domain<'n>> {
+        let Host::RegName(name) = host else {
+            return None;
+        };
+        let name = name.decode().into_string().ok()?;
+        let (suffix_start, registrable_start) = self.find(&name)?;
+        Some(Domain {
+            name,
+            suffix_start,
+            registrable_start,
+        })
+    }
+
+    /// Returns the starting indexes of the public suffix and the registrable domain.
+    fn find(&self, name: &str) -> Option<(usize, Option<usize>)> {
+        let name = name.strip_suffix('.').unwrap_or(name);
+        if name.is_empty() {
+            return None;
+        }
+        let lowercase;
+        let name = if name.bytes().any(|x| x.is_ascii_uppercase()) {
+            lowercase = name.to_ascii_lowercase();
+            &lowercase[..]
+        } else {
+            name
+        };
+
+        // Starting indexes of labels, from right to left.
+        let starts: Vec<usize> = name
+            .rmatch_indices('.')
+            .map(|(i, _)| i + 1)
+            .chain([0])
+            .collect();
+        if starts.windows(2).any(|w| w[0] - w[1] < 2) || starts[0] == name.len() {
+            return None;
+        }
+
+        // The implicit rule "*" applies if no rule matches.
+        let mut suffix_labels = 1;
+        for (i, &start) in starts.iter().enumerate() {
+            let Some(rules) = self.rules.get(&name[start..]) else {
+                continue;
+            };
+            if rules.exception && i > 0 {
+                suffix_labels = i;
+                break;
+            }
+            if rules.normal {
+                suffix_labels = suffix_labels.max(i + 1);
+            }
+            if rules.wildcard && i + 1 < starts.len() {
+                suffix_labels = suffix_labels.max(i + 2);
+            }
+        }
+
+        Some((
+            starts[suffix_labels - 1],
+            starts.get(suffix_labels).copied(),
+        ))
+    }
+}
+
+impl List<'static> {
+    /// Parses the embedded copy of the Public Suffix List.
+    #[must_use]
+    pub fn embedded() -> Self {
+        List::parse(EMBEDDED)
+    }
+}
+
+/// A domain name split into its public suffix and registrable domain.
+///
+/// This struct is created by [`List::domain`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Domain<'a> {
+    name: Cow<'a, str>,
+    suffix_start: usize,
+    registrable_start: Option<usize>,
+}
+
+impl<'a> Domain<'a> {
+    /// Returns the percent-decoded domain name.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the public suffix.
+    #[must_use]
+    pub fn public_suffix(&self) -> &str {
+        &self.name[self.suffix_start..]
+    }
+
+    /// Returns the registrable domain, or `None` if the domain name
+    /// is itself a public suffix.
+    #[must_use]
+    pub fn registrable_domain(&self) -> Option<&str> {
+        self.registrable_start.map(|start| &self.name[start..])
+    }
+
+    /// Consumes this `Domain` and yields the percent-decoded domain name.
+    #[must_use]
+    pub fn into_name(self) -> Cow<'a, str> {
+        self.name
+    }
+}
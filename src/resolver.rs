@@ -3,36 +3,157 @@ use crate::{
     internal::Meta,
     Uri,
 };
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
+use borrow_or_share::Bos;
 use core::num::NonZeroUsize;
 
+/// Options for resolving URI references against a base URI.
+///
+/// This struct is created by [`ResolveOptions::new`], which yields the same
+/// behavior as [`Uri::resolve_against`]. The following options may be set:
+///
+/// - [`strict`]: Whether to follow the strict algorithm in [Section 5.2.2 of RFC 3986][resolve].
+///   When disabled, a scheme in the reference that is equal to that of the base URI
+///   (compared case-insensitively) is ignored, as with the backward-compatible
+///   non-strict parsers described in the RFC.
+/// - [`allow_path_underflow`]: Whether to allow a double-dot segment to climb
+///   above the root of the path, in which case it is silently dropped.
+///   When disabled, a [`ResolveError`] is returned instead.
+/// - [`whatwg_non_hierarchical_base`]: Whether to follow the [WHATWG URL Standard][whatwg]
+///   when the base URI is non-hierarchical, i.e., contains no authority and has
+///   a rootless path. When enabled, only references with a scheme or starting
+///   with `'#'` are allowed, the empty reference being rejected as well.
+///
+/// [`strict`]: Self::strict
+/// [`allow_path_underflow`]: Self::allow_path_underflow
+/// [`whatwg_non_hierarchical_base`]: Self::whatwg_non_hierarchical_base
+/// [resolve]: https://datatracker.ietf.org/doc/html/rfc3986/#section-5.2.2
+/// [whatwg]: https://url.spec.whatwg.org/#no-scheme-state
+///
+/// # Examples
+///
+/// ```
+/// use fluent_uri::{ResolveOptions, Uri};
+///
+/// let base = Uri::parse("http://a/b/c/d;p?q")?;
+///
+/// let r = Uri::parse("http:g")?;
+/// assert_eq!(r.resolve_against(&base)?, "http:g");
+/// assert_eq!(ResolveOptions::new().strict(false).resolve(&r, &base)?, "http://a/b/c/g");
+///
+/// let r = Uri::parse("../../../g")?;
+/// assert_eq!(r.resolve_against(&base)?, "http://a/g");
+/// assert!(ResolveOptions::new().allow_path_underflow(false).resolve(&r, &base).is_err());
+///
+/// let base = Uri::parse("mailto:user@example.com")?;
+/// let r = Uri::parse("")?;
+/// assert_eq!(r.resolve_against(&base)?, "mailto:user@example.com");
+/// assert!(ResolveOptions::new().whatwg_non_hierarchical_base(true).resolve(&r, &base).is_err());
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct ResolveOptions {
+    strict: bool,
+    allow_path_underflow: bool,
+    whatwg_non_hierarchical_base: bool,
+}
+
+impl ResolveOptions {
+    /// Creates a new `ResolveOptions` that behaves like [`Uri::resolve_against`].
+    pub const fn new() -> Self {
+        ResolveOptions {
+            strict: true,
+            allow_path_underflow: true,
+            whatwg_non_hierarchical_base: false,
+        }
+    }
+
+    /// Sets whether to follow the strict resolution algorithm,
+    /// as opposed to ignoring a scheme in the reference equal to that of the base URI.
+    pub const fn strict(mut self, enabled: bool) -> Self {
+        self.strict = enabled;
+        self
+    }
+
+    /// Sets whether to allow a double-dot segment to climb above the root of the path.
+    pub const fn allow_path_underflow(mut self, enabled: bool) -> Self {
+        self.allow_path_underflow = enabled;
+        self
+    }
+
+    /// Sets whether to follow the WHATWG URL Standard when the base URI is non-hierarchical.
+    pub const fn whatwg_non_hierarchical_base(mut self, enabled: bool) -> Self {
+        self.whatwg_non_hierarchical_base = enabled;
+        self
+    }
+
+    /// Resolves a URI reference against a base URI with the options.
+    ///
+    /// See [`Uri::resolve_against`] for the deviations from the original
+    /// algorithm, which also apply here.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the base URI is not an [absolute URI], if the reference
+    /// is not allowed against a non-hierarchical base URI, or if path underflow
+    /// occurs when disallowed.
+    ///
+    /// [absolute URI]: Uri::is_absolute_uri
+    pub fn resolve<T: Bos<str>, U: Bos<str>>(
+        &self,
+        r: &Uri<T>,
+        base: &Uri<U>,
+    ) -> Result<Uri<String>, ResolveError> {
+        resolve(base.as_ref(), r.as_ref(), *self)
+    }
+}
+
+impl Default for ResolveOptions {
+    /// Creates a new `ResolveOptions` that behaves like [`Uri::resolve_against`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 pub(crate) fn resolve(
     base: Uri<&str>,
     /* reference */ r: Uri<&str>,
+    opts: ResolveOptions,
 ) -> Result<Uri<String>, ResolveError> {
     if !base.is_absolute_uri() {
         return Err(ResolveError(ResolveErrorKind::NonAbsoluteBase));
     }
-    if base.auth_meta.is_none()
-        && base.path().is_rootless()
-        && r.scheme_end.is_none()
-        && !matches!(r.as_str().bytes().next(), None | Some(b'#'))
-    {
-        return Err(ResolveError(ResolveErrorKind::NonHierarchicalBase));
+
+    let (r_scheme, r_authority, r_path, r_query, r_fragment) =
+        (r.scheme(), r.authority(), r.path(), r.query(), r.fragment());
+    let r_scheme = r_scheme.filter(|&scheme| opts.strict || scheme != base.scheme().unwrap());
+
+    if base.auth_meta.is_none() && base.path().is_rootless() && r_scheme.is_none() {
+        let same_document = r_authority.is_none() && r_path.is_empty() && r_query.is_none();
+        if !same_document || (opts.whatwg_non_hierarchical_base && r_fragment.is_none()) {
+            return Err(ResolveError(ResolveErrorKind::NonHierarchicalBase));
+        }
     }
 
+    let remove_dot_segments = |buf: &mut String, path: &str| {
+        if remove_dot_segments_checked(buf, path) || opts.allow_path_underflow {
+            Ok(())
+        } else {
+            Err(ResolveError(ResolveErrorKind::PathUnderflow))
+        }
+    };
+
     let (t_scheme, t_authority, t_path, t_query, t_fragment);
     let mut buf = String::new();
 
-    let (r_scheme, r_authority, r_path, r_query, r_fragment) =
-        (r.scheme(), r.authority(), r.path(), r.query(), r.fragment());
-
     if let Some(r_scheme) = r_scheme {
         t_scheme = r_scheme;
         t_authority = r_authority;
         t_path = if r_path.is_absolute() {
             buf.reserve_exact(r_path.len());
-            remove_dot_segments(&mut buf, r_path.as_str())
+            remove_dot_segments(&mut buf, r_path.as_str())?;
+            &buf
         } else {
             r_path.as_str()
         };
@@ -41,7 +162,8 @@ pub(crate) fn resolve(
         if r_authority.is_some() {
             t_authority = r_authority;
             buf.reserve_exact(r_path.len());
-            t_path = remove_dot_segments(&mut buf, r_path.as_str());
+            remove_dot_segments(&mut buf, r_path.as_str())?;
+            t_path = &buf;
             t_query = r_query;
         } else {
             if r_path.is_empty() {
@@ -54,28 +176,12 @@ pub(crate) fn resolve(
             } else {
                 if r_path.is_absolute() {
                     buf.reserve_exact(r_path.len());
-                    t_path = remove_dot_segments(&mut buf, r_path.as_str());
                 } else {
                     // Instead of merging the paths, remove dot segments incrementally.
-                    let base_path = base.path().as_str();
-                    if base_path.is_empty() {
-                        buf.reserve_exact(r_path.len() + 1);
-                        buf.push('/');
-                    } else {
-                        // Make sure that swapping the order of resolution and normalization
-                        // does not change the result.
-                        let last_slash_i = base_path.rfind('/').unwrap();
-                        let last_seg = &base_path[last_slash_i + 1..];
-                        let base_path_stripped = match classify_segment(last_seg) {
-                            SegKind::DoubleDot => base_path,
-                            _ => &base_path[..=last_slash_i],
-                        };
-
-                        buf.reserve_exact(base_path_stripped.len() + r_path.len());
-                        remove_dot_segments(&mut buf, base_path_stripped);
-                    }
-                    t_path = remove_dot_segments(&mut buf, r_path.as_str());
+                    push_base_dir(&mut buf, base.path().as_str(), r_path.len());
                 }
+                remove_dot_segments(&mut buf, r_path.as_str())?;
+                t_path = &buf;
                 t_query = r_query;
             }
             t_authority = base.authority();
@@ -146,13 +252,170 @@ pub(crate) fn resolve(
     Ok(Uri { val: buf, meta })
 }
 
+/// Pushes the directory of a base path with dot segments removed,
+/// reserving additional capacity for the reference path.
+///
+/// The pushed directory always ends with `'/'`.
+fn push_base_dir(buf: &mut String, base_path: &str, additional: usize) {
+    if base_path.is_empty() {
+        buf.reserve_exact(additional + 1);
+        buf.push('/');
+    } else {
+        // Make sure that swapping the order of resolution and normalization
+        // does not change the result.
+        let last_slash_i = base_path.rfind('/').unwrap();
+        let last_seg = &base_path[last_slash_i + 1..];
+        let base_path_stripped = match classify_segment(last_seg) {
+            SegKind::DoubleDot => base_path,
+            _ => &base_path[..=last_slash_i],
+        };
+
+        buf.reserve_exact(base_path_stripped.len() + additional);
+        remove_dot_segments(buf, base_path_stripped);
+    }
+}
+
+pub(crate) fn make_relative(base: Uri<&str>, target: Uri<&str>) -> Option<Uri<String>> {
+    let resolves_to_target = |r: &Uri<String>| {
+        resolve(base, r.as_ref(), ResolveOptions::new())
+            .map_or(false, |t| t.as_str() == target.as_str())
+    };
+
+    if let Some(r) = relative_candidate(base, target).and_then(|s| Uri::parse(s).ok()) {
+        if resolves_to_target(&r) {
+            return Some(r);
+        }
+    }
+
+    // Fall back to the target itself, which may fail to resolve back
+    // if its path contains dot segments.
+    let r = Uri {
+        val: target.as_str().into(),
+        meta: target.meta,
+    };
+    resolves_to_target(&r).then_some(r)
+}
+
+/// Computes the shortest reference that is expected to resolve
+/// against the base to the target.
+fn relative_candidate(base: Uri<&str>, target: Uri<&str>) -> Option<String> {
+    if target.scheme()?.as_str() != base.scheme()?.as_str() {
+        return None;
+    }
+
+    let mut buf = String::new();
+    let push_rest = |buf: &mut String, with_path: bool| {
+        if with_path {
+            buf.push_str(target.path().as_str());
+        }
+        if let Some(query) = target.query() {
+            buf.push('?');
+            buf.push_str(query.as_str());
+        }
+        if let Some(fragment) = target.fragment() {
+            buf.push('#');
+            buf.push_str(fragment.as_str());
+        }
+    };
+
+    // Same-document reference.
+    let target_end = target.fragment_start().map_or(target.len(), |i| i - 1);
+    if target.as_str()[..target_end] == *base.as_str() {
+        if let Some(fragment) = target.fragment() {
+            buf.push('#');
+            buf.push_str(fragment.as_str());
+        }
+        return Some(buf);
+    }
+
+    // A non-hierarchical base only allows same-document references.
+    if base.auth_meta.is_none() && base.path().is_rootless() {
+        return None;
+    }
+
+    let t_path = target.path().as_str();
+    match (target.authority(), base.authority()) {
+        (Some(t_auth), Some(b_auth)) if t_auth.as_str() == b_auth.as_str() => {}
+        (Some(t_auth), _) => {
+            buf.push_str("//");
+            buf.push_str(t_auth.as_str());
+            push_rest(&mut buf, true);
+            return Some(buf);
+        }
+        (None, Some(_)) => return None,
+        (None, None) => {}
+    }
+
+    if t_path == base.path().as_str() && target.query().is_some() {
+        push_rest(&mut buf, false);
+        return Some(buf);
+    }
+
+    if !t_path.starts_with('/') {
+        // An empty path can only be reached with a network-path reference.
+        let t_auth = target.authority()?;
+        buf.push_str("//");
+        buf.push_str(t_auth.as_str());
+        push_rest(&mut buf, true);
+        return Some(buf);
+    }
+
+    let mut dir = String::new();
+    push_base_dir(&mut dir, base.path().as_str(), 0);
+    let dir_segs: Vec<&str> = match dir.len() {
+        1 => Vec::new(),
+        len => dir[1..len - 1].split('/').collect(),
+    };
+    let t_segs: Vec<&str> = t_path[1..].split('/').collect();
+
+    let common = dir_segs
+        .iter()
+        .zip(&t_segs[..t_segs.len() - 1])
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    for _ in common..dir_segs.len() {
+        buf.push_str("../");
+    }
+    let rest = &t_segs[common..];
+    if buf.is_empty() && (rest == [""] || rest[0].is_empty() || rest[0].contains(':')) {
+        // Avoid an empty reference, an absolute path, or a colon in the first segment.
+        buf.push_str("./");
+    }
+    for (i, seg) in rest.iter().enumerate() {
+        if i > 0 {
+            buf.push('/');
+        }
+        buf.push_str(seg);
+    }
+
+    // An absolute-path reference may be shorter.
+    if !t_path.starts_with("//") && t_path.len() < buf.len() {
+        buf.clear();
+        buf.push_str(t_path);
+    }
+    push_rest(&mut buf, false);
+    Some(buf)
+}
+
 pub(crate) fn remove_dot_segments<'a>(buf: &'a mut String, path: &str) -> &'a str {
+    remove_dot_segments_checked(buf, path);
+    buf
+}
+
+/// Removes dot segments from the path, appending the output to the buffer.
+///
+/// Returns `false` if a double-dot segment climbs above the root.
+fn remove_dot_segments_checked(buf: &mut String, path: &str) -> bool {
+    let mut ok = true;
     for seg in path.split_inclusive('/') {
         let seg_stripped = seg.strip_suffix('/').unwrap_or(seg);
         match classify_segment(seg_stripped) {
             SegKind::Dot => buf.truncate(buf.rfind('/').unwrap() + 1),
             SegKind::DoubleDot => {
-                if buf.len() != 1 {
+                if buf.len() == 1 {
+                    ok = false;
+                } else {
                     buf.truncate(buf.rfind('/').unwrap());
                     buf.truncate(buf.rfind('/').unwrap() + 1);
                 }
@@ -160,16 +423,16 @@ pub(crate) fn remove_dot_segments<'a>(buf: &'a mut String, path: &str) -> &'a st
             SegKind::Normal => buf.push_str(seg),
         }
     }
-    buf
+    ok
 }
 
-enum SegKind {
+pub(crate) enum SegKind {
     Dot,
     DoubleDot,
     Normal,
 }
 
-fn classify_segment(mut seg: &str) -> SegKind {
+pub(crate) fn classify_segment(mut seg: &str) -> SegKind {
     if seg.is_empty() {
         return SegKind::Normal;
     }
@@ -0,0 +1,370 @@
+//! The `data` URI scheme as defined in [RFC 2397].
+//!
+//! [RFC 2397]: https://datatracker.ietf.org/doc/html/rfc2397/
+//!
+//! A `data` URI has the syntax `data:[<mediatype>][;base64],<data>`, where the
+//! media type consists of an optional `type/subtype` followed by any number of
+//! `;attribute=value` parameters. A missing media type defaults to
+//! `text/plain;charset=US-ASCII`.
+//!
+//! Since the data may contain `'?'`, it extends over both the path and the
+//! query of a [`Uri`], while any fragment is not part of it.
+//!
+//! # Examples
+//!
+//! Parse and decode a `data` URI:
+//!
+//! ```
+//! use fluent_uri::{scheme::data::DataUri, Uri};
+//!
+//! let uri = Uri::parse("data:text/plain;charset=utf-8;base64,SGVsbG8sIOS4lueVjCE=")?;
+//! let data = DataUri::new(&uri)?;
+//! assert_eq!(data.mime_type(), "text/plain");
+//! assert_eq!(data.param("Charset").unwrap(), "utf-8");
+//! assert!(data.is_base64());
+//! assert_eq!(&*data.decode()?, "Hello, 世界!".as_bytes());
+//!
+//! let uri = Uri::parse("data:,A%20brief%20note")?;
+//! let data = DataUri::new(&uri)?;
+//! assert_eq!(data.mime_type(), "text/plain");
+//! assert_eq!(&*data.decode()?, b"A brief note");
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! Build a `data` URI:
+//!
+//! ```
+//! use fluent_uri::scheme::data::DataUri;
+//!
+//! let uri = DataUri::builder("image/gif").build(b"GIF89a")?;
+//! assert_eq!(uri.as_str(), "data:image/gif;base64,R0lGODlh");
+//!
+//! let uri = DataUri::builder("text/plain")
+//!     .param("charset", "utf-8")
+//!     .base64(false)
+//!     .build("Hello, 世界!".as_bytes())?;
+//! assert_eq!(uri.as_str(), "data:text/plain;charset=utf-8,Hello,%20%E4%B8%96%E7%95%8C!");
+//! # Ok::<_, fluent_uri::error::DataUriError>(())
+//! ```
+
+use crate::{
+    component::Scheme,
+    encoding::{encoder::Path, encoder::Query, EStr, EString, Encoder, Table},
+    error::{DataUriError, DataUriErrorKind},
+    Uri,
+};
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use borrow_or_share::Bos;
+use core::iter::FusedIterator;
+
+const SCHEME_DATA: &Scheme = Scheme::new_or_panic("data");
+
+/// Characters allowed in a type, subtype or attribute name.
+///
+/// These are the characters allowed both in a `token` defined in
+/// RFC 2045 and unencoded in a path.
+const TOKEN: &Table =
+    &Table::gen(b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!$&'*+-._~");
+
+/// An encoder for parameter values, which encodes `';'` and `','`.
+struct ParamValue(());
+
+impl Encoder for ParamValue {
+    const TABLE: &'static Table = &Path::TABLE.sub(&Table::gen(b";,"));
+}
+
+fn is_token(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|x| TOKEN.allows(x))
+}
+
+fn is_mime_type(s: &str) -> bool {
+    s.split_once('/')
+        .map_or(false, |(ty, subty)| is_token(ty) && is_token(subty))
+}
+
+/// A view of a `data` URI.
+///
+/// This struct is created by [`DataUri::new`].
+#[derive(Clone, Copy, Debug)]
+pub struct DataUri<'a> {
+    mime_type: &'a str,
+    params: &'a str,
+    base64: bool,
+    data: &'a EStr<Query>,
+}
+
+impl<'a> DataUri<'a> {
+    /// Parses a `Uri` as a `data` URI.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the scheme is not `data`, if authority is present,
+    /// if the `','` before the data is missing, or if the media type is malformed.
+    pub fn new<T: Bos<str>>(uri: &'a Uri<T>) -> Result<Self, DataUriError> {
+        let err = |kind| Err(DataUriError(kind));
+
+        if uri.scheme() != Some(SCHEME_DATA) || uri.authority().is_some() {
+            return err(DataUriErrorKind::NotDataUri);
+        }
+
+        let s = uri.as_str();
+        let start = SCHEME_DATA.as_str().len() + 1;
+        let end = uri.fragment().map_or(s.len(), |f| s.len() - f.len() - 1);
+        let s = &s[start..end];
+
+        let Some((media_type, data)) = s.split_once(',') else {
+            return err(DataUriErrorKind::MissingComma);
+        };
+
+        let (media_type, base64) = match media_type.rsplit_once(';') {
+            Some((rest, last)) if last.eq_ignore_ascii_case("base64") => (rest, true),
+            _ => (media_type, false),
+        };
+        let (mime_type, params) = match media_type.split_once(';') {
+            Some((mime_type, params)) => {
+                for param in params.split(';') {
+                    match param.split_once('=') {
+                        Some((name, value)) if is_token(name) && !value.is_empty() => {}
+                        _ => return err(DataUriErrorKind::InvalidMediaType),
+                    }
+                }
+                (mime_type, params)
+            }
+            None => (media_type, ""),
+        };
+
+        if !mime_type.is_empty() && !is_mime_type(mime_type) {
+            return err(DataUriErrorKind::InvalidMediaType);
+        }
+
+        Ok(DataUri {
+            mime_type,
+            params,
+            base64,
+            data: EStr::new_validated(data),
+        })
+    }
+
+    /// Creates a new builder for `data` URI with the given MIME type.
+    ///
+    /// An empty MIME type is omitted from the output.
+    pub fn builder(mime_type: &'a str) -> DataUriBuilder<'a> {
+        DataUriBuilder::new(mime_type)
+    }
+
+    /// Returns the MIME type in the form `type/subtype`, which is
+    /// `"text/plain"` if the media type is omitted.
+    #[must_use]
+    pub fn mime_type(&self) -> &'a str {
+        if self.mime_type.is_empty() {
+            "text/plain"
+        } else {
+            self.mime_type
+        }
+    }
+
+    /// Returns an iterator over the name-value pairs of media type parameters.
+    ///
+    /// The parameter `charset=US-ASCII` is not yielded when implied by
+    /// an omitted media type.
+    pub fn params(&self) -> Params<'a> {
+        Params {
+            inner: (!self.params.is_empty()).then(|| self.params.split(';')),
+        }
+    }
+
+    /// Returns the value of the first media type parameter with
+    /// the given name, compared case-insensitively.
+    #[must_use]
+    pub fn param(&self, name: &str) -> Option<&'a EStr<Path>> {
+        self.params()
+            .find(|(n, _)| n.as_str().eq_ignore_ascii_case(name))
+            .map(|(_, v)| v)
+    }
+
+    /// Checks whether the data is base64-encoded.
+    #[must_use]
+    pub fn is_base64(&self) -> bool {
+        self.base64
+    }
+
+    /// Returns the data as is, without percent-decoding or base64-decoding.
+    #[must_use]
+    pub fn data(&self) -> &'a EStr<Query> {
+        self.data
+    }
+
+    /// Percent-decodes the data, and base64-decodes it if [base64-encoded].
+    ///
+    /// ASCII whitespace and missing padding are tolerated in base64.
+    ///
+    /// [base64-encoded]: Self::is_base64
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the data is base64-encoded but is not valid base64.
+    pub fn decode(&self) -> Result<Cow<'a, [u8]>, DataUriError> {
+        let decoded = self.data.decode();
+        if !self.base64 {
+            return Ok(decoded.into_bytes());
+        }
+        base64_decode(decoded.as_bytes())
+            .map(Cow::Owned)
+            .ok_or(DataUriError(DataUriErrorKind::InvalidBase64))
+    }
+}
+
+/// An iterator over the name-value pairs of media type parameters.
+///
+/// This struct is created by [`DataUri::params`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Params<'a> {
+    inner: Option<core::str::Split<'a, char>>,
+}
+
+impl<'a> Iterator for Params<'a> {
+    type Item = (&'a EStr<Path>, &'a EStr<Path>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (name, value) = self.inner.as_mut()?.next()?.split_once('=')?;
+        Some((EStr::new_validated(name), EStr::new_validated(value)))
+    }
+}
+
+impl FusedIterator for Params<'_> {}
+
+/// A builder for `data` URI.
+///
+/// This struct is created by [`DataUri::builder`], which enables base64
+/// encoding of the data.
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct DataUriBuilder<'a> {
+    mime_type: &'a str,
+    params: Vec<(&'a str, &'a str)>,
+    base64: bool,
+}
+
+impl<'a> DataUriBuilder<'a> {
+    /// Creates a new builder with the given MIME type and base64 encoding enabled.
+    ///
+    /// An empty MIME type is omitted from the output.
+    pub fn new(mime_type: &'a str) -> Self {
+        DataUriBuilder {
+            mime_type,
+            params: Vec::new(),
+            base64: true,
+        }
+    }
+
+    /// Appends a media type parameter.
+    ///
+    /// The value is percent-encoded where necessary.
+    pub fn param(mut self, name: &'a str, value: &'a str) -> Self {
+        self.params.push((name, value));
+        self
+    }
+
+    /// Sets whether to base64-encode the data.
+    ///
+    /// If disabled, the data is percent-encoded where necessary instead.
+    pub fn base64(mut self, enabled: bool) -> Self {
+        self.base64 = enabled;
+        self
+    }
+
+    /// Builds a `data` URI with the given data.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the MIME type is not of the form `type/subtype`, if a
+    /// parameter name or value is empty, or if a type, subtype or parameter name
+    /// contains a character other than an ASCII letter, digit or one of
+    /// ``!$&'*+-._~``.
+    pub fn build(&self, data: &[u8]) -> Result<Uri<String>, DataUriError> {
+        let invalid = DataUriError(DataUriErrorKind::InvalidMediaType);
+        if !self.mime_type.is_empty() && !is_mime_type(self.mime_type) {
+            return Err(invalid);
+        }
+
+        let mut path = EString::<Path>::new();
+        path.encode::<Path>(self.mime_type);
+        for &(name, value) in &self.params {
+            if !is_token(name) || value.is_empty() {
+                return Err(invalid);
+            }
+            path.push_byte(b';');
+            path.encode::<Path>(name);
+            path.push_byte(b'=');
+            path.encode::<ParamValue>(value);
+        }
+        if self.base64 {
+            path.encode::<Path>(";base64,");
+            path.encode::<Path>(&base64_encode(data));
+        } else {
+            path.push_byte(b',');
+            path.encode::<Path>(data);
+        }
+
+        Ok(Uri::builder()
+            .scheme(SCHEME_DATA)
+            .path(&path)
+            .build()
+            .expect("path should be rootless"))
+    }
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64_encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity((data.len() + 2) / 3 * 4);
+    for chunk in data.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, &x)| n | u32::from(x) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (n >> (18 - 6 * i)) & 0x3f;
+                out.push(char::from(BASE64_ALPHABET[index as usize]));
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+fn base64_decode(s: &[u8]) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(s.len() / 4 * 3);
+    let (mut acc, mut bits) = (0u32, 0u32);
+    let (mut len, mut padding) = (0usize, 0usize);
+
+    for &x in s {
+        if x.is_ascii_whitespace() {
+            continue;
+        }
+        if x == b'=' {
+            padding += 1;
+            continue;
+        }
+        if padding > 0 {
+            return None;
+        }
+        let value = BASE64_ALPHABET.iter().position(|&y| y == x)? as u32;
+        acc = (acc << 6 | value) & 0xfff;
+        bits += 6;
+        len += 1;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits) as u8);
+        }
+    }
+
+    if len % 4 == 1 || padding > 2 || (padding > 0 && (len + padding) % 4 != 0) {
+        return None;
+    }
+    Some(out)
+}
@@ -0,0 +1,327 @@
+//! The `mailto` URI scheme as defined in [RFC 6068].
+//!
+//! [RFC 6068]: https://datatracker.ietf.org/doc/html/rfc6068/
+//!
+//! A `mailto` URI has the syntax `mailto:[<to>][?<hfields>]`, where `to` is
+//! a list of addresses separated by `','` and `hfields` is a list of
+//! `hfname=hfvalue` header fields separated by `'&'`. Both are percent-encoded
+//! UTF-8, and `'+'` does not stand for a space.
+//!
+//! # Examples
+//!
+//! Parse a `mailto` URI:
+//!
+//! ```
+//! use fluent_uri::{scheme::mailto::MailtoUri, Uri};
+//!
+//! let uri = Uri::parse(
+//!     "mailto:alice@example.com,%22b%2Cob%22@example.com?subject=Hello%20there&cc=carol@example.com",
+//! )?;
+//! let mailto = MailtoUri::new(&uri)?;
+//! assert!(mailto.to().eq(["alice@example.com", "\"b,ob\"@example.com"]));
+//! assert_eq!(mailto.subject().unwrap(), "Hello there");
+//! assert_eq!(mailto.header("CC").unwrap(), "carol@example.com");
+//! assert_eq!(mailto.body(), None);
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! Build a `mailto` URI:
+//!
+//! ```
+//! use fluent_uri::scheme::mailto::MailtoUri;
+//!
+//! let uri = MailtoUri::builder()
+//!     .to("alice@example.com")
+//!     .to("\"b,ob\"@example.com")
+//!     .subject("Hello & goodbye")
+//!     .body("Line 1\nLine 2")
+//!     .build()?;
+//! assert_eq!(
+//!     uri.as_str(),
+//!     "mailto:alice@example.com,%22b%2Cob%22@example.com\
+//!      ?subject=Hello%20%26%20goodbye&body=Line%201%0D%0ALine%202"
+//! );
+//! # Ok::<_, fluent_uri::error::MailtoUriError>(())
+//! ```
+
+use crate::{
+    component::Scheme,
+    encoding::{
+        encoder::{Path, Query},
+        table::UNRESERVED,
+        EStr, EString, Encoder, Table,
+    },
+    error::{MailtoUriError, MailtoUriErrorKind},
+    Builder, Uri,
+};
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use borrow_or_share::Bos;
+use core::{iter::FusedIterator, str};
+
+const SCHEME_MAILTO: &Scheme = Scheme::new_or_panic("mailto");
+
+/// Characters allowed unencoded in addresses and header fields by the
+/// `qchar` ABNF rule, except for `','`.
+const QCHAR_NO_COMMA: &Table = &UNRESERVED.or(&Table::gen(b"!$'()*+;:@")).enc();
+
+/// An encoder for addresses, which encodes `','`.
+struct Address(());
+
+impl Encoder for Address {
+    const TABLE: &'static Table = QCHAR_NO_COMMA;
+}
+
+/// An encoder for header field names and values, which encodes `'&'` and `'='`.
+struct HeaderField(());
+
+impl Encoder for HeaderField {
+    const TABLE: &'static Table = &QCHAR_NO_COMMA.or(&Table::gen(b","));
+}
+
+/// A view of a `mailto` URI.
+///
+/// This struct is created by [`MailtoUri::new`].
+#[derive(Clone, Copy, Debug)]
+pub struct MailtoUri<'a> {
+    to: &'a EStr<Path>,
+    hfields: Option<&'a EStr<Query>>,
+}
+
+impl<'a> MailtoUri<'a> {
+    /// Parses a `Uri` as a `mailto` URI.
+    ///
+    /// An empty query is taken as having no header fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the scheme is not `mailto`, if authority is present,
+    /// if any percent-decoded address or header field is not valid UTF-8,
+    /// if any address is empty, or if any header field lacks a `'='`
+    /// or has an empty name.
+    pub fn new<T: Bos<str>>(uri: &'a Uri<T>) -> Result<Self, MailtoUriError> {
+        let err = |kind| Err(MailtoUriError(kind));
+
+        if uri.scheme() != Some(SCHEME_MAILTO) || uri.authority().is_some() {
+            return err(MailtoUriErrorKind::NotMailtoUri);
+        }
+
+        let to = uri.path();
+        if !to.is_empty() {
+            for addr in to.split(',') {
+                if addr.is_empty() {
+                    return err(MailtoUriErrorKind::EmptyAddress);
+                }
+                if addr.decode().into_string().is_err() {
+                    return err(MailtoUriErrorKind::InvalidUtf8);
+                }
+            }
+        }
+
+        let hfields = uri.query().filter(|query| !query.is_empty());
+        if let Some(hfields) = hfields {
+            for hfield in hfields.split('&') {
+                let Some((name, value)) = hfield.split_once('=') else {
+                    return err(MailtoUriErrorKind::InvalidHeaderField);
+                };
+                if name.is_empty() {
+                    return err(MailtoUriErrorKind::InvalidHeaderField);
+                }
+                if name.decode().into_string().is_err() || value.decode().into_string().is_err() {
+                    return err(MailtoUriErrorKind::InvalidUtf8);
+                }
+            }
+        }
+
+        Ok(MailtoUri { to, hfields })
+    }
+
+    /// Creates a new builder for `mailto` URI.
+    pub fn builder() -> MailtoUriBuilder<'a> {
+        MailtoUriBuilder::new()
+    }
+
+    /// Returns an iterator over the percent-decoded addresses in the path.
+    ///
+    /// Addresses in `to` header fields are not included.
+    pub fn to(&self) -> Addresses<'a> {
+        Addresses {
+            inner: (!self.to.is_empty()).then(|| self.to.as_str().split(',')),
+        }
+    }
+
+    /// Returns an iterator over the percent-decoded name-value pairs of header fields.
+    pub fn headers(&self) -> Headers<'a> {
+        Headers {
+            inner: self.hfields.map(|hfields| hfields.as_str().split('&')),
+        }
+    }
+
+    /// Returns the percent-decoded value of the first header field with
+    /// the given name, compared case-insensitively.
+    #[must_use]
+    pub fn header(&self, name: &str) -> Option<Cow<'a, str>> {
+        self.headers()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v)
+    }
+
+    /// Returns the percent-decoded value of the first `subject` header field.
+    #[must_use]
+    pub fn subject(&self) -> Option<Cow<'a, str>> {
+        self.header("subject")
+    }
+
+    /// Returns the percent-decoded value of the first `body` header field.
+    #[must_use]
+    pub fn body(&self) -> Option<Cow<'a, str>> {
+        self.header("body")
+    }
+}
+
+/// An iterator over the percent-decoded addresses in a `mailto` URI.
+///
+/// This struct is created by [`MailtoUri::to`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Addresses<'a> {
+    inner: Option<str::Split<'a, char>>,
+}
+
+impl<'a> Iterator for Addresses<'a> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let addr = EStr::<Path>::new_validated(self.inner.as_mut()?.next()?);
+        Some(addr.decode().into_string_lossy())
+    }
+}
+
+impl FusedIterator for Addresses<'_> {}
+
+/// An iterator over the percent-decoded name-value pairs of header fields
+/// in a `mailto` URI.
+///
+/// This struct is created by [`MailtoUri::headers`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Headers<'a> {
+    inner: Option<str::Split<'a, char>>,
+}
+
+impl<'a> Iterator for Headers<'a> {
+    type Item = (Cow<'a, str>, Cow<'a, str>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let hfield = EStr::<Query>::new_validated(self.inner.as_mut()?.next()?);
+        let (name, value) = hfield.split_once('=')?;
+        Some((
+            name.decode().into_string_lossy(),
+            value.decode().into_string_lossy(),
+        ))
+    }
+}
+
+impl FusedIterator for Headers<'_> {}
+
+/// A builder for `mailto` URI.
+///
+/// This struct is created by [`MailtoUri::builder`].
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct MailtoUriBuilder<'a> {
+    to: Vec<&'a str>,
+    headers: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> MailtoUriBuilder<'a> {
+    /// Creates a new builder with no addresses or header fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an address to the path.
+    pub fn to(mut self, addr: &'a str) -> Self {
+        self.to.push(addr);
+        self
+    }
+
+    /// Appends a header field.
+    ///
+    /// Line breaks in the value of a `body` header field are
+    /// normalized to CRLF on build.
+    pub fn header(mut self, name: &'a str, value: &'a str) -> Self {
+        self.headers.push((name, value));
+        self
+    }
+
+    /// Appends a `subject` header field.
+    pub fn subject(self, value: &'a str) -> Self {
+        self.header("subject", value)
+    }
+
+    /// Appends a `body` header field.
+    ///
+    /// Line breaks in the value are normalized to CRLF on build.
+    pub fn body(self, value: &'a str) -> Self {
+        self.header("body", value)
+    }
+
+    /// Builds a `mailto` URI, percent-encoding addresses and header fields
+    /// where necessary.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if any address or header field name is empty.
+    pub fn build(&self) -> Result<Uri<String>, MailtoUriError> {
+        let mut to = EString::<Path>::new();
+        for (i, addr) in self.to.iter().enumerate() {
+            if addr.is_empty() {
+                return Err(MailtoUriError(MailtoUriErrorKind::EmptyAddress));
+            }
+            if i > 0 {
+                to.push_byte(b',');
+            }
+            to.encode::<Address>(addr);
+        }
+
+        let mut hfields = EString::<Query>::new();
+        for (i, &(name, value)) in self.headers.iter().enumerate() {
+            if name.is_empty() {
+                return Err(MailtoUriError(MailtoUriErrorKind::InvalidHeaderField));
+            }
+            if i > 0 {
+                hfields.push_byte(b'&');
+            }
+            hfields.encode::<HeaderField>(name);
+            hfields.push_byte(b'=');
+            if name.eq_ignore_ascii_case("body") {
+                encode_body(&mut hfields, value);
+            } else {
+                hfields.encode::<HeaderField>(value);
+            }
+        }
+
+        Ok(Uri::builder()
+            .scheme(SCHEME_MAILTO)
+            .path(&to)
+            .optional(
+                Builder::query,
+                (!self.headers.is_empty()).then_some(&*hfields),
+            )
+            .build()
+            .expect("path should be rootless"))
+    }
+}
+
+/// Encodes the value of a `body` header field with line breaks normalized to CRLF.
+fn encode_body(buf: &mut EString<Query>, value: &str) {
+    let mut lines = value.split('\n').peekable();
+    while let Some(line) = lines.next() {
+        if lines.peek().is_some() {
+            buf.encode::<HeaderField>(line.strip_suffix('\r').unwrap_or(line));
+            buf.encode::<HeaderField>("\r\n");
+        } else {
+            buf.encode::<HeaderField>(line);
+        }
+    }
+}
@@ -0,0 +1,143 @@
+//! Scheme-specific rules.
+//!
+//! A [`SchemeRegistry`] maps a [`Scheme`] to the [`SchemeRules`] defined by its
+//! specification, which drive the scheme-based normalization described in
+//! [Section 6.2.3 of RFC 3986][scheme-based] and performed by [`Uri::normalize_with`].
+//!
+//! Modules for specific schemes, such as [`data`] and [`mailto`], provide views of URIs
+//! with those schemes.
+//!
+//! [scheme-based]: https://datatracker.ietf.org/doc/html/rfc3986/#section-6.2.3
+//!
+//! # Examples
+//!
+//! ```
+//! use fluent_uri::{
+//!     component::Scheme,
+//!     scheme::{SchemeRules, WELL_KNOWN},
+//!     Uri,
+//! };
+//!
+//! let uri = Uri::parse("HTTP://example.com:80")?;
+//! assert_eq!(uri.normalize_with(WELL_KNOWN), "http://example.com/");
+//!
+//! // A registry may also be a closure.
+//! const SCHEME_FOO: &Scheme = Scheme::new_or_panic("foo");
+//! let registry = |scheme: &Scheme| {
+//!     (scheme == SCHEME_FOO).then(|| SchemeRules::new().default_port(1234))
+//! };
+//! let uri = Uri::parse("foo://example.com:1234")?;
+//! assert_eq!(uri.normalize_with(&registry), "foo://example.com");
+//! # Ok::<_, fluent_uri::error::ParseError>(())
+//! ```
+//!
+//! [`Uri::normalize_with`]: crate::Uri::normalize_with
+
+pub mod data;
+pub mod mailto;
+
+use crate::component::Scheme;
+
+/// Rules defined by the specification of a scheme.
+///
+/// This struct is created by [`SchemeRules::new`], which enables none of the rules.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[must_use]
+pub struct SchemeRules {
+    pub(crate) default_port: Option<u16>,
+    pub(crate) empty_path_as_root: bool,
+    pub(crate) remove_empty_query: bool,
+}
+
+impl SchemeRules {
+    /// Creates a new `SchemeRules` with none of the rules enabled.
+    pub const fn new() -> Self {
+        SchemeRules {
+            default_port: None,
+            empty_path_as_root: false,
+            remove_empty_query: false,
+        }
+    }
+
+    /// Sets the default port of the scheme, which is removed on normalization.
+    pub const fn default_port(mut self, port: u16) -> Self {
+        self.default_port = Some(port);
+        self
+    }
+
+    /// Sets whether an empty path is equivalent to `"/"` when authority is present,
+    /// in which case the former is normalized to the latter.
+    pub const fn empty_path_as_root(mut self, enabled: bool) -> Self {
+        self.empty_path_as_root = enabled;
+        self
+    }
+
+    /// Sets whether an empty query is equivalent to an absent one,
+    /// in which case its `'?'` delimiter is removed on normalization.
+    pub const fn remove_empty_query(mut self, enabled: bool) -> Self {
+        self.remove_empty_query = enabled;
+        self
+    }
+}
+
+impl Default for SchemeRules {
+    /// Creates a new `SchemeRules` with none of the rules enabled.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A registry of rules for schemes.
+///
+/// This trait is implemented for slices of scheme-rules pairs, such as
+/// [`WELL_KNOWN`], and for closures `Fn(&Scheme) -> Option<SchemeRules>`.
+pub trait SchemeRegistry {
+    /// Returns the rules for the scheme, or `None` if the scheme is unknown.
+    fn rules(&self, scheme: &Scheme) -> Option<SchemeRules>;
+}
+
+impl SchemeRegistry for [(&Scheme, SchemeRules)] {
+    fn rules(&self, scheme: &Scheme) -> Option<SchemeRules> {
+        self.iter()
+            .find(|(name, _)| *name == scheme)
+            .map(|&(_, rules)| rules)
+    }
+}
+
+impl<const N: usize> SchemeRegistry for [(&Scheme, SchemeRules); N] {
+    fn rules(&self, scheme: &Scheme) -> Option<SchemeRules> {
+        self[..].rules(scheme)
+    }
+}
+
+impl<F: Fn(&Scheme) -> Option<SchemeRules>> SchemeRegistry for F {
+    fn rules(&self, scheme: &Scheme) -> Option<SchemeRules> {
+        self(scheme)
+    }
+}
+
+const fn http_like(port: u16) -> SchemeRules {
+    SchemeRules::new()
+        .default_port(port)
+        .empty_path_as_root(true)
+}
+
+/// Rules for well-known schemes with a default port.
+///
+/// | Scheme  | Default port | Empty path as `"/"` |
+/// | ------- | ------------ | ------------------- |
+/// | `ftp`   | 21           | Yes                 |
+/// | `http`  | 80           | Yes                 |
+/// | `https` | 443          | Yes                 |
+/// | `ws`    | 80           | Yes                 |
+/// | `wss`   | 443          | Yes                 |
+///
+/// An empty query is kept, as a URI with it cannot be assumed to be
+/// equivalent to one without it, e.g., `http://example.com/?`.
+pub const WELL_KNOWN: &[(&Scheme, SchemeRules)] = &[
+    (Scheme::new_or_panic("ftp"), http_like(21)),
+    (Scheme::new_or_panic("http"), http_like(80)),
+    (Scheme::new_or_panic("https"), http_like(443)),
+    (Scheme::new_or_panic("ws"), http_like(80)),
+    (Scheme::new_or_panic("wss"), http_like(443)),
+];
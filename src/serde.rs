@@ -0,0 +1,122 @@
+use crate::{
+    encoding::{EStr, EString, Encoder},
+    Uri,
+};
+use ::serde::{
+    de::{self, Unexpected, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use alloc::{borrow::ToOwned, string::String};
+use borrow_or_share::Bos;
+use core::{fmt, marker::PhantomData};
+
+impl<T: Bos<str>> Serialize for Uri<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+struct UriVisitor<T>(PhantomData<T>);
+
+impl<'de> Visitor<'de> for UriVisitor<&'de str> {
+    type Value = Uri<&'de str>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a borrowed URI reference")
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        Uri::parse(v).map_err(E::custom)
+    }
+}
+
+impl<'de> Visitor<'de> for UriVisitor<String> {
+    type Value = Uri<String>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a URI reference")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Uri::parse(v).map(|uri| uri.to_owned()).map_err(E::custom)
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        Uri::parse(v).map_err(|e| E::custom(e.strip_input()))
+    }
+}
+
+/// Deserializes without copying, failing if the input cannot be borrowed.
+impl<'de: 'a, 'a> Deserialize<'de> for Uri<&'a str> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(UriVisitor::<&'de str>(PhantomData))
+    }
+}
+
+impl<'de> Deserialize<'de> for Uri<String> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_string(UriVisitor::<String>(PhantomData))
+    }
+}
+
+impl<E: Encoder> Serialize for EStr<E> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<E: Encoder> Serialize for EString<E> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+struct EStrVisitor<T>(PhantomData<T>);
+
+impl<'de, E: Encoder> Visitor<'de> for EStrVisitor<&'de EStr<E>> {
+    type Value = &'de EStr<E>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a borrowed percent-encoded string")
+    }
+
+    fn visit_borrowed_str<Er: de::Error>(self, v: &'de str) -> Result<Self::Value, Er> {
+        EStr::new(v).ok_or_else(|| Er::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+impl<'de, E: Encoder> Visitor<'de> for EStrVisitor<EString<E>> {
+    type Value = EString<E>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a percent-encoded string")
+    }
+
+    fn visit_str<Er: de::Error>(self, v: &str) -> Result<Self::Value, Er> {
+        match EStr::<E>::new(v) {
+            Some(s) => Ok(s.to_owned()),
+            None => Err(Er::invalid_value(Unexpected::Str(v), &self)),
+        }
+    }
+
+    fn visit_string<Er: de::Error>(self, v: String) -> Result<Self::Value, Er> {
+        if EStr::<E>::new(&v).is_some() {
+            Ok(EString::new_validated(v))
+        } else {
+            Err(Er::invalid_value(Unexpected::Str(&v), &self))
+        }
+    }
+}
+
+/// Deserializes without copying, failing if the input cannot be borrowed.
+impl<'de: 'a, 'a, E: Encoder> Deserialize<'de> for &'a EStr<E> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(EStrVisitor::<&'de EStr<E>>(PhantomData))
+    }
+}
+
+impl<'de, E: Encoder> Deserialize<'de> for EString<E> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_string(EStrVisitor::<EString<E>>(PhantomData))
+    }
+}
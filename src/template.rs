@@ -0,0 +1,959 @@
+//! URI templates ([RFC 6570]).
+//!
+//! A URI template is a compact sequence of characters for describing a range of
+//! URI references through variable expansion. All four levels of templates
+//! defined in RFC 6570 are supported:
+//!
+//! | Level | Expressions                                                    |
+//! |-------|----------------------------------------------------------------|
+//! | 1     | Simple string expansion: `{var}`                               |
+//! | 2     | Reserved expansion: `{+var}`, fragment expansion: `{#var}`     |
+//! | 3     | Multiple variables, label, path segment, path-style parameter, |
+//! |       | form-style query and query continuation expansion: `{x,y}`,    |
+//! |       | `{.x}`, `{/x}`, `{;x}`, `{?x}`, `{&x}`                         |
+//! | 4     | Value modifiers: prefix `{var:3}` and explode `{var*}`         |
+//!
+//! [RFC 6570]: https://datatracker.ietf.org/doc/html/rfc6570/
+//!
+//! # Examples
+//!
+//! ```
+//! use fluent_uri::template::{UriTemplate, Value};
+//! use std::collections::HashMap;
+//!
+//! let template = UriTemplate::parse("/users/{id}{?fields*}")?;
+//!
+//! let mut vars = HashMap::new();
+//! vars.insert("id", Value::from("42"));
+//! vars.insert("fields", Value::from(vec![("name", "Zoë"), ("sort", "asc")]));
+//!
+//! let uri = template.expand(&vars)?;
+//! assert_eq!(uri, "/users/42?name=Zo%C3%AB&sort=asc");
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::{
+    encoding::{next_code_point, table::*, EStr, Encoder, Table},
+    error::{
+        ExpandError, ExpandErrorKind, MatchError, MatchErrorKind, TemplateError, TemplateErrorKind,
+    },
+    Uri,
+};
+use alloc::{
+    borrow::{Borrow, ToOwned},
+    collections::BTreeMap,
+    string::String,
+    vec::Vec,
+};
+use borrow_or_share::Bos;
+use core::str::{self, FromStr};
+
+/// Characters allowed as literals.
+const LITERAL: &Table = &UNRESERVED
+    .or(RESERVED)
+    .sub(&Table::gen(b"'"))
+    .enc()
+    .ucschar()
+    .iprivate();
+
+/// Characters allowed in variable names.
+const VARCHAR: &Table = &ALPHA.or(DIGIT).or(&Table::gen(b"_")).enc();
+
+/// Characters preserved by reserved and fragment expansion.
+const UNRESERVED_OR_RESERVED: &Table = &UNRESERVED.or(RESERVED);
+
+/// An encoder for substrings of a URI reference captured by an expression.
+struct Captured;
+
+impl Encoder for Captured {
+    const TABLE: &'static Table = &UNRESERVED.or(RESERVED).enc();
+}
+
+/// Returns immediately with an error.
+macro_rules! err {
+    ($index:expr, $kind:ident) => {
+        return Err(TemplateError {
+            index: $index,
+            kind: TemplateErrorKind::$kind,
+        })
+    };
+}
+
+/// A parsed URI template.
+///
+/// See the [module-level documentation](self) for an overview.
+///
+/// # Examples
+///
+/// ```
+/// use fluent_uri::template::{UriTemplate, Value};
+///
+/// let template = UriTemplate::parse("http://example.com/{+path}/here{#frag}")?;
+/// let vars = [
+///     ("path", Value::from("foo/bar")),
+///     ("frag", Value::from(["a b", "c"])),
+/// ];
+/// let uri = template.expand(&vars)?;
+/// assert_eq!(uri, "http://example.com/foo/bar/here#a%20b,c");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone)]
+pub struct UriTemplate {
+    source: String,
+    parts: Vec<Part>,
+}
+
+#[derive(Clone)]
+enum Part {
+    Literal((usize, usize)),
+    Expr(Operator, Vec<VarSpec>),
+}
+
+#[derive(Clone, Copy)]
+struct VarSpec {
+    name: (usize, usize),
+    modifier: Modifier,
+}
+
+#[derive(Clone, Copy)]
+enum Modifier {
+    None,
+    Prefix(usize),
+    Explode,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Simple,
+    Reserved,
+    Fragment,
+    Label,
+    PathSegment,
+    PathParam,
+    Query,
+    QueryCont,
+}
+
+impl Operator {
+    fn from_byte(x: u8) -> Option<Self> {
+        Some(match x {
+            b'+' => Self::Reserved,
+            b'#' => Self::Fragment,
+            b'.' => Self::Label,
+            b'/' => Self::PathSegment,
+            b';' => Self::PathParam,
+            b'?' => Self::Query,
+            b'&' => Self::QueryCont,
+            _ => return None,
+        })
+    }
+
+    /// The string to append before the first defined variable.
+    fn first(self) -> &'static str {
+        match self {
+            Self::Simple | Self::Reserved => "",
+            Self::Fragment => "#",
+            Self::Label => ".",
+            Self::PathSegment => "/",
+            Self::PathParam => ";",
+            Self::Query => "?",
+            Self::QueryCont => "&",
+        }
+    }
+
+    /// The separator between expanded values.
+    fn sep(self) -> &'static str {
+        match self {
+            Self::Simple | Self::Reserved | Self::Fragment => ",",
+            Self::Label => ".",
+            Self::PathSegment => "/",
+            Self::PathParam => ";",
+            Self::Query | Self::QueryCont => "&",
+        }
+    }
+
+    /// Whether to expand as name-value pairs.
+    fn named(self) -> bool {
+        matches!(self, Self::PathParam | Self::Query | Self::QueryCont)
+    }
+
+    /// The string to append after the name when the value is empty.
+    fn ifemp(self) -> &'static str {
+        match self {
+            Self::Query | Self::QueryCont => "=",
+            _ => "",
+        }
+    }
+
+    /// Whether reserved characters and percent-encoded octets are preserved.
+    fn allows_reserved(self) -> bool {
+        matches!(self, Self::Reserved | Self::Fragment)
+    }
+
+    /// Returns the characters that may follow `first` in an expansion
+    /// of the given variables, not including percent-encoded octets.
+    fn capture_table(self, specs: &[VarSpec]) -> Table {
+        if self.allows_reserved() {
+            return *UNRESERVED_OR_RESERVED;
+        }
+        let explode = specs
+            .iter()
+            .any(|s| matches!(s.modifier, Modifier::Explode));
+        let mut table = UNRESERVED.or(&Table::gen(b","));
+        if self.named() || explode {
+            table = table.or(&Table::gen(b"="));
+        }
+        if specs.len() > 1 || explode {
+            table = table.or(&Table::gen(self.sep().as_bytes()));
+        }
+        table
+    }
+
+    fn encode(self, buf: &mut String, s: &str) {
+        if self.allows_reserved() {
+            encode_reserved(buf, s);
+        } else {
+            UNRESERVED.encode(s.as_bytes(), buf);
+        }
+    }
+}
+
+/// Encodes a string, preserving unreserved and reserved
+/// characters as well as percent-encoded octets.
+fn encode_reserved(buf: &mut String, s: &str) {
+    let s = s.as_bytes();
+    let (mut i, mut start) = (0, 0);
+    while i < s.len() {
+        if s[i] == b'%' && i + 2 < s.len() && HEXDIG.allows(s[i + 1]) && HEXDIG.allows(s[i + 2]) {
+            UNRESERVED_OR_RESERVED.encode(&s[start..i], buf);
+            // The octet is ASCII.
+            buf.push_str(str::from_utf8(&s[i..i + 3]).unwrap());
+            i += 3;
+            start = i;
+        } else {
+            i += 1;
+        }
+    }
+    UNRESERVED_OR_RESERVED.encode(&s[start..], buf);
+}
+
+impl UriTemplate {
+    /// Parses a URI template from a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the string does not match
+    /// the [`URI-Template`] ABNF rule from RFC 6570.
+    ///
+    /// [`URI-Template`]: https://datatracker.ietf.org/doc/html/rfc6570/#section-2
+    pub fn parse(s: &str) -> Result<Self, TemplateError> {
+        let bytes = s.as_bytes();
+        let mut parts = Vec::new();
+
+        let (mut i, mut start) = (0, 0);
+        while i < bytes.len() {
+            let x = bytes[i];
+            if x == b'{' {
+                if start < i {
+                    parts.push(Part::Literal((start, i)));
+                }
+                i = parse_expr(bytes, i, &mut parts)?;
+                start = i;
+            } else if x == b'%' {
+                i = read_octet(bytes, i)?;
+            } else if x.is_ascii() {
+                if !LITERAL.allows(x) {
+                    err!(i, UnexpectedChar);
+                }
+                i += 1;
+            } else {
+                let (cp, len) = next_code_point(bytes, i);
+                if !LITERAL.allows_code_point(cp) {
+                    err!(i, UnexpectedChar);
+                }
+                i += len;
+            }
+        }
+        if start < i {
+            parts.push(Part::Literal((start, i)));
+        }
+
+        Ok(UriTemplate {
+            source: s.to_owned(),
+            parts,
+        })
+    }
+
+    /// Returns the template as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns an iterator over the names of the variables in the template,
+    /// in the order of their appearance.
+    pub fn variables(&self) -> impl Iterator<Item = &str> {
+        self.parts
+            .iter()
+            .flat_map(|part| match part {
+                Part::Literal(_) => &[][..],
+                Part::Expr(_, specs) => specs,
+            })
+            .map(|spec| &self.source[spec.name.0..spec.name.1])
+    }
+
+    /// Expands the template with the given variables into a URI reference.
+    ///
+    /// Variables that are not found are undefined, as are empty lists and
+    /// associative arrays. Undefined variables are ignored in the expansion.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if a prefix modifier is applied to a list or an associative
+    /// array, or if the expansion does not match the [`URI-reference`] ABNF rule
+    /// from RFC 3986, e.g., when two fragment expansions produce two `'#'`.
+    ///
+    /// [`URI-reference`]: https://datatracker.ietf.org/doc/html/rfc3986/#section-4.1
+    pub fn expand<V: Variables + ?Sized>(&self, vars: &V) -> Result<Uri<String>, ExpandError> {
+        let mut buf = String::with_capacity(self.source.len());
+        for part in &self.parts {
+            match part {
+                Part::Literal((start, end)) => {
+                    encode_reserved(&mut buf, &self.source[*start..*end]);
+                }
+                Part::Expr(op, specs) => self.expand_expr(&mut buf, *op, specs, vars)?,
+            }
+        }
+        Uri::parse(buf).map_err(|e| ExpandError(ExpandErrorKind::InvalidUri(e.strip_input())))
+    }
+
+    fn expand_expr<V: Variables + ?Sized>(
+        &self,
+        buf: &mut String,
+        op: Operator,
+        specs: &[VarSpec],
+        vars: &V,
+    ) -> Result<(), ExpandError> {
+        let mut first = true;
+        for spec in specs {
+            let name = &self.source[spec.name.0..spec.name.1];
+            let Some(value) = vars.get(name).filter(|v| v.is_defined()) else {
+                continue;
+            };
+
+            buf.push_str(if first { op.first() } else { op.sep() });
+            first = false;
+
+            let explode = matches!(spec.modifier, Modifier::Explode);
+            match value {
+                Value::String(s) => {
+                    let s = match spec.modifier {
+                        Modifier::Prefix(len) => {
+                            s.char_indices().nth(len).map_or(&s[..], |(i, _)| &s[..i])
+                        }
+                        _ => s,
+                    };
+                    if op.named() {
+                        push_name(buf, op, name, s.is_empty());
+                    }
+                    op.encode(buf, s);
+                }
+                _ if matches!(spec.modifier, Modifier::Prefix(_)) => {
+                    return Err(ExpandError(ExpandErrorKind::PrefixOnComposite));
+                }
+                Value::List(list) if !explode => {
+                    if op.named() {
+                        push_name(buf, op, name, false);
+                    }
+                    for (i, item) in list.iter().enumerate() {
+                        if i > 0 {
+                            buf.push(',');
+                        }
+                        op.encode(buf, item);
+                    }
+                }
+                Value::List(list) => {
+                    for (i, item) in list.iter().enumerate() {
+                        if i > 0 {
+                            buf.push_str(op.sep());
+                        }
+                        if op.named() {
+                            push_name(buf, op, name, item.is_empty());
+                        }
+                        op.encode(buf, item);
+                    }
+                }
+                Value::AssocArray(pairs) if !explode => {
+                    if op.named() {
+                        push_name(buf, op, name, false);
+                    }
+                    for (i, (k, v)) in pairs.iter().enumerate() {
+                        if i > 0 {
+                            buf.push(',');
+                        }
+                        op.encode(buf, k);
+                        buf.push(',');
+                        op.encode(buf, v);
+                    }
+                }
+                Value::AssocArray(pairs) => {
+                    for (i, (k, v)) in pairs.iter().enumerate() {
+                        if i > 0 {
+                            buf.push_str(op.sep());
+                        }
+                        op.encode(buf, k);
+                        if op.named() && v.is_empty() {
+                            buf.push_str(op.ifemp());
+                        } else {
+                            buf.push('=');
+                            op.encode(buf, v);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl UriTemplate {
+    /// Matches a URI reference against the template, returning the
+    /// percent-decoded values of the variables that expand into it.
+    ///
+    /// Since expansion is lossy, matching follows these rules:
+    ///
+    /// - An expression that expands to nothing is considered to have all its
+    ///   variables undefined. Undefined variables are absent from the result.
+    /// - An expression followed by a literal ends at the earliest occurrence
+    ///   of the literal that allows the rest of the template to match.
+    /// - Values are assigned to the variables of an unnamed expression from left
+    ///   to right, and the last variable takes all the remaining values.
+    ///   Variables of a named expression are identified by their names.
+    /// - A value containing an unencoded `','` is returned as a list, except in
+    ///   reserved and fragment expansion where it is kept as a string.
+    ///   An exploded variable is returned as an associative array if all its
+    ///   values are name-value pairs, and as a string if it has only one value.
+    /// - Values truncated by a prefix modifier are returned as is.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the template is ambiguous or the URI reference does not match.
+    ///
+    /// A template is ambiguous if it has two adjacent expressions where the
+    /// expansion of the first one may contain the first character of the
+    /// expansion of the second one, e.g., `{x}{y}`, `{+path}{/seg}` or `{/a*}{/b}`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_uri::{template::{UriTemplate, Value}, Uri};
+    ///
+    /// let template = UriTemplate::parse("/users/{id}{?fields*}")?;
+    /// let uri = Uri::parse("/users/42?name=Zo%C3%AB&sort=asc")?;
+    ///
+    /// let vars = template.match_uri(&uri)?;
+    /// assert_eq!(vars["id"], Value::from("42"));
+    /// assert_eq!(vars["fields"], Value::from([("name", "Zoë"), ("sort", "asc")]));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn match_uri<T: Bos<str>>(
+        &self,
+        uri: &Uri<T>,
+    ) -> Result<BTreeMap<String, Value>, MatchError> {
+        self.check_unambiguous()?;
+
+        let mut vars = BTreeMap::new();
+        if self.match_from(uri.as_str(), 0, 0, &mut vars, &mut BTreeMap::new()) {
+            Ok(vars)
+        } else {
+            Err(MatchError(MatchErrorKind::NoMatch))
+        }
+    }
+
+    fn check_unambiguous(&self) -> Result<(), MatchError> {
+        for pair in self.parts.windows(2) {
+            if let [Part::Expr(op, specs), Part::Expr(next, _)] = pair {
+                let ambiguous = match next.first().as_bytes() {
+                    [x] => op.capture_table(specs).allows(*x),
+                    _ => true,
+                };
+                if ambiguous {
+                    return Err(MatchError(MatchErrorKind::AmbiguousTemplate));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn literal(&self, (start, end): (usize, usize)) -> String {
+        let mut buf = String::new();
+        encode_reserved(&mut buf, &self.source[start..end]);
+        buf
+    }
+
+    fn name(&self, spec: &VarSpec) -> &str {
+        &self.source[spec.name.0..spec.name.1]
+    }
+
+    /// Matches the parts from index `i` against the string from index `pos`.
+    ///
+    /// Failures are memoized in `failed` to keep matching polynomial. Since the
+    /// outcome also depends on the values already bound to variables that occur
+    /// again in the remaining parts, these values are part of the key.
+    fn match_from(
+        &self,
+        s: &str,
+        i: usize,
+        pos: usize,
+        vars: &mut BTreeMap<String, Value>,
+        failed: &mut Failures,
+    ) -> bool {
+        if i == self.parts.len() {
+            return pos == s.len();
+        }
+
+        let bound: Vec<Option<Value>> = self.parts[i..]
+            .iter()
+            .filter_map(|part| match part {
+                Part::Expr(_, specs) => Some(specs),
+                Part::Literal(_) => None,
+            })
+            .flatten()
+            .map(|spec| vars.get(self.name(spec)).cloned())
+            .collect();
+        if failed.get(&(i, pos)).map_or(false, |v| v.contains(&bound)) {
+            return false;
+        }
+
+        let matched = self.match_part(s, i, pos, vars, failed);
+        if !matched {
+            failed.entry((i, pos)).or_default().push(bound);
+        }
+        matched
+    }
+
+    /// Matches the part at index `i` and the rest against the string from index `pos`.
+    fn match_part(
+        &self,
+        s: &str,
+        i: usize,
+        pos: usize,
+        vars: &mut BTreeMap<String, Value>,
+        failed: &mut Failures,
+    ) -> bool {
+        let (op, specs) = match &self.parts[i] {
+            Part::Literal(bounds) => {
+                let lit = self.literal(*bounds);
+                return s[pos..].starts_with(&lit[..])
+                    && self.match_from(s, i + 1, pos + lit.len(), vars, failed);
+            }
+            Part::Expr(op, specs) => (*op, specs),
+        };
+
+        // Find the longest possible expansion.
+        let bytes = s.as_bytes();
+        let mut end = pos;
+        if s[pos..].starts_with(op.first()) {
+            let table = op.capture_table(specs);
+            end += op.first().len();
+            while end < bytes.len() && (bytes[end] == b'%' || table.allows(bytes[end])) {
+                end += 1;
+            }
+        }
+
+        let next_lit = match self.parts.get(i + 1) {
+            Some(Part::Literal(bounds)) => Some(self.literal(*bounds)),
+            _ => None,
+        };
+
+        let mut bound = Vec::new();
+        for e in pos..=end {
+            match &next_lit {
+                Some(lit) if !s[e..].starts_with(&lit[..]) => continue,
+                // The template is unambiguous, so the expansion
+                // must end where the next expression starts.
+                None if e != end => continue,
+                _ => {}
+            }
+            if self.extract(&s[pos..e], op, specs, vars, &mut bound)
+                && self.match_from(s, i + 1, e, vars, failed)
+            {
+                return true;
+            }
+            for name in bound.drain(..) {
+                vars.remove(name);
+            }
+        }
+        false
+    }
+
+    /// Extracts the variable values from the expansion of an expression.
+    fn extract<'a>(
+        &'a self,
+        s: &str,
+        op: Operator,
+        specs: &'a [VarSpec],
+        vars: &mut BTreeMap<String, Value>,
+        bound: &mut Vec<&'a str>,
+    ) -> bool {
+        let Some(body) = s.strip_prefix(op.first()).filter(|_| !s.is_empty()) else {
+            return true;
+        };
+        let body = EStr::<Captured>::new_validated(body);
+
+        let items: Vec<&EStr<Captured>> = match op.sep() {
+            // `'.'` is not a reserved character.
+            "." => body.as_str().split('.').map(EStr::new_validated).collect(),
+            sep => body.split(sep.as_bytes()[0] as char).collect(),
+        };
+        let mut idx = 0;
+
+        if op.named() {
+            for (k, spec) in specs.iter().enumerate() {
+                let name = self.name(spec);
+                let value = if let Modifier::Explode = spec.modifier {
+                    let mut list = Vec::new();
+                    while let Some((n, v)) = items.get(idx).map(|item| split_pair(item)) {
+                        if n.as_str() != name {
+                            break;
+                        }
+                        list.push(decode(v));
+                        idx += 1;
+                    }
+
+                    if list.is_empty() {
+                        let mut pairs = Vec::new();
+                        while let Some((n, v)) = items.get(idx).map(|item| split_pair(item)) {
+                            if specs[k + 1..].iter().any(|s| self.name(s) == n.as_str()) {
+                                break;
+                            }
+                            pairs.push((decode(n), decode(v)));
+                            idx += 1;
+                        }
+                        Value::AssocArray(pairs)
+                    } else if list.len() == 1 {
+                        Value::String(list.pop().unwrap())
+                    } else {
+                        Value::List(list)
+                    }
+                } else {
+                    match items.get(idx).map(|item| split_pair(item)) {
+                        Some((n, v)) if n.as_str() == name => {
+                            idx += 1;
+                            decode_value(v, op, spec)
+                        }
+                        _ => continue,
+                    }
+                };
+                if value.is_defined() && !bind(vars, bound, name, value) {
+                    return false;
+                }
+            }
+            return idx == items.len();
+        }
+
+        if let ([spec], true) = (specs, op.allows_reserved()) {
+            if !matches!(spec.modifier, Modifier::Explode) {
+                let value = decode_value(body, op, spec);
+                return bind(vars, bound, self.name(spec), value);
+            }
+        }
+
+        for (k, spec) in specs.iter().enumerate() {
+            let last = k + 1 == specs.len();
+            let rest = &items[idx..];
+            let value = match rest {
+                [] => break,
+                [item, ..] if !last => decode_value(item, op, spec),
+                _ if matches!(spec.modifier, Modifier::Explode) => {
+                    let pairs: Option<Vec<_>> = rest
+                        .iter()
+                        .map(|item| item.split_once('=').filter(|_| !op.allows_reserved()))
+                        .map(|pair| pair.map(|(n, v)| (decode(n), decode(v))))
+                        .collect();
+                    match (pairs, rest) {
+                        (Some(pairs), _) => Value::AssocArray(pairs),
+                        (None, [item]) => Value::String(decode(item)),
+                        (None, _) => Value::List(rest.iter().map(|item| decode(item)).collect()),
+                    }
+                }
+                [item] => decode_value(item, op, spec),
+                _ if op.sep() == "," => Value::List(rest.iter().map(|item| decode(item)).collect()),
+                _ => return false,
+            };
+            idx = if last { items.len() } else { idx + 1 };
+            if !bind(vars, bound, self.name(spec), value) {
+                return false;
+            }
+        }
+        idx == items.len()
+    }
+}
+
+/// Splits an item of a named expansion into its name and value.
+fn split_pair(item: &EStr<Captured>) -> (&EStr<Captured>, &EStr<Captured>) {
+    item.split_once('=').unwrap_or((item, EStr::EMPTY))
+}
+
+fn decode(s: &EStr<Captured>) -> String {
+    s.decode().into_string_lossy().into_owned()
+}
+
+/// Decodes a non-exploded value, splitting it into a list at unencoded commas.
+fn decode_value(s: &EStr<Captured>, op: Operator, spec: &VarSpec) -> Value {
+    if op.allows_reserved()
+        || matches!(spec.modifier, Modifier::Prefix(_))
+        || !s.as_str().contains(',')
+    {
+        Value::String(decode(s))
+    } else {
+        Value::List(s.split(',').map(decode).collect())
+    }
+}
+
+/// Failed matches keyed by part index and string index, along with the values
+/// bound to the variables in the remaining parts at the time of failure.
+type Failures = BTreeMap<(usize, usize), Vec<Vec<Option<Value>>>>;
+
+/// Binds a value to a variable, checking that
+/// it agrees with any previously bound value.
+fn bind<'a>(
+    vars: &mut BTreeMap<String, Value>,
+    bound: &mut Vec<&'a str>,
+    name: &'a str,
+    value: Value,
+) -> bool {
+    if let Some(v) = vars.get(name) {
+        return *v == value;
+    }
+    vars.insert(name.to_owned(), value);
+    bound.push(name);
+    true
+}
+
+/// Appends the variable name followed by `ifemp` or `'='`.
+fn push_name(buf: &mut String, op: Operator, name: &str, empty: bool) {
+    buf.push_str(name);
+    buf.push_str(if empty { op.ifemp() } else { "=" });
+}
+
+/// Reads a percent-encoded octet at the given index, returning the index after it.
+fn read_octet(bytes: &[u8], i: usize) -> Result<usize, TemplateError> {
+    match bytes.get(i + 1..i + 3) {
+        Some(&[hi, lo]) if HEXDIG.allows(hi) && HEXDIG.allows(lo) => Ok(i + 3),
+        _ => err!(i, InvalidOctet),
+    }
+}
+
+/// Parses an expression starting at `{`, returning the index after `}`.
+fn parse_expr(bytes: &[u8], start: usize, parts: &mut Vec<Part>) -> Result<usize, TemplateError> {
+    let mut i = start + 1;
+
+    let op = match bytes.get(i) {
+        Some(&x) => match Operator::from_byte(x) {
+            Some(op) => {
+                i += 1;
+                op
+            }
+            // Reserved for future extensions.
+            None if matches!(x, b'=' | b',' | b'!' | b'@' | b'|') => err!(i, UnexpectedChar),
+            None => Operator::Simple,
+        },
+        None => err!(start, UnclosedExpression),
+    };
+
+    let mut specs = Vec::new();
+    loop {
+        // varname = varchar *( ["."] varchar )
+        let name_start = i;
+        loop {
+            match bytes.get(i) {
+                Some(b'%') => i = read_octet(bytes, i)?,
+                Some(&x) if VARCHAR.allows(x) => i += 1,
+                Some(_) => err!(i, UnexpectedChar),
+                None => err!(start, UnclosedExpression),
+            }
+            if bytes.get(i) == Some(&b'.') {
+                i += 1;
+            } else if !matches!(bytes.get(i), Some(&x) if x == b'%' || VARCHAR.allows(x)) {
+                break;
+            }
+        }
+        let name = (name_start, i);
+
+        let modifier = match bytes.get(i) {
+            Some(b':') => {
+                i += 1;
+                let digits_start = i;
+                while i - digits_start < 4 && matches!(bytes.get(i), Some(x) if x.is_ascii_digit())
+                {
+                    i += 1;
+                }
+                if i == digits_start || bytes[digits_start] == b'0' {
+                    if i == bytes.len() {
+                        err!(start, UnclosedExpression);
+                    }
+                    err!(digits_start, UnexpectedChar);
+                }
+                // The digits are ASCII and at most 9999.
+                let len = str::from_utf8(&bytes[digits_start..i])
+                    .unwrap()
+                    .parse()
+                    .unwrap();
+                Modifier::Prefix(len)
+            }
+            Some(b'*') => {
+                i += 1;
+                Modifier::Explode
+            }
+            _ => Modifier::None,
+        };
+        specs.push(VarSpec { name, modifier });
+
+        match bytes.get(i) {
+            Some(b',') => i += 1,
+            Some(b'}') => break,
+            Some(_) => err!(i, UnexpectedChar),
+            None => err!(start, UnclosedExpression),
+        }
+    }
+
+    parts.push(Part::Expr(op, specs));
+    Ok(i + 1)
+}
+
+impl FromStr for UriTemplate {
+    type Err = TemplateError;
+
+    /// Equivalent to [`UriTemplate::parse`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        UriTemplate::parse(s)
+    }
+}
+
+/// A variable value.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Value {
+    /// A string value.
+    String(String),
+    /// A list of string values.
+    ///
+    /// An empty list is considered undefined.
+    List(Vec<String>),
+    /// An associative array of name-value pairs.
+    ///
+    /// An empty associative array is considered undefined.
+    AssocArray(Vec<(String, String)>),
+}
+
+impl Value {
+    fn is_defined(&self) -> bool {
+        match self {
+            Value::String(_) => true,
+            Value::List(list) => !list.is_empty(),
+            Value::AssocArray(pairs) => !pairs.is_empty(),
+        }
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Value::String(s.to_owned())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Value::String(s)
+    }
+}
+
+impl From<Vec<String>> for Value {
+    fn from(list: Vec<String>) -> Self {
+        Value::List(list)
+    }
+}
+
+impl From<Vec<&str>> for Value {
+    fn from(list: Vec<&str>) -> Self {
+        Value::List(list.into_iter().map(|s| s.to_owned()).collect())
+    }
+}
+
+impl<const N: usize> From<[&str; N]> for Value {
+    fn from(list: [&str; N]) -> Self {
+        Value::List(list.into_iter().map(|s| s.to_owned()).collect())
+    }
+}
+
+impl From<Vec<(String, String)>> for Value {
+    fn from(pairs: Vec<(String, String)>) -> Self {
+        Value::AssocArray(pairs)
+    }
+}
+
+impl From<Vec<(&str, &str)>> for Value {
+    fn from(pairs: Vec<(&str, &str)>) -> Self {
+        Value::AssocArray(
+            pairs
+                .into_iter()
+                .map(|(k, v)| (k.to_owned(), v.to_owned()))
+                .collect(),
+        )
+    }
+}
+
+impl<const N: usize> From<[(&str, &str); N]> for Value {
+    fn from(pairs: [(&str, &str); N]) -> Self {
+        Value::AssocArray(
+            pairs
+                .into_iter()
+                .map(|(k, v)| (k.to_owned(), v.to_owned()))
+                .collect(),
+        )
+    }
+}
+
+/// A trait for looking up variable values by name.
+///
+/// This trait is implemented for [`BTreeMap`], [`HashMap`], and slices
+/// and arrays of name-value pairs.
+///
+/// [`BTreeMap`]: alloc::collections::BTreeMap
+/// [`HashMap`]: std::collections::HashMap
+pub trait Variables {
+    /// Returns the value of the variable with the given name,
+    /// or `None` if the variable is undefined.
+    fn get(&self, name: &str) -> Option<&Value>;
+}
+
+impl<K: Borrow<str> + Ord> Variables for BTreeMap<K, Value> {
+    fn get(&self, name: &str) -> Option<&Value> {
+        BTreeMap::get(self, name)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, S> Variables for std::collections::HashMap<K, Value, S>
+where
+    K: Borrow<str> + core::hash::Hash + Eq,
+    S: core::hash::BuildHasher,
+{
+    fn get(&self, name: &str) -> Option<&Value> {
+        std::collections::HashMap::get(self, name)
+    }
+}
+
+impl<K: Borrow<str>> Variables for [(K, Value)] {
+    fn get(&self, name: &str) -> Option<&Value> {
+        self.iter()
+            .find(|(k, _)| k.borrow() == name)
+            .map(|(_, v)| v)
+    }
+}
+
+impl<K: Borrow<str>, const N: usize> Variables for [(K, Value); N] {
+    fn get(&self, name: &str) -> Option<&Value> {
+        Variables::get(&self[..], name)
+    }
+}
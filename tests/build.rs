@@ -0,0 +1,126 @@
+use fluent_uri::{
+    component::{Host, Scheme},
+    encoding::EStr,
+    error::Component,
+    DynBuilder, Uri,
+};
+
+#[test]
+fn dyn_builder() {
+    let uri = DynBuilder::new()
+        .fragment(Some(EStr::new_or_panic("nose")))
+        .path(Some(EStr::new_or_panic("/over/there")))
+        .port(Some(8042))
+        .host(Some(Host::RegName(EStr::new_or_panic("example.com"))))
+        .userinfo(Some(EStr::new_or_panic("user")))
+        .query(Some(EStr::new_or_panic("name=ferret")))
+        .scheme(Some(Scheme::new_or_panic("foo")))
+        .build()
+        .unwrap();
+    assert_eq!(
+        uri.as_str(),
+        "foo://user@example.com:8042/over/there?name=ferret#nose"
+    );
+    assert_eq!(uri.authority().unwrap().host(), "example.com");
+
+    let uri = DynBuilder::new().build().unwrap();
+    assert_eq!(uri.as_str(), "");
+
+    let uri = DynBuilder::new()
+        .host(Some(Host::RegName(EStr::EMPTY)))
+        .build()
+        .unwrap();
+    assert_eq!(uri.as_str(), "//");
+
+    let e = DynBuilder::new()
+        .userinfo(Some(EStr::new_or_panic("user")))
+        .build()
+        .unwrap_err();
+    assert_eq!(e.component(), Component::Userinfo);
+    assert_eq!(
+        e.to_string(),
+        "userinfo cannot be set when authority is absent"
+    );
+
+    let e = DynBuilder::new()
+        .host(Some(Host::RegName(EStr::EMPTY)))
+        .path(Some(EStr::new_or_panic("a")))
+        .build()
+        .unwrap_err();
+    assert_eq!(e.component(), Component::Path);
+
+    let e = DynBuilder::new()
+        .path(Some(EStr::new_or_panic("a:b")))
+        .build()
+        .unwrap_err();
+    assert_eq!(e.component(), Component::Path);
+    assert_eq!(
+        e.to_string(),
+        "first path segment cannot contain ':' in relative-path reference"
+    );
+
+    let future = Uri::parse("//[v1.addr]").unwrap();
+    let e = DynBuilder::new()
+        .host(Some(future.authority().unwrap().host_parsed()))
+        .build()
+        .unwrap_err();
+    assert_eq!(e.component(), Component::Host);
+}
+
+#[test]
+fn encoded() {
+    use fluent_uri::Uri;
+
+    let uri = Uri::builder()
+        .scheme(Scheme::new_or_panic("foo"))
+        .authority(|b| {
+            b.userinfo_encoded("a:b@", None)
+                .host(EStr::new_or_panic("example.com"))
+        })
+        .path_segments(["", "50%", "ü", "a:b"])
+        .query_pairs(vec![
+            (String::from("k"), String::from("a+b c")),
+            ("".into(), "".into()),
+        ])
+        .fragment_encoded("#?ü")
+        .build()
+        .unwrap();
+    assert_eq!(
+        uri.as_str(),
+        "foo://a%3Ab%40@example.com//50%25/%C3%BC/a:b?k=a%2Bb+c&=#%23?%C3%BC"
+    );
+    assert_eq!(
+        uri.authority()
+            .unwrap()
+            .userinfo()
+            .unwrap()
+            .decode()
+            .as_bytes(),
+        b"a:b@"
+    );
+    assert!(uri
+        .path()
+        .as_str()
+        .split('/')
+        .eq(["", "", "50%25", "%C3%BC", "a:b"]));
+
+    let uri = Uri::builder()
+        .path_segments(Vec::<&str>::new())
+        .build()
+        .unwrap();
+    assert_eq!(uri.as_str(), "");
+
+    let e = Uri::builder().path_segments(["", "x"]).build().unwrap_err();
+    assert_eq!(e.component(), Component::Path);
+}
+
+#[test]
+fn unwritable_host() {
+    let future = Uri::parse("//[v1.addr]").unwrap();
+    let e = Uri::builder()
+        .authority(|b| b.host(future.authority().unwrap().host_parsed()))
+        .path(EStr::EMPTY)
+        .build()
+        .unwrap_err();
+    assert_eq!(e.component(), Component::Host);
+}
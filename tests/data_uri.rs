@@ -0,0 +1,170 @@
+use fluent_uri::{scheme::data::DataUri, Uri};
+
+#[test]
+fn parse() {
+    let uri = Uri::parse("data:,A%20brief%20note").unwrap();
+    let data = DataUri::new(&uri).unwrap();
+    assert_eq!(data.mime_type(), "text/plain");
+    assert_eq!(data.params().count(), 0);
+    assert!(!data.is_base64());
+    assert_eq!(data.data(), "A%20brief%20note");
+    assert_eq!(&*data.decode().unwrap(), b"A brief note");
+
+    let uri = Uri::parse("DATA:Text/HTML;charset=utf-8;q=1;BASE64,PGI+aGk8L2I+#frag").unwrap();
+    let data = DataUri::new(&uri).unwrap();
+    assert_eq!(data.mime_type(), "Text/HTML");
+    assert!(data
+        .params()
+        .map(|(name, value)| (name.as_str(), value.as_str()))
+        .eq([("charset", "utf-8"), ("q", "1")]));
+    assert_eq!(data.param("CHARSET").unwrap(), "utf-8");
+    assert_eq!(data.param("base64"), None);
+    assert!(data.is_base64());
+    assert_eq!(data.data(), "PGI+aGk8L2I+");
+    assert_eq!(&*data.decode().unwrap(), b"<b>hi</b>");
+
+    // Parameters without MIME type.
+    let uri = Uri::parse("data:;charset=iso-8859-7,%be%d3%be").unwrap();
+    let data = DataUri::new(&uri).unwrap();
+    assert_eq!(data.mime_type(), "text/plain");
+    assert_eq!(data.param("charset").unwrap(), "iso-8859-7");
+    assert_eq!(&*data.decode().unwrap(), b"\xbe\xd3\xbe");
+
+    // Data extends over the query.
+    let uri = Uri::parse("data:,a?b,c;d#e").unwrap();
+    let data = DataUri::new(&uri).unwrap();
+    assert_eq!(data.data(), "a?b,c;d");
+
+    // A parameter value may contain '='.
+    let uri = Uri::parse("data:a/b;x==,").unwrap();
+    let data = DataUri::new(&uri).unwrap();
+    assert_eq!(data.param("x").unwrap(), "=");
+    assert_eq!(&*data.decode().unwrap(), b"");
+}
+
+#[test]
+fn parse_error() {
+    let cases = [
+        ("http://example.com/,", "not a data URI"),
+        ("data://example.com/,", "not a data URI"),
+        ("urn:data:,", "not a data URI"),
+        ("data:text/plain", "missing comma before data in data URI"),
+        ("data:text/plain#,", "missing comma before data in data URI"),
+        ("data:text,", "invalid media type in data URI"),
+        ("data:text/,", "invalid media type in data URI"),
+        ("data:text/plain/x,", "invalid media type in data URI"),
+        ("data:te%78t/plain,", "invalid media type in data URI"),
+        ("data:text/plain?,", "invalid media type in data URI"),
+        ("data:text/plain;charset,", "invalid media type in data URI"),
+        (
+            "data:text/plain;charset=,",
+            "invalid media type in data URI",
+        ),
+        ("data:text/plain;=x,", "invalid media type in data URI"),
+        ("data:text/plain;;base64,", "invalid media type in data URI"),
+    ];
+    for (s, msg) in cases {
+        let uri = Uri::parse(s).unwrap();
+        assert_eq!(DataUri::new(&uri).unwrap_err().to_string(), msg, "{s}");
+    }
+}
+
+#[test]
+fn decode_base64() {
+    let cases = [
+        ("", Some(&b""[..])),
+        ("Zg==", Some(b"f")),
+        ("Zm8=", Some(b"fo")),
+        ("Zm9v", Some(b"foo")),
+        ("Zm9vYg==", Some(b"foob")),
+        ("Zm9vYmE=", Some(b"fooba")),
+        ("Zm9vYmFy", Some(b"foobar")),
+        ("Zm9vYg", Some(b"foob")),
+        ("Zm9v%20YmE", Some(b"fooba")),
+        ("Zm9v%0AYmFy%0A", Some(b"foobar")),
+        ("%5A%6D%38%3D", Some(b"fo")),
+        ("//79", Some(b"\xff\xfe\xfd")),
+        ("Z", None),
+        ("Zm9vY", None),
+        ("Zg=", None),
+        ("Zg===", None),
+        ("Zm9v=", None),
+        ("Zg==Zg==", None),
+        ("Zm9v-_", None),
+        ("Zm9v%FF", None),
+    ];
+    for (s, expected) in cases {
+        let s = format!("data:;base64,{s}");
+        let uri = Uri::parse(&s[..]).unwrap();
+        let res = DataUri::new(&uri).unwrap().decode();
+        match expected {
+            Some(expected) => assert_eq!(&*res.unwrap(), expected, "{s}"),
+            None => assert_eq!(
+                res.unwrap_err().to_string(),
+                "invalid base64 data in data URI",
+                "{s}"
+            ),
+        }
+    }
+}
+
+#[test]
+fn build() {
+    let uri = DataUri::builder("image/gif").build(b"GIF89a").unwrap();
+    assert_eq!(uri.as_str(), "data:image/gif;base64,R0lGODlh");
+
+    let uri = DataUri::builder("").build(b"").unwrap();
+    assert_eq!(uri.as_str(), "data:;base64,");
+
+    let uri = DataUri::builder("").base64(false).build(b"").unwrap();
+    assert_eq!(uri.as_str(), "data:,");
+
+    let uri = DataUri::builder("text/plain")
+        .param("charset", "utf-8")
+        .param("x", "a;b,c=d e")
+        .base64(false)
+        .build(b"a,b?c#d/e\xff")
+        .unwrap();
+    assert_eq!(
+        uri.as_str(),
+        "data:text/plain;charset=utf-8;x=a%3Bb%2Cc=d%20e,a,b%3Fc%23d/e%FF"
+    );
+    let data = DataUri::new(&uri).unwrap();
+    assert_eq!(
+        data.param("x").unwrap().decode().into_string().unwrap(),
+        "a;b,c=d e"
+    );
+    assert_eq!(&*data.decode().unwrap(), b"a,b?c#d/e\xff");
+
+    // Round trip.
+    let bytes: Vec<u8> = (0..=255).collect();
+    for base64 in [true, false] {
+        for len in 0..7 {
+            let uri = DataUri::builder("application/octet-stream")
+                .base64(base64)
+                .build(&bytes[..len * 37])
+                .unwrap();
+            let data = DataUri::new(&uri).unwrap();
+            assert_eq!(data.is_base64(), base64);
+            assert_eq!(data.mime_type(), "application/octet-stream");
+            assert_eq!(&*data.decode().unwrap(), &bytes[..len * 37]);
+        }
+    }
+
+    for mime in [
+        "text",
+        "text/",
+        "/plain",
+        "text/pl ain",
+        "a/b/c",
+        "t%78t/plain",
+    ] {
+        assert!(DataUri::builder(mime).build(b"").is_err(), "{mime}");
+    }
+    for (name, value) in [("", "x"), ("x", ""), ("a b", "x"), ("a=b", "x")] {
+        assert!(DataUri::builder("text/plain")
+            .param(name, value)
+            .build(b"")
+            .is_err());
+    }
+}
@@ -0,0 +1,135 @@
+use fluent_uri::{
+    component::DnsName,
+    encoding::{encoder::RegName, EStr},
+    error::DnsNameErrorKind,
+    Iri, Uri,
+};
+use std::collections::HashSet;
+
+#[test]
+fn valid() {
+    let label63 = "a".repeat(63);
+    let name253 = [&label63[..], &label63, &label63, &"b".repeat(61)].join(".");
+    assert_eq!(name253.len(), 253);
+    let fqdn254 = format!("{name253}.");
+
+    for s in [
+        "localhost",
+        "example.com",
+        "Example.COM",
+        "example.com.",
+        "3com.com",
+        "a-b.c-d",
+        "123.example",
+        "x1",
+        &label63,
+        &name253,
+        &fqdn254,
+    ] {
+        let name = DnsName::new(s).unwrap();
+        assert_eq!(name.as_str(), s);
+        assert_eq!(name.is_fully_qualified(), s.ends_with('.'));
+        assert_eq!(
+            name.labels().collect::<Vec<_>>(),
+            s.strip_suffix('.')
+                .unwrap_or(s)
+                .split('.')
+                .collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn invalid() {
+    let label64 = "a".repeat(64);
+    let name254 = format!("a{}", ".a".repeat(126));
+    assert_eq!(name254.len(), 253);
+    let name254 = format!("b{name254}");
+
+    let cases = [
+        ("", DnsNameErrorKind::Empty),
+        (".", DnsNameErrorKind::Empty),
+        ("..", DnsNameErrorKind::EmptyLabel),
+        (".com", DnsNameErrorKind::EmptyLabel),
+        ("a..com", DnsNameErrorKind::EmptyLabel),
+        ("example.com..", DnsNameErrorKind::EmptyLabel),
+        (&label64, DnsNameErrorKind::LabelTooLong),
+        (&name254, DnsNameErrorKind::NameTooLong),
+        ("under_score.com", DnsNameErrorKind::InvalidChar),
+        ("a b.com", DnsNameErrorKind::InvalidChar),
+        ("bücher.example", DnsNameErrorKind::InvalidChar),
+        ("-a.com", DnsNameErrorKind::HyphenAtLabelEdge),
+        ("a-.com", DnsNameErrorKind::HyphenAtLabelEdge),
+        ("example.-", DnsNameErrorKind::HyphenAtLabelEdge),
+        ("1.2.3.256", DnsNameErrorKind::NumericTopLevelLabel),
+        ("example.123.", DnsNameErrorKind::NumericTopLevelLabel),
+    ];
+    for (s, kind) in cases {
+        assert_eq!(DnsName::new(s).unwrap_err().kind(), kind, "{s}");
+    }
+}
+
+#[test]
+fn from_host() {
+    let host_dns_name = |s: &str| {
+        let uri = Uri::parse(s).unwrap();
+        let res = uri.authority().unwrap().host_parsed().as_dns_name();
+        res.map(|name| name.as_str().to_owned())
+            .map_err(|e| e.kind())
+    };
+
+    assert_eq!(
+        host_dns_name("http://Example.com/"),
+        Ok("Example.com".into())
+    );
+    assert_eq!(
+        host_dns_name("http://%65xample.com./"),
+        Ok("example.com.".into())
+    );
+    assert_eq!(
+        host_dns_name("http://ex%2Eample.com/"),
+        Ok("ex.ample.com".into())
+    );
+    assert_eq!(
+        host_dns_name("http://ex%5Fample.com/"),
+        Err(DnsNameErrorKind::InvalidChar)
+    );
+    assert_eq!(
+        host_dns_name("http://%FF.com/"),
+        Err(DnsNameErrorKind::InvalidChar)
+    );
+    assert_eq!(
+        host_dns_name("http://a!b.com/"),
+        Err(DnsNameErrorKind::InvalidChar)
+    );
+    assert_eq!(host_dns_name("http:///"), Err(DnsNameErrorKind::Empty));
+    for s in ["http://127.0.0.1/", "http://[::1]/", "http://[v1.x]/"] {
+        assert_eq!(host_dns_name(s), Err(DnsNameErrorKind::NotRegName));
+    }
+
+    let iri = Iri::parse("http://bücher.example/").unwrap();
+    let e = iri.authority().unwrap().host_parsed().as_dns_name();
+    assert_eq!(e.unwrap_err().kind(), DnsNameErrorKind::InvalidChar);
+
+    let name = EStr::<RegName>::new_or_panic("www.%65xample.com");
+    assert_eq!(name.to_hostname().unwrap().as_str(), "www.example.com");
+    assert_eq!(
+        EStr::<RegName>::new_or_panic("a..b")
+            .to_hostname()
+            .unwrap_err()
+            .kind(),
+        DnsNameErrorKind::EmptyLabel
+    );
+}
+
+#[test]
+fn compare() {
+    let a = DnsName::new("Example.COM").unwrap();
+    let b = DnsName::new("example.com").unwrap();
+    let c = DnsName::new("example.com.").unwrap();
+    assert_eq!(a, b);
+    assert_ne!(b, c);
+
+    let set: HashSet<_> = [a, b, c].into_iter().collect();
+    assert_eq!(set.len(), 2);
+}
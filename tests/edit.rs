@@ -0,0 +1,266 @@
+use fluent_uri::{component::Authority, encoding::EStr, error::Component, Uri};
+
+fn uri(s: &str) -> Uri<String> {
+    Uri::parse(s.to_owned()).unwrap()
+}
+
+fn check(uri: &Uri<String>, expected: &str) {
+    assert_eq!(uri.as_str(), expected);
+    // Metadata must be identical to that of a freshly parsed one.
+    assert_eq!(
+        format!("{uri:?}"),
+        format!("{:?}", Uri::parse(expected).unwrap())
+    );
+}
+
+#[test]
+fn params() {
+    let mut u = uri("http://example.com/?a=1&b=2&a=3#frag");
+    u.set_param("a", "x y");
+    check(&u, "http://example.com/?a=x+y&b=2#frag");
+    u.set_param("c", "&=");
+    check(&u, "http://example.com/?a=x+y&b=2&c=%26%3D#frag");
+    u.remove_param("b");
+    check(&u, "http://example.com/?a=x+y&c=%26%3D#frag");
+    assert_eq!(u.fragment().unwrap(), "frag");
+
+    u.retain_params(|name, value| name == "c" && value == "&=");
+    check(&u, "http://example.com/?c=%26%3D#frag");
+    u.remove_param("c");
+    check(&u, "http://example.com/#frag");
+    u.remove_param("c");
+    check(&u, "http://example.com/#frag");
+
+    let mut u = uri("foo:bar");
+    u.append_param("k", "v");
+    check(&u, "foo:bar?k=v");
+    u.append_param("k", "");
+    check(&u, "foo:bar?k=v&k=");
+
+    // Untouched pairs keep their encoding; empty pairs are dropped.
+    let mut u = uri("?z=%7E&&a%20b=1&m&a+b=0");
+    u.sort_params();
+    check(&u, "?a%20b=1&a+b=0&m&z=%7E");
+    u.set_param("a b", "2");
+    check(&u, "?a+b=2&m&z=%7E");
+
+    let mut u = uri("/?#");
+    u.sort_params();
+    check(&u, "/#");
+    u.set_param("a", "1");
+    check(&u, "/?a=1#");
+}
+
+#[test]
+#[cfg(feature = "net")]
+fn setters() {
+    use fluent_uri::component::Scheme;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    let mut u = uri("foo://user@example.com:8042/over/there?name=ferret#nose");
+    u.set_scheme(Some(Scheme::new_or_panic("https"))).unwrap();
+    check(
+        &u,
+        "https://user@example.com:8042/over/there?name=ferret#nose",
+    );
+    u.set_userinfo(None).unwrap();
+    check(&u, "https://example.com:8042/over/there?name=ferret#nose");
+    u.set_userinfo(Some(EStr::new_or_panic("a:b"))).unwrap();
+    check(
+        &u,
+        "https://a:b@example.com:8042/over/there?name=ferret#nose",
+    );
+    u.set_host(Ipv4Addr::new(127, 0, 0, 1)).unwrap();
+    check(&u, "https://a:b@127.0.0.1:8042/over/there?name=ferret#nose");
+    u.set_host(Ipv6Addr::LOCALHOST).unwrap();
+    check(&u, "https://a:b@[::1]:8042/over/there?name=ferret#nose");
+    u.set_host(EStr::new_or_panic("10.0.0.1")).unwrap();
+    check(&u, "https://a:b@10.0.0.1:8042/over/there?name=ferret#nose");
+    u.set_port(None).unwrap();
+    check(&u, "https://a:b@10.0.0.1/over/there?name=ferret#nose");
+    u.set_port(Some(80)).unwrap();
+    check(&u, "https://a:b@10.0.0.1:80/over/there?name=ferret#nose");
+    u.set_path(EStr::EMPTY).unwrap();
+    check(&u, "https://a:b@10.0.0.1:80?name=ferret#nose");
+    u.set_query(None);
+    check(&u, "https://a:b@10.0.0.1:80#nose");
+    u.set_fragment(None);
+    check(&u, "https://a:b@10.0.0.1:80");
+    u.set_fragment(Some(EStr::new_or_panic("f")));
+    check(&u, "https://a:b@10.0.0.1:80#f");
+    u.set_query(Some(EStr::EMPTY));
+    check(&u, "https://a:b@10.0.0.1:80?#f");
+    u.set_path(EStr::new_or_panic("/p")).unwrap();
+    check(&u, "https://a:b@10.0.0.1:80/p?#f");
+
+    let other = uri("//x@[v1.addr]:1/");
+    u.set_authority(other.authority()).unwrap();
+    check(&u, "https://x@[v1.addr]:1/p?#f");
+    u.set_authority(None::<&Authority<String>>).unwrap();
+    check(&u, "https:/p?#f");
+    u.set_scheme(None).unwrap();
+    check(&u, "/p?#f");
+    u.set_host(EStr::new_or_panic("example.com")).unwrap();
+    check(&u, "//example.com/p?#f");
+    u.set_scheme(Some(Scheme::new_or_panic("http"))).unwrap();
+    check(&u, "http://example.com/p?#f");
+}
+
+#[test]
+fn setters_error() {
+    let mut u = uri("a:b:c/d");
+    let e = u.set_scheme(None).unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "first path segment cannot contain ':' in relative-path reference"
+    );
+    let e = u.set_host(EStr::new_or_panic("example.com")).unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "path must either be empty or start with '/' when authority is present"
+    );
+    let e = u.set_port(Some(80)).unwrap_err();
+    assert_eq!(e.to_string(), "port cannot be set when authority is absent");
+    assert!(u.set_userinfo(None).is_err());
+    check(&u, "a:b:c/d");
+
+    let mut u = uri("http://example.com//x");
+    let e = u.set_authority(None::<&Authority<String>>).unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "path cannot start with \"//\" when authority is absent"
+    );
+    assert!(u.set_path(EStr::new_or_panic("x")).is_err());
+    check(&u, "http://example.com//x");
+
+    // A host that does not carry its text cannot be written.
+    let future = uri("//[v1.addr]");
+    let e = u
+        .set_host(future.authority().unwrap().host_parsed())
+        .unwrap_err();
+    assert_eq!(e.component(), Component::Host);
+    assert_eq!(
+        e.to_string(),
+        "host cannot be written without its text, e.g., IPvFuture address"
+    );
+    check(&u, "http://example.com//x");
+
+    let mut u = uri("a/b");
+    assert!(u
+        .set_host(future.authority().unwrap().host_parsed())
+        .is_err());
+    check(&u, "a/b");
+
+    #[cfg(not(feature = "net"))]
+    {
+        let ip = uri("//127.0.0.1");
+        assert!(u.set_host(ip.authority().unwrap().host_parsed()).is_err());
+        check(&u, "a/b");
+    }
+}
+
+#[test]
+fn to_builder() {
+    let base = uri("foo://user@example.com:8042/over/there?name=ferret#nose");
+    let u = base
+        .to_builder()
+        .set_path(EStr::new_or_panic("/p"))
+        .set_fragment(None)
+        .build()
+        .unwrap();
+    check(&u, "foo://user@example.com:8042/p?name=ferret");
+
+    // Components may be set in any order and are validated as a whole.
+    let u = Uri::parse("rel/path")
+        .unwrap()
+        .to_builder()
+        .set_port(Some(80))
+        .set_path(EStr::new_or_panic("/abs"))
+        .set_host(EStr::new_or_panic("example.com"))
+        .set_userinfo(Some(EStr::new_or_panic("u")))
+        .build()
+        .unwrap();
+    check(&u, "//u@example.com:80/abs");
+
+    let u = u
+        .to_builder()
+        .set_authority(None::<&Authority<&str>>)
+        .set_query(Some(EStr::new_or_panic("q")))
+        .build()
+        .unwrap();
+    check(&u, "/abs?q");
+
+    let other = uri("//other:1");
+    let e = base
+        .to_builder()
+        .set_authority(other.authority())
+        .set_path(EStr::new_or_panic("x"))
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "path must either be empty or start with '/' when authority is present"
+    );
+
+    // A host that does not carry its text fails the build.
+    let future = uri("//[v1.addr]");
+    let e = uri("http://a/")
+        .to_builder()
+        .set_host(future.authority().unwrap().host_parsed())
+        .set_host(EStr::new_or_panic("b"))
+        .build()
+        .unwrap_err();
+    assert_eq!(e.component(), Component::Host);
+
+    // Clearing userinfo or port leaves a URI without authority unchanged.
+    for s in ["file:/x", "mailto:a@b", "foo"] {
+        let u = uri(s)
+            .to_builder()
+            .set_userinfo(None)
+            .set_port(None)
+            .build()
+            .unwrap();
+        check(&u, s);
+    }
+}
+
+#[test]
+fn path_segments() {
+    let mut u = uri("http://example.com?q#f");
+    assert!(!u.pop_segment());
+    u.push_segment("a").unwrap();
+    check(&u, "http://example.com/a?q#f");
+    u.push_segment("").unwrap();
+    check(&u, "http://example.com/a/?q#f");
+    u.push_segment("b/c.tar.gz").unwrap();
+    check(&u, "http://example.com/a/b%2Fc.tar.gz?q#f");
+    u.set_extension("zip").unwrap();
+    check(&u, "http://example.com/a/b%2Fc.tar.zip?q#f");
+    u.set_last_segment("..").unwrap();
+    check(&u, "http://example.com/a/..?q#f");
+    u.set_extension("x").unwrap();
+    check(&u, "http://example.com/a/..?q#f");
+    assert!(u.pop_segment());
+    check(&u, "http://example.com/a?q#f");
+    assert!(u.pop_segment());
+    check(&u, "http://example.com/?q#f");
+    assert!(!u.pop_segment());
+
+    let mut u = uri("foo:bar");
+    assert!(u.pop_segment());
+    check(&u, "foo:");
+    u.set_last_segment("x:y").unwrap();
+    check(&u, "foo:x:y");
+
+    let mut u = uri("");
+    assert!(u.push_segment("a:b").is_err());
+    u.push_segment("a").unwrap();
+    check(&u, "a");
+    u.push_segment("b:c").unwrap();
+    check(&u, "a/b:c");
+    assert!(u.set_extension("d:e").is_ok());
+    check(&u, "a/b:c.d:e");
+    assert!(u.pop_segment());
+    assert!(u.set_extension(":").is_err());
+    check(&u, "a");
+}
@@ -0,0 +1,81 @@
+use fluent_uri::encoding::{encoder::Query, EStr, EString};
+use std::borrow::Cow;
+
+fn pairs(s: &str) -> Vec<(String, String)> {
+    EStr::<Query>::new_or_panic(s)
+        .form_pairs()
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect()
+}
+
+fn owned(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs.iter().map(|&(k, v)| (k.into(), v.into())).collect()
+}
+
+#[test]
+fn form_pairs() {
+    // '+' is a space, while "%2B" is a plus sign.
+    assert_eq!(pairs("a+b=c+d"), owned(&[("a b", "c d")]));
+    assert_eq!(pairs("a%2Bb=c%2Bd"), owned(&[("a+b", "c+d")]));
+    assert_eq!(pairs("a%20b=%2B+"), owned(&[("a b", "+ ")]));
+
+    // A pair is split at its first '='.
+    assert_eq!(pairs("a=b=c"), owned(&[("a", "b=c")]));
+    assert_eq!(pairs("a=="), owned(&[("a", "=")]));
+    assert_eq!(pairs("=b"), owned(&[("", "b")]));
+    assert_eq!(pairs("a%3Db=c"), owned(&[("a=b", "c")]));
+
+    // The value is empty if there is no '='.
+    assert_eq!(pairs("a"), owned(&[("a", "")]));
+    assert_eq!(pairs("a&b=1"), owned(&[("a", ""), ("b", "1")]));
+    assert_eq!(pairs("="), owned(&[("", "")]));
+
+    // Empty pieces are skipped.
+    assert_eq!(pairs(""), owned(&[]));
+    assert_eq!(pairs("&&"), owned(&[]));
+    assert_eq!(pairs("a=1&&b=2"), owned(&[("a", "1"), ("b", "2")]));
+    assert_eq!(pairs("&a=1&b=2&"), owned(&[("a", "1"), ("b", "2")]));
+    assert_eq!(pairs("&&a=1&&&"), owned(&[("a", "1")]));
+
+    // Invalid UTF-8 is replaced with U+FFFD.
+    assert_eq!(pairs("a%FF=%C3%28"), owned(&[("a\u{fffd}", "\u{fffd}(")]));
+    assert_eq!(pairs("%E2%82=%E2%82%AC"), owned(&[("\u{fffd}", "€")]));
+
+    // Pairs with nothing to decode are borrowed.
+    let query = EStr::<Query>::new_or_panic("a=1&b+c=%41");
+    let mut iter = query.form_pairs();
+    let (k, v) = iter.next().unwrap();
+    assert!(matches!((k, v), (Cow::Borrowed("a"), Cow::Borrowed("1"))));
+    let (k, v) = iter.next().unwrap();
+    assert!(matches!((&k, &v), (Cow::Owned(_), Cow::Owned(_))));
+    assert_eq!((k, v), ("b c".into(), "A".into()));
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn push_form_pair() {
+    let mut query = EString::<Query>::new();
+    query.push_form_pair("a b", "c+d");
+    assert_eq!(query, "a+b=c%2Bd");
+    query.push_form_pair("", "");
+    assert_eq!(query, "a+b=c%2Bd&=");
+    query.push_form_pair("x=y&z", "100%");
+    assert_eq!(query, "a+b=c%2Bd&=&x%3Dy%26z=100%25");
+    query.push_form_pair("*-._~", "Zoë");
+    assert_eq!(query, "a+b=c%2Bd&=&x%3Dy%26z=100%25&*-._%7E=Zo%C3%AB");
+
+    // Pairs round-trip through `form_pairs`.
+    let input = [
+        ("name", "Zoë Smith"),
+        ("expr", "1+1=2"),
+        ("", ""),
+        ("  ", "a&b"),
+        ("100%", "%2B"),
+        ("\u{fffd}\n", "\u{1f600}"),
+    ];
+    let mut query = EString::<Query>::new();
+    for (name, value) in input {
+        query.push_form_pair(name, value);
+    }
+    assert_eq!(pairs(query.as_str()), owned(&input));
+}
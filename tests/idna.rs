@@ -0,0 +1,261 @@
+#![cfg(feature = "idna")]
+
+use fluent_uri::{
+    component::Host,
+    encoding::EStr,
+    error::IdnaError,
+    idna::{self, punycode},
+    Iri, Uri,
+};
+
+#[test]
+fn punycode() {
+    // Sample strings from Section 7.1 of RFC 3492.
+    let cases = [
+        (
+            "\u{644}\u{64a}\u{647}\u{645}\u{627}\u{628}\u{62a}\u{643}\u{644}\u{645}\u{648}\u{634}\u{639}\u{631}\u{628}\u{64a}\u{61f}",
+            "egbpdaj6bu4bxfgehfvwxn",
+        ),
+        (
+            "\u{4ed6}\u{4eec}\u{4e3a}\u{4ec0}\u{4e48}\u{4e0d}\u{8bf4}\u{4e2d}\u{6587}",
+            "ihqwcrb4cv8a8dqg056pqjye",
+        ),
+        ("Pročprostěnemluvíčesky", "Proprostnemluvesky-uyb24dma41a"),
+        (
+            "PorquénopuedensimplementehablarenEspañol",
+            "PorqunopuedensimplementehablarenEspaol-fmd56a",
+        ),
+        ("3年B組金八先生", "3B-ww4c5e180e575a65lsy2b"),
+        ("安室奈美恵-with-SUPER-MONKEYS", "-with-SUPER-MONKEYS-pc58ag80a8qai00g7n9n"),
+        ("Hello-Another-Way-それぞれの場所", "Hello-Another-Way--fc4qua05auwb3674vfr0b"),
+        ("MajiでKoiする5秒前", "MajiKoi5-783gue6qz075azm5e"),
+        ("-> $1.00 <-", "-> $1.00 <--"),
+        ("bücher", "bcher-kva"),
+    ];
+    for (decoded, encoded) in cases {
+        assert_eq!(punycode::encode(decoded).unwrap(), encoded);
+        assert_eq!(punycode::decode(encoded).unwrap(), decoded);
+    }
+
+    assert_eq!(punycode::decode(""), Some("".into()));
+    assert_eq!(punycode::decode("abc-"), Some("abc".into()));
+    assert_eq!(punycode::decode("a-b"), None);
+    assert_eq!(punycode::decode("ü-kva"), None);
+    assert_eq!(punycode::decode("99999999999"), None);
+}
+
+#[test]
+fn to_ascii_and_unicode() {
+    let cases = [
+        ("example.com", "example.com", "example.com"),
+        ("EXAMPLE.com.", "example.com.", "example.com."),
+        ("Bücher.example", "xn--bcher-kva.example", "bücher.example"),
+        (
+            "xn--BCHER-KVA.example",
+            "xn--bcher-kva.example",
+            "bücher.example",
+        ),
+        ("例子。中国", "xn--fsqu00a.xn--fiqs8s", "例子.中国"),
+        ("ＥＸＡＭＰＬＥ．com", "example.com", "example.com"),
+        ("ex\u{ad}ample.com", "example.com", "example.com"),
+        ("MÜNCHEN.de", "xn--mnchen-3ya.de", "münchen.de"),
+        // Mapping is followed by normalization to NFC.
+        (
+            "bu\u{308}cher.example",
+            "xn--bcher-kva.example",
+            "bücher.example",
+        ),
+        ("ℌ.example", "h.example", "h.example"),
+        ("Straẞe.de", "xn--strae-oqa.de", "straße.de"),
+        ("ﾊﾟｰﾙ.jp", "xn--odk9b6b.jp", "パール.jp"),
+        ("", "", ""),
+    ];
+    for (input, ascii, unicode) in cases {
+        assert_eq!(idna::to_ascii(input).unwrap(), ascii, "{input}");
+        assert_eq!(idna::to_unicode(input).unwrap(), unicode, "{input}");
+        assert_eq!(idna::to_ascii(unicode).unwrap(), ascii, "{input}");
+    }
+
+    let errors = [
+        "exa mple.com",
+        "example.com/",
+        "a%2Eb",
+        "ex\u{7}ample.com",
+        "\u{e000}.com",
+        "xn--a.example",
+        "xn--bcher-kva-ü.example",
+        // An A-label must decode to a non-ASCII label.
+        "xn--abc-.example",
+        // An A-label must decode to a label unchanged by mapping.
+        "xn--wca.example",
+        // An A-label must decode to a label in NFC.
+        "xn--a-ccb.example",
+        // A label cannot start with a combining mark.
+        "\u{308}a.example",
+        // A zero width joiner must follow a virama.
+        "a\u{200d}b.example",
+        // A label in a Bidi domain name must satisfy the Bidi Rule.
+        "a\u{5d0}.example",
+    ];
+    for input in errors {
+        assert!(idna::to_ascii(input).is_err(), "{input}");
+        assert!(idna::to_unicode(input).is_err(), "{input}");
+    }
+}
+
+#[test]
+fn host_ascii_and_unicode() {
+    let uri = Uri::parse("http://B%C3%BCcher.example/").unwrap();
+    let auth = uri.authority().unwrap();
+    assert_eq!(auth.host_ascii().unwrap(), "xn--bcher-kva.example");
+    assert_eq!(auth.host_unicode().unwrap(), "bücher.example");
+
+    let uri = Uri::parse("http://xn--bcher-kva.example/").unwrap();
+    let auth = uri.authority().unwrap();
+    assert_eq!(auth.host_ascii().unwrap(), "xn--bcher-kva.example");
+    assert_eq!(auth.host_unicode().unwrap(), "bücher.example");
+
+    let iri = Iri::parse("http://例子.中国/").unwrap();
+    let auth = iri.authority().unwrap();
+    assert_eq!(auth.host_ascii().unwrap(), "xn--fsqu00a.xn--fiqs8s");
+    assert_eq!(auth.host_unicode().unwrap(), "例子.中国");
+
+    for s in ["http://127.0.0.1/", "http://[::1]/", "http://[v1.x]/"] {
+        let uri = Uri::parse(s).unwrap();
+        let auth = uri.authority().unwrap();
+        assert_eq!(auth.host_ascii().unwrap(), auth.host());
+        assert_eq!(auth.host_unicode().unwrap(), auth.host());
+    }
+
+    let uri = Uri::parse("http://%FF.example/").unwrap();
+    assert!(uri.authority().unwrap().host_ascii().is_err());
+
+    let uri = Uri::parse("http://xn--a.example/").unwrap();
+    assert!(uri.authority().unwrap().host_unicode().is_err());
+}
+
+#[test]
+fn build_unicode_host() {
+    let uri = Uri::builder()
+        .scheme(fluent_uri::component::Scheme::new_or_panic("http"))
+        .authority(|b| b.host("Bücher.example"))
+        .path(EStr::new_or_panic("/"))
+        .build()
+        .unwrap();
+    assert_eq!(uri.as_str(), "http://xn--bcher-kva.example/");
+
+    let uri = Uri::builder()
+        .authority(|b| b.host("127.0.0.1"))
+        .path(EStr::EMPTY)
+        .build()
+        .unwrap();
+    assert!(matches!(
+        uri.authority().unwrap().host_parsed(),
+        Host::Ipv4 { .. }
+    ));
+
+    // Fails to build if the conversion fails.
+    for host in ["a b", "\u{e000}.example", "xn--a.example"] {
+        let e = Uri::builder()
+            .authority(|b| b.host(host))
+            .path(EStr::EMPTY)
+            .build()
+            .unwrap_err();
+        assert_eq!(e.component(), fluent_uri::error::Component::Host);
+    }
+    let e = Uri::builder()
+        .authority(|b| b.host("a b"))
+        .path(EStr::EMPTY)
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "invalid domain name in host: disallowed character in domain name"
+    );
+}
+
+/// Runs the conformance tests in `IdnaTestV2.txt` of Unicode 13.0.0
+/// with nontransitional processing.
+#[test]
+fn conformance() {
+    // Errors for the options disabled: CheckHyphens, UseSTD3ASCIIRules
+    // and VerifyDnsLength.
+    const DISABLED: &[&str] = &["V2", "V3", "U1", "A4_1", "A4_2", "X4_2"];
+    // U+0D01 has become valid in a later version of Unicode.
+    const SKIPPED: &[&str] = &["㨛𘱎.。7\u{d01}", "xn--mbm8237g..xn--7-7hf"];
+
+    fn unescape(s: &str) -> String {
+        let mut out = String::new();
+        let mut rest = s;
+        while let Some(i) = rest.find("\\u") {
+            out.push_str(&rest[..i]);
+            let code = u32::from_str_radix(&rest[i + 2..i + 6], 16).unwrap();
+            out.push(char::from_u32(code).unwrap());
+            rest = &rest[i + 6..];
+        }
+        out + rest
+    }
+
+    fn check(
+        source: &str,
+        actual: Result<String, IdnaError>,
+        expected: &str,
+        status: &str,
+        std3: bool,
+    ) {
+        let errors: Vec<_> = status
+            .trim_matches(|c| c == '[' || c == ']')
+            .split(", ")
+            .filter(|e| !e.is_empty())
+            .collect();
+        // This version of the file reports characters disallowed by
+        // UseSTD3ASCIIRules with P1 or V6 instead of U1.
+        let is_err = errors
+            .iter()
+            .filter(|e| !DISABLED.contains(e))
+            .any(|e| !(std3 && matches!(*e, "P1" | "V6")));
+
+        match actual {
+            Ok(actual) => {
+                assert!(!is_err, "{source:?} should fail with {status}");
+                assert_eq!(actual, expected, "{source:?}");
+            }
+            // Forbidden domain code points are disallowed, as are labels
+            // starting with "xn--" after decoding.
+            Err(_) => assert!(
+                is_err || std3 || errors.iter().any(|e| DISABLED.contains(e)),
+                "{source:?} should succeed"
+            ),
+        }
+    }
+
+    let data = include_str!("data/IdnaTestV2.txt");
+    for line in data.lines() {
+        let line = line.split('#').next().unwrap();
+        if line.trim().is_empty() {
+            continue;
+        }
+        let cols: Vec<_> = line.split(';').map(|col| unescape(col.trim())).collect();
+        let source = &cols[0][..];
+        if SKIPPED.contains(&source) {
+            continue;
+        }
+
+        let or = |col: &str, default: &str| -> String {
+            if col.is_empty() { default } else { col }.into()
+        };
+        let unicode = or(&cols[1], source);
+        let unicode_status = &cols[2];
+        let ascii = or(&cols[3], &unicode);
+        let ascii_status = or(&cols[4], unicode_status);
+
+        let std3 = unicode.chars().any(|c| {
+            matches!(c, '≠' | '≮' | '≯') || c.is_ascii_punctuation() && !matches!(c, '-' | '.')
+        });
+
+        let to_unicode = idna::to_unicode(source);
+        check(source, to_unicode, &unicode, unicode_status, std3);
+        let to_ascii = idna::to_ascii(source);
+        check(source, to_ascii, &ascii, &ascii_status, std3);
+    }
+}
@@ -0,0 +1,171 @@
+use fluent_uri::{
+    component::Host,
+    encoding::{
+        encoder::{IPath, IQuery, Path},
+        EStr, EString,
+    },
+    Iri, Uri,
+};
+
+#[test]
+fn parse_iri() {
+    let i = Iri::parse("http://résumé.example.org").unwrap();
+    assert_eq!(i.scheme().unwrap().as_str(), "http");
+    let a = i.authority().unwrap();
+    assert_eq!(a.as_str(), "résumé.example.org");
+    assert_eq!(a.userinfo(), None);
+    assert_eq!(a.host(), "résumé.example.org");
+    assert!(matches!(a.host_parsed(), Host::RegName(name) if name == "résumé.example.org"));
+    assert_eq!(a.port(), None);
+    assert_eq!(i.path(), "");
+    assert_eq!(i.query(), None);
+    assert_eq!(i.fragment(), None);
+
+    let i = Iri::parse("foo://用户:密码@例子.中国:8080/路径/文件?查询=值#片段").unwrap();
+    let a = i.authority().unwrap();
+    assert_eq!(a.userinfo().unwrap(), "用户:密码");
+    assert_eq!(a.host(), "例子.中国");
+    assert_eq!(a.port_to_u16(), Ok(Some(8080)));
+    assert_eq!(i.path(), "/路径/文件");
+    assert_eq!(i.query().unwrap(), "查询=值");
+    assert_eq!(i.fragment().unwrap(), "片段");
+
+    // Relative reference with a non-ASCII first segment.
+    let i = Iri::parse("ほげ/ふが").unwrap();
+    assert!(i.is_relative_reference());
+    assert_eq!(i.path(), "ほげ/ふが");
+
+    // Private use characters are allowed in query only.
+    let i = Iri::parse("?\u{e000}").unwrap();
+    assert_eq!(i.query().unwrap(), "\u{e000}");
+
+    // Every URI reference is an IRI reference.
+    let i = Iri::parse("http://[::1]:80/%7Efoo?bar#baz").unwrap();
+    assert_eq!(
+        i,
+        Uri::parse("http://[::1]:80/%7Efoo?bar#baz")
+            .unwrap()
+            .as_str()
+    );
+}
+
+#[test]
+fn parse_iri_error() {
+    // Non-ASCII characters are rejected in URI references.
+    let e = Uri::parse("http://résumé.example.org").unwrap_err();
+    assert_eq!(e.to_string(), "unexpected character at index 8");
+
+    // Private use characters outside query.
+    let e = Iri::parse("/\u{e000}").unwrap_err();
+    assert_eq!(e.to_string(), "unexpected character at index 1");
+    let e = Iri::parse("#\u{e000}").unwrap_err();
+    assert_eq!(e.to_string(), "unexpected character at index 1");
+
+    // Non-characters and specials.
+    let e = Iri::parse("\u{fffe}").unwrap_err();
+    assert_eq!(e.to_string(), "unexpected character at index 0");
+    let e = Iri::parse("/\u{fff0}").unwrap_err();
+    assert_eq!(e.to_string(), "unexpected character at index 1");
+    let e = Iri::parse("/\u{1fffe}").unwrap_err();
+    assert_eq!(e.to_string(), "unexpected character at index 1");
+    let e = Iri::parse("/\u{e0001}").unwrap_err();
+    assert_eq!(e.to_string(), "unexpected character at index 1");
+
+    // Non-ASCII characters in scheme, port and IP literals.
+    let e = Iri::parse("hé://a").unwrap_err();
+    assert_eq!(e.to_string(), "unexpected character at index 3");
+    let e = Iri::parse("//a:8é").unwrap_err();
+    assert_eq!(e.to_string(), "unexpected character at index 5");
+    let e = Iri::parse("//[v1.é]").unwrap_err();
+    assert_eq!(e.to_string(), "unexpected character at index 6");
+
+    // Colon in first segment of relative reference.
+    let e = Iri::parse("ほ:げ").unwrap_err();
+    assert_eq!(e.to_string(), "unexpected character at index 3");
+}
+
+#[test]
+fn iri_to_uri() {
+    let i = Iri::parse("http://résumé.example.org/ほげ?\u{e000}#€").unwrap();
+    let u = i.to_uri();
+    assert_eq!(
+        u,
+        "http://r%C3%A9sum%C3%A9.example.org/%E3%81%BB%E3%81%92?%EE%80%80#%E2%82%AC"
+    );
+    assert_eq!(
+        u.authority().unwrap().host(),
+        "r%C3%A9sum%C3%A9.example.org"
+    );
+    assert_eq!(u.path(), "/%E3%81%BB%E3%81%92");
+    assert_eq!(u.query().unwrap(), "%EE%80%80");
+    assert_eq!(u.fragment().unwrap(), "%E2%82%AC");
+
+    let i = Iri::parse("//用户@[::1]:80").unwrap();
+    let u = i.to_uri();
+    assert_eq!(u, "//%E7%94%A8%E6%88%B7@[::1]:80");
+    let a = u.authority().unwrap();
+    assert_eq!(a.userinfo().unwrap(), "%E7%94%A8%E6%88%B7");
+    assert_eq!(a.host(), "[::1]");
+    assert_eq!(a.port().unwrap(), "80");
+
+    // Existing percent-encoded octets are preserved.
+    let i = Iri::parse("a%2fb%c3%a9").unwrap();
+    assert_eq!(i.to_uri(), "a%2fb%c3%a9");
+}
+
+#[test]
+fn uri_to_iri() {
+    let u = Uri::parse("http://r%C3%A9sum%C3%A9.example.org/%E3%81%BB%e3%81%92").unwrap();
+    let i = u.to_iri();
+    assert_eq!(i, "http://résumé.example.org/ほげ");
+    assert_eq!(i.authority().unwrap().host(), "résumé.example.org");
+    assert_eq!(i.path(), "/ほげ");
+
+    // ASCII characters are never decoded.
+    let u = Uri::parse("/%41%2F%20").unwrap();
+    assert_eq!(u.to_iri(), "/%41%2F%20");
+
+    // Invalid or incomplete UTF-8 sequences are left intact.
+    let u = Uri::parse("/%C3%28%E3%81%FF%C3").unwrap();
+    assert_eq!(u.to_iri(), "/%C3%28%E3%81%FF%C3");
+
+    // Private use characters are decoded in query only.
+    let u = Uri::parse("/%EE%80%80?%EE%80%80#%EE%80%80").unwrap();
+    let i = u.to_iri();
+    assert_eq!(i, "/%EE%80%80?\u{e000}#%EE%80%80");
+    assert_eq!(i.query().unwrap(), "\u{e000}");
+    assert_eq!(i.fragment().unwrap(), "%EE%80%80");
+
+    // Bidi formatting characters are not decoded.
+    let u = Uri::parse("/%E2%80%AE").unwrap();
+    assert_eq!(u.to_iri(), "/%E2%80%AE");
+
+    // Round trip.
+    let i = Iri::parse("foo://用户@例子.中国/路径?查询#片段").unwrap();
+    assert_eq!(i.to_uri().to_iri(), i);
+
+    // Bidi formatting characters do not survive a round trip.
+    let i = Iri::parse("http://example.com/a\u{200e}b").unwrap();
+    assert_eq!(i.to_uri().to_iri(), "http://example.com/a%E2%80%8Eb");
+}
+
+#[test]
+fn iri_encoders() {
+    assert!(EStr::<IPath>::new("/ほげ").is_some());
+    assert!(EStr::<IPath>::new("/\u{e000}").is_none());
+    assert!(EStr::<IQuery>::new("\u{e000}").is_some());
+    assert!(EStr::<Path>::new("/ほげ").is_none());
+
+    let mut buf = EString::<IPath>::new();
+    buf.encode::<IPath>("/ほげ ふが\u{e000}");
+    assert_eq!(buf, "/ほげ%20ふが%EE%80%80");
+
+    // Invalid UTF-8 is always percent-encoded.
+    let mut buf = EString::<IQuery>::new();
+    buf.encode::<IQuery>(&[0xc3, 0x28, 0xe2, 0x82]);
+    assert_eq!(buf, "%C3(%E2%82");
+
+    let mut buf = EString::<Path>::new();
+    buf.encode::<Path>("ほげ");
+    assert_eq!(buf, "%E3%81%BB%E3%81%92");
+}
@@ -0,0 +1,130 @@
+use fluent_uri::{scheme::mailto::MailtoUri, Uri};
+
+#[test]
+fn parse() {
+    // Examples from Section 6 of RFC 6068.
+    let uri = Uri::parse("mailto:chris@example.com").unwrap();
+    let mailto = MailtoUri::new(&uri).unwrap();
+    assert!(mailto.to().eq(["chris@example.com"]));
+    assert_eq!(mailto.headers().count(), 0);
+
+    let uri = Uri::parse("mailto:infobot@example.com?subject=current-issue").unwrap();
+    let mailto = MailtoUri::new(&uri).unwrap();
+    assert_eq!(mailto.subject().unwrap(), "current-issue");
+
+    let uri = Uri::parse("mailto:infobot@example.com?body=send%20current-issue%0D%0Asend%20index")
+        .unwrap();
+    let mailto = MailtoUri::new(&uri).unwrap();
+    assert_eq!(mailto.body().unwrap(), "send current-issue\r\nsend index");
+    assert_eq!(mailto.subject(), None);
+
+    let uri = Uri::parse("mailto:?to=joe@example.com&cc=bob@example.com&body=hello").unwrap();
+    let mailto = MailtoUri::new(&uri).unwrap();
+    assert_eq!(mailto.to().count(), 0);
+    assert!(mailto.headers().eq([
+        ("to".into(), "joe@example.com".into()),
+        ("cc".into(), "bob@example.com".into()),
+        ("body".into(), "hello".into()),
+    ]));
+    assert_eq!(mailto.header("CC").unwrap(), "bob@example.com");
+
+    let uri = Uri::parse("mailto:%22not%40me%22@example.org").unwrap();
+    let mailto = MailtoUri::new(&uri).unwrap();
+    assert!(mailto.to().eq(["\"not@me\"@example.org"]));
+
+    let uri = Uri::parse("mailto:user@example.org?subject=caf%C3%A9&X-Tag=a+b=c").unwrap();
+    let mailto = MailtoUri::new(&uri).unwrap();
+    assert_eq!(mailto.subject().unwrap(), "café");
+    assert_eq!(mailto.header("x-tag").unwrap(), "a+b=c");
+
+    let uri = Uri::parse("MAILTO:a@example.com,b%2Cc@example.com?#frag").unwrap();
+    let mailto = MailtoUri::new(&uri).unwrap();
+    assert!(mailto.to().eq(["a@example.com", "b,c@example.com"]));
+    assert_eq!(mailto.headers().count(), 0);
+}
+
+#[test]
+fn parse_error() {
+    let cases = [
+        ("http://example.com/", "not a mailto URI"),
+        ("mailto://example.com/", "not a mailto URI"),
+        ("mailto:a@example.com,", "empty address in mailto URI"),
+        ("mailto:,a@example.com", "empty address in mailto URI"),
+        ("mailto:a@b,,c@d", "empty address in mailto URI"),
+        (
+            "mailto:%FF@example.com",
+            "invalid UTF-8 after percent-decoding in mailto URI",
+        ),
+        (
+            "mailto:?subject=%C3",
+            "invalid UTF-8 after percent-decoding in mailto URI",
+        ),
+        ("mailto:?subject", "invalid header field in mailto URI"),
+        ("mailto:?=x", "invalid header field in mailto URI"),
+        ("mailto:?a=b&", "invalid header field in mailto URI"),
+    ];
+    for (s, msg) in cases {
+        let uri = Uri::parse(s).unwrap();
+        assert_eq!(MailtoUri::new(&uri).unwrap_err().to_string(), msg, "{s}");
+    }
+}
+
+#[test]
+fn build() {
+    let uri = MailtoUri::builder().build().unwrap();
+    assert_eq!(uri.as_str(), "mailto:");
+
+    let uri = MailtoUri::builder()
+        .to("chris@example.com")
+        .build()
+        .unwrap();
+    assert_eq!(uri.as_str(), "mailto:chris@example.com");
+
+    let uri = MailtoUri::builder()
+        .to("\"not@me\"@example.org")
+        .to("a,b/c?d#e%f@example.org")
+        .header("cc", "x@example.com,y@example.com")
+        .subject("a&b=c d")
+        .body("line 1\nline 2\r\nline 3\r")
+        .header("X-Empty", "")
+        .build()
+        .unwrap();
+    assert_eq!(
+        uri.as_str(),
+        "mailto:%22not@me%22@example.org,a%2Cb%2Fc%3Fd%23e%25f@example.org\
+         ?cc=x@example.com,y@example.com&subject=a%26b%3Dc%20d\
+         &body=line%201%0D%0Aline%202%0D%0Aline%203%0D&X-Empty="
+    );
+
+    let mailto = MailtoUri::new(&uri).unwrap();
+    assert!(mailto
+        .to()
+        .eq(["\"not@me\"@example.org", "a,b/c?d#e%f@example.org"]));
+    assert_eq!(mailto.header("cc").unwrap(), "x@example.com,y@example.com");
+    assert_eq!(mailto.subject().unwrap(), "a&b=c d");
+    assert_eq!(mailto.body().unwrap(), "line 1\r\nline 2\r\nline 3\r");
+    assert_eq!(mailto.header("x-empty").unwrap(), "");
+
+    let uri = MailtoUri::builder()
+        .to("用户@例子.广告")
+        .subject("café")
+        .build()
+        .unwrap();
+    assert_eq!(
+        uri.as_str(),
+        "mailto:%E7%94%A8%E6%88%B7@%E4%BE%8B%E5%AD%90.%E5%B9%BF%E5%91%8A?subject=caf%C3%A9"
+    );
+
+    assert_eq!(
+        MailtoUri::builder().to("").build().unwrap_err().to_string(),
+        "empty address in mailto URI"
+    );
+    assert_eq!(
+        MailtoUri::builder()
+            .header("", "x")
+            .build()
+            .unwrap_err()
+            .to_string(),
+        "invalid header field in mailto URI"
+    );
+}
@@ -121,3 +121,219 @@ fn normalize() {
     let u = Uri::parse("//[v1FdE.AddR]").unwrap();
     assert_eq!(u.normalize(), "//[v1fde.addr]");
 }
+
+#[test]
+fn normalize_with_scheme() {
+    use fluent_uri::{
+        component::Scheme,
+        scheme::{SchemeRules, WELL_KNOWN},
+    };
+
+    let normalize = |s: &str| Uri::parse(s).unwrap().normalize_with(WELL_KNOWN);
+
+    // Default port.
+    assert_eq!(normalize("http://example.com:80/"), "http://example.com/");
+    assert_eq!(
+        normalize("HTTPS://example.com:443/"),
+        "https://example.com/"
+    );
+    assert_eq!(normalize("ws://example.com:0080/"), "ws://example.com/");
+    assert_eq!(normalize("wss://example.com:443/"), "wss://example.com/");
+    assert_eq!(normalize("ftp://example.com:21/"), "ftp://example.com/");
+    assert_eq!(
+        normalize("http://example.com:443/"),
+        "http://example.com:443/"
+    );
+    assert_eq!(
+        normalize("http://example.com:99999/"),
+        "http://example.com:99999/"
+    );
+
+    // Empty path with authority.
+    assert_eq!(normalize("http://example.com"), "http://example.com/");
+    assert_eq!(normalize("http://example.com:80"), "http://example.com/");
+    assert_eq!(normalize("http://example.com?q"), "http://example.com/?q");
+
+    // Empty port, with the empty query kept.
+    assert_eq!(normalize("http://example.com:?"), "http://example.com/?");
+    assert_eq!(normalize("http://example.com/?#"), "http://example.com/?#");
+
+    // Unknown scheme and relative reference.
+    assert_eq!(normalize("foo://example.com:80?"), "foo://example.com:80?");
+    assert_eq!(normalize("//example.com:80?"), "//example.com:80?");
+
+    // Idempotence.
+    let u = normalize("HTTP://%65xample.com:80?");
+    assert_eq!(u, "http://example.com/?");
+    assert_eq!(u.normalize_with(WELL_KNOWN), u);
+
+    // Custom registry.
+    const SCHEME_FOO: &Scheme = Scheme::new_or_panic("foo");
+    let registry = [(SCHEME_FOO, SchemeRules::new().default_port(1234))];
+    let u = Uri::parse("FOO://example.com:1234?").unwrap();
+    assert_eq!(u.normalize_with(&registry), "foo://example.com?");
+
+    let registry = |scheme: &Scheme| {
+        (scheme == SCHEME_FOO).then(|| SchemeRules::new().empty_path_as_root(true))
+    };
+    assert_eq!(u.normalize_with(&registry), "foo://example.com:1234/?");
+    let registry = |scheme: &Scheme| {
+        (scheme == SCHEME_FOO).then(|| SchemeRules::new().remove_empty_query(true))
+    };
+    assert_eq!(u.normalize_with(&registry), "foo://example.com:1234");
+}
+
+#[test]
+fn normalize_options() {
+    use fluent_uri::{NormalizeOptions, TrailingSlash};
+
+    let check = |opts: NormalizeOptions, s: &str, expected: &str| {
+        let u = opts.normalize(&Uri::parse(s).unwrap());
+        assert_eq!(u, expected);
+        // Idempotence.
+        assert_eq!(opts.normalize(&u), expected);
+        // Components are correctly delimited.
+        assert_eq!(
+            format!("{u:?}"),
+            format!("{:?}", Uri::parse(expected).unwrap())
+        );
+    };
+
+    let opts = NormalizeOptions::new().case(false);
+    check(opts, "HTTP://EXAMPLE.com/%7e%3a", "HTTP://EXAMPLE.com/~%3a");
+    check(opts, "a://[V1.AB]", "a://[V1.AB]");
+
+    let opts = NormalizeOptions::new().percent_encoding(false);
+    check(
+        opts,
+        "HTTP://%45XAMPLE.com/%7e%3a",
+        "http://%45xample.com/%7E%3A",
+    );
+
+    let opts = NormalizeOptions::new().remove_dot_segments(false);
+    check(opts, "http://a/b/../c/./d", "http://a/b/../c/./d");
+
+    let opts = NormalizeOptions::new().remove_dot_segments_in_relative(true);
+    check(opts, "/a/../b", "/b");
+    check(opts, "//a/../b", "//a/b");
+    check(opts, "a/./b/../c", "a/c");
+    check(opts, "../a/../../b/.", "../../b/");
+    check(opts, "a/..", "./");
+    check(opts, ".", "./");
+    check(opts, "./a:b", "./a:b");
+    check(opts, "a/../b%3Ac/%2e", "b%3Ac/");
+    check(opts, "a/..//b", ".//b");
+    check(opts, "?q", "?q");
+    check(opts, "foo:a/../b", "foo:a/../b");
+
+    let opts = NormalizeOptions::new().canonicalize_ipv6(false);
+    check(opts, "http://[0:0::AB]/", "http://[0:0::ab]/");
+    let opts = opts.case(false);
+    check(opts, "http://[0:0::AB]/", "http://[0:0::AB]/");
+    check(
+        NormalizeOptions::new(),
+        "http://[0:0::AB]/",
+        "http://[::ab]/",
+    );
+
+    let opts = NormalizeOptions::new().trailing_slash(TrailingSlash::Add);
+    check(opts, "http://a", "http://a");
+    check(opts, "http://a/b?q", "http://a/b/?q");
+    check(opts, "http://a/b/", "http://a/b/");
+    check(opts, "b", "b/");
+    check(opts, "mailto:a@b", "mailto:a@b");
+
+    let opts = NormalizeOptions::new().trailing_slash(TrailingSlash::Remove);
+    check(opts, "http://a/", "http://a/");
+    check(opts, "http://a/b//?q", "http://a/b?q");
+    check(opts, "b/", "b");
+    check(opts, "foo:/a/b/./", "foo:/a/b");
+    check(opts, "mailto:a@b/", "mailto:a@b/");
+
+    let opts = NormalizeOptions::new().remove_empty_components(true);
+    check(opts, "http://@a:?#", "http://a");
+    check(opts, "http://u@a/?q#f", "http://u@a/?q#f");
+    check(NormalizeOptions::new(), "http://@a:?#", "http://@a?#");
+}
+
+#[test]
+fn normalized_eq() {
+    use fluent_uri::NormalizedHash;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    fn hash(u: &Uri<&str>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        NormalizedHash(*u).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let cases = [
+        "http://example.com/a/b",
+        "HTTP://EXAMPLE.COM/a/b",
+        "http://%65xample.com/a/./b",
+        "http://example.com:/a/c/../b",
+        "http://example.com/a/B",
+        "http://example.com/a/b/",
+        "http://example.com/a/b/.",
+        "http://example.com/a/b/c/..",
+        "http://example.com/a/%62",
+        "http://example.com/a/%2e%2E/a/b",
+        "http://example.com/../../a/b",
+        "http://example.com/a/b?",
+        "http://example.com/a/b#",
+        "http://example.com/a/b?%7e",
+        "http://example.com/a/b?~",
+        "http://example.com/a/b?%7E%2f",
+        "http://example.com/a/b?%7e%2F",
+        "http://@example.com/a/b",
+        "http://%75@example.com/a/b",
+        "http://u@example.com/a/b",
+        "http://example.com:80/a/b",
+        "http://example.com",
+        "http://example.com/",
+        "http://example.com/.",
+        "http://example.com/..",
+        "http://example.com/a/..",
+        "http://[::1]/",
+        "http://[0:0::1]/",
+        "http://[0:0::0:1]/",
+        "http://[::ffff:1.2.3.4]/",
+        "http://[::ffff:102:304]/",
+        "http://[v1.AB]/",
+        "http://[V1.ab]/",
+        "http://1.2.3.4/",
+        "http://%31.2.3.4/",
+        "foo:/a//b",
+        "foo:/a/c/..//b",
+        "foo:/.//a",
+        "foo:/..//a",
+        "foo:a/../b",
+        "FOO:a/../b",
+        "foo:b",
+        "a/../b",
+        "b",
+        "%62",
+        "",
+        "#",
+        "?",
+        "//example.com/a/../b",
+        "//EXAMPLE.com/a/../b",
+        "//example.com/b",
+    ];
+
+    for a in cases {
+        let a = Uri::parse(a).unwrap();
+        for b in cases {
+            let b = Uri::parse(b).unwrap();
+            let expected = a.normalize() == b.normalize();
+            assert_eq!(a.normalized_eq(&b), expected, "{a} vs {b}");
+            assert_eq!(NormalizedHash(a) == NormalizedHash(b), expected);
+            if expected {
+                assert_eq!(hash(&a), hash(&b), "{a} vs {b}");
+            }
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use fluent_uri::{origin::Origin, Uri};
+use std::collections::HashSet;
+
+fn origin(s: &str) -> Origin {
+    Uri::parse(s).unwrap().origin()
+}
+
+#[test]
+fn tuple_origin() {
+    let check = |s: &str, scheme: &str, host: &str, port: u16, serialized: &str| {
+        let origin = origin(s);
+        assert_eq!(origin.to_string(), serialized);
+        assert_eq!(origin.ascii_serialization(), serialized);
+        let Origin::Tuple(tuple) = origin else {
+            panic!("{s} has opaque origin");
+        };
+        assert_eq!(tuple.scheme().as_str(), scheme);
+        assert_eq!(tuple.host(), host);
+        assert_eq!(tuple.port(), port);
+    };
+
+    check(
+        "http://example.com/",
+        "http",
+        "example.com",
+        80,
+        "http://example.com",
+    );
+    check(
+        "HTTP://EXAMPLE.com:80",
+        "http",
+        "example.com",
+        80,
+        "http://example.com",
+    );
+    check(
+        "https://example.com:80",
+        "https",
+        "example.com",
+        80,
+        "https://example.com:80",
+    );
+    check(
+        "https://u:p@example.com:/?q#f",
+        "https",
+        "example.com",
+        443,
+        "https://example.com",
+    );
+    check("ws://%65x.com", "ws", "ex.com", 80, "ws://ex.com");
+    check("wss://ex.com:0443", "wss", "ex.com", 443, "wss://ex.com");
+    check("ftp://ex.com", "ftp", "ex.com", 21, "ftp://ex.com");
+    check(
+        "http://127.0.0.1:8080",
+        "http",
+        "127.0.0.1",
+        8080,
+        "http://127.0.0.1:8080",
+    );
+    check("http://[0:0::1]", "http", "[::1]", 80, "http://[::1]");
+    check(
+        "http://%31%32%37.0.0.1",
+        "http",
+        "127.0.0.1",
+        80,
+        "http://127.0.0.1",
+    );
+    check("HTTP://[A::B]/", "http", "[a::b]", 80, "http://[a::b]");
+}
+
+#[test]
+fn opaque_origin() {
+    for s in [
+        "file:///etc/hosts",
+        "file://host/share",
+        "mailto:user@example.com",
+        "data:,hello",
+        "foo://example.com/",
+        "http:example.com",
+        "http:///path",
+        "http://[v1.x]/",
+        "http://example.com:65536/",
+        "//example.com/",
+        "/path",
+    ] {
+        let origin = origin(s);
+        assert!(origin.is_opaque(), "{s}");
+        assert!(!origin.is_tuple());
+        assert_eq!(origin.to_string(), "null");
+        assert_eq!(origin, origin.clone());
+
+        let uri = Uri::parse(s).unwrap();
+        assert!(!uri.same_origin(&uri));
+        assert_ne!(uri.origin(), uri.origin());
+    }
+}
+
+#[test]
+fn same_origin() {
+    let a = Uri::parse("https://example.com/a?b#c").unwrap();
+    assert!(a.same_origin(&a));
+    for s in [
+        "HTTPS://Example.com:443/",
+        "https://%65xample.com/d",
+        "https://x@example.com",
+    ] {
+        assert!(a.same_origin(&Uri::parse(s).unwrap()), "{s}");
+    }
+    for s in [
+        "http://example.com/a",
+        "https://example.com:8443/a",
+        "https://www.example.com/a",
+        "wss://example.com/a",
+    ] {
+        assert!(!a.same_origin(&Uri::parse(s).unwrap()), "{s}");
+    }
+
+    let set: HashSet<Origin> = [
+        "http://a.com",
+        "http://A.com:80/x",
+        "http://a.com:81",
+        "https://a.com",
+    ]
+    .into_iter()
+    .map(origin)
+    .collect();
+    assert_eq!(set.len(), 3);
+}
@@ -0,0 +1,133 @@
+use fluent_uri::{Fixup, ParseOptions, Uri};
+
+fn pass(s: &str, res: &str, fixups: &[Fixup]) {
+    let (uri, f) = Uri::parse_lenient(s).unwrap();
+    assert_eq!(uri, res);
+    assert!(f.iter().eq(fixups.iter().copied()), "{f:?} != {fixups:?}");
+    // The output is a valid URI reference.
+    assert_eq!(Uri::parse(uri.as_str()).unwrap(), uri);
+}
+
+#[test]
+fn parse_lenient() {
+    use Fixup::*;
+
+    pass("http://example.com/", "http://example.com/", &[]);
+    pass("", "", &[]);
+
+    pass(
+        "\u{0}\t http://example.com/ \x1f",
+        "http://example.com/",
+        &[TrimmedC0ControlOrSpace],
+    );
+    pass(
+        "http://exa\tmple.com/\r\nfoo",
+        "http://example.com/foo",
+        &[RemovedTabOrNewline],
+    );
+
+    // Backslashes before query and fragment.
+    pass(
+        "http:\\\\example.com\\foo\\bar?a\\b#c\\d",
+        "http://example.com/foo/bar?a%5Cb#c%5Cd",
+        &[ReplacedBackslash, EncodedInvalidChar],
+    );
+    pass(
+        "HTTPS:\\\\example.com\\",
+        "HTTPS://example.com/",
+        &[ReplacedBackslash],
+    );
+    // Not a special scheme.
+    pass("foo:\\bar", "foo:%5Cbar", &[EncodedInvalidChar]);
+    pass("\\foo", "%5Cfoo", &[EncodedInvalidChar]);
+
+    // Slashes after special schemes.
+    pass(
+        "http:example.com",
+        "http://example.com",
+        &[FixedAuthoritySlashes],
+    );
+    pass(
+        "ws:/example.com/",
+        "ws://example.com/",
+        &[FixedAuthoritySlashes],
+    );
+    pass(
+        "ftp:////example.com/",
+        "ftp://example.com/",
+        &[FixedAuthoritySlashes],
+    );
+    pass("file:foo", "file:foo", &[]);
+    pass("file:/foo", "file:/foo", &[]);
+    pass("foo:bar", "foo:bar", &[]);
+
+    // Invalid characters.
+    pass(
+        "http://us er@exam ple.com:8080/a b?c d#e f",
+        "http://us%20er@exam%20ple.com:8080/a%20b?c%20d#e%20f",
+        &[EncodedInvalidChar],
+    );
+    pass(
+        "http://a@b@example.com/",
+        "http://a%40b@example.com/",
+        &[EncodedInvalidChar],
+    );
+    pass(
+        "http://example.com/ö?ö#ö",
+        "http://example.com/%C3%B6?%C3%B6#%C3%B6",
+        &[EncodedInvalidChar],
+    );
+    pass(
+        "http://[::1]:80/<>",
+        "http://[::1]:80/%3C%3E",
+        &[EncodedInvalidChar],
+    );
+    pass("/%zz%4", "/%25zz%254", &[EncodedInvalidChar]);
+    pass("/%41", "/%41", &[]);
+    pass("#a#b", "#a%23b", &[EncodedInvalidChar]);
+    pass("?a?b[]", "?a?b%5B%5D", &[EncodedInvalidChar]);
+    pass("1a:b/c:d", "1a%3Ab/c:d", &[EncodedInvalidChar]);
+}
+
+#[test]
+fn parse_lenient_error() {
+    // Invalid port.
+    let e = Uri::parse_lenient("http://example.com:8o/").unwrap_err();
+    assert_eq!(e.to_string(), "unexpected character at index 20");
+    assert_eq!(e.into_input(), "http://example.com:8o/");
+
+    // Invalid IPv6 address.
+    let e = Uri::parse_lenient(" http://[::1::]/").unwrap_err();
+    assert_eq!(e.to_string(), "invalid IPv6 address at index 8");
+    assert_eq!(e.into_input(), "http://[::1::]/");
+}
+
+#[test]
+fn parse_options() {
+    let opts = ParseOptions::new()
+        .trim_c0_control_or_space(false)
+        .remove_tab_or_newline(false)
+        .backslash_as_slash(false)
+        .fix_authority_slashes(false)
+        .encode_invalid_chars(false);
+    assert!(opts.parse(" http://example.com/").is_err());
+    assert!(opts.parse("http://exa\tmple.com/").is_err());
+    assert!(opts.parse("http:\\\\example.com\\").is_err());
+
+    let (uri, fixups) = opts.parse("http:example.com").unwrap();
+    assert_eq!(uri, "http:example.com");
+    assert!(fixups.is_empty());
+
+    let opts = ParseOptions::new().encode_invalid_chars(false);
+    let (uri, fixups) = opts.parse("http:\\\\example.com\\").unwrap();
+    assert_eq!(uri, "http://example.com/");
+    assert!(fixups.contains(Fixup::ReplacedBackslash));
+    assert!(!fixups.contains(Fixup::FixedAuthoritySlashes));
+
+    let opts = ParseOptions::new().backslash_as_slash(false);
+    let (uri, fixups) = opts.parse("http:\\\\example.com\\").unwrap();
+    assert_eq!(uri, "http://%5C%5Cexample.com%5C");
+    assert!(fixups
+        .iter()
+        .eq([Fixup::FixedAuthoritySlashes, Fixup::EncodedInvalidChar]));
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "psl")]
+
+use fluent_uri::{psl::List, Iri, Uri};
+
+#[test]
+fn parse() {
+    let list = List::parse(
+        "// comment\n\
+         \n\
+         com\n\
+         *.jp   trailing text\n\
+         !city.jp\n\
+         BAD..rule\n\
+         a.*.b\n\
+         \tindented\n",
+    );
+    assert_eq!(list.len(), 4);
+    assert_eq!(list.registrable_domain("example.COM"), Some("example.COM"));
+    assert_eq!(list.registrable_domain("a.b.jp"), Some("a.b.jp"));
+    assert_eq!(list.registrable_domain("a.city.jp"), Some("city.jp"));
+    assert_eq!(list.public_suffix("x.indented"), Some("indented"));
+
+    let empty = List::parse("");
+    assert!(empty.is_empty());
+    assert_eq!(empty.public_suffix("a.b.c"), Some("c"));
+    assert_eq!(empty.registrable_domain("a.b.c"), Some("b.c"));
+}
+
+#[test]
+fn embedded() {
+    let list = List::embedded();
+    let check = |name: &str, suffix: Option<&str>, registrable: Option<&str>| {
+        assert_eq!(list.public_suffix(name), suffix, "{name}");
+        assert_eq!(list.registrable_domain(name), registrable, "{name}");
+    };
+
+    check("", None, None);
+    check(".", None, None);
+    check(".com", None, None);
+    check("example..com", None, None);
+    check("example.com..", None, None);
+
+    // Unlisted TLD.
+    check("example", Some("example"), None);
+    check("example.example", Some("example"), Some("example.example"));
+    check(
+        "b.example.example",
+        Some("example"),
+        Some("example.example"),
+    );
+
+    // Listed TLD.
+    check("com", Some("com"), None);
+    check("example.com", Some("com"), Some("example.com"));
+    check("b.example.COM", Some("COM"), Some("example.COM"));
+    check("www.example.com.", Some("com."), Some("example.com."));
+    check("example.co.uk", Some("co.uk"), Some("example.co.uk"));
+
+    // Private domains.
+    check("github.io", Some("github.io"), None);
+    check("user.github.io", Some("github.io"), Some("user.github.io"));
+
+    // Wildcard and exception rules.
+    check("ck", Some("ck"), None);
+    check("test.ck", Some("test.ck"), None);
+    check("b.test.ck", Some("test.ck"), Some("b.test.ck"));
+    check("www.ck", Some("ck"), Some("www.ck"));
+    check("www.www.ck", Some("ck"), Some("www.ck"));
+    check("c.kobe.jp", Some("c.kobe.jp"), None);
+    check("a.b.c.kobe.jp", Some("c.kobe.jp"), Some("b.c.kobe.jp"));
+    check("city.kobe.jp", Some("kobe.jp"), Some("city.kobe.jp"));
+    check("www.city.kobe.jp", Some("kobe.jp"), Some("city.kobe.jp"));
+
+    // Internationalized domain names.
+    check("食狮.com.cn", Some("com.cn"), Some("食狮.com.cn"));
+    check("www.食狮.公司.cn", Some("公司.cn"), Some("食狮.公司.cn"));
+    #[cfg(feature = "idna")]
+    check(
+        "www.xn--85x722f.xn--55qx5d.cn",
+        Some("xn--55qx5d.cn"),
+        Some("xn--85x722f.xn--55qx5d.cn"),
+    );
+}
+
+#[test]
+fn domain() {
+    let list = List::embedded();
+
+    let uri = Uri::parse("http://www.Example.co.uk:8080/").unwrap();
+    let domain = list.domain(uri.authority().unwrap().host_parsed()).unwrap();
+    assert_eq!(domain.as_str(), "www.Example.co.uk");
+    assert_eq!(domain.public_suffix(), "co.uk");
+    assert_eq!(domain.registrable_domain(), Some("Example.co.uk"));
+
+    let uri = Uri::parse("http://www.%E9%A3%9F%E7%8B%AE.com.cn/").unwrap();
+    let domain = list.domain(uri.authority().unwrap().host_parsed()).unwrap();
+    assert_eq!(domain.registrable_domain(), Some("食狮.com.cn"));
+    assert_eq!(domain.into_name(), "www.食狮.com.cn");
+
+    let iri = Iri::parse("http://www.食狮.公司.cn/").unwrap();
+    let domain = list.domain(iri.authority().unwrap().host_parsed()).unwrap();
+    assert_eq!(domain.registrable_domain(), Some("食狮.公司.cn"));
+
+    let uri = Uri::parse("http://co.uk/").unwrap();
+    let domain = list.domain(uri.authority().unwrap().host_parsed()).unwrap();
+    assert_eq!(domain.public_suffix(), "co.uk");
+    assert_eq!(domain.registrable_domain(), None);
+
+    for s in [
+        "http://127.0.0.1/",
+        "http://[::1]/",
+        "http://[v1.x]/",
+        "http:///",
+        "http://%FF.com/",
+    ] {
+        let uri = Uri::parse(s).unwrap();
+        assert!(list
+            .domain(uri.authority().unwrap().host_parsed())
+            .is_none());
+    }
+}
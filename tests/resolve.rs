@@ -1,4 +1,4 @@
-use fluent_uri::Uri;
+use fluent_uri::{ResolveOptions, Uri};
 
 trait Test {
     fn pass(&self, r: &str, res: &str);
@@ -112,3 +112,92 @@ fn resolve_error() {
         "resolving non-same-document relative reference against non-hierarchical base URI",
     );
 }
+
+#[test]
+fn resolve_options() {
+    let resolve = |opts: ResolveOptions, base: &str, r: &str| {
+        let (base, r) = (Uri::parse(base).unwrap(), Uri::parse(r).unwrap());
+        opts.resolve(&r, &base).map_err(|e| e.to_string())
+    };
+
+    let opts = ResolveOptions::new().strict(false);
+    let base = "http://a/b/c/d;p?q";
+    assert_eq!(resolve(opts, base, "http:g").unwrap(), "http://a/b/c/g");
+    assert_eq!(
+        resolve(opts, base, "HTTP:?y").unwrap(),
+        "http://a/b/c/d;p?y"
+    );
+    assert_eq!(resolve(opts, base, "http://g").unwrap(), "http://g");
+    assert_eq!(resolve(opts, base, "https:g").unwrap(), "https:g");
+    assert_eq!(resolve(opts, "foo:bar", "foo:#baz").unwrap(), "foo:bar#baz");
+
+    let opts = ResolveOptions::new().allow_path_underflow(false);
+    let underflow = "double-dot segment climbing above root of path";
+    assert_eq!(resolve(opts, base, "../../g").unwrap(), "http://a/g");
+    assert_eq!(resolve(opts, base, "../../../g").unwrap_err(), underflow);
+    assert_eq!(resolve(opts, base, "/../g").unwrap_err(), underflow);
+    assert_eq!(resolve(opts, base, "//g/../h").unwrap_err(), underflow);
+    assert_eq!(resolve(opts, base, "g:/../h").unwrap_err(), underflow);
+    assert_eq!(resolve(opts, "foo:/bar/..", "..").unwrap_err(), underflow);
+    assert_eq!(resolve(opts, "foo:/../..", ".").unwrap(), "foo:/");
+
+    let opts = ResolveOptions::new().whatwg_non_hierarchical_base(true);
+    let non_hierarchical =
+        "resolving non-same-document relative reference against non-hierarchical base URI";
+    assert_eq!(resolve(opts, "foo:bar?q", "#baz").unwrap(), "foo:bar?q#baz");
+    assert_eq!(resolve(opts, "foo:bar", "baz:qux").unwrap(), "baz:qux");
+    assert_eq!(resolve(opts, "foo:bar", "").unwrap_err(), non_hierarchical);
+    assert_eq!(
+        resolve(opts, "foo:bar", "?baz").unwrap_err(),
+        non_hierarchical
+    );
+    assert_eq!(resolve(opts, base, "").unwrap(), base);
+}
+
+#[test]
+fn make_relative() {
+    fn check(base: &str, target: &str, expected: Option<&str>) {
+        let base = Uri::parse(base).unwrap();
+        let target = Uri::parse(target).unwrap();
+        let rel = target.make_relative_to(&base);
+        assert_eq!(rel.as_ref().map(|r| r.as_str()), expected);
+        if let Some(rel) = rel {
+            assert_eq!(rel.resolve_against(&base).unwrap(), target);
+        }
+    }
+
+    let base = "http://a/b/c/d;p?q";
+    check(base, "http://a/b/c/d;p?q", Some(""));
+    check(base, "http://a/b/c/d;p?q#s", Some("#s"));
+    check(base, "http://a/b/c/d;p?y", Some("?y"));
+    check(base, "http://a/b/c/d;p", Some("d;p"));
+    check(base, "http://a/b/c/g", Some("g"));
+    check(base, "http://a/b/c/g/", Some("g/"));
+    check(base, "http://a/b/c/", Some("./"));
+    check(base, "http://a/b/", Some("../"));
+    check(base, "http://a/b/g", Some("../g"));
+    check(base, "http://a/g", Some("/g"));
+    check(base, "http://a/", Some("/"));
+    check(base, "http://a/b/c/g?y#s", Some("g?y#s"));
+    check(base, "http://a/b/c/g:h", Some("./g:h"));
+    check(base, "http://a/b/c//g", Some(".//g"));
+    check(base, "http://a//g", Some("../..//g"));
+    check(base, "http://a", Some("//a"));
+    check(base, "http://g/b", Some("//g/b"));
+    check(base, "https://a/b/c/g", Some("https://a/b/c/g"));
+    check(base, "http://a/b/../g", None);
+    check(base, "http:g", Some("http:g"));
+
+    check("http://a", "http://a/b", Some("b"));
+    check("http://a?q", "http://a", Some("//a"));
+    check("foo:/a/b", "foo:/a/c", Some("c"));
+    check("foo:/a/b", "foo:/.//c", Some("/.//c"));
+    check("foo:/a/b", "foo:c", Some("foo:c"));
+    check("foo:/a/b/..", "foo:/a/c", Some("c"));
+
+    check("foo:bar", "foo:bar#baz", Some("#baz"));
+    check("foo:bar", "foo:baz", Some("foo:baz"));
+
+    check("http://a/#f", "http://a/", None);
+    check("http://a/", "/b", None);
+}
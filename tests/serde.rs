@@ -0,0 +1,66 @@
+#![cfg(feature = "serde")]
+
+use fluent_uri::{
+    encoding::{
+        encoder::{Path, Query},
+        EStr, EString,
+    },
+    Uri,
+};
+use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
+
+#[test]
+fn serde_uri() {
+    let s = "http://example.com/foo?bar#baz";
+
+    let uri = Uri::parse(s).unwrap();
+    assert_tokens(&uri, &[Token::BorrowedStr(s)]);
+
+    let uri = uri.to_owned();
+    assert_tokens(&uri, &[Token::Str(s)]);
+    assert_de_tokens(&uri, &[Token::String(s)]);
+    assert_de_tokens(&uri, &[Token::BorrowedStr(s)]);
+
+    assert_de_tokens_error::<Uri<String>>(
+        &[Token::Str("http://[::1")],
+        "unexpected character at index 11",
+    );
+    assert_de_tokens_error::<Uri<String>>(
+        &[Token::String("a b")],
+        "unexpected character at index 1",
+    );
+    assert_de_tokens_error::<Uri<&str>>(
+        &[Token::BorrowedStr("%zz")],
+        "invalid percent-encoded octet at index 0",
+    );
+
+    // Borrowed input is required.
+    assert_de_tokens_error::<Uri<&str>>(
+        &[Token::Str(s)],
+        &format!("invalid type: string {s:?}, expected a borrowed URI reference"),
+    );
+}
+
+#[test]
+fn serde_estr() {
+    let s = EStr::<Path>::new_or_panic("/foo%20bar");
+    assert_tokens(&s, &[Token::BorrowedStr("/foo%20bar")]);
+
+    let mut buf = EString::<Query>::new();
+    buf.encode::<Query>("a b");
+    assert_tokens(&buf, &[Token::Str("a%20b")]);
+    assert_de_tokens(&buf, &[Token::String("a%20b")]);
+
+    assert_de_tokens_error::<&EStr<Path>>(
+        &[Token::BorrowedStr("/a?b")],
+        "invalid value: string \"/a?b\", expected a borrowed percent-encoded string",
+    );
+    assert_de_tokens_error::<EString<Path>>(
+        &[Token::String("%2")],
+        "invalid value: string \"%2\", expected a percent-encoded string",
+    );
+    assert_de_tokens_error::<&EStr<Path>>(
+        &[Token::String("/a")],
+        "invalid type: string \"/a\", expected a borrowed percent-encoded string",
+    );
+}
@@ -0,0 +1,332 @@
+use fluent_uri::{
+    template::{UriTemplate, Value},
+    Uri,
+};
+use std::collections::BTreeMap;
+
+fn vars() -> BTreeMap<&'static str, Value> {
+    // Variables from RFC 6570, Section 3.2.
+    let mut vars = BTreeMap::new();
+    vars.insert("count", Value::from(["one", "two", "three"]));
+    vars.insert("dom", Value::from(["example", "com"]));
+    vars.insert("dub", Value::from("me/too"));
+    vars.insert("hello", Value::from("Hello World!"));
+    vars.insert("half", Value::from("50%"));
+    vars.insert("var", Value::from("value"));
+    vars.insert("who", Value::from("fred"));
+    vars.insert("base", Value::from("http://example.com/home/"));
+    vars.insert("path", Value::from("/foo/bar"));
+    vars.insert("list", Value::from(["red", "green", "blue"]));
+    vars.insert(
+        "keys",
+        Value::from([("semi", ";"), ("dot", "."), ("comma", ",")]),
+    );
+    vars.insert("v", Value::from("6"));
+    vars.insert("x", Value::from("1024"));
+    vars.insert("y", Value::from("768"));
+    vars.insert("empty", Value::from(""));
+    vars.insert("empty_keys", Value::AssocArray(vec![]));
+    vars
+}
+
+fn check(cases: &[(&str, &str)]) {
+    let vars = vars();
+    for &(template, expected) in cases {
+        let t = UriTemplate::parse(template).unwrap();
+        assert_eq!(t.as_str(), template);
+        assert_eq!(t.expand(&vars).unwrap(), expected, "{template}");
+    }
+}
+
+#[test]
+fn expand_level1_and_level2() {
+    check(&[
+        ("{var}", "value"),
+        ("{hello}", "Hello%20World%21"),
+        ("{half}", "50%25"),
+        ("O{empty}X", "OX"),
+        ("O{undef}X", "OX"),
+        ("{+var}", "value"),
+        ("{+hello}", "Hello%20World!"),
+        ("{+half}", "50%25"),
+        ("{base}index", "http%3A%2F%2Fexample.com%2Fhome%2Findex"),
+        ("{+base}index", "http://example.com/home/index"),
+        ("{+path}/here", "/foo/bar/here"),
+        ("here?ref={+path}", "here?ref=/foo/bar"),
+        ("X{#var}", "X#value"),
+        ("X{#hello}", "X#Hello%20World!"),
+    ]);
+}
+
+#[test]
+fn expand_level3() {
+    check(&[
+        ("map?{x,y}", "map?1024,768"),
+        ("{x,hello,y}", "1024,Hello%20World%21,768"),
+        ("{+x,hello,y}", "1024,Hello%20World!,768"),
+        ("{+path,x}/here", "/foo/bar,1024/here"),
+        ("{#x,hello,y}", "#1024,Hello%20World!,768"),
+        ("{#path,x}/here", "#/foo/bar,1024/here"),
+        ("X{.var}", "X.value"),
+        ("X{.x,y}", "X.1024.768"),
+        ("{/var}", "/value"),
+        ("{/var,x}/here", "/value/1024/here"),
+        ("{;x,y}", ";x=1024;y=768"),
+        ("{;x,y,empty}", ";x=1024;y=768;empty"),
+        ("{?x,y}", "?x=1024&y=768"),
+        ("{?x,y,empty}", "?x=1024&y=768&empty="),
+        ("?fixed=yes{&x}", "?fixed=yes&x=1024"),
+        ("{&x,y,empty}", "&x=1024&y=768&empty="),
+    ]);
+}
+
+#[test]
+fn expand_level4() {
+    check(&[
+        ("{var:3}", "val"),
+        ("{var:30}", "value"),
+        ("{list}", "red,green,blue"),
+        ("{list*}", "red,green,blue"),
+        ("{keys}", "semi,%3B,dot,.,comma,%2C"),
+        ("{keys*}", "semi=%3B,dot=.,comma=%2C"),
+        ("{+path:6}/here", "/foo/b/here"),
+        ("{+list}", "red,green,blue"),
+        ("{+list*}", "red,green,blue"),
+        ("{+keys}", "semi,;,dot,.,comma,,"),
+        ("{+keys*}", "semi=;,dot=.,comma=,"),
+        ("{#path:6}/here", "#/foo/b/here"),
+        ("{#list}", "#red,green,blue"),
+        ("{#list*}", "#red,green,blue"),
+        ("{#keys}", "#semi,;,dot,.,comma,,"),
+        ("{#keys*}", "#semi=;,dot=.,comma=,"),
+        ("X{.var:3}", "X.val"),
+        ("X{.list}", "X.red,green,blue"),
+        ("X{.list*}", "X.red.green.blue"),
+        ("X{.keys}", "X.semi,%3B,dot,.,comma,%2C"),
+        ("X{.keys*}", "X.semi=%3B.dot=..comma=%2C"),
+        ("X{.empty_keys}", "X"),
+        ("X{.empty_keys*}", "X"),
+        ("{/var:1,var}", "/v/value"),
+        ("{/list}", "/red,green,blue"),
+        ("{/list*}", "/red/green/blue"),
+        ("{/list*,path:4}", "/red/green/blue/%2Ffoo"),
+        ("{/keys}", "/semi,%3B,dot,.,comma,%2C"),
+        ("{/keys*}", "/semi=%3B/dot=./comma=%2C"),
+        ("{;hello:5}", ";hello=Hello"),
+        ("{;list}", ";list=red,green,blue"),
+        ("{;list*}", ";list=red;list=green;list=blue"),
+        ("{;keys}", ";keys=semi,%3B,dot,.,comma,%2C"),
+        ("{;keys*}", ";semi=%3B;dot=.;comma=%2C"),
+        ("{?var:3}", "?var=val"),
+        ("{?list}", "?list=red,green,blue"),
+        ("{?list*}", "?list=red&list=green&list=blue"),
+        ("{?keys}", "?keys=semi,%3B,dot,.,comma,%2C"),
+        ("{?keys*}", "?semi=%3B&dot=.&comma=%2C"),
+        ("{&var:3}", "&var=val"),
+        ("{&list}", "&list=red,green,blue"),
+        ("{&list*}", "&list=red&list=green&list=blue"),
+        ("{&keys}", "&keys=semi,%3B,dot,.,comma,%2C"),
+        ("{&keys*}", "&semi=%3B&dot=.&comma=%2C"),
+    ]);
+}
+
+#[test]
+fn expand_misc() {
+    let t = UriTemplate::parse("/users/{id}{?fields*}").unwrap();
+    assert!(t.variables().eq(["id", "fields"]));
+
+    let vars = [
+        ("id", Value::from("é 1")),
+        ("fields", Value::from(vec!["a", ""])),
+    ];
+    assert_eq!(
+        t.expand(&vars).unwrap(),
+        "/users/%C3%A9%201?fields=a&fields="
+    );
+
+    // Literals are copied, with disallowed characters percent-encoded.
+    let t = UriTemplate::parse("/ö%20{x}").unwrap();
+    assert_eq!(t.expand(&vars).unwrap(), "/%C3%B6%20");
+
+    // Prefixes are counted in characters.
+    let t = UriTemplate::parse("{id:1}").unwrap();
+    assert_eq!(t.expand(&vars).unwrap(), "%C3%A9");
+
+    // Names with dots and percent-encoded octets.
+    let t = UriTemplate::parse("{a.b,%41}").unwrap();
+    let vars = [("a.b", Value::from("1")), ("%41", Value::from("2"))];
+    assert_eq!(t.expand(&vars).unwrap(), "1,2");
+}
+
+#[test]
+fn expand_error() {
+    let vars = vars();
+
+    let t = UriTemplate::parse("{list:1}").unwrap();
+    assert_eq!(
+        t.expand(&vars).unwrap_err().to_string(),
+        "prefix modifier applied to list or associative array"
+    );
+
+    let t = UriTemplate::parse("{#x}{#y}").unwrap();
+    assert_eq!(
+        t.expand(&vars).unwrap_err().to_string(),
+        "expansion is not a valid URI reference: unexpected character at index 5"
+    );
+
+    let t = UriTemplate::parse("{+x}:{y}").unwrap();
+    assert!(t.expand(&vars).is_err());
+}
+
+#[test]
+fn parse_error() {
+    let cases = [
+        ("{", "unclosed expression at index 0"),
+        ("a{b", "unclosed expression at index 1"),
+        ("{a,", "unclosed expression at index 0"),
+        ("{a:", "unclosed expression at index 0"),
+        ("}", "unexpected character at index 0"),
+        ("a b", "unexpected character at index 1"),
+        ("'", "unexpected character at index 0"),
+        ("%zz", "invalid percent-encoded octet at index 0"),
+        ("%4", "invalid percent-encoded octet at index 0"),
+        ("{}", "unexpected character at index 1"),
+        ("{=a}", "unexpected character at index 1"),
+        ("{|a}", "unexpected character at index 1"),
+        ("{a b}", "unexpected character at index 2"),
+        ("{.a.}", "unexpected character at index 4"),
+        ("{a..b}", "unexpected character at index 3"),
+        ("{.a}", ""),
+        ("{a:0}", "unexpected character at index 3"),
+        ("{a:10000}", "unexpected character at index 7"),
+        ("{a:}", "unexpected character at index 3"),
+        ("{a*:3}", "unexpected character at index 3"),
+        ("{a%2}", "invalid percent-encoded octet at index 2"),
+        ("{a}}", "unexpected character at index 3"),
+        ("{{a}", "unexpected character at index 1"),
+    ];
+    for (s, msg) in cases {
+        match UriTemplate::parse(s) {
+            Ok(_) => assert_eq!(msg, "", "{s}"),
+            Err(e) => assert_eq!(e.to_string(), msg, "{s}"),
+        }
+    }
+
+    assert_eq!(UriTemplate::parse("{a:9999}").unwrap().as_str(), "{a:9999}");
+    assert!("{a}".parse::<UriTemplate>().is_ok());
+}
+
+#[test]
+fn match_uri() {
+    let vars = vars();
+    for template in [
+        "{var}",
+        "{hello}",
+        "{half}",
+        "{+path}/here",
+        "here?ref={+path}",
+        "X{#hello}",
+        "{x,hello,y}",
+        "{+x,hello,y}",
+        "map?{x,y}",
+        "{/var,x}/here",
+        "{;x,y}",
+        "{;x,y,empty}",
+        "{?x,y}",
+        "{?x,y,empty}",
+        "?fixed=yes{&x}",
+        "{/list*}",
+        "{;list}",
+        "{;list*}",
+        "{?list*}",
+        "{/keys*}",
+        "{?keys*}",
+        "{&keys*}",
+        "{.dom*}",
+        "{var}{/who}{?x,y}{#hello}",
+    ] {
+        let t = UriTemplate::parse(template).unwrap();
+        let uri = t.expand(&vars).unwrap();
+        let matched = t.match_uri(&uri).unwrap();
+        for (name, value) in &matched {
+            assert_eq!(vars.get(&name[..]), Some(value), "{template}: {name}");
+        }
+        assert_eq!(
+            matched.len(),
+            t.variables().filter(|v| vars.contains_key(v)).count(),
+            "{template}"
+        );
+    }
+
+    let t = UriTemplate::parse("/repos/{owner}/{repo}/issues{?state,labels}").unwrap();
+    let uri = Uri::parse("/repos/a%20b/c/issues?labels=x,y").unwrap();
+    let matched = t.match_uri(&uri).unwrap();
+    assert_eq!(matched["owner"], Value::from("a b"));
+    assert_eq!(matched["repo"], Value::from("c"));
+    assert_eq!(matched["labels"], Value::from(["x", "y"]));
+    assert!(!matched.contains_key("state"));
+
+    let uri = Uri::parse("/repos/a/b/c/issues").unwrap();
+    assert!(t.match_uri(&uri).is_err());
+
+    // Non-greedy matching with backtracking.
+    let t = UriTemplate::parse("{+a}/{b}.txt").unwrap();
+    let matched = t.match_uri(&Uri::parse("x/y/z.txt").unwrap()).unwrap();
+    assert_eq!(matched["a"], Value::from("x/y"));
+    assert_eq!(matched["b"], Value::from("z"));
+
+    // Repeated variables must agree.
+    let t = UriTemplate::parse("/{x}/{x}").unwrap();
+    assert!(t.match_uri(&Uri::parse("/a/a").unwrap()).is_ok());
+    assert!(t.match_uri(&Uri::parse("/a/b").unwrap()).is_err());
+
+    // Prefix values are returned as is.
+    let t = UriTemplate::parse("{var:3}").unwrap();
+    let matched = t.match_uri(&Uri::parse("val").unwrap()).unwrap();
+    assert_eq!(matched["var"], Value::from("val"));
+
+    for template in [
+        "{x}{y}",
+        "{+path}{/seg}",
+        "{/a*}{/b}",
+        "{a}{.b}",
+        "{#a}{?b}",
+    ] {
+        let t = UriTemplate::parse(template).unwrap();
+        let e = t.match_uri(&Uri::parse("").unwrap()).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "template has adjacent expressions that cannot be told apart"
+        );
+    }
+
+    let t = UriTemplate::parse("{/a}{?b}").unwrap();
+    assert!(t.match_uri(&Uri::parse("/x?b=y").unwrap()).is_ok());
+}
+
+#[test]
+fn match_uri_backtracking() {
+    // Each variable may end at any 'x', so naive backtracking is exponential.
+    let mut template = String::from("/");
+    for i in 0..20 {
+        template.push_str(&format!("{{v{i}}}x"));
+    }
+    template.push('!');
+    let template = UriTemplate::parse(&template[..]).unwrap();
+
+    let start = std::time::Instant::now();
+    let uri = Uri::parse(format!("/{}", "x".repeat(40))).unwrap();
+    assert!(template.match_uri(&uri).is_err());
+    let uri = Uri::parse(format!("/{}!", "x".repeat(40))).unwrap();
+    assert!(template.match_uri(&uri).is_ok());
+    assert!(start.elapsed().as_secs() < 5);
+
+    // Variables occurring more than once must still bind to the same value.
+    let template = UriTemplate::parse("/{a}x{b}x{a}!").unwrap();
+    let uri = Uri::parse("/axbxc!").unwrap();
+    assert!(template.match_uri(&uri).is_err());
+    let uri = Uri::parse("/axbxxa!").unwrap();
+    let vars = template.match_uri(&uri).unwrap();
+    assert_eq!(vars["a"], Value::from("a"));
+    assert_eq!(vars["b"], Value::from("bx"));
+}